@@ -0,0 +1,265 @@
+//! Pluggable request authentication
+//!
+//! Register an [`Authenticator`] with
+//! [`HttpMcpServerBuilder::authenticator`](crate::HttpMcpServerBuilder::authenticator)
+//! and it is called for every request to the MCP endpoint: POSTed JSON-RPC messages
+//! ahead of session lookup and any other middleware, GET (SSE) and DELETE requests
+//! before the session is touched. On success the returned [`Identity`] is attached to the
+//! request extensions under [`IDENTITY_EXTENSION`], where tools read it through
+//! `SessionContext::identity()`. On failure the client gets HTTP 401 with a
+//! `WWW-Authenticate: Bearer` challenge and a JSON-RPC error body (code `-32001`).
+//!
+//! The authenticator sees the request headers with `Authorization: Bearer` rebuilt
+//! from the hardened parser in [`crate::middleware::bearer`], so malformed bearer
+//! values never reach it.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use hyper::HeaderMap;
+use hyper::header::{AUTHORIZATION, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tracing::debug;
+use turul_mcp_json_rpc_server::JsonRpcError;
+use turul_mcp_json_rpc_server::error::JsonRpcErrorObject;
+use turul_mcp_session_storage::SessionView;
+
+use crate::middleware::{
+    McpMiddleware, MiddlewareError, RequestContext, SessionInjection, error_codes,
+};
+
+/// Request extension key holding the authenticated [`Identity`]
+pub const IDENTITY_EXTENSION: &str = "__turul_internal.identity";
+
+/// Who a request was authenticated as
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Identity {
+    /// Stable identifier of the caller (user id, client id, token subject)
+    pub subject: String,
+    /// Additional attributes from the credential (scopes, tenant, roles)
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub claims: Map<String, Value>,
+}
+
+impl Identity {
+    /// Identity for `subject` with no claims
+    pub fn new(subject: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            claims: Map::new(),
+        }
+    }
+
+    /// Add a claim
+    pub fn with_claim(mut self, key: impl Into<String>, value: Value) -> Self {
+        self.claims.insert(key.into(), value);
+        self
+    }
+
+    /// Look up a claim
+    pub fn claim(&self, key: &str) -> Option<&Value> {
+        self.claims.get(key)
+    }
+}
+
+/// Why a request could not be authenticated
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AuthError {
+    /// The request carries no credentials
+    #[error("missing credentials")]
+    MissingCredentials,
+    /// The credentials were present but rejected
+    #[error("invalid credentials: {0}")]
+    InvalidCredentials(String),
+}
+
+/// Validates the credentials on an incoming request
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use async_trait::async_trait;
+/// use hyper::HeaderMap;
+/// use turul_http_mcp_server::{AuthError, Authenticator, Identity};
+/// use turul_http_mcp_server::middleware::extract_bearer_token;
+///
+/// struct StaticToken;
+///
+/// #[async_trait]
+/// impl Authenticator for StaticToken {
+///     async fn authenticate(&self, headers: &HeaderMap) -> Result<Identity, AuthError> {
+///         let token = headers
+///             .get("authorization")
+///             .and_then(|value| value.to_str().ok())
+///             .and_then(extract_bearer_token)
+///             .ok_or(AuthError::MissingCredentials)?;
+///         if token == "s3cret" {
+///             Ok(Identity::new("service-account"))
+///         } else {
+///             Err(AuthError::InvalidCredentials("unknown token".into()))
+///         }
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Authenticator: Send + Sync {
+    /// Return the caller's identity, or why it could not be established
+    async fn authenticate(&self, headers: &HeaderMap) -> Result<Identity, AuthError>;
+}
+
+/// Runs an [`Authenticator`] as pre-session middleware
+///
+/// [`HttpMcpServerBuilder::authenticator`](crate::HttpMcpServerBuilder::authenticator)
+/// installs this at the front of the middleware stack; use it directly when building
+/// a [`MiddlewareStack`](crate::middleware::MiddlewareStack) by hand.
+pub struct AuthenticatorMiddleware {
+    authenticator: Arc<dyn Authenticator>,
+}
+
+impl AuthenticatorMiddleware {
+    /// Wrap `authenticator`
+    pub fn new(authenticator: Arc<dyn Authenticator>) -> Self {
+        Self { authenticator }
+    }
+
+    fn challenge(err: &AuthError) -> MiddlewareError {
+        let www_authenticate = match err {
+            AuthError::MissingCredentials => "Bearer realm=\"mcp\"",
+            AuthError::InvalidCredentials(_) => "Bearer realm=\"mcp\", error=\"invalid_token\"",
+        };
+        let error = JsonRpcError::new(
+            None,
+            JsonRpcErrorObject {
+                code: error_codes::UNAUTHENTICATED,
+                message: format!("Authentication required: {}", err),
+                data: None,
+            },
+        );
+        MiddlewareError::HttpChallenge {
+            status: 401,
+            www_authenticate: www_authenticate.to_string(),
+            body: serde_json::to_string(&error).ok(),
+        }
+    }
+}
+
+/// Rebuild request headers from a pre-session [`RequestContext`]
+fn request_headers(ctx: &RequestContext<'_>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in ctx.metadata() {
+        if let (Ok(name), Some(Ok(value))) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            value.as_str().map(HeaderValue::from_str),
+        ) {
+            headers.insert(name, value);
+        }
+    }
+    if let Some(token) = ctx.bearer_token()
+        && let Ok(value) = HeaderValue::from_str(&format!("Bearer {}", token))
+    {
+        headers.insert(AUTHORIZATION, value);
+    }
+    headers
+}
+
+#[async_trait]
+impl McpMiddleware for AuthenticatorMiddleware {
+    fn runs_before_session(&self) -> bool {
+        true
+    }
+
+    fn guards_all_http_methods(&self) -> bool {
+        true
+    }
+
+    async fn before_dispatch(
+        &self,
+        ctx: &mut RequestContext<'_>,
+        _session: Option<&dyn SessionView>,
+        _injection: &mut SessionInjection,
+    ) -> Result<(), MiddlewareError> {
+        let identity = self
+            .authenticator
+            .authenticate(&request_headers(ctx))
+            .await
+            .map_err(|err| {
+                debug!("Authentication failed for {}: {}", ctx.method(), err);
+                Self::challenge(&err)
+            })?;
+        ctx.set_extension(
+            IDENTITY_EXTENSION,
+            serde_json::to_value(&identity).unwrap_or_default(),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct OneToken;
+
+    #[async_trait]
+    impl Authenticator for OneToken {
+        async fn authenticate(&self, headers: &HeaderMap) -> Result<Identity, AuthError> {
+            match headers.get(AUTHORIZATION).and_then(|v| v.to_str().ok()) {
+                Some("Bearer good") => Ok(Identity::new("alice").with_claim("tier", json!("gold"))),
+                Some(_) => Err(AuthError::InvalidCredentials("unknown token".into())),
+                None => Err(AuthError::MissingCredentials),
+            }
+        }
+    }
+
+    async fn run(ctx: &mut RequestContext<'_>) -> Result<(), MiddlewareError> {
+        AuthenticatorMiddleware::new(Arc::new(OneToken))
+            .before_dispatch(ctx, None, &mut SessionInjection::new())
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_accepted_token_sets_identity_extension() {
+        let mut ctx = RequestContext::new("tools/list", None);
+        ctx.set_bearer_token("good".to_string());
+        ctx.add_metadata("x-tenant", json!("acme"));
+
+        run(&mut ctx).await.unwrap();
+
+        let identity: Identity =
+            serde_json::from_value(ctx.get_extension(IDENTITY_EXTENSION).unwrap().clone()).unwrap();
+        assert_eq!(identity.subject, "alice");
+        assert_eq!(identity.claim("tier"), Some(&json!("gold")));
+    }
+
+    #[tokio::test]
+    async fn test_rejection_is_401_challenge_with_json_rpc_body() {
+        let mut ctx = RequestContext::new("initialize", None);
+        ctx.set_bearer_token("bad".to_string());
+
+        let Err(MiddlewareError::HttpChallenge {
+            status,
+            www_authenticate,
+            body,
+        }) = run(&mut ctx).await
+        else {
+            panic!("expected an HTTP challenge");
+        };
+        assert_eq!(status, 401);
+        assert!(www_authenticate.contains("invalid_token"));
+        let body: Value = serde_json::from_str(&body.unwrap()).unwrap();
+        assert_eq!(body["error"]["code"], error_codes::UNAUTHENTICATED);
+        assert_eq!(body["id"], Value::Null);
+        assert!(ctx.get_extension(IDENTITY_EXTENSION).is_none());
+
+        let mut ctx = RequestContext::new("initialize", None);
+        let Err(MiddlewareError::HttpChallenge {
+            www_authenticate, ..
+        }) = run(&mut ctx).await
+        else {
+            panic!("expected an HTTP challenge");
+        };
+        assert_eq!(www_authenticate, "Bearer realm=\"mcp\"");
+    }
+}
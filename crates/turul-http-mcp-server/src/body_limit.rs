@@ -0,0 +1,89 @@
+//! Bounded reading of HTTP request bodies
+//!
+//! POST handlers read JSON-RPC bodies with [`read_body_limited`] instead of
+//! `collect()`, so a client cannot make the server buffer more than
+//! [`ServerConfig::max_body_size`](crate::ServerConfig::max_body_size).
+//! A declared `Content-Length` over the limit is rejected before any frame is read;
+//! otherwise reading stops at the first frame that crosses it.
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http_body::Body;
+use http_body_util::BodyExt;
+
+/// Default limit, in bytes, on a request body (1 MiB)
+pub const DEFAULT_MAX_REQUEST_BODY_BYTES: usize = 1024 * 1024;
+
+/// Why a request body could not be read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BodyReadError {
+    /// The body is longer than the configured limit
+    TooLarge,
+    /// The underlying body returned an error
+    Read,
+}
+
+/// Read `body` into memory, failing once more than `limit` bytes arrive
+pub(crate) async fn read_body_limited<B>(body: B, limit: usize) -> Result<Bytes, BodyReadError>
+where
+    B: Body,
+{
+    if body.size_hint().lower() > limit as u64 {
+        return Err(BodyReadError::TooLarge);
+    }
+
+    let mut body = std::pin::pin!(body);
+    let mut buffer = BytesMut::new();
+    while let Some(frame) = body.frame().await {
+        let frame = frame.map_err(|_| BodyReadError::Read)?;
+        if let Ok(data) = frame.into_data() {
+            if buffer.len() + data.remaining() > limit {
+                return Err(BodyReadError::TooLarge);
+            }
+            buffer.put(data);
+        }
+    }
+    Ok(buffer.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+    use http_body::Frame;
+    use http_body_util::{Full, StreamBody};
+    use std::convert::Infallible;
+
+    fn chunked(
+        chunks: &[&'static str],
+    ) -> StreamBody<impl futures::Stream<Item = Result<Frame<Bytes>, Infallible>>> {
+        let frames: Vec<_> = chunks
+            .iter()
+            .map(|chunk| Ok(Frame::data(Bytes::from_static(chunk.as_bytes()))))
+            .collect();
+        StreamBody::new(stream::iter(frames))
+    }
+
+    #[tokio::test]
+    async fn test_body_within_limit_is_read() {
+        let body = read_body_limited(Full::new(Bytes::from_static(b"12345")), 5)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"12345");
+
+        let body = read_body_limited(chunked(&["12", "34", "5"]), 5)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"12345");
+    }
+
+    #[tokio::test]
+    async fn test_body_over_limit_is_rejected() {
+        // Known length is rejected up front
+        let result = read_body_limited(Full::new(Bytes::from_static(b"123456")), 5).await;
+        assert_eq!(result, Err(BodyReadError::TooLarge));
+
+        // Streamed body is rejected at the frame that crosses the limit
+        let result = read_body_limited(chunked(&["123", "456"]), 5).await;
+        assert_eq!(result, Err(BodyReadError::TooLarge));
+    }
+}
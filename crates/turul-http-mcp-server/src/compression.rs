@@ -0,0 +1,258 @@
+//! Response compression negotiated from `Accept-Encoding`
+//!
+//! `server.rs` passes every response through [`compress_response`]. JSON bodies at
+//! least [`ServerConfig::compression_threshold`] bytes long are encoded with the first
+//! of [`ServerConfig::compression_algorithms`] the client accepts. An
+//! `application/json` response always carries a single JSON-RPC message, so it is
+//! buffered before encoding even when the handler streamed it. SSE streams and
+//! responses that already carry a `Content-Encoding` are left untouched.
+
+use std::io::Write;
+
+use bytes::Bytes;
+use flate2::Compression;
+use flate2::write::{GzEncoder, ZlibEncoder};
+use http_body::Body;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full};
+use hyper::Response;
+use hyper::header::{self, HeaderValue};
+use tracing::warn;
+
+use crate::ServerConfig;
+
+/// Default minimum body size, in bytes, before a response is compressed
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 1024;
+
+/// A `Content-Encoding` the server can produce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentEncoding {
+    /// `gzip` (RFC 1952)
+    Gzip,
+    /// `deflate`, the zlib format (RFC 1950) as HTTP defines it
+    Deflate,
+}
+
+impl ContentEncoding {
+    /// The token used in `Accept-Encoding` and `Content-Encoding`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Deflate => "deflate",
+        }
+    }
+
+    fn encode(&self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            ContentEncoding::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+            ContentEncoding::Deflate => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+        }
+    }
+}
+
+/// Pick the first of `algorithms` that `accept_encoding` allows
+///
+/// Tokens with `q=0` are refused; `*` covers any algorithm not listed explicitly.
+pub(crate) fn negotiate(
+    accept_encoding: &str,
+    algorithms: &[ContentEncoding],
+) -> Option<ContentEncoding> {
+    let offers: Vec<(String, bool)> = accept_encoding
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let coding = parts.next()?.trim().to_ascii_lowercase();
+            if coding.is_empty() {
+                return None;
+            }
+            let acceptable = parts
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .all(|q| q.trim().parse::<f32>().map_or(true, |q| q > 0.0));
+            Some((coding, acceptable))
+        })
+        .collect();
+
+    let accepts = |coding: &str| {
+        offers
+            .iter()
+            .find(|(offered, _)| offered == coding)
+            .or_else(|| offers.iter().find(|(offered, _)| offered == "*"))
+            .is_some_and(|(_, acceptable)| *acceptable)
+    };
+    algorithms
+        .iter()
+        .copied()
+        .find(|algorithm| accepts(algorithm.as_str()))
+}
+
+/// Compress a buffered JSON response when the client accepts one of the configured
+/// algorithms and the body reaches the configured threshold
+pub(crate) async fn compress_response(
+    response: Response<UnsyncBoxBody<Bytes, hyper::Error>>,
+    accept_encoding: Option<&str>,
+    config: &ServerConfig,
+) -> Result<Response<UnsyncBoxBody<Bytes, hyper::Error>>, hyper::Error> {
+    let Some(accept_encoding) = accept_encoding else {
+        return Ok(response);
+    };
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    if !is_json || response.headers().contains_key(header::CONTENT_ENCODING) {
+        return Ok(response);
+    }
+    let Some(algorithm) = negotiate(accept_encoding, &config.compression_algorithms) else {
+        return Ok(response);
+    };
+    // Skip buffering when the body is known to be too small
+    if response
+        .body()
+        .size_hint()
+        .exact()
+        .is_some_and(|size| (size as usize) < config.compression_threshold)
+    {
+        return Ok(response);
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let data = body.collect().await?.to_bytes();
+    let encoded = if data.len() < config.compression_threshold {
+        None
+    } else {
+        match algorithm.encode(&data) {
+            Ok(encoded) => Some(encoded),
+            Err(err) => {
+                warn!("Failed to {} encode response: {}", algorithm.as_str(), err);
+                None
+            }
+        }
+    };
+    let body = match encoded {
+        Some(encoded) => {
+            parts.headers.insert(
+                header::CONTENT_ENCODING,
+                HeaderValue::from_static(algorithm.as_str()),
+            );
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Bytes::from(encoded)
+        }
+        None => data,
+    };
+    parts
+        .headers
+        .append(header::VARY, HeaderValue::from_static("accept-encoding"));
+    Ok(Response::from_parts(
+        parts,
+        Full::new(body)
+            .map_err(|never| match never {})
+            .boxed_unsync(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::{GzDecoder, ZlibDecoder};
+    use std::io::Read;
+
+    const BOTH: &[ContentEncoding] = &[ContentEncoding::Gzip, ContentEncoding::Deflate];
+
+    fn json_response(body: &str) -> Response<UnsyncBoxBody<Bytes, hyper::Error>> {
+        Response::builder()
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(
+                Full::new(Bytes::from(body.to_string()))
+                    .map_err(|never| match never {})
+                    .boxed_unsync(),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_negotiate_prefers_server_order() {
+        assert_eq!(
+            negotiate("deflate, gzip", BOTH),
+            Some(ContentEncoding::Gzip)
+        );
+        assert_eq!(negotiate("deflate", BOTH), Some(ContentEncoding::Deflate));
+        assert_eq!(negotiate("br", BOTH), None);
+        assert_eq!(negotiate("GZIP;q=0.5", BOTH), Some(ContentEncoding::Gzip));
+    }
+
+    #[test]
+    fn test_negotiate_honours_q_zero_and_wildcard() {
+        assert_eq!(
+            negotiate("gzip;q=0, deflate", BOTH),
+            Some(ContentEncoding::Deflate)
+        );
+        assert_eq!(negotiate("*", BOTH), Some(ContentEncoding::Gzip));
+        assert_eq!(
+            negotiate("gzip;q=0, *", BOTH),
+            Some(ContentEncoding::Deflate)
+        );
+        assert_eq!(negotiate("*;q=0", BOTH), None);
+        assert_eq!(negotiate("gzip", &[ContentEncoding::Deflate]), None);
+    }
+
+    #[tokio::test]
+    async fn test_large_json_is_compressed() {
+        let json = format!("{{\"data\":\"{}\"}}", "x".repeat(4096));
+        let config = ServerConfig::default();
+
+        let response = compress_response(json_response(&json), Some("gzip"), &config)
+            .await
+            .unwrap();
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let mut decoded = String::new();
+        GzDecoder::new(&body[..])
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, json);
+
+        let response = compress_response(json_response(&json), Some("deflate"), &config)
+            .await
+            .unwrap();
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "deflate");
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let mut decoded = String::new();
+        ZlibDecoder::new(&body[..])
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, json);
+    }
+
+    #[tokio::test]
+    async fn test_small_or_disabled_responses_pass_through() {
+        let config = ServerConfig::default();
+        let response = compress_response(json_response("{}"), Some("gzip"), &config)
+            .await
+            .unwrap();
+        assert!(!response.headers().contains_key(header::CONTENT_ENCODING));
+
+        let json = "x".repeat(4096);
+        let disabled = ServerConfig {
+            compression_algorithms: Vec::new(),
+            ..ServerConfig::default()
+        };
+        let response = compress_response(json_response(&json), Some("gzip"), &disabled)
+            .await
+            .unwrap();
+        assert!(!response.headers().contains_key(header::CONTENT_ENCODING));
+
+        let response = compress_response(json_response(&json), None, &config)
+            .await
+            .unwrap();
+        assert!(!response.headers().contains_key(header::CONTENT_ENCODING));
+    }
+}
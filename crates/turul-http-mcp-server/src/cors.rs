@@ -1,11 +1,14 @@
 //! CORS (Cross-Origin Resource Sharing) support
 //!
 //! All CORS headers for the HTTP transport are centralized here.
-//! `server.rs` applies [`CorsLayer::apply_cors_headers`] to every response
-//! (including OPTIONS preflight), so individual handlers do not need to
-//! set CORS headers themselves.
+//! `server.rs` applies [`CorsLayer::apply`] with the server's [`CorsConfig`] to
+//! every response (including OPTIONS preflight), so individual handlers do not
+//! need to set CORS headers themselves.
 
-use hyper::HeaderMap;
+use std::time::Duration;
+
+use hyper::header::{self, HeaderValue};
+use hyper::{HeaderMap, Method};
 
 // ── Canonical header values (single source of truth) ──────────────────
 
@@ -34,6 +37,122 @@ pub(crate) const CORS_MAX_AGE: &str = "86400";
 
 // ── Public API ────────────────────────────────────────────────────────
 
+/// Origins permitted to make cross-origin requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AllowedOrigins {
+    /// Any origin (`*`, or the request's origin when credentials are allowed)
+    Any,
+    /// Only these exact origins (e.g. `https://app.example.com`); the matching
+    /// origin is echoed back
+    List(Vec<String>),
+}
+
+/// CORS policy applied by [`CorsLayer::apply`]
+///
+/// The default allows every origin with the MCP transport's methods and headers,
+/// matching [`CorsLayer::apply_cors_headers`].
+///
+/// ```rust
+/// use std::time::Duration;
+/// use turul_http_mcp_server::CorsConfig;
+///
+/// let cors = CorsConfig::allow_origins(vec!["https://app.example.com".to_string()])
+///     .allow_credentials(true)
+///     .max_age(Duration::from_secs(600));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CorsConfig {
+    /// Origins permitted to make cross-origin requests
+    pub allowed_origins: AllowedOrigins,
+    /// Methods advertised in `Access-Control-Allow-Methods`
+    pub allowed_methods: Vec<Method>,
+    /// Request headers advertised in `Access-Control-Allow-Headers`
+    pub allowed_headers: Vec<String>,
+    /// Response headers advertised in `Access-Control-Expose-Headers`
+    pub expose_headers: Vec<String>,
+    /// Preflight cache duration (`Access-Control-Max-Age`); `None` omits the header
+    pub max_age: Option<Duration>,
+    /// Send `Access-Control-Allow-Credentials: true`; the request's origin is then
+    /// echoed instead of `*`
+    pub allow_credentials: bool,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: AllowedOrigins::Any,
+            allowed_methods: CORS_ALLOW_METHODS
+                .split(", ")
+                .filter_map(|method| method.parse().ok())
+                .collect(),
+            allowed_headers: split_list(CORS_ALLOW_HEADERS),
+            expose_headers: split_list(CORS_EXPOSE_HEADERS),
+            max_age: CORS_MAX_AGE.parse().ok().map(Duration::from_secs),
+            allow_credentials: false,
+        }
+    }
+}
+
+impl CorsConfig {
+    /// Allow every origin (the default)
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Allow only `origins`, echoing the matching one back
+    pub fn allow_origins(origins: Vec<String>) -> Self {
+        Self {
+            allowed_origins: AllowedOrigins::List(origins),
+            ..Self::default()
+        }
+    }
+
+    /// Set the methods advertised to preflight requests
+    pub fn allow_methods(mut self, methods: Vec<Method>) -> Self {
+        self.allowed_methods = methods;
+        self
+    }
+
+    /// Set the request headers advertised to preflight requests
+    pub fn allow_headers(mut self, headers: Vec<String>) -> Self {
+        self.allowed_headers = headers;
+        self
+    }
+
+    /// Set the response headers browsers may read
+    pub fn expose_headers(mut self, headers: Vec<String>) -> Self {
+        self.expose_headers = headers;
+        self
+    }
+
+    /// Set how long browsers may cache a preflight response
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Allow credentialed requests (cookies, `Authorization`)
+    pub fn allow_credentials(mut self, allow: bool) -> Self {
+        self.allow_credentials = allow;
+        self
+    }
+
+    /// The `Access-Control-Allow-Origin` value for a request from `origin`, or
+    /// `None` when the origin is not allowed
+    fn allow_origin_value(&self, origin: Option<&str>) -> Option<String> {
+        match (&self.allowed_origins, origin) {
+            (AllowedOrigins::Any, _) if !self.allow_credentials => Some("*".to_string()),
+            (AllowedOrigins::Any, Some(origin)) => Some(origin.to_string()),
+            (AllowedOrigins::List(allowed), Some(origin))
+                if allowed.iter().any(|allowed| allowed == origin) =>
+            {
+                Some(origin.to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
 /// CORS layer for adding appropriate headers to HTTP responses.
 ///
 /// Applied by `server.rs` to **every** response when `enable_cors` is true,
@@ -46,20 +165,92 @@ impl CorsLayer {
     ///
     /// Sets `Access-Control-Allow-Origin: *` (no credentials).
     pub fn apply_cors_headers(headers: &mut HeaderMap) {
-        headers.insert("Access-Control-Allow-Origin", "*".parse().unwrap());
-        headers.insert(
-            "Access-Control-Allow-Methods",
-            CORS_ALLOW_METHODS.parse().unwrap(),
+        Self::apply(&CorsConfig::default(), None, headers);
+    }
+
+    /// Apply the CORS headers `config` allows for a request from `origin`.
+    ///
+    /// A disallowed origin gets no CORS headers at all, and any set earlier
+    /// (e.g. by the SSE stream manager) are removed. When a specific origin is
+    /// echoed, `Vary: Origin` is added so caches keep responses apart.
+    pub fn apply(config: &CorsConfig, origin: Option<&str>, headers: &mut HeaderMap) {
+        let Some(allow_origin) = config
+            .allow_origin_value(origin)
+            .and_then(|value| HeaderValue::from_str(&value).ok())
+        else {
+            for name in CORS_RESPONSE_HEADERS {
+                headers.remove(name);
+            }
+            return;
+        };
+
+        if allow_origin != "*" {
+            headers.append(header::VARY, HeaderValue::from_static("origin"));
+        }
+        headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, allow_origin);
+        set_list(
+            headers,
+            header::ACCESS_CONTROL_ALLOW_METHODS,
+            config.allowed_methods.iter().map(Method::as_str),
         );
-        headers.insert(
-            "Access-Control-Allow-Headers",
-            CORS_ALLOW_HEADERS.parse().unwrap(),
+        set_list(
+            headers,
+            header::ACCESS_CONTROL_ALLOW_HEADERS,
+            config.allowed_headers.iter().map(String::as_str),
         );
-        headers.insert(
-            "Access-Control-Expose-Headers",
-            CORS_EXPOSE_HEADERS.parse().unwrap(),
+        set_list(
+            headers,
+            header::ACCESS_CONTROL_EXPOSE_HEADERS,
+            config.expose_headers.iter().map(String::as_str),
         );
-        headers.insert("Access-Control-Max-Age", CORS_MAX_AGE.parse().unwrap());
+        match config.max_age {
+            Some(max_age) => {
+                headers.insert(header::ACCESS_CONTROL_MAX_AGE, max_age.as_secs().into());
+            }
+            None => {
+                headers.remove(header::ACCESS_CONTROL_MAX_AGE);
+            }
+        }
+        if config.allow_credentials {
+            headers.insert(
+                header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                HeaderValue::from_static("true"),
+            );
+        } else {
+            headers.remove(header::ACCESS_CONTROL_ALLOW_CREDENTIALS);
+        }
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────
+
+const CORS_RESPONSE_HEADERS: [header::HeaderName; 6] = [
+    header::ACCESS_CONTROL_ALLOW_ORIGIN,
+    header::ACCESS_CONTROL_ALLOW_METHODS,
+    header::ACCESS_CONTROL_ALLOW_HEADERS,
+    header::ACCESS_CONTROL_EXPOSE_HEADERS,
+    header::ACCESS_CONTROL_MAX_AGE,
+    header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+];
+
+fn split_list(list: &str) -> Vec<String> {
+    list.split(", ").map(str::to_string).collect()
+}
+
+/// Set a comma-separated list header, or remove it when the list is empty
+fn set_list<'a>(
+    headers: &mut HeaderMap,
+    name: header::HeaderName,
+    values: impl Iterator<Item = &'a str>,
+) {
+    let joined = values.collect::<Vec<_>>().join(", ");
+    match HeaderValue::from_str(&joined) {
+        Ok(value) if !joined.is_empty() => {
+            headers.insert(name, value);
+        }
+        _ => {
+            headers.remove(name);
+        }
     }
 }
 
@@ -222,4 +413,100 @@ mod tests {
                 .is_none()
         );
     }
+
+    fn restricted() -> CorsConfig {
+        CorsConfig::allow_origins(vec![
+            "https://app.example.com".to_string(),
+            "https://admin.example.com".to_string(),
+        ])
+    }
+
+    #[test]
+    fn test_allowed_origin_is_echoed() {
+        let mut headers = HeaderMap::new();
+        CorsLayer::apply(
+            &restricted(),
+            Some("https://admin.example.com"),
+            &mut headers,
+        );
+
+        assert_eq!(
+            headers.get("Access-Control-Allow-Origin").unwrap(),
+            "https://admin.example.com"
+        );
+        assert_eq!(headers.get("Vary").unwrap(), "origin");
+        assert!(headers.contains_key("Access-Control-Allow-Methods"));
+    }
+
+    #[test]
+    fn test_disallowed_origin_gets_no_cors_headers() {
+        let mut headers = HeaderMap::new();
+        // e.g. the wildcard the SSE stream manager sets on its responses
+        headers.insert("Access-Control-Allow-Origin", "*".parse().unwrap());
+
+        CorsLayer::apply(
+            &restricted(),
+            Some("https://evil.example.com"),
+            &mut headers,
+        );
+        assert!(headers.is_empty(), "unexpected headers {headers:?}");
+
+        CorsLayer::apply(&restricted(), None, &mut headers);
+        assert!(headers.is_empty(), "unexpected headers {headers:?}");
+    }
+
+    #[test]
+    fn test_preflight_uses_configured_policy() {
+        use crate::json_rpc_responses::options_response;
+
+        let config = restricted()
+            .allow_methods(vec![Method::POST, Method::OPTIONS])
+            .allow_headers(vec![
+                "Content-Type".to_string(),
+                "Mcp-Session-Id".to_string(),
+            ])
+            .max_age(Duration::from_secs(600))
+            .allow_credentials(true);
+        let mut response = options_response();
+        CorsLayer::apply(
+            &config,
+            Some("https://app.example.com"),
+            response.headers_mut(),
+        );
+
+        let headers = response.headers();
+        assert_eq!(
+            headers.get("Access-Control-Allow-Origin").unwrap(),
+            "https://app.example.com"
+        );
+        assert_eq!(
+            headers.get("Access-Control-Allow-Methods").unwrap(),
+            "POST, OPTIONS"
+        );
+        assert_eq!(
+            headers.get("Access-Control-Allow-Headers").unwrap(),
+            "Content-Type, Mcp-Session-Id"
+        );
+        assert_eq!(headers.get("Access-Control-Max-Age").unwrap(), "600");
+        assert_eq!(
+            headers.get("Access-Control-Allow-Credentials").unwrap(),
+            "true"
+        );
+    }
+
+    #[test]
+    fn test_credentials_reflect_origin_instead_of_wildcard() {
+        let config = CorsConfig::allow_all().allow_credentials(true);
+        let mut headers = HeaderMap::new();
+        CorsLayer::apply(&config, Some("https://app.example.com"), &mut headers);
+
+        assert_eq!(
+            headers.get("Access-Control-Allow-Origin").unwrap(),
+            "https://app.example.com"
+        );
+        assert_eq!(
+            headers.get("Access-Control-Allow-Credentials").unwrap(),
+            "true"
+        );
+    }
 }
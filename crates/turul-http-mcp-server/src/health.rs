@@ -0,0 +1,158 @@
+//! Liveness and readiness probes for orchestrators such as Kubernetes
+//!
+//! Both probes are off by default. Enable them with
+//! [`HttpMcpServerBuilder::liveness_path`](crate::HttpMcpServerBuilder::liveness_path),
+//! [`HttpMcpServerBuilder::readiness_path`](crate::HttpMcpServerBuilder::readiness_path),
+//! or [`HttpMcpServerBuilder::health_checks`](crate::HttpMcpServerBuilder::health_checks)
+//! for [`DEFAULT_LIVENESS_PATH`] and [`DEFAULT_READINESS_PATH`]. Probes answer `GET` and
+//! `HEAD` without a session, middleware or JSON-RPC:
+//!
+//! - liveness: `200 {"status":"ok"}` whenever the server is accepting requests
+//! - readiness: pings the session storage backend, answering
+//!   `200 {"status":"ready","storage":"<backend>"}`, or
+//!   `503 {"status":"unavailable","storage":"<backend>","error":"..."}` when the ping
+//!   fails or takes longer than [`READINESS_TIMEOUT`]
+
+use std::time::Duration;
+
+use bytes::Bytes;
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full};
+use hyper::header::{self, HeaderValue};
+use hyper::{Method, Response, StatusCode};
+use serde_json::{Value, json};
+use tracing::warn;
+use turul_mcp_session_storage::BoxedSessionStorage;
+
+use crate::ServerConfig;
+
+/// Conventional liveness path enabled by `health_checks()`
+pub const DEFAULT_LIVENESS_PATH: &str = "/healthz";
+
+/// Conventional readiness path enabled by `health_checks()`
+pub const DEFAULT_READINESS_PATH: &str = "/readyz";
+
+/// Longest a readiness probe waits for the storage ping
+pub const READINESS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Which probe a request path selects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Probe {
+    Liveness,
+    Readiness,
+}
+
+impl Probe {
+    /// The probe configured at `path`, if any
+    pub(crate) fn for_path(config: &ServerConfig, path: &str) -> Option<Self> {
+        if config.liveness_path.as_deref() == Some(path) {
+            Some(Probe::Liveness)
+        } else if config.readiness_path.as_deref() == Some(path) {
+            Some(Probe::Readiness)
+        } else {
+            None
+        }
+    }
+
+    /// Answer the probe
+    pub(crate) async fn respond(
+        self,
+        method: &Method,
+        storage: &BoxedSessionStorage,
+    ) -> Response<UnsyncBoxBody<Bytes, hyper::Error>> {
+        if method != Method::GET && method != Method::HEAD {
+            let mut response = probe_response(
+                StatusCode::METHOD_NOT_ALLOWED,
+                json!({ "status": "method not allowed" }),
+            );
+            response
+                .headers_mut()
+                .insert(header::ALLOW, HeaderValue::from_static("GET, HEAD"));
+            return response;
+        }
+
+        match self {
+            Probe::Liveness => probe_response(StatusCode::OK, json!({ "status": "ok" })),
+            Probe::Readiness => readiness(storage).await,
+        }
+    }
+}
+
+async fn readiness(storage: &BoxedSessionStorage) -> Response<UnsyncBoxBody<Bytes, hyper::Error>> {
+    let backend = storage.backend_name();
+    let error = match tokio::time::timeout(READINESS_TIMEOUT, storage.ping()).await {
+        Ok(Ok(())) => {
+            return probe_response(
+                StatusCode::OK,
+                json!({ "status": "ready", "storage": backend }),
+            );
+        }
+        Ok(Err(err)) => err.to_string(),
+        Err(_) => format!("storage ping timed out after {:?}", READINESS_TIMEOUT),
+    };
+    warn!("Readiness probe failed for {} storage: {}", backend, error);
+    probe_response(
+        StatusCode::SERVICE_UNAVAILABLE,
+        json!({ "status": "unavailable", "storage": backend, "error": error }),
+    )
+}
+
+fn probe_response(status: StatusCode, body: Value) -> Response<UnsyncBoxBody<Bytes, hyper::Error>> {
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::CACHE_CONTROL, "no-store")
+        .body(
+            Full::new(Bytes::from(body.to_string()))
+                .map_err(|never| match never {})
+                .boxed_unsync(),
+        )
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use turul_mcp_session_storage::InMemorySessionStorage;
+
+    async fn body_json(response: Response<UnsyncBoxBody<Bytes, hyper::Error>>) -> Value {
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_probes_are_off_by_default() {
+        let config = ServerConfig::default();
+        assert_eq!(Probe::for_path(&config, DEFAULT_LIVENESS_PATH), None);
+        assert_eq!(Probe::for_path(&config, DEFAULT_READINESS_PATH), None);
+
+        let config = ServerConfig {
+            liveness_path: Some("/live".to_string()),
+            readiness_path: Some("/ready".to_string()),
+            ..ServerConfig::default()
+        };
+        assert_eq!(Probe::for_path(&config, "/live"), Some(Probe::Liveness));
+        assert_eq!(Probe::for_path(&config, "/ready"), Some(Probe::Readiness));
+        assert_eq!(Probe::for_path(&config, "/mcp"), None);
+    }
+
+    #[tokio::test]
+    async fn test_probe_responses() {
+        let storage = InMemorySessionStorage::new();
+
+        let response = Probe::Liveness.respond(&Method::GET, &storage).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await, json!({ "status": "ok" }));
+
+        let response = Probe::Readiness.respond(&Method::GET, &storage).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            body_json(response).await,
+            json!({ "status": "ready", "storage": "InMemory" })
+        );
+
+        let response = Probe::Readiness.respond(&Method::POST, &storage).await;
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers()[header::ALLOW], "GET, HEAD");
+    }
+}
@@ -0,0 +1,34 @@
+//! `Idempotency-Key` header forwarding
+//!
+//! The HTTP transport copies a request's `Idempotency-Key` header into the
+//! request-scoped extensions under [`IDEMPOTENCY_KEY_EXTENSION`], where the
+//! `tools/call` handler picks it up to replay a cached result instead of running
+//! the tool again.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// HTTP header a client sets to make a `tools/call` safe to retry
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Request extension key holding the request's idempotency key
+pub const IDEMPOTENCY_KEY_EXTENSION: &str = "__turul_internal.idempotency_key";
+
+/// Copy a non-empty `Idempotency-Key` header from `headers` into `extensions`
+pub(crate) fn forward_idempotency_key(
+    headers: &HashMap<String, String>,
+    extensions: &mut HashMap<String, Value>,
+) {
+    let key = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(IDEMPOTENCY_KEY_HEADER))
+        .map(|(_, value)| value.trim())
+        .filter(|value| !value.is_empty());
+    if let Some(key) = key {
+        extensions.insert(
+            IDEMPOTENCY_KEY_EXTENSION.to_string(),
+            Value::String(key.to_string()),
+        );
+    }
+}
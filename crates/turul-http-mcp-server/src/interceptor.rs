@@ -0,0 +1,109 @@
+//! Global raw JSON-RPC request interceptor
+//!
+//! A request interceptor sees every parsed JSON-RPC request before it reaches the
+//! middleware stack and dispatcher. It is the raw-protocol counterpart to
+//! [`McpMiddleware`](crate::middleware::McpMiddleware): use it to inspect or lightly
+//! mutate requests (inject a default `_meta`, normalize a legacy field) or to reject
+//! them outright.
+//!
+//! # Ordering
+//!
+//! For each POSTed JSON-RPC request the HTTP transport runs, in order:
+//!
+//! 1. Transport validation (Accept, Content-Type, body size, UTF-8, JSON-RPC parsing)
+//! 2. Pre-session middleware (`before_session`, e.g. bearer-token authentication)
+//! 3. Session validation (`Mcp-Session-Id` lookup / creation for `initialize`)
+//! 4. **Request interceptor**
+//! 5. Session-aware middleware (`before_dispatch`) — observes the mutated request
+//! 6. Dispatch to the method handler
+//!
+//! Notifications are not passed to the interceptor.
+
+use std::sync::Arc;
+
+use turul_mcp_json_rpc_server::{JsonRpcMessage, JsonRpcRequest};
+use turul_mcp_protocol::McpError;
+
+/// Interceptor invoked for every parsed JSON-RPC request.
+///
+/// Returning `Err` short-circuits dispatch; the error is converted to a JSON-RPC
+/// error response carrying the request's id.
+pub type RequestInterceptor =
+    Arc<dyn Fn(&mut JsonRpcRequest) -> Result<(), McpError> + Send + Sync>;
+
+/// Run the interceptor (if any), returning the error response when it rejects the request
+pub(crate) fn apply_request_interceptor(
+    interceptor: Option<&RequestInterceptor>,
+    request: &mut JsonRpcRequest,
+) -> Option<JsonRpcMessage> {
+    let interceptor = interceptor?;
+    match interceptor(request) {
+        Ok(()) => None,
+        Err(err) => {
+            tracing::debug!(
+                "Request interceptor rejected method '{}': {}",
+                request.method,
+                err
+            );
+            Some(JsonRpcMessage::error(
+                err.to_json_rpc_response(Some(request.id.clone())),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use turul_mcp_json_rpc_server::{RequestId, RequestParams};
+
+    #[test]
+    fn test_interceptor_mutates_request() {
+        let interceptor: RequestInterceptor = Arc::new(|req: &mut JsonRpcRequest| {
+            if let Some(RequestParams::Object(map)) = req.params.as_mut() {
+                map.entry("_meta".to_string())
+                    .or_insert_with(|| json!({"tenant": "default"}));
+            }
+            Ok(())
+        });
+
+        let mut request = JsonRpcRequest::new_with_object_params(
+            RequestId::Number(1),
+            "tools/call".to_string(),
+            [("name".to_string(), json!("echo"))].into_iter().collect(),
+        );
+
+        assert!(apply_request_interceptor(Some(&interceptor), &mut request).is_none());
+        assert_eq!(
+            request.get_param("_meta"),
+            Some(&json!({"tenant": "default"}))
+        );
+    }
+
+    #[test]
+    fn test_interceptor_rejection_carries_request_id() {
+        let interceptor: RequestInterceptor = Arc::new(|_req: &mut JsonRpcRequest| {
+            Err(McpError::InvalidParameters(
+                "legacy field not allowed".to_string(),
+            ))
+        });
+
+        let mut request =
+            JsonRpcRequest::new_no_params(RequestId::Number(7), "tools/list".to_string());
+
+        match apply_request_interceptor(Some(&interceptor), &mut request) {
+            Some(JsonRpcMessage::Error(err)) => {
+                assert_eq!(err.id, Some(RequestId::Number(7)));
+                assert_eq!(err.error.code, -32602);
+            }
+            other => panic!("Expected error response, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_no_interceptor_is_passthrough() {
+        let mut request = JsonRpcRequest::new_no_params(RequestId::Number(1), "ping".to_string());
+        assert!(apply_request_interceptor(None, &mut request).is_none());
+    }
+}
@@ -0,0 +1,727 @@
+//! Per-client-IP connection, session, and concurrency limits
+//!
+//! Guards the HTTP transport against a single source monopolizing the server:
+//!
+//! - **Connections** — concurrent TCP connections per socket peer. Checked at accept
+//!   time, before any headers exist, so behind a reverse proxy this caps the proxy
+//!   itself; rejected connections get a `429` and are closed.
+//! - **Sessions** — live MCP sessions created per client IP. A session-creating
+//!   request (POST without `Mcp-Session-Id`) over the cap gets a `429`.
+//! - **In-flight requests** — concurrent requests per client IP, plus an optional
+//!   server-wide cap shared fairly: each active IP may hold at most
+//!   `max_in_flight_total / active_ips` requests. An IP counts as active while it has
+//!   requests in flight or has tried within the fair-share window, so a newcomer that
+//!   was turned away still shrinks everyone else's share. Over its share an IP gets a
+//!   `429`; when the server as a whole is full the response is a `503`.
+//!
+//! The client IP is the socket peer unless that peer is a configured trusted proxy,
+//! in which case the forwarded header (default `X-Forwarded-For`) is walked right to
+//! left and the first address that is not itself a trusted proxy is used.
+
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full};
+use hyper::header::{CONNECTION, CONTENT_TYPE, HeaderMap, RETRY_AFTER};
+use hyper::{Response, StatusCode};
+use tracing::{debug, warn};
+
+/// Configuration for per-client-IP limits
+#[derive(Debug, Clone)]
+pub struct IpLimitConfig {
+    /// Maximum concurrent TCP connections per socket peer (default: unlimited)
+    pub max_connections_per_ip: Option<usize>,
+    /// Maximum live sessions created per client IP (default: unlimited)
+    pub max_sessions_per_ip: Option<usize>,
+    /// Maximum concurrent in-flight requests per client IP (default: unlimited)
+    pub max_in_flight_per_ip: Option<usize>,
+    /// Server-wide in-flight request capacity shared fairly between IPs (default: unlimited)
+    pub max_in_flight_total: Option<usize>,
+    /// How long a client IP stays "active" for fair-share purposes after its last attempt
+    pub fair_share_window: Duration,
+    /// Socket peers whose forwarded header is trusted (default: none)
+    pub trusted_proxies: Vec<IpAddr>,
+    /// Header carrying the forwarded client chain (default: `x-forwarded-for`)
+    pub forwarded_header: String,
+    /// Value of the `Retry-After` header on rejections, in seconds (default: 1)
+    pub retry_after_secs: u64,
+}
+
+impl Default for IpLimitConfig {
+    fn default() -> Self {
+        Self {
+            max_connections_per_ip: None,
+            max_sessions_per_ip: None,
+            max_in_flight_per_ip: None,
+            max_in_flight_total: None,
+            fair_share_window: Duration::from_secs(5),
+            trusted_proxies: Vec::new(),
+            forwarded_header: "x-forwarded-for".to_string(),
+            retry_after_secs: 1,
+        }
+    }
+}
+
+impl IpLimitConfig {
+    /// Create a configuration with no limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cap concurrent TCP connections per socket peer
+    pub fn max_connections_per_ip(mut self, max: usize) -> Self {
+        self.max_connections_per_ip = Some(max);
+        self
+    }
+
+    /// Cap live sessions created per client IP
+    pub fn max_sessions_per_ip(mut self, max: usize) -> Self {
+        self.max_sessions_per_ip = Some(max);
+        self
+    }
+
+    /// Cap concurrent in-flight requests per client IP
+    pub fn max_in_flight_per_ip(mut self, max: usize) -> Self {
+        self.max_in_flight_per_ip = Some(max);
+        self
+    }
+
+    /// Set the server-wide in-flight capacity shared fairly between client IPs
+    pub fn max_in_flight_total(mut self, max: usize) -> Self {
+        self.max_in_flight_total = Some(max);
+        self
+    }
+
+    /// Set how long an IP counts towards the fair share after its last attempt
+    pub fn fair_share_window(mut self, window: Duration) -> Self {
+        self.fair_share_window = window;
+        self
+    }
+
+    /// Trust the forwarded header when the socket peer is one of these proxies
+    pub fn trusted_proxies(mut self, proxies: impl IntoIterator<Item = IpAddr>) -> Self {
+        self.trusted_proxies = proxies.into_iter().collect();
+        self
+    }
+
+    /// Use a different forwarded header (e.g. `x-real-ip`)
+    pub fn forwarded_header(mut self, header: impl Into<String>) -> Self {
+        self.forwarded_header = header.into().to_ascii_lowercase();
+        self
+    }
+
+    /// Set the `Retry-After` value sent with rejections
+    pub fn retry_after_secs(mut self, secs: u64) -> Self {
+        self.retry_after_secs = secs;
+        self
+    }
+}
+
+/// Why a request was turned away
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitRejection {
+    /// The client IP is over its own limit or fair share (`429`)
+    ClientLimit,
+    /// The server as a whole is at capacity (`503`)
+    ServerCapacity,
+}
+
+impl LimitRejection {
+    /// HTTP status for this rejection
+    pub fn status(&self) -> StatusCode {
+        match self {
+            LimitRejection::ClientLimit => StatusCode::TOO_MANY_REQUESTS,
+            LimitRejection::ServerCapacity => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+#[derive(Default)]
+struct LimiterState {
+    connections: HashMap<IpAddr, usize>,
+    in_flight: HashMap<IpAddr, usize>,
+    in_flight_total: usize,
+    last_attempt: HashMap<IpAddr, Instant>,
+    sessions: HashMap<IpAddr, HashSet<String>>,
+    pending_sessions: HashMap<IpAddr, usize>,
+}
+
+/// Shared per-client-IP limiter used by the HTTP server
+pub struct IpLimiter {
+    config: IpLimitConfig,
+    state: Mutex<LimiterState>,
+}
+
+impl IpLimiter {
+    /// Create a limiter from configuration
+    pub fn new(config: IpLimitConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(LimiterState::default()),
+        }
+    }
+
+    /// Get the limiter configuration
+    pub fn config(&self) -> &IpLimitConfig {
+        &self.config
+    }
+
+    /// Resolve the client IP for a request received from `peer`
+    pub fn client_ip(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        if !self.config.trusted_proxies.contains(&peer) {
+            return peer;
+        }
+
+        let forwarded = headers
+            .get_all(self.config.forwarded_header.as_str())
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .filter_map(|hop| hop.trim().parse::<IpAddr>().ok())
+            .collect::<Vec<_>>();
+
+        forwarded
+            .into_iter()
+            .rev()
+            .find(|hop| !self.config.trusted_proxies.contains(hop))
+            .unwrap_or(peer)
+    }
+
+    /// Try to admit a new TCP connection from `peer`
+    pub fn try_acquire_connection(self: &Arc<Self>, peer: IpAddr) -> Option<ConnectionPermit> {
+        let mut state = self.state.lock().unwrap();
+        let count = state.connections.entry(peer).or_insert(0);
+        if let Some(max) = self.config.max_connections_per_ip
+            && *count >= max
+        {
+            warn!("Connection limit reached for {} ({} open)", peer, count);
+            return None;
+        }
+        *count += 1;
+        Some(ConnectionPermit {
+            limiter: Arc::clone(self),
+            ip: peer,
+        })
+    }
+
+    /// Try to admit a request from `client_ip`, applying per-IP and fair-share limits
+    pub fn try_acquire_request(
+        self: &Arc<Self>,
+        client_ip: IpAddr,
+    ) -> std::result::Result<RequestPermit, LimitRejection> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+
+        // Every attempt, admitted or not, marks the IP as contending for capacity
+        let window = self.config.fair_share_window;
+        state
+            .last_attempt
+            .retain(|_, last| now.duration_since(*last) < window);
+        state.last_attempt.insert(client_ip, now);
+
+        let held = state.in_flight.get(&client_ip).copied().unwrap_or(0);
+
+        if let Some(max) = self.config.max_in_flight_per_ip
+            && held >= max
+        {
+            debug!("In-flight limit reached for {} ({} held)", client_ip, held);
+            return Err(LimitRejection::ClientLimit);
+        }
+
+        if let Some(total) = self.config.max_in_flight_total {
+            let active: HashSet<IpAddr> = state
+                .in_flight
+                .keys()
+                .chain(state.last_attempt.keys())
+                .copied()
+                .collect();
+            let share = (total / active.len().max(1)).max(1);
+            if held >= share {
+                debug!(
+                    "Fair share reached for {} ({} held, share {} of {} across {} IPs)",
+                    client_ip,
+                    held,
+                    share,
+                    total,
+                    active.len()
+                );
+                return Err(LimitRejection::ClientLimit);
+            }
+            if state.in_flight_total >= total {
+                debug!("Server in-flight capacity reached ({})", total);
+                return Err(LimitRejection::ServerCapacity);
+            }
+        }
+
+        *state.in_flight.entry(client_ip).or_insert(0) += 1;
+        state.in_flight_total += 1;
+        Ok(RequestPermit {
+            limiter: Arc::clone(self),
+            ip: client_ip,
+        })
+    }
+
+    /// Reserve a session slot for a session-creating request from `client_ip`
+    ///
+    /// Sessions recorded for the IP that no longer exist in `storage` (expired or
+    /// deleted) are forgotten first. On success the slot stays reserved until
+    /// [`SessionReservation::complete`] records the created session or the
+    /// reservation is dropped. Without a session cap nothing is tracked.
+    pub async fn try_reserve_session(
+        self: &Arc<Self>,
+        client_ip: IpAddr,
+        storage: &turul_mcp_session_storage::BoxedSessionStorage,
+    ) -> std::result::Result<SessionReservation, LimitRejection> {
+        let Some(max) = self.config.max_sessions_per_ip else {
+            return Ok(SessionReservation {
+                limiter: Arc::clone(self),
+                ip: client_ip,
+                tracked: false,
+            });
+        };
+
+        self.forget_closed_sessions(Some(client_ip), storage).await;
+
+        let mut state = self.state.lock().unwrap();
+        let live = state.sessions.get(&client_ip).map_or(0, HashSet::len);
+        let pending = state.pending_sessions.get(&client_ip).copied().unwrap_or(0);
+        if live + pending >= max {
+            warn!(
+                "Session limit reached for {} ({} live, {} pending)",
+                client_ip, live, pending
+            );
+            return Err(LimitRejection::ClientLimit);
+        }
+        *state.pending_sessions.entry(client_ip).or_insert(0) += 1;
+        drop(state);
+
+        Ok(SessionReservation {
+            limiter: Arc::clone(self),
+            ip: client_ip,
+            tracked: true,
+        })
+    }
+
+    /// Forget tracked sessions that no longer exist in `storage`, for every client IP
+    ///
+    /// Run periodically (the HTTP server does so from its session cleanup task) so
+    /// sessions of IPs that never come back do not accumulate.
+    pub async fn prune_sessions(&self, storage: &turul_mcp_session_storage::BoxedSessionStorage) {
+        self.forget_closed_sessions(None, storage).await;
+    }
+
+    /// Drop tracked sessions that are gone or terminated, for one IP or all of them
+    async fn forget_closed_sessions(
+        &self,
+        only: Option<IpAddr>,
+        storage: &turul_mcp_session_storage::BoxedSessionStorage,
+    ) {
+        let tracked: Vec<(IpAddr, String)> = {
+            let state = self.state.lock().unwrap();
+            state
+                .sessions
+                .iter()
+                .filter(|(ip, _)| only.is_none_or(|only| only == **ip))
+                .flat_map(|(ip, ids)| ids.iter().map(move |id| (*ip, id.clone())))
+                .collect()
+        };
+
+        let mut gone = Vec::new();
+        for (ip, session_id) in tracked {
+            match storage.get_session(&session_id).await {
+                Ok(Some(info)) if !info.is_terminated() => {}
+                Ok(_) => gone.push((ip, session_id)),
+                // Keep counting sessions we cannot verify rather than fail open
+                Err(_) => {}
+            }
+        }
+        if gone.is_empty() {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        for (ip, session_id) in gone {
+            if let Some(ids) = state.sessions.get_mut(&ip) {
+                ids.remove(&session_id);
+                if ids.is_empty() {
+                    state.sessions.remove(&ip);
+                }
+            }
+        }
+    }
+
+    /// Number of live sessions currently attributed to `client_ip`
+    pub fn session_count(&self, client_ip: IpAddr) -> usize {
+        self.state
+            .lock()
+            .unwrap()
+            .sessions
+            .get(&client_ip)
+            .map_or(0, HashSet::len)
+    }
+
+    /// Build the HTTP response for a rejection
+    pub fn rejection_response(
+        &self,
+        rejection: LimitRejection,
+    ) -> Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>> {
+        let status = rejection.status();
+        let message = match rejection {
+            LimitRejection::ClientLimit => "Too many requests from this client",
+            LimitRejection::ServerCapacity => "Server is at capacity",
+        };
+        let body = serde_json::json!({
+            "error": {
+                "code": status.as_u16(),
+                "message": message
+            }
+        })
+        .to_string();
+        Response::builder()
+            .status(status)
+            .header(CONTENT_TYPE, "application/json")
+            .header(RETRY_AFTER, self.config.retry_after_secs.to_string())
+            .header(CONNECTION, "close")
+            .body(
+                Full::new(Bytes::from(body))
+                    .map_err(|never| match never {})
+                    .boxed_unsync(),
+            )
+            .unwrap()
+    }
+}
+
+/// Held for the lifetime of an admitted TCP connection
+pub struct ConnectionPermit {
+    limiter: Arc<IpLimiter>,
+    ip: IpAddr,
+}
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        let mut state = self.limiter.state.lock().unwrap();
+        if let Some(count) = state.connections.get_mut(&self.ip) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                state.connections.remove(&self.ip);
+            }
+        }
+    }
+}
+
+/// Held while an admitted request is being handled
+pub struct RequestPermit {
+    limiter: Arc<IpLimiter>,
+    ip: IpAddr,
+}
+
+impl RequestPermit {
+    /// Keep the permit until `response`'s body has been fully sent or dropped
+    ///
+    /// SSE and streamed tool responses keep working inside the body after the
+    /// handler returns, so the request is only finished once the body is.
+    pub(crate) fn hold_until_body_ends(
+        self,
+        response: Response<UnsyncBoxBody<Bytes, hyper::Error>>,
+    ) -> Response<UnsyncBoxBody<Bytes, hyper::Error>> {
+        response.map(|inner| {
+            PermitBody {
+                inner,
+                permit: Some(self),
+            }
+            .boxed_unsync()
+        })
+    }
+}
+
+/// Response body that releases its [`RequestPermit`] at end of stream
+struct PermitBody {
+    inner: UnsyncBoxBody<Bytes, hyper::Error>,
+    permit: Option<RequestPermit>,
+}
+
+impl Body for PermitBody {
+    type Data = Bytes;
+    type Error = hyper::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<std::result::Result<Frame<Self::Data>, Self::Error>>> {
+        let polled = Pin::new(&mut self.inner).poll_frame(cx);
+        if let Poll::Ready(None) = polled {
+            self.permit.take();
+        }
+        polled
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+impl Drop for RequestPermit {
+    fn drop(&mut self) {
+        let mut state = self.limiter.state.lock().unwrap();
+        state.in_flight_total = state.in_flight_total.saturating_sub(1);
+        if let Some(count) = state.in_flight.get_mut(&self.ip) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                state.in_flight.remove(&self.ip);
+            }
+        }
+    }
+}
+
+/// A reserved session slot for a session-creating request
+pub struct SessionReservation {
+    limiter: Arc<IpLimiter>,
+    ip: IpAddr,
+    tracked: bool,
+}
+
+impl SessionReservation {
+    /// Attribute the created session to the reserving IP
+    pub fn complete(self, session_id: impl Into<String>) {
+        if !self.tracked {
+            return;
+        }
+        self.limiter
+            .state
+            .lock()
+            .unwrap()
+            .sessions
+            .entry(self.ip)
+            .or_default()
+            .insert(session_id.into());
+    }
+}
+
+impl Drop for SessionReservation {
+    fn drop(&mut self) {
+        if !self.tracked {
+            return;
+        }
+        let mut state = self.limiter.state.lock().unwrap();
+        if let Some(count) = state.pending_sessions.get_mut(&self.ip) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                state.pending_sessions.remove(&self.ip);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use turul_mcp_session_storage::InMemorySessionStorage;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_client_ip_ignores_header_from_untrusted_peer() {
+        let limiter = IpLimiter::new(IpLimitConfig::new().trusted_proxies([ip("10.0.0.1")]));
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "203.0.113.7".parse().unwrap());
+
+        assert_eq!(
+            limiter.client_ip(ip("192.0.2.1"), &headers),
+            ip("192.0.2.1")
+        );
+    }
+
+    #[test]
+    fn test_client_ip_walks_forwarded_chain_from_trusted_proxy() {
+        let limiter =
+            IpLimiter::new(IpLimitConfig::new().trusted_proxies([ip("10.0.0.1"), ip("10.0.0.2")]));
+        let mut headers = HeaderMap::new();
+        // Leftmost entry is client-controlled and must not be trusted blindly
+        headers.insert(
+            "x-forwarded-for",
+            "198.51.100.1, 203.0.113.7, 10.0.0.2".parse().unwrap(),
+        );
+
+        assert_eq!(
+            limiter.client_ip(ip("10.0.0.1"), &headers),
+            ip("203.0.113.7")
+        );
+        assert_eq!(
+            limiter.client_ip(ip("10.0.0.1"), &HeaderMap::new()),
+            ip("10.0.0.1")
+        );
+    }
+
+    #[test]
+    fn test_connection_cap_is_per_ip() {
+        let limiter = Arc::new(IpLimiter::new(
+            IpLimitConfig::new().max_connections_per_ip(2),
+        ));
+
+        let first = limiter.try_acquire_connection(ip("192.0.2.1")).unwrap();
+        let _second = limiter.try_acquire_connection(ip("192.0.2.1")).unwrap();
+        assert!(limiter.try_acquire_connection(ip("192.0.2.1")).is_none());
+        assert!(limiter.try_acquire_connection(ip("192.0.2.2")).is_some());
+
+        drop(first);
+        assert!(limiter.try_acquire_connection(ip("192.0.2.1")).is_some());
+    }
+
+    #[test]
+    fn test_fair_share_limits_monopolizing_ip() {
+        let limiter = Arc::new(IpLimiter::new(IpLimitConfig::new().max_in_flight_total(4)));
+        let hog = ip("192.0.2.1");
+        let newcomer = ip("192.0.2.2");
+
+        // Alone, the hog may use the whole capacity
+        let mut held: Vec<_> = (0..4)
+            .map(|_| limiter.try_acquire_request(hog).unwrap())
+            .collect();
+        assert_eq!(
+            limiter.try_acquire_request(newcomer).err(),
+            Some(LimitRejection::ServerCapacity)
+        );
+
+        // The turned-away newcomer now counts: the hog's share drops to 2
+        held.truncate(2);
+        assert_eq!(
+            limiter.try_acquire_request(hog).err(),
+            Some(LimitRejection::ClientLimit)
+        );
+        assert!(limiter.try_acquire_request(newcomer).is_ok());
+    }
+
+    #[test]
+    fn test_in_flight_cap_per_ip() {
+        let limiter = Arc::new(IpLimiter::new(IpLimitConfig::new().max_in_flight_per_ip(1)));
+        let permit = limiter.try_acquire_request(ip("192.0.2.1")).unwrap();
+        assert_eq!(
+            limiter.try_acquire_request(ip("192.0.2.1")).err(),
+            Some(LimitRejection::ClientLimit)
+        );
+        drop(permit);
+        assert!(limiter.try_acquire_request(ip("192.0.2.1")).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_session_cap_prunes_deleted_sessions() {
+        let storage: Arc<turul_mcp_session_storage::BoxedSessionStorage> =
+            Arc::new(InMemorySessionStorage::new());
+        let limiter = Arc::new(IpLimiter::new(IpLimitConfig::new().max_sessions_per_ip(1)));
+        let client = ip("192.0.2.1");
+
+        let session = storage
+            .create_session(turul_mcp_protocol::ServerCapabilities::default())
+            .await
+            .unwrap();
+        limiter
+            .try_reserve_session(client, storage.as_ref())
+            .await
+            .unwrap()
+            .complete(session.session_id.clone());
+
+        assert_eq!(
+            limiter
+                .try_reserve_session(client, storage.as_ref())
+                .await
+                .err(),
+            Some(LimitRejection::ClientLimit)
+        );
+        assert!(
+            limiter
+                .try_reserve_session(ip("192.0.2.2"), storage.as_ref())
+                .await
+                .is_ok()
+        );
+
+        storage.delete_session(&session.session_id).await.unwrap();
+        assert!(
+            limiter
+                .try_reserve_session(client, storage.as_ref())
+                .await
+                .is_ok()
+        );
+        assert_eq!(limiter.session_count(client), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sessions_not_tracked_without_session_cap() {
+        let storage: Arc<turul_mcp_session_storage::BoxedSessionStorage> =
+            Arc::new(InMemorySessionStorage::new());
+        let limiter = Arc::new(IpLimiter::new(IpLimitConfig::new().max_in_flight_per_ip(4)));
+        let client = ip("192.0.2.1");
+
+        for _ in 0..3 {
+            let session = storage
+                .create_session(turul_mcp_protocol::ServerCapabilities::default())
+                .await
+                .unwrap();
+            limiter
+                .try_reserve_session(client, storage.as_ref())
+                .await
+                .unwrap()
+                .complete(session.session_id);
+        }
+        assert_eq!(limiter.session_count(client), 0);
+    }
+
+    #[tokio::test]
+    async fn test_prune_sessions_forgets_closed_sessions_of_every_ip() {
+        let storage: Arc<turul_mcp_session_storage::BoxedSessionStorage> =
+            Arc::new(InMemorySessionStorage::new());
+        let limiter = Arc::new(IpLimiter::new(IpLimitConfig::new().max_sessions_per_ip(5)));
+        let client = ip("192.0.2.1");
+
+        let session = storage
+            .create_session(turul_mcp_protocol::ServerCapabilities::default())
+            .await
+            .unwrap();
+        limiter
+            .try_reserve_session(client, storage.as_ref())
+            .await
+            .unwrap()
+            .complete(session.session_id.clone());
+        assert_eq!(limiter.session_count(client), 1);
+
+        storage.delete_session(&session.session_id).await.unwrap();
+        limiter.prune_sessions(storage.as_ref()).await;
+        assert_eq!(limiter.session_count(client), 0);
+        assert!(limiter.state.lock().unwrap().sessions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_request_permit_held_until_body_ends() {
+        let limiter = Arc::new(IpLimiter::new(IpLimitConfig::new().max_in_flight_per_ip(1)));
+        let client = ip("192.0.2.1");
+
+        let permit = limiter.try_acquire_request(client).unwrap();
+        let body = Full::new(Bytes::from_static(b"data: chunk\n\n"))
+            .map_err(|never| match never {})
+            .boxed_unsync();
+        let mut response = permit.hold_until_body_ends(Response::new(body));
+
+        // The handler has returned, but the body is still streaming
+        assert_eq!(
+            limiter.try_acquire_request(client).err(),
+            Some(LimitRejection::ClientLimit)
+        );
+
+        let body = response.body_mut();
+        assert!(body.frame().await.is_some());
+        assert!(body.frame().await.is_none());
+        assert!(limiter.try_acquire_request(client).is_ok());
+    }
+}
@@ -77,9 +77,9 @@ pub fn jsonrpc_response_with_session(
     response: JsonRpcResponse,
     session_id: Option<String>,
 ) -> Result<Response<JsonRpcBody>, hyper::Error> {
-    let body_bytes = serde_json::to_vec(&response).unwrap_or_else(|e| {
+    let body_bytes = crate::response_buffer::serialize_response(&response).unwrap_or_else(|e| {
         error!("Failed to serialize JSON-RPC response: {}", e);
-        b"{}".to_vec()
+        Bytes::from_static(b"{}")
     });
 
     let mut builder = Response::builder()
@@ -90,7 +90,7 @@ pub fn jsonrpc_response_with_session(
         builder = builder.header("Mcp-Session-Id", session_id);
     }
 
-    Ok(builder.body(Full::new(Bytes::from(body_bytes))).unwrap())
+    Ok(builder.body(Full::new(body_bytes)).unwrap())
 }
 
 /// Build HTTP response for method not allowed (405).
@@ -129,10 +129,31 @@ pub fn bad_request_response(message: &str) -> Response<JsonRpcBody> {
         .unwrap()
 }
 
+/// Build HTTP response for a request body over the configured limit (413).
+///
+/// The body is a JSON-RPC invalid-request error whose `data.maxBytes` carries the limit.
+pub fn payload_too_large_response(max_bytes: usize) -> Response<JsonRpcBody> {
+    let error_obj = JsonRpcErrorObject {
+        code: -32600, // Invalid Request
+        message: format!("Request body exceeds {} bytes", max_bytes),
+        data: Some(serde_json::json!({ "maxBytes": max_bytes })),
+    };
+
+    let err = JsonRpcError::new(None, error_obj);
+
+    let body_bytes = serde_json::to_vec(&err).unwrap_or_else(|_| b"{}".to_vec());
+
+    Response::builder()
+        .status(StatusCode::PAYLOAD_TOO_LARGE)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(body_bytes)))
+        .unwrap()
+}
+
 /// Build HTTP response for OPTIONS preflight requests.
 ///
 /// Returns a bare 200 OK with an empty body. CORS headers are added by
-/// [`CorsLayer::apply_cors_headers`] in `server.rs` when `enable_cors` is true.
+/// [`CorsLayer::apply`] in `server.rs` when `enable_cors` is true.
 pub fn options_response() -> Response<JsonRpcBody> {
     Response::builder()
         .status(StatusCode::OK)
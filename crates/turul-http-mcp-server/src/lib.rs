@@ -46,42 +46,76 @@
 //! }
 //! ```
 
+pub mod auth;
+pub mod body_limit;
+pub mod compression;
 pub mod cors;
 pub mod handler;
+pub mod health;
+pub mod idempotency;
+pub mod interceptor;
+pub mod ip_limits;
 pub mod json_rpc_responses;
 pub mod mcp_session;
 pub mod middleware;
 pub mod notification_bridge;
+#[cfg(feature = "otel")]
+pub mod otel;
 pub mod prelude;
 pub mod protocol;
+pub(crate) mod response_buffer;
 pub mod routes;
 pub mod server;
 pub mod session_handler;
 pub mod sse;
 pub mod stream_manager;
 pub mod streamable_http;
+#[cfg(feature = "tls")]
+pub mod tls;
+pub mod wire_log;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export main types
-pub use cors::CorsLayer;
+/// Per-request authentication hook and the resulting caller identity
+pub use auth::{AuthError, Authenticator, AuthenticatorMiddleware, IDENTITY_EXTENSION, Identity};
+pub use body_limit::DEFAULT_MAX_REQUEST_BODY_BYTES;
+pub use compression::{ContentEncoding, DEFAULT_COMPRESSION_THRESHOLD};
+pub use cors::{AllowedOrigins, CorsConfig, CorsLayer};
+pub use health::{DEFAULT_LIVENESS_PATH, DEFAULT_READINESS_PATH, READINESS_TIMEOUT};
+pub use idempotency::{IDEMPOTENCY_KEY_EXTENSION, IDEMPOTENCY_KEY_HEADER};
+pub use interceptor::RequestInterceptor;
+/// Per-client-IP connection, session, and in-flight request limits
+pub use ip_limits::{IpLimitConfig, IpLimiter, LimitRejection};
 // McpHttpHandler removed in 0.2.0 - use SessionMcpHandler instead
 pub use notification_bridge::{
     BroadcastError, NotificationBroadcaster, SharedNotificationBroadcaster,
     StreamManagerNotificationBroadcaster,
 };
+/// OpenTelemetry span per JSON-RPC request
+#[cfg(feature = "otel")]
+pub use otel::{HeaderTraceContext, MetaTraceContext, RequestTracing, TraceContextExtractor};
 pub use protocol::{
     McpProtocolVersion, extract_last_event_id, extract_protocol_version, extract_session_id,
 };
 pub use routes::{RouteBody, RouteHandler, RouteRegistry, RouteValidationError};
 /// HTTP MCP server implementation with builder pattern and configuration
-pub use server::{HttpMcpServer, HttpMcpServerBuilder, ServerConfig, ServerStats};
+pub use server::{
+    DEFAULT_SHUTDOWN_GRACE_PERIOD, HttpMcpServer, HttpMcpServerBuilder, McpService, ServerConfig,
+    ServerStats,
+};
 /// Session-aware request handler with SSE streaming capabilities
 pub use session_handler::{SessionMcpHandler, SessionSseStream};
 /// SSE stream management with connection tracking and event delivery
-pub use stream_manager::{StreamConfig, StreamError, StreamManager, StreamStats};
+pub use stream_manager::{
+    FRAGMENT_META_KEY, OversizedEventPolicy, StreamConfig, StreamError, StreamManager, StreamStats,
+    reassemble_fragments,
+};
 pub use streamable_http::{StreamableHttpContext, StreamableHttpHandler};
+/// In-process TLS termination with rustls
+#[cfg(feature = "tls")]
+pub use tls::TlsConfig;
 
 /// Awaitable notifier for tool change events (restart/redeploy fingerprint mismatch).
 /// Implemented by the server layer (backed by SessionManager → dispatcher).
@@ -1,6 +1,9 @@
 //! Middleware stack execution
 
+use super::bearer::{extract_bearer_token, is_bearer_scheme};
 use super::{DispatcherResult, McpMiddleware, MiddlewareError, RequestContext, SessionInjection};
+use hyper::HeaderMap;
+use hyper::header::AUTHORIZATION;
 use std::sync::Arc;
 use turul_mcp_session_storage::SessionView;
 
@@ -71,6 +74,15 @@ impl MiddlewareStack {
         self.middleware.push(middleware);
     }
 
+    /// Add middleware to the front of the stack, ahead of everything already pushed
+    ///
+    /// Used for middleware that must see every request first, such as the
+    /// [`AuthenticatorMiddleware`](crate::AuthenticatorMiddleware) installed by
+    /// [`HttpMcpServerBuilder::authenticator`](crate::HttpMcpServerBuilder::authenticator).
+    pub fn push_front(&mut self, middleware: Arc<dyn McpMiddleware>) {
+        self.middleware.insert(0, middleware);
+    }
+
     /// Get the number of middleware in the stack
     pub fn len(&self) -> usize {
         self.middleware.len()
@@ -86,6 +98,48 @@ impl MiddlewareStack {
         self.middleware.iter().any(|m| m.runs_before_session())
     }
 
+    /// Run the middleware that guard requests without a JSON-RPC message
+    ///
+    /// Called by the transport for GET (SSE) and DELETE requests. Only pre-session
+    /// middleware with [`McpMiddleware::guards_all_http_methods`] run; `method` is
+    /// the HTTP method and the headers reach them the same way as for a POST, with
+    /// the bearer token taken from the hardened parser.
+    pub async fn execute_http_guards(
+        &self,
+        method: &str,
+        headers: &HeaderMap,
+    ) -> Result<(), MiddlewareError> {
+        let guards: Vec<&Arc<dyn McpMiddleware>> = self
+            .middleware
+            .iter()
+            .filter(|m| m.runs_before_session() && m.guards_all_http_methods())
+            .collect();
+        if guards.is_empty() {
+            return Ok(());
+        }
+
+        let mut ctx = RequestContext::new(method, None);
+        for (name, value) in headers {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+            if name == AUTHORIZATION && is_bearer_scheme(value) {
+                if let Some(token) = extract_bearer_token(value) {
+                    ctx.set_bearer_token(token);
+                }
+                continue;
+            }
+            ctx.add_metadata(name.as_str(), serde_json::json!(value));
+        }
+
+        for middleware in guards {
+            middleware
+                .before_dispatch(&mut ctx, None, &mut SessionInjection::new())
+                .await?;
+        }
+        Ok(())
+    }
+
     /// Execute only pre-session middleware (those with `runs_before_session() == true`)
     ///
     /// Called by the transport layer before session lookup/creation.
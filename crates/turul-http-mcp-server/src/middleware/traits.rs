@@ -21,6 +21,18 @@ use turul_mcp_session_storage::SessionView;
 ///    - Can modify the response
 ///    - Can log, audit, or transform results
 ///
+/// # Ordering and Short-Circuiting
+///
+/// With several middleware registered, `before_dispatch` runs for pre-session
+/// middleware (see [`runs_before_session`](McpMiddleware::runs_before_session))
+/// first, then for the rest in registration order. The first `Err` stops the
+/// request: later middleware are skipped, the method handler is never invoked,
+/// no `after_dispatch` runs, and the error is returned to the client as a
+/// JSON-RPC error response (see [`MiddlewareError`] for the code mapping).
+///
+/// When every `before_dispatch` succeeds, `after_dispatch` runs in reverse
+/// registration order once the handler has produced a result.
+///
 /// # Transport Agnostic
 ///
 /// Middleware works across all transports (HTTP, Lambda) via normalized `RequestContext`.
@@ -68,6 +80,40 @@ use turul_mcp_session_storage::SessionView;
 ///     }
 /// }
 /// ```
+///
+/// Method-level authorization, rejecting `tools/call` for sessions that were
+/// not marked as authorized while leaving every other method untouched:
+///
+/// ```rust,no_run
+/// use turul_http_mcp_server::middleware::{McpMiddleware, RequestContext, SessionInjection, MiddlewareError};
+/// use turul_mcp_session_storage::SessionView;
+/// use async_trait::async_trait;
+///
+/// struct ToolCallGuard;
+///
+/// #[async_trait]
+/// impl McpMiddleware for ToolCallGuard {
+///     async fn before_dispatch(
+///         &self,
+///         ctx: &mut RequestContext<'_>,
+///         session: Option<&dyn SessionView>,
+///         _injection: &mut SessionInjection,
+///     ) -> Result<(), MiddlewareError> {
+///         if ctx.method() != "tools/call" {
+///             return Ok(());
+///         }
+///         let authorized = match session {
+///             Some(session) => session.get_state("authorized").await.ok().flatten(),
+///             None => None,
+///         };
+///         if authorized != Some(serde_json::json!(true)) {
+///             // The tool is never called; the client receives error -32002
+///             return Err(MiddlewareError::unauthorized("tools/call not permitted"));
+///         }
+///         Ok(())
+///     }
+/// }
+/// ```
 #[async_trait]
 pub trait McpMiddleware: Send + Sync {
     /// Whether this middleware runs before session creation (pre-session phase)
@@ -85,6 +131,19 @@ pub trait McpMiddleware: Send + Sync {
         false
     }
 
+    /// Whether this pre-session middleware also guards GET (SSE) and DELETE requests
+    ///
+    /// Those requests carry no JSON-RPC message. When this returns `true`, the
+    /// transport runs `before_dispatch()` for them with the HTTP method as the
+    /// method name and `session: None`; any error rejects the request, with
+    /// [`MiddlewareError::HttpChallenge`] sent as-is. Ignored unless
+    /// [`runs_before_session`](Self::runs_before_session) is also `true`.
+    ///
+    /// Default: `false` (only POSTed JSON-RPC messages are seen)
+    fn guards_all_http_methods(&self) -> bool {
+        false
+    }
+
     /// Called before the MCP method handler executes
     ///
     /// # Parameters
@@ -103,7 +162,7 @@ pub trait McpMiddleware: Send + Sync {
     /// # Notes
     ///
     /// - Middleware executes in registration order
-    /// - First error stops the chain
+    /// - First error stops the chain; the handler and `after_dispatch` are skipped
     /// - Session injection is applied after all middleware succeed
     /// - For `initialize`, session is `None` but middleware can still validate headers/rate-limit
     async fn before_dispatch(
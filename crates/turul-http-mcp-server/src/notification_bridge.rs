@@ -100,6 +100,25 @@ pub trait NotificationBroadcaster: Send + Sync {
         session_id: &str,
         notification: JsonRpcNotification,
     ) -> Result<(), BroadcastError>;
+
+    // ================== SERVER-TO-CLIENT REQUESTS ==================
+
+    /// Send a JSON-RPC request (e.g. `sampling/createMessage`) to the client and wait
+    /// for its response message, which carries either `result` or `error`
+    ///
+    /// Broadcasters that cannot carry requests keep the default, which fails.
+    async fn request_client(
+        &self,
+        _session_id: &str,
+        method: &str,
+        _params: serde_json::Value,
+        _timeout: std::time::Duration,
+    ) -> Result<serde_json::Value, BroadcastError> {
+        Err(BroadcastError::BroadcastFailed(format!(
+            "{} requests are not supported by this transport",
+            method
+        )))
+    }
 }
 
 /// Errors that can occur during notification broadcasting
@@ -378,23 +397,37 @@ impl NotificationBroadcaster for StreamManagerNotificationBroadcaster {
         let sse_data =
             serde_json::to_value(&notification).map_err(BroadcastError::SerializationError)?;
 
-        // Send via StreamManager with proper JSON-RPC format
-        match self
-            .stream_manager
-            .broadcast_to_session(
-                session_id,
-                notification.method.clone(), // Use actual MCP method name as event type
-                sse_data,
-            )
-            .await
-        {
-            Ok(event_id) => {
+        // Send via StreamManager with proper JSON-RPC format; resource updates may be coalesced
+        let result = if notification.method == "notifications/resources/updated" {
+            self.stream_manager
+                .broadcast_resource_updated(session_id, sse_data)
+                .await
+        } else {
+            self.stream_manager
+                .broadcast_to_session(
+                    session_id,
+                    notification.method.clone(), // Use actual MCP method name as event type
+                    sse_data,
+                )
+                .await
+                .map(Some)
+        };
+
+        match result {
+            Ok(Some(event_id)) => {
                 debug!(
                     "✅ Sent JSON-RPC notification: session={}, method={}, event_id={}",
                     session_id, notification.method, event_id
                 );
                 Ok(())
             }
+            Ok(None) => {
+                debug!(
+                    "Coalesced JSON-RPC notification: session={}, method={}",
+                    session_id, notification.method
+                );
+                Ok(())
+            }
             Err(e) => {
                 error!(
                     "❌ Failed to send JSON-RPC notification: session={}, method={}, error={}",
@@ -404,6 +437,21 @@ impl NotificationBroadcaster for StreamManagerNotificationBroadcaster {
             }
         }
     }
+
+    // ================== SERVER-TO-CLIENT REQUESTS ==================
+
+    async fn request_client(
+        &self,
+        session_id: &str,
+        method: &str,
+        params: serde_json::Value,
+        timeout: std::time::Duration,
+    ) -> Result<serde_json::Value, BroadcastError> {
+        self.stream_manager
+            .request_client(session_id, method, params, timeout)
+            .await
+            .map_err(|e| BroadcastError::BroadcastFailed(e.to_string()))
+    }
 }
 
 /// Shared NotificationBroadcaster type alias for use across the turul-http-mcp-server crate
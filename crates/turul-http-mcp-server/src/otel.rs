@@ -0,0 +1,241 @@
+//! OpenTelemetry spans for JSON-RPC requests (`otel` feature)
+//!
+//! Install [`RequestTracing`] with
+//! [`HttpMcpServerBuilder::request_tracing`](crate::HttpMcpServerBuilder::request_tracing)
+//! and every JSON-RPC request gets one server span, named after its method, around
+//! the session-aware middleware and dispatch. Notifications are not traced.
+//!
+//! # Attributes
+//!
+//! - `rpc.system` = `"jsonrpc"`
+//! - `rpc.method` — the JSON-RPC method
+//! - `mcp.session.id` — when the request belongs to a session
+//! - `mcp.tool.name` — the called tool, for `tools/call`
+//! - `rpc.jsonrpc.error_code` — on error responses
+//!
+//! The span status is `Ok` for results and `Error` (with the error message) for
+//! JSON-RPC errors. Its parent comes from a [`TraceContextExtractor`]: by default
+//! the W3C `traceparent`/`tracestate` HTTP headers ([`HeaderTraceContext`]), or
+//! `params._meta` first with [`MetaTraceContext`].
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use opentelemetry::global::BoxedTracer;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry::trace::{SpanKind, Status, TraceContextExt, Tracer};
+use opentelemetry::{Context, KeyValue};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use turul_mcp_json_rpc_server::{JsonRpcMessage, JsonRpcRequest, RequestParams};
+
+/// Finds the remote parent of a request's span
+pub trait TraceContextExtractor: Send + Sync {
+    /// Build the parent context from the request's HTTP headers and JSON-RPC body
+    fn extract(&self, headers: &HashMap<String, String>, request: &JsonRpcRequest) -> Context;
+}
+
+/// Reads W3C trace context from the `traceparent` and `tracestate` HTTP headers
+#[derive(Debug, Clone, Default)]
+pub struct HeaderTraceContext;
+
+impl TraceContextExtractor for HeaderTraceContext {
+    fn extract(&self, headers: &HashMap<String, String>, _request: &JsonRpcRequest) -> Context {
+        TraceContextPropagator::new().extract(&HeaderCarrier(headers))
+    }
+}
+
+/// Reads W3C trace context from `params._meta.traceparent`, falling back to the
+/// HTTP headers when the request carries none
+#[derive(Debug, Clone, Default)]
+pub struct MetaTraceContext;
+
+impl TraceContextExtractor for MetaTraceContext {
+    fn extract(&self, headers: &HashMap<String, String>, request: &JsonRpcRequest) -> Context {
+        let meta = MetaCarrier(meta_object(request));
+        if meta.get("traceparent").is_some() {
+            TraceContextPropagator::new().extract(&meta)
+        } else {
+            HeaderTraceContext.extract(headers, request)
+        }
+    }
+}
+
+/// Tracer and parent extraction used to trace JSON-RPC requests
+#[derive(Clone)]
+pub struct RequestTracing {
+    tracer: Arc<BoxedTracer>,
+    extractor: Arc<dyn TraceContextExtractor>,
+}
+
+impl RequestTracing {
+    /// Trace with `tracer`, taking parents from the HTTP headers
+    pub fn new<T>(tracer: T) -> Self
+    where
+        T: Tracer + Send + Sync + 'static,
+        T::Span: Send + Sync + 'static,
+    {
+        Self {
+            tracer: Arc::new(BoxedTracer::new(Box::new(tracer))),
+            extractor: Arc::new(HeaderTraceContext),
+        }
+    }
+
+    /// Trace with the globally installed tracer provider
+    pub fn global() -> Self {
+        Self {
+            tracer: Arc::new(opentelemetry::global::tracer(env!("CARGO_PKG_NAME"))),
+            extractor: Arc::new(HeaderTraceContext),
+        }
+    }
+
+    /// Replace where parent trace context is read from
+    pub fn with_extractor(mut self, extractor: impl TraceContextExtractor + 'static) -> Self {
+        self.extractor = Arc::new(extractor);
+        self
+    }
+
+    /// Start the span for `request`, returning the context to run its dispatch in
+    pub(crate) fn start(
+        &self,
+        request: &JsonRpcRequest,
+        headers: &HashMap<String, String>,
+        session_id: Option<&str>,
+    ) -> Context {
+        let parent = self.extractor.extract(headers, request);
+
+        let mut attributes = vec![
+            KeyValue::new("rpc.system", "jsonrpc"),
+            KeyValue::new("rpc.method", request.method.clone()),
+        ];
+        if let Some(session_id) = session_id {
+            attributes.push(KeyValue::new("mcp.session.id", session_id.to_string()));
+        }
+        if request.method == "tools/call"
+            && let Some(RequestParams::Object(params)) = &request.params
+            && let Some(name) = params.get("name").and_then(|name| name.as_str())
+        {
+            attributes.push(KeyValue::new("mcp.tool.name", name.to_string()));
+        }
+
+        let span = self
+            .tracer
+            .span_builder(request.method.clone())
+            .with_kind(SpanKind::Server)
+            .with_attributes(attributes)
+            .start_with_context(self.tracer.as_ref(), &parent);
+        parent.with_span(span)
+    }
+
+    /// Record the outcome on the span started by [`start`](Self::start) and end it
+    ///
+    /// `None` is a request cancelled by the client; its span ends with no status.
+    pub(crate) fn finish(&self, cx: &Context, response: Option<&JsonRpcMessage>) {
+        let span = cx.span();
+        match response {
+            Some(JsonRpcMessage::Response(_)) => span.set_status(Status::Ok),
+            Some(JsonRpcMessage::Error(err)) => {
+                span.set_attribute(KeyValue::new("rpc.jsonrpc.error_code", err.error.code));
+                span.set_status(Status::error(err.error.message.clone()));
+            }
+            None => {}
+        }
+        span.end();
+    }
+}
+
+impl std::fmt::Debug for RequestTracing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestTracing").finish_non_exhaustive()
+    }
+}
+
+/// HTTP headers as a propagation carrier; header names match case-insensitively
+struct HeaderCarrier<'a>(&'a HashMap<String, String>);
+
+impl Extractor for HeaderCarrier<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(String::as_str).collect()
+    }
+}
+
+/// String fields of `params._meta` as a propagation carrier
+struct MetaCarrier<'a>(Option<&'a serde_json::Map<String, serde_json::Value>>);
+
+impl Extractor for MetaCarrier<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0?.get(key)?.as_str()
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0
+            .map(|meta| meta.keys().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+}
+
+fn meta_object(request: &JsonRpcRequest) -> Option<&serde_json::Map<String, serde_json::Value>> {
+    match &request.params {
+        Some(RequestParams::Object(params)) => params.get("_meta")?.as_object(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::TraceId;
+    use serde_json::json;
+    use turul_mcp_json_rpc_server::RequestId;
+
+    const PARENT_TRACE: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+
+    fn request(params: serde_json::Value) -> JsonRpcRequest {
+        JsonRpcRequest::new(
+            RequestId::Number(1),
+            "tools/call".to_string(),
+            serde_json::from_value(params).ok(),
+        )
+    }
+
+    fn trace_id(cx: &Context) -> TraceId {
+        cx.span().span_context().trace_id()
+    }
+
+    #[test]
+    fn test_header_extractor_reads_traceparent_case_insensitively() {
+        let headers = HashMap::from([(
+            "TraceParent".to_string(),
+            format!("00-{PARENT_TRACE}-00f067aa0ba902b7-01"),
+        )]);
+
+        let cx = HeaderTraceContext.extract(&headers, &request(json!({})));
+
+        assert_eq!(trace_id(&cx), TraceId::from_hex(PARENT_TRACE).unwrap());
+    }
+
+    #[test]
+    fn test_meta_extractor_prefers_meta_then_headers() {
+        let meta_trace = "0af7651916cd43dd8448eb211c80319c";
+        let headers = HashMap::from([(
+            "traceparent".to_string(),
+            format!("00-{PARENT_TRACE}-00f067aa0ba902b7-01"),
+        )]);
+        let with_meta = request(json!({
+            "name": "echo",
+            "_meta": { "traceparent": format!("00-{meta_trace}-b7ad6b7169203331-01") }
+        }));
+
+        let cx = MetaTraceContext.extract(&headers, &with_meta);
+        assert_eq!(trace_id(&cx), TraceId::from_hex(meta_trace).unwrap());
+
+        let cx = MetaTraceContext.extract(&headers, &request(json!({ "name": "echo" })));
+        assert_eq!(trace_id(&cx), TraceId::from_hex(PARENT_TRACE).unwrap());
+    }
+}
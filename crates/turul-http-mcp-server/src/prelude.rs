@@ -11,7 +11,9 @@
 pub use crate::cors::CorsLayer;
 pub use crate::server::{HttpMcpServer, HttpMcpServerBuilder, ServerConfig, ServerStats};
 pub use crate::session_handler::{SessionMcpHandler, SessionSseStream};
-pub use crate::stream_manager::{StreamConfig, StreamError, StreamManager, StreamStats};
+pub use crate::stream_manager::{
+    OversizedEventPolicy, StreamConfig, StreamError, StreamManager, StreamStats,
+};
 
 // Protocol and notification types
 pub use crate::notification_bridge::{
@@ -0,0 +1,149 @@
+//! Pooled buffers for serializing JSON-RPC responses
+//!
+//! `serde_json::to_vec` starts every response from an empty `Vec` and reallocates
+//! as it grows, so a large `tools/call` result costs several allocations per
+//! response. [`serialize_response`] instead writes into a [`BytesMut`] taken from a
+//! small process-wide pool and hands out the written bytes as a frozen [`Bytes`]
+//! sharing the buffer's allocation. The emptied buffer goes straight back to the
+//! pool and later responses are written into the rest of that allocation; once
+//! hyper has written a response and dropped its `Bytes`, the space it used is
+//! reclaimed as well, so a warm pool serializes without allocating.
+//!
+//! A buffer is always cleared before it is written and the `Bytes` handed out
+//! covers only what was just written, so nothing from an earlier response can
+//! appear in a later one. While an earlier response is still in flight its
+//! allocation is left alone and the next one simply allocates.
+
+use std::sync::Mutex;
+
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::Serialize;
+
+/// Capacity reserved before serializing into a pooled buffer
+const INITIAL_CAPACITY: usize = 1024;
+
+/// Buffers kept by [`RESPONSE_BUFFERS`]
+const MAX_POOLED_BUFFERS: usize = 64;
+
+/// Responses larger than this (1 MiB) do not return their buffer to [`RESPONSE_BUFFERS`]
+const MAX_POOLED_CAPACITY: usize = 1024 * 1024;
+
+/// Pool used by the HTTP response paths
+pub(crate) static RESPONSE_BUFFERS: BufferPool =
+    BufferPool::new(MAX_POOLED_BUFFERS, MAX_POOLED_CAPACITY);
+
+/// Serialize `value` as JSON using a buffer from [`RESPONSE_BUFFERS`]
+pub(crate) fn serialize_response<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<Bytes> {
+    RESPONSE_BUFFERS.serialize(value)
+}
+
+/// A bounded pool of reusable serialization buffers
+pub(crate) struct BufferPool {
+    buffers: Mutex<Vec<BytesMut>>,
+    max_buffers: usize,
+    max_capacity: usize,
+}
+
+impl BufferPool {
+    /// Pool keeping at most `max_buffers` buffers; a buffer that held more than
+    /// `max_capacity` bytes is dropped instead of pooled
+    pub(crate) const fn new(max_buffers: usize, max_capacity: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::new()),
+            max_buffers,
+            max_capacity,
+        }
+    }
+
+    /// Serialize `value` as JSON into a pooled buffer
+    pub(crate) fn serialize<T: Serialize + ?Sized>(&self, value: &T) -> serde_json::Result<Bytes> {
+        let mut buffer = self.take();
+        let result = serde_json::to_writer((&mut buffer).writer(), value);
+        let written = buffer.split().freeze();
+        if written.len() <= self.max_capacity {
+            self.release(buffer);
+        }
+        result.map(|()| written)
+    }
+
+    /// Number of idle buffers in the pool
+    #[cfg(test)]
+    fn idle(&self) -> usize {
+        self.buffers.lock().unwrap().len()
+    }
+
+    fn take(&self) -> BytesMut {
+        let mut buffer = self.buffers.lock().unwrap().pop().unwrap_or_default();
+        buffer.clear();
+        // Reclaims the start of the allocation once earlier responses are dropped
+        buffer.reserve(INITIAL_CAPACITY);
+        buffer
+    }
+
+    fn release(&self, mut buffer: BytesMut) {
+        buffer.clear();
+        let mut buffers = self.buffers.lock().unwrap();
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use turul_mcp_json_rpc_server::{JsonRpcResponse, RequestId};
+    use turul_mcp_protocol::tools::{CallToolResult, ToolResult};
+
+    fn tool_response(id: i64, text: &str) -> JsonRpcResponse {
+        let result = CallToolResult::success(vec![ToolResult::text(text)]);
+        JsonRpcResponse::success(RequestId::Number(id), serde_json::to_value(result).unwrap())
+    }
+
+    #[test]
+    fn test_reused_buffer_does_not_leak_previous_response() {
+        let pool = BufferPool::new(1, MAX_POOLED_CAPACITY);
+
+        let large = tool_response(1, &"secret-".repeat(512));
+        let first = pool.serialize(&large).unwrap();
+        assert_eq!(first, serde_json::to_vec(&large).unwrap());
+        drop(first);
+
+        // The next response reuses the same allocation but only sees its own bytes
+        let small = tool_response(2, "ok");
+        let second = pool.serialize(&small).unwrap();
+        assert_eq!(second, serde_json::to_vec(&small).unwrap());
+        assert!(!String::from_utf8_lossy(&second).contains("secret"));
+        assert_eq!(pool.idle(), 1);
+    }
+
+    #[test]
+    fn test_in_flight_response_is_never_overwritten() {
+        let pool = BufferPool::new(1, MAX_POOLED_CAPACITY);
+
+        let first = pool.serialize(&tool_response(1, "first")).unwrap();
+        let second = pool.serialize(&tool_response(2, "second")).unwrap();
+        assert_ne!(first.as_ptr(), second.as_ptr());
+        assert_eq!(
+            first,
+            serde_json::to_vec(&tool_response(1, "first")).unwrap()
+        );
+        assert_eq!(
+            second,
+            serde_json::to_vec(&tool_response(2, "second")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pool_is_bounded() {
+        let pool = BufferPool::new(1, 16);
+
+        // Larger than max_capacity: the buffer is dropped, not pooled
+        pool.serialize(&json!({ "text": "x".repeat(64) })).unwrap();
+        assert_eq!(pool.idle(), 0);
+
+        pool.serialize(&json!(1)).unwrap();
+        assert_eq!(pool.idle(), 1);
+    }
+}
@@ -8,9 +8,13 @@ use http_body_util::{BodyExt, Full};
 use hyper::server::conn::http1;
 use hyper::service::service_fn;
 use hyper::{Request, Response};
+#[cfg(feature = "tls")]
+use hyper_util::rt::TokioExecutor;
 use hyper_util::rt::TokioIo;
+use hyper_util::server::graceful::GracefulShutdown;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
 use tracing::{debug, error, info, warn};
 
@@ -19,7 +23,14 @@ use turul_mcp_protocol::McpError;
 use turul_mcp_session_storage::InMemorySessionStorage;
 
 use crate::streamable_http::{McpProtocolVersion, StreamableHttpHandler};
-use crate::{CorsLayer, Result, SessionMcpHandler, StreamConfig, StreamManager};
+use crate::{
+    ContentEncoding, CorsConfig, CorsLayer, DEFAULT_COMPRESSION_THRESHOLD,
+    DEFAULT_MAX_REQUEST_BODY_BYTES, OversizedEventPolicy, Result, SessionMcpHandler, StreamConfig,
+    StreamManager,
+};
+
+/// Default time allowed for in-flight requests to finish on shutdown
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(30);
 
 /// Configuration for the HTTP MCP server
 #[derive(Debug, Clone)]
@@ -30,7 +41,11 @@ pub struct ServerConfig {
     pub mcp_path: String,
     /// Enable CORS
     pub enable_cors: bool,
-    /// Maximum request body size
+    /// CORS policy applied when `enable_cors` is true (default: allow all origins)
+    pub cors: CorsConfig,
+    /// Largest POST body, in bytes, that will be read (default: 1 MiB)
+    ///
+    /// Larger requests get HTTP 413 with a JSON-RPC error; the excess is never buffered.
     pub max_body_size: usize,
     /// Enable GET SSE support (persistent event streams)
     pub enable_get_sse: bool,
@@ -46,6 +61,19 @@ pub struct ServerConfig {
     ///
     /// Set to false for hardened deployments that require session for all methods.
     pub allow_unauthenticated_ping: bool,
+    /// Minimum JSON response size, in bytes, before it is compressed (default: 1 KiB)
+    pub compression_threshold: usize,
+    /// Encodings offered to clients via `Accept-Encoding`, in order of preference
+    /// (default: gzip, then deflate); empty disables compression
+    ///
+    /// Only buffered JSON responses are compressed; SSE streams never are.
+    pub compression_algorithms: Vec<ContentEncoding>,
+    /// Path answering liveness probes with `200 {"status":"ok"}` (default: off)
+    pub liveness_path: Option<String>,
+    /// Path answering readiness probes, which ping the session storage (default: off)
+    ///
+    /// See [`crate::health`] for the response bodies.
+    pub readiness_path: Option<String>,
 }
 
 impl Default for ServerConfig {
@@ -54,11 +82,16 @@ impl Default for ServerConfig {
             bind_address: "127.0.0.1:8000".parse().unwrap(),
             mcp_path: "/mcp".to_string(),
             enable_cors: true,
-            max_body_size: 1024 * 1024,            // 1MB
+            cors: CorsConfig::default(),
+            max_body_size: DEFAULT_MAX_REQUEST_BODY_BYTES,
             enable_get_sse: cfg!(feature = "sse"), // GET SSE enabled if "sse" feature is compiled
             enable_post_sse: false, // Disabled by default for better client compatibility (e.g., MCP Inspector)
             session_expiry_minutes: 30, // 30 minutes default
             allow_unauthenticated_ping: true, // Allow pre-init pings per MCP spec
+            compression_threshold: DEFAULT_COMPRESSION_THRESHOLD,
+            compression_algorithms: vec![ContentEncoding::Gzip, ContentEncoding::Deflate],
+            liveness_path: None,
+            readiness_path: None,
         }
     }
 }
@@ -74,6 +107,16 @@ pub struct HttpMcpServerBuilder {
     route_registry: Arc<crate::routes::RouteRegistry>,
     tool_fingerprint: Option<String>,
     tool_notifier: Option<Arc<dyn crate::ToolChangeNotifier>>,
+    request_interceptor: Option<crate::RequestInterceptor>,
+    authenticator: Option<Arc<dyn crate::Authenticator>>,
+    ip_limits: Option<crate::IpLimitConfig>,
+    wire_log_level: Option<tracing::Level>,
+    wire_redact_fields: Vec<String>,
+    shutdown_grace_period: Duration,
+    #[cfg(feature = "otel")]
+    request_tracing: Option<crate::otel::RequestTracing>,
+    #[cfg(feature = "tls")]
+    tls: Option<crate::TlsConfig>,
 }
 
 impl HttpMcpServerBuilder {
@@ -89,6 +132,16 @@ impl HttpMcpServerBuilder {
             route_registry: Arc::new(crate::routes::RouteRegistry::new()),
             tool_fingerprint: None,
             tool_notifier: None,
+            request_interceptor: None,
+            authenticator: None,
+            ip_limits: None,
+            wire_log_level: None,
+            wire_redact_fields: crate::wire_log::default_redacted_fields(),
+            shutdown_grace_period: DEFAULT_SHUTDOWN_GRACE_PERIOD,
+            #[cfg(feature = "otel")]
+            request_tracing: None,
+            #[cfg(feature = "tls")]
+            tls: None,
         }
     }
 }
@@ -108,6 +161,16 @@ impl HttpMcpServerBuilder {
             route_registry: Arc::new(crate::routes::RouteRegistry::new()),
             tool_fingerprint: None,
             tool_notifier: None,
+            request_interceptor: None,
+            authenticator: None,
+            ip_limits: None,
+            wire_log_level: None,
+            wire_redact_fields: crate::wire_log::default_redacted_fields(),
+            shutdown_grace_period: DEFAULT_SHUTDOWN_GRACE_PERIOD,
+            #[cfg(feature = "otel")]
+            request_tracing: None,
+            #[cfg(feature = "tls")]
+            tls: None,
         }
     }
 
@@ -142,6 +205,105 @@ impl HttpMcpServerBuilder {
         self
     }
 
+    /// Install a global interceptor run for every parsed JSON-RPC request
+    ///
+    /// The interceptor may mutate the request (e.g. inject a default `_meta`) or
+    /// reject it by returning an `McpError`, which is sent back as the JSON-RPC
+    /// error response. It runs after pre-session authentication and session
+    /// validation, and before the session-aware middleware stack and dispatch.
+    /// See [`crate::interceptor`] for the full ordering.
+    pub fn request_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(&mut turul_mcp_json_rpc_server::JsonRpcRequest) -> std::result::Result<(), McpError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.request_interceptor = Some(Arc::new(interceptor));
+        self
+    }
+
+    /// Authenticate every MCP request (POST, GET and DELETE) before session lookup and middleware
+    ///
+    /// Rejected requests get HTTP 401 with a JSON-RPC error; accepted ones carry the
+    /// [`Identity`](crate::Identity) to tools. See [`crate::auth`].
+    pub fn authenticator(mut self, authenticator: impl crate::Authenticator + 'static) -> Self {
+        self.authenticator = Some(Arc::new(authenticator));
+        self
+    }
+
+    /// Enforce per-client-IP connection, session, and in-flight request limits
+    ///
+    /// See [`crate::ip_limits`] for how limits are applied and how the client IP is
+    /// resolved behind trusted proxies.
+    pub fn ip_limits(mut self, config: crate::IpLimitConfig) -> Self {
+        self.ip_limits = Some(config);
+        self
+    }
+
+    /// Log every inbound and outbound JSON-RPC message as a tracing event at `level`
+    ///
+    /// Covers POST bodies, JSON responses, and SSE payloads; fields named by
+    /// [`redact_wire_fields`](Self::redact_wire_fields) are masked. See [`crate::wire_log`].
+    pub fn log_wire_json(mut self, level: tracing::Level) -> Self {
+        self.wire_log_level = Some(level);
+        self
+    }
+
+    /// Replace the object field names masked in wire logs
+    /// (default: [`DEFAULT_REDACTED_FIELDS`](crate::wire_log::DEFAULT_REDACTED_FIELDS))
+    pub fn redact_wire_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.wire_redact_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Trace each JSON-RPC request in an OpenTelemetry span
+    ///
+    /// See [`crate::otel`] for the span's attributes and where its parent comes from.
+    #[cfg(feature = "otel")]
+    pub fn request_tracing(mut self, tracing: crate::otel::RequestTracing) -> Self {
+        self.request_tracing = Some(tracing);
+        self
+    }
+
+    /// How long [`HttpMcpServer::run_with_shutdown`] waits for in-flight requests
+    /// to finish before abandoning them (default: 30 seconds)
+    pub fn shutdown_grace_period(mut self, grace: Duration) -> Self {
+        self.shutdown_grace_period = grace;
+        self
+    }
+
+    /// Serve HTTPS with the PEM certificate chain and private key at these paths
+    ///
+    /// Shorthand for [`tls_config`](Self::tls_config) with
+    /// [`TlsConfig::from_pem_files`](crate::TlsConfig::from_pem_files). Without a
+    /// TLS configuration the server speaks plain HTTP.
+    #[cfg(feature = "tls")]
+    pub fn tls(
+        self,
+        cert_path: impl Into<std::path::PathBuf>,
+        key_path: impl Into<std::path::PathBuf>,
+    ) -> Self {
+        self.tls_config(crate::TlsConfig::from_pem_files(cert_path, key_path))
+    }
+
+    /// Serve HTTPS with a prebuilt rustls configuration
+    #[cfg(feature = "tls")]
+    pub fn with_rustls_config(self, config: Arc<rustls::ServerConfig>) -> Self {
+        self.tls_config(crate::TlsConfig::from_rustls_config(config))
+    }
+
+    /// Terminate TLS in-process as described by `tls` (see [`crate::tls`])
+    #[cfg(feature = "tls")]
+    pub fn tls_config(mut self, tls: crate::TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
     /// Set the bind address
     pub fn bind_address(mut self, addr: SocketAddr) -> Self {
         self.config.bind_address = addr;
@@ -160,12 +322,23 @@ impl HttpMcpServerBuilder {
         self
     }
 
-    /// Set maximum request body size
+    /// Set the CORS policy applied while CORS is enabled (default: allow all origins)
+    pub fn cors_config(mut self, cors: CorsConfig) -> Self {
+        self.config.cors = cors;
+        self
+    }
+
+    /// Set the largest POST body, in bytes, that will be read
     pub fn max_body_size(mut self, size: usize) -> Self {
         self.config.max_body_size = size;
         self
     }
 
+    /// Set maximum request body size; same as [`max_body_size`](Self::max_body_size)
+    pub fn max_request_body_bytes(self, bytes: usize) -> Self {
+        self.max_body_size(bytes)
+    }
+
     /// Enable or disable GET SSE for persistent event streams
     pub fn get_sse(mut self, enable: bool) -> Self {
         self.config.enable_get_sse = enable;
@@ -200,12 +373,85 @@ impl HttpMcpServerBuilder {
         self
     }
 
+    /// Set the minimum JSON response size, in bytes, that gets compressed
+    pub fn compression_threshold(mut self, bytes: usize) -> Self {
+        self.config.compression_threshold = bytes;
+        self
+    }
+
+    /// Set the response encodings offered to clients, in order of preference
+    ///
+    /// Pass an empty list to disable compression.
+    pub fn compression(mut self, algorithms: Vec<ContentEncoding>) -> Self {
+        self.config.compression_algorithms = algorithms;
+        self
+    }
+
+    /// Serve liveness probes at `path` (e.g. `/healthz`)
+    pub fn liveness_path(mut self, path: impl Into<String>) -> Self {
+        self.config.liveness_path = Some(path.into());
+        self
+    }
+
+    /// Serve readiness probes at `path` (e.g. `/readyz`)
+    ///
+    /// Readiness answers 503 while the session storage ping fails.
+    pub fn readiness_path(mut self, path: impl Into<String>) -> Self {
+        self.config.readiness_path = Some(path.into());
+        self
+    }
+
+    /// Serve liveness at `/healthz` and readiness at `/readyz`
+    pub fn health_checks(self) -> Self {
+        self.liveness_path(crate::health::DEFAULT_LIVENESS_PATH)
+            .readiness_path(crate::health::DEFAULT_READINESS_PATH)
+    }
+
     /// Configure SSE streaming settings
     pub fn stream_config(mut self, config: StreamConfig) -> Self {
         self.stream_config = config;
         self
     }
 
+    /// Coalesce `notifications/resources/updated` bursts per session and URI
+    ///
+    /// Sets [`StreamConfig::resource_update_coalesce_window_ms`]; see
+    /// [`StreamManager::broadcast_resource_updated`] for delivery semantics.
+    pub fn resource_update_coalesce_window_ms(mut self, window_ms: u64) -> Self {
+        self.stream_config.resource_update_coalesce_window_ms = Some(window_ms);
+        self
+    }
+
+    /// Accept POST bodies encoded with `codec` in addition to JSON
+    ///
+    /// A request whose `Content-Type` names the codec (e.g. `application/msgpack`
+    /// for `MessagePackCodec`, with the `msgpack` feature) gets a single response
+    /// in the same codec; everything else stays JSON.
+    pub fn codec(mut self, codec: Arc<dyn turul_mcp_json_rpc_server::JsonRpcCodec>) -> Self {
+        self.dispatcher.register_codec(codec);
+        self
+    }
+
+    /// Limit how many SSE streams one session may hold open at once
+    ///
+    /// Sets [`StreamConfig::max_streams_per_session`]; further GET streams are
+    /// refused with 429 until one closes.
+    pub fn max_streams_per_session(mut self, max: usize) -> Self {
+        self.stream_config.max_streams_per_session = Some(max);
+        self
+    }
+
+    /// Limit the serialized size of a single SSE event
+    ///
+    /// Sets [`StreamConfig::max_event_size_bytes`] and
+    /// [`StreamConfig::oversized_event_policy`]: larger events are rejected or split
+    /// into fragments.
+    pub fn max_event_size(mut self, max_bytes: usize, policy: OversizedEventPolicy) -> Self {
+        self.stream_config.max_event_size_bytes = Some(max_bytes);
+        self.stream_config.oversized_event_policy = policy;
+        self
+    }
+
     /// Register a JSON-RPC handler for specific methods
     pub fn register_handler<H>(mut self, methods: Vec<String>, handler: H) -> Self
     where
@@ -248,8 +494,15 @@ impl HttpMcpServerBuilder {
         // Create shared dispatcher Arc
         let dispatcher = Arc::new(self.dispatcher);
 
-        // Use middleware stack from builder
-        let middleware_stack = self.middleware_stack;
+        // Use middleware stack from builder, with the authenticator (if any) in front
+        let middleware_stack = match self.authenticator {
+            Some(authenticator) => {
+                let mut stack = (*self.middleware_stack).clone();
+                stack.push_front(Arc::new(crate::AuthenticatorMiddleware::new(authenticator)));
+                Arc::new(stack)
+            }
+            None => self.middleware_stack,
+        };
 
         // Create StreamableHttpHandler for MCP 2025-11-25 support
         let mut streamable_handler = StreamableHttpHandler::new(
@@ -264,6 +517,14 @@ impl HttpMcpServerBuilder {
         if let Some(ref notifier) = self.tool_notifier {
             streamable_handler = streamable_handler.with_tool_notifier(Arc::clone(notifier));
         }
+        if let Some(ref interceptor) = self.request_interceptor {
+            streamable_handler =
+                streamable_handler.with_request_interceptor(Arc::clone(interceptor));
+        }
+        #[cfg(feature = "otel")]
+        if let Some(ref tracing) = self.request_tracing {
+            streamable_handler = streamable_handler.with_request_tracing(tracing.clone());
+        }
 
         HttpMcpServer {
             config: self.config,
@@ -275,6 +536,18 @@ impl HttpMcpServerBuilder {
             route_registry: self.route_registry,
             tool_fingerprint: self.tool_fingerprint,
             tool_notifier: self.tool_notifier,
+            request_interceptor: self.request_interceptor,
+            ip_limiter: self
+                .ip_limits
+                .map(|config| Arc::new(crate::IpLimiter::new(config))),
+            wire_log: self
+                .wire_log_level
+                .map(|level| crate::wire_log::WireLog::new(level, self.wire_redact_fields)),
+            shutdown_grace_period: self.shutdown_grace_period,
+            #[cfg(feature = "otel")]
+            request_tracing: self.request_tracing,
+            #[cfg(feature = "tls")]
+            tls: self.tls.map(crate::tls::TlsTerminator::new),
         }
     }
 }
@@ -302,6 +575,20 @@ pub struct HttpMcpServer {
     tool_fingerprint: Option<String>,
     // Tool change notifier for restart/redeploy fingerprint mismatch
     tool_notifier: Option<Arc<dyn crate::ToolChangeNotifier>>,
+    // Global raw JSON-RPC request interceptor (shared with both handlers)
+    request_interceptor: Option<crate::RequestInterceptor>,
+    // Per-client-IP limits (None = unlimited)
+    ip_limiter: Option<Arc<crate::IpLimiter>>,
+    // Wire-level JSON-RPC logging (None = disabled)
+    wire_log: Option<crate::wire_log::WireLog>,
+    // Drain deadline for run_with_shutdown
+    shutdown_grace_period: Duration,
+    // OpenTelemetry request spans (shared with both handlers)
+    #[cfg(feature = "otel")]
+    request_tracing: Option<crate::otel::RequestTracing>,
+    // In-process TLS termination (None = plain HTTP)
+    #[cfg(feature = "tls")]
+    tls: Option<crate::tls::TlsTerminator>,
 }
 
 impl HttpMcpServer {
@@ -325,18 +612,146 @@ impl HttpMcpServer {
         Arc::clone(&self.stream_manager)
     }
 
+    /// Re-read the TLS certificate and key; new connections use them
+    ///
+    /// Connections already open keep their certificate. On error the previous
+    /// certificate stays in use. Fails if the server was built without TLS.
+    #[cfg(feature = "tls")]
+    pub fn reload_tls(&self) -> Result<()> {
+        match self.tls {
+            Some(ref tls) => tls.reload(),
+            None => Err(crate::tls::invalid_input(
+                "TLS is not configured for this server".to_string(),
+            )),
+        }
+    }
+
     /// Run the server with session management
     pub async fn run(&self) -> Result<()> {
+        self.run_with_shutdown(std::future::pending()).await
+    }
+
+    /// Run the server until `shutdown` completes, then drain
+    ///
+    /// On shutdown the listener is closed, open GET SSE streams receive a final
+    /// event and end, and in-flight requests get up to the configured
+    /// [`shutdown_grace_period`](HttpMcpServerBuilder::shutdown_grace_period) to
+    /// complete. Connections still busy after that are abandoned.
+    pub async fn run_with_shutdown(&self, shutdown: impl Future<Output = ()>) -> Result<()> {
+        // Load the certificate before accepting anything, so bad files fail startup
+        #[cfg(feature = "tls")]
+        if let Some(ref tls) = self.tls {
+            tls.reload()?;
+            #[cfg(unix)]
+            if tls.config().reloads_on_sighup() {
+                crate::tls::reload_on_sighup(tls.clone())?;
+            }
+        }
+
         // Start session cleanup task
         self.start_session_cleanup().await;
 
         let listener = TcpListener::bind(&self.config.bind_address).await?;
         info!("HTTP MCP server listening on {}", self.config.bind_address);
+        #[cfg(feature = "tls")]
+        if self.tls.is_some() {
+            info!("TLS enabled: serving HTTPS");
+        }
         info!("MCP endpoint available at: {}", self.config.mcp_path);
         info!("Session storage: {}", self.session_storage.backend_name());
 
-        // ✅ CORRECTED ARCHITECTURE: Create single SessionMcpHandler instance outside the loop
-        // Use the same middleware stack as streamable_handler (both handlers share it)
+        let handler = self.request_handler();
+
+        let graceful = GracefulShutdown::new();
+        tokio::pin!(shutdown);
+
+        loop {
+            let (stream, peer_addr) = tokio::select! {
+                accepted = listener.accept() => accepted?,
+                () = &mut shutdown => break,
+            };
+            debug!("New connection from {}", peer_addr);
+
+            // Picked per connection so a reloaded certificate applies to new ones
+            #[cfg(feature = "tls")]
+            let tls_acceptor = self
+                .tls
+                .as_ref()
+                .and_then(crate::tls::TlsTerminator::acceptor);
+
+            let connection_permit = match self.ip_limiter {
+                Some(ref limiter) => match limiter.try_acquire_connection(peer_addr.ip()) {
+                    Some(permit) => Some(permit),
+                    None => {
+                        // Answer with a proper HTTP rejection, then close
+                        let limiter = Arc::clone(limiter);
+                        tokio::spawn(async move {
+                            #[cfg(feature = "tls")]
+                            if let Some(acceptor) = tls_acceptor {
+                                if let Ok(stream) = acceptor.accept(stream).await {
+                                    serve_limit_rejection(stream, limiter).await;
+                                }
+                                return;
+                            }
+                            serve_limit_rejection(stream, limiter).await;
+                        });
+                        continue;
+                    }
+                },
+                None => None,
+            };
+
+            let handler_clone = handler.clone();
+            let service =
+                service_fn(move |req| handle_request(req, handler_clone.clone(), peer_addr));
+
+            #[cfg(feature = "tls")]
+            if let Some(acceptor) = tls_acceptor {
+                // The handshake runs on the connection's task, not the accept loop
+                let watcher = graceful.watcher();
+                tokio::spawn(async move {
+                    let _connection_permit = connection_permit;
+
+                    let stream = match acceptor.accept(stream).await {
+                        Ok(stream) => stream,
+                        Err(err) => {
+                            debug!("TLS handshake with {} failed: {}", peer_addr, err);
+                            return;
+                        }
+                    };
+                    let h2 = stream.get_ref().1.alpn_protocol() == Some(crate::tls::ALPN_H2);
+                    let io = TokioIo::new(stream);
+                    let result = if h2 {
+                        let builder =
+                            hyper::server::conn::http2::Builder::new(TokioExecutor::new());
+                        watcher.watch(builder.serve_connection(io, service)).await
+                    } else {
+                        watcher
+                            .watch(http1::Builder::new().serve_connection(io, service))
+                            .await
+                    };
+                    log_connection_result(result);
+                });
+                continue;
+            }
+
+            let io = TokioIo::new(stream);
+            let connection = graceful.watch(http1::Builder::new().serve_connection(io, service));
+            tokio::spawn(async move {
+                let _connection_permit = connection_permit;
+
+                log_connection_result(connection.await);
+            });
+        }
+
+        drop(listener);
+        self.drain(graceful).await;
+        Ok(())
+    }
+
+    /// Build the request router shared by every connection
+    fn request_handler(&self) -> McpRequestHandler {
+        // Both handlers share the same middleware stack
         let mut session_handler = SessionMcpHandler::with_shared_stream_manager(
             self.config.clone(),
             Arc::clone(&self.dispatcher),
@@ -349,39 +764,58 @@ impl HttpMcpServer {
         if let Some(ref notifier) = self.tool_notifier {
             session_handler = session_handler.with_tool_notifier(Arc::clone(notifier));
         }
+        if let Some(ref interceptor) = self.request_interceptor {
+            session_handler = session_handler.with_request_interceptor(Arc::clone(interceptor));
+        }
+        #[cfg(feature = "otel")]
+        if let Some(ref tracing) = self.request_tracing {
+            session_handler = session_handler.with_request_tracing(tracing.clone());
+        }
 
-        // Create combined handler that routes based on protocol version
-        let handler = McpRequestHandler {
+        McpRequestHandler {
             session_handler,
             streamable_handler: self.streamable_handler.clone(),
             route_registry: Arc::clone(&self.route_registry),
-        };
+            session_storage: Arc::clone(&self.session_storage),
+            ip_limiter: self.ip_limiter.clone(),
+            wire_log: self.wire_log.clone(),
+        }
+    }
 
-        loop {
-            let (stream, peer_addr) = listener.accept().await?;
-            debug!("New connection from {}", peer_addr);
+    /// In-process handle to this server's MCP endpoint
+    ///
+    /// The returned [`McpService`] answers requests through the same handlers as
+    /// the HTTP listener, without binding a socket. Useful for embedding a server
+    /// in the same process as its client, e.g. in tests.
+    pub fn service(&self) -> McpService {
+        McpService {
+            handler: self.request_handler(),
+        }
+    }
 
-            let handler_clone = handler.clone();
-            tokio::spawn(async move {
-                let io = TokioIo::new(stream);
-                let service = service_fn(move |req| handle_request(req, handler_clone.clone()));
-
-                if let Err(err) = http1::Builder::new().serve_connection(io, service).await {
-                    // Filter out common client disconnection errors that aren't actual problems
-                    let err_str = err.to_string();
-                    if err_str.contains("connection closed before message completed") {
-                        debug!("Client disconnected (normal): {}", err);
-                    } else {
-                        error!("Error serving connection: {}", err);
-                    }
-                }
-            });
+    /// Close SSE streams and wait for watched connections to finish
+    async fn drain(&self, graceful: GracefulShutdown) {
+        let sse_streams = self.stream_manager.close_sse_streams();
+        info!(
+            connections = graceful.count(),
+            sse_streams,
+            grace_period_ms = self.shutdown_grace_period.as_millis() as u64,
+            "Shutdown requested: draining in-flight requests"
+        );
+
+        match tokio::time::timeout(self.shutdown_grace_period, graceful.shutdown()).await {
+            Ok(()) => info!("Drain complete: all connections closed"),
+            Err(_) => warn!(
+                grace_period_ms = self.shutdown_grace_period.as_millis() as u64,
+                "Drain timed out: abandoning connections still in flight"
+            ),
         }
     }
 
     /// Start background session cleanup task
     async fn start_session_cleanup(&self) {
         let storage = Arc::clone(&self.session_storage);
+        let ip_limiter = self.ip_limiter.clone();
         let session_expiry_minutes = self.config.session_expiry_minutes;
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(60));
@@ -403,6 +837,10 @@ impl HttpMcpServer {
                         error!("Session cleanup error: {}", err);
                     }
                 }
+
+                if let Some(ref limiter) = ip_limiter {
+                    limiter.prune_sessions(storage.as_ref()).await;
+                }
             }
         });
     }
@@ -420,6 +858,35 @@ impl HttpMcpServer {
     }
 }
 
+/// Answer one request on `stream` with the per-IP connection rejection, then close
+async fn serve_limit_rejection<S>(stream: S, limiter: Arc<crate::IpLimiter>)
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + 'static,
+{
+    let io = TokioIo::new(stream);
+    let service = service_fn(move |_req| {
+        let response = limiter.rejection_response(crate::LimitRejection::ClientLimit);
+        async move { Ok::<_, hyper::Error>(response) }
+    });
+    let _ = http1::Builder::new()
+        .keep_alive(false)
+        .serve_connection(io, service)
+        .await;
+}
+
+/// Log how a served connection ended
+fn log_connection_result(result: std::result::Result<(), hyper::Error>) {
+    if let Err(err) = result {
+        // Filter out common client disconnection errors that aren't actual problems
+        let err_str = err.to_string();
+        if err_str.contains("connection closed before message completed") {
+            debug!("Client disconnected (normal): {}", err);
+        } else {
+            error!("Error serving connection: {}", err);
+        }
+    }
+}
+
 /// Handle requests with MCP 2025-11-25 compliance
 /// Combined handler that routes based on MCP protocol version
 #[derive(Clone)]
@@ -427,15 +894,125 @@ struct McpRequestHandler {
     session_handler: SessionMcpHandler,
     streamable_handler: StreamableHttpHandler,
     route_registry: Arc<crate::routes::RouteRegistry>,
+    session_storage: Arc<turul_mcp_session_storage::BoxedSessionStorage>,
+    ip_limiter: Option<Arc<crate::IpLimiter>>,
+    wire_log: Option<crate::wire_log::WireLog>,
 }
 
 async fn handle_request(
     req: Request<hyper::body::Incoming>,
     handler: McpRequestHandler,
+    peer_addr: SocketAddr,
+) -> std::result::Result<
+    Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>>,
+    hyper::Error,
+> {
+    let Some(limiter) = handler.ip_limiter.clone() else {
+        return route_request(req, handler).await;
+    };
+
+    let client_ip = limiter.client_ip(peer_addr.ip(), req.headers());
+    let origin = request_origin(&req);
+    let creates_session = req.method() == hyper::Method::POST
+        && req.uri().path() == handler.session_handler.config.mcp_path
+        && !req.headers().contains_key("mcp-session-id");
+
+    let admitted = match limiter.try_acquire_request(client_ip) {
+        Ok(permit) if creates_session => limiter
+            .try_reserve_session(client_ip, handler.session_storage.as_ref())
+            .await
+            .map(|reservation| (permit, Some(reservation))),
+        Ok(permit) => Ok((permit, None)),
+        Err(rejection) => Err(rejection),
+    };
+    let (request_permit, session_reservation) = match admitted {
+        Ok(admitted) => admitted,
+        Err(rejection) => {
+            debug!(
+                "Rejecting request from {} (peer {}): {:?}",
+                client_ip, peer_addr, rejection
+            );
+            let mut response = limiter.rejection_response(rejection);
+            let config = &handler.session_handler.config;
+            if config.enable_cors {
+                CorsLayer::apply(&config.cors, origin.as_deref(), response.headers_mut());
+            }
+            return Ok(response);
+        }
+    };
+
+    let response = route_request(req, handler).await?;
+    if let Some(reservation) = session_reservation
+        && let Some(session_id) = response
+            .headers()
+            .get("mcp-session-id")
+            .and_then(|value| value.to_str().ok())
+    {
+        reservation.complete(session_id);
+    }
+    Ok(request_permit.hold_until_body_ends(response))
+}
+
+async fn route_request(
+    req: Request<hyper::body::Incoming>,
+    handler: McpRequestHandler,
 ) -> std::result::Result<
     Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>>,
     hyper::Error,
 > {
+    let accept_encoding = req
+        .headers()
+        .get(hyper::header::ACCEPT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned);
+    let origin = request_origin(&req);
+
+    // Log JSON-RPC traffic on the MCP endpoint before it is compressed
+    let response = match &handler.wire_log {
+        Some(wire_log) if req.uri().path() == handler.session_handler.config.mcp_path => {
+            let session_id = crate::protocol::extract_session_id(req.headers());
+            let response = dispatch_request(wire_log.tap_request(req), &handler).await;
+            match response {
+                Ok(response) => wire_log.tap_response(response, session_id).await,
+                Err(e) => Err(e),
+            }
+        }
+        _ => dispatch_request(req, &handler).await,
+    };
+
+    // Apply CORS if enabled, then compress buffered JSON bodies
+    match response {
+        Ok(mut final_response) => {
+            let config = &handler.session_handler.config;
+            if config.enable_cors {
+                CorsLayer::apply(
+                    &config.cors,
+                    origin.as_deref(),
+                    final_response.headers_mut(),
+                );
+            }
+            crate::compression::compress_response(
+                final_response,
+                accept_encoding.as_deref(),
+                config,
+            )
+            .await
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Route a request to the MCP handlers or a custom route
+async fn dispatch_request<B>(
+    req: Request<B>,
+    handler: &McpRequestHandler,
+) -> std::result::Result<
+    Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>>,
+    hyper::Error,
+>
+where
+    B: hyper::body::Body<Data = Bytes, Error = hyper::Error> + Send + Unpin + 'static,
+{
     let method = req.method().clone();
     let uri = req.uri().clone();
     let path = uri.path();
@@ -447,7 +1024,7 @@ async fn handle_request(
         "HTTP server dispatch: path={}, expected_mcp_path={}",
         path, handler.session_handler.config.mcp_path
     );
-    let response = if path == handler.session_handler.config.mcp_path {
+    if path == handler.session_handler.config.mcp_path {
         debug!("Path match: Request routed to MCP handler");
         // Extract MCP protocol version from headers
         let protocol_version_str = req
@@ -505,6 +1082,12 @@ async fn handle_request(
                 }
             }
         }
+    } else if let Some(probe) =
+        crate::health::Probe::for_path(&handler.session_handler.config, path)
+    {
+        Ok(probe
+            .respond(&method, handler.session_storage.as_ref())
+            .await)
     } else {
         // Check custom routes (e.g., .well-known)
         match handler.route_registry.match_route(path) {
@@ -535,17 +1118,43 @@ async fn handle_request(
                 Ok(validation_err.into_response())
             }
         }
-    };
+    }
+}
 
-    // Apply CORS if enabled
-    match response {
-        Ok(mut final_response) => {
-            if handler.session_handler.config.enable_cors {
-                CorsLayer::apply_cors_headers(final_response.headers_mut());
-            }
-            Ok(final_response)
-        }
-        Err(e) => Err(e),
+fn request_origin<B>(req: &Request<B>) -> Option<String> {
+    req.headers()
+        .get(hyper::header::ORIGIN)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
+
+/// In-process entry point to an [`HttpMcpServer`]'s MCP endpoint
+///
+/// Obtained from [`HttpMcpServer::service`]. Requests run through the same session
+/// and streamable handlers, middleware and custom routes as the listener, but skip
+/// the transport-level layers: per-IP limits, CORS, compression and wire logging.
+#[derive(Clone)]
+pub struct McpService {
+    handler: McpRequestHandler,
+}
+
+impl McpService {
+    /// Path of the MCP endpoint requests should target
+    pub fn mcp_path(&self) -> &str {
+        &self.handler.session_handler.config.mcp_path
+    }
+
+    /// Handle one HTTP request with a fully buffered body
+    pub async fn call(
+        &self,
+        request: Request<Bytes>,
+    ) -> std::result::Result<
+        Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>>,
+        hyper::Error,
+    > {
+        let request =
+            request.map(|body| Full::new(body).map_err(|never| -> hyper::Error { match never {} }));
+        dispatch_request(request, &self.handler).await
     }
 }
 
@@ -36,6 +36,7 @@ use uuid::Uuid;
 
 use crate::{
     Result, ServerConfig, StreamConfig, StreamManager,
+    body_limit::{BodyReadError, read_body_limited},
     json_rpc_responses::*,
     notification_bridge::{SharedNotificationBroadcaster, StreamManagerNotificationBroadcaster},
     protocol::{
@@ -154,6 +155,9 @@ pub struct SessionMcpHandler {
     pub(crate) middleware_stack: Arc<crate::middleware::MiddlewareStack>,
     pub(crate) tool_fingerprint: Option<String>,
     pub(crate) tool_notifier: Option<Arc<dyn crate::ToolChangeNotifier>>,
+    pub(crate) request_interceptor: Option<crate::RequestInterceptor>,
+    #[cfg(feature = "otel")]
+    pub(crate) request_tracing: Option<crate::otel::RequestTracing>,
 }
 
 impl Clone for SessionMcpHandler {
@@ -167,6 +171,9 @@ impl Clone for SessionMcpHandler {
             middleware_stack: Arc::clone(&self.middleware_stack),
             tool_fingerprint: self.tool_fingerprint.clone(),
             tool_notifier: self.tool_notifier.clone(),
+            request_interceptor: self.request_interceptor.clone(),
+            #[cfg(feature = "otel")]
+            request_tracing: self.request_tracing.clone(),
         }
     }
 }
@@ -202,6 +209,9 @@ impl SessionMcpHandler {
             middleware_stack,
             tool_fingerprint: None,
             tool_notifier: None,
+            request_interceptor: None,
+            #[cfg(feature = "otel")]
+            request_tracing: None,
         }
     }
 
@@ -229,6 +239,9 @@ impl SessionMcpHandler {
             middleware_stack,
             tool_fingerprint: None,
             tool_notifier: None,
+            request_interceptor: None,
+            #[cfg(feature = "otel")]
+            request_tracing: None,
         }
     }
 
@@ -244,6 +257,19 @@ impl SessionMcpHandler {
         self
     }
 
+    /// Set the global request interceptor run before middleware and dispatch.
+    pub fn with_request_interceptor(mut self, interceptor: crate::RequestInterceptor) -> Self {
+        self.request_interceptor = Some(interceptor);
+        self
+    }
+
+    /// Trace each JSON-RPC request in an OpenTelemetry span
+    #[cfg(feature = "otel")]
+    pub fn with_request_tracing(mut self, tracing: crate::otel::RequestTracing) -> Self {
+        self.request_tracing = Some(tracing);
+        self
+    }
+
     /// Get access to the StreamManager for notifications
     pub fn get_stream_manager(&self) -> &Arc<StreamManager> {
         &self.stream_manager
@@ -264,8 +290,16 @@ impl SessionMcpHandler {
                 let response = self.handle_json_rpc_request(req).await?;
                 Ok(response)
             }
-            Method::GET => self.handle_sse_request(req).await,
+            Method::GET => {
+                if let Some(rejection) = self.run_http_guards(&req).await {
+                    return Ok(rejection);
+                }
+                self.handle_sse_request(req).await
+            }
             Method::DELETE => {
+                if let Some(rejection) = self.run_http_guards(&req).await {
+                    return Ok(rejection);
+                }
                 let response = self.handle_delete_request(req).await?;
                 Ok(response.map(convert_to_unified_body))
             }
@@ -336,28 +370,20 @@ impl SessionMcpHandler {
         );
 
         // Read request body
-        let body = req.into_body();
-        let body_bytes = match body.collect().await {
-            Ok(collected) => collected.to_bytes(),
-            Err(err) => {
-                error!("Failed to read request body: {}", err);
+        let limit = self.config.max_body_size;
+        let body_bytes = match read_body_limited(req.into_body(), limit).await {
+            Ok(bytes) => bytes,
+            Err(BodyReadError::TooLarge) => {
+                warn!("Request body exceeds {} bytes", limit);
+                return Ok(payload_too_large_response(limit).map(convert_to_unified_body));
+            }
+            Err(BodyReadError::Read) => {
+                error!("Failed to read request body");
                 return Ok(bad_request_response("Failed to read request body")
                     .map(convert_to_unified_body));
             }
         };
 
-        // Check body size
-        if body_bytes.len() > self.config.max_body_size {
-            warn!("Request body too large: {} bytes", body_bytes.len());
-            return Ok(Response::builder()
-                .status(StatusCode::PAYLOAD_TOO_LARGE)
-                .header(CONTENT_TYPE, "application/json")
-                .body(convert_to_unified_body(Full::new(Bytes::from(
-                    "Request body too large",
-                ))))
-                .unwrap());
-        }
-
         // Parse as UTF-8
         let body_str = match std::str::from_utf8(&body_bytes) {
             Ok(s) => s,
@@ -524,7 +550,7 @@ impl SessionMcpHandler {
                                             Some(error_msg),
                                         ),
                                     );
-                                (error_response, None, Vec::new())
+                                (Some(error_response), None, Vec::new())
                             }
                         }
                     } else {
@@ -621,7 +647,14 @@ impl SessionMcpHandler {
                             .await
                         } else {
                             // No session - fast path (no middleware, just dispatch)
-                            (self.dispatcher.handle_request(request).await, None)
+                            let mut request = request;
+                            match crate::interceptor::apply_request_interceptor(
+                                self.request_interceptor.as_ref(),
+                                &mut request,
+                            ) {
+                                Some(rejection) => (Some(rejection), None),
+                                None => (Some(self.dispatcher.handle_request(request).await), None),
+                            }
                         };
 
                         // Drain and unregister the temporary connection
@@ -644,14 +677,16 @@ impl SessionMcpHandler {
                         (response, session_id, inline_notifications)
                     };
 
-                    // Convert JsonRpcMessage to JsonRpcMessageResult
+                    // Convert JsonRpcMessage to JsonRpcMessageResult; a cancelled
+                    // request is answered like a notification, with no JSON-RPC body
                     let message_result = match response {
-                        turul_mcp_json_rpc_server::JsonRpcMessage::Response(resp) => {
+                        Some(turul_mcp_json_rpc_server::JsonRpcMessage::Response(resp)) => {
                             JsonRpcMessageResult::Response(resp)
                         }
-                        turul_mcp_json_rpc_server::JsonRpcMessage::Error(err) => {
+                        Some(turul_mcp_json_rpc_server::JsonRpcMessage::Error(err)) => {
                             JsonRpcMessageResult::Error(err)
                         }
+                        None => JsonRpcMessageResult::NoResponse,
                     };
                     (
                         message_result,
@@ -910,6 +945,21 @@ impl SessionMcpHandler {
             .await
         {
             Ok(response) => Ok(response),
+            Err(err @ crate::StreamError::TooManyStreams { .. }) => {
+                warn!("Refusing SSE stream: {}", err);
+                let body = serde_json::json!({
+                    "error": {
+                        "code": 429,
+                        "message": err.to_string()
+                    }
+                })
+                .to_string();
+                Ok(Response::builder()
+                    .status(StatusCode::TOO_MANY_REQUESTS)
+                    .header("content-type", "application/json")
+                    .body(convert_to_unified_body(Full::new(Bytes::from(body))))
+                    .unwrap())
+            }
             Err(err) => {
                 error!("Failed to create SSE connection: {}", err);
                 let error = JsonRpcError::new(
@@ -924,6 +974,45 @@ impl SessionMcpHandler {
         }
     }
 
+    /// Run authentication guards for a GET or DELETE request
+    ///
+    /// Returns the response to send when a guard rejects the request.
+    async fn run_http_guards<B>(&self, req: &Request<B>) -> Option<Response<UnifiedMcpBody>> {
+        match self
+            .middleware_stack
+            .execute_http_guards(req.method().as_str(), req.headers())
+            .await
+        {
+            Ok(()) => None,
+            Err(crate::middleware::MiddlewareError::HttpChallenge {
+                status,
+                www_authenticate,
+                body,
+            }) => Some(
+                Response::builder()
+                    .status(StatusCode::from_u16(status).unwrap_or(StatusCode::UNAUTHORIZED))
+                    .header("WWW-Authenticate", &www_authenticate)
+                    .header("Cache-Control", "no-store")
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(convert_to_unified_body(Full::new(Bytes::from(
+                        body.unwrap_or_default(),
+                    ))))
+                    .unwrap(),
+            ),
+            Err(err) => {
+                debug!("{} rejected by middleware: {}", req.method(), err);
+                Some(
+                    Response::builder()
+                        .status(StatusCode::FORBIDDEN)
+                        .body(convert_to_unified_body(Full::new(Bytes::from(
+                            err.to_string(),
+                        ))))
+                        .unwrap(),
+                )
+            }
+        }
+    }
+
     /// Handle DELETE requests for session cleanup
     async fn handle_delete_request<B>(&self, req: Request<B>) -> Result<Response<JsonRpcBody>>
     where
@@ -1106,8 +1195,7 @@ impl SessionMcpHandler {
         }
     }
 
-    /// Helper method to run middleware pipeline and dispatch request
-    /// Shared logic between StreamableHttpHandler and SessionMcpHandler
+    /// Run middleware and dispatch, inside a request span when tracing is enabled
     async fn run_middleware_and_dispatch(
         &self,
         request: turul_mcp_json_rpc_server::JsonRpcRequest,
@@ -1115,9 +1203,50 @@ impl SessionMcpHandler {
         session: turul_mcp_json_rpc_server::SessionContext,
         pre_session_extensions: Option<HashMap<String, serde_json::Value>>,
     ) -> (
-        turul_mcp_json_rpc_server::JsonRpcMessage,
+        Option<turul_mcp_json_rpc_server::JsonRpcMessage>,
+        Option<crate::middleware::SessionInjection>,
+    ) {
+        #[cfg(feature = "otel")]
+        if let Some(tracing) = &self.request_tracing {
+            use opentelemetry::trace::FutureExt;
+            let cx = tracing.start(&request, &headers, Some(&session.session_id));
+            let outcome = self
+                .dispatch_through_middleware(request, headers, session, pre_session_extensions)
+                .with_context(cx.clone())
+                .await;
+            tracing.finish(&cx, outcome.0.as_ref());
+            return outcome;
+        }
+        self.dispatch_through_middleware(request, headers, session, pre_session_extensions)
+            .await
+    }
+
+    /// Helper method to run middleware pipeline and dispatch request
+    /// Shared logic between StreamableHttpHandler and SessionMcpHandler
+    ///
+    /// The message is `None` when the request was cancelled by the client.
+    async fn dispatch_through_middleware(
+        &self,
+        mut request: turul_mcp_json_rpc_server::JsonRpcRequest,
+        headers: HashMap<String, String>,
+        session: turul_mcp_json_rpc_server::SessionContext,
+        pre_session_extensions: Option<HashMap<String, serde_json::Value>>,
+    ) -> (
+        Option<turul_mcp_json_rpc_server::JsonRpcMessage>,
         Option<crate::middleware::SessionInjection>,
     ) {
+        // Global request interceptor runs before middleware sees the request
+        if let Some(rejection) = crate::interceptor::apply_request_interceptor(
+            self.request_interceptor.as_ref(),
+            &mut request,
+        ) {
+            return (Some(rejection), None);
+        }
+
+        // Idempotency-Key reaches the tools/call handler as a request extension
+        let mut session = session;
+        crate::idempotency::forward_idempotency_key(&headers, &mut session.extensions);
+
         // Fast path: if middleware stack is empty, dispatch directly
         if self.middleware_stack.is_empty() {
             let result = self
@@ -1177,7 +1306,10 @@ impl SessionMcpHandler {
             Ok(inj) => inj,
             Err(err) => {
                 // Map middleware error to proper JSON-RPC error code
-                return (Self::map_middleware_error_to_jsonrpc(err, request.id), None);
+                return (
+                    Some(Self::map_middleware_error_to_jsonrpc(err, request.id)),
+                    None,
+                );
             }
         };
 
@@ -1196,17 +1328,19 @@ impl SessionMcpHandler {
         }
 
         // Thread extensions from RequestContext → JSON-RPC SessionContext (D3 canonical flow)
-        let mut session = session;
-        session.extensions = ctx.extensions().clone();
+        session.extensions.extend(ctx.extensions().clone());
 
         // Save request ID before dispatch consumes the request
         let request_id = request.id.clone();
 
-        // Dispatch the request
-        let result = self
+        // Dispatch the request; a cancelled request skips after_dispatch
+        let Some(result) = self
             .dispatcher
             .handle_request_with_context(request, session)
-            .await;
+            .await
+        else {
+            return (None, None);
+        };
 
         // Execute after_dispatch
         // Convert JsonRpcMessage to DispatcherResult for middleware
@@ -1231,10 +1365,13 @@ impl SessionMcpHandler {
         {
             Ok(()) => {
                 let result = Self::apply_dispatcher_result(result, dispatcher_result);
-                (result, None)
+                (Some(result), None)
             }
             Err(middleware_err) => (
-                Self::map_middleware_error_to_jsonrpc(middleware_err, request_id),
+                Some(Self::map_middleware_error_to_jsonrpc(
+                    middleware_err,
+                    request_id,
+                )),
                 None,
             ),
         }
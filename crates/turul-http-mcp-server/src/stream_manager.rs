@@ -6,6 +6,7 @@
 //! - Per-session event targeting (not broadcast to all)
 //! - Event persistence and replay
 //! - Proper HTTP status codes and headers
+//! - Size limits for individual events, with optional fragmentation
 
 use bytes::Bytes;
 use futures::{Stream, StreamExt};
@@ -16,6 +17,7 @@ use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::{RwLock, mpsc};
 use tracing::{debug, error, warn};
 
@@ -25,6 +27,17 @@ use turul_mcp_session_storage::SseEvent;
 pub type ConnectionId = String;
 pub type SessionConnections = HashMap<ConnectionId, mpsc::Sender<SseEvent>>;
 pub type ConnectionsMap = Arc<RwLock<HashMap<String, SessionConnections>>>;
+type ResourceUpdateWindows = Arc<tokio::sync::Mutex<HashMap<(String, String), Option<Value>>>>;
+/// Server-initiated requests awaiting the client's response, keyed by (session, request id)
+type PendingClientRequests =
+    Arc<std::sync::Mutex<HashMap<(String, String), tokio::sync::oneshot::Sender<Value>>>>;
+
+/// MCP method name of resource-updated notifications
+const RESOURCE_UPDATED_METHOD: &str = "notifications/resources/updated";
+
+/// `_meta` key carrying `{ id, index, count }` on events split under
+/// [`OversizedEventPolicy::Fragment`]
+pub const FRAGMENT_META_KEY: &str = "turul/fragment";
 
 /// Enhanced stream manager with resumability support (MCP spec compliant)
 pub struct StreamManager {
@@ -36,6 +49,13 @@ pub struct StreamManager {
     subscriptions: Arc<RwLock<HashMap<String, HashSet<String>>>>,
     /// Configuration
     config: StreamConfig,
+    /// Open resource-update coalescing windows keyed by (session, URI), holding the
+    /// latest update received while the window was open
+    resource_update_windows: ResourceUpdateWindows,
+    /// Flipped to `true` when the server starts draining; GET SSE streams end on it
+    closing: tokio::sync::watch::Sender<bool>,
+    /// Server-to-client requests (e.g. `sampling/createMessage`) awaiting a response
+    pending_client_requests: PendingClientRequests,
     /// Unique instance ID for debugging
     instance_id: String,
 }
@@ -47,10 +67,42 @@ pub struct StreamConfig {
     pub channel_buffer_size: usize,
     /// Maximum events to replay on reconnection
     pub max_replay_events: usize,
-    /// Keep-alive interval in seconds
-    pub keepalive_interval_seconds: u64,
+    /// How long a GET SSE stream may sit idle before a `: ping` comment is sent
+    /// (`None` disables keep-alives)
+    ///
+    /// Comments carry no event id, so they never move the client's `Last-Event-ID`.
+    pub keep_alive: Option<Duration>,
     /// CORS configuration
     pub cors_origin: String,
+    /// Window in milliseconds for coalescing `notifications/resources/updated` per
+    /// session and URI (`None` delivers every update)
+    pub resource_update_coalesce_window_ms: Option<u64>,
+    /// Largest serialized event payload (the SSE `data:` line) in bytes; `None` = no limit
+    pub max_event_size_bytes: Option<usize>,
+    /// What to do with events larger than `max_event_size_bytes`
+    pub oversized_event_policy: OversizedEventPolicy,
+    /// Most SSE streams one session may hold open at once; `None` = no limit
+    ///
+    /// Opening a GET stream beyond the limit fails with
+    /// [`StreamError::TooManyStreams`] (HTTP 429) while existing streams carry on.
+    /// POST response streams count toward the limit but are never refused, since
+    /// each belongs to a request already in flight. A stream frees its slot as
+    /// soon as the client disconnects.
+    pub max_streams_per_session: Option<usize>,
+}
+
+/// How [`StreamManager`] handles events larger than [`StreamConfig::max_event_size_bytes`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OversizedEventPolicy {
+    /// Drop the event, log an error, and return [`StreamError::EventTooLarge`]
+    #[default]
+    Reject,
+    /// Split the serialized event into ordered fragment events that each fit the limit
+    ///
+    /// Every fragment is a notification with the original method whose `params` hold a
+    /// `chunk` string and a [`FRAGMENT_META_KEY`] entry in `_meta`; concatenating the
+    /// chunks in `index` order yields the original JSON. See [`reassemble_fragments`].
+    Fragment,
 }
 
 impl Default for StreamConfig {
@@ -58,8 +110,12 @@ impl Default for StreamConfig {
         Self {
             channel_buffer_size: 1000,
             max_replay_events: 100,
-            keepalive_interval_seconds: 30,
+            keep_alive: Some(Duration::from_secs(30)),
             cors_origin: "*".to_string(),
+            resource_update_coalesce_window_ms: None,
+            max_event_size_bytes: None,
+            oversized_event_policy: OversizedEventPolicy::Reject,
+            max_streams_per_session: None,
         }
     }
 }
@@ -120,6 +176,15 @@ pub enum StreamError {
     NoConnections(String),
     #[error("Session {0} not subscribed to notification type: {1}")]
     NotSubscribed(String, String),
+    #[error("Event of {size} bytes exceeds the {max}-byte limit")]
+    EventTooLarge { size: usize, max: usize },
+    #[error("Session {session_id} already has {max} open SSE streams")]
+    TooManyStreams { session_id: String, max: usize },
+    #[error("Client did not answer {method} within {timeout:?}")]
+    ClientRequestTimeout {
+        method: String,
+        timeout: std::time::Duration,
+    },
 }
 
 impl StreamManager {
@@ -141,10 +206,110 @@ impl StreamManager {
             connections: Arc::new(RwLock::new(HashMap::new())),
             subscriptions: Arc::new(RwLock::new(HashMap::new())),
             config,
+            resource_update_windows: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
+            closing: tokio::sync::watch::Sender::new(false),
+            pending_client_requests: Arc::new(std::sync::Mutex::new(HashMap::new())),
             instance_id,
         }
     }
 
+    /// Send a JSON-RPC request to the client and wait for its response
+    ///
+    /// The request is delivered like any other event for the session: on the POST stream
+    /// of a call in progress, or on the GET stream. The client answers by POSTing a
+    /// JSON-RPC response, which the transport passes to
+    /// [`deliver_client_response`](Self::deliver_client_response). Returns the client's
+    /// whole response message, carrying either `result` or `error`.
+    pub async fn request_client(
+        &self,
+        session_id: &str,
+        method: &str,
+        params: Value,
+        timeout: std::time::Duration,
+    ) -> Result<Value, StreamError> {
+        let request_id = format!("srv-{}", uuid::Uuid::now_v7().as_simple());
+        let key = (session_id.to_string(), request_id.clone());
+        let (response_tx, response_rx) = tokio::sync::oneshot::channel();
+        self.pending_client_requests
+            .lock()
+            .unwrap()
+            .insert(key.clone(), response_tx);
+
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": request_id,
+            "method": method,
+            "params": params,
+        });
+        if let Err(e) = self
+            .broadcast_to_session(session_id, method.to_string(), request)
+            .await
+        {
+            self.pending_client_requests.lock().unwrap().remove(&key);
+            return Err(e);
+        }
+
+        let outcome = tokio::time::timeout(timeout, response_rx).await;
+        self.pending_client_requests.lock().unwrap().remove(&key);
+        match outcome {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(_)) => Err(StreamError::ConnectionError(format!(
+                "{} request {} was abandoned",
+                method, request_id
+            ))),
+            Err(_) => Err(StreamError::ClientRequestTimeout {
+                method: method.to_string(),
+                timeout,
+            }),
+        }
+    }
+
+    /// Hand a client's JSON-RPC response to the [`request_client`](Self::request_client)
+    /// call awaiting it
+    ///
+    /// Returns `false` when the session has no pending request with that id.
+    pub fn deliver_client_response(&self, session_id: &str, response: Value) -> bool {
+        let Some(request_id) = response.get("id").and_then(Value::as_str) else {
+            return false;
+        };
+        let pending = self
+            .pending_client_requests
+            .lock()
+            .unwrap()
+            .remove(&(session_id.to_string(), request_id.to_string()));
+        match pending {
+            Some(response_tx) => response_tx.send(response).is_ok(),
+            None => false,
+        }
+    }
+
+    /// End every open GET SSE stream with a final shutdown event
+    ///
+    /// Streams opened afterwards end immediately. POST streams carrying tool-call
+    /// responses are left to finish. Returns the number of streams signalled.
+    pub fn close_sse_streams(&self) -> usize {
+        self.closing.send_replace(true);
+        self.closing.receiver_count()
+    }
+
+    /// Final event sent on GET SSE streams when the server shuts down
+    ///
+    /// Unpersisted (`id: 0`), so it does not move the client's Last-Event-ID.
+    fn shutdown_event() -> SseEvent {
+        SseEvent::new(
+            "shutdown".to_string(),
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/message",
+                "params": {
+                    "level": "notice",
+                    "logger": "turul-mcp-server",
+                    "data": "Server shutting down"
+                }
+            }),
+        )
+    }
+
     /// Handle SSE connection request with proper resumability
     pub async fn handle_sse_connection(
         &self,
@@ -201,10 +366,11 @@ impl StreamManager {
     ) -> Result<SseStream, StreamError> {
         // Create mpsc channel for this specific connection (MCP compliant)
         let (sender, mut receiver) = mpsc::channel(self.config.channel_buffer_size);
+        let mut closing = self.closing.subscribe();
 
-        // Register this connection with the session
-        self.register_connection(&session_id, connection_id.clone(), sender)
-            .await;
+        // Register this connection with the session, within the per-session limit
+        self.try_register_connection(&session_id, connection_id.clone(), sender)
+            .await?;
 
         // Create the combined stream
         let storage = self.storage.clone();
@@ -236,18 +402,29 @@ impl StreamManager {
                        session_id_clone, connection_id_clone);
             }
 
-            // 2. Then, stream real-time events from dedicated channel
-            let mut keepalive_interval = tokio::time::interval(
-                tokio::time::Duration::from_secs(config.keepalive_interval_seconds)
-            );
+            // 2. Then, stream real-time events from dedicated channel, sending a
+            // keep-alive comment whenever the stream has been idle for `keep_alive`
+            let mut keep_alive = config.keep_alive.map(tokio::time::interval);
 
             loop {
+                if *closing.borrow_and_update() {
+                    debug!("Server shutting down, closing SSE stream: session={}, connection={}", session_id_clone, connection_id_clone);
+                    yield Self::shutdown_event();
+                    break;
+                }
+
                 tokio::select! {
+                    // Server shutdown: loop back to send the final event
+                    Ok(()) = closing.changed() => {},
+
                     // Real-time events from this connection's channel
                     event = receiver.recv() => {
                         match event {
                             Some(event) => {
                                 debug!("Received event for connection {}: {}", connection_id_clone, event.event_type);
+                                if let Some(keep_alive) = keep_alive.as_mut() {
+                                    keep_alive.reset();
+                                }
                                 yield event;
                             },
                             None => {
@@ -258,7 +435,14 @@ impl StreamManager {
                     },
 
                     // Keep-alive pings (comment-style to preserve Last-Event-ID for resumability)
-                    _ = keepalive_interval.tick() => {
+                    _ = async {
+                        match keep_alive.as_mut() {
+                            Some(keep_alive) => {
+                                keep_alive.tick().await;
+                            }
+                            None => std::future::pending().await,
+                        }
+                    } => {
                         let keepalive_event = SseEvent {
                             id: 0, // Will be ignored - comment-style keepalives don't have id field
                             timestamp: chrono::Utc::now().timestamp_millis() as u64,
@@ -336,6 +520,45 @@ impl StreamManager {
         }
     }
 
+    /// Register a connection unless the session is at `max_streams_per_session`
+    ///
+    /// Connections whose stream has gone away are pruned first, so a disconnected
+    /// client's slot is free again even before an event reveals it as dead.
+    async fn try_register_connection(
+        &self,
+        session_id: &str,
+        connection_id: ConnectionId,
+        sender: mpsc::Sender<SseEvent>,
+    ) -> Result<(), StreamError> {
+        let mut connections = self.connections.write().await;
+        let session_connections = connections.entry(session_id.to_string()).or_default();
+        session_connections.retain(|_, sender| !sender.is_closed());
+
+        if let Some(max) = self.config.max_streams_per_session
+            && session_connections.len() >= max
+        {
+            warn!(
+                "Rejecting SSE stream for session {}: {} streams already open",
+                session_id,
+                session_connections.len()
+            );
+            return Err(StreamError::TooManyStreams {
+                session_id: session_id.to_string(),
+                max,
+            });
+        }
+
+        session_connections.insert(connection_id.clone(), sender);
+        debug!(
+            "[{}] 🔗 Registered connection: session={}, connection={}, total_connections={}",
+            self.instance_id,
+            session_id,
+            connection_id,
+            session_connections.len()
+        );
+        Ok(())
+    }
+
     /// Register a streaming connection to receive events for a session (public API for POST streaming)
     pub async fn register_streaming_connection(
         &self,
@@ -521,9 +744,63 @@ impl StreamManager {
             return Err(StreamError::NoConnections(session_id.to_string()));
         }
 
-        // Create the event
-        let event = SseEvent::new(event_type.clone(), data);
+        if let Some(max) = self.config.max_event_size_bytes {
+            let size = data.to_string().len();
+            if size > max {
+                return self
+                    .send_oversized(session_id, event_type, data, size, max)
+                    .await;
+            }
+        }
 
+        self.store_and_deliver(session_id, SseEvent::new(event_type, data))
+            .await
+    }
+
+    /// Apply [`StreamConfig::oversized_event_policy`] to an event over the size limit
+    async fn send_oversized(
+        &self,
+        session_id: &str,
+        event_type: String,
+        data: Value,
+        size: usize,
+        max: usize,
+    ) -> Result<u64, StreamError> {
+        let fragments = match self.config.oversized_event_policy {
+            OversizedEventPolicy::Reject => None,
+            OversizedEventPolicy::Fragment => fragment_event(&event_type, &data, max),
+        };
+        let Some(fragments) = fragments else {
+            error!(
+                "Rejecting {} event for session {}: {} bytes exceeds the {}-byte limit",
+                event_type, session_id, size, max
+            );
+            return Err(StreamError::EventTooLarge { size, max });
+        };
+
+        debug!(
+            "Splitting {} event for session {} into {} fragments ({} bytes, limit {})",
+            event_type,
+            session_id,
+            fragments.len(),
+            size,
+            max
+        );
+        let mut last_id = 0;
+        for fragment in fragments {
+            last_id = self
+                .store_and_deliver(session_id, SseEvent::new(event_type.clone(), fragment))
+                .await?;
+        }
+        Ok(last_id)
+    }
+
+    /// Store an event for resumability, then hand it to one live connection
+    async fn store_and_deliver(
+        &self,
+        session_id: &str,
+        event: SseEvent,
+    ) -> Result<u64, StreamError> {
         // Store event for resumability (always store for compliant clients)
         let stored_event = self
             .storage
@@ -612,6 +889,85 @@ impl StreamManager {
         Ok(stored_event.id)
     }
 
+    /// Send a `notifications/resources/updated` event to a session, coalescing bursts
+    ///
+    /// With [`StreamConfig::resource_update_coalesce_window_ms`] set, the first update for a
+    /// URI is delivered immediately and opens a window. Further updates to that URI while the
+    /// window is open are folded into a single notification carrying the latest payload, sent
+    /// when the window closes. The window stays open until one passes without updates, so a
+    /// burst always ends with its final state delivered.
+    ///
+    /// Returns `Ok(None)` when the update was folded into a pending one.
+    pub async fn broadcast_resource_updated(
+        self: &Arc<Self>,
+        session_id: &str,
+        data: Value,
+    ) -> Result<Option<u64>, StreamError> {
+        let uri = data.pointer("/params/uri").and_then(Value::as_str);
+        let (Some(window_ms), Some(uri)) = (self.config.resource_update_coalesce_window_ms, uri)
+        else {
+            return self
+                .broadcast_to_session(session_id, RESOURCE_UPDATED_METHOD.to_string(), data)
+                .await
+                .map(Some);
+        };
+
+        let key = (session_id.to_string(), uri.to_string());
+        {
+            let mut windows = self.resource_update_windows.lock().await;
+            if let Some(pending) = windows.get_mut(&key) {
+                debug!(
+                    "Coalescing resource update: session={}, uri={}",
+                    session_id, key.1
+                );
+                *pending = Some(data);
+                return Ok(None);
+            }
+            windows.insert(key.clone(), None);
+        }
+
+        let result = self
+            .broadcast_to_session(session_id, RESOURCE_UPDATED_METHOD.to_string(), data)
+            .await;
+
+        let manager = Arc::clone(self);
+        tokio::spawn(async move {
+            manager
+                .flush_resource_updates(key, std::time::Duration::from_millis(window_ms))
+                .await;
+        });
+
+        result.map(Some)
+    }
+
+    /// Deliver the latest held update at the end of each window until a window passes quietly
+    async fn flush_resource_updates(&self, key: (String, String), window: std::time::Duration) {
+        loop {
+            tokio::time::sleep(window).await;
+
+            let latest = {
+                let mut windows = self.resource_update_windows.lock().await;
+                match windows.get_mut(&key).and_then(Option::take) {
+                    Some(latest) => latest,
+                    None => {
+                        windows.remove(&key);
+                        return;
+                    }
+                }
+            };
+
+            if let Err(e) = self
+                .broadcast_to_session(&key.0, RESOURCE_UPDATED_METHOD.to_string(), latest)
+                .await
+            {
+                warn!(
+                    "Failed to deliver coalesced resource update: session={}, uri={}, error={}",
+                    key.0, key.1, e
+                );
+            }
+        }
+    }
+
     /// Broadcast to all sessions (for server-wide notifications)
     pub async fn broadcast_to_all_sessions(
         &self,
@@ -981,6 +1337,95 @@ pub struct StreamStats {
     pub channel_buffer_size: usize,
 }
 
+/// Split `data` into fragment notifications whose serialized form is at most `max`
+/// bytes each; `None` if `max` cannot hold even the fragment envelope.
+fn fragment_event(event_type: &str, data: &Value, max: usize) -> Option<Vec<Value>> {
+    let serialized = data.to_string();
+    let method = data
+        .get("method")
+        .and_then(Value::as_str)
+        .unwrap_or(event_type);
+    let id = uuid::Uuid::now_v7().as_simple().to_string();
+    let envelope = |index: usize, count: usize, chunk: &str| {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": {
+                "_meta": { FRAGMENT_META_KEY: { "id": id, "index": index, "count": count } },
+                "chunk": chunk
+            }
+        })
+    };
+
+    // Size the envelope with index/count at an upper bound so every fragment fits;
+    // the budget must also hold the widest escaped character (`\u00XX`)
+    let bound = serialized.len();
+    let overhead = envelope(bound, bound, "").to_string().len();
+    let budget = max.checked_sub(overhead).filter(|budget| *budget >= 6)?;
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut used = 0;
+    for (offset, c) in serialized.char_indices() {
+        let cost = escaped_len(c);
+        if used + cost > budget {
+            chunks.push(&serialized[start..offset]);
+            start = offset;
+            used = 0;
+        }
+        used += cost;
+    }
+    chunks.push(&serialized[start..]);
+
+    let count = chunks.len();
+    Some(
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, chunk)| envelope(index, count, chunk))
+            .collect(),
+    )
+}
+
+/// Bytes `c` occupies inside a JSON string literal, as serde_json escapes it
+fn escaped_len(c: char) -> usize {
+    match c {
+        '"' | '\\' | '\n' | '\r' | '\t' | '\u{08}' | '\u{0c}' => 2,
+        c if (c as u32) < 0x20 => 6,
+        c => c.len_utf8(),
+    }
+}
+
+/// Rebuild an event split under [`OversizedEventPolicy::Fragment`] from its fragment
+/// event payloads, in any order.
+///
+/// Returns `None` unless `fragments` is exactly one complete set sharing a fragment id.
+pub fn reassemble_fragments(fragments: &[Value]) -> Option<Value> {
+    let mut parts = Vec::with_capacity(fragments.len());
+    for fragment in fragments {
+        let params = fragment.get("params")?;
+        let meta = params.get("_meta")?.get(FRAGMENT_META_KEY)?;
+        parts.push((
+            meta.get("id")?.as_str()?,
+            meta.get("index")?.as_u64()? as usize,
+            meta.get("count")?.as_u64()? as usize,
+            params.get("chunk")?.as_str()?,
+        ));
+    }
+
+    let (id, _, count, _) = *parts.first()?;
+    if parts.len() != count || parts.iter().any(|part| part.0 != id || part.2 != count) {
+        return None;
+    }
+    parts.sort_by_key(|part| part.1);
+    if parts.iter().enumerate().any(|(i, part)| part.1 != i) {
+        return None;
+    }
+
+    let serialized: String = parts.iter().map(|part| part.3).collect();
+    serde_json::from_str(&serialized).ok()
+}
+
 // Helper to create async stream
 #[cfg(not(test))]
 use async_stream;
@@ -1235,4 +1680,484 @@ mod tests {
             "has_connections must return true when at least one sender is open"
         );
     }
+
+    /// GET streams beyond `max_streams_per_session` are refused until one closes.
+    #[tokio::test]
+    async fn test_max_streams_per_session() {
+        let storage = Arc::new(InMemorySessionStorage::new());
+        let manager = StreamManager::with_config(
+            storage.clone(),
+            StreamConfig {
+                max_streams_per_session: Some(2),
+                ..Default::default()
+            },
+        );
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        let session_id = session.session_id.clone();
+
+        let first = manager
+            .handle_sse_connection(session_id.clone(), "first".to_string(), None)
+            .await
+            .unwrap();
+        let _second = manager
+            .handle_sse_connection(session_id.clone(), "second".to_string(), None)
+            .await
+            .unwrap();
+
+        let rejected = manager
+            .handle_sse_connection(session_id.clone(), "third".to_string(), None)
+            .await;
+        assert!(matches!(
+            rejected,
+            Err(StreamError::TooManyStreams { max: 2, .. })
+        ));
+        assert_eq!(manager.get_stats().await.active_broadcasters, 2);
+
+        // Closing a stream frees its slot without waiting for a failed send
+        drop(first);
+        manager
+            .handle_sse_connection(session_id.clone(), "third".to_string(), None)
+            .await
+            .expect("closed stream should free a slot");
+
+        // Other sessions have their own allowance
+        let other = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        assert!(
+            manager
+                .handle_sse_connection(other.session_id, "other".to_string(), None)
+                .await
+                .is_ok()
+        );
+    }
+
+    /// A burst of updates to one URI collapses to the first and the latest.
+    #[tokio::test]
+    async fn test_resource_updates_coalesced_within_window() {
+        let storage = Arc::new(InMemorySessionStorage::new());
+        let manager = Arc::new(StreamManager::with_config(
+            storage.clone(),
+            StreamConfig {
+                resource_update_coalesce_window_ms: Some(100),
+                ..Default::default()
+            },
+        ));
+
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        let session_id = session.session_id.clone();
+
+        let (sender, mut receiver) = mpsc::channel(100);
+        manager
+            .register_connection(&session_id, "subscriber".to_string(), sender)
+            .await;
+
+        let update = |uri: &str, reading: u64| {
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": RESOURCE_UPDATED_METHOD,
+                "params": { "uri": uri, "_meta": { "reading": reading } }
+            })
+        };
+
+        for reading in 0..50 {
+            manager
+                .broadcast_resource_updated(&session_id, update("file:///metrics", reading))
+                .await
+                .unwrap();
+        }
+        // A different URI has its own window
+        manager
+            .broadcast_resource_updated(&session_id, update("file:///other", 0))
+            .await
+            .unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(350)).await;
+
+        let mut metrics_readings = Vec::new();
+        let mut other_count = 0;
+        while let Ok(event) = receiver.try_recv() {
+            assert_eq!(event.event_type, RESOURCE_UPDATED_METHOD);
+            match event.data["params"]["uri"].as_str().unwrap() {
+                "file:///metrics" => {
+                    metrics_readings.push(event.data["params"]["_meta"]["reading"].clone())
+                }
+                _ => other_count += 1,
+            }
+        }
+
+        assert_eq!(
+            metrics_readings,
+            vec![serde_json::json!(0), serde_json::json!(49)]
+        );
+        assert_eq!(other_count, 1);
+        assert!(
+            manager.resource_update_windows.lock().await.is_empty(),
+            "windows close once a quiet period passes"
+        );
+    }
+
+    /// Read SSE frames from `body` until `window` elapses
+    async fn read_frames(
+        body: &mut http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>,
+        window: Duration,
+    ) -> Vec<String> {
+        let mut frames = Vec::new();
+        let deadline = tokio::time::Instant::now() + window;
+        while let Ok(Some(Ok(frame))) = tokio::time::timeout_at(deadline, body.frame()).await {
+            if let Ok(data) = frame.into_data() {
+                frames.push(String::from_utf8_lossy(&data).into_owned());
+            }
+        }
+        frames
+    }
+
+    async fn keep_alive_stream(
+        keep_alive: Option<Duration>,
+    ) -> (
+        Arc<StreamManager>,
+        String,
+        http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>,
+    ) {
+        let storage = Arc::new(InMemorySessionStorage::new());
+        let manager = Arc::new(StreamManager::with_config(
+            storage.clone(),
+            StreamConfig {
+                keep_alive,
+                ..Default::default()
+            },
+        ));
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        let response = manager
+            .handle_sse_connection(session.session_id.clone(), "idle".to_string(), None)
+            .await
+            .unwrap();
+        (manager, session.session_id, response.into_body())
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_pings_silent_stream() {
+        let (_manager, _session_id, mut body) =
+            keep_alive_stream(Some(Duration::from_millis(50))).await;
+
+        let frames = read_frames(&mut body, Duration::from_millis(230)).await;
+
+        assert!(frames.len() >= 3, "expected heartbeats, got {frames:?}");
+        assert!(frames.iter().all(|frame| frame == ": ping\n\n"));
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_leaves_event_ids_alone() {
+        let (manager, session_id, mut body) =
+            keep_alive_stream(Some(Duration::from_millis(50))).await;
+        read_frames(&mut body, Duration::from_millis(10)).await;
+
+        let event_id = manager
+            .broadcast_to_session(
+                &session_id,
+                "notifications/message".to_string(),
+                serde_json::json!({"jsonrpc": "2.0", "method": "notifications/message"}),
+            )
+            .await
+            .unwrap();
+        let frames = read_frames(&mut body, Duration::from_millis(180)).await;
+
+        assert!(frames[0].starts_with(&format!("id: {event_id}\n")));
+        let pings = &frames[1..];
+        assert!(pings.len() >= 2, "expected heartbeats, got {frames:?}");
+        assert!(pings.iter().all(|frame| !frame.contains("id:")));
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_disabled_sends_nothing() {
+        let (_manager, _session_id, mut body) = keep_alive_stream(None).await;
+
+        let frames = read_frames(&mut body, Duration::from_millis(150)).await;
+
+        assert!(frames.is_empty(), "unexpected frames {frames:?}");
+    }
+
+    /// Without a window every update is delivered.
+    #[tokio::test]
+    async fn test_resource_updates_not_coalesced_by_default() {
+        let storage = Arc::new(InMemorySessionStorage::new());
+        let manager = Arc::new(StreamManager::new(storage.clone()));
+
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        let session_id = session.session_id.clone();
+
+        let (sender, mut receiver) = mpsc::channel(100);
+        manager
+            .register_connection(&session_id, "subscriber".to_string(), sender)
+            .await;
+
+        for _ in 0..5 {
+            let sent = manager
+                .broadcast_resource_updated(
+                    &session_id,
+                    serde_json::json!({
+                        "jsonrpc": "2.0",
+                        "method": RESOURCE_UPDATED_METHOD,
+                        "params": { "uri": "file:///metrics" }
+                    }),
+                )
+                .await
+                .unwrap();
+            assert!(sent.is_some());
+        }
+
+        let mut delivered = 0;
+        while receiver.try_recv().is_ok() {
+            delivered += 1;
+        }
+        assert_eq!(delivered, 5);
+    }
+
+    async fn size_limited_manager(
+        policy: OversizedEventPolicy,
+    ) -> (
+        Arc<InMemorySessionStorage>,
+        StreamManager,
+        String,
+        mpsc::Receiver<SseEvent>,
+    ) {
+        let storage = Arc::new(InMemorySessionStorage::new());
+        let manager = StreamManager::with_config(
+            storage.clone(),
+            StreamConfig {
+                max_event_size_bytes: Some(256),
+                oversized_event_policy: policy,
+                ..Default::default()
+            },
+        );
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        let (sender, receiver) = mpsc::channel(100);
+        manager
+            .register_connection(&session.session_id, "subscriber".to_string(), sender)
+            .await;
+        (storage, manager, session.session_id, receiver)
+    }
+
+    /// A structured-content fragment well over the 256-byte limit, with characters
+    /// that need escaping and multi-byte UTF-8
+    fn oversized_notification() -> Value {
+        let text: String = (0..40)
+            .map(|i| format!("line {i}: \"quoted\" \\ tab\t ünïcødé 🦀\n"))
+            .collect();
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/message",
+            "params": { "level": "info", "data": { "text": text } }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_oversized_event_rejected() {
+        let (storage, manager, session_id, mut receiver) =
+            size_limited_manager(OversizedEventPolicy::Reject).await;
+        let notification = oversized_notification();
+        let size = notification.to_string().len();
+
+        let result = manager
+            .broadcast_to_session(
+                &session_id,
+                "notifications/message".to_string(),
+                notification,
+            )
+            .await;
+
+        match result {
+            Err(StreamError::EventTooLarge { size: actual, max }) => {
+                assert_eq!(actual, size);
+                assert_eq!(max, 256);
+            }
+            other => panic!("expected EventTooLarge, got {:?}", other),
+        }
+        assert!(receiver.try_recv().is_err(), "nothing is delivered");
+        assert!(
+            storage
+                .get_events_after(&session_id, 0)
+                .await
+                .unwrap()
+                .is_empty(),
+            "nothing is stored for replay"
+        );
+
+        // Events within the limit are unaffected
+        manager
+            .broadcast_to_session(
+                &session_id,
+                "notifications/message".to_string(),
+                serde_json::json!({"jsonrpc": "2.0", "method": "notifications/message"}),
+            )
+            .await
+            .unwrap();
+        assert!(receiver.try_recv().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_oversized_event_fragmented() {
+        let (storage, manager, session_id, mut receiver) =
+            size_limited_manager(OversizedEventPolicy::Fragment).await;
+        let notification = oversized_notification();
+
+        let last_id = manager
+            .broadcast_to_session(
+                &session_id,
+                "notifications/message".to_string(),
+                notification.clone(),
+            )
+            .await
+            .unwrap();
+
+        let mut events = Vec::new();
+        while let Ok(event) = receiver.try_recv() {
+            events.push(event);
+        }
+        assert!(events.len() > 1, "event is split into several fragments");
+        assert_eq!(events.last().unwrap().id, last_id);
+        assert!(events.windows(2).all(|pair| pair[0].id < pair[1].id));
+        for event in &events {
+            assert_eq!(event.event_type, "notifications/message");
+            assert_eq!(event.data["method"], "notifications/message");
+            assert!(
+                event.data.to_string().len() <= 256,
+                "fragment of {} bytes exceeds the limit",
+                event.data.to_string().len()
+            );
+        }
+
+        // Fragments reassemble in delivery order, in any order, and from replay
+        let mut fragments: Vec<Value> = events.iter().map(|event| event.data.clone()).collect();
+        assert_eq!(reassemble_fragments(&fragments), Some(notification.clone()));
+        fragments.reverse();
+        assert_eq!(reassemble_fragments(&fragments), Some(notification.clone()));
+        let replayed: Vec<Value> = storage
+            .get_events_after(&session_id, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|event| event.data)
+            .collect();
+        assert_eq!(reassemble_fragments(&replayed), Some(notification));
+
+        // An incomplete set does not reassemble
+        fragments.pop();
+        assert_eq!(reassemble_fragments(&fragments), None);
+    }
+
+    #[tokio::test]
+    async fn test_fragment_limit_too_small_rejects() {
+        let storage = Arc::new(InMemorySessionStorage::new());
+        let manager = StreamManager::with_config(
+            storage.clone(),
+            StreamConfig {
+                max_event_size_bytes: Some(32),
+                oversized_event_policy: OversizedEventPolicy::Fragment,
+                ..Default::default()
+            },
+        );
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+
+        let result = manager
+            .broadcast_to_session(
+                &session.session_id,
+                "notifications/message".to_string(),
+                oversized_notification(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(StreamError::EventTooLarge { max: 32, .. })
+        ));
+    }
+
+    /// A client request is answered by the response the client POSTs back for the same session.
+    #[tokio::test]
+    async fn test_request_client_resolves_with_delivered_response() {
+        let storage = Arc::new(InMemorySessionStorage::new());
+        let manager = Arc::new(StreamManager::new(storage.clone()));
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        let session_id = session.session_id.clone();
+
+        let (sender, mut receiver) = mpsc::channel(10);
+        manager
+            .register_connection(&session_id, "client".to_string(), sender)
+            .await;
+
+        let requester = {
+            let manager = Arc::clone(&manager);
+            let session_id = session_id.clone();
+            tokio::spawn(async move {
+                manager
+                    .request_client(
+                        &session_id,
+                        "sampling/createMessage",
+                        serde_json::json!({"maxTokens": 10}),
+                        std::time::Duration::from_secs(5),
+                    )
+                    .await
+            })
+        };
+
+        let event = receiver.recv().await.unwrap();
+        assert_eq!(event.event_type, "sampling/createMessage");
+        assert_eq!(event.data["method"], "sampling/createMessage");
+        let request_id = event.data["id"].clone();
+
+        // Another session cannot answer this session's request
+        let response =
+            serde_json::json!({"jsonrpc": "2.0", "id": request_id, "result": {"ok": true}});
+        assert!(!manager.deliver_client_response("other-session", response.clone()));
+        assert!(manager.deliver_client_response(&session_id, response.clone()));
+        assert!(!manager.deliver_client_response(&session_id, response.clone()));
+
+        assert_eq!(requester.await.unwrap().unwrap(), response);
+    }
+
+    #[tokio::test]
+    async fn test_request_client_times_out() {
+        let storage = Arc::new(InMemorySessionStorage::new());
+        let manager = StreamManager::new(storage.clone());
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+
+        let result = manager
+            .request_client(
+                &session.session_id,
+                "sampling/createMessage",
+                serde_json::json!({}),
+                std::time::Duration::from_millis(20),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(StreamError::ClientRequestTimeout { .. })
+        ));
+        assert!(manager.pending_client_requests.lock().unwrap().is_empty());
+    }
 }
@@ -21,9 +21,12 @@ use hyper::header::{ACCEPT, CONTENT_TYPE};
 use hyper::{HeaderMap, Method, Request, Response, StatusCode};
 use serde_json::Value;
 use tracing::{debug, error, info, warn};
+use turul_mcp_json_rpc_server::codec::{JsonCodec, JsonRpcCodec};
 use turul_mcp_session_storage::SessionView;
 
 use crate::ServerConfig;
+use crate::body_limit::{BodyReadError, read_body_limited};
+use crate::json_rpc_responses::payload_too_large_response;
 use crate::protocol::normalize_header_value;
 
 /// MCP Protocol versions
@@ -141,6 +144,8 @@ pub struct StreamableHttpContext {
     pub accepts_json: bool,
     /// Whether client accepts stream frames (application/json, text/event-stream, or */*)
     pub accepts_stream_frames: bool,
+    /// Whether client accepts NDJSON batch responses (application/x-ndjson)
+    pub accepts_ndjson: bool,
     /// Additional request headers
     pub headers: HashMap<String, String>,
 }
@@ -174,6 +179,7 @@ impl StreamableHttpContext {
         let accepts_json =
             accept_header.contains("application/json") || accept_header.contains("*/*");
         let accepts_stream_frames = accepts_json || accept_header.contains("text/event-stream");
+        let accepts_ndjson = accept_header.contains("application/x-ndjson");
 
         // Collect additional headers for debugging/logging
         let mut header_map = HashMap::new();
@@ -189,6 +195,7 @@ impl StreamableHttpContext {
             wants_sse_stream,
             accepts_json,
             accepts_stream_frames,
+            accepts_ndjson,
             headers: header_map,
         }
     }
@@ -419,6 +426,9 @@ pub struct StreamableHttpHandler {
     pub(crate) middleware_stack: Arc<crate::middleware::MiddlewareStack>,
     tool_fingerprint: Option<String>,
     tool_notifier: Option<Arc<dyn crate::ToolChangeNotifier>>,
+    request_interceptor: Option<crate::RequestInterceptor>,
+    #[cfg(feature = "otel")]
+    request_tracing: Option<crate::otel::RequestTracing>,
 }
 
 impl StreamableHttpHandler {
@@ -440,6 +450,9 @@ impl StreamableHttpHandler {
             middleware_stack,
             tool_fingerprint,
             tool_notifier: None,
+            request_interceptor: None,
+            #[cfg(feature = "otel")]
+            request_tracing: None,
         }
     }
 
@@ -449,6 +462,19 @@ impl StreamableHttpHandler {
         self
     }
 
+    /// Set the global request interceptor run before middleware and dispatch.
+    pub fn with_request_interceptor(mut self, interceptor: crate::RequestInterceptor) -> Self {
+        self.request_interceptor = Some(interceptor);
+        self
+    }
+
+    /// Trace each JSON-RPC request in an OpenTelemetry span
+    #[cfg(feature = "otel")]
+    pub fn with_request_tracing(mut self, tracing: crate::otel::RequestTracing) -> Self {
+        self.request_tracing = Some(tracing);
+        self
+    }
+
     /// Handle incoming HTTP request with streamable HTTP support
     pub async fn handle_request<T>(
         &self,
@@ -486,6 +512,14 @@ impl StreamableHttpHandler {
                 .map(|body| body.map_err(|never| match never {}).boxed_unsync());
         }
 
+        // POST bodies in a registered non-JSON codec (e.g. MessagePack) are answered in
+        // that codec, so the JSON/SSE Accept negotiation below does not apply to them
+        if *req.method() == Method::POST
+            && let Some(codec) = self.negotiated_codec(req.headers())
+        {
+            return self.handle_codec_message(req, context, codec).await;
+        }
+
         // Validate request
         if let Err(error) = context.validate(req.method()) {
             warn!("Invalid streamable HTTP request: {}", error);
@@ -504,10 +538,16 @@ impl StreamableHttpHandler {
                 self.handle_client_message(req, context).await
             }
             Method::GET => {
+                if let Some(rejection) = self.run_http_guards(&req, &context).await {
+                    return rejection;
+                }
                 // Optional SSE stream for server-initiated messages
                 self.handle_get_sse_notifications(req, context).await
             }
             Method::DELETE => {
+                if let Some(rejection) = self.run_http_guards(&req, &context).await {
+                    return rejection;
+                }
                 // Optional session cleanup
                 self.handle_session_delete(req, context).await
             }
@@ -615,6 +655,14 @@ impl StreamableHttpHandler {
                 // This maintains event replay from session storage and live streaming
                 streaming_response
             }
+            Err(err @ crate::StreamError::TooManyStreams { .. }) => {
+                warn!("Refusing SSE stream: {}", err);
+                StreamableResponse::Error {
+                    status: StatusCode::TOO_MANY_REQUESTS,
+                    message: err.to_string(),
+                }
+                .into_boxed_response(&context)
+            }
             Err(err) => {
                 error!("Failed to create streamable HTTP connection: {}", err);
                 StreamableResponse::Error {
@@ -745,9 +793,15 @@ impl StreamableHttpHandler {
         }
 
         // Read request body
-        let body_bytes = match req.into_body().collect().await {
-            Ok(collected) => collected.to_bytes(),
-            Err(_err) => {
+        let limit = self.config.max_body_size;
+        let body_bytes = match read_body_limited(req.into_body(), limit).await {
+            Ok(bytes) => bytes,
+            Err(BodyReadError::TooLarge) => {
+                warn!("Legacy POST request body exceeds {} bytes", limit);
+                return payload_too_large_response(limit)
+                    .map(|body| body.map_err(|never| match never {}).boxed_unsync());
+            }
+            Err(BodyReadError::Read) => {
                 error!("Failed to read legacy POST request body");
                 return StreamableResponse::Error {
                     status: StatusCode::BAD_REQUEST,
@@ -757,19 +811,6 @@ impl StreamableHttpHandler {
             }
         };
 
-        // Check body size
-        if body_bytes.len() > self.config.max_body_size {
-            warn!(
-                "Legacy POST request body too large: {} bytes",
-                body_bytes.len()
-            );
-            return StreamableResponse::Error {
-                status: StatusCode::PAYLOAD_TOO_LARGE,
-                message: "Request body too large".to_string(),
-            }
-            .into_boxed_response(&context);
-        }
-
         // Parse as UTF-8
         let body_str = match std::str::from_utf8(&body_bytes) {
             Ok(s) => s,
@@ -856,27 +897,28 @@ impl StreamableHttpHandler {
                         Err(err) => {
                             error!("Failed to create session during legacy initialize: {}", err);
                             let error_msg = format!("Session creation failed: {}", err);
-                            turul_mcp_json_rpc_server::JsonRpcMessage::error(
+                            Some(turul_mcp_json_rpc_server::JsonRpcMessage::error(
                                 turul_mcp_json_rpc_server::JsonRpcError::internal_error(
                                     Some(request.id),
                                     Some(error_msg),
                                 ),
-                            )
+                            ))
                         }
                     }
                 } else {
                     // For non-initialize requests, process without session context (legacy mode)
-                    self.dispatcher.handle_request(request).await
+                    Some(self.dispatcher.handle_request(request).await)
                 };
 
                 // Convert JsonRpcMessage to JsonRpcMessageResult
                 match response {
-                    turul_mcp_json_rpc_server::JsonRpcMessage::Response(resp) => {
+                    Some(turul_mcp_json_rpc_server::JsonRpcMessage::Response(resp)) => {
                         JsonRpcMessageResult::Response(resp)
                     }
-                    turul_mcp_json_rpc_server::JsonRpcMessage::Error(err) => {
+                    Some(turul_mcp_json_rpc_server::JsonRpcMessage::Error(err)) => {
                         JsonRpcMessageResult::Error(err)
                     }
+                    None => JsonRpcMessageResult::NoResponse,
                 }
             }
             JsonRpcMessage::Notification(notification) => {
@@ -901,14 +943,16 @@ impl StreamableHttpHandler {
         // 3. Return single JSON response (no streaming) - legacy compatibility
         match message_result {
             JsonRpcMessageResult::Response(response) => {
-                let response_json = serde_json::to_string(&response)
-                    .unwrap_or_else(|_| r#"{"error": "Failed to serialize response"}"#.to_string());
+                let response_json = crate::response_buffer::serialize_response(&response)
+                    .unwrap_or_else(|_| {
+                        Bytes::from_static(br#"{"error": "Failed to serialize response"}"#)
+                    });
 
                 Response::builder()
                     .status(StatusCode::OK)
                     .header(CONTENT_TYPE, "application/json")
                     .header("MCP-Protocol-Version", context.protocol_version.as_str())
-                    .body(Full::new(Bytes::from(response_json)))
+                    .body(Full::new(response_json))
                     .unwrap()
                     .map(|body| body.map_err(|never| match never {}).boxed_unsync())
             }
@@ -925,7 +969,7 @@ impl StreamableHttpHandler {
                     .map(|body| body.map_err(|never| match never {}).boxed_unsync())
             }
             JsonRpcMessageResult::NoResponse => {
-                // Notifications return 202 Accepted per MCP spec
+                // Notifications and cancelled requests return 202 Accepted
                 Response::builder()
                     .status(StatusCode::ACCEPTED)
                     .header("MCP-Protocol-Version", context.protocol_version.as_str())
@@ -1098,7 +1142,7 @@ impl StreamableHttpHandler {
     async fn handle_post_streamable_http<T>(
         &self,
         req: Request<T>,
-        mut context: StreamableHttpContext,
+        context: StreamableHttpContext,
     ) -> Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>>
     where
         T: Body + Send + 'static,
@@ -1106,9 +1150,15 @@ impl StreamableHttpHandler {
         debug!("Streaming handler called - using true streaming POST");
 
         // Parse request body (still need to collect for JSON-RPC parsing)
-        let body_bytes = match req.into_body().collect().await {
-            Ok(collected) => collected.to_bytes(),
-            Err(_err) => {
+        let limit = self.config.max_body_size;
+        let body_bytes = match read_body_limited(req.into_body(), limit).await {
+            Ok(bytes) => bytes,
+            Err(BodyReadError::TooLarge) => {
+                warn!("Streaming POST request body exceeds {} bytes", limit);
+                return payload_too_large_response(limit)
+                    .map(|body| body.map_err(|never| match never {}).boxed_unsync());
+            }
+            Err(BodyReadError::Read) => {
                 error!("Failed to read streaming POST request body");
                 return StreamableResponse::Error {
                     status: StatusCode::BAD_REQUEST,
@@ -1118,19 +1168,6 @@ impl StreamableHttpHandler {
             }
         };
 
-        // Check body size
-        if body_bytes.len() > self.config.max_body_size {
-            warn!(
-                "Streaming POST request body too large: {} bytes",
-                body_bytes.len()
-            );
-            return StreamableResponse::Error {
-                status: StatusCode::PAYLOAD_TOO_LARGE,
-                message: "Request body too large".to_string(),
-            }
-            .into_boxed_response(&context);
-        }
-
         // Parse as UTF-8
         let body_str = match std::str::from_utf8(&body_bytes) {
             Ok(s) => s,
@@ -1146,9 +1183,18 @@ impl StreamableHttpHandler {
 
         debug!("Streaming POST received JSON-RPC request: {}", body_str);
 
+        // Batches are only accepted from clients that opt in to NDJSON responses
+        if context.accepts_ndjson && body_str.trim_start().starts_with('[') {
+            return self.handle_ndjson_batch(body_str, context).await;
+        }
+
+        // Responses to server-initiated requests (e.g. sampling/createMessage)
+        if let Some(response) = parse_client_response(body_str) {
+            return self.accept_client_response(response, &context);
+        }
+
         // Parse JSON-RPC message
-        use turul_mcp_json_rpc_server::dispatch::{JsonRpcMessage, parse_json_rpc_message};
-        use turul_mcp_json_rpc_server::error::JsonRpcErrorObject;
+        use turul_mcp_json_rpc_server::dispatch::parse_json_rpc_message;
 
         let message = match parse_json_rpc_message(body_str) {
             Ok(msg) => msg,
@@ -1171,6 +1217,23 @@ impl StreamableHttpHandler {
             }
         };
 
+        self.handle_post_message(message, context, None).await
+    }
+
+    /// Process one parsed JSON-RPC request or notification from a POST
+    ///
+    /// Shared by JSON bodies and bodies in a negotiated codec. With a `codec`,
+    /// JSON-RPC replies are encoded with it instead of JSON; the caller has
+    /// already turned SSE off, so a request gets a single response body.
+    async fn handle_post_message(
+        &self,
+        message: turul_mcp_json_rpc_server::dispatch::JsonRpcMessage,
+        mut context: StreamableHttpContext,
+        codec: Option<Arc<dyn JsonRpcCodec>>,
+    ) -> Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>> {
+        use turul_mcp_json_rpc_server::dispatch::JsonRpcMessage;
+        use turul_mcp_json_rpc_server::error::JsonRpcErrorObject;
+
         // Handle sessionless ping (pre-init ping support per MCP 2025-11-25)
         // Clients are permitted to send pings before initialization completes.
         // When allowed by config, dispatch through the shared middleware + dispatch pipeline
@@ -1188,8 +1251,17 @@ impl StreamableHttpHandler {
                     let (response, _) = self
                         .run_middleware_and_dispatch(request, context.headers.clone(), None, None)
                         .await;
-                    let response_value =
-                        serde_json::to_value(&response).unwrap_or(serde_json::json!({}));
+                    if let (Some(codec), Some(response)) = (&codec, &response) {
+                        return encoded_reply_response(
+                            StatusCode::OK,
+                            response,
+                            codec.as_ref(),
+                            &context,
+                        );
+                    }
+                    let response_value = response
+                        .and_then(|response| serde_json::to_value(&response).ok())
+                        .unwrap_or(serde_json::json!({}));
                     StreamableResponse::Json(response_value).into_boxed_response(&context)
                 }
                 JsonRpcMessage::Notification(notification) => {
@@ -1218,71 +1290,50 @@ impl StreamableHttpHandler {
         }
 
         // --- Pre-session auth phase (D4) ---
-        // Extract Bearer token using hardened parser (D6)
-        let bearer_token = context
-            .headers
-            .get("authorization")
-            .and_then(|v| extract_bearer_token(v));
-
-        // Run pre-session middleware if any are registered
-        let pre_session_extensions = if self.middleware_stack.has_pre_session_middleware() {
-            let method_name = match &message {
-                JsonRpcMessage::Request(req) => req.method.as_str(),
-                JsonRpcMessage::Notification(notif) => notif.method.as_str(),
-            };
-            let mut pre_ctx = crate::middleware::RequestContext::new(method_name, None);
-            if let Some(ref token) = bearer_token {
-                pre_ctx.set_bearer_token(token.clone());
-            }
-            // Copy headers to metadata, excluding Bearer authorization (D5)
-            for (k, v) in &context.headers {
-                if k.eq_ignore_ascii_case("authorization") && is_bearer_scheme(v) {
-                    continue;
-                }
-                pre_ctx.add_metadata(k.clone(), serde_json::json!(v));
-            }
-            match self
-                .middleware_stack
-                .execute_before_session(&mut pre_ctx)
-                .await
-            {
-                Ok(()) => Some(pre_ctx.take_extensions()),
-                Err(crate::middleware::MiddlewareError::HttpChallenge {
+        let pre_session_extensions = match self
+            .run_pre_session_middleware(message.method(), &context)
+            .await
+        {
+            Ok(extensions) => extensions,
+            Err(crate::middleware::MiddlewareError::HttpChallenge {
+                status,
+                www_authenticate,
+                body,
+            }) => {
+                return build_http_challenge_response(
                     status,
-                    www_authenticate,
-                    body,
-                }) => {
-                    return build_http_challenge_response(
-                        status,
-                        &www_authenticate,
-                        body.as_deref(),
-                        &context,
-                    );
-                }
-                Err(other_err) => {
-                    // Non-challenge pre-session errors → JSON-RPC error
-                    if let JsonRpcMessage::Request(ref req) = message {
-                        let response =
-                            Self::map_middleware_error_to_jsonrpc(other_err, req.id.clone());
-                        let response_value =
-                            serde_json::to_value(&response).unwrap_or(serde_json::json!({}));
-                        return StreamableResponse::Json(response_value)
-                            .into_boxed_response(&context);
-                    } else {
-                        // Notification — can't return JSON-RPC error, just reject
-                        return Response::builder()
-                            .status(StatusCode::FORBIDDEN)
-                            .body(
-                                Full::new(Bytes::from(other_err.to_string()))
-                                    .map_err(|never| match never {})
-                                    .boxed_unsync(),
-                            )
-                            .unwrap();
+                    &www_authenticate,
+                    body.as_deref(),
+                    &context,
+                );
+            }
+            Err(other_err) => {
+                // Non-challenge pre-session errors → JSON-RPC error
+                if let JsonRpcMessage::Request(ref req) = message {
+                    let response = Self::map_middleware_error_to_jsonrpc(other_err, req.id.clone());
+                    if let Some(codec) = &codec {
+                        return encoded_reply_response(
+                            StatusCode::OK,
+                            &response,
+                            codec.as_ref(),
+                            &context,
+                        );
                     }
+                    let response_value =
+                        serde_json::to_value(&response).unwrap_or(serde_json::json!({}));
+                    return StreamableResponse::Json(response_value).into_boxed_response(&context);
+                } else {
+                    // Notification — can't return JSON-RPC error, just reject
+                    return Response::builder()
+                        .status(StatusCode::FORBIDDEN)
+                        .body(
+                            Full::new(Bytes::from(other_err.to_string()))
+                                .map_err(|never| match never {})
+                                .boxed_unsync(),
+                        )
+                        .unwrap();
                 }
             }
-        } else {
-            None
         };
 
         // Validate session requirements based on method
@@ -1366,6 +1417,15 @@ impl StreamableHttpHandler {
                         ),
                     );
 
+                    if let Some(codec) = &codec {
+                        return encoded_reply_response(
+                            StatusCode::UNAUTHORIZED,
+                            &turul_mcp_json_rpc_server::JsonRpcMessage::Error(error_response),
+                            codec.as_ref(),
+                            &context,
+                        );
+                    }
+
                     let error_json =
                         serde_json::to_string(&error_response).unwrap_or_else(|_| "{}".to_string());
 
@@ -1397,6 +1457,7 @@ impl StreamableHttpHandler {
                     session_id,
                     context,
                     pre_session_extensions.clone(),
+                    codec,
                 )
                 .await
             }
@@ -1476,6 +1537,341 @@ impl StreamableHttpHandler {
         }
     }
 
+    /// Route a client's JSON-RPC response to the server-initiated request awaiting it
+    ///
+    /// 202 Accepted when it answers a pending request of this session, 400 otherwise.
+    fn accept_client_response(
+        &self,
+        response: Value,
+        context: &StreamableHttpContext,
+    ) -> Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>> {
+        let delivered = context.session_id.as_deref().is_some_and(|session_id| {
+            self.stream_manager
+                .deliver_client_response(session_id, response.clone())
+        });
+        if !delivered {
+            warn!(
+                "Client response {} matches no pending server request",
+                response["id"]
+            );
+            return StreamableResponse::Error {
+                status: StatusCode::BAD_REQUEST,
+                message: "Response does not match a pending server request".to_string(),
+            }
+            .into_boxed_response(context);
+        }
+
+        Response::builder()
+            .status(StatusCode::ACCEPTED)
+            .header("MCP-Protocol-Version", context.protocol_version.as_str())
+            .body(
+                Full::new(Bytes::new())
+                    .map_err(|never| match never {})
+                    .boxed_unsync(),
+            )
+            .unwrap()
+    }
+
+    /// Run pre-session middleware (D4) for one message, returning the extensions it set.
+    ///
+    /// Returns `Ok(None)` when no pre-session middleware is registered.
+    /// Run authentication guards for a GET or DELETE request
+    ///
+    /// Returns the response to send when a guard rejects the request.
+    async fn run_http_guards<B>(
+        &self,
+        req: &Request<B>,
+        context: &StreamableHttpContext,
+    ) -> Option<Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>>> {
+        match self
+            .middleware_stack
+            .execute_http_guards(req.method().as_str(), req.headers())
+            .await
+        {
+            Ok(()) => None,
+            Err(crate::middleware::MiddlewareError::HttpChallenge {
+                status,
+                www_authenticate,
+                body,
+            }) => Some(build_http_challenge_response(
+                status,
+                &www_authenticate,
+                body.as_deref(),
+                context,
+            )),
+            Err(err) => {
+                debug!("{} rejected by middleware: {}", req.method(), err);
+                Some(
+                    Response::builder()
+                        .status(StatusCode::FORBIDDEN)
+                        .body(
+                            Full::new(Bytes::from(err.to_string()))
+                                .map_err(|never| match never {})
+                                .boxed_unsync(),
+                        )
+                        .unwrap(),
+                )
+            }
+        }
+    }
+
+    async fn run_pre_session_middleware(
+        &self,
+        method_name: &str,
+        context: &StreamableHttpContext,
+    ) -> std::result::Result<
+        Option<HashMap<String, serde_json::Value>>,
+        crate::middleware::MiddlewareError,
+    > {
+        if !self.middleware_stack.has_pre_session_middleware() {
+            return Ok(None);
+        }
+
+        let mut pre_ctx = crate::middleware::RequestContext::new(method_name, None);
+        // Extract Bearer token using hardened parser (D6)
+        if let Some(token) = context
+            .headers
+            .get("authorization")
+            .and_then(|v| extract_bearer_token(v))
+        {
+            pre_ctx.set_bearer_token(token);
+        }
+        // Copy headers to metadata, excluding Bearer authorization (D5)
+        for (k, v) in &context.headers {
+            if k.eq_ignore_ascii_case("authorization") && is_bearer_scheme(v) {
+                continue;
+            }
+            pre_ctx.add_metadata(k.clone(), serde_json::json!(v));
+        }
+        self.middleware_stack
+            .execute_before_session(&mut pre_ctx)
+            .await?;
+        Ok(Some(pre_ctx.take_extensions()))
+    }
+
+    /// Handle a JSON-RPC batch POST with an NDJSON (`application/x-ndjson`) response.
+    ///
+    /// Every request in the batch is dispatched concurrently and its response is
+    /// written as one line the moment it completes, so a slow call never holds back
+    /// a fast one. Notifications are dispatched but produce no line. Batches require
+    /// an existing session; `initialize` cannot be batched.
+    async fn handle_ndjson_batch(
+        &self,
+        body_str: &str,
+        context: StreamableHttpContext,
+    ) -> Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>> {
+        use http_body_util::StreamBody;
+        use tokio_stream::StreamExt;
+        use tokio_stream::wrappers::UnboundedReceiverStream;
+        use turul_mcp_json_rpc_server::dispatch::{JsonRpcMessage, parse_json_rpc_messages};
+        use turul_mcp_json_rpc_server::error::{JsonRpcErrorCode, JsonRpcErrorObject};
+
+        let Some(session_id) = context.session_id.clone() else {
+            warn!("Missing session ID for NDJSON batch");
+            let error_response = turul_mcp_json_rpc_server::JsonRpcError::new(
+                None,
+                JsonRpcErrorObject::server_error(
+                    -32001,
+                    "Missing Mcp-Session-Id header. Call initialize first.",
+                    None::<serde_json::Value>,
+                ),
+            );
+            let error_json =
+                serde_json::to_string(&error_response).unwrap_or_else(|_| "{}".to_string());
+
+            return Response::builder()
+                .status(StatusCode::UNAUTHORIZED)
+                .header(CONTENT_TYPE, "application/json")
+                .header("MCP-Protocol-Version", context.protocol_version.as_str())
+                .body(
+                    Full::new(Bytes::from(error_json))
+                        .map_err(|never| match never {})
+                        .boxed_unsync(),
+                )
+                .unwrap();
+        };
+
+        if let Err(err) = self.validate_session_exists(&session_id).await {
+            warn!("Invalid session ID {}: {}", session_id, err.message());
+            return StreamableResponse::Error {
+                status: err.status_code(),
+                message: format!("Invalid or expired session: {}", err.message()),
+            }
+            .into_boxed_response(&context);
+        }
+
+        fn ndjson_line<S: serde::Serialize>(message: &S) -> Bytes {
+            let mut line = serde_json::to_string(message).unwrap_or_else(|_| "{}".to_string());
+            line.push('\n');
+            Bytes::from(line)
+        }
+
+        // Resolve parse errors, pre-session rejections and batched initialize up front;
+        // those lines are written before any dispatched response.
+        let mut early_lines = Vec::new();
+        let mut dispatches = Vec::new();
+        for parsed in parse_json_rpc_messages(body_str) {
+            let message = match parsed {
+                Ok(message) => message,
+                Err(rpc_err) => {
+                    early_lines.push(ndjson_line(&rpc_err));
+                    continue;
+                }
+            };
+
+            let pre_session_extensions = match self
+                .run_pre_session_middleware(message.method(), &context)
+                .await
+            {
+                Ok(extensions) => extensions,
+                Err(crate::middleware::MiddlewareError::HttpChallenge {
+                    status,
+                    www_authenticate,
+                    body,
+                }) => {
+                    // A challenge applies to the whole HTTP request, not one line
+                    return build_http_challenge_response(
+                        status,
+                        &www_authenticate,
+                        body.as_deref(),
+                        &context,
+                    );
+                }
+                Err(other_err) => {
+                    if let JsonRpcMessage::Request(req) = &message {
+                        early_lines.push(ndjson_line(&Self::map_middleware_error_to_jsonrpc(
+                            other_err,
+                            req.id.clone(),
+                        )));
+                    }
+                    continue;
+                }
+            };
+
+            if let JsonRpcMessage::Request(req) = &message
+                && req.method == "initialize"
+            {
+                early_lines.push(ndjson_line(&turul_mcp_json_rpc_server::JsonRpcError::new(
+                    Some(req.id.clone()),
+                    JsonRpcErrorObject::new(
+                        JsonRpcErrorCode::InvalidRequest,
+                        Some("initialize cannot be sent in a batch".to_string()),
+                        None,
+                    ),
+                )));
+                continue;
+            }
+
+            dispatches.push((message, pre_session_extensions));
+        }
+
+        debug!(
+            "Dispatching NDJSON batch of {} message(s) for session: {}",
+            dispatches.len(),
+            session_id
+        );
+
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<Result<Bytes, hyper::Error>>();
+        for line in early_lines {
+            let _ = tx.send(Ok(line));
+        }
+
+        for (message, pre_session_extensions) in dispatches {
+            let session_context = self.broadcasting_session_context(&session_id);
+            match message {
+                JsonRpcMessage::Request(request) => {
+                    let sender = tx.clone();
+                    let headers = context.headers.clone();
+                    let self_clone = self.clone();
+                    tokio::spawn(async move {
+                        let request_id = request.id.clone();
+                        let dispatch = self_clone.run_middleware_and_dispatch(
+                            request,
+                            headers,
+                            Some(session_context),
+                            pre_session_extensions,
+                        );
+                        // As for a single POST, a disconnected client abandons the call
+                        let (response, _) = tokio::select! {
+                            result = dispatch => result,
+                            _ = sender.closed() => {
+                                debug!(
+                                    "NDJSON client disconnected before request {:?} completed, abandoning it",
+                                    request_id
+                                );
+                                return;
+                            }
+                        };
+                        // A cancelled request writes no line
+                        let Some(response) = response else {
+                            return;
+                        };
+                        if sender.send(Ok(ndjson_line(&response))).is_err() {
+                            debug!(
+                                "NDJSON client disconnected before response for request ID: {:?}",
+                                request_id
+                            );
+                        }
+                    });
+                }
+                JsonRpcMessage::Notification(notification) => {
+                    let dispatcher = Arc::clone(&self.dispatcher);
+                    tokio::spawn(async move {
+                        if let Err(e) = dispatcher
+                            .handle_notification_with_context(notification, Some(session_context))
+                            .await
+                        {
+                            error!("Failed to process batched notification: {}", e);
+                        }
+                    });
+                }
+            }
+        }
+        // The body ends once every spawned request has written its line
+        drop(tx);
+
+        let body_stream =
+            UnboundedReceiverStream::new(rx).map(|item| item.map(http_body::Frame::data));
+        let mut response = Response::builder()
+            .status(StatusCode::OK)
+            .header(CONTENT_TYPE, "application/x-ndjson")
+            .header("Transfer-Encoding", "chunked")
+            .header("Cache-Control", "no-cache")
+            .body(http_body_util::BodyExt::boxed_unsync(StreamBody::new(
+                body_stream,
+            )))
+            .unwrap();
+
+        for (key, value) in context.response_headers().iter() {
+            response.headers_mut().insert(key, value.clone());
+        }
+
+        response
+    }
+
+    /// Build a JSON-RPC session context whose notifications are routed through the StreamManager
+    fn broadcasting_session_context(
+        &self,
+        session_id: &str,
+    ) -> turul_mcp_json_rpc_server::SessionContext {
+        use crate::notification_bridge::{
+            SharedNotificationBroadcaster, StreamManagerNotificationBroadcaster,
+        };
+
+        let broadcaster: SharedNotificationBroadcaster = Arc::new(
+            StreamManagerNotificationBroadcaster::new(Arc::clone(&self.stream_manager)),
+        );
+        let broadcaster_any = Arc::new(broadcaster) as Arc<dyn std::any::Any + Send + Sync>;
+
+        turul_mcp_json_rpc_server::SessionContext {
+            session_id: session_id.to_string(),
+            metadata: HashMap::new(),
+            broadcaster: Some(broadcaster_any),
+            timestamp: chrono::Utc::now().timestamp_millis() as u64,
+            extensions: HashMap::new(),
+        }
+    }
+
     /// Create a streaming response using hyper::Body::channel()
     /// This enables true progressive responses with Transfer-Encoding: chunked
     async fn create_streaming_response(
@@ -1484,6 +1880,7 @@ impl StreamableHttpHandler {
         session_id: String,
         context: StreamableHttpContext,
         pre_session_extensions: Option<HashMap<String, serde_json::Value>>,
+        codec: Option<Arc<dyn JsonRpcCodec>>,
     ) -> Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>> {
         debug!(
             "Creating streaming response for method: {}, session: {}",
@@ -1595,6 +1992,12 @@ impl StreamableHttpHandler {
                             // Handle explicit shutdown signal from main task
                             _ = &mut shutdown_rx => {
                                 debug!("🔍 Progress task: shutdown_rx branch fired! Received explicit shutdown signal for session: {}", session_id_clone);
+                                // Flush events emitted before the request finished
+                                while let Ok(sse_event) = progress_rx.try_recv() {
+                                    if sender_clone.send(Ok(Bytes::from(sse_event.format()))).is_err() {
+                                        break;
+                                    }
+                                }
                                 break;
                             }
                         }
@@ -1649,6 +2052,13 @@ impl StreamableHttpHandler {
         // Capture headers for middleware (clone before move into spawn)
         let headers = context.headers.clone();
         let self_clone = self.clone();
+        let content_type = if wants_sse {
+            "text/event-stream"
+        } else {
+            codec
+                .as_ref()
+                .map_or("application/json", |codec| codec.content_type())
+        };
 
         tokio::spawn(async move {
             debug!(
@@ -1658,14 +2068,33 @@ impl StreamableHttpHandler {
 
             // Process actual request through middleware pipeline
             // Injection is applied immediately inside run_middleware_and_dispatch
-            let (response, _) = self_clone
-                .run_middleware_and_dispatch(
-                    request,
-                    headers,
-                    Some(session_context),
-                    pre_session_extensions,
-                )
-                .await;
+            let dispatch = self_clone.run_middleware_and_dispatch(
+                request,
+                headers,
+                Some(session_context),
+                pre_session_extensions,
+            );
+            // A disconnected client drops the response body; dropping the dispatch
+            // with it lets the handler observe the cancellation
+            let (response, _) = tokio::select! {
+                result = dispatch => result,
+                _ = sender.closed() => {
+                    debug!(
+                        "Client disconnected before request {:?} completed, abandoning it",
+                        request_id
+                    );
+                    return;
+                }
+            };
+            // A request cancelled by the client gets no final frame; dropping the
+            // senders ends the stream
+            let Some(response) = response else {
+                debug!(
+                    "Request {:?} was cancelled, ending the stream without a response",
+                    request_id
+                );
+                return;
+            };
 
             // Send final result - format depends on client type
             if wants_sse {
@@ -1696,17 +2125,9 @@ impl StreamableHttpHandler {
                     }
                 };
 
-                let final_json = final_frame.to_json();
-                // SSE framing: data: {json}\n\n
-                let final_chunk =
-                    format!("data: {}\n\n", serde_json::to_string(&final_json).unwrap());
-
-                if let Err(err) = sender.send(Ok(Bytes::from(final_chunk))) {
-                    error!("Failed to send SSE final chunk: {}", err);
-                }
-
                 // CRITICAL: Send explicit shutdown signal to progress forwarding task (SSE only)
-                // This breaks it out of the progress_rx.recv().await loop immediately
+                // It flushes events the request emitted and exits, so progress frames are
+                // written before the final result rather than racing it
                 if let Some(shutdown_tx) = shutdown_tx {
                     debug!(
                         "🔍 Main task sending shutdown signal to progress task for request: {:?}",
@@ -1762,11 +2183,34 @@ impl StreamableHttpHandler {
                         request_id
                     );
                 }
+
+                let final_json = final_frame.to_json();
+                // SSE framing: data: {json}\n\n
+                let final_chunk =
+                    format!("data: {}\n\n", serde_json::to_string(&final_json).unwrap());
+
+                if let Err(err) = sender.send(Ok(Bytes::from(final_chunk))) {
+                    error!("Failed to send SSE final chunk: {}", err);
+                }
+            } else if let Some(codec) = codec {
+                // Bodies in a negotiated codec are answered in the same codec
+                match codec.encode_reply(&response) {
+                    Ok(encoded) => {
+                        if let Err(err) = sender.send(Ok(Bytes::from(encoded))) {
+                            error!(
+                                "Failed to send final {} response: {}",
+                                codec.content_type(),
+                                err
+                            );
+                        }
+                    }
+                    Err(err) => error!("Failed to encode final response: {}", err),
+                }
             } else {
                 // For JSON-only clients, send as regular JSON-RPC response (no streaming frames)
-                let final_json = serde_json::to_string(&response).unwrap();
+                let final_json = crate::response_buffer::serialize_response(&response).unwrap();
 
-                if let Err(err) = sender.send(Ok(Bytes::from(final_json))) {
+                if let Err(err) = sender.send(Ok(final_json)) {
                     error!("Failed to send final JSON response: {}", err);
                 }
             }
@@ -1786,12 +2230,6 @@ impl StreamableHttpHandler {
 
         // Build response with MCP headers merged from context
         // Content-Type must match the framing decision made by wants_sse above
-        let content_type = if wants_sse {
-            "text/event-stream"
-        } else {
-            "application/json"
-        };
-
         let mut response = Response::builder()
             .status(StatusCode::OK)
             .header(CONTENT_TYPE, content_type)
@@ -1890,6 +2328,111 @@ impl StreamableHttpHandler {
         return self.handle_post_streamable_http(req, context).await;
     }
 
+    /// The non-JSON codec named by the request's `Content-Type`, if one is registered
+    fn negotiated_codec(&self, headers: &HeaderMap) -> Option<Arc<dyn JsonRpcCodec>> {
+        let content_type = headers.get(CONTENT_TYPE)?.to_str().ok()?;
+        if JsonCodec.matches(content_type) {
+            return None;
+        }
+        self.dispatcher.codec_for(content_type)
+    }
+
+    /// Handle a POST whose body is encoded with a non-JSON codec
+    ///
+    /// The codec decodes the body straight into a request or notification, which
+    /// then goes through the same session, middleware and dispatch path as a JSON
+    /// POST. A request always gets a single response (never SSE) encoded with the
+    /// same codec. Batches and replies to server-initiated requests are JSON only.
+    async fn handle_codec_message<T>(
+        &self,
+        req: Request<T>,
+        mut context: StreamableHttpContext,
+        codec: Arc<dyn JsonRpcCodec>,
+    ) -> Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>>
+    where
+        T: Body + Send + 'static,
+    {
+        let limit = self.config.max_body_size;
+        let body_bytes = match read_body_limited(req.into_body(), limit).await {
+            Ok(bytes) => bytes,
+            Err(BodyReadError::TooLarge) => {
+                warn!(
+                    "{} POST request body exceeds {} bytes",
+                    codec.content_type(),
+                    limit
+                );
+                return payload_too_large_response(limit)
+                    .map(|body| body.map_err(|never| match never {}).boxed_unsync());
+            }
+            Err(BodyReadError::Read) => {
+                error!("Failed to read {} POST request body", codec.content_type());
+                return StreamableResponse::Error {
+                    status: StatusCode::BAD_REQUEST,
+                    message: "Failed to read request body".to_string(),
+                }
+                .into_boxed_response(&context);
+            }
+        };
+
+        let message = match codec.decode_message(&body_bytes) {
+            Ok(message) => message,
+            Err(rpc_err) => {
+                warn!(
+                    "Undecodable {} POST body: {}",
+                    codec.content_type(),
+                    rpc_err
+                );
+                // No session header for parse errors; JSON-RPC errors still use 200 OK
+                context.session_id = None;
+                return encoded_reply_response(
+                    StatusCode::OK,
+                    &turul_mcp_json_rpc_server::JsonRpcMessage::Error(rpc_err),
+                    codec.as_ref(),
+                    &context,
+                );
+            }
+        };
+
+        // One response body per request, whatever the Accept header says
+        context.wants_sse_stream = false;
+        context.accepts_json = true;
+        context.accepts_stream_frames = true;
+        context.accepts_ndjson = false;
+
+        self.handle_post_message(message, context, Some(codec))
+            .await
+    }
+
+    /// Run middleware and dispatch, inside a request span when tracing is enabled
+    async fn run_middleware_and_dispatch(
+        &self,
+        request: turul_mcp_json_rpc_server::JsonRpcRequest,
+        headers: HashMap<String, String>,
+        session: Option<turul_mcp_json_rpc_server::SessionContext>,
+        pre_session_extensions: Option<HashMap<String, serde_json::Value>>,
+    ) -> (
+        Option<turul_mcp_json_rpc_server::JsonRpcMessage>,
+        Option<crate::middleware::SessionInjection>,
+    ) {
+        #[cfg(feature = "otel")]
+        if let Some(tracing) = &self.request_tracing {
+            use opentelemetry::trace::FutureExt;
+            let cx = tracing.start(
+                &request,
+                &headers,
+                session.as_ref().map(|s| s.session_id.as_str()),
+            );
+            let outcome = self
+                .dispatch_through_middleware(request, headers, session, pre_session_extensions)
+                .with_context(cx.clone())
+                .await;
+            tracing.finish(&cx, outcome.0.as_ref());
+            return outcome;
+        }
+        self.dispatch_through_middleware(request, headers, session, pre_session_extensions)
+            .await
+    }
+
     /// Run middleware stack around dispatcher call
     ///
     /// This helper:
@@ -1901,19 +2444,34 @@ impl StreamableHttpHandler {
     /// 6. Calls dispatcher
     /// 7. Executes after_dispatch
     ///
-    /// Returns (JsonRpcMessage, Option<SessionInjection>) where the injection
+    /// Returns (Option<JsonRpcMessage>, Option<SessionInjection>) where the
+    /// message is None when the client cancelled the request, and the injection
     /// is Some when session was None (initialize case) and needs to be applied
     /// after session creation.
-    async fn run_middleware_and_dispatch(
+    async fn dispatch_through_middleware(
         &self,
-        request: turul_mcp_json_rpc_server::JsonRpcRequest,
+        mut request: turul_mcp_json_rpc_server::JsonRpcRequest,
         headers: HashMap<String, String>,
         session: Option<turul_mcp_json_rpc_server::SessionContext>,
         pre_session_extensions: Option<HashMap<String, serde_json::Value>>,
     ) -> (
-        turul_mcp_json_rpc_server::JsonRpcMessage,
+        Option<turul_mcp_json_rpc_server::JsonRpcMessage>,
         Option<crate::middleware::SessionInjection>,
     ) {
+        // Global request interceptor runs before middleware sees the request
+        if let Some(rejection) = crate::interceptor::apply_request_interceptor(
+            self.request_interceptor.as_ref(),
+            &mut request,
+        ) {
+            return (Some(rejection), None);
+        }
+
+        // Idempotency-Key reaches the tools/call handler as a request extension
+        let session = session.map(|mut s| {
+            crate::idempotency::forward_idempotency_key(&headers, &mut s.extensions);
+            s
+        });
+
         // Fast path: if middleware stack is empty, dispatch directly
         if self.middleware_stack.is_empty() {
             let result = if let Some(session_ctx) = session {
@@ -1921,7 +2479,7 @@ impl StreamableHttpHandler {
                     .handle_request_with_context(request, session_ctx)
                     .await
             } else {
-                self.dispatcher.handle_request(request).await
+                Some(self.dispatcher.handle_request(request).await)
             };
             return (result, None);
         }
@@ -1978,7 +2536,10 @@ impl StreamableHttpHandler {
         {
             Ok(inj) => inj,
             Err(err) => {
-                return (Self::map_middleware_error_to_jsonrpc(err, request.id), None);
+                return (
+                    Some(Self::map_middleware_error_to_jsonrpc(err, request.id)),
+                    None,
+                );
             }
         };
 
@@ -2000,20 +2561,23 @@ impl StreamableHttpHandler {
 
         // Thread extensions from RequestContext → JSON-RPC SessionContext (D3 canonical flow)
         let session = session.map(|mut s| {
-            s.extensions = ctx.extensions().clone();
+            s.extensions.extend(ctx.extensions().clone());
             s
         });
 
         // Save request ID before dispatch consumes the request
         let request_id = request.id.clone();
 
-        // Dispatch the request
+        // Dispatch the request; a cancelled request skips after_dispatch
         let result = if let Some(session_ctx) = session {
             self.dispatcher
                 .handle_request_with_context(request, session_ctx)
                 .await
         } else {
-            self.dispatcher.handle_request(request).await
+            Some(self.dispatcher.handle_request(request).await)
+        };
+        let Some(result) = result else {
+            return (None, None);
         };
 
         // Execute after_dispatch
@@ -2038,10 +2602,13 @@ impl StreamableHttpHandler {
         {
             Ok(()) => {
                 let result = Self::apply_dispatcher_result(result, dispatcher_result);
-                (result, None)
+                (Some(result), None)
             }
             Err(middleware_err) => (
-                Self::map_middleware_error_to_jsonrpc(middleware_err, request_id),
+                Some(Self::map_middleware_error_to_jsonrpc(
+                    middleware_err,
+                    request_id,
+                )),
                 None,
             ),
         }
@@ -2143,6 +2710,50 @@ impl StreamableHttpHandler {
 
 use crate::middleware::bearer::{extract_bearer_token, is_bearer_scheme};
 
+/// A JSON-RPC reply encoded with a negotiated codec, with the MCP response headers
+fn encoded_reply_response(
+    status: StatusCode,
+    reply: &turul_mcp_json_rpc_server::JsonRpcMessage,
+    codec: &dyn JsonRpcCodec,
+    context: &StreamableHttpContext,
+) -> Response<http_body_util::combinators::UnsyncBoxBody<Bytes, hyper::Error>> {
+    let body = match codec.encode_reply(reply) {
+        Ok(body) => body,
+        Err(err) => {
+            error!("Failed to encode reply: {}", err);
+            return StreamableResponse::Error {
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                message: "Failed to encode response".to_string(),
+            }
+            .into_boxed_response(context);
+        }
+    };
+
+    let mut response = Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, codec.content_type())
+        .body(
+            Full::new(Bytes::from(body))
+                .map_err(|never| match never {})
+                .boxed_unsync(),
+        )
+        .unwrap();
+    for (key, value) in context.response_headers().iter() {
+        response.headers_mut().insert(key, value.clone());
+    }
+    response
+}
+
+/// Parse a POST body that is a JSON-RPC response (`id` plus `result` or `error`, no `method`)
+fn parse_client_response(body: &str) -> Option<Value> {
+    let value: Value = serde_json::from_str(body).ok()?;
+    let object = value.as_object()?;
+    let is_response = !object.contains_key("method")
+        && object.contains_key("id")
+        && (object.contains_key("result") || object.contains_key("error"));
+    is_response.then_some(value)
+}
+
 /// Build an HTTP challenge response (401/403 with WWW-Authenticate header).
 ///
 /// Returns a raw HTTP response — never enters the JSON-RPC layer.
@@ -2216,6 +2827,7 @@ mod tests {
             wants_sse_stream: true,
             accepts_json: true,
             accepts_stream_frames: true,
+            accepts_ndjson: false,
             headers: HashMap::new(),
         };
 
@@ -0,0 +1,221 @@
+//! In-process TLS termination with rustls (`tls` feature)
+//!
+//! [`HttpMcpServerBuilder::tls`](crate::HttpMcpServerBuilder::tls) serves the MCP
+//! endpoint over HTTPS from a PEM certificate chain and private key, without a
+//! reverse proxy in front. Certificates loaded from files can be re-read while the
+//! server runs with [`HttpMcpServer::reload_tls`](crate::HttpMcpServer::reload_tls),
+//! or on `SIGHUP` with [`TlsConfig::reload_on_sighup`]; connections already open
+//! keep the certificate they were accepted with.
+//!
+//! A prebuilt [`rustls::ServerConfig`] can be supplied instead with
+//! [`HttpMcpServerBuilder::with_rustls_config`](crate::HttpMcpServerBuilder::with_rustls_config),
+//! e.g. to require client certificates. Whichever way the configuration is made,
+//! connections that negotiate `h2` through ALPN are served over HTTP/2 and all
+//! others over HTTP/1.1.
+
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::TlsAcceptor;
+
+use crate::Result;
+
+/// ALPN protocol id for HTTP/2
+pub(crate) const ALPN_H2: &[u8] = b"h2";
+
+/// ALPN protocol id for HTTP/1.1
+const ALPN_HTTP_1_1: &[u8] = b"http/1.1";
+
+/// How the HTTP server terminates TLS
+#[derive(Clone)]
+pub struct TlsConfig {
+    source: TlsSource,
+    http2: bool,
+    reload_on_sighup: bool,
+}
+
+#[derive(Clone)]
+enum TlsSource {
+    PemFiles {
+        cert_path: PathBuf,
+        key_path: PathBuf,
+    },
+    Rustls(Arc<rustls::ServerConfig>),
+}
+
+impl TlsConfig {
+    /// Serve the certificate chain in `cert_path` with the private key in `key_path`
+    ///
+    /// Both files are PEM. The chain starts with the server's own certificate; the
+    /// key may be PKCS#8, PKCS#1 (RSA) or SEC1 (EC). The files are read when the
+    /// server starts, so a missing or malformed file fails `run()`.
+    pub fn from_pem_files(cert_path: impl Into<PathBuf>, key_path: impl Into<PathBuf>) -> Self {
+        Self {
+            source: TlsSource::PemFiles {
+                cert_path: cert_path.into(),
+                key_path: key_path.into(),
+            },
+            http2: false,
+            reload_on_sighup: false,
+        }
+    }
+
+    /// Serve with a prebuilt rustls configuration, used as is
+    ///
+    /// Set `alpn_protocols` on it to offer HTTP/2. It cannot be reloaded.
+    pub fn from_rustls_config(config: Arc<rustls::ServerConfig>) -> Self {
+        Self {
+            source: TlsSource::Rustls(config),
+            http2: false,
+            reload_on_sighup: false,
+        }
+    }
+
+    /// Offer HTTP/2 through ALPN alongside HTTP/1.1 (default: HTTP/1.1 only)
+    ///
+    /// Only applies to certificates loaded from PEM files.
+    pub fn http2(mut self, enable: bool) -> Self {
+        self.http2 = enable;
+        self
+    }
+
+    /// Re-read the PEM files whenever the process receives `SIGHUP` (default: off)
+    ///
+    /// A reload that fails is logged and the previous certificate stays in use.
+    /// Has no effect on non-Unix platforms or with a prebuilt rustls configuration.
+    pub fn reload_on_sighup(mut self, enable: bool) -> Self {
+        self.reload_on_sighup = enable;
+        self
+    }
+
+    pub(crate) fn reloads_on_sighup(&self) -> bool {
+        self.reload_on_sighup && matches!(self.source, TlsSource::PemFiles { .. })
+    }
+
+    /// Build the rustls configuration this describes
+    fn server_config(&self) -> Result<Arc<rustls::ServerConfig>> {
+        match &self.source {
+            TlsSource::Rustls(config) => Ok(Arc::clone(config)),
+            TlsSource::PemFiles {
+                cert_path,
+                key_path,
+            } => {
+                let certs = CertificateDer::pem_file_iter(cert_path)
+                    .and_then(|certs| certs.collect::<std::result::Result<Vec<_>, _>>())
+                    .map_err(|e| {
+                        invalid_input(format!(
+                            "Failed to read certificates from {}: {}",
+                            cert_path.display(),
+                            e
+                        ))
+                    })?;
+                if certs.is_empty() {
+                    return Err(invalid_input(format!(
+                        "No certificates found in {}",
+                        cert_path.display()
+                    )));
+                }
+                let key = PrivateKeyDer::from_pem_file(key_path).map_err(|e| {
+                    invalid_input(format!(
+                        "Failed to read private key from {}: {}",
+                        key_path.display(),
+                        e
+                    ))
+                })?;
+
+                let mut config = rustls::ServerConfig::builder_with_provider(Arc::new(
+                    rustls::crypto::ring::default_provider(),
+                ))
+                .with_safe_default_protocol_versions()
+                .map_err(|e| invalid_input(format!("Unsupported TLS versions: {}", e)))?
+                .with_no_client_auth()
+                .with_single_cert(certs, key)
+                .map_err(|e| invalid_input(format!("Invalid certificate or key: {}", e)))?;
+                config.alpn_protocols = if self.http2 {
+                    vec![ALPN_H2.to_vec(), ALPN_HTTP_1_1.to_vec()]
+                } else {
+                    vec![ALPN_HTTP_1_1.to_vec()]
+                };
+                Ok(Arc::new(config))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for TlsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("TlsConfig");
+        match &self.source {
+            TlsSource::PemFiles {
+                cert_path,
+                key_path,
+            } => debug
+                .field("cert_path", cert_path)
+                .field("key_path", key_path),
+            TlsSource::Rustls(_) => debug.field("rustls_config", &".."),
+        };
+        debug
+            .field("http2", &self.http2)
+            .field("reload_on_sighup", &self.reload_on_sighup)
+            .finish()
+    }
+}
+
+/// The acceptor new connections are handshaken with, swapped on reload
+#[derive(Clone)]
+pub(crate) struct TlsTerminator {
+    config: TlsConfig,
+    acceptor: Arc<RwLock<Option<TlsAcceptor>>>,
+}
+
+impl TlsTerminator {
+    pub(crate) fn new(config: TlsConfig) -> Self {
+        Self {
+            config,
+            acceptor: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    pub(crate) fn config(&self) -> &TlsConfig {
+        &self.config
+    }
+
+    /// Load the certificate, replacing the one new connections are accepted with
+    ///
+    /// On error the previous certificate, if any, stays in use.
+    pub(crate) fn reload(&self) -> Result<()> {
+        let acceptor = TlsAcceptor::from(self.config.server_config()?);
+        *self.acceptor.write().unwrap() = Some(acceptor);
+        Ok(())
+    }
+
+    /// Acceptor for a new connection; `None` until the first successful load
+    pub(crate) fn acceptor(&self) -> Option<TlsAcceptor> {
+        self.acceptor.read().unwrap().clone()
+    }
+}
+
+/// Reload `terminator` every time the process receives `SIGHUP`
+#[cfg(unix)]
+pub(crate) fn reload_on_sighup(terminator: TlsTerminator) -> Result<()> {
+    use tokio::signal::unix::{SignalKind, signal};
+    use tracing::{info, warn};
+
+    let mut hangups = signal(SignalKind::hangup())?;
+    tokio::spawn(async move {
+        while hangups.recv().await.is_some() {
+            match terminator.reload() {
+                Ok(()) => info!("Reloaded TLS certificate on SIGHUP"),
+                Err(err) => warn!("Keeping previous TLS certificate, reload failed: {}", err),
+            }
+        }
+    });
+    Ok(())
+}
+
+pub(crate) fn invalid_input(message: String) -> crate::HttpMcpError {
+    io::Error::new(io::ErrorKind::InvalidInput, message).into()
+}
@@ -0,0 +1,350 @@
+//! Wire-level JSON-RPC logging for protocol debugging
+//!
+//! Enable with [`HttpMcpServerBuilder::log_wire_json`](crate::HttpMcpServerBuilder::log_wire_json)
+//! and every JSON-RPC message crossing the MCP endpoint is emitted as a tracing event
+//! with target [`WIRE_LOG_TARGET`] at the chosen level:
+//!
+//! - inbound POST bodies (one event per message, batches included)
+//! - outbound `application/json` responses
+//! - outbound SSE `data:` payloads on both GET and POST streams
+//!
+//! Each event carries `direction` (`"inbound"`/`"outbound"`), `transport`
+//! (`"http"`/`"sse"`), `method` and `id` when the message has them, `session_id`
+//! when known, and `json`: the message with every object field named in the
+//! redaction list replaced by [`REDACTED`]. Key matching ignores ASCII case.
+//!
+//! When the feature is off nothing is wrapped; when it is on but the level is
+//! filtered out by the subscriber, bodies pass through without being parsed.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, ready};
+
+use bytes::{Bytes, BytesMut};
+use http_body::{Body, Frame, SizeHint};
+use http_body_util::combinators::UnsyncBoxBody;
+use http_body_util::{BodyExt, Full};
+use hyper::header::{self, HeaderMap};
+use hyper::{Request, Response};
+use serde_json::Value;
+use tracing::Level;
+
+/// Tracing target of wire log events
+pub const WIRE_LOG_TARGET: &str = "turul_mcp_wire";
+
+/// Replacement for redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Field names redacted unless overridden with
+/// [`HttpMcpServerBuilder::redact_wire_fields`](crate::HttpMcpServerBuilder::redact_wire_fields)
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "password",
+    "secret",
+    "token",
+    "access_token",
+    "refresh_token",
+    "api_key",
+    "apiKey",
+    "authorization",
+];
+
+pub(crate) fn default_redacted_fields() -> Vec<String> {
+    DEFAULT_REDACTED_FIELDS
+        .iter()
+        .map(|field| field.to_string())
+        .collect()
+}
+
+/// Emit one wire event at a level chosen at runtime
+macro_rules! wire_event {
+    ($level:expr, $($fields:tt)+) => {
+        match $level {
+            Level::TRACE => tracing::event!(target: WIRE_LOG_TARGET, Level::TRACE, $($fields)+),
+            Level::DEBUG => tracing::event!(target: WIRE_LOG_TARGET, Level::DEBUG, $($fields)+),
+            Level::INFO => tracing::event!(target: WIRE_LOG_TARGET, Level::INFO, $($fields)+),
+            Level::WARN => tracing::event!(target: WIRE_LOG_TARGET, Level::WARN, $($fields)+),
+            Level::ERROR => tracing::event!(target: WIRE_LOG_TARGET, Level::ERROR, $($fields)+),
+        }
+    };
+}
+
+/// Which way a message travelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Inbound,
+    Outbound,
+}
+
+impl Direction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Direction::Inbound => "inbound",
+            Direction::Outbound => "outbound",
+        }
+    }
+}
+
+/// Level and redaction list for wire logging
+#[derive(Debug, Clone)]
+pub(crate) struct WireLog {
+    level: Level,
+    redact: Arc<[String]>,
+}
+
+impl WireLog {
+    pub(crate) fn new(level: Level, redact: Vec<String>) -> Self {
+        Self {
+            level,
+            redact: redact.into(),
+        }
+    }
+
+    /// Whether the installed subscriber wants events at this level
+    fn enabled(&self) -> bool {
+        match self.level {
+            Level::TRACE => tracing::enabled!(target: WIRE_LOG_TARGET, Level::TRACE),
+            Level::DEBUG => tracing::enabled!(target: WIRE_LOG_TARGET, Level::DEBUG),
+            Level::INFO => tracing::enabled!(target: WIRE_LOG_TARGET, Level::INFO),
+            Level::WARN => tracing::enabled!(target: WIRE_LOG_TARGET, Level::WARN),
+            Level::ERROR => tracing::enabled!(target: WIRE_LOG_TARGET, Level::ERROR),
+        }
+    }
+
+    fn log_message(
+        &self,
+        direction: Direction,
+        transport: &'static str,
+        session_id: Option<&str>,
+        mut message: Value,
+    ) {
+        let method = message
+            .get("method")
+            .and_then(Value::as_str)
+            .map(str::to_owned);
+        let id = message.get("id").map(Value::to_string);
+        redact(&mut message, &self.redact);
+        wire_event!(
+            self.level,
+            direction = direction.as_str(),
+            transport,
+            method = method.as_deref(),
+            id = id.as_deref(),
+            session_id,
+            json = %message,
+            "MCP wire message"
+        );
+    }
+
+    /// Log a JSON body holding one message or a batch
+    fn log_json(
+        &self,
+        direction: Direction,
+        transport: &'static str,
+        session_id: Option<&str>,
+        data: &[u8],
+    ) {
+        match serde_json::from_slice::<Value>(data) {
+            Ok(Value::Array(batch)) => {
+                for message in batch {
+                    self.log_message(direction, transport, session_id, message);
+                }
+            }
+            Ok(message) => self.log_message(direction, transport, session_id, message),
+            Err(_) => {}
+        }
+    }
+
+    /// Log every `data:` payload in a chunk of SSE output
+    fn log_sse_chunk(&self, session_id: Option<&str>, chunk: &[u8]) {
+        let Ok(text) = std::str::from_utf8(chunk) else {
+            return;
+        };
+        for line in text.lines() {
+            if let Some(data) = line.strip_prefix("data:") {
+                self.log_json(
+                    Direction::Outbound,
+                    "sse",
+                    session_id,
+                    data.trim_start().as_bytes(),
+                );
+            }
+        }
+    }
+
+    /// Wrap the request body so it is logged once fully read
+    pub(crate) fn tap_request<B>(&self, req: Request<B>) -> Request<InboundTap<B>> {
+        let session_id = session_id(req.headers());
+        let enabled = req.method() == hyper::Method::POST && self.enabled();
+        req.map(|inner| InboundTap {
+            inner,
+            tap: enabled.then(|| (self.clone(), session_id, BytesMut::new())),
+        })
+    }
+
+    /// Log an outbound response: buffered JSON is logged whole, SSE frame by frame
+    pub(crate) async fn tap_response(
+        &self,
+        response: Response<UnsyncBoxBody<Bytes, hyper::Error>>,
+        request_session_id: Option<String>,
+    ) -> Result<Response<UnsyncBoxBody<Bytes, hyper::Error>>, hyper::Error> {
+        if !self.enabled() {
+            return Ok(response);
+        }
+        let session_id = session_id(response.headers()).or(request_session_id);
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        if content_type.starts_with("application/json") {
+            let (parts, body) = response.into_parts();
+            let data = body.collect().await?.to_bytes();
+            self.log_json(Direction::Outbound, "http", session_id.as_deref(), &data);
+            Ok(Response::from_parts(
+                parts,
+                Full::new(data)
+                    .map_err(|never| match never {})
+                    .boxed_unsync(),
+            ))
+        } else if content_type.starts_with("text/event-stream") {
+            let wire_log = self.clone();
+            Ok(response.map(|body| {
+                body.map_frame(move |frame| {
+                    if let Some(data) = frame.data_ref() {
+                        wire_log.log_sse_chunk(session_id.as_deref(), data);
+                    }
+                    frame
+                })
+                .boxed_unsync()
+            }))
+        } else {
+            Ok(response)
+        }
+    }
+}
+
+fn session_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("mcp-session-id")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
+
+/// Replace the value of every object field named in `keys`, at any depth
+fn redact(value: &mut Value, keys: &[String]) {
+    match value {
+        Value::Object(object) => {
+            for (key, field) in object.iter_mut() {
+                if keys
+                    .iter()
+                    .any(|redacted| redacted.eq_ignore_ascii_case(key))
+                {
+                    *field = Value::String(REDACTED.to_string());
+                } else {
+                    redact(field, keys);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                redact(item, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Request body that logs its JSON once the handler has read all of it
+pub(crate) struct InboundTap<B> {
+    inner: B,
+    tap: Option<(WireLog, Option<String>, BytesMut)>,
+}
+
+impl<B> Body for InboundTap<B>
+where
+    B: Body<Data = Bytes> + Unpin,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.get_mut();
+        let frame = ready!(Pin::new(&mut this.inner).poll_frame(cx));
+        match &frame {
+            Some(Ok(frame)) => {
+                if let (Some((_, _, buffer)), Some(data)) = (this.tap.as_mut(), frame.data_ref()) {
+                    buffer.extend_from_slice(data);
+                }
+            }
+            None => {
+                if let Some((wire_log, session_id, buffer)) = this.tap.take() {
+                    wire_log.log_json(Direction::Inbound, "http", session_id.as_deref(), &buffer);
+                }
+            }
+            Some(Err(_)) => this.tap = None,
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_redact_masks_nested_fields_case_insensitively() {
+        let mut message = json!({
+            "method": "tools/call",
+            "params": {
+                "name": "login",
+                "arguments": { "user": "ada", "Password": "hunter2" },
+                "_meta": { "tokens": [{ "token": "abc" }] }
+            }
+        });
+
+        redact(&mut message, &keys(&["password", "token"]));
+
+        assert_eq!(message["params"]["arguments"]["Password"], REDACTED);
+        assert_eq!(message["params"]["arguments"]["user"], "ada");
+        assert_eq!(message["params"]["_meta"]["tokens"][0]["token"], REDACTED);
+        assert_eq!(message["method"], "tools/call");
+    }
+
+    #[test]
+    fn test_redacted_object_field_is_replaced_whole() {
+        let mut message = json!({ "secret": { "nested": "value" } });
+        redact(&mut message, &keys(&["secret"]));
+        assert_eq!(message, json!({ "secret": REDACTED }));
+    }
+
+    #[tokio::test]
+    async fn test_inbound_tap_passes_body_through_unchanged() {
+        let wire_log = WireLog::new(Level::INFO, default_redacted_fields());
+        let body = r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#;
+        let req = Request::post("/mcp")
+            .body(Full::new(Bytes::from_static(body.as_bytes())))
+            .unwrap();
+
+        let tapped = wire_log.tap_request(req).into_body();
+        let read = tapped.collect().await.unwrap().to_bytes();
+
+        assert_eq!(&read[..], body.as_bytes());
+    }
+}
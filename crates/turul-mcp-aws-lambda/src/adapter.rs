@@ -251,6 +251,7 @@ pub fn sanitize_authorizer_field_name(field: &str) -> String {
 /// - Converts non-string values to JSON strings
 /// - Handles `ApiGateway.authorizer.fields["lambda"]` (V1 nested) or direct fields (V1 flat)
 /// - Handles `ApiGatewayV2.authorizer.fields` (V2, deserialized from "lambda" key by serde)
+/// - Handles `ApiGatewayV2.authorizer.iam` (function URLs with `AWS_IAM` auth) as `iam_*` fields
 ///
 /// # Examples
 ///
@@ -308,6 +309,17 @@ pub fn extract_authorizer_context(req: &LambdaRequest) -> HashMap<String, String
                 for (key, value) in &authorizer.fields {
                     authorizer_fields_map.insert(key.clone(), value.clone());
                 }
+                // Function URLs with AuthType AWS_IAM carry the caller identity under
+                // "iam" instead; expose it with an "iam_" prefix (iam_user_arn, ...)
+                if let Some(ref iam) = authorizer.iam
+                    && let Ok(serde_json::Value::Object(iam_fields)) = serde_json::to_value(iam)
+                {
+                    for (key, value) in iam_fields {
+                        if !value.is_null() {
+                            authorizer_fields_map.insert(format!("iam_{}", key), value);
+                        }
+                    }
+                }
             }
         }
         RequestContext::ApiGatewayV1(ctx) => {
@@ -13,7 +13,7 @@ use turul_mcp_server::{
     McpCompletion, McpElicitation, McpLogger, McpNotification, McpPrompt, McpResource, McpRoot,
     McpSampling, McpTool,
 };
-use turul_mcp_session_storage::BoxedSessionStorage;
+use turul_mcp_session_storage::{BoxedSessionStorage, CachedSessionStorage, SessionCacheConfig};
 
 use crate::error::Result;
 
@@ -125,6 +125,9 @@ pub struct LambdaMcpServerBuilder {
     /// Session storage backend (defaults to InMemory if None)
     session_storage: Option<Arc<BoxedSessionStorage>>,
 
+    /// In-process cache layered over the session storage (off if None)
+    session_cache: Option<SessionCacheConfig>,
+
     /// MCP Lifecycle enforcement configuration
     strict_lifecycle: bool,
 
@@ -187,7 +190,10 @@ impl LambdaMcpServerBuilder {
             "prompts/get".to_string(),
             Arc::new(PromptsGetHandler::new()),
         );
-        handlers.insert("logging/setLevel".to_string(), Arc::new(LoggingHandler));
+        handlers.insert(
+            "logging/setLevel".to_string(),
+            Arc::new(LoggingHandler::new()),
+        );
         handlers.insert("roots/list".to_string(), Arc::new(RootsHandler::new()));
         handlers.insert(
             "sampling/createMessage".to_string(),
@@ -271,6 +277,7 @@ impl LambdaMcpServerBuilder {
             session_timeout_minutes: None,
             session_cleanup_interval_seconds: None,
             session_storage: None,
+            session_cache: None,
             strict_lifecycle: true, // MCP 2025-11-25: require notifications/initialized
             enable_sse: cfg!(feature = "sse"),
             server_config: ServerConfig::default(),
@@ -351,6 +358,36 @@ impl LambdaMcpServerBuilder {
         self
     }
 
+    /// Register a tool that is constructed on its first call
+    ///
+    /// `descriptor` is what `tools/list` advertises, so listing never runs `factory`.
+    /// The first `tools/call` builds the tool and the instance is kept for the life of
+    /// the Lambda container. Use this to keep expensive tools off the cold-start path.
+    ///
+    /// ```rust,no_run
+    /// use turul_mcp_aws_lambda::LambdaMcpServerBuilder;
+    /// use turul_mcp_builders::ToolBuilder;
+    /// use turul_mcp_protocol::{Tool, ToolSchema};
+    ///
+    /// let builder = LambdaMcpServerBuilder::new().lazy_tool(
+    ///     Tool::new("report", ToolSchema::object()).with_description("Build a report"),
+    ///     || {
+    ///         ToolBuilder::new("report")
+    ///             .description("Build a report")
+    ///             .execute(|_args| async move { Ok(serde_json::json!("done")) })
+    ///             .build()
+    ///             .unwrap()
+    ///     },
+    /// );
+    /// ```
+    pub fn lazy_tool<T, F>(self, descriptor: turul_mcp_protocol::Tool, factory: F) -> Self
+    where
+        T: McpTool + 'static,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        self.tool(turul_mcp_server::LazyTool::new(descriptor, factory))
+    }
+
     /// Register a resource with the server
     ///
     /// Automatically detects template resources (URIs containing `{variables}`)
@@ -627,7 +664,7 @@ impl LambdaMcpServerBuilder {
     pub fn with_logging(mut self) -> Self {
         use turul_mcp_protocol::initialize::LoggingCapabilities;
         self.capabilities.logging = Some(LoggingCapabilities::default());
-        self.handler(LoggingHandler)
+        self.handler(LoggingHandler::new())
     }
 
     /// Add roots support
@@ -817,6 +854,21 @@ impl LambdaMcpServerBuilder {
         Ok(self.storage(Arc::new(storage)))
     }
 
+    /// Cache session reads in process across warm invocations
+    ///
+    /// A warm container that handled a session moments ago answers the next
+    /// invocation's session lookup from a bounded, TTL'd LRU cache instead of
+    /// reading the backend (e.g. DynamoDB) again. Writes always go to the backend
+    /// and evict the cached copy. When `config.session_timeout_minutes` is unset it
+    /// follows [`session_timeout_minutes`](Self::session_timeout_minutes), so
+    /// expired sessions are never served from the cache.
+    ///
+    /// See [`CachedSessionStorage`] for the consistency guarantees.
+    pub fn session_cache(mut self, config: SessionCacheConfig) -> Self {
+        self.session_cache = Some(config);
+        self
+    }
+
     /// Register middleware for request/response interception
     ///
     /// Middleware can inspect and modify requests before they reach handlers,
@@ -973,9 +1025,15 @@ impl LambdaMcpServerBuilder {
         // - handle_streaming(): Works with run_with_streaming_response() for real SSE streaming
 
         // Create session storage (use in-memory if none provided)
-        let session_storage = self
+        let mut session_storage = self
             .session_storage
             .unwrap_or_else(|| Arc::new(InMemorySessionStorage::new()));
+        if let Some(mut cache) = self.session_cache {
+            cache.session_timeout_minutes = cache
+                .session_timeout_minutes
+                .or(self.session_timeout_minutes);
+            session_storage = Arc::new(CachedSessionStorage::new(session_storage, cache));
+        }
 
         // Create implementation info
         let mut implementation = Implementation::new(&self.name, &self.version);
@@ -1323,6 +1381,34 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_lazy_tool_not_built_until_called() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let builds = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&builds);
+        let builder = LambdaMcpServerBuilder::new()
+            .name("lazy-test")
+            .version("1.0.0")
+            .lazy_tool(TestTool.to_tool(), move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                TestTool
+            })
+            .storage(Arc::new(InMemorySessionStorage::new()))
+            .sse(false);
+        let tool = Arc::clone(&builder.tools["test_tool"]);
+
+        // Building computes capabilities and the tool fingerprint from the descriptor
+        let server = builder.build().await.unwrap();
+        server.handler().await.unwrap();
+        assert_eq!(tool.to_tool().description.as_deref(), Some("Test tool"));
+        assert_eq!(builds.load(Ordering::SeqCst), 0);
+
+        tool.call(serde_json::json!({}), None).await.unwrap();
+        tool.call(serde_json::json!({}), None).await.unwrap();
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+    }
+
     #[tokio::test]
     async fn test_simple_lambda_server() {
         let tools = vec![TestTool];
@@ -424,12 +424,13 @@ impl LambdaMcpHandler {
                     use http_body_util::BodyExt;
                     let (parts, body) = hyper_req.into_parts();
                     let boxed_req = hyper::Request::from_parts(parts, body.boxed_unsync());
-                    return Ok(route_handler.handle(boxed_req).await);
+                    let route_resp = route_handler.handle(boxed_req).await;
+                    return self.finish_streaming(route_resp, request_origin.as_deref());
                 }
                 Ok(None) => {} // No match, continue to MCP handler
                 Err(e) => {
                     debug!("Route validation error (streaming): {}", e);
-                    return Ok(e.into_response());
+                    return self.finish_streaming(e.into_response(), request_origin.as_deref());
                 }
             }
         }
@@ -466,15 +467,37 @@ impl LambdaMcpHandler {
                 })?
         };
 
+        self.finish_streaming(hyper_resp, request_origin.as_deref())
+    }
+
+    /// Convert a hyper response to a Lambda streaming response and apply CORS
+    ///
+    /// The body is passed through untouched, so SSE frames reach the client as they
+    /// are produced rather than after the handler finishes.
+    fn finish_streaming(
+        &self,
+        hyper_resp: hyper::Response<
+            http_body_util::combinators::UnsyncBoxBody<bytes::Bytes, hyper::Error>,
+        >,
+        request_origin: Option<&str>,
+    ) -> std::result::Result<
+        lambda_http::Response<
+            http_body_util::combinators::UnsyncBoxBody<bytes::Bytes, hyper::Error>,
+        >,
+        Box<dyn std::error::Error + Send + Sync>,
+    > {
         // 🚀 DELEGATION: Convert hyper response to Lambda streaming response (preserves streaming!)
+        #[allow(unused_mut)]
         let mut lambda_resp = crate::adapter::hyper_to_lambda_streaming(hyper_resp);
 
         // Apply CORS headers if configured (Lambda-specific logic)
         #[cfg(feature = "cors")]
         if let Some(ref cors_config) = self.cors_config {
-            inject_cors_headers(&mut lambda_resp, cors_config, request_origin.as_deref())
+            inject_cors_headers(&mut lambda_resp, cors_config, request_origin)
                 .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
         }
+        #[cfg(not(feature = "cors"))]
+        let _ = request_origin;
 
         Ok(lambda_resp)
     }
@@ -588,10 +611,14 @@ mod tests {
 
         // Create a custom StreamConfig with non-default values
         let custom_stream_config = StreamConfig {
-            channel_buffer_size: 1024,      // Non-default value (default is 1000)
-            max_replay_events: 200,         // Non-default value (default is 100)
-            keepalive_interval_seconds: 10, // Non-default value (default is 30)
+            channel_buffer_size: 1024, // Non-default value (default is 1000)
+            max_replay_events: 200,    // Non-default value (default is 100)
+            keep_alive: Some(std::time::Duration::from_secs(10)), // Non-default value (default is 30s)
             cors_origin: "https://custom-test.example.com".to_string(), // Non-default value
+            resource_update_coalesce_window_ms: Some(250), // Non-default value (default is None)
+            max_event_size_bytes: None,
+            oversized_event_policy: Default::default(),
+            max_streams_per_session: None,
         };
 
         // Create stream manager with the custom config
@@ -631,14 +658,18 @@ mod tests {
             "Custom max_replay_events was not propagated correctly"
         );
         assert_eq!(
-            actual_config.keepalive_interval_seconds,
-            custom_stream_config.keepalive_interval_seconds,
-            "Custom keepalive_interval_seconds was not propagated correctly"
+            actual_config.keep_alive, custom_stream_config.keep_alive,
+            "Custom keep_alive was not propagated correctly"
         );
         assert_eq!(
             actual_config.cors_origin, custom_stream_config.cors_origin,
             "Custom cors_origin was not propagated correctly"
         );
+        assert_eq!(
+            actual_config.resource_update_coalesce_window_ms,
+            custom_stream_config.resource_update_coalesce_window_ms,
+            "Custom resource_update_coalesce_window_ms was not propagated correctly"
+        );
 
         // Verify the stream manager is accessible (proves delegation worked)
         assert!(Arc::strong_count(stream_manager) >= 1);
@@ -652,10 +683,14 @@ mod tests {
 
         // Create a custom StreamConfig with non-default values
         let custom_stream_config = turul_http_mcp_server::StreamConfig {
-            channel_buffer_size: 2048,      // Non-default value
-            max_replay_events: 500,         // Non-default value
-            keepalive_interval_seconds: 15, // Non-default value
+            channel_buffer_size: 2048,                            // Non-default value
+            max_replay_events: 500,                               // Non-default value
+            keep_alive: Some(std::time::Duration::from_secs(15)), // Non-default value
             cors_origin: "https://full-chain-test.example.com".to_string(),
+            resource_update_coalesce_window_ms: Some(500),
+            max_event_size_bytes: None,
+            oversized_event_policy: Default::default(),
+            max_streams_per_session: None,
         };
 
         // Test the complete builder → server → handler chain
@@ -691,14 +726,18 @@ mod tests {
             "Custom max_replay_events should be preserved through builder → server → handler chain"
         );
         assert_eq!(
-            actual_config.keepalive_interval_seconds,
-            custom_stream_config.keepalive_interval_seconds,
-            "Custom keepalive_interval_seconds should be preserved through builder → server → handler chain"
+            actual_config.keep_alive, custom_stream_config.keep_alive,
+            "Custom keep_alive should be preserved through builder → server → handler chain"
         );
         assert_eq!(
             actual_config.cors_origin, custom_stream_config.cors_origin,
             "Custom cors_origin should be preserved through builder → server → handler chain"
         );
+        assert_eq!(
+            actual_config.resource_update_coalesce_window_ms,
+            custom_stream_config.resource_update_coalesce_window_ms,
+            "Custom resource_update_coalesce_window_ms should be preserved through builder → server → handler chain"
+        );
 
         // Verify the stream manager is functional
         assert!(
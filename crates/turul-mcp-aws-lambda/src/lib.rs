@@ -58,6 +58,13 @@
 //! - [`run_streaming_with()`] — custom dispatch: provide your own closure for
 //!   pre-dispatch logic (e.g., `.well-known` routing) while still getting
 //!   completion-invocation handling for free
+//!
+//! Both accept API Gateway (REST and HTTP API) and Lambda function URL events.
+//! API Gateway buffers integration responses unless the integration is configured
+//! for response streaming, so SSE clients are best served by a function URL with
+//! `InvokeMode: RESPONSE_STREAM`: each SSE frame is written to the client as soon
+//! as the handler produces it, and CORS headers from the builder's CORS config are
+//! still injected.
 
 pub mod adapter;
 pub mod builder;
@@ -97,6 +104,8 @@ enum RuntimeEventClassification {
     /// `large_enum_variant`). Callers dereference with `(*lambda_request).into()`
     /// to move the inner `LambdaRequest` into an `http::Request`.
     ApiGatewayEvent(Box<lambda_http::request::LambdaRequest>),
+    /// Lambda function URL event (payload format 2.0 from a `*.lambda-url.*` domain)
+    FunctionUrlEvent(Box<lambda_http::request::LambdaRequest>),
     /// AWS streaming completion invocation (contains `invokeCompletionStatus`)
     StreamingCompletion,
     /// Unrecognized payload — not API Gateway, not completion
@@ -132,6 +141,9 @@ fn classify_runtime_event(payload: serde_json::Value) -> RuntimeEventClassificat
     if let Ok(request) =
         serde_json::from_value::<lambda_http::request::LambdaRequest>(payload.clone())
     {
+        if is_function_url_payload(&payload) {
+            return RuntimeEventClassification::FunctionUrlEvent(Box::new(request));
+        }
         return RuntimeEventClassification::ApiGatewayEvent(Box::new(request));
     }
 
@@ -144,6 +156,18 @@ fn classify_runtime_event(payload: serde_json::Value) -> RuntimeEventClassificat
     RuntimeEventClassification::UnrecognizedEvent
 }
 
+/// Whether an HTTP event payload came from a Lambda function URL.
+///
+/// Function URL events share API Gateway's payload format 2.0 and deserialize
+/// as `ApiGatewayV2`; they are told apart by the `*.lambda-url.<region>.on.aws`
+/// domain in `requestContext.domainName`.
+fn is_function_url_payload(payload: &serde_json::Value) -> bool {
+    payload
+        .pointer("/requestContext/domainName")
+        .and_then(serde_json::Value::as_str)
+        .is_some_and(|domain| domain.contains(".lambda-url."))
+}
+
 type StreamBody = http_body_util::combinators::UnsyncBoxBody<bytes::Bytes, hyper::Error>;
 type StreamResult = lambda_runtime::StreamResponse<http_body_util::BodyDataStream<StreamBody>>;
 
@@ -151,15 +175,15 @@ type StreamResult = lambda_runtime::StreamResponse<http_body_util::BodyDataStrea
 /// and a static string identifying the event type for logging/observability.
 struct HandleResult {
     response: StreamResult,
-    /// One of `"api_gateway_event"`, `"streaming_completion"`, or
-    /// `"unrecognized_lambda_payload"`.
+    /// One of `"api_gateway_event"`, `"function_url_event"`,
+    /// `"streaming_completion"`, or `"unrecognized_lambda_payload"`.
     event_type: &'static str,
 }
 
 /// Process a raw Lambda runtime payload into a streaming response.
 ///
 /// Classifies the payload via [`classify_runtime_event()`], dispatches API
-/// Gateway events through `dispatch`, and acknowledges non-API payloads with
+/// Gateway and function URL events through `dispatch`, and acknowledges non-API payloads with
 /// an empty 200 response. Returns a [`HandleResult`] so the caller can
 /// inspect `event_type` for logging decisions.
 async fn handle_runtime_payload<F, Fut>(
@@ -173,33 +197,42 @@ where
             Output = std::result::Result<http::Response<StreamBody>, lambda_http::Error>,
         >,
 {
-    match classify_runtime_event(payload) {
+    let (lambda_request, event_type) = match classify_runtime_event(payload) {
         RuntimeEventClassification::ApiGatewayEvent(lambda_request) => {
-            use lambda_http::RequestExt;
-            let request: lambda_http::Request = (*lambda_request).into();
-            let request = request.with_lambda_context(context);
-            let response = dispatch(request).await?;
-            Ok(HandleResult {
-                response: into_lambda_stream_response(response),
-                event_type: "api_gateway_event",
-            })
+            (lambda_request, "api_gateway_event")
         }
-        RuntimeEventClassification::StreamingCompletion => Ok(HandleResult {
-            response: into_lambda_stream_response(empty_streaming_response()),
-            event_type: "streaming_completion",
-        }),
-        RuntimeEventClassification::UnrecognizedEvent => Ok(HandleResult {
-            response: into_lambda_stream_response(empty_streaming_response()),
-            event_type: "unrecognized_lambda_payload",
-        }),
-    }
+        RuntimeEventClassification::FunctionUrlEvent(lambda_request) => {
+            (lambda_request, "function_url_event")
+        }
+        RuntimeEventClassification::StreamingCompletion => {
+            return Ok(HandleResult {
+                response: into_lambda_stream_response(empty_streaming_response()),
+                event_type: "streaming_completion",
+            });
+        }
+        RuntimeEventClassification::UnrecognizedEvent => {
+            return Ok(HandleResult {
+                response: into_lambda_stream_response(empty_streaming_response()),
+                event_type: "unrecognized_lambda_payload",
+            });
+        }
+    };
+
+    use lambda_http::RequestExt;
+    let request: lambda_http::Request = (*lambda_request).into();
+    let request = request.with_lambda_context(context);
+    let response = dispatch(request).await?;
+    Ok(HandleResult {
+        response: into_lambda_stream_response(response),
+        event_type,
+    })
 }
 
 /// Map an event type string to the appropriate tracing log level.
 ///
 /// Returns `Some(Level::WARN)` for unrecognized payloads (surfaced in
 /// CloudWatch), `Some(Level::DEBUG)` for completion acks (normally silent),
-/// and `None` for API Gateway events (no extra logging needed).
+/// and `None` for API Gateway and function URL events (no extra logging needed).
 fn event_log_level(event_type: &str) -> Option<tracing::Level> {
     match event_type {
         "streaming_completion" => Some(tracing::Level::DEBUG),
@@ -383,6 +416,7 @@ mod streaming_completion_tests {
         let json_str = match name {
             "apigw_v1" => include_str!("fixtures/apigw_v1_proxy_event.json"),
             "apigw_v2" => include_str!("fixtures/apigw_v2_http_api_event.json"),
+            "function_url" => include_str!("fixtures/function_url_event.json"),
             "completion_success" => include_str!("fixtures/streaming_completion_success.json"),
             "completion_failure" => include_str!("fixtures/streaming_completion_failure.json"),
             "completion_extra" => include_str!("fixtures/streaming_completion_extra_fields.json"),
@@ -420,6 +454,36 @@ mod streaming_completion_tests {
         );
     }
 
+    #[test]
+    fn test_classify_function_url_event() {
+        let payload = load_fixture("function_url");
+        assert!(
+            matches!(
+                classify_runtime_event(payload),
+                RuntimeEventClassification::FunctionUrlEvent(_)
+            ),
+            "Function URL event must classify as FunctionUrlEvent"
+        );
+    }
+
+    #[test]
+    fn test_function_url_iam_authorizer_is_extracted() {
+        let lambda_request: lambda_http::request::LambdaRequest =
+            serde_json::from_value(load_fixture("function_url")).unwrap();
+        let request: lambda_http::Request = lambda_request.into();
+
+        let fields = crate::adapter::extract_authorizer_context(&request);
+        assert_eq!(
+            fields.get("iam_user_arn").map(String::as_str),
+            Some("arn:aws:iam::123456789012:user/mcp-client")
+        );
+        assert_eq!(
+            fields.get("iam_account_id").map(String::as_str),
+            Some("123456789012")
+        );
+        assert!(!fields.contains_key("iam_cognito_identity"));
+    }
+
     // ── Fixture tests: Streaming completion → StreamingCompletion ──
 
     #[test]
@@ -860,4 +924,127 @@ mod streaming_completion_tests {
         assert_eq!(result.event_type, "streaming_completion");
         assert_eq!(result.response.metadata_prelude.status_code, 200);
     }
+
+    // ── Function URL streaming tests ──
+
+    #[tokio::test]
+    async fn test_handle_function_url_dispatches() {
+        let dispatched = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let dispatched_clone = dispatched.clone();
+
+        let result = handle_runtime_payload(
+            load_fixture("function_url"),
+            lambda_runtime::Context::default(),
+            |req| {
+                let d = dispatched_clone.clone();
+                async move {
+                    assert_eq!(req.uri().path(), "/mcp");
+                    d.store(true, std::sync::atomic::Ordering::SeqCst);
+                    Ok(empty_streaming_response())
+                }
+            },
+        )
+        .await
+        .expect("handle should succeed");
+
+        assert!(
+            dispatched.load(std::sync::atomic::Ordering::SeqCst),
+            "Function URL events must dispatch to handler"
+        );
+        assert_eq!(result.event_type, "function_url_event");
+        assert_eq!(event_log_level(result.event_type), None);
+    }
+
+    #[tokio::test]
+    async fn test_function_url_streams_frames_incrementally() {
+        use futures::StreamExt;
+        use http_body_util::BodyExt;
+        use std::time::Duration;
+
+        let (tx, rx) = futures::channel::mpsc::unbounded::<
+            std::result::Result<http_body::Frame<bytes::Bytes>, hyper::Error>,
+        >();
+        let send = |event: &'static str| {
+            tx.unbounded_send(Ok(http_body::Frame::data(bytes::Bytes::from_static(
+                event.as_bytes(),
+            ))))
+            .unwrap()
+        };
+
+        let result = handle_runtime_payload(
+            load_fixture("function_url"),
+            lambda_runtime::Context::default(),
+            move |_req| async move {
+                let body = http_body_util::StreamBody::new(rx).boxed_unsync();
+                Ok(http::Response::builder()
+                    .status(200)
+                    .header("Content-Type", "text/event-stream")
+                    .body(body)
+                    .unwrap())
+            },
+        )
+        .await
+        .expect("handle should succeed");
+        assert_eq!(result.event_type, "function_url_event");
+
+        // Each frame must be readable while the handler is still producing the body
+        let mut stream = result.response.stream;
+        for event in ["data: first\n\n", "data: second\n\n"] {
+            send(event);
+            let chunk = tokio::time::timeout(Duration::from_secs(1), stream.next())
+                .await
+                .expect("frame must be emitted before the body completes")
+                .expect("stream ended early")
+                .unwrap();
+            assert_eq!(chunk, bytes::Bytes::from_static(event.as_bytes()));
+        }
+
+        drop(tx);
+        assert!(stream.next().await.is_none());
+    }
+
+    #[cfg(feature = "cors")]
+    #[tokio::test]
+    async fn test_function_url_event_through_streaming_handler() {
+        use futures::StreamExt;
+        use std::time::Duration;
+
+        let server = crate::LambdaMcpServerBuilder::new()
+            .name("function-url-test")
+            .version("1.0.0")
+            .cors_allow_all_origins()
+            .build()
+            .await
+            .unwrap();
+        let handler = server.handler().await.unwrap();
+
+        let result = handle_runtime_payload(
+            load_fixture("function_url"),
+            lambda_runtime::Context::default(),
+            |req| handler.handle_streaming(req),
+        )
+        .await
+        .expect("handle should succeed");
+        assert_eq!(result.event_type, "function_url_event");
+
+        let prelude = &result.response.metadata_prelude;
+        assert_eq!(prelude.status_code, 200);
+        assert!(
+            prelude.headers.contains_key("access-control-allow-origin"),
+            "CORS headers must be injected on function URL responses"
+        );
+        assert!(prelude.headers.contains_key("mcp-session-id"));
+
+        let chunks: Vec<_> = tokio::time::timeout(
+            Duration::from_secs(5),
+            result.response.stream.collect::<Vec<_>>(),
+        )
+        .await
+        .expect("initialize response must complete");
+        let body: Vec<u8> = chunks
+            .into_iter()
+            .flat_map(|chunk| chunk.unwrap().to_vec())
+            .collect();
+        assert!(String::from_utf8(body).unwrap().contains("protocolVersion"));
+    }
 }
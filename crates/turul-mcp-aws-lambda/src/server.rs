@@ -453,6 +453,13 @@ impl LambdaMcpServer {
             handler
         };
 
+        #[cfg(feature = "cors")]
+        let handler = if let Some(ref cors_config) = self.cors_config {
+            handler.with_cors(cors_config.clone())
+        } else {
+            handler
+        };
+
         Ok(handler)
     }
 
@@ -9,15 +9,24 @@ use std::collections::HashMap;
 // Import from protocol via alias
 use turul_mcp_protocol::completion::{
     CompleteArgument, CompleteParams, CompleteRequest, CompletionContext, CompletionReference,
-    PromptReference, ResourceTemplateReference,
+    CompletionResult, PromptReference, ResourceTemplateReference,
 };
 
+/// Most values a single `completion/complete` response may carry (per MCP spec)
+pub const MAX_COMPLETION_VALUES: usize = 100;
+
+/// Score bonus for a fuzzy match at the start of a word
+const WORD_START_BONUS: i64 = 3;
+/// Score bonus for a fuzzy match right after the previous one
+const CONSECUTIVE_BONUS: i64 = 2;
+
 /// Builder for creating completion requests at runtime
 pub struct CompletionBuilder {
     reference: CompletionReference,
     argument: CompleteArgument,
     context: Option<CompletionContext>,
     meta: Option<HashMap<String, Value>>,
+    fuzzy: bool,
 }
 
 impl CompletionBuilder {
@@ -28,6 +37,7 @@ impl CompletionBuilder {
             argument: CompleteArgument::new("", ""), // Will be set later
             context: None,
             meta: None,
+            fuzzy: false,
         }
     }
 
@@ -38,6 +48,7 @@ impl CompletionBuilder {
             argument: CompleteArgument::new("", ""), // Will be set later
             context: None,
             meta: None,
+            fuzzy: false,
         }
     }
 
@@ -87,6 +98,52 @@ impl CompletionBuilder {
         self
     }
 
+    /// Match candidates in [`complete`](Self::complete) as subsequences of the
+    /// current value instead of by prefix
+    ///
+    /// `"gdb"` then matches `"get_debug_build"`. Results are ranked best-first:
+    /// matches at word starts and runs of adjacent characters score higher, gaps
+    /// score lower, and equal scores are ordered alphabetically.
+    pub fn fuzzy(mut self) -> Self {
+        self.fuzzy = true;
+        self
+    }
+
+    /// Complete the current value from `candidates`
+    ///
+    /// Keeps the candidates that start with the current value (case-insensitive, in
+    /// the order given), or with [`fuzzy`](Self::fuzzy) the ranked subsequence
+    /// matches. At most [`MAX_COMPLETION_VALUES`] are returned; `total` and
+    /// `hasMore` report the full match count.
+    pub fn complete<I, S>(&self, candidates: I) -> CompletionResult
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let candidates = candidates.into_iter().map(Into::into);
+        let mut values: Vec<String> = if self.fuzzy {
+            let mut scored: Vec<(i64, String)> = candidates
+                .filter_map(|candidate| {
+                    fuzzy_score(&self.argument.value, &candidate).map(|score| (score, candidate))
+                })
+                .collect();
+            scored
+                .sort_by(|(a_score, a), (b_score, b)| b_score.cmp(a_score).then_with(|| a.cmp(b)));
+            scored.into_iter().map(|(_, candidate)| candidate).collect()
+        } else {
+            let prefix = self.argument.value.to_lowercase();
+            candidates
+                .filter(|candidate| candidate.to_lowercase().starts_with(&prefix))
+                .collect()
+        };
+
+        let total = values.len();
+        values.truncate(MAX_COMPLETION_VALUES);
+        CompletionResult::new(values)
+            .with_total(u32::try_from(total).unwrap_or(u32::MAX))
+            .with_has_more(total > MAX_COMPLETION_VALUES)
+    }
+
     /// Build the completion request
     pub fn build(self) -> CompleteRequest {
         let mut params = CompleteParams::new(self.reference, self.argument);
@@ -121,6 +178,45 @@ impl CompletionBuilder {
     }
 }
 
+/// Score `candidate` as a case-insensitive subsequence match of `pattern`
+///
+/// Each pattern character takes the next matching candidate character; `None`
+/// if some character has no match left.
+fn fuzzy_score(pattern: &str, candidate: &str) -> Option<i64> {
+    let chars: Vec<char> = candidate.chars().collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for wanted in pattern.chars() {
+        let found =
+            (next..chars.len()).find(|&i| chars[i].to_lowercase().eq(wanted.to_lowercase()))?;
+        score += 1;
+        if is_word_start(&chars, found) {
+            score += WORD_START_BONUS;
+        }
+        // Reward adjacent matches; charge for every character skipped
+        match previous {
+            Some(previous) if found == previous + 1 => score += CONSECUTIVE_BONUS,
+            Some(previous) => score -= (found - previous - 1) as i64,
+            None => score -= found as i64,
+        }
+        previous = Some(found);
+        next = found + 1;
+    }
+    Some(score)
+}
+
+/// Whether `chars[i]` starts a word: after a separator or at a camelCase hump
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    match i.checked_sub(1).map(|before| chars[before]) {
+        None => true,
+        Some(before) => {
+            !before.is_alphanumeric() || (before.is_lowercase() && chars[i].is_uppercase())
+        }
+    }
+}
+
 /// Convenience methods for common completion scenarios
 impl CompletionBuilder {
     /// Create completion for a prompt argument with no current value
@@ -298,6 +394,71 @@ mod tests {
         assert_eq!(args.get("workflow_id").unwrap(), "wf-456");
     }
 
+    const COMMANDS: [&str; 5] = [
+        "list_builds",
+        "get_database",
+        "get_debug_build",
+        "gd_backup",
+        "go_deploy_bundle",
+    ];
+
+    #[test]
+    fn test_prefix_vs_fuzzy_completion() {
+        let prefix =
+            CompletionBuilder::prompt_argument_partial("run", "command", "gdb").complete(COMMANDS);
+        assert!(prefix.values.is_empty());
+        assert_eq!(prefix.total, Some(0));
+
+        let fuzzy = CompletionBuilder::prompt_argument_partial("run", "command", "gdb")
+            .fuzzy()
+            .complete(COMMANDS);
+        // Word starts and adjacent characters rank first; wide gaps rank last
+        assert_eq!(
+            fuzzy.values,
+            [
+                "gd_backup",
+                "get_debug_build",
+                "go_deploy_bundle",
+                "get_database"
+            ]
+        );
+        assert_eq!(fuzzy.total, Some(4));
+        assert_eq!(fuzzy.has_more, Some(false));
+
+        // Prefix matching is case-insensitive and keeps the given order
+        let prefix =
+            CompletionBuilder::prompt_argument_partial("run", "command", "GET").complete(COMMANDS);
+        assert_eq!(prefix.values, ["get_database", "get_debug_build"]);
+    }
+
+    #[test]
+    fn test_fuzzy_ties_are_alphabetical() {
+        let result = CompletionBuilder::prompt_argument_partial("run", "command", "ab")
+            .fuzzy()
+            .complete(["cab", "ab_zeta", "ab_alpha", "xab"]);
+        assert_eq!(result.values, ["ab_alpha", "ab_zeta", "cab", "xab"]);
+
+        // An empty value matches everything with equal scores
+        let result = CompletionBuilder::prompt_argument("run", "command")
+            .fuzzy()
+            .complete(["b", "c", "a"]);
+        assert_eq!(result.values, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_completion_capped_at_spec_limit() {
+        let candidates: Vec<String> = (0..150).map(|i| format!("item_{i:03}")).collect();
+        for builder in [
+            CompletionBuilder::prompt_argument_partial("p", "arg", "item"),
+            CompletionBuilder::prompt_argument_partial("p", "arg", "itm").fuzzy(),
+        ] {
+            let result = builder.complete(candidates.clone());
+            assert_eq!(result.values.len(), MAX_COMPLETION_VALUES);
+            assert_eq!(result.total, Some(150));
+            assert_eq!(result.has_more, Some(true));
+        }
+    }
+
     #[test]
     fn test_completion_builder_build_params_only() {
         let params = CompletionBuilder::for_prompt("test")
@@ -156,6 +156,10 @@ pub use schemars_helpers::{
 // users to add schemars as an explicit dependency
 pub use schemars;
 
+// Re-export regex so `#[param(pattern = "...")]` checks compile without a
+// direct regex dependency in user code
+pub use regex;
+
 // Re-export all framework traits for convenience
 pub use traits::*;
 
@@ -24,7 +24,7 @@ pub use crate::notification::NotificationBuilder;
 pub use crate::prompt::PromptBuilder;
 pub use crate::resource::ResourceBuilder;
 pub use crate::root::RootBuilder;
-pub use crate::tool::{DynamicToolFn, ToolBuilder};
+pub use crate::tool::{DynamicToolFn, FallibleToolFn, ToolBuilder};
 
 // Re-export commonly used protocol types for convenience
 pub use turul_mcp_protocol::{
@@ -16,7 +16,9 @@ use crate::traits::{
 };
 // Import protocol types
 use turul_mcp_protocol::icons::Icon;
-use turul_mcp_protocol::prompts::{ContentBlock, GetPromptResult, PromptArgument, PromptMessage};
+use turul_mcp_protocol::prompts::{
+    ContentBlock, GetPromptResult, PromptArgument, PromptMessage, Role,
+};
 
 /// Type alias for dynamic prompt generation function
 pub type DynamicPromptFn = Box<
@@ -32,8 +34,14 @@ pub struct PromptBuilder {
     name: String,
     title: Option<String>,
     description: Option<String>,
+    localized_descriptions: Option<HashMap<String, String>>,
     arguments: Vec<PromptArgument>,
     messages: Vec<PromptMessage>,
+    /// Role and block count of each `turn()`, validated in `build()`
+    turns: Vec<(Role, usize)>,
+    /// Message index -> argument that must be present and non-empty to emit it
+    conditions: HashMap<usize, String>,
+    strict_substitution: bool,
     icons: Option<Vec<Icon>>,
     meta: Option<HashMap<String, Value>>,
     get_fn: Option<DynamicPromptFn>,
@@ -46,8 +54,12 @@ impl PromptBuilder {
             name: name.into(),
             title: None,
             description: None,
+            localized_descriptions: None,
             arguments: Vec::new(),
             messages: Vec::new(),
+            turns: Vec::new(),
+            conditions: HashMap::new(),
+            strict_substitution: false,
             icons: None,
             meta: None,
             get_fn: None,
@@ -66,6 +78,18 @@ impl PromptBuilder {
         self
     }
 
+    /// Add a translated description served to sessions whose locale matches `locale`
+    pub fn localized_description(
+        mut self,
+        locale: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        self.localized_descriptions
+            .get_or_insert_with(HashMap::new)
+            .insert(locale.into(), description.into());
+        self
+    }
+
     /// Add an argument to the prompt
     pub fn argument(mut self, argument: PromptArgument) -> Self {
         self.arguments.push(argument);
@@ -143,6 +167,64 @@ impl PromptBuilder {
         self
     }
 
+    /// Add a templated message that is only emitted when `required_arg` is
+    /// supplied with a non-empty value at `get()` time
+    ///
+    /// Lets one prompt cover every combination of optional arguments instead
+    /// of needing a prompt per combination. Placeholders in an emitted message
+    /// are substituted like any other template message.
+    pub fn conditional_message(
+        mut self,
+        required_arg: impl Into<String>,
+        role: Role,
+        template: impl Into<String>,
+    ) -> Self {
+        self.conditions
+            .insert(self.messages.len(), required_arg.into());
+        self.messages.push(PromptMessage {
+            role,
+            content: ContentBlock::text(template),
+        });
+        self
+    }
+
+    /// Fail `get()` when a `{var}` placeholder has no supplied argument
+    ///
+    /// By default unresolved placeholders are left in the output as written.
+    /// `{{` and `}}` always produce literal braces and are never treated as
+    /// placeholders.
+    pub fn strict_substitution(mut self, strict: bool) -> Self {
+        self.strict_substitution = strict;
+        self
+    }
+
+    /// Add a conversation turn: one role speaking a sequence of content blocks
+    ///
+    /// Each block becomes a message with `role`, so a turn can mix text,
+    /// images and resource links. `{arg}` placeholders are substituted in text
+    /// blocks only; other blocks are passed through untouched. `build()` rejects
+    /// empty turns and consecutive turns by the same role.
+    pub fn turn(mut self, role: Role, content: impl IntoIterator<Item = ContentBlock>) -> Self {
+        let before = self.messages.len();
+        self.messages
+            .extend(content.into_iter().map(|content| PromptMessage {
+                role: role.clone(),
+                content,
+            }));
+        self.turns.push((role, self.messages.len() - before));
+        self
+    }
+
+    /// Add a user turn (see [`turn`](Self::turn))
+    pub fn user_turn(self, content: impl IntoIterator<Item = ContentBlock>) -> Self {
+        self.turn(Role::User, content)
+    }
+
+    /// Add an assistant turn (see [`turn`](Self::turn))
+    pub fn assistant_turn(self, content: impl IntoIterator<Item = ContentBlock>) -> Self {
+        self.turn(Role::Assistant, content)
+    }
+
     /// Set the prompt icons (display hints)
     pub fn icons(mut self, icons: Vec<Icon>) -> Self {
         self.icons = Some(icons);
@@ -167,18 +249,36 @@ impl PromptBuilder {
 
     /// Build the dynamic prompt
     pub fn build(self) -> Result<DynamicPrompt, String> {
+        validate_turns(&self.name, &self.turns)?;
+
         // If no get function provided, create a template processor
         let get_fn = if let Some(f) = self.get_fn {
             f
         } else {
             // Default template processor
             let messages = self.messages.clone();
+            let conditions = self.conditions.clone();
             let description = self.description.clone();
+            let name = self.name.clone();
+            let strict = self.strict_substitution;
             Box::new(move |args| {
-                let messages = messages.clone();
+                let messages = select_messages(&messages, &conditions, &args);
                 let description = description.clone();
+                let name = name.clone();
                 Box::pin(async move {
-                    let processed_messages = process_template_messages(messages, &args)?;
+                    let (processed_messages, unresolved) =
+                        process_template_messages(messages, &args);
+                    if strict && !unresolved.is_empty() {
+                        return Err(format!(
+                            "Prompt '{}': unresolved template variables: {}",
+                            name,
+                            unresolved
+                                .iter()
+                                .map(|var| format!("{{{}}}", var))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ));
+                    }
                     let mut result = GetPromptResult::new(processed_messages);
                     if let Some(desc) = description {
                         result = result.with_description(desc);
@@ -193,6 +293,7 @@ impl PromptBuilder {
             name: self.name,
             title: self.title,
             description: self.description,
+            localized_descriptions: self.localized_descriptions,
             arguments: self.arguments,
             messages: self.messages,
             icons: self.icons,
@@ -207,6 +308,7 @@ pub struct DynamicPrompt {
     name: String,
     title: Option<String>,
     description: Option<String>,
+    localized_descriptions: Option<HashMap<String, String>>,
     arguments: Vec<PromptArgument>,
     #[allow(dead_code)]
     messages: Vec<PromptMessage>,
@@ -237,6 +339,10 @@ impl HasPromptDescription for DynamicPrompt {
     fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
+
+    fn localized_descriptions(&self) -> Option<&HashMap<String, String>> {
+        self.localized_descriptions.as_ref()
+    }
 }
 
 impl HasPromptArguments for DynamicPrompt {
@@ -270,20 +376,77 @@ impl HasIcons for DynamicPrompt {
 
 // PromptDefinition is automatically implemented via blanket impl!
 
+/// Check that turns are non-empty and alternate between roles
+fn validate_turns(prompt_name: &str, turns: &[(Role, usize)]) -> Result<(), String> {
+    for (index, (role, blocks)) in turns.iter().enumerate() {
+        if *blocks == 0 {
+            return Err(format!(
+                "Prompt '{}': turn {} ({:?}) has no content",
+                prompt_name, index, role
+            ));
+        }
+        if index > 0 && turns[index - 1].0 == *role {
+            return Err(format!(
+                "Prompt '{}': turns {} and {} are both {:?}; merge them or alternate roles",
+                prompt_name,
+                index - 1,
+                index,
+                role
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Drop conditional messages whose required argument is missing or empty
+fn select_messages(
+    messages: &[PromptMessage],
+    conditions: &HashMap<usize, String>,
+    args: &HashMap<String, String>,
+) -> Vec<PromptMessage> {
+    messages
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| {
+            conditions
+                .get(index)
+                .is_none_or(|arg| args.get(arg).is_some_and(|value| !value.is_empty()))
+        })
+        .map(|(_, message)| message.clone())
+        .collect()
+}
+
 /// Simple template processing for message content
+///
+/// Returns the processed messages and the distinct placeholders that had no
+/// supplied argument.
 fn process_template_messages(
     messages: Vec<PromptMessage>,
     args: &HashMap<String, String>,
-) -> Result<Vec<PromptMessage>, String> {
+) -> (Vec<PromptMessage>, Vec<String>) {
     let mut processed = Vec::new();
+    let mut unresolved = Vec::new();
 
     for message in messages {
         let processed_message = match message.content {
-            ContentBlock::Text { text, .. } => {
-                let processed_text = process_template_string(&text, args);
+            ContentBlock::Text {
+                text,
+                annotations,
+                meta,
+            } => {
+                let (text, missing) = process_template_string(&text, args);
+                for var in missing {
+                    if !unresolved.contains(&var) {
+                        unresolved.push(var);
+                    }
+                }
                 PromptMessage {
                     role: message.role,
-                    content: ContentBlock::text(processed_text),
+                    content: ContentBlock::Text {
+                        text,
+                        annotations,
+                        meta,
+                    },
                 }
             }
             // For other content types, just pass through unchanged
@@ -295,19 +458,64 @@ fn process_template_messages(
         processed.push(processed_message);
     }
 
-    Ok(processed)
+    (processed, unresolved)
 }
 
 /// Simple template string processing (replaces {arg_name} with values)
-fn process_template_string(template: &str, args: &HashMap<String, String>) -> String {
-    let mut result = template.to_string();
+///
+/// `{{` and `}}` become literal braces. Placeholders without a supplied
+/// argument are left as written and returned alongside the output. Braces
+/// around anything that isn't a plain name (such as inline JSON) are kept
+/// verbatim and not reported.
+fn process_template_string(
+    template: &str,
+    args: &HashMap<String, String>,
+) -> (String, Vec<String>) {
+    let mut result = String::with_capacity(template.len());
+    let mut unresolved = Vec::new();
+    let mut rest = template;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        result.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+
+        if let Some(after) = tail.strip_prefix("{{").or_else(|| tail.strip_prefix("}}")) {
+            result.push_str(&tail[..1]);
+            rest = after;
+            continue;
+        }
+
+        if let Some(inner) = tail.strip_prefix('{')
+            && let Some(end) = inner.find(['{', '}'])
+            && inner[end..].starts_with('}')
+        {
+            let name = &inner[..end];
+            if let Some(value) = args.get(name) {
+                result.push_str(value);
+                rest = &inner[end + 1..];
+                continue;
+            }
+            if is_placeholder_name(name) {
+                result.push_str(&tail[..end + 2]);
+                unresolved.push(name.to_string());
+                rest = &inner[end + 1..];
+                continue;
+            }
+        }
 
-    for (key, value) in args {
-        let placeholder = format!("{{{}}}", key);
-        result = result.replace(&placeholder, value);
+        result.push_str(&tail[..1]);
+        rest = &tail[1..];
     }
+    result.push_str(rest);
 
-    result
+    (result, unresolved)
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
 }
 
 // Note: McpPrompt implementation will be provided by the turul-mcp-server crate
@@ -436,6 +644,55 @@ mod tests {
         assert_eq!(args[2].title, Some("Custom Argument".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_prompt_builder_conditional_messages() {
+        let prompt = PromptBuilder::new("review")
+            .string_argument("code", "Code to review")
+            .optional_string_argument("focus", "Area to focus on")
+            .user_message("Review this code:\n{code}")
+            .conditional_message("focus", Role::User, "Pay particular attention to {focus}.")
+            .assistant_message("I'll review it now.")
+            .build()
+            .expect("Failed to build prompt");
+
+        let texts = |result: GetPromptResult| -> Vec<String> {
+            result
+                .messages
+                .into_iter()
+                .map(|message| match message.content {
+                    ContentBlock::Text { text, .. } => text,
+                    other => panic!("Expected text content, got {:?}", other),
+                })
+                .collect()
+        };
+
+        // Argument present: message included and substituted
+        let mut args = HashMap::new();
+        args.insert("code".to_string(), "fn main() {}".to_string());
+        args.insert("focus".to_string(), "error handling".to_string());
+        let result = prompt.get(args.clone()).await.unwrap();
+        assert_eq!(
+            texts(result),
+            [
+                "Review this code:\nfn main() {}",
+                "Pay particular attention to error handling.",
+                "I'll review it now.",
+            ]
+        );
+
+        // Argument empty or absent: message omitted, order of the rest kept
+        args.insert("focus".to_string(), String::new());
+        let result = prompt.get(args.clone()).await.unwrap();
+        assert_eq!(
+            texts(result),
+            ["Review this code:\nfn main() {}", "I'll review it now."]
+        );
+
+        args.remove("focus");
+        let result = prompt.get(args).await.unwrap();
+        assert_eq!(result.messages.len(), 2);
+    }
+
     #[test]
     fn test_template_string_processing() {
         let template = "Hello {name}, welcome to {place}!";
@@ -443,7 +700,150 @@ mod tests {
         args.insert("name".to_string(), "Alice".to_string());
         args.insert("place".to_string(), "Wonderland".to_string());
 
-        let result = process_template_string(template, &args);
+        let (result, unresolved) = process_template_string(template, &args);
         assert_eq!(result, "Hello Alice, welcome to Wonderland!");
+        assert!(unresolved.is_empty());
+    }
+
+    #[test]
+    fn test_template_string_escaped_braces() {
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), "Alice".to_string());
+
+        let (result, unresolved) =
+            process_template_string("Use {{name}} to insert {name}; JSON: {\"a\": 1}}", &args);
+        assert_eq!(result, "Use {name} to insert Alice; JSON: {\"a\": 1}");
+        assert!(unresolved.is_empty());
+
+        let (result, unresolved) = process_template_string("{{{name}}} and {typo}", &args);
+        assert_eq!(result, "{Alice} and {typo}");
+        assert_eq!(unresolved, ["typo"]);
+    }
+
+    #[tokio::test]
+    async fn test_prompt_builder_strict_substitution() {
+        let build = |strict: bool| {
+            PromptBuilder::new("summarize")
+                .string_argument("topic", "Topic to summarize")
+                .user_message("Summarize {topic} as {{\"summary\": ...}}.")
+                .assistant_message("Focusing on {topc}.")
+                .strict_substitution(strict)
+                .build()
+                .expect("Failed to build prompt")
+        };
+        let mut args = HashMap::new();
+        args.insert("topic".to_string(), "Rust".to_string());
+
+        // Lenient (default): typo is left in place, escapes are unescaped
+        let result = build(false).get(args.clone()).await.unwrap();
+        match &result.messages[0].content {
+            ContentBlock::Text { text, .. } => {
+                assert_eq!(text, "Summarize Rust as {\"summary\": ...}.")
+            }
+            other => panic!("Expected text content, got {:?}", other),
+        }
+        match &result.messages[1].content {
+            ContentBlock::Text { text, .. } => assert_eq!(text, "Focusing on {topc}."),
+            other => panic!("Expected text content, got {:?}", other),
+        }
+
+        // Strict: the unresolved variable is an error, escaped braces are not
+        let err = build(true).get(args).await.unwrap_err();
+        assert_eq!(
+            err,
+            "Prompt 'summarize': unresolved template variables: {topc}"
+        );
+
+        // Strict with everything resolved succeeds
+        let prompt = PromptBuilder::new("escaped")
+            .string_argument("name", "Name")
+            .user_message("{{literal}} {name}")
+            .strict_substitution(true)
+            .build()
+            .unwrap();
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), "Bob".to_string());
+        let result = prompt.get(args).await.unwrap();
+        match &result.messages[0].content {
+            ContentBlock::Text { text, .. } => assert_eq!(text, "{literal} Bob"),
+            other => panic!("Expected text content, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prompt_builder_multi_turn_with_image() {
+        use turul_mcp_protocol::prompts::ResourceReference;
+
+        let prompt = PromptBuilder::new("few_shot_caption")
+            .string_argument("subject", "What to caption")
+            .user_turn([ContentBlock::text("Caption a photo of {subject}.")])
+            .assistant_turn([
+                ContentBlock::text("Here is an example of {subject}:"),
+                ContentBlock::image("aW1hZ2U6e3N1YmplY3R9", "image/png"),
+            ])
+            .user_turn([
+                ContentBlock::text("Now describe {subject} in this style."),
+                ContentBlock::resource_link(ResourceReference::new(
+                    "file:///styles/{subject}.md",
+                    "style guide",
+                )),
+            ])
+            .build()
+            .expect("Failed to build prompt");
+
+        let mut args = HashMap::new();
+        args.insert("subject".to_string(), "a lighthouse".to_string());
+        let result = prompt.get(args).await.expect("Failed to get prompt");
+
+        let roles: Vec<Role> = result.messages.iter().map(|m| m.role.clone()).collect();
+        assert_eq!(
+            roles,
+            [
+                Role::User,
+                Role::Assistant,
+                Role::Assistant,
+                Role::User,
+                Role::User
+            ]
+        );
+
+        let ContentBlock::Text { text, .. } = &result.messages[1].content else {
+            panic!("Expected text content");
+        };
+        assert_eq!(text, "Here is an example of a lighthouse:");
+
+        // Non-text blocks are passed through without interpolation
+        let ContentBlock::Image {
+            data, mime_type, ..
+        } = &result.messages[2].content
+        else {
+            panic!("Expected image content");
+        };
+        assert_eq!(data, "aW1hZ2U6e3N1YmplY3R9");
+        assert_eq!(mime_type, "image/png");
+
+        let ContentBlock::ResourceLink { resource, .. } = &result.messages[4].content else {
+            panic!("Expected resource link content");
+        };
+        assert_eq!(resource.uri, "file:///styles/{subject}.md");
+    }
+
+    #[test]
+    fn test_prompt_builder_rejects_invalid_turns() {
+        let err = PromptBuilder::new("repeated")
+            .user_turn([ContentBlock::text("one")])
+            .user_turn([ContentBlock::text("two")])
+            .build()
+            .err()
+            .expect("consecutive user turns should be rejected");
+        assert!(err.contains("turns 0 and 1 are both User"), "{}", err);
+
+        let err = PromptBuilder::new("empty")
+            .user_turn([ContentBlock::text("one")])
+            .assistant_turn([])
+            .build()
+            .err()
+            .expect("empty turns should be rejected");
+        assert!(err.contains("turn 1 (Assistant) has no content"), "{}", err);
     }
 }
@@ -4,10 +4,14 @@
 //! without requiring procedural macros. This enables dynamic resource creation
 //! for configuration-driven systems.
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use tokio::io::AsyncReadExt;
 
 // Import traits from local traits module
 use crate::traits::{
@@ -17,7 +21,12 @@ use crate::traits::{
 // Import protocol types
 use turul_mcp_protocol::icons::Icon;
 use turul_mcp_protocol::meta::Annotations;
-use turul_mcp_protocol::resources::ResourceContent;
+use turul_mcp_protocol::resources::{ResourceContent, TextResourceContents};
+use turul_mcp_protocol::{McpError, McpResult};
+
+/// Bytes read per step when base64-encoding a file (a multiple of 3, so
+/// chunks encode without padding and concatenate into one valid blob)
+const FILE_CHUNK_SIZE: usize = 3 * 64 * 1024;
 
 /// Type alias for dynamic resource read function
 pub type DynamicResourceFn = Box<
@@ -32,12 +41,14 @@ pub struct ResourceBuilder {
     name: String,
     title: Option<String>,
     description: Option<String>,
+    localized_descriptions: Option<HashMap<String, String>>,
     mime_type: Option<String>,
     size: Option<u64>,
     content: Option<ResourceContent>,
     annotations: Option<Annotations>,
     icons: Option<Vec<Icon>>,
     meta: Option<HashMap<String, Value>>,
+    file_path: Option<PathBuf>,
     read_fn: Option<DynamicResourceFn>,
 }
 
@@ -53,12 +64,14 @@ impl ResourceBuilder {
             name,
             title: None,
             description: None,
+            localized_descriptions: None,
             mime_type: None,
             size: None,
             content: None,
             annotations: None,
             icons: None,
             meta: None,
+            file_path: None,
             read_fn: None,
         }
     }
@@ -81,6 +94,18 @@ impl ResourceBuilder {
         self
     }
 
+    /// Add a translated description served to sessions whose locale matches `locale`
+    pub fn localized_description(
+        mut self,
+        locale: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        self.localized_descriptions
+            .get_or_insert_with(HashMap::new)
+            .insert(locale.into(), description.into());
+        self
+    }
+
     /// Set the MIME type
     pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
         self.mime_type = Some(mime_type.into());
@@ -125,6 +150,23 @@ impl ResourceBuilder {
         self
     }
 
+    /// Serve the contents of a file, read from disk on every `read()`
+    ///
+    /// The MIME type is inferred from the file extension unless one was set
+    /// with [`mime_type`](Self::mime_type). Text types are returned as text
+    /// content; everything else (or text that is not valid UTF-8) is returned
+    /// as a base64 blob, encoded in chunks so the raw bytes are never held
+    /// alongside their encoding. A missing file reads as
+    /// [`McpError::ResourceNotFound`].
+    pub fn with_file(mut self, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        if self.mime_type.is_none() {
+            self.mime_type = Some(mime_type_for_path(path).to_string());
+        }
+        self.file_path = Some(path.to_path_buf());
+        self
+    }
+
     /// Set annotations
     pub fn annotations(mut self, annotations: Annotations) -> Self {
         self.annotations = Some(annotations);
@@ -193,12 +235,14 @@ impl ResourceBuilder {
             name: self.name,
             title: self.title,
             description: self.description,
+            localized_descriptions: self.localized_descriptions,
             mime_type: self.mime_type,
             size: self.size,
             content: self.content,
             annotations: self.annotations,
             icons: self.icons,
             meta: self.meta,
+            file_path: self.file_path,
             read_fn: self.read_fn,
         })
     }
@@ -210,30 +254,144 @@ pub struct DynamicResource {
     name: String,
     title: Option<String>,
     description: Option<String>,
+    localized_descriptions: Option<HashMap<String, String>>,
     mime_type: Option<String>,
     size: Option<u64>,
     content: Option<ResourceContent>,
     annotations: Option<Annotations>,
     icons: Option<Vec<Icon>>,
     meta: Option<HashMap<String, Value>>,
+    file_path: Option<PathBuf>,
     read_fn: Option<DynamicResourceFn>,
 }
 
 impl DynamicResource {
     /// Read the resource content
-    pub async fn read(&self) -> Result<ResourceContent, String> {
+    pub async fn read(&self) -> McpResult<ResourceContent> {
         if let Some(ref content) = self.content {
             // Static content
             Ok(content.clone())
+        } else if let Some(ref path) = self.file_path {
+            // File content, read lazily
+            let mime_type = self
+                .mime_type
+                .clone()
+                .unwrap_or_else(|| mime_type_for_path(path).to_string());
+            read_file_content(&self.uri, path, mime_type).await
         } else if let Some(ref read_fn) = self.read_fn {
             // Dynamic content
-            read_fn(self.uri.clone()).await
+            read_fn(self.uri.clone())
+                .await
+                .map_err(|e| McpError::resource_execution(&e))
         } else {
-            Err("No content or read function provided".to_string())
+            Err(McpError::resource_execution(
+                "No content or read function provided",
+            ))
         }
     }
 }
 
+/// Infer a MIME type from a file extension, defaulting to `application/octet-stream`
+fn mime_type_for_path(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("txt" | "log") => "text/plain",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("html" | "htm") => "text/html",
+        Some("css") => "text/css",
+        Some("js") => "text/javascript",
+        Some("json") => "application/json",
+        Some("xml") => "application/xml",
+        Some("yaml" | "yml") => "application/yaml",
+        Some("toml") => "application/toml",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("pdf") => "application/pdf",
+        Some("zip") => "application/zip",
+        Some("gz") => "application/gzip",
+        Some("wasm") => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Whether content of this MIME type is served as text rather than a blob
+fn is_text_mime_type(mime_type: &str) -> bool {
+    mime_type.starts_with("text/")
+        || matches!(
+            mime_type,
+            "application/json"
+                | "application/xml"
+                | "application/yaml"
+                | "application/toml"
+                | "image/svg+xml"
+        )
+}
+
+async fn read_file_content(
+    uri: &str,
+    path: &Path,
+    mime_type: String,
+) -> McpResult<ResourceContent> {
+    let mut file = match tokio::fs::File::open(path).await {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(McpError::ResourceNotFound(uri.to_string()));
+        }
+        Err(e) => {
+            return Err(McpError::resource_execution(&format!(
+                "Failed to open {}: {}",
+                path.display(),
+                e
+            )));
+        }
+    };
+    let len = file.metadata().await?.len() as usize;
+
+    if is_text_mime_type(&mime_type) {
+        let mut bytes = Vec::with_capacity(len);
+        file.read_to_end(&mut bytes).await?;
+        return match String::from_utf8(bytes) {
+            Ok(text) => Ok(ResourceContent::Text(TextResourceContents {
+                uri: uri.to_string(),
+                mime_type: Some(mime_type),
+                meta: None,
+                text,
+            })),
+            Err(e) => Ok(ResourceContent::blob(
+                uri,
+                BASE64.encode(e.into_bytes()),
+                mime_type,
+            )),
+        };
+    }
+
+    let mut blob = String::with_capacity(len.div_ceil(3) * 4);
+    let mut chunk = vec![0u8; (len.max(1).div_ceil(3) * 3).min(FILE_CHUNK_SIZE)];
+    loop {
+        // Fill the whole chunk so only the final one can need padding
+        let mut filled = 0;
+        while filled < chunk.len() {
+            let n = file.read(&mut chunk[filled..]).await?;
+            if n == 0 {
+                break;
+            }
+            filled += n;
+        }
+        BASE64.encode_string(&chunk[..filled], &mut blob);
+        if filled < chunk.len() {
+            break;
+        }
+    }
+    Ok(ResourceContent::blob(uri, blob, mime_type))
+}
+
 // Implement all fine-grained traits for DynamicResource
 /// Implements HasResourceMetadata for DynamicResource providing name and title access
 impl HasResourceMetadata for DynamicResource {
@@ -251,6 +409,10 @@ impl HasResourceDescription for DynamicResource {
     fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
+
+    fn localized_descriptions(&self) -> Option<&HashMap<String, String>> {
+        self.localized_descriptions.as_ref()
+    }
 }
 
 /// Implements HasResourceUri for DynamicResource providing URI access
@@ -387,4 +549,69 @@ mod tests {
         assert_eq!(resource.mime_type(), Some("image/png"));
         assert!(resource.size().unwrap() > 0);
     }
+
+    #[tokio::test]
+    async fn test_resource_builder_with_text_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.md");
+        std::fs::write(&path, "# Notes\n\nLoaded lazily").unwrap();
+
+        let resource = ResourceBuilder::new("file:///notes.md")
+            .with_file(&path)
+            .build()
+            .expect("Failed to build resource");
+        assert_eq!(resource.mime_type(), Some("text/markdown"));
+
+        // Read at read() time, not at build time
+        std::fs::write(&path, "# Notes\n\nUpdated").unwrap();
+        match resource.read().await.expect("Failed to read content") {
+            ResourceContent::Text(text_content) => {
+                assert_eq!(text_content.text, "# Notes\n\nUpdated");
+                assert_eq!(text_content.mime_type.as_deref(), Some("text/markdown"));
+                assert_eq!(text_content.uri, "file:///notes.md");
+            }
+            _ => panic!("Expected text content"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resource_builder_with_binary_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dataset.png");
+        // Larger than one chunk and not a multiple of 3
+        let bytes: Vec<u8> = (0..FILE_CHUNK_SIZE + 1001)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        std::fs::write(&path, &bytes).unwrap();
+
+        let resource = ResourceBuilder::new("file:///dataset.png")
+            .with_file(&path)
+            .build()
+            .expect("Failed to build resource");
+        assert_eq!(resource.mime_type(), Some("image/png"));
+
+        match resource.read().await.expect("Failed to read content") {
+            ResourceContent::Blob(blob_content) => {
+                assert_eq!(blob_content.mime_type.as_deref(), Some("image/png"));
+                assert_eq!(BASE64.decode(&blob_content.blob).unwrap(), bytes);
+            }
+            _ => panic!("Expected blob content"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resource_builder_with_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let resource = ResourceBuilder::new("file:///missing.txt")
+            .with_file(dir.path().join("missing.txt"))
+            .build()
+            .expect("Failed to build resource");
+
+        let err = resource.read().await.unwrap_err();
+        assert!(
+            matches!(err, McpError::ResourceNotFound(ref uri) if uri == "file:///missing.txt"),
+            "{:?}",
+            err
+        );
+    }
 }
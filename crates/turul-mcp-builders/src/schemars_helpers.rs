@@ -65,6 +65,7 @@ pub fn convert_value_to_json_schema_with_defs(
         // true = accept anything, false = accept nothing
         // Both represented as generic objects
         return JsonSchema::Object {
+            title: None,
             description: None,
             properties: None,
             required: None,
@@ -78,6 +79,7 @@ pub fn convert_value_to_json_schema_with_defs(
         None => {
             // Not an object or boolean - return generic object
             return JsonSchema::Object {
+                title: None,
                 description: None,
                 properties: None,
                 required: None,
@@ -101,6 +103,7 @@ pub fn convert_value_to_json_schema_with_defs(
         }
         // Couldn't resolve reference - fall back to generic object
         return JsonSchema::Object {
+            title: None,
             description: obj
                 .get("description")
                 .and_then(|v| v.as_str())
@@ -128,6 +131,7 @@ pub fn convert_value_to_json_schema_with_defs(
         }
         // All schemas were null or couldn't parse - fall back to generic object
         return JsonSchema::Object {
+            title: None,
             description: obj
                 .get("description")
                 .and_then(|v| v.as_str())
@@ -175,6 +179,7 @@ pub fn convert_value_to_json_schema_with_defs(
     // Convert based on type
     match schema_type {
         Some("string") => JsonSchema::String {
+            title: None,
             description: obj
                 .get("description")
                 .and_then(|v| v.as_str())
@@ -192,31 +197,41 @@ pub fn convert_value_to_json_schema_with_defs(
                         .collect::<Option<Vec<_>>>()
                 })
             }),
+            default: obj
+                .get("default")
+                .and_then(|v| v.as_str())
+                .map(String::from),
         },
 
         Some("number") => JsonSchema::Number {
+            title: None,
             description: obj
                 .get("description")
                 .and_then(|v| v.as_str())
                 .map(String::from),
             minimum: obj.get("minimum").and_then(|v| v.as_f64()),
             maximum: obj.get("maximum").and_then(|v| v.as_f64()),
+            default: obj.get("default").and_then(|v| v.as_f64()),
         },
 
         Some("integer") => JsonSchema::Integer {
+            title: None,
             description: obj
                 .get("description")
                 .and_then(|v| v.as_str())
                 .map(String::from),
             minimum: obj.get("minimum").and_then(|v| v.as_i64()),
             maximum: obj.get("maximum").and_then(|v| v.as_i64()),
+            default: obj.get("default").and_then(|v| v.as_i64()),
         },
 
         Some("boolean") => JsonSchema::Boolean {
+            title: None,
             description: obj
                 .get("description")
                 .and_then(|v| v.as_str())
                 .map(String::from),
+            default: obj.get("default").and_then(|v| v.as_bool()),
         },
 
         Some("array") => {
@@ -226,6 +241,7 @@ pub fn convert_value_to_json_schema_with_defs(
                 .map(|v| Box::new(convert_value_to_json_schema_with_defs(v, definitions)));
 
             JsonSchema::Array {
+                title: None,
                 description: obj
                     .get("description")
                     .and_then(|v| v.as_str())
@@ -261,6 +277,7 @@ pub fn convert_value_to_json_schema_with_defs(
             });
 
             JsonSchema::Object {
+                title: None,
                 description: obj
                     .get("description")
                     .and_then(|v| v.as_str())
@@ -275,6 +292,7 @@ pub fn convert_value_to_json_schema_with_defs(
             // Unknown type, $ref, anyOf, oneOf, allOf, etc.
             // Return generic object (lossy but safe)
             JsonSchema::Object {
+                title: None,
                 description: obj
                     .get("description")
                     .and_then(|v| v.as_str())
@@ -410,6 +428,45 @@ impl ToolSchemaExt for ToolSchema {
     }
 }
 
+/// Schema lookup for tool parameters of types the derive macros don't know.
+///
+/// Generated code calls `(&ParamSchemaProbe::<T>(PhantomData)).param_schema()` with both
+/// traits in scope. Method resolution picks [`ParamSchemaFromTrait`] when `T`
+/// implements `ToJsonSchema` (types with the framework's `#[derive(JsonSchema)]`)
+/// and only autorefs to [`ParamSchemaFromSchemars`] for `schemars::JsonSchema` types.
+#[doc(hidden)]
+pub struct ParamSchemaProbe<T>(pub std::marker::PhantomData<T>);
+
+#[doc(hidden)]
+pub trait ParamSchemaFromTrait {
+    fn param_schema(&self) -> JsonSchema;
+}
+
+impl<T: turul_mcp_protocol::schema::ToJsonSchema> ParamSchemaFromTrait for ParamSchemaProbe<T> {
+    fn param_schema(&self) -> JsonSchema {
+        T::to_json_schema()
+    }
+}
+
+#[doc(hidden)]
+pub trait ParamSchemaFromSchemars {
+    fn param_schema(&self) -> JsonSchema;
+}
+
+impl<T: schemars::JsonSchema> ParamSchemaFromSchemars for &ParamSchemaProbe<T> {
+    fn param_schema(&self) -> JsonSchema {
+        let schema_value = serde_json::to_value(schemars::schema_for!(T))
+            .expect("schemars schema should serialize to JSON");
+        let definitions = schema_value
+            .get("definitions")
+            .or_else(|| schema_value.get("$defs"))
+            .and_then(|v| v.as_object())
+            .map(|obj| obj.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+            .unwrap_or_default();
+        convert_value_to_json_schema_with_defs(&schema_value, &definitions)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,6 +7,7 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
+use tracing::warn;
 
 // Import traits from local traits module
 use crate::traits::{
@@ -17,23 +18,38 @@ use crate::traits::{
 use turul_mcp_protocol::icons::Icon;
 use turul_mcp_protocol::schema::JsonSchema;
 use turul_mcp_protocol::tools::{ToolAnnotations, ToolExecution, ToolSchema};
+use turul_mcp_protocol::{McpError, McpResult};
 
 /// Type alias for dynamic tool execution function
 pub type DynamicToolFn =
     Box<dyn Fn(Value) -> Pin<Box<dyn Future<Output = Result<Value, String>> + Send>> + Send + Sync>;
 
+/// Type alias for a dynamic tool execution function returning typed MCP errors
+pub type FallibleToolFn =
+    Box<dyn Fn(Value) -> Pin<Box<dyn Future<Output = McpResult<Value>> + Send>> + Send + Sync>;
+
+/// Execution function of a [`DynamicTool`], as registered on the builder
+enum ToolExecuteFn {
+    /// From [`ToolBuilder::execute`]: errors are messages
+    Message(DynamicToolFn),
+    /// From [`ToolBuilder::fallible_execute`]: errors are `McpError`s
+    Fallible(FallibleToolFn),
+}
+
 /// Builder for creating tools at runtime
 pub struct ToolBuilder {
     name: String,
     title: Option<String>,
     description: Option<String>,
+    localized_descriptions: Option<HashMap<String, String>>,
     input_schema: ToolSchema,
     output_schema: Option<ToolSchema>,
     annotations: Option<ToolAnnotations>,
     execution: Option<ToolExecution>,
     icons: Option<Vec<Icon>>,
     meta: Option<HashMap<String, Value>>,
-    execute_fn: Option<DynamicToolFn>,
+    param_aliases: Vec<(String, Vec<String>)>,
+    execute_fn: Option<ToolExecuteFn>,
 }
 
 impl ToolBuilder {
@@ -43,12 +59,14 @@ impl ToolBuilder {
             name: name.into(),
             title: None,
             description: None,
+            localized_descriptions: None,
             input_schema: ToolSchema::object(),
             output_schema: None,
             annotations: None,
             execution: None,
             icons: None,
             meta: None,
+            param_aliases: Vec::new(),
             execute_fn: None,
         }
     }
@@ -65,6 +83,18 @@ impl ToolBuilder {
         self
     }
 
+    /// Add a translated description served to sessions whose locale matches `locale`
+    pub fn localized_description(
+        mut self,
+        locale: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        self.localized_descriptions
+            .get_or_insert_with(HashMap::new)
+            .insert(locale.into(), description.into());
+        self
+    }
+
     /// Add a parameter to the input schema
     pub fn param<T: Into<String>>(mut self, name: T, schema: JsonSchema) -> Self {
         let param_name = name.into();
@@ -110,6 +140,19 @@ impl ToolBuilder {
         self.required_param(name, JsonSchema::boolean().with_description(description))
     }
 
+    /// Accept `aliases` as former names of parameter `name`
+    ///
+    /// Only `name` is advertised in the input schema; see [`resolve_param_alias`].
+    pub fn param_aliases<I, S>(mut self, name: impl Into<String>, aliases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.param_aliases
+            .push((name.into(), aliases.into_iter().map(Into::into).collect()));
+        self
+    }
+
     /// Set the output schema
     pub fn output_schema(mut self, schema: ToolSchema) -> Self {
         self.output_schema = Some(schema);
@@ -142,6 +185,28 @@ impl ToolBuilder {
         self
     }
 
+    /// Set the output schema to an object with the given fields, all required
+    ///
+    /// With an output schema set, the server returns a tool's JSON object
+    /// result as `structuredContent` as well as text content.
+    pub fn output_object<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = (S, JsonSchema)>,
+        S: Into<String>,
+    {
+        let fields: Vec<(String, JsonSchema)> = fields
+            .into_iter()
+            .map(|(name, schema)| (name.into(), schema))
+            .collect();
+        let required = fields.iter().map(|(name, _)| name.clone()).collect();
+        self.output_schema = Some(
+            ToolSchema::object()
+                .with_properties(fields.into_iter().collect())
+                .with_required(required),
+        );
+        self
+    }
+
     /// Set annotations
     pub fn annotations(mut self, annotations: ToolAnnotations) -> Self {
         self.annotations = Some(annotations);
@@ -167,12 +232,32 @@ impl ToolBuilder {
     }
 
     /// Set the execution function
+    ///
+    /// Errors are reported as tool execution failures; use
+    /// [`fallible_execute`](Self::fallible_execute) to return a specific `McpError`.
     pub fn execute<F, Fut>(mut self, f: F) -> Self
     where
         F: Fn(Value) -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<Value, String>> + Send + 'static,
     {
-        self.execute_fn = Some(Box::new(move |args| Box::pin(f(args))));
+        self.execute_fn = Some(ToolExecuteFn::Message(Box::new(move |args| {
+            Box::pin(f(args))
+        })));
+        self
+    }
+
+    /// Set an execution function returning typed MCP errors
+    ///
+    /// An `McpError` such as [`McpError::param_out_of_range`] reaches the client
+    /// unchanged, with its own JSON-RPC error code.
+    pub fn fallible_execute<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = McpResult<Value>> + Send + 'static,
+    {
+        self.execute_fn = Some(ToolExecuteFn::Fallible(Box::new(move |args| {
+            Box::pin(f(args))
+        })));
         self
     }
 
@@ -184,12 +269,14 @@ impl ToolBuilder {
             name: self.name,
             title: self.title,
             description: self.description,
+            localized_descriptions: self.localized_descriptions,
             input_schema: self.input_schema,
             output_schema: self.output_schema,
             annotations: self.annotations,
             execution: self.execution,
             icons: self.icons,
             meta: self.meta,
+            param_aliases: self.param_aliases,
             execute_fn,
         })
     }
@@ -200,20 +287,227 @@ pub struct DynamicTool {
     name: String,
     title: Option<String>,
     description: Option<String>,
+    localized_descriptions: Option<HashMap<String, String>>,
     input_schema: ToolSchema,
     output_schema: Option<ToolSchema>,
     annotations: Option<ToolAnnotations>,
     execution: Option<ToolExecution>,
     icons: Option<Vec<Icon>>,
     meta: Option<HashMap<String, Value>>,
-    execute_fn: DynamicToolFn,
+    param_aliases: Vec<(String, Vec<String>)>,
+    execute_fn: ToolExecuteFn,
 }
 
 impl DynamicTool {
     /// Execute the tool with the given arguments
+    ///
+    /// Parameter aliases are resolved to their current names first.
     pub async fn execute(&self, args: Value) -> Result<Value, String> {
-        (self.execute_fn)(args).await
+        match &self.execute_fn {
+            ToolExecuteFn::Message(f) => f(self.resolve_aliases(args)).await,
+            ToolExecuteFn::Fallible(f) => f(self.resolve_aliases(args))
+                .await
+                .map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Execute the tool, keeping errors as `McpError`
+    ///
+    /// Errors from an [`execute`](ToolBuilder::execute) closure become
+    /// [`McpError::ToolExecutionError`].
+    pub async fn try_execute(&self, args: Value) -> McpResult<Value> {
+        match &self.execute_fn {
+            ToolExecuteFn::Message(f) => f(self.resolve_aliases(args))
+                .await
+                .map_err(|e| McpError::tool_execution(&e)),
+            ToolExecuteFn::Fallible(f) => f(self.resolve_aliases(args)).await,
+        }
+    }
+
+    fn resolve_aliases(&self, mut args: Value) -> Value {
+        for (name, aliases) in &self.param_aliases {
+            resolve_param_alias(&self.name, &mut args, name, aliases);
+        }
+        args
+    }
+}
+
+/// Rename an aliased argument to its current parameter name
+///
+/// When `args` lacks `name` but carries one of `aliases`, the first alias found
+/// (in declaration order) is renamed to `name` and a deprecation warning is
+/// logged. The current name wins when both are present. Leftover alias keys are
+/// dropped, so the tool only ever sees `name`. Used by `#[param(aliases = [...])]`
+/// and [`ToolBuilder::param_aliases`].
+pub fn resolve_param_alias<A: AsRef<str>>(
+    tool_name: &str,
+    args: &mut Value,
+    name: &str,
+    aliases: &[A],
+) {
+    let Some(object) = args.as_object_mut() else {
+        return;
+    };
+
+    for alias in aliases {
+        let alias = alias.as_ref();
+        let Some(value) = object.remove(alias) else {
+            continue;
+        };
+        if object.contains_key(name) {
+            warn!(
+                "Tool '{}': ignoring deprecated parameter '{}' because '{}' was also supplied",
+                tool_name, alias, name
+            );
+        } else {
+            warn!(
+                "Tool '{}': parameter '{}' is deprecated, use '{}' instead",
+                tool_name, alias, name
+            );
+            object.insert(name.to_string(), value);
+        }
+    }
+}
+
+/// Fill in a parameter's default value when its argument is absent or null
+///
+/// Missing (null) `args` become an object holding just the default. Used by
+/// `#[param(default = ...)]`.
+pub fn apply_param_default(args: &mut Value, name: &str, default: Value) {
+    if args.is_null() {
+        *args = Value::Object(serde_json::Map::new());
+    }
+    let Some(object) = args.as_object_mut() else {
+        return;
+    };
+    match object.get(name) {
+        Some(value) if !value.is_null() => {}
+        _ => {
+            object.insert(name.to_string(), default);
+        }
+    }
+}
+
+/// Check a string argument against a parameter's regex pattern
+///
+/// Absent and non-string arguments pass, leaving those to parameter extraction.
+/// A mismatch returns [`McpError::ParameterOutOfRange`]. Used by
+/// `#[param(pattern = "...")]`.
+pub fn check_param_pattern(args: &Value, name: &str, pattern: &regex::Regex) -> McpResult<()> {
+    match args.get(name).and_then(Value::as_str) {
+        Some(value) if !pattern.is_match(value) => Err(McpError::param_out_of_range(
+            name,
+            &format!("'{}'", value),
+            &format!("must match pattern '{}'", pattern.as_str()),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Check an argument against the range and length constraints in its schema
+///
+/// Walks nested object properties and array items, so constraints declared on
+/// the fields of a `#[derive(JsonSchema)]` parameter type are enforced too.
+/// Absent values and type mismatches pass, leaving those to parameter
+/// extraction. A violation returns [`McpError::ParameterOutOfRange`] naming the
+/// dotted path to the offending value.
+pub fn check_param_constraints(args: &Value, name: &str, schema: &JsonSchema) -> McpResult<()> {
+    match args.get(name) {
+        Some(value) => check_value_constraints(value, name, schema),
+        None => Ok(()),
+    }
+}
+
+fn check_value_constraints(value: &Value, path: &str, schema: &JsonSchema) -> McpResult<()> {
+    let out_of_range = |constraint: String| {
+        Err(McpError::param_out_of_range(
+            path,
+            &value.to_string(),
+            &constraint,
+        ))
+    };
+    match schema {
+        JsonSchema::Number {
+            minimum, maximum, ..
+        } => {
+            let Some(number) = value.as_f64() else {
+                return Ok(());
+            };
+            if let Some(min) = minimum
+                && number < *min
+            {
+                return out_of_range(format!("must be >= {}", min));
+            }
+            if let Some(max) = maximum
+                && number > *max
+            {
+                return out_of_range(format!("must be <= {}", max));
+            }
+        }
+        JsonSchema::Integer {
+            minimum, maximum, ..
+        } => {
+            let Some(number) = value.as_f64() else {
+                return Ok(());
+            };
+            if let Some(min) = minimum
+                && number < *min as f64
+            {
+                return out_of_range(format!("must be >= {}", min));
+            }
+            if let Some(max) = maximum
+                && number > *max as f64
+            {
+                return out_of_range(format!("must be <= {}", max));
+            }
+        }
+        JsonSchema::String {
+            min_length,
+            max_length,
+            ..
+        } => {
+            let Some(text) = value.as_str() else {
+                return Ok(());
+            };
+            let length = text.chars().count() as u64;
+            if let Some(min) = min_length
+                && length < *min
+            {
+                return out_of_range(format!("length must be >= {}", min));
+            }
+            if let Some(max) = max_length
+                && length > *max
+            {
+                return out_of_range(format!("length must be <= {}", max));
+            }
+        }
+        JsonSchema::Array {
+            items: Some(items), ..
+        } => {
+            for (index, item) in value.as_array().into_iter().flatten().enumerate() {
+                check_value_constraints(item, &format!("{}[{}]", path, index), items)?;
+            }
+        }
+        JsonSchema::Object {
+            properties: Some(properties),
+            ..
+        } => {
+            let Some(object) = value.as_object() else {
+                return Ok(());
+            };
+            for (field, field_schema) in properties {
+                if let Some(field_value) = object.get(field) {
+                    check_value_constraints(
+                        field_value,
+                        &format!("{}.{}", path, field),
+                        field_schema,
+                    )?;
+                }
+            }
+        }
+        _ => {}
     }
+    Ok(())
 }
 
 // Implement all fine-grained traits for DynamicTool
@@ -233,6 +527,10 @@ impl HasDescription for DynamicTool {
     fn description(&self) -> Option<&str> {
         self.description.as_deref()
     }
+
+    fn localized_descriptions(&self) -> Option<&HashMap<String, String>> {
+        self.localized_descriptions.as_ref()
+    }
 }
 
 /// Implements HasInputSchema for DynamicTool providing parameter schema access
@@ -286,6 +584,7 @@ impl HasExecution for DynamicTool {
 mod tests {
     use super::*;
     use serde_json::json;
+    use turul_mcp_protocol::tools::CallToolResult;
 
     #[tokio::test]
     async fn test_tool_builder_basic() {
@@ -313,6 +612,33 @@ mod tests {
         assert_eq!(result, json!({"result": "Hello, World"}));
     }
 
+    #[tokio::test]
+    async fn test_fallible_execute_keeps_mcp_error() {
+        let tool = ToolBuilder::new("set_volume")
+            .integer_param("level", "Volume from 0 to 10")
+            .fallible_execute(|args| async move {
+                let level = args.get("level").and_then(|v| v.as_i64()).unwrap_or(0);
+                if !(0..=10).contains(&level) {
+                    return Err(McpError::param_out_of_range(
+                        "level",
+                        &level.to_string(),
+                        "0..=10",
+                    ));
+                }
+                Ok(json!({"level": level}))
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            tool.try_execute(json!({"level": 4})).await.unwrap(),
+            json!({"level": 4})
+        );
+        let error = tool.try_execute(json!({"level": 11})).await.unwrap_err();
+        assert!(matches!(error, McpError::ParameterOutOfRange { .. }));
+        assert!(tool.execute(json!({"level": 11})).await.is_err());
+    }
+
     #[test]
     fn test_tool_builder_schema_generation() {
         let tool = ToolBuilder::new("calculator")
@@ -336,4 +662,138 @@ mod tests {
         let output_schema = tool.output_schema();
         assert!(output_schema.is_some());
     }
+
+    #[tokio::test]
+    async fn test_tool_builder_output_object_structured_content() {
+        let tool = ToolBuilder::new("divide")
+            .number_param("a", "Dividend")
+            .number_param("b", "Divisor")
+            .output_object([
+                ("quotient", JsonSchema::number()),
+                ("remainder", JsonSchema::number()),
+            ])
+            .execute(|args| async move {
+                let a = args.get("a").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                let b = args.get("b").and_then(|v| v.as_f64()).unwrap_or(1.0);
+                Ok(json!({"quotient": (a / b).trunc(), "remainder": a % b}))
+            })
+            .build()
+            .expect("Failed to build divide tool");
+
+        let output_schema = tool.output_schema().expect("Expected output schema");
+        assert_eq!(
+            output_schema.required,
+            Some(vec!["quotient".to_string(), "remainder".to_string()])
+        );
+        let properties = output_schema.properties.as_ref().unwrap();
+        assert!(properties.contains_key("quotient"));
+        assert!(properties.contains_key("remainder"));
+
+        let result = tool.execute(json!({"a": 7.0, "b": 2.0})).await.unwrap();
+        let response =
+            CallToolResult::from_result_with_schema(&result, tool.output_schema()).unwrap();
+        assert_eq!(
+            response.structured_content,
+            Some(json!({"quotient": 3.0, "remainder": 1.0}))
+        );
+    }
+
+    #[test]
+    fn test_resolve_param_alias() {
+        // Alias only: renamed to the current name
+        let mut args = json!({"old_query": "rust"});
+        resolve_param_alias("search", &mut args, "query", &["old_query"]);
+        assert_eq!(args, json!({"query": "rust"}));
+
+        // Current name only: untouched
+        let mut args = json!({"query": "rust"});
+        resolve_param_alias("search", &mut args, "query", &["old_query"]);
+        assert_eq!(args, json!({"query": "rust"}));
+
+        // Both: current name wins and the alias is dropped
+        let mut args = json!({"query": "new", "old_query": "old"});
+        resolve_param_alias("search", &mut args, "query", &["old_query"]);
+        assert_eq!(args, json!({"query": "new"}));
+
+        // Several aliases: the first declared one wins
+        let mut args = json!({"q": "second", "old_query": "first"});
+        resolve_param_alias("search", &mut args, "query", &["old_query", "q"]);
+        assert_eq!(args, json!({"query": "first"}));
+    }
+
+    #[test]
+    fn test_apply_param_default() {
+        let mut args = json!({"query": "rust"});
+        apply_param_default(&mut args, "limit", json!(10));
+        assert_eq!(args, json!({"query": "rust", "limit": 10}));
+
+        // Supplied values win, explicit nulls are replaced
+        let mut args = json!({"limit": 3});
+        apply_param_default(&mut args, "limit", json!(10));
+        assert_eq!(args, json!({"limit": 3}));
+        let mut args = json!({"limit": null});
+        apply_param_default(&mut args, "limit", json!(10));
+        assert_eq!(args, json!({"limit": 10}));
+
+        let mut args = Value::Null;
+        apply_param_default(&mut args, "limit", json!(10));
+        assert_eq!(args, json!({"limit": 10}));
+    }
+
+    #[test]
+    fn test_check_param_constraints() {
+        let schema = JsonSchema::object_with_properties(HashMap::from([
+            (
+                "volume".to_string(),
+                JsonSchema::integer().with_minimum(0.0).with_maximum(100.0),
+            ),
+            (
+                "tags".to_string(),
+                JsonSchema::array(JsonSchema::string().with_min_length(1)),
+            ),
+        ]));
+
+        let args = json!({"settings": {"volume": 50, "tags": ["a"]}});
+        assert!(check_param_constraints(&args, "settings", &schema).is_ok());
+        assert!(check_param_constraints(&json!({}), "settings", &schema).is_ok());
+
+        let args = json!({"settings": {"volume": 101}});
+        let err = check_param_constraints(&args, "settings", &schema).unwrap_err();
+        assert!(matches!(
+            err,
+            McpError::ParameterOutOfRange { ref param, ref constraint, .. }
+                if param == "settings.volume" && constraint == "must be <= 100"
+        ));
+
+        let args = json!({"settings": {"tags": ["a", ""]}});
+        let err = check_param_constraints(&args, "settings", &schema).unwrap_err();
+        assert!(matches!(
+            err,
+            McpError::ParameterOutOfRange { ref param, .. } if param == "settings.tags[1]"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tool_builder_param_aliases() {
+        let tool = ToolBuilder::new("greet")
+            .string_param("name", "Who to greet")
+            .param_aliases("name", ["user"])
+            .execute(|args| async move {
+                let name = args
+                    .get("name")
+                    .and_then(|v| v.as_str())
+                    .ok_or("Missing name parameter")?;
+                Ok(json!({"result": format!("Hello, {}", name)}))
+            })
+            .build()
+            .expect("Failed to build tool");
+
+        // Schema advertises only the current name
+        let properties = tool.input_schema().properties.as_ref().unwrap();
+        assert!(properties.contains_key("name"));
+        assert!(!properties.contains_key("user"));
+
+        let result = tool.execute(json!({"user": "Ada"})).await.unwrap();
+        assert_eq!(result, json!({"result": "Hello, Ada"}));
+    }
 }
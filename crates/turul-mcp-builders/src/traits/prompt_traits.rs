@@ -25,6 +25,13 @@ pub trait HasPromptDescription {
     fn description(&self) -> Option<&str> {
         None
     }
+
+    /// Translations of the description keyed by locale tag (e.g. `"fr"`, `"pt-BR"`).
+    /// Servers pick the best match for the session's locale and fall back to
+    /// [`description`](Self::description).
+    fn localized_descriptions(&self) -> Option<&HashMap<String, String>> {
+        None
+    }
 }
 
 /// Prompt arguments trait
@@ -7,8 +7,8 @@ use serde_json::Value;
 use std::collections::HashMap;
 
 // Import protocol types (spec-defined)
-use turul_mcp_protocol::Resource;
 use turul_mcp_protocol::meta::Annotations;
+use turul_mcp_protocol::{Resource, ResourceTemplate};
 
 pub trait HasResourceMetadata {
     /// Programmatic identifier (fallback display name)
@@ -25,6 +25,13 @@ pub trait HasResourceDescription {
     fn description(&self) -> Option<&str> {
         None
     }
+
+    /// Translations of the description keyed by locale tag (e.g. `"fr"`, `"pt-BR"`).
+    /// Servers pick the best match for the session's locale and fall back to
+    /// [`description`](Self::description).
+    fn localized_descriptions(&self) -> Option<&HashMap<String, String>> {
+        None
+    }
 }
 
 /// Resource URI trait
@@ -238,6 +245,20 @@ pub trait ResourceDefinition:
             meta: self.resource_meta().cloned(),
         }
     }
+
+    /// Convert to a ResourceTemplate advertising `uri_template` for `resources/templates/list`
+    fn to_resource_template(&self, uri_template: &str) -> ResourceTemplate {
+        ResourceTemplate {
+            name: self.name().to_string(),
+            title: self.title().map(String::from),
+            uri_template: uri_template.to_string(),
+            description: self.description().map(String::from),
+            mime_type: self.mime_type().map(String::from),
+            annotations: self.annotations().cloned(),
+            icons: self.icons().cloned(),
+            meta: self.resource_meta().cloned(),
+        }
+    }
 }
 impl<T> ResourceDefinition for T where
     T: HasResourceMetadata
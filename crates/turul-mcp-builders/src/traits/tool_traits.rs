@@ -26,6 +26,13 @@ pub trait HasDescription {
     fn description(&self) -> Option<&str> {
         None
     }
+
+    /// Translations of the description keyed by locale tag (e.g. `"fr"`, `"pt-BR"`).
+    /// Servers pick the best match for the session's locale and fall back to
+    /// [`description`](Self::description).
+    fn localized_descriptions(&self) -> Option<&HashMap<String, String>> {
+        None
+    }
 }
 
 /// Input schema trait
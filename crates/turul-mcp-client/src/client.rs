@@ -2,25 +2,29 @@
 
 use serde_json::{Value, json};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
-use tokio::time::timeout;
+use tokio::time::{Instant, timeout, timeout_at};
 use tracing::{debug, info, warn};
 
 use crate::config::ClientConfig;
-use crate::error::{McpClientError, McpClientResult, SessionError};
+use crate::error::{McpClientError, McpClientResult, ProtocolError, SessionError, TransportError};
 use crate::session::{SessionManager, SessionState};
 use crate::streaming::StreamHandler;
 use crate::transport::BoxedTransport;
+use turul_mcp_json_rpc_server::Correlation;
 
 // Re-export protocol types for convenience
+use turul_mcp_protocol::logging::LoggingLevel;
 use turul_mcp_protocol::meta::Cursor;
 use turul_mcp_protocol::resources::{ListResourceTemplatesResult, ResourceTemplate};
 use turul_mcp_protocol::tasks::{
-    CancelTaskResult, CreateTaskResult, GetTaskResult, ListTasksResult, Task,
+    CancelTaskResult, CreateTaskResult, GetTaskResult, ListTasksResult, Task, TaskStatus,
 };
 use turul_mcp_protocol::{
-    CallToolResult, GetPromptResult, InitializeResult, ListPromptsResult, ListResourcesResult,
-    ListToolsResult, Prompt, ReadResourceResult, Resource, Tool,
+    CallToolResult, GetPromptResult, Implementation, InitializeResult, JsonSchema,
+    ListPromptsResult, ListResourcesResult, ListToolsResult, McpVersion, Prompt,
+    ReadResourceResult, Resource, ServerCapabilities, Tool,
 };
 
 /// Callback type for receiving server notifications.
@@ -29,6 +33,10 @@ use turul_mcp_protocol::{
 /// and the optional params object.
 pub type NotificationCallback = Arc<dyn Fn(&str, Option<&Value>) + Send + Sync>;
 
+/// Poll interval used by [`McpClient::call_tool_as_task`] when the server
+/// does not suggest one via `pollInterval`.
+pub const DEFAULT_TASK_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Main MCP client
 pub struct McpClient {
     /// Transport layer — `Arc<BoxedTransport>` (no Mutex) so concurrent
@@ -53,6 +61,10 @@ pub struct McpClient {
     cached_prompts: Arc<RwLock<Option<Vec<Prompt>>>>,
     /// User-supplied notification callback
     notification_callback: Option<NotificationCallback>,
+    /// Wakes task pollers when the server sends `notifications/tasks/status`
+    task_status_notify: Arc<tokio::sync::Notify>,
+    /// Check `tools/call` arguments against the cached tool schemas
+    validate_arguments: bool,
 }
 
 impl Drop for McpClient {
@@ -128,6 +140,8 @@ impl McpClient {
             cached_resources: Arc::new(RwLock::new(None)),
             cached_prompts: Arc::new(RwLock::new(None)),
             notification_callback,
+            task_status_notify: Arc::new(tokio::sync::Notify::new()),
+            validate_arguments: false,
         }
     }
 
@@ -162,6 +176,7 @@ impl McpClient {
                     let cached_resources = Arc::clone(&self.cached_resources);
                     let cached_prompts = Arc::clone(&self.cached_prompts);
                     let user_callback = self.notification_callback.clone();
+                    let task_status_notify = Arc::clone(&self.task_status_notify);
 
                     stream_handler.on_notification(move |notification| {
                         let method = notification
@@ -196,6 +211,10 @@ impl McpClient {
                                     warn!("Could not acquire prompt cache write lock for invalidation");
                                 }
                             }
+                            "notifications/tasks/status" => {
+                                debug!("Server sent notifications/tasks/status — waking task pollers");
+                                task_status_notify.notify_waiters();
+                            }
                             _ => {
                                 debug!(method = method, "Received server notification");
                             }
@@ -362,6 +381,7 @@ impl McpClient {
             .initialize(
                 init_request.capabilities,
                 init_response.capabilities,
+                init_response.server_info,
                 init_response.protocol_version,
             )
             .await?;
@@ -534,15 +554,8 @@ impl McpClient {
         .map_err(|_| McpClientError::Timeout)??;
 
         // Check for JSON-RPC error
-        if let Some(error) = response.get("error") {
-            let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1) as i32;
-            let message = error
-                .get("message")
-                .and_then(|m| m.as_str())
-                .unwrap_or("Unknown error");
-            let data = error.get("data").cloned();
-
-            return Err(McpClientError::server_error(code, message, data));
+        if let Some(error) = json_rpc_error(&response) {
+            return Err(error);
         }
 
         Ok(response)
@@ -648,6 +661,7 @@ impl McpClient {
     /// Call a tool
     pub async fn call_tool(&self, name: &str, arguments: Value) -> McpClientResult<CallToolResult> {
         debug!(tool = name, "Calling tool");
+        self.check_tool_arguments(name, &arguments).await?;
 
         let request = json!({
             "jsonrpc": "2.0",
@@ -671,6 +685,73 @@ impl McpClient {
         Ok(call_response)
     }
 
+    /// Allocate a request id for an upcoming call and correlate it with this session.
+    ///
+    /// Pass the result to [`call_tool_with_progress`](Self::call_tool_with_progress),
+    /// then match incoming `notifications/progress` back to the call with
+    /// [`Correlation::parse`] on their `progressToken`.
+    pub async fn next_correlation(&self) -> Correlation {
+        let correlation = Correlation::new(self.next_request_id());
+        match self.session.session_id_optional().await {
+            Some(session_id) => correlation.with_session(session_id),
+            None => correlation,
+        }
+    }
+
+    /// Call a tool, requesting progress notifications under the correlation's token
+    ///
+    /// The request is sent with the correlation's request id and with
+    /// `_meta.progressToken` set to [`Correlation::progress_token`].
+    pub async fn call_tool_with_progress(
+        &self,
+        correlation: &Correlation,
+        name: &str,
+        arguments: Value,
+    ) -> McpClientResult<CallToolResult> {
+        debug!(tool = name, correlation = %correlation, "Calling tool with progress");
+        self.check_tool_arguments(name, &arguments).await?;
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": correlation.request_id(),
+            "params": {
+                "name": name,
+                "arguments": arguments,
+                "_meta": { "progressToken": correlation.progress_token() }
+            }
+        });
+
+        let response = self.send_request_internal(request).await?;
+        let call_response: CallToolResult =
+            serde_json::from_value(response.get("result").cloned().unwrap_or(Value::Null))?;
+
+        debug!(
+            tool = name,
+            is_error = call_response.is_error,
+            "Tool call completed"
+        );
+        Ok(call_response)
+    }
+
+    /// Validate tool arguments against the cached schema when enabled
+    ///
+    /// Tools missing from the cache (or an empty cache) are passed through;
+    /// the server remains the authority on what it accepts.
+    async fn check_tool_arguments(&self, name: &str, arguments: &Value) -> McpClientResult<()> {
+        if !self.validate_arguments {
+            return Ok(());
+        }
+        let cache = self.cached_tools.read().await;
+        match cache
+            .as_ref()
+            .and_then(|tools| tools.iter().find(|t| t.name == name))
+        {
+            Some(tool) => validate_tool_arguments(tool, arguments),
+            None => Ok(()),
+        }
+    }
+
     /// List available resources (returns cached result if available)
     ///
     /// The cache is automatically invalidated when the server sends a
@@ -758,10 +839,10 @@ impl McpClient {
     }
 
     /// Read a resource
-    pub async fn read_resource(
-        &self,
-        uri: &str,
-    ) -> McpClientResult<Vec<turul_mcp_protocol::ResourceContent>> {
+    ///
+    /// Returns the full `resources/read` result; the content blocks are in
+    /// [`contents`](ReadResourceResult::contents).
+    pub async fn read_resource(&self, uri: &str) -> McpClientResult<ReadResourceResult> {
         debug!(uri = uri, "Reading resource");
 
         let request = json!({
@@ -782,7 +863,7 @@ impl McpClient {
             content_count = read_response.contents.len(),
             "Resource read completed"
         );
-        Ok(read_response.contents)
+        Ok(read_response)
     }
 
     /// List available resource templates
@@ -973,6 +1054,107 @@ impl McpClient {
         Ok(())
     }
 
+    /// Set the minimum level of `notifications/message` the server sends this session
+    pub async fn set_log_level(&self, level: LoggingLevel) -> McpClientResult<()> {
+        debug!(level = ?level, "Setting logging level");
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": "logging/setLevel",
+            "id": self.next_request_id(),
+            "params": { "level": level }
+        });
+
+        self.send_request_internal(request).await?;
+        Ok(())
+    }
+
+    /// Send several requests and notifications as one JSON-RPC batch
+    ///
+    /// Results are returned in input order, whatever order the server answers
+    /// in. A request resolves to its `result` member or the JSON-RPC error the
+    /// server returned for it; a notification resolves to `Ok(Value::Null)`
+    /// once the batch is accepted. If the server rejects the whole batch with a
+    /// single error object, every entry gets that error.
+    ///
+    /// Transports without batch support send the items individually. Batches
+    /// are not retried.
+    ///
+    /// ```rust,no_run
+    /// # use turul_mcp_client::prelude::*;
+    /// # use serde_json::json;
+    /// # async fn example(client: &McpClient) {
+    /// let results = client
+    ///     .batch(vec![
+    ///         BatchItem::call_tool("add", json!({"a": 1, "b": 2})),
+    ///         BatchItem::call_tool("add", json!({"a": 3, "b": 4})),
+    ///         BatchItem::notification("notifications/roots/list_changed", json!({})),
+    ///     ])
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn batch(&self, requests: Vec<BatchItem>) -> Vec<McpClientResult<Value>> {
+        if requests.is_empty() {
+            return Vec::new();
+        }
+        if !self.session.is_ready().await {
+            return requests
+                .iter()
+                .map(|_| Err(SessionError::NotInitialized.into()))
+                .collect();
+        }
+
+        let ids: Vec<Option<String>> = requests
+            .iter()
+            .map(|item| item.expects_response().then(|| self.next_request_id()))
+            .collect();
+        let messages: Vec<Value> = requests
+            .into_iter()
+            .zip(&ids)
+            .map(|(item, id)| item.into_message(id.as_deref()))
+            .collect();
+
+        debug!(size = messages.len(), "Sending batch");
+
+        let responses = match timeout(
+            self.config.timeouts.request,
+            self.transport.send_batch(messages.clone()),
+        )
+        .await
+        {
+            Ok(Ok(responses)) => responses,
+            Ok(Err(McpClientError::Transport(TransportError::Unsupported(reason)))) => {
+                debug!(reason = %reason, "Sending batch items individually");
+                let sends = messages
+                    .into_iter()
+                    .map(|message| self.send_batch_item(message));
+                return futures::future::join_all(sends).await;
+            }
+            Ok(Err(e)) => {
+                warn!(error = %e, "Batch request failed");
+                let message = format!("Batch request failed: {}", e);
+                return ids
+                    .iter()
+                    .map(|_| Err(McpClientError::generic(message.clone())))
+                    .collect();
+            }
+            Err(_) => return ids.iter().map(|_| Err(McpClientError::Timeout)).collect(),
+        };
+
+        self.session.update_activity().await;
+        demux_batch_responses(&ids, responses)
+    }
+
+    /// Send one batch entry on its own (fallback for transports without batches)
+    async fn send_batch_item(&self, message: Value) -> McpClientResult<Value> {
+        if message.get("id").is_none() {
+            self.send_notification_internal(message).await?;
+            return Ok(Value::Null);
+        }
+        let response = self.send_request_internal(message).await?;
+        Ok(response.get("result").cloned().unwrap_or(Value::Null))
+    }
+
     // === Task Operations ===
 
     /// Get a task by ID
@@ -1108,6 +1290,7 @@ impl McpClient {
         ttl_ms: Option<i64>,
     ) -> McpClientResult<ToolCallResponse> {
         debug!(tool = name, "Calling tool with task augmentation");
+        self.check_tool_arguments(name, &arguments).await?;
 
         let mut params = json!({
             "name": name,
@@ -1150,6 +1333,173 @@ impl McpClient {
         }
     }
 
+    /// Call a tool as a task and wait for its outcome
+    ///
+    /// Issues a task-augmented `tools/call`, polls `tasks/get` at the server's
+    /// suggested `pollInterval` (or [`DEFAULT_TASK_POLL_INTERVAL`]) until the task
+    /// reaches a terminal status, then fetches the payload with `tasks/result`.
+    /// `on_progress` is invoked with each status change, starting with the
+    /// status the task was created in.
+    ///
+    /// If the task enters `input_required`, polling stops and
+    /// [`TaskCallOutcome::InputRequired`] is returned so the caller can respond
+    /// and resume with [`wait_for_task`](Self::wait_for_task). If the server runs
+    /// the tool synchronously, its result is returned directly.
+    ///
+    /// If `timeout` elapses, or the returned future is dropped while the task is
+    /// still running, a best-effort `tasks/cancel` is sent for the task.
+    pub async fn call_tool_as_task(
+        &self,
+        name: &str,
+        arguments: Value,
+        timeout: Duration,
+        on_progress: impl FnMut(TaskStatus),
+    ) -> McpClientResult<TaskCallOutcome> {
+        let deadline = Instant::now() + timeout;
+
+        let response = timeout_at(deadline, self.call_tool_with_task(name, arguments, None))
+            .await
+            .map_err(|_| McpClientError::Timeout)??;
+
+        match response {
+            ToolCallResponse::Immediate(result) => Ok(TaskCallOutcome::Completed(result)),
+            ToolCallResponse::TaskCreated(task) => {
+                self.poll_task_until(
+                    task,
+                    deadline,
+                    DEFAULT_TASK_POLL_INTERVAL,
+                    true,
+                    on_progress,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Call a tool as a task and wait for its final result
+    ///
+    /// Like [`call_tool_as_task`](Self::call_tool_as_task), but keeps polling
+    /// through `input_required` (for input the server gathers itself, e.g. via
+    /// elicitation) and resolves only once the task is terminal. Failed tasks
+    /// surface the error the server returns from `tasks/result`.
+    ///
+    /// Polling honours the server's `pollInterval` and wakes early when the
+    /// server sends `notifications/tasks/status`. If `options.timeout` elapses or
+    /// the returned future is dropped first, `tasks/cancel` is sent.
+    pub async fn call_tool_await_task(
+        &self,
+        name: &str,
+        arguments: Value,
+        options: TaskPollOptions,
+    ) -> McpClientResult<CallToolResult> {
+        let deadline = Instant::now() + options.timeout;
+
+        let response = timeout_at(
+            deadline,
+            self.call_tool_with_task(name, arguments, options.ttl_ms),
+        )
+        .await
+        .map_err(|_| McpClientError::Timeout)??;
+
+        let task = match response {
+            ToolCallResponse::Immediate(result) => return Ok(result),
+            ToolCallResponse::TaskCreated(task) => task,
+        };
+
+        match self
+            .poll_task_until(task, deadline, options.poll_interval, false, |_| {})
+            .await?
+        {
+            TaskCallOutcome::Completed(result) => Ok(result),
+            TaskCallOutcome::InputRequired(_) => {
+                unreachable!("input_required is polled through when not returning early")
+            }
+        }
+    }
+
+    /// Wait for an existing task to finish
+    ///
+    /// Resumes polling a task previously returned as
+    /// [`TaskCallOutcome::InputRequired`], with the same progress, timeout and
+    /// cancel-on-drop behaviour as [`call_tool_as_task`](Self::call_tool_as_task).
+    pub async fn wait_for_task(
+        &self,
+        task_id: &str,
+        timeout: Duration,
+        on_progress: impl FnMut(TaskStatus),
+    ) -> McpClientResult<TaskCallOutcome> {
+        let deadline = Instant::now() + timeout;
+
+        let task = timeout_at(deadline, self.get_task(task_id))
+            .await
+            .map_err(|_| McpClientError::Timeout)??;
+
+        self.poll_task_until(
+            task,
+            deadline,
+            DEFAULT_TASK_POLL_INTERVAL,
+            true,
+            on_progress,
+        )
+        .await
+    }
+
+    /// Poll `task` until it is terminal (or needs input, if `return_on_input`),
+    /// cancelling it if the deadline passes or the future is dropped first.
+    async fn poll_task_until(
+        &self,
+        task: Task,
+        deadline: Instant,
+        fallback_interval: Duration,
+        return_on_input: bool,
+        mut on_progress: impl FnMut(TaskStatus),
+    ) -> McpClientResult<TaskCallOutcome> {
+        let mut cancel_guard = TaskCancelGuard::new(self, &task.task_id);
+        let task_id = task.task_id.clone();
+
+        let outcome = timeout_at(deadline, async {
+            let mut task = task;
+            let mut last_status = None;
+
+            loop {
+                if last_status != Some(task.status) {
+                    on_progress(task.status);
+                    last_status = Some(task.status);
+                }
+
+                match task.status {
+                    TaskStatus::Working => {}
+                    TaskStatus::InputRequired if !return_on_input => {}
+                    TaskStatus::InputRequired => {
+                        return Ok(TaskCallOutcome::InputRequired(task));
+                    }
+                    TaskStatus::Completed | TaskStatus::Failed | TaskStatus::Cancelled => break,
+                }
+
+                let interval = task
+                    .poll_interval
+                    .map(Duration::from_millis)
+                    .unwrap_or(fallback_interval);
+                tokio::select! {
+                    _ = tokio::time::sleep(interval) => {}
+                    _ = self.task_status_notify.notified() => {}
+                }
+
+                task = self.get_task(&task_id).await?;
+            }
+
+            let result = self.get_task_result(&task_id).await?;
+            Ok(TaskCallOutcome::Completed(serde_json::from_value(result)?))
+        })
+        .await
+        .map_err(|_| McpClientError::Timeout)?;
+
+        // Terminal, waiting on input, or a request error: nothing to cancel.
+        // Only a timeout or a dropped future leaves the guard armed.
+        cancel_guard.disarm();
+        outcome
+    }
+
     /// Get stream handler for event callbacks
     pub async fn stream_handler(&self) -> tokio::sync::MutexGuard<'_, StreamHandler> {
         self.stream_handler.lock().await
@@ -1171,6 +1521,39 @@ impl McpClient {
         self.session.session_info().await
     }
 
+    /// Capabilities the server advertised in its `initialize` response
+    ///
+    /// Returns `None` until [`connect`](Self::connect) has completed, so calls
+    /// can be guarded on what the server supports:
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: &turul_mcp_client::McpClient) {
+    /// let subscribe = client
+    ///     .server_capabilities()
+    ///     .await
+    ///     .and_then(|caps| caps.resources)
+    ///     .and_then(|resources| resources.subscribe)
+    ///     .unwrap_or(false);
+    /// # }
+    /// ```
+    pub async fn server_capabilities(&self) -> Option<ServerCapabilities> {
+        self.session.session_info().await.server_capabilities
+    }
+
+    /// Server name and version from its `initialize` response
+    pub async fn server_info(&self) -> Option<Implementation> {
+        self.session.session_info().await.server_info
+    }
+
+    /// Protocol version negotiated during `initialize`
+    pub async fn negotiated_version(&self) -> Option<McpVersion> {
+        self.session
+            .session_info()
+            .await
+            .protocol_version
+            .and_then(|version| version.parse().ok())
+    }
+
     /// Get transport statistics
     pub async fn transport_stats(&self) -> crate::transport::TransportStatistics {
         self.transport.statistics()
@@ -1243,12 +1626,327 @@ impl ToolCallResponse {
     }
 }
 
+/// Outcome of [`McpClient::call_tool_as_task`] and [`McpClient::wait_for_task`]
+#[derive(Debug)]
+pub enum TaskCallOutcome {
+    /// The tool finished, either synchronously or as a task that reached a
+    /// terminal status
+    Completed(CallToolResult),
+    /// The task is waiting for input — respond, then resume with
+    /// `wait_for_task()`
+    InputRequired(Task),
+}
+
+impl TaskCallOutcome {
+    /// Returns the result if the tool finished
+    pub fn completed(&self) -> Option<&CallToolResult> {
+        match self {
+            TaskCallOutcome::Completed(result) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Returns the task if it is waiting for input
+    pub fn input_required(&self) -> Option<&Task> {
+        match self {
+            TaskCallOutcome::InputRequired(task) => Some(task),
+            _ => None,
+        }
+    }
+}
+
+/// Options for [`McpClient::call_tool_await_task`]
+#[derive(Debug, Clone)]
+pub struct TaskPollOptions {
+    /// Overall time allowed for the call, from creation to final result
+    pub timeout: Duration,
+    /// Poll interval used when the server does not suggest one
+    pub poll_interval: Duration,
+    /// Requested task TTL in milliseconds
+    pub ttl_ms: Option<i64>,
+}
+
+impl Default for TaskPollOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(300),
+            poll_interval: DEFAULT_TASK_POLL_INTERVAL,
+            ttl_ms: None,
+        }
+    }
+}
+
+impl TaskPollOptions {
+    /// Set the overall timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the fallback poll interval
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set the requested task TTL in milliseconds
+    pub fn with_ttl_ms(mut self, ttl_ms: i64) -> Self {
+        self.ttl_ms = Some(ttl_ms);
+        self
+    }
+}
+
+/// One entry in an [`McpClient::batch`] call
+#[derive(Debug, Clone)]
+pub enum BatchItem {
+    /// A request; its response is matched back by id
+    Request { method: String, params: Value },
+    /// A notification; the server sends no response
+    Notification { method: String, params: Value },
+}
+
+impl BatchItem {
+    /// Create a request entry
+    pub fn request(method: impl Into<String>, params: Value) -> Self {
+        BatchItem::Request {
+            method: method.into(),
+            params,
+        }
+    }
+
+    /// Create a notification entry
+    pub fn notification(method: impl Into<String>, params: Value) -> Self {
+        BatchItem::Notification {
+            method: method.into(),
+            params,
+        }
+    }
+
+    /// Create a `tools/call` request entry
+    pub fn call_tool(name: &str, arguments: Value) -> Self {
+        Self::request(
+            "tools/call",
+            json!({
+                "name": name,
+                "arguments": arguments
+            }),
+        )
+    }
+
+    fn expects_response(&self) -> bool {
+        matches!(self, BatchItem::Request { .. })
+    }
+
+    fn into_message(self, id: Option<&str>) -> Value {
+        let (method, params) = match self {
+            BatchItem::Request { method, params } | BatchItem::Notification { method, params } => {
+                (method, params)
+            }
+        };
+        let mut message = json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params
+        });
+        if let Some(id) = id {
+            message["id"] = json!(id);
+        }
+        message
+    }
+}
+
+/// Convert a JSON-RPC error member into a client error
+fn json_rpc_error(response: &Value) -> Option<McpClientError> {
+    let error = response.get("error")?;
+    let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1) as i32;
+    let message = error
+        .get("message")
+        .and_then(|m| m.as_str())
+        .unwrap_or("Unknown error");
+    let data = error.get("data").cloned();
+    Some(McpClientError::server_error(code, message, data))
+}
+
+/// Check required fields and top-level property types against a tool's input schema
+///
+/// Fields the schema does not declare are allowed, as are `null` values for
+/// optional fields.
+fn validate_tool_arguments(tool: &Tool, arguments: &Value) -> McpClientResult<()> {
+    let empty = serde_json::Map::new();
+    let fields = match arguments {
+        Value::Object(fields) => fields,
+        Value::Null => &empty,
+        other => {
+            return Err(McpClientError::invalid_arguments(
+                &tool.name,
+                "arguments",
+                format!("expected object, found {}", json_type_name(other)),
+            ));
+        }
+    };
+
+    let schema = &tool.input_schema;
+    let required = schema.required.as_deref().unwrap_or_default();
+    if let Some(missing) = required.iter().find(|field| !fields.contains_key(*field)) {
+        return Err(McpClientError::invalid_arguments(
+            &tool.name,
+            missing,
+            "is required",
+        ));
+    }
+
+    let Some(properties) = &schema.properties else {
+        return Ok(());
+    };
+    for (field, value) in fields {
+        let Some(property) = properties.get(field) else {
+            continue;
+        };
+        if value.is_null() && !required.contains(field) {
+            continue;
+        }
+        let (expected, accepted) = match property {
+            JsonSchema::String { .. } => ("string", value.is_string()),
+            JsonSchema::Number { .. } => ("number", value.is_number()),
+            JsonSchema::Integer { .. } => ("integer", value.is_i64() || value.is_u64()),
+            JsonSchema::Boolean { .. } => ("boolean", value.is_boolean()),
+            JsonSchema::Array { .. } => ("array", value.is_array()),
+            JsonSchema::Object { .. } => ("object", value.is_object()),
+            // Shared definitions are not resolved locally; leave them to the server
+            JsonSchema::Ref { .. } => continue,
+        };
+        if !accepted {
+            return Err(McpClientError::invalid_arguments(
+                &tool.name,
+                field,
+                format!("expected {}, found {}", expected, json_type_name(value)),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Match batch responses to their requests by id, in request order
+///
+/// `ids` holds the id of each entry, or `None` for notifications. An error
+/// with a null id, and no other responses, rejects the batch as a whole.
+fn demux_batch_responses(
+    ids: &[Option<String>],
+    responses: Vec<Value>,
+) -> Vec<McpClientResult<Value>> {
+    let mut by_id = std::collections::HashMap::new();
+    let mut rejection = None;
+    for response in responses {
+        match response.get("id") {
+            Some(Value::String(id)) => {
+                by_id.insert(id.clone(), response);
+            }
+            Some(Value::Null) | None if response.get("error").is_some() => {
+                rejection = Some(response);
+            }
+            other => debug!(id = ?other, "Ignoring batch response with unknown id"),
+        }
+    }
+    let batch_rejected = by_id.is_empty() && rejection.is_some();
+
+    ids.iter()
+        .map(|id| {
+            let response = match id {
+                Some(id) => by_id
+                    .remove(id)
+                    .or_else(|| rejection.clone())
+                    .ok_or_else(|| {
+                        ProtocolError::InvalidResponse(format!(
+                            "No response for batch request {}",
+                            id
+                        ))
+                    })?,
+                None if batch_rejected => rejection.clone().unwrap_or_default(),
+                None => return Ok(Value::Null),
+            };
+            match json_rpc_error(&response) {
+                Some(error) => Err(error),
+                None => Ok(response.get("result").cloned().unwrap_or(Value::Null)),
+            }
+        })
+        .collect()
+}
+
+/// Sends `tasks/cancel` for a task when dropped while still armed.
+struct TaskCancelGuard {
+    transport: Arc<BoxedTransport>,
+    request_counter: Arc<std::sync::atomic::AtomicU64>,
+    task_id: Option<String>,
+}
+
+impl TaskCancelGuard {
+    fn new(client: &McpClient, task_id: &str) -> Self {
+        Self {
+            transport: client.transport.clone(),
+            request_counter: client.request_counter.clone(),
+            task_id: Some(task_id.to_string()),
+        }
+    }
+
+    fn disarm(&mut self) {
+        self.task_id = None;
+    }
+}
+
+impl Drop for TaskCancelGuard {
+    fn drop(&mut self) {
+        let Some(task_id) = self.task_id.take() else {
+            return;
+        };
+        // Drop may run outside a runtime (e.g. during shutdown); skip cleanup then
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let counter = self
+            .request_counter
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": "tasks/cancel",
+            "id": format!("req_{}", counter),
+            "params": {
+                "taskId": task_id
+            }
+        });
+        let transport = self.transport.clone();
+
+        runtime.spawn(async move {
+            info!(
+                task_id = task_id,
+                "Task wait abandoned - sending tasks/cancel"
+            );
+            if let Err(e) = transport.send_request(request).await {
+                warn!(task_id = task_id, error = %e, "Failed to cancel abandoned task");
+            }
+        });
+    }
+}
+
 /// Builder for creating MCP clients
 pub struct McpClientBuilder {
     transport: Option<BoxedTransport>,
     url: Option<String>,
     config: Option<ClientConfig>,
     notification_callback: Option<NotificationCallback>,
+    validate_arguments: bool,
 }
 
 impl McpClientBuilder {
@@ -1259,6 +1957,7 @@ impl McpClientBuilder {
             url: None,
             config: None,
             notification_callback: None,
+            validate_arguments: false,
         }
     }
 
@@ -1278,11 +1977,13 @@ impl McpClientBuilder {
             crate::error::TransportError::ConnectionFailed(format!("Invalid URL: {}", e))
         })?;
         if !matches!(parsed.scheme(), "http" | "https") {
-            return Err(crate::error::TransportError::ConnectionFailed(format!(
-                "Invalid scheme: {}",
-                parsed.scheme()
-            ))
-            .into());
+            // ws://, wss:// and stdio: are accepted when those transports are compiled in
+            crate::transport::detect_transport_type(url).map_err(|_| {
+                crate::error::TransportError::ConnectionFailed(format!(
+                    "Invalid scheme: {}",
+                    parsed.scheme()
+                ))
+            })?;
         }
         self.url = Some(url.to_string());
         self.transport = None; // URL overrides explicit transport
@@ -1311,9 +2012,22 @@ impl McpClientBuilder {
         self
     }
 
+    /// Validate `tools/call` arguments locally before sending them (off by default)
+    ///
+    /// Arguments are checked against the input schemas cached by
+    /// [`McpClient::list_tools`]: a missing required field or a top-level type
+    /// mismatch fails with [`McpClientError::InvalidArguments`] without a server
+    /// round-trip. Undeclared fields are passed through, and tools that have not
+    /// been listed yet are not checked.
+    pub fn validate_arguments(mut self, enabled: bool) -> Self {
+        self.validate_arguments = enabled;
+        self
+    }
+
     /// Build the client
     ///
-    /// If `with_url()` was used, the transport is constructed here with `ConnectionConfig` applied.
+    /// If `with_url()` was used, the transport is constructed here with `ConnectionConfig` and
+    /// `TimeoutConfig` applied.
     /// If `with_transport()` was used, the provided transport is used as-is.
     pub fn build(self) -> McpClient {
         let config = self.config.unwrap_or_default();
@@ -1325,25 +2039,45 @@ impl McpClientBuilder {
                 .expect("URL was validated in with_url() but detection failed");
             match transport_type {
                 crate::transport::TransportType::Http => Box::new(
-                    crate::transport::http::HttpTransport::with_config(url, &config.connection)
-                        .expect(
-                            "URL was validated in with_url() but transport construction failed",
-                        ),
+                    crate::transport::http::HttpTransport::with_client_config(url, &config).expect(
+                        "URL was validated in with_url() but transport construction failed",
+                    ),
                 )
                     as crate::transport::BoxedTransport,
                 crate::transport::TransportType::Sse => {
                     // SSE is a legacy transport — ConnectionConfig not wired (no with_config)
                     Box::new(
                         crate::transport::sse::SseTransport::new(url)
-                            .expect("URL was validated in with_url() but SSE construction failed"),
+                            .expect("URL was validated in with_url() but SSE construction failed")
+                            .with_retry_config(config.retry.clone()),
                     )
                 }
+                #[cfg(feature = "websocket")]
+                crate::transport::TransportType::WebSocket => Box::new(
+                    crate::transport::websocket::WebSocketTransport::new(url)
+                        .expect("URL was validated in with_url() but WebSocket construction failed")
+                        .with_request_timeout(config.timeouts.request),
+                ),
+                #[cfg(feature = "stdio")]
+                crate::transport::TransportType::Stdio => Box::new(
+                    crate::transport::stdio::StdioTransport::from_url(url)
+                        .expect("URL was validated in with_url() but stdio construction failed")
+                        .with_request_timeout(config.timeouts.request),
+                ),
+                // URLs never detect as in-process
+                #[cfg(feature = "in-process")]
+                crate::transport::TransportType::InProcess => {
+                    unreachable!("in-process transport has no URL form")
+                }
             }
         } else {
             panic!("Transport must be set via with_transport() or with_url() before building");
         };
 
-        McpClient::new_with_callback(transport, config, self.notification_callback)
+        let mut client =
+            McpClient::new_with_callback(transport, config, self.notification_callback);
+        client.validate_arguments = self.validate_arguments;
+        client
     }
 }
 
@@ -1357,13 +2091,13 @@ impl Default for McpClientBuilder {
 mod tests {
     use super::*;
     use crate::transport::http::HttpTransport;
-    use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
     use crate::transport::{
         ConnectionInfo, EventReceiver, ServerEvent, TransportCapabilities, TransportResponse,
         TransportStatistics, TransportType,
     };
     use async_trait::async_trait;
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
     use tokio::sync::mpsc;
 
     #[tokio::test]
@@ -1687,6 +2421,8 @@ mod tests {
         init_responses: Arc<std::sync::Mutex<VecDeque<McpClientResult<TransportResponse>>>>,
         /// Sequence of responses for send_request (normal requests)
         request_responses: Arc<std::sync::Mutex<VecDeque<McpClientResult<Value>>>>,
+        /// Records every request passed to send_request, in order
+        sent_requests: Arc<std::sync::Mutex<Vec<Value>>>,
         /// Tracks set_session_id calls in order
         set_session_ids: Arc<std::sync::Mutex<Vec<String>>>,
         /// Tracks clear_session_id call count
@@ -1707,6 +2443,7 @@ mod tests {
             Self {
                 init_responses: Arc::new(std::sync::Mutex::new(VecDeque::new())),
                 request_responses: Arc::new(std::sync::Mutex::new(VecDeque::new())),
+                sent_requests: Arc::new(std::sync::Mutex::new(Vec::new())),
                 set_session_ids: Arc::new(std::sync::Mutex::new(Vec::new())),
                 clear_count: Arc::new(AtomicU32::new(0)),
                 event_tx: Some(event_tx),
@@ -1785,7 +2522,8 @@ mod tests {
             self.connected.load(AtomicOrdering::SeqCst)
         }
 
-        async fn send_request(&self, _request: Value) -> McpClientResult<Value> {
+        async fn send_request(&self, request: Value) -> McpClientResult<Value> {
+            self.sent_requests.lock().unwrap().push(request);
             self.request_responses
                 .lock()
                 .unwrap()
@@ -2094,6 +2832,38 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_server_capabilities_stored_after_connect() {
+        let mut transport = StatefulMockTransport::new();
+        transport.push_init_response(Ok(StatefulMockTransport::make_init_response(
+            Some("session-123"),
+            "2025-11-25",
+        )));
+
+        let client = McpClient::new(Box::new(transport), ClientConfig::default());
+        assert!(client.server_capabilities().await.is_none());
+        assert!(client.server_info().await.is_none());
+        assert!(client.negotiated_version().await.is_none());
+
+        client.connect().await.unwrap();
+
+        let capabilities = client.server_capabilities().await.unwrap();
+        assert_eq!(
+            capabilities.tools.and_then(|tools| tools.list_changed),
+            Some(true)
+        );
+        assert!(capabilities.resources.is_none());
+        assert!(capabilities.tasks.is_none());
+
+        let server_info = client.server_info().await.unwrap();
+        assert_eq!(server_info.name, "stateful-mock");
+        assert_eq!(server_info.version, "1.0.0");
+        assert_eq!(
+            client.negotiated_version().await,
+            Some(McpVersion::V2025_11_25)
+        );
+    }
+
     // ── Error propagation tests ────────────────────────────────────────
 
     /// Test 4.1 — JSON-RPC error response surfaces as `ServerError` with code, message, and data.
@@ -2921,4 +3691,312 @@ mod tests {
         assert!(client.cached_resources.read().await.is_none());
         assert!(client.cached_prompts.read().await.is_none());
     }
+
+    // ── call_tool_as_task tests ─────────────────────────────────────────
+
+    fn task_json(status: &str, poll_interval: u64) -> Value {
+        json!({
+            "taskId": "task-1",
+            "status": status,
+            "createdAt": "2025-11-25T00:00:00Z",
+            "lastUpdatedAt": "2025-11-25T00:00:00Z",
+            "pollInterval": poll_interval
+        })
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_as_task_polls_until_completed() {
+        let mut transport = StatefulMockTransport::new();
+        transport.push_init_response(Ok(StatefulMockTransport::make_init_response(
+            Some("session-1"),
+            "2025-11-25",
+        )));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_1", "result": { "task": task_json("working", 1) }
+        })));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_2", "result": task_json("working", 1)
+        })));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_3", "result": task_json("completed", 1)
+        })));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_4", "result": {
+                "content": [{"type": "text", "text": "done"}],
+                "isError": false
+            }
+        })));
+        let sent = transport.sent_requests.clone();
+
+        let client = McpClient::new(Box::new(transport), ClientConfig::default());
+        client.connect().await.unwrap();
+
+        let mut statuses = Vec::new();
+        let outcome = client
+            .call_tool_as_task("slow_add", json!({"a": 1}), Duration::from_secs(5), |s| {
+                statuses.push(s)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(statuses, [TaskStatus::Working, TaskStatus::Completed]);
+        assert_eq!(outcome.completed().unwrap().content.len(), 1);
+
+        let methods: Vec<String> = sent
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| r["method"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            methods,
+            ["tools/call", "tasks/get", "tasks/get", "tasks/result"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_as_task_returns_on_input_required() {
+        let mut transport = StatefulMockTransport::new();
+        transport.push_init_response(Ok(StatefulMockTransport::make_init_response(
+            Some("session-1"),
+            "2025-11-25",
+        )));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_1", "result": { "task": task_json("working", 1) }
+        })));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_2", "result": task_json("input_required", 1)
+        })));
+        let sent = transport.sent_requests.clone();
+
+        let client = McpClient::new(Box::new(transport), ClientConfig::default());
+        client.connect().await.unwrap();
+
+        let outcome = client
+            .call_tool_as_task("ask", json!({}), Duration::from_secs(5), |_| {})
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.input_required().unwrap().task_id, "task-1");
+
+        // Returning early for input must not cancel the task
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(sent.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_call_tool_as_task_timeout_cancels_task() {
+        let mut transport = StatefulMockTransport::new();
+        transport.push_init_response(Ok(StatefulMockTransport::make_init_response(
+            Some("session-1"),
+            "2025-11-25",
+        )));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_1", "result": { "task": task_json("working", 60_000) }
+        })));
+        let sent = transport.sent_requests.clone();
+
+        let client = McpClient::new(Box::new(transport), ClientConfig::default());
+        client.connect().await.unwrap();
+
+        let result = client
+            .call_tool_as_task("slow", json!({}), Duration::from_millis(20), |_| {})
+            .await;
+        assert!(matches!(result, Err(McpClientError::Timeout)));
+
+        let cancel = tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                if let Some(request) = sent
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .find(|r| r["method"] == "tasks/cancel")
+                {
+                    return request.clone();
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("tasks/cancel should be sent after timeout");
+        assert_eq!(cancel["params"]["taskId"], "task-1");
+    }
+
+    #[test]
+    fn test_demux_batch_responses_by_id() {
+        let ids = vec![
+            Some("req_1".to_string()),
+            None,
+            Some("req_2".to_string()),
+            Some("req_3".to_string()),
+        ];
+        // Out of order, with one request left unanswered
+        let responses = vec![
+            json!({"jsonrpc": "2.0", "id": "req_2", "error": {"code": -32602, "message": "bad"}}),
+            json!({"jsonrpc": "2.0", "id": "req_1", "result": {"n": 1}}),
+        ];
+
+        let results = demux_batch_responses(&ids, responses);
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap()["n"], 1);
+        assert_eq!(results[1].as_ref().unwrap(), &Value::Null);
+        assert!(matches!(
+            results[2],
+            Err(McpClientError::ServerError { code: -32602, .. })
+        ));
+        assert!(matches!(
+            results[3],
+            Err(McpClientError::Protocol(ProtocolError::InvalidResponse(_)))
+        ));
+    }
+
+    #[test]
+    fn test_demux_batch_rejected_as_a_whole() {
+        let ids = vec![Some("req_1".to_string()), None];
+        let responses = vec![json!({
+            "jsonrpc": "2.0", "id": null,
+            "error": {"code": -32600, "message": "Batching not supported"}
+        })];
+
+        let results = demux_batch_responses(&ids, responses);
+        for result in results {
+            let Err(McpClientError::ServerError { code, message, .. }) = result else {
+                panic!("Expected the batch rejection, got {:?}", result);
+            };
+            assert_eq!(code, -32600);
+            assert_eq!(message, "Batching not supported");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_falls_back_to_individual_requests() {
+        let mut transport = StatefulMockTransport::new();
+        transport.push_init_response(Ok(StatefulMockTransport::make_init_response(
+            Some("session-1"),
+            "2025-11-25",
+        )));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_1", "result": {"n": 1}
+        })));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0", "id": "req_2", "error": {"code": -32601, "message": "nope"}
+        })));
+        let sent = transport.sent_requests.clone();
+
+        let client = McpClient::new(Box::new(transport), ClientConfig::default());
+        client.connect().await.unwrap();
+
+        let results = client
+            .batch(vec![
+                BatchItem::call_tool("first", json!({})),
+                BatchItem::notification("notifications/roots/list_changed", json!({})),
+                BatchItem::request("missing/method", json!({})),
+            ])
+            .await;
+
+        assert_eq!(results[0].as_ref().unwrap()["n"], 1);
+        assert_eq!(results[1].as_ref().unwrap(), &Value::Null);
+        assert!(matches!(
+            results[2],
+            Err(McpClientError::ServerError { code: -32601, .. })
+        ));
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0]["params"]["name"], "first");
+    }
+
+    /// Connects a client with one cached `add(a: integer, b: integer)` tool, and
+    /// returns it with the log of requests reaching the transport
+    async fn client_with_add_tool(
+        validate_arguments: bool,
+    ) -> (McpClient, Arc<std::sync::Mutex<Vec<Value>>>) {
+        let mut transport = StatefulMockTransport::new();
+        transport.push_init_response(Ok(StatefulMockTransport::make_init_response(
+            Some("session-1"),
+            "2025-11-25",
+        )));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0",
+            "id": "req_1",
+            "result": {
+                "tools": [{
+                    "name": "add",
+                    "inputSchema": {
+                        "type": "object",
+                        "properties": {
+                            "a": { "type": "integer" },
+                            "b": { "type": "integer" },
+                            "label": { "type": "string" }
+                        },
+                        "required": ["a", "b"]
+                    }
+                }]
+            }
+        })));
+        transport.push_request_response(Ok(json!({
+            "jsonrpc": "2.0",
+            "id": "req_2",
+            "result": { "content": [{ "type": "text", "text": "server saw it" }] }
+        })));
+        let sent = transport.sent_requests.clone();
+
+        let client = McpClientBuilder::new()
+            .with_transport(Box::new(transport))
+            .validate_arguments(validate_arguments)
+            .build();
+        client.connect().await.unwrap();
+        client.list_tools().await.unwrap();
+        (client, sent)
+    }
+
+    #[tokio::test]
+    async fn test_validate_arguments_missing_required_field() {
+        let (client, sent) = client_with_add_tool(true).await;
+
+        let err = client.call_tool("add", json!({"a": 1})).await.unwrap_err();
+        let McpClientError::InvalidArguments { tool, field, .. } = &err else {
+            panic!("Expected InvalidArguments, got {:?}", err);
+        };
+        assert_eq!(tool, "add");
+        assert_eq!(field, "b");
+        assert_eq!(
+            err.to_string(),
+            "Invalid arguments for tool 'add': field 'b' is required"
+        );
+        assert_eq!(sent.lock().unwrap().len(), 1, "only tools/list was sent");
+    }
+
+    #[tokio::test]
+    async fn test_validate_arguments_type_mismatch() {
+        let (client, sent) = client_with_add_tool(true).await;
+
+        let err = client
+            .call_tool("add", json!({"a": 1, "b": "two"}))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            &err,
+            McpClientError::InvalidArguments { field, message, .. }
+                if field == "b" && message == "expected integer, found string"
+        ));
+        assert_eq!(sent.lock().unwrap().len(), 1, "only tools/list was sent");
+
+        // Undeclared fields and valid arguments pass
+        let result = client
+            .call_tool("add", json!({"a": 1, "b": 2, "extra": true}))
+            .await
+            .unwrap();
+        assert!(!result.content.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validate_arguments_disabled_passes_through() {
+        let (client, sent) = client_with_add_tool(false).await;
+
+        let result = client.call_tool("add", json!({"b": "two"})).await.unwrap();
+        assert!(!result.content.is_empty());
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[1]["params"]["arguments"], json!({"b": "two"}));
+    }
 }
@@ -42,6 +42,9 @@ pub struct ClientInfo {
 }
 
 /// Timeout configuration
+///
+/// `connect` and `request` are applied to the HTTP transport's `reqwest::Client`. The
+/// idle timeout for pooled connections lives in [`PoolConfig::idle_timeout`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeoutConfig {
     /// Connection timeout
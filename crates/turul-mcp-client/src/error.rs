@@ -49,6 +49,14 @@ pub enum McpClientError {
         data: Option<Value>,
     },
 
+    /// Tool arguments rejected by client-side schema validation
+    #[error("Invalid arguments for tool '{tool}': field '{field}' {message}")]
+    InvalidArguments {
+        tool: String,
+        field: String,
+        message: String,
+    },
+
     /// Generic error with context
     #[error("Error: {message}")]
     Generic { message: String },
@@ -153,6 +161,19 @@ impl McpClientError {
         }
     }
 
+    /// Create an invalid-arguments error for a tool call
+    pub fn invalid_arguments(
+        tool: impl Into<String>,
+        field: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::InvalidArguments {
+            tool: tool.into(),
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
     /// Check if the error is retryable
     pub fn is_retryable(&self) -> bool {
         match self {
@@ -255,11 +276,7 @@ mod tests {
 
     #[test]
     fn test_session_not_initialized_by_code() {
-        let err = McpClientError::server_error(
-            -32031,
-            "Session error: something",
-            None,
-        );
+        let err = McpClientError::server_error(-32031, "Session error: something", None);
         assert!(err.is_session_not_initialized());
     }
 
@@ -275,11 +292,7 @@ mod tests {
 
     #[test]
     fn test_unrelated_error_is_not_session_not_initialized() {
-        let err = McpClientError::server_error(
-            -32602,
-            "Invalid params",
-            None,
-        );
+        let err = McpClientError::server_error(-32602, "Invalid params", None);
         assert!(!err.is_session_not_initialized());
     }
 
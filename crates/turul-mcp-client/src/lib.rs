@@ -12,7 +12,8 @@
 //!
 //! ## Features
 //!
-//! - **Multi-transport**: HTTP and Server-Sent Events (SSE), with stdio planned
+//! - **Multi-transport**: HTTP and Server-Sent Events (SSE), plus WebSocket, stdio and
+//!   in-process behind the `websocket`, `stdio` and `in-process` features
 //! - **Full Protocol**: Complete MCP 2025-11-25 specification support
 //! - **High Performance**: Built on Tokio with async/await throughout
 //! - **Session Management**: Automatic connection handling and recovery
@@ -87,15 +88,30 @@
 //! # }
 //! ```
 //!
-//! ### Future Transports
+//! ### Stdio Transport
 //!
-//! Stdio transports are planned for future releases:
+//! With the `stdio` feature, a local server executable is spawned as a child
+//! process and spoken to over stdin/stdout:
 //!
-//! ```text
-//! // Coming soon:
-//! // StdioTransport::new("./mcp-server-executable")
+//! ```rust,no_run
+//! # #[cfg(feature = "stdio")]
+//! # fn main() {
+//! use turul_mcp_client::transport::StdioTransport;
+//!
+//! let transport = StdioTransport::new("./mcp-server-executable").with_args(["--verbose"]);
+//! # }
+//! # #[cfg(not(feature = "stdio"))]
+//! # fn main() {}
 //! ```
 //!
+//! ### In-Process Transport
+//!
+//! With the `in-process` feature, `InProcessTransport` talks to a server in the
+//! same process through the `McpService` returned by
+//! `McpServer::in_process_service()`. Messages take the server's normal
+//! Streamable HTTP path, including progress and GET notifications, without
+//! binding a socket, which makes it a good fit for tests and embedding.
+//!
 //! ## Common Operations
 //!
 //! ### Tool Execution
@@ -130,8 +146,8 @@
 //! let templates = client.list_resource_templates().await?;
 //!
 //! // Read a specific resource
-//! let content = client.read_resource("file://config.json").await?;
-//! println!("Resource content: {:?}", content);
+//! let result = client.read_resource("file://config.json").await?;
+//! println!("Resource content: {:?}", result.contents);
 //! # Ok(())
 //! # }
 //! ```
@@ -206,7 +222,10 @@ pub mod transport;
 
 // Re-export main types
 /// High-level MCP client with session management and automatic reconnection
-pub use client::{McpClient, McpClientBuilder, NotificationCallback, ToolCallResponse};
+pub use client::{
+    BatchItem, DEFAULT_TASK_POLL_INTERVAL, McpClient, McpClientBuilder, NotificationCallback,
+    TaskCallOutcome, TaskPollOptions, ToolCallResponse,
+};
 /// Client configuration types for timeouts, retries, and connection parameters
 pub use config::{ClientConfig, RetryConfig, TimeoutConfig};
 /// Client-specific error types and result aliases for error handling
@@ -214,6 +233,9 @@ pub use error::{McpClientError, McpClientResult};
 /// Session management types for tracking connection state and statistics
 pub use session::{SessionInfo, SessionManager, SessionState};
 
+/// Correlation between a request id and its `progressToken`
+pub use turul_mcp_json_rpc_server::{Correlation, CorrelationError};
+
 // Re-export transport types
 /// Transport layer abstractions for different MCP connection types
 pub use transport::{Transport, TransportType};
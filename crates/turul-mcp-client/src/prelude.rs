@@ -8,10 +8,13 @@
 //! ```
 
 // Core client types
-pub use crate::client::{McpClient, McpClientBuilder, ToolCallResponse};
+pub use crate::client::{
+    BatchItem, McpClient, McpClientBuilder, TaskCallOutcome, TaskPollOptions, ToolCallResponse,
+};
 pub use crate::config::{ClientConfig, RetryConfig, TimeoutConfig};
 pub use crate::error::{McpClientError, McpClientResult};
 pub use crate::session::{SessionInfo, SessionManager, SessionState};
+pub use turul_mcp_json_rpc_server::Correlation;
 
 // Transport types
 pub use crate::transport::{Transport, TransportType};
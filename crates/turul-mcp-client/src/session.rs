@@ -60,6 +60,9 @@ pub struct SessionInfo {
     /// Server capabilities received during initialization
     pub server_capabilities: Option<ServerCapabilities>,
 
+    /// Server implementation info received during initialization
+    pub server_info: Option<Implementation>,
+
     /// Protocol version negotiated
     pub protocol_version: Option<String>,
 
@@ -85,6 +88,7 @@ impl SessionInfo {
             state: SessionState::Uninitialized,
             client_capabilities: None,
             server_capabilities: None,
+            server_info: None,
             protocol_version: None,
             created_at: now,
             last_activity: now,
@@ -198,6 +202,7 @@ impl SessionManager {
         &self,
         client_capabilities: ClientCapabilities,
         server_capabilities: ServerCapabilities,
+        server_info: Implementation,
         protocol_version: String,
     ) -> McpClientResult<()> {
         let mut session = self.session.write().await;
@@ -211,6 +216,7 @@ impl SessionManager {
 
         session.client_capabilities = Some(client_capabilities);
         session.server_capabilities = Some(server_capabilities);
+        session.server_info = Some(server_info);
         session.protocol_version = Some(protocol_version.clone());
         session.state = SessionState::Active;
         session.update_activity();
@@ -467,7 +473,12 @@ mod tests {
         };
 
         manager
-            .initialize(client_caps, server_caps, "2025-11-25".to_string())
+            .initialize(
+                client_caps,
+                server_caps,
+                Implementation::new("test-server", "1.0.0"),
+                "2025-11-25".to_string(),
+            )
             .await
             .unwrap();
         assert_eq!(manager.state().await, SessionState::Active);
@@ -8,20 +8,23 @@ use url::Url;
 use crate::error::{McpClientResult, TransportError};
 
 pub mod http;
+#[cfg(feature = "in-process")]
+pub mod in_process;
 pub mod sse;
-
-// Stdio transport is planned for future implementation
-
-// #[cfg(feature = "stdio")]
-// pub mod stdio;
+#[cfg(feature = "stdio")]
+pub mod stdio;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 // Re-export transport implementations
 pub use http::HttpTransport;
+#[cfg(feature = "in-process")]
+pub use in_process::InProcessTransport;
 pub use sse::SseTransport;
-
-// Re-exports for future transport implementations
-// #[cfg(feature = "stdio")]
-// pub use stdio::StdioTransport;
+#[cfg(feature = "stdio")]
+pub use stdio::StdioTransport;
+#[cfg(feature = "websocket")]
+pub use websocket::WebSocketTransport;
 
 /// Transport type enumeration
 #[derive(Debug, Clone, PartialEq)]
@@ -30,8 +33,15 @@ pub enum TransportType {
     Http,
     /// Server-Sent Events transport (HTTP+SSE)
     Sse,
-    // Future transport types:
-    // Stdio,
+    /// WebSocket transport (`ws://` / `wss://`)
+    #[cfg(feature = "websocket")]
+    WebSocket,
+    /// Stdio transport (child process stdin/stdout)
+    #[cfg(feature = "stdio")]
+    Stdio,
+    /// In-process transport (server in the same process, no sockets)
+    #[cfg(feature = "in-process")]
+    InProcess,
 }
 
 impl std::fmt::Display for TransportType {
@@ -39,6 +49,12 @@ impl std::fmt::Display for TransportType {
         match self {
             TransportType::Http => write!(f, "HTTP"),
             TransportType::Sse => write!(f, "SSE"),
+            #[cfg(feature = "websocket")]
+            TransportType::WebSocket => write!(f, "WebSocket"),
+            #[cfg(feature = "stdio")]
+            TransportType::Stdio => write!(f, "Stdio"),
+            #[cfg(feature = "in-process")]
+            TransportType::InProcess => write!(f, "InProcess"),
         }
     }
 }
@@ -119,14 +135,26 @@ pub trait Transport: Send + Sync {
     async fn send_request(&self, request: Value) -> McpClientResult<Value>;
 
     /// Send a request and return response with headers (for initialization)
-    async fn send_request_with_headers(
-        &self,
-        request: Value,
-    ) -> McpClientResult<TransportResponse>;
+    async fn send_request_with_headers(&self, request: Value)
+    -> McpClientResult<TransportResponse>;
 
     /// Send a notification (no response expected)
     async fn send_notification(&self, notification: Value) -> McpClientResult<()>;
 
+    /// Send a JSON-RPC batch in one message and return the response objects
+    ///
+    /// Responses may come back in any order and a batch of only notifications
+    /// yields none. The default returns [`TransportError::Unsupported`], which
+    /// tells callers to send the items individually instead.
+    async fn send_batch(&self, batch: Vec<Value>) -> McpClientResult<Vec<Value>> {
+        let _ = batch;
+        Err(TransportError::Unsupported(format!(
+            "{} transport does not support JSON-RPC batches",
+            self.transport_type()
+        ))
+        .into())
+    }
+
     /// Send a DELETE request for session termination (MCP session management)
     async fn send_delete(&self, session_id: &str) -> McpClientResult<()>;
 
@@ -221,8 +249,18 @@ pub fn detect_transport_type(url_str: &str) -> McpClientResult<TransportType> {
                 Ok(TransportType::Http)
             }
         }
-        "stdio" | "file" => Err(TransportError::Unsupported(
-            "Stdio transport not yet implemented".to_string(),
+        #[cfg(feature = "websocket")]
+        "ws" | "wss" => Ok(TransportType::WebSocket),
+        #[cfg(not(feature = "websocket"))]
+        "ws" | "wss" => Err(TransportError::Unsupported(
+            "WebSocket transport requires the `websocket` feature".to_string(),
+        )
+        .into()),
+        #[cfg(feature = "stdio")]
+        "stdio" => Ok(TransportType::Stdio),
+        #[cfg(not(feature = "stdio"))]
+        "stdio" => Err(TransportError::Unsupported(
+            "Stdio transport requires the `stdio` feature".to_string(),
         )
         .into()),
         scheme => Err(TransportError::Unsupported(format!("Unknown scheme: {}", scheme)).into()),
@@ -240,6 +278,12 @@ impl TransportFactory {
         match transport_type {
             TransportType::Http => Ok(Box::new(HttpTransport::new(url)?)),
             TransportType::Sse => Ok(Box::new(SseTransport::new(url)?)),
+            #[cfg(feature = "websocket")]
+            TransportType::WebSocket => Ok(Box::new(WebSocketTransport::new(url)?)),
+            #[cfg(feature = "stdio")]
+            TransportType::Stdio => Ok(Box::new(StdioTransport::from_url(url)?)),
+            #[cfg(feature = "in-process")]
+            TransportType::InProcess => Err(in_process_needs_service()),
         }
     }
 
@@ -251,19 +295,95 @@ impl TransportFactory {
         match transport_type {
             TransportType::Http => Ok(Box::new(HttpTransport::new(endpoint)?)),
             TransportType::Sse => Ok(Box::new(SseTransport::new(endpoint)?)),
+            #[cfg(feature = "websocket")]
+            TransportType::WebSocket => Ok(Box::new(WebSocketTransport::new(endpoint)?)),
+            // The endpoint is the program to spawn
+            #[cfg(feature = "stdio")]
+            TransportType::Stdio => Ok(Box::new(StdioTransport::new(endpoint))),
+            #[cfg(feature = "in-process")]
+            TransportType::InProcess => Err(in_process_needs_service()),
         }
     }
 
     /// List available transport types
     pub fn available_transports() -> Vec<TransportType> {
-        vec![TransportType::Http, TransportType::Sse]
+        vec![
+            TransportType::Http,
+            TransportType::Sse,
+            #[cfg(feature = "websocket")]
+            TransportType::WebSocket,
+            #[cfg(feature = "stdio")]
+            TransportType::Stdio,
+        ]
     }
 }
 
+/// The in-process transport wraps a server handle, so it has no endpoint string
+#[cfg(feature = "in-process")]
+fn in_process_needs_service() -> crate::error::McpClientError {
+    TransportError::Unsupported(
+        "in-process transport is created from an McpService with InProcessTransport::new"
+            .to_string(),
+    )
+    .into()
+}
+
+/// Accept header for batch POSTs
+///
+/// The streamable HTTP server only takes batches from clients that accept
+/// NDJSON (one response per line); plain JSON covers servers that answer with
+/// a single array.
+pub(crate) const BATCH_POST_ACCEPT: &str = "application/x-ndjson, application/json";
+
+/// Split a batch response body into response objects
+///
+/// Takes a JSON array, an NDJSON body (one response per line) or a single
+/// object rejecting the batch as a whole. An empty body (a batch of only
+/// notifications) yields no responses.
+pub(crate) fn parse_batch_responses(body: &str) -> McpClientResult<Vec<Value>> {
+    let invalid =
+        |e: serde_json::Error| TransportError::Http(format!("Invalid batch response: {}", e));
+
+    if body.trim_start().starts_with('[') {
+        return serde_json::from_str::<Vec<Value>>(body).map_err(|e| invalid(e).into());
+    }
+
+    let mut responses = Vec::new();
+    for line in body.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str::<Value>(line).map_err(invalid)? {
+            response @ Value::Object(_) => responses.push(response),
+            other => {
+                return Err(
+                    TransportError::Http(format!("Unexpected batch response: {}", other)).into(),
+                );
+            }
+        }
+    }
+    Ok(responses)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_batch_responses() {
+        let ndjson = "{\"jsonrpc\":\"2.0\",\"id\":\"b\",\"result\":{}}\n\
+                      {\"jsonrpc\":\"2.0\",\"id\":\"a\",\"result\":{}}\n";
+        let responses = parse_batch_responses(ndjson).unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], "b");
+
+        let array = r#"[{"jsonrpc":"2.0","id":"a","result":{}}]"#;
+        assert_eq!(parse_batch_responses(array).unwrap().len(), 1);
+
+        let rejection = r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32600,"message":"no"}}"#;
+        assert_eq!(parse_batch_responses(rejection).unwrap().len(), 1);
+
+        assert!(parse_batch_responses("").unwrap().is_empty());
+        assert!(parse_batch_responses("\"text\"").is_err());
+    }
+
     #[test]
     fn test_transport_type_detection() {
         assert_eq!(
@@ -280,6 +400,28 @@ mod tests {
         assert!(detect_transport_type("ftp://localhost:8080/mcp").is_err());
 
         assert!(detect_transport_type("invalid://localhost").is_err());
+
+        #[cfg(feature = "websocket")]
+        {
+            assert_eq!(
+                detect_transport_type("ws://localhost:8080/mcp").unwrap(),
+                TransportType::WebSocket
+            );
+            assert_eq!(
+                detect_transport_type("wss://example.com/mcp").unwrap(),
+                TransportType::WebSocket
+            );
+        }
+        #[cfg(not(feature = "websocket"))]
+        assert!(detect_transport_type("ws://localhost:8080/mcp").is_err());
+
+        #[cfg(feature = "stdio")]
+        assert_eq!(
+            detect_transport_type("stdio:///usr/local/bin/server").unwrap(),
+            TransportType::Stdio
+        );
+        #[cfg(not(feature = "stdio"))]
+        assert!(detect_transport_type("stdio:///usr/local/bin/server").is_err());
     }
 
     #[test]
@@ -23,7 +23,8 @@ const MCP_POST_ACCEPT: &str = "application/json, text/event-stream";
 /// HTTP transport for MCP client (Streamable HTTP)
 #[derive(Debug)]
 pub struct HttpTransport {
-    /// HTTP client
+    /// HTTP client, built once per transport. `reqwest::Client` is reference-counted,
+    /// so every request and the SSE listener share its connection pool.
     client: Client,
     /// Server endpoint URL
     endpoint: Url,
@@ -80,9 +81,31 @@ impl HttpTransport {
     }
 
     /// Create HTTP transport with connection configuration applied
+    ///
+    /// Uses the default [`TimeoutConfig`](crate::config::TimeoutConfig); see
+    /// [`with_client_config`](Self::with_client_config) to set timeouts too.
     pub fn with_config(
         endpoint: &str,
         config: &crate::config::ConnectionConfig,
+    ) -> McpClientResult<Self> {
+        Self::with_timeouts(endpoint, config, &crate::config::TimeoutConfig::default())
+    }
+
+    /// Create HTTP transport with the connection and timeout settings of a client config
+    ///
+    /// `timeouts.connect` bounds TCP/TLS connection setup and `timeouts.request` bounds
+    /// each request. Idle pooled connections are governed by `connection.pool_settings`.
+    pub fn with_client_config(
+        endpoint: &str,
+        config: &crate::config::ClientConfig,
+    ) -> McpClientResult<Self> {
+        Self::with_timeouts(endpoint, &config.connection, &config.timeouts)
+    }
+
+    fn with_timeouts(
+        endpoint: &str,
+        config: &crate::config::ConnectionConfig,
+        timeouts: &crate::config::TimeoutConfig,
     ) -> McpClientResult<Self> {
         let url = Url::parse(endpoint)
             .map_err(|e| TransportError::ConnectionFailed(format!("Invalid URL: {}", e)))?;
@@ -98,7 +121,8 @@ impl HttpTransport {
         let user_agent = config.user_agent.as_deref().unwrap_or("mcp-client/0.1.0");
 
         let mut builder = Client::builder()
-            .timeout(Duration::from_secs(30))
+            .connect_timeout(timeouts.connect)
+            .timeout(timeouts.request)
             .user_agent(user_agent)
             .pool_max_idle_per_host(config.pool_settings.max_idle_per_host as usize)
             .pool_idle_timeout(config.pool_settings.idle_timeout)
@@ -729,6 +753,65 @@ impl Transport for HttpTransport {
         }
     }
 
+    async fn send_batch(&self, batch: Vec<Value>) -> McpClientResult<Vec<Value>> {
+        if !self.is_connected() {
+            return Err(TransportError::ConnectionFailed("Not connected".to_string()).into());
+        }
+
+        let request_count = batch.iter().filter(|item| item.get("id").is_some()).count() as u64;
+        let notification_count = batch.len() as u64 - request_count;
+        debug!(
+            requests = request_count,
+            notifications = notification_count,
+            "Sending HTTP batch"
+        );
+
+        self.update_stats(|stats| {
+            stats.requests_sent += request_count;
+            stats.notifications_sent += notification_count;
+        });
+
+        // Batch responses come back as NDJSON lines or one JSON array, so SSE is not offered
+        let mut req_builder = self
+            .client
+            .post(self.endpoint.clone())
+            .header("Content-Type", "application/json")
+            .header("Accept", super::BATCH_POST_ACCEPT)
+            .header("MCP-Protocol-Version", "2025-11-25");
+
+        if let Some(ref session_id) = *self.session_id.lock() {
+            req_builder = req_builder.header("Mcp-Session-Id", session_id);
+        }
+
+        let response = req_builder
+            .json(&batch)
+            .send()
+            .await
+            .map_err(|e| TransportError::Http(format!("Failed to send batch: {}", e)))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| TransportError::Http(format!("Failed to read batch response: {}", e)))?;
+
+        if !status.is_success() {
+            self.update_stats(|stats| {
+                stats.errors += 1;
+                stats.last_error = Some(format!("HTTP {}: {}", status, body));
+            });
+            return Err(TransportError::HttpStatus {
+                status: status.as_u16(),
+                message: body,
+            }
+            .into());
+        }
+
+        let responses = super::parse_batch_responses(&body)?;
+        self.update_stats(|stats| stats.responses_received += responses.len() as u64);
+        Ok(responses)
+    }
+
     async fn send_delete(&self, session_id: &str) -> McpClientResult<()> {
         if !self.is_connected() {
             return Err(TransportError::ConnectionFailed("Not connected".to_string()).into());
@@ -1054,7 +1137,7 @@ impl Transport for HttpTransport {
 
 /// Parse SSE lines, route server requests/notifications, return final response frame.
 /// Extracted from handle_sse_stream for testability.
-async fn parse_sse_lines<R: tokio::io::AsyncBufRead + Unpin>(
+pub(super) async fn parse_sse_lines<R: tokio::io::AsyncBufRead + Unpin>(
     lines: &mut tokio::io::Lines<R>,
     event_sender: Option<mpsc::UnboundedSender<ServerEvent>>,
     queued_events: &Arc<parking_lot::Mutex<Vec<ServerEvent>>>,
@@ -1428,6 +1511,21 @@ mod tests {
         assert!(transport.is_ok());
     }
 
+    #[test]
+    fn test_with_client_config_applies_timeouts() {
+        let config = crate::config::ClientConfig {
+            timeouts: crate::config::TimeoutConfig {
+                connect: Duration::from_secs(2),
+                request: Duration::from_secs(5),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let transport = HttpTransport::with_client_config("http://localhost:9999/mcp", &config);
+        assert!(transport.is_ok());
+    }
+
     #[test]
     fn test_with_config_default() {
         let config = crate::config::ConnectionConfig::default();
@@ -0,0 +1,583 @@
+//! In-process transport implementation for MCP client
+//!
+//! Drives a server living in the same process through its
+//! [`McpService`], without binding a socket. Every message is handed to the
+//! server's Streamable HTTP handlers as an in-memory request, so sessions,
+//! progress frames on POST SSE responses and the GET notification stream behave
+//! exactly as they do over the network. Only the transport-level layers of the
+//! HTTP listener (per-IP limits, CORS, compression, wire logging) are skipped.
+//!
+//! Get the service from `McpServer::in_process_service()` or
+//! `HttpMcpServer::service()`:
+//!
+//! ```rust,no_run
+//! # use turul_mcp_client::McpClientBuilder;
+//! # use turul_mcp_client::transport::InProcessTransport;
+//! # async fn example(service: turul_http_mcp_server::McpService) {
+//! let client = McpClientBuilder::new()
+//!     .with_transport(Box::new(InProcessTransport::new(service)))
+//!     .build();
+//! client.connect().await.unwrap();
+//! # }
+//! ```
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::TryStreamExt;
+use http_body_util::BodyExt;
+use http_body_util::combinators::UnsyncBoxBody;
+use hyper::{Method, Request, Response};
+use serde_json::{Deserializer, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Instant;
+use tokio::io::AsyncBufReadExt;
+use tokio::sync::{mpsc, watch};
+use tracing::{debug, info, warn};
+use turul_http_mcp_server::McpService;
+
+use crate::error::{McpClientResult, TransportError};
+use crate::transport::{
+    ConnectionInfo, EventReceiver, ServerEvent, Transport, TransportCapabilities,
+    TransportResponse, TransportStatistics, TransportType,
+};
+
+/// Accept header for MCP POST requests per spec (MUST include both media types)
+const MCP_POST_ACCEPT: &str = "application/json, text/event-stream";
+
+type ServiceResponse = Response<UnsyncBoxBody<Bytes, hyper::Error>>;
+
+/// In-process transport for MCP client
+pub struct InProcessTransport {
+    /// Server endpoint handle
+    service: McpService,
+    /// Connection state
+    connected: AtomicBool,
+    /// Request counter
+    request_counter: AtomicU64,
+    /// Statistics
+    stats: Arc<parking_lot::Mutex<TransportStatistics>>,
+    /// Event sender for server events
+    event_sender: parking_lot::Mutex<Option<mpsc::UnboundedSender<ServerEvent>>>,
+    /// Queue for notifications that arrive before a listener exists
+    queued_events: Arc<parking_lot::Mutex<Vec<ServerEvent>>>,
+    /// Session ID from server; the notification stream waits for it to be set
+    session_id: watch::Sender<Option<String>>,
+    /// GET notification stream task handle
+    listener_handle: parking_lot::Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl std::fmt::Debug for InProcessTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InProcessTransport")
+            .field("mcp_path", &self.service.mcp_path())
+            .field("connected", &self.is_connected())
+            .field("session_id", &*self.session_id.borrow())
+            .finish()
+    }
+}
+
+impl InProcessTransport {
+    /// Create a transport that sends every message to `service`
+    pub fn new(service: McpService) -> Self {
+        Self {
+            service,
+            connected: AtomicBool::new(false),
+            request_counter: AtomicU64::new(0),
+            stats: Arc::new(parking_lot::Mutex::new(TransportStatistics::default())),
+            event_sender: parking_lot::Mutex::new(None),
+            queued_events: Arc::new(parking_lot::Mutex::new(Vec::new())),
+            session_id: watch::Sender::new(None),
+            listener_handle: parking_lot::Mutex::new(None),
+        }
+    }
+
+    /// Generate unique request ID
+    fn next_request_id(&self) -> String {
+        let counter = self.request_counter.fetch_add(1, Ordering::SeqCst);
+        format!("req_{}", counter)
+    }
+
+    /// Update statistics
+    fn update_stats<F>(&self, update_fn: F)
+    where
+        F: FnOnce(&mut TransportStatistics),
+    {
+        let mut stats = self.stats.lock();
+        update_fn(&mut stats);
+    }
+
+    fn ensure_connected(&self) -> McpClientResult<()> {
+        if self.is_connected() {
+            Ok(())
+        } else {
+            Err(TransportError::ConnectionFailed("Not connected".to_string()).into())
+        }
+    }
+
+    /// Build a request for the MCP endpoint carrying the current session ID
+    fn request(&self, method: Method, accept: &str, body: Bytes) -> Request<Bytes> {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(self.service.mcp_path())
+            .header("Content-Type", "application/json")
+            .header("Accept", accept)
+            .header("MCP-Protocol-Version", "2025-11-25");
+        if let Some(ref session_id) = *self.session_id.borrow() {
+            builder = builder.header("Mcp-Session-Id", session_id);
+        }
+        builder
+            .body(body)
+            .expect("MCP request parts are always valid")
+    }
+
+    /// POST a JSON-RPC message and return the successful response
+    async fn post(&self, message: &Value, accept: &str) -> McpClientResult<ServiceResponse> {
+        let body = serde_json::to_vec(message)
+            .map_err(|e| TransportError::Http(format!("Failed to encode message: {}", e)))?;
+        let response = self
+            .service
+            .call(self.request(Method::POST, accept, Bytes::from(body)))
+            .await
+            .map_err(|e| TransportError::Http(format!("Failed to send request: {}", e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = body_text(response).await;
+            self.update_stats(|stats| {
+                stats.errors += 1;
+                stats.last_error = Some(format!("HTTP {}: {}", status, error_text));
+            });
+            return Err(TransportError::HttpStatus {
+                status: status.as_u16(),
+                message: error_text,
+            }
+            .into());
+        }
+
+        // Capture session ID from response headers if present
+        if let Some(session_header) = response.headers().get("mcp-session-id")
+            && let Ok(session_str) = session_header.to_str()
+        {
+            debug!("Captured session ID from response: {}", session_str);
+            self.session_id.send_replace(Some(session_str.to_owned()));
+        }
+
+        Ok(response)
+    }
+
+    /// Read the final JSON-RPC frame, routing notifications seen on the way
+    async fn read_response(&self, response: ServiceResponse) -> McpClientResult<Value> {
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("")
+            .to_owned();
+
+        if content_type.contains("text/event-stream") {
+            let reader = tokio_util::io::StreamReader::new(
+                response
+                    .into_body()
+                    .into_data_stream()
+                    .map_err(std::io::Error::other),
+            );
+            let mut lines = tokio::io::BufReader::new(reader).lines();
+            let sender_snapshot = self.event_sender.lock().clone();
+            return super::http::parse_sse_lines(
+                &mut lines,
+                sender_snapshot,
+                &self.queued_events,
+                &self.stats,
+            )
+            .await;
+        }
+
+        if !content_type.contains("application/json") {
+            return Err(TransportError::Http(format!(
+                "Unsupported content type: {}",
+                content_type
+            ))
+            .into());
+        }
+
+        let body = response
+            .into_body()
+            .collect()
+            .await
+            .map_err(|e| TransportError::Http(format!("Failed to read response: {}", e)))?
+            .to_bytes();
+        for frame in Deserializer::from_slice(&body).into_iter::<Value>() {
+            let json =
+                frame.map_err(|e| TransportError::Http(format!("Invalid JSON response: {}", e)))?;
+            if json.get("id").is_some()
+                && (json.get("result").is_some() || json.get("error").is_some())
+            {
+                self.update_stats(|stats| stats.responses_received += 1);
+                return Ok(json);
+            }
+            if json.get("method").is_some() {
+                self.route_event(classify_event(json));
+            }
+        }
+
+        Err(TransportError::Http("Response ended without final result".to_string()).into())
+    }
+
+    /// Deliver an event to the listener, or queue it until one exists
+    fn route_event(&self, event: ServerEvent) {
+        let sender_snapshot = self.event_sender.lock().clone();
+        match sender_snapshot {
+            Some(sender) => {
+                if let Err(mpsc::error::SendError(event)) = sender.send(event) {
+                    self.queued_events.lock().push(event);
+                }
+            }
+            None => self.queued_events.lock().push(event),
+        }
+    }
+
+    /// Send a request, filling in an ID if missing, and time it
+    async fn round_trip(
+        &self,
+        request: Value,
+    ) -> McpClientResult<(Value, HashMap<String, String>)> {
+        self.ensure_connected()?;
+
+        let mut request = request;
+        if request.get("id").is_none() {
+            request["id"] = Value::String(self.next_request_id());
+        }
+
+        debug!(
+            method = request.get("method").and_then(|v| v.as_str()),
+            id = request.get("id").and_then(|v| v.as_str()),
+            "Sending in-process request"
+        );
+
+        self.update_stats(|stats| stats.requests_sent += 1);
+        let start_time = Instant::now();
+
+        let response = self.post(&request, MCP_POST_ACCEPT).await?;
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect();
+        let body = self.read_response(response).await?;
+
+        let elapsed = start_time.elapsed();
+        self.update_stats(|stats| {
+            let new_avg = if stats.responses_received > 0 {
+                (stats.avg_response_time_ms * (stats.responses_received - 1) as f64
+                    + elapsed.as_millis() as f64)
+                    / stats.responses_received as f64
+            } else {
+                elapsed.as_millis() as f64
+            };
+            stats.avg_response_time_ms = new_avg;
+        });
+
+        Ok((body, headers))
+    }
+}
+
+impl Drop for InProcessTransport {
+    fn drop(&mut self) {
+        if let Some(handle) = self.listener_handle.lock().take() {
+            handle.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for InProcessTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::InProcess
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        TransportCapabilities {
+            streaming: true,
+            bidirectional: false,
+            server_events: true,
+            max_message_size: None,
+            persistent: false,
+        }
+    }
+
+    async fn connect(&self) -> McpClientResult<()> {
+        self.connected.store(true, Ordering::SeqCst);
+        info!(
+            path = self.service.mcp_path(),
+            "In-process transport connected"
+        );
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> McpClientResult<()> {
+        debug!("Disconnecting in-process transport");
+        self.connected.store(false, Ordering::SeqCst);
+
+        if let Some(handle) = self.listener_handle.lock().take() {
+            handle.abort();
+        }
+        if let Some(sender) = self.event_sender.lock().take() {
+            sender.send(ServerEvent::ConnectionLost).ok();
+        }
+
+        info!("In-process transport disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    async fn send_request(&self, request: Value) -> McpClientResult<Value> {
+        Ok(self.round_trip(request).await?.0)
+    }
+
+    async fn send_request_with_headers(
+        &self,
+        request: Value,
+    ) -> McpClientResult<TransportResponse> {
+        let (body, headers) = self.round_trip(request).await?;
+        Ok(TransportResponse::new(body, headers))
+    }
+
+    async fn send_notification(&self, notification: Value) -> McpClientResult<()> {
+        self.ensure_connected()?;
+
+        debug!(
+            method = notification.get("method").and_then(|v| v.as_str()),
+            "Sending in-process notification"
+        );
+
+        self.update_stats(|stats| stats.notifications_sent += 1);
+        self.post(&notification, MCP_POST_ACCEPT).await?;
+        Ok(())
+    }
+
+    async fn send_batch(&self, batch: Vec<Value>) -> McpClientResult<Vec<Value>> {
+        self.ensure_connected()?;
+
+        let request_count = batch.iter().filter(|item| item.get("id").is_some()).count() as u64;
+        self.update_stats(|stats| {
+            stats.requests_sent += request_count;
+            stats.notifications_sent += batch.len() as u64 - request_count;
+        });
+
+        // Batch responses come back as NDJSON lines or one JSON array, so SSE is not offered
+        let response = self
+            .post(&Value::Array(batch), super::BATCH_POST_ACCEPT)
+            .await?;
+        let body = body_text(response).await;
+
+        let responses = super::parse_batch_responses(&body)?;
+        self.update_stats(|stats| stats.responses_received += responses.len() as u64);
+        Ok(responses)
+    }
+
+    async fn send_delete(&self, session_id: &str) -> McpClientResult<()> {
+        self.ensure_connected()?;
+
+        info!(
+            session_id = session_id,
+            "Sending in-process DELETE for session termination"
+        );
+        self.update_stats(|stats| stats.requests_sent += 1);
+
+        let mut request = self.request(Method::DELETE, "application/json", Bytes::new());
+        request.headers_mut().insert(
+            "Mcp-Session-Id",
+            session_id
+                .parse()
+                .map_err(|_| TransportError::Http(format!("Invalid session ID: {}", session_id)))?,
+        );
+        match self.service.call(request).await {
+            Ok(response) if response.status().is_success() => {
+                self.update_stats(|stats| stats.responses_received += 1);
+            }
+            Ok(response) => {
+                // Don't fail on DELETE errors - session cleanup should continue locally
+                warn!(
+                    session_id = session_id,
+                    status = %response.status(),
+                    "DELETE request failed but continuing with cleanup"
+                );
+            }
+            Err(e) => warn!(session_id = session_id, error = %e, "DELETE request failed"),
+        }
+        Ok(())
+    }
+
+    fn set_session_id(&self, session_id: String) {
+        debug!("InProcessTransport: Setting session ID: {}", session_id);
+        self.session_id.send_replace(Some(session_id));
+    }
+
+    fn clear_session_id(&self) {
+        debug!("InProcessTransport: Clearing session ID for re-initialization");
+        self.session_id.send_replace(None);
+    }
+
+    async fn start_event_listener(&self) -> McpClientResult<EventReceiver> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        *self.event_sender.lock() = Some(tx.clone());
+
+        // Replay any queued events to the new listener
+        for event in std::mem::take(&mut *self.queued_events.lock()) {
+            if tx.send(event).is_err() {
+                warn!("Failed to replay queued event - channel already closed");
+                break;
+            }
+        }
+
+        if !self.is_connected() {
+            warn!("Not connected - event listener will be inactive");
+            return Ok(rx);
+        }
+
+        // The GET stream needs a session, which only exists once initialize returns
+        let service = self.service.clone();
+        let mut session_rx = self.session_id.subscribe();
+        let handle = tokio::spawn(async move {
+            let Ok(session_id) = session_rx
+                .wait_for(Option::is_some)
+                .await
+                .map(|id| id.clone().unwrap_or_default())
+            else {
+                return;
+            };
+
+            let request = Request::builder()
+                .method(Method::GET)
+                .uri(service.mcp_path())
+                .header("Accept", "text/event-stream")
+                .header("MCP-Protocol-Version", "2025-11-25")
+                .header("Mcp-Session-Id", session_id)
+                .body(Bytes::new())
+                .expect("MCP request parts are always valid");
+
+            let response = match service.call(request).await {
+                Ok(response) if response.status().is_success() => response,
+                Ok(response) => {
+                    warn!("SSE stream failed with status: {}", response.status());
+                    tx.send(ServerEvent::Error(format!("HTTP {}", response.status())))
+                        .ok();
+                    return;
+                }
+                Err(e) => {
+                    warn!("SSE stream error: {}", e);
+                    tx.send(ServerEvent::Error(e.to_string())).ok();
+                    return;
+                }
+            };
+
+            let reader = tokio_util::io::StreamReader::new(
+                response
+                    .into_body()
+                    .into_data_stream()
+                    .map_err(std::io::Error::other),
+            );
+            let mut lines = tokio::io::BufReader::new(reader).lines();
+            let mut data = String::new();
+
+            // Events end with a blank line; only their `data:` lines matter here
+            while let Ok(Some(line)) = lines.next_line().await {
+                if let Some(value) = line.strip_prefix("data:") {
+                    if !data.is_empty() {
+                        data.push('\n');
+                    }
+                    data.push_str(value.strip_prefix(' ').unwrap_or(value));
+                    continue;
+                }
+                if !line.is_empty() || data.is_empty() {
+                    continue;
+                }
+
+                match serde_json::from_str::<Value>(&std::mem::take(&mut data)) {
+                    Ok(json) => {
+                        if tx.send(classify_event(json)).is_err() {
+                            debug!("Event channel closed, stopping SSE listener");
+                            return;
+                        }
+                    }
+                    Err(e) => warn!("Failed to parse SSE data as JSON: {}", e),
+                }
+            }
+
+            tx.send(ServerEvent::ConnectionLost).ok();
+        });
+        if let Some(previous) = self.listener_handle.lock().replace(handle) {
+            previous.abort();
+        }
+
+        Ok(rx)
+    }
+
+    fn connection_info(&self) -> ConnectionInfo {
+        ConnectionInfo {
+            transport_type: self.transport_type(),
+            endpoint: format!("in-process:{}", self.service.mcp_path()),
+            connected: self.is_connected(),
+            capabilities: self.capabilities(),
+            metadata: serde_json::json!({
+                "path": self.service.mcp_path(),
+                "session_id": *self.session_id.borrow(),
+            }),
+        }
+    }
+
+    fn statistics(&self) -> TransportStatistics {
+        self.stats.lock().clone()
+    }
+}
+
+/// Classify a server-sent JSON-RPC message
+fn classify_event(json: Value) -> ServerEvent {
+    let has_method = json.get("method").is_some();
+    let has_id = json.get("id").is_some_and(|id| !id.is_null());
+    match (has_method, has_id) {
+        (true, true) => ServerEvent::Request(json),
+        (false, true) => ServerEvent::Response(json),
+        _ => ServerEvent::Notification(json),
+    }
+}
+
+/// Read a whole response body as text, for error messages
+async fn body_text(response: ServiceResponse) -> String {
+    match response.into_body().collect().await {
+        Ok(collected) => String::from_utf8_lossy(&collected.to_bytes()).into_owned(),
+        Err(_) => "Unknown error".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_classify_event() {
+        assert!(matches!(
+            classify_event(json!({"jsonrpc": "2.0", "method": "notifications/progress"})),
+            ServerEvent::Notification(_)
+        ));
+        assert!(matches!(
+            classify_event(json!({"jsonrpc": "2.0", "id": 1, "method": "sampling/createMessage"})),
+            ServerEvent::Request(_)
+        ));
+        assert!(matches!(
+            classify_event(json!({"jsonrpc": "2.0", "id": 1, "result": {}})),
+            ServerEvent::Response(_)
+        ));
+    }
+}
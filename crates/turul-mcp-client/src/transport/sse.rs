@@ -1,4 +1,14 @@
 //! SSE (Server-Sent Events) transport implementation for MCP client
+//!
+//! # Reconnection
+//!
+//! When the event stream drops, the transport reconnects on its own, waiting
+//! [`RetryConfig::delay_for_attempt`] between attempts and sending the `id` of
+//! the last event it delivered as `Last-Event-ID` so the server can replay
+//! anything missed. The event receiver stays open throughout: a transient drop
+//! is only logged. Once `max_attempts` consecutive reconnects have failed, the
+//! transport emits [`ServerEvent::Error`] and [`ServerEvent::ConnectionLost`]
+//! and stops listening.
 
 use async_trait::async_trait;
 use futures::stream::StreamExt;
@@ -11,6 +21,7 @@ use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 use url::Url;
 
+use crate::config::RetryConfig;
 use crate::error::{McpClientResult, TransportError};
 use crate::transport::{
     ConnectionInfo, EventReceiver, ServerEvent, Transport, TransportCapabilities,
@@ -37,7 +48,19 @@ pub struct SseTransport {
     /// SSE stream handle
     sse_handle: parking_lot::Mutex<Option<tokio::task::JoinHandle<()>>>,
     /// Session ID from server (set after initialization)
-    session_id: parking_lot::Mutex<Option<String>>,
+    session_id: Arc<parking_lot::Mutex<Option<String>>>,
+    /// `id` of the last event delivered, sent as `Last-Event-ID` on reconnect
+    last_event_id: Arc<parking_lot::Mutex<Option<String>>>,
+    /// Backoff and retry budget for reconnecting the event stream
+    retry_config: RetryConfig,
+}
+
+/// Why an SSE connection stopped delivering events
+enum StreamEnd {
+    /// The event receiver was dropped; stop listening
+    ReceiverClosed,
+    /// The connection dropped; reconnect
+    Interrupted(String),
 }
 
 impl SseTransport {
@@ -75,7 +98,9 @@ impl SseTransport {
             stats: Arc::new(parking_lot::Mutex::new(TransportStatistics::default())),
             event_sender: parking_lot::Mutex::new(None),
             sse_handle: parking_lot::Mutex::new(None),
-            session_id: parking_lot::Mutex::new(None),
+            session_id: Arc::new(parking_lot::Mutex::new(None)),
+            last_event_id: Arc::new(parking_lot::Mutex::new(None)),
+            retry_config: RetryConfig::default(),
         })
     }
 
@@ -102,10 +127,18 @@ impl SseTransport {
             stats: Arc::new(parking_lot::Mutex::new(TransportStatistics::default())),
             event_sender: parking_lot::Mutex::new(None),
             sse_handle: parking_lot::Mutex::new(None),
-            session_id: parking_lot::Mutex::new(None),
+            session_id: Arc::new(parking_lot::Mutex::new(None)),
+            last_event_id: Arc::new(parking_lot::Mutex::new(None)),
+            retry_config: RetryConfig::default(),
         })
     }
 
+    /// Set the backoff and retry budget used to reconnect the event stream
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
     /// Generate unique request ID
     fn next_request_id(&self) -> String {
         let counter = self.request_counter.fetch_add(1, Ordering::SeqCst);
@@ -164,86 +197,127 @@ impl SseTransport {
         let client = self.client.clone();
         let sse_endpoint = self.sse_endpoint.clone();
         let stats = Arc::clone(&self.stats);
+        let session_id = Arc::clone(&self.session_id);
+        let last_event_id = Arc::clone(&self.last_event_id);
+        let retry = self.retry_config.clone();
 
         let handle = tokio::spawn(async move {
+            // Consecutive reconnect attempts since the last successful connection
+            let mut attempt = 0;
+
             loop {
                 debug!("Connecting to SSE endpoint");
 
-                let response = match client
+                let mut request = client
                     .get(sse_endpoint.clone())
                     .header("Accept", "text/event-stream")
                     .header("Cache-Control", "no-cache")
-                    .header("MCP-Protocol-Version", "2025-11-25")
-                    .send()
-                    .await
-                {
-                    Ok(response) => response,
-                    Err(e) => {
-                        error!(error = %e, "Failed to connect to SSE endpoint");
-                        sender
-                            .send(ServerEvent::Error(format!("SSE connection failed: {}", e)))
-                            .ok();
-                        tokio::time::sleep(Duration::from_secs(5)).await;
-                        continue;
+                    .header("MCP-Protocol-Version", "2025-11-25");
+                if let Some(ref session_id) = *session_id.lock() {
+                    request = request.header("Mcp-Session-Id", session_id);
+                }
+                if let Some(ref event_id) = *last_event_id.lock() {
+                    request = request.header("Last-Event-ID", event_id);
+                }
+
+                let reason = match request.send().await {
+                    Ok(response) if response.status().is_success() => {
+                        if attempt > 0 {
+                            info!(attempt, "SSE stream reconnected");
+                        } else {
+                            info!("SSE stream connected");
+                        }
+                        attempt = 0;
+
+                        match Self::pump_events(response, &sender, &stats, &last_event_id).await {
+                            StreamEnd::ReceiverClosed => {
+                                debug!("Event receiver closed, stopping SSE stream");
+                                return;
+                            }
+                            StreamEnd::Interrupted(reason) => reason,
+                        }
                     }
+                    Ok(response) => format!("SSE endpoint returned {}", response.status()),
+                    Err(e) => format!("SSE connection failed: {}", e),
                 };
 
-                if !response.status().is_success() {
-                    error!(status = %response.status(), "SSE endpoint returned error");
-                    sender
-                        .send(ServerEvent::Error(format!(
-                            "SSE error: {}",
-                            response.status()
-                        )))
-                        .ok();
-                    tokio::time::sleep(Duration::from_secs(5)).await;
-                    continue;
+                attempt += 1;
+                if attempt > retry.max_attempts {
+                    error!(
+                        attempts = retry.max_attempts,
+                        reason = %reason,
+                        "SSE reconnect budget exhausted, giving up"
+                    );
+                    stats.lock().last_error = Some(reason.clone());
+                    sender.send(ServerEvent::Error(reason)).ok();
+                    sender.send(ServerEvent::ConnectionLost).ok();
+                    return;
                 }
 
-                info!("SSE stream connected");
+                let delay = retry.delay_for_attempt(attempt);
+                warn!(
+                    attempt,
+                    max_attempts = retry.max_attempts,
+                    delay_ms = delay.as_millis() as u64,
+                    reason = %reason,
+                    "SSE stream interrupted, reconnecting"
+                );
+                stats.lock().errors += 1;
+                tokio::time::sleep(delay).await;
+            }
+        });
 
-                let mut stream = response.bytes_stream();
-                let mut buffer = String::new();
+        *self.sse_handle.lock() = Some(handle);
+        Ok(())
+    }
 
-                while let Some(chunk) = stream.next().await {
-                    match chunk {
-                        Ok(bytes) => {
-                            let text = String::from_utf8_lossy(&bytes);
-                            buffer.push_str(&text);
+    /// Deliver events from one SSE connection until it ends
+    async fn pump_events(
+        response: Response,
+        sender: &mpsc::UnboundedSender<ServerEvent>,
+        stats: &parking_lot::Mutex<TransportStatistics>,
+        last_event_id: &parking_lot::Mutex<Option<String>>,
+    ) -> StreamEnd {
+        let mut stream = response.bytes_stream();
+        // A partial event left here when the connection drops is replayed by the server
+        let mut buffer = String::new();
+
+        while let Some(chunk) = stream.next().await {
+            let bytes = match chunk {
+                Ok(bytes) => bytes,
+                Err(e) => return StreamEnd::Interrupted(format!("SSE stream error: {}", e)),
+            };
+            buffer.push_str(&String::from_utf8_lossy(&bytes));
 
-                            // Process complete SSE events
-                            while let Some(pos) = buffer.find("\n\n") {
-                                let event_text = buffer[..pos].to_string();
-                                buffer = buffer[pos + 2..].to_string();
+            // Process complete SSE events
+            while let Some(pos) = buffer.find("\n\n") {
+                let event_text = buffer[..pos].to_string();
+                buffer = buffer[pos + 2..].to_string();
 
-                                if let Some(event) = Self::parse_sse_event(&event_text) {
-                                    stats.lock().events_received += 1;
+                if let Some(event_id) = Self::parse_event_id(&event_text) {
+                    *last_event_id.lock() = Some(event_id);
+                }
+                if let Some(event) = Self::parse_sse_event(&event_text) {
+                    stats.lock().events_received += 1;
 
-                                    if sender.send(event).is_err() {
-                                        debug!("Event receiver closed, stopping SSE stream");
-                                        return;
-                                    }
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            error!(error = %e, "SSE stream error");
-                            sender
-                                .send(ServerEvent::Error(format!("SSE stream error: {}", e)))
-                                .ok();
-                            break;
-                        }
+                    if sender.send(event).is_err() {
+                        return StreamEnd::ReceiverClosed;
                     }
                 }
-
-                warn!("SSE stream disconnected, reconnecting in 5 seconds");
-                sender.send(ServerEvent::ConnectionLost).ok();
-                tokio::time::sleep(Duration::from_secs(5)).await;
             }
-        });
+        }
 
-        *self.sse_handle.lock() = Some(handle);
-        Ok(())
+        StreamEnd::Interrupted("SSE stream closed by server".to_string())
+    }
+
+    /// Extract the `id` field of an SSE event, if present and non-empty
+    fn parse_event_id(event_text: &str) -> Option<String> {
+        event_text
+            .lines()
+            .filter_map(|line| line.strip_prefix("id:"))
+            .map(|id| id.trim_start().to_string())
+            .next_back()
+            .filter(|id| !id.is_empty())
     }
 
     /// Parse SSE event from text
@@ -589,7 +663,8 @@ impl Transport for SseTransport {
                 "sse_endpoint": self.sse_endpoint.to_string(),
                 "scheme": self.endpoint.scheme(),
                 "host": self.endpoint.host_str(),
-                "port": self.endpoint.port()
+                "port": self.endpoint.port(),
+                "last_event_id": *self.last_event_id.lock()
             }),
         }
     }
@@ -668,6 +743,21 @@ mod tests {
         assert_eq!(json["method"], "test");
     }
 
+    #[test]
+    fn test_sse_event_id_parsing() {
+        assert_eq!(
+            SseTransport::parse_event_id("id: 42\nevent: notification\ndata: {}"),
+            Some("42".to_string())
+        );
+        assert_eq!(
+            SseTransport::parse_event_id("id:7\ndata: {}"),
+            Some("7".to_string())
+        );
+        assert_eq!(SseTransport::parse_event_id("data: {}"), None);
+        // An empty id does not replace the last one
+        assert_eq!(SseTransport::parse_event_id("id:\ndata: {}"), None);
+    }
+
     #[test]
     fn test_custom_endpoints() {
         let transport = SseTransport::with_endpoints(
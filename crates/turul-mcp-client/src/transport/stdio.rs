@@ -0,0 +1,639 @@
+//! Stdio transport implementation for MCP client
+//!
+//! Spawns the MCP server as a child process and exchanges newline-delimited
+//! JSON-RPC with it: each message is one line on the child's stdin or stdout.
+//! Responses are matched to requests by `id`; server notifications and
+//! server-initiated requests are delivered through the event channel.
+//!
+//! Anything the server writes to stderr is forwarded to `tracing` at INFO
+//! level under the [`STDERR_TARGET`] target, so it can be filtered separately
+//! from the client's own logs.
+//!
+//! # Process lifetime
+//!
+//! [`disconnect`](Transport::disconnect) closes the child's stdin, which is how
+//! the stdio convention asks a server to exit, and waits up to the shutdown
+//! timeout before killing it. Dropping the transport does the same in the
+//! background; the child is killed outright if no Tokio runtime is available.
+//! If the server closes its stdout the transport emits
+//! [`ServerEvent::ConnectionLost`] and fails any pending requests; there is no
+//! automatic restart.
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{debug, info, warn};
+use url::Url;
+
+use crate::error::{McpClientResult, TransportError};
+use crate::transport::{
+    ConnectionInfo, EventReceiver, ServerEvent, Transport, TransportCapabilities,
+    TransportResponse, TransportStatistics, TransportType,
+};
+
+type PendingRequests = HashMap<String, oneshot::Sender<Value>>;
+
+/// Tracing target for lines the server writes to stderr
+pub const STDERR_TARGET: &str = "turul_mcp_client::stdio::stderr";
+
+/// Default time to wait for a response to a request
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+/// Default time the server gets to exit after stdin is closed
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Stdio transport for MCP client
+pub struct StdioTransport {
+    /// Program to spawn
+    program: String,
+    /// Arguments passed to the program
+    args: Vec<String>,
+    /// Extra environment variables for the child
+    envs: Vec<(String, String)>,
+    /// Working directory for the child
+    current_dir: Option<PathBuf>,
+    /// State shared with the I/O tasks
+    shared: Arc<Shared>,
+    /// Request counter
+    request_counter: AtomicU64,
+    /// Time to wait for a response to a request
+    request_timeout: Duration,
+    /// Time the child gets to exit before it is killed
+    shutdown_timeout: Duration,
+    /// Running child process
+    child: parking_lot::Mutex<Option<Child>>,
+    /// stdout and stderr reader task handles
+    reader_handles: parking_lot::Mutex<Vec<tokio::task::JoinHandle<()>>>,
+}
+
+struct Shared {
+    /// Connection state
+    connected: AtomicBool,
+    /// Lines queued for the child's stdin
+    outgoing: parking_lot::Mutex<Option<mpsc::UnboundedSender<String>>>,
+    /// Requests awaiting a response, keyed by serialized JSON-RPC id
+    pending: parking_lot::Mutex<PendingRequests>,
+    /// Event sender for server events
+    event_sender: parking_lot::Mutex<Option<mpsc::UnboundedSender<ServerEvent>>>,
+    /// Session ID (stdio servers normally have none; kept for connection info)
+    session_id: parking_lot::Mutex<Option<String>>,
+    /// Statistics
+    stats: parking_lot::Mutex<TransportStatistics>,
+}
+
+impl std::fmt::Debug for StdioTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StdioTransport")
+            .field("program", &self.program)
+            .field("args", &self.args)
+            .field("connected", &self.is_connected())
+            .field("pid", &self.process_id())
+            .finish()
+    }
+}
+
+impl StdioTransport {
+    /// Create a new stdio transport that will spawn `program` on connect
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            envs: Vec::new(),
+            current_dir: None,
+            shared: Arc::new(Shared {
+                connected: AtomicBool::new(false),
+                outgoing: parking_lot::Mutex::new(None),
+                pending: parking_lot::Mutex::new(HashMap::new()),
+                event_sender: parking_lot::Mutex::new(None),
+                session_id: parking_lot::Mutex::new(None),
+                stats: parking_lot::Mutex::new(TransportStatistics::default()),
+            }),
+            request_counter: AtomicU64::new(0),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+            child: parking_lot::Mutex::new(None),
+            reader_handles: parking_lot::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Create a stdio transport from a `stdio:` URL
+    ///
+    /// The URL path is the program and each `arg` query parameter is passed
+    /// as an argument, e.g. `stdio:///usr/local/bin/server?arg=--verbose`.
+    pub fn from_url(url: &str) -> McpClientResult<Self> {
+        let url = Url::parse(url)
+            .map_err(|e| TransportError::ConnectionFailed(format!("Invalid URL: {}", e)))?;
+        if url.scheme() != "stdio" {
+            return Err(TransportError::ConnectionFailed(format!(
+                "Invalid scheme for stdio transport: {}",
+                url.scheme()
+            ))
+            .into());
+        }
+        if url.path().is_empty() {
+            return Err(
+                TransportError::ConnectionFailed("stdio URL has no program".to_string()).into(),
+            );
+        }
+
+        let args = url
+            .query_pairs()
+            .filter(|(key, _)| key == "arg")
+            .map(|(_, value)| value.into_owned());
+        Ok(Self::new(url.path()).with_args(args))
+    }
+
+    /// Append arguments passed to the program
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set an environment variable for the child process
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set the working directory for the child process
+    pub fn with_current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    /// Set how long to wait for a response to a request
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Set how long the child gets to exit after stdin is closed before it is killed
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    /// OS process ID of the running server, if connected
+    pub fn process_id(&self) -> Option<u32> {
+        self.child.lock().as_ref().and_then(Child::id)
+    }
+
+    /// Command line shown in logs and connection info
+    fn command_line(&self) -> String {
+        std::iter::once(self.program.as_str())
+            .chain(self.args.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Generate unique request ID
+    fn next_request_id(&self) -> String {
+        let counter = self.request_counter.fetch_add(1, Ordering::SeqCst);
+        format!("req_{}", counter)
+    }
+
+    /// Queue a message as one line on the child's stdin
+    fn send_line(&self, message: &Value) -> McpClientResult<()> {
+        let outgoing = self.shared.outgoing.lock();
+        let sender = outgoing
+            .as_ref()
+            .ok_or_else(|| TransportError::ConnectionFailed("Not connected".to_string()))?;
+        // Compact serialization never contains a raw newline
+        sender
+            .send(message.to_string())
+            .map_err(|_| TransportError::Closed.into())
+    }
+}
+
+impl Shared {
+    /// Update statistics
+    fn update_stats<F>(&self, update_fn: F)
+    where
+        F: FnOnce(&mut TransportStatistics),
+    {
+        let mut stats = self.stats.lock();
+        update_fn(&mut stats);
+    }
+
+    /// Deliver a server event, if a listener has been started
+    fn emit(&self, event: ServerEvent) {
+        if let Some(sender) = self.event_sender.lock().as_ref() {
+            sender.send(event).ok();
+        }
+    }
+
+    /// Route one line read from the child's stdout
+    fn handle_line(&self, line: &str) {
+        let message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(e) => {
+                warn!(error = %e, line = line, "Ignoring non-JSON line on server stdout");
+                return;
+            }
+        };
+
+        let has_method = message.get("method").is_some();
+        let id = message.get("id").filter(|id| !id.is_null()).cloned();
+        match (has_method, id) {
+            (false, Some(id)) => {
+                let waiter = self.pending.lock().remove(&id.to_string());
+                self.update_stats(|stats| stats.responses_received += 1);
+                match waiter {
+                    Some(waiter) => {
+                        waiter.send(message).ok();
+                    }
+                    None => self.emit(ServerEvent::Response(message)),
+                }
+            }
+            (true, Some(_)) => {
+                self.update_stats(|stats| stats.events_received += 1);
+                self.emit(ServerEvent::Request(message));
+            }
+            (true, None) => {
+                self.update_stats(|stats| stats.events_received += 1);
+                self.emit(ServerEvent::Notification(message));
+            }
+            (false, None) => {
+                debug!(message = %message, "Ignoring stdout message without method or id");
+            }
+        }
+    }
+
+    /// Read stdout until the server closes it
+    async fn read_stdout(&self, stdout: ChildStdout) {
+        let mut lines = BufReader::new(stdout).lines();
+        loop {
+            match lines.next_line().await {
+                Ok(Some(line)) if line.trim().is_empty() => continue,
+                Ok(Some(line)) => self.handle_line(&line),
+                Ok(None) => break,
+                Err(e) => {
+                    warn!(error = %e, "Failed to read server stdout");
+                    self.emit(ServerEvent::Error(format!("stdout read error: {}", e)));
+                    break;
+                }
+            }
+        }
+
+        // Fail everything still waiting on this process
+        self.outgoing.lock().take();
+        self.pending.lock().clear();
+        if self.connected.swap(false, Ordering::SeqCst) {
+            warn!("MCP server closed stdout");
+            self.emit(ServerEvent::ConnectionLost);
+        }
+    }
+}
+
+/// Write queued lines to the child's stdin, closing it when the sender is dropped
+async fn write_stdin(mut stdin: ChildStdin, mut receiver: mpsc::UnboundedReceiver<String>) {
+    while let Some(mut line) = receiver.recv().await {
+        line.push('\n');
+        let written = async {
+            stdin.write_all(line.as_bytes()).await?;
+            stdin.flush().await
+        };
+        if let Err(e) = written.await {
+            debug!(error = %e, "Failed to write to server stdin");
+            return;
+        }
+    }
+}
+
+/// Forward the child's stderr to tracing
+async fn forward_stderr(stderr: ChildStderr) {
+    let mut lines = BufReader::new(stderr).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        info!(target: STDERR_TARGET, "{}", line);
+    }
+}
+
+/// Wait for the child to exit on its own, killing it after `grace`
+async fn reap(mut child: Child, grace: Duration) {
+    match tokio::time::timeout(grace, child.wait()).await {
+        Ok(Ok(status)) => debug!(%status, "MCP server exited"),
+        Ok(Err(e)) => warn!(error = %e, "Failed to wait for MCP server"),
+        Err(_) => {
+            warn!(
+                grace_ms = grace.as_millis(),
+                "MCP server did not exit after stdin closed, killing it"
+            );
+            if let Err(e) = child.kill().await {
+                warn!(error = %e, "Failed to kill MCP server");
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for StdioTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::Stdio
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        TransportCapabilities {
+            streaming: true,
+            bidirectional: true,
+            server_events: true,
+            max_message_size: None,
+            persistent: true,
+        }
+    }
+
+    async fn connect(&self) -> McpClientResult<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+        debug!(command = %self.command_line(), "Spawning stdio MCP server");
+
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .envs(self.envs.iter().map(|(k, v)| (k, v)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        if let Some(dir) = &self.current_dir {
+            command.current_dir(dir);
+        }
+
+        let mut child = command.spawn().map_err(|e| {
+            self.shared.update_stats(|stats| {
+                stats.errors += 1;
+                stats.last_error = Some(e.to_string());
+            });
+            TransportError::Stdio(format!("Failed to spawn '{}': {}", self.program, e))
+        })?;
+        let (Some(stdin), Some(stdout), Some(stderr)) =
+            (child.stdin.take(), child.stdout.take(), child.stderr.take())
+        else {
+            return Err(TransportError::Stdio("Child stdio was not piped".to_string()).into());
+        };
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(write_stdin(stdin, receiver));
+        *self.shared.outgoing.lock() = Some(sender);
+        self.shared.connected.store(true, Ordering::SeqCst);
+
+        let shared = Arc::clone(&self.shared);
+        let stdout_handle = tokio::spawn(async move { shared.read_stdout(stdout).await });
+        let stderr_handle = tokio::spawn(forward_stderr(stderr));
+        let mut reader_handles = self.reader_handles.lock();
+        for previous in reader_handles.drain(..) {
+            previous.abort();
+        }
+        reader_handles.extend([stdout_handle, stderr_handle]);
+        drop(reader_handles);
+
+        info!(pid = child.id(), "Stdio transport connected");
+        *self.child.lock() = Some(child);
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> McpClientResult<()> {
+        debug!("Disconnecting stdio transport");
+        self.shared.connected.store(false, Ordering::SeqCst);
+
+        // Dropping the sender closes stdin once queued lines are written
+        self.shared.outgoing.lock().take();
+        self.shared.pending.lock().clear();
+
+        let child = self.child.lock().take();
+        if let Some(child) = child {
+            reap(child, self.shutdown_timeout).await;
+        }
+        for handle in self.reader_handles.lock().drain(..) {
+            handle.abort();
+        }
+
+        // Close event sender
+        if let Some(sender) = self.shared.event_sender.lock().take() {
+            sender.send(ServerEvent::ConnectionLost).ok();
+        }
+
+        info!("Stdio transport disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.shared.connected.load(Ordering::SeqCst)
+    }
+
+    async fn send_request(&self, request: Value) -> McpClientResult<Value> {
+        if !self.is_connected() {
+            return Err(TransportError::ConnectionFailed("Not connected".to_string()).into());
+        }
+
+        let start_time = Instant::now();
+
+        // Ensure request has an ID
+        let mut request = request;
+        if request.get("id").is_none() {
+            request["id"] = Value::String(self.next_request_id());
+        }
+        let key = request["id"].to_string();
+
+        debug!(
+            method = request.get("method").and_then(|v| v.as_str()),
+            id = %key,
+            "Sending stdio request"
+        );
+
+        let (sender, receiver) = oneshot::channel();
+        self.shared.pending.lock().insert(key.clone(), sender);
+        if let Err(e) = self.send_line(&request) {
+            self.shared.pending.lock().remove(&key);
+            return Err(e);
+        }
+        self.shared.update_stats(|stats| stats.requests_sent += 1);
+
+        let response = match tokio::time::timeout(self.request_timeout, receiver).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => return Err(TransportError::Closed.into()),
+            Err(_) => {
+                self.shared.pending.lock().remove(&key);
+                self.shared.update_stats(|stats| {
+                    stats.errors += 1;
+                    stats.last_error = Some(format!("Request {} timed out", key));
+                });
+                return Err(crate::error::McpClientError::Timeout);
+            }
+        };
+
+        let elapsed = start_time.elapsed();
+        self.shared.update_stats(|stats| {
+            let new_avg = if stats.responses_received > 0 {
+                (stats.avg_response_time_ms * (stats.responses_received - 1) as f64
+                    + elapsed.as_millis() as f64)
+                    / stats.responses_received as f64
+            } else {
+                elapsed.as_millis() as f64
+            };
+            stats.avg_response_time_ms = new_avg;
+        });
+
+        debug!(elapsed_ms = elapsed.as_millis(), "Stdio request completed");
+
+        Ok(response)
+    }
+
+    async fn send_request_with_headers(
+        &self,
+        request: Value,
+    ) -> McpClientResult<TransportResponse> {
+        // Stdio has no headers, so no Mcp-Session-Id: the session is the process
+        let body = self.send_request(request).await?;
+        Ok(TransportResponse::body_only(body))
+    }
+
+    async fn send_notification(&self, notification: Value) -> McpClientResult<()> {
+        if !self.is_connected() {
+            return Err(TransportError::ConnectionFailed("Not connected".to_string()).into());
+        }
+
+        debug!(
+            method = notification.get("method").and_then(|v| v.as_str()),
+            "Sending stdio notification"
+        );
+
+        self.send_line(&notification)?;
+        self.shared
+            .update_stats(|stats| stats.notifications_sent += 1);
+        Ok(())
+    }
+
+    async fn send_delete(&self, session_id: &str) -> McpClientResult<()> {
+        // The session ends with the process; disconnect() stops it
+        debug!(
+            session_id = session_id,
+            "Stdio transport has no DELETE; session ends when the server exits"
+        );
+        Ok(())
+    }
+
+    async fn start_event_listener(&self) -> McpClientResult<EventReceiver> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        *self.shared.event_sender.lock() = Some(sender);
+
+        info!("Stdio event listener started");
+        Ok(receiver)
+    }
+
+    fn connection_info(&self) -> ConnectionInfo {
+        ConnectionInfo {
+            transport_type: self.transport_type(),
+            endpoint: self.command_line(),
+            connected: self.is_connected(),
+            capabilities: self.capabilities(),
+            metadata: serde_json::json!({
+                "program": self.program,
+                "args": self.args,
+                "pid": self.process_id(),
+                "session_id": *self.shared.session_id.lock(),
+            }),
+        }
+    }
+
+    fn set_session_id(&self, session_id: String) {
+        debug!("Stdio transport: Setting session ID: {}", session_id);
+        *self.shared.session_id.lock() = Some(session_id);
+    }
+
+    fn clear_session_id(&self) {
+        debug!("Stdio transport: Clearing session ID for re-initialization");
+        *self.shared.session_id.lock() = None;
+    }
+
+    fn statistics(&self) -> TransportStatistics {
+        self.shared.stats.lock().clone()
+    }
+}
+
+impl Drop for StdioTransport {
+    fn drop(&mut self) {
+        self.shared.connected.store(false, Ordering::SeqCst);
+        // Closes stdin so the server can exit on its own
+        self.shared.outgoing.lock().take();
+        for handle in self.reader_handles.lock().drain(..) {
+            handle.abort();
+        }
+
+        if let Some(mut child) = self.child.lock().take() {
+            match tokio::runtime::Handle::try_current() {
+                Ok(runtime) => {
+                    runtime.spawn(reap(child, self.shutdown_timeout));
+                }
+                Err(_) => {
+                    child.start_kill().ok();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stdio_transport_from_url() {
+        let transport =
+            StdioTransport::from_url("stdio:///usr/local/bin/server?arg=--verbose&arg=-p&arg=8")
+                .unwrap();
+        assert_eq!(transport.transport_type(), TransportType::Stdio);
+        assert_eq!(transport.program, "/usr/local/bin/server");
+        assert_eq!(transport.args, ["--verbose", "-p", "8"]);
+        assert!(!transport.is_connected());
+        assert!(transport.process_id().is_none());
+
+        assert!(StdioTransport::from_url("http://localhost:8080/mcp").is_err());
+    }
+
+    #[test]
+    fn test_line_routing() {
+        let transport = StdioTransport::new("server");
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        *transport.shared.event_sender.lock() = Some(sender);
+
+        transport
+            .shared
+            .handle_line(r#"{"jsonrpc":"2.0","method":"notifications/progress","params":{}}"#);
+        assert!(matches!(
+            receiver.try_recv(),
+            Ok(ServerEvent::Notification(_))
+        ));
+
+        transport
+            .shared
+            .handle_line(r#"{"jsonrpc":"2.0","id":7,"method":"sampling/createMessage"}"#);
+        assert!(matches!(receiver.try_recv(), Ok(ServerEvent::Request(_))));
+
+        // Log output on stdout is skipped rather than treated as fatal
+        transport.shared.handle_line("server starting...");
+        assert!(receiver.try_recv().is_err());
+
+        let (waiter, mut response) = oneshot::channel();
+        transport
+            .shared
+            .pending
+            .lock()
+            .insert("\"req_1\"".to_string(), waiter);
+        transport
+            .shared
+            .handle_line(r#"{"jsonrpc":"2.0","id":"req_1","result":{}}"#);
+        assert_eq!(response.try_recv().unwrap()["id"], "req_1");
+    }
+}
@@ -0,0 +1,639 @@
+//! WebSocket transport implementation for MCP client
+//!
+//! Every JSON-RPC message travels as one text frame in either direction.
+//! Responses are matched to requests by `id`; server notifications and
+//! server-initiated requests are delivered through the same event channel
+//! the HTTP and SSE transports use.
+//!
+//! # URLs and TLS
+//!
+//! `ws://` connects in plain text. `wss://` connects over TLS using rustls
+//! with the Mozilla root certificates from `webpki-roots`; supply a custom
+//! [`rustls::ClientConfig`] with [`WebSocketTransport::with_tls_config`] for
+//! private CAs or client certificates.
+//!
+//! # Reconnection and resume
+//!
+//! Like [`SseTransport`](super::SseTransport), a dropped connection emits
+//! [`ServerEvent::ConnectionLost`] and is re-established after a delay until
+//! [`disconnect`](Transport::disconnect) is called. Requests still awaiting a
+//! response when the connection drops fail with [`TransportError::Closed`].
+//!
+//! A server may tag frames with a top-level `"eventId"` member. The transport
+//! strips it before delivering the message and sends the most recent one as a
+//! `Last-Event-ID` header when reconnecting, so the server can replay what the
+//! client missed.
+
+use async_trait::async_trait;
+use futures::stream::{SplitStream, StreamExt};
+use futures::{SinkExt, stream::SplitSink};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::{Connector, MaybeTlsStream, WebSocketStream};
+use tracing::{debug, error, info, warn};
+use url::Url;
+
+use crate::error::{McpClientResult, TransportError};
+use crate::transport::{
+    ConnectionInfo, EventReceiver, ServerEvent, Transport, TransportCapabilities,
+    TransportResponse, TransportStatistics, TransportType,
+};
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type PendingRequests = HashMap<String, oneshot::Sender<Value>>;
+
+/// Default delay before reconnecting after the connection drops
+const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+/// Default time to wait for a response to a request
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// WebSocket transport for MCP client
+pub struct WebSocketTransport {
+    /// State shared with the connection task
+    shared: Arc<Shared>,
+    /// Request counter
+    request_counter: AtomicU64,
+    /// Time to wait for a response to a request
+    request_timeout: Duration,
+    /// Connection task handle
+    connection_handle: parking_lot::Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+struct Shared {
+    /// Server endpoint URL
+    endpoint: Url,
+    /// Connection state (false once `disconnect()` is called)
+    connected: AtomicBool,
+    /// Frames queued for the current connection's writer
+    outgoing: parking_lot::Mutex<Option<mpsc::UnboundedSender<Message>>>,
+    /// Requests awaiting a response, keyed by serialized JSON-RPC id
+    pending: parking_lot::Mutex<PendingRequests>,
+    /// Event sender for server events
+    event_sender: parking_lot::Mutex<Option<mpsc::UnboundedSender<ServerEvent>>>,
+    /// Headers from the most recent handshake response
+    handshake_headers: parking_lot::Mutex<HashMap<String, String>>,
+    /// Session ID from server (set after initialization)
+    session_id: parking_lot::Mutex<Option<String>>,
+    /// Most recent `eventId` seen, sent as `Last-Event-ID` on reconnect
+    last_event_id: parking_lot::Mutex<Option<String>>,
+    /// Custom TLS configuration for `wss://`
+    tls_config: Option<Arc<rustls::ClientConfig>>,
+    /// Delay before reconnecting
+    reconnect_delay: Duration,
+    /// Statistics
+    stats: parking_lot::Mutex<TransportStatistics>,
+}
+
+impl std::fmt::Debug for WebSocketTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketTransport")
+            .field("endpoint", &self.shared.endpoint.as_str())
+            .field("connected", &self.is_connected())
+            .field("request_timeout", &self.request_timeout)
+            .field("reconnect_delay", &self.shared.reconnect_delay)
+            .finish()
+    }
+}
+
+impl WebSocketTransport {
+    /// Create a new WebSocket transport for a `ws://` or `wss://` URL
+    pub fn new(endpoint: &str) -> McpClientResult<Self> {
+        let url = Url::parse(endpoint)
+            .map_err(|e| TransportError::ConnectionFailed(format!("Invalid URL: {}", e)))?;
+
+        // Validate URL scheme
+        if !matches!(url.scheme(), "ws" | "wss") {
+            return Err(TransportError::ConnectionFailed(format!(
+                "Invalid scheme for WebSocket transport: {}",
+                url.scheme()
+            ))
+            .into());
+        }
+
+        Ok(Self {
+            shared: Arc::new(Shared {
+                endpoint: url,
+                connected: AtomicBool::new(false),
+                outgoing: parking_lot::Mutex::new(None),
+                pending: parking_lot::Mutex::new(HashMap::new()),
+                event_sender: parking_lot::Mutex::new(None),
+                handshake_headers: parking_lot::Mutex::new(HashMap::new()),
+                session_id: parking_lot::Mutex::new(None),
+                last_event_id: parking_lot::Mutex::new(None),
+                tls_config: None,
+                reconnect_delay: DEFAULT_RECONNECT_DELAY,
+                stats: parking_lot::Mutex::new(TransportStatistics::default()),
+            }),
+            request_counter: AtomicU64::new(0),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            connection_handle: parking_lot::Mutex::new(None),
+        })
+    }
+
+    /// Use a custom TLS configuration for `wss://` connections
+    pub fn with_tls_config(mut self, config: Arc<rustls::ClientConfig>) -> Self {
+        self.shared_mut().tls_config = Some(config);
+        self
+    }
+
+    /// Set the delay before reconnecting after the connection drops
+    pub fn with_reconnect_delay(mut self, delay: Duration) -> Self {
+        self.shared_mut().reconnect_delay = delay;
+        self
+    }
+
+    /// Set how long to wait for a response to a request
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    fn shared_mut(&mut self) -> &mut Shared {
+        Arc::get_mut(&mut self.shared)
+            .expect("WebSocketTransport is configured before it is connected")
+    }
+
+    /// Generate unique request ID
+    fn next_request_id(&self) -> String {
+        let counter = self.request_counter.fetch_add(1, Ordering::SeqCst);
+        format!("req_{}", counter)
+    }
+
+    /// Queue a message on the current connection
+    fn send_frame(&self, message: &Value) -> McpClientResult<()> {
+        let outgoing = self.shared.outgoing.lock();
+        let sender = outgoing
+            .as_ref()
+            .ok_or_else(|| TransportError::ConnectionFailed("Not connected".to_string()))?;
+        sender
+            .send(Message::text(message.to_string()))
+            .map_err(|_| TransportError::Closed.into())
+    }
+}
+
+impl Shared {
+    /// Update statistics
+    fn update_stats<F>(&self, update_fn: F)
+    where
+        F: FnOnce(&mut TransportStatistics),
+    {
+        let mut stats = self.stats.lock();
+        update_fn(&mut stats);
+    }
+
+    /// Perform the WebSocket handshake, resuming from the last event if known
+    async fn open(&self) -> McpClientResult<WsStream> {
+        let mut request = self
+            .endpoint
+            .as_str()
+            .into_client_request()
+            .map_err(|e| TransportError::ConnectionFailed(format!("Invalid request: {}", e)))?;
+        let headers = request.headers_mut();
+        headers.insert(
+            "MCP-Protocol-Version",
+            HeaderValue::from_static("2025-11-25"),
+        );
+        if let Some(session_id) = self.session_id.lock().as_deref()
+            && let Ok(value) = HeaderValue::from_str(session_id)
+        {
+            headers.insert("Mcp-Session-Id", value);
+        }
+        if let Some(event_id) = self.last_event_id.lock().as_deref()
+            && let Ok(value) = HeaderValue::from_str(event_id)
+        {
+            headers.insert("Last-Event-ID", value);
+        }
+
+        let connector = match (&self.tls_config, self.endpoint.scheme()) {
+            (Some(config), "wss") => Some(Connector::Rustls(Arc::clone(config))),
+            (None, "wss") => Some(Connector::Rustls(default_tls_config()?)),
+            _ => None,
+        };
+
+        let (stream, response) =
+            tokio_tungstenite::connect_async_tls_with_config(request, None, false, connector)
+                .await
+                .map_err(|e| {
+                    self.update_stats(|stats| {
+                        stats.errors += 1;
+                        stats.last_error = Some(e.to_string());
+                    });
+                    TransportError::ConnectionFailed(format!("WebSocket handshake failed: {}", e))
+                })?;
+
+        let mut handshake_headers = HashMap::new();
+        for (name, value) in response.headers() {
+            if let Ok(value_str) = value.to_str() {
+                handshake_headers.insert(name.to_string(), value_str.to_string());
+            }
+        }
+        *self.handshake_headers.lock() = handshake_headers;
+
+        Ok(stream)
+    }
+
+    /// Start the writer for a freshly opened connection and return its reader
+    fn attach(&self, stream: WsStream) -> SplitStream<WsStream> {
+        let (sink, reader) = stream.split();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(write_frames(sink, receiver));
+        *self.outgoing.lock() = Some(sender);
+        reader
+    }
+
+    /// Deliver a server event, if a listener has been started
+    fn emit(&self, event: ServerEvent) {
+        if let Some(sender) = self.event_sender.lock().as_ref() {
+            sender.send(event).ok();
+        }
+    }
+
+    /// Route one incoming text frame
+    fn handle_text(&self, text: &str) {
+        let mut message: Value = match serde_json::from_str(text) {
+            Ok(message) => message,
+            Err(e) => {
+                warn!(error = %e, "Ignoring non-JSON WebSocket frame");
+                return;
+            }
+        };
+
+        if let Some(event_id) = message.as_object_mut().and_then(|m| m.remove("eventId")) {
+            let event_id = match event_id {
+                Value::String(id) => id,
+                other => other.to_string(),
+            };
+            *self.last_event_id.lock() = Some(event_id);
+        }
+
+        let has_method = message.get("method").is_some();
+        let id = message.get("id").filter(|id| !id.is_null()).cloned();
+        match (has_method, id) {
+            (false, Some(id)) => {
+                let waiter = self.pending.lock().remove(&id.to_string());
+                self.update_stats(|stats| stats.responses_received += 1);
+                match waiter {
+                    Some(waiter) => {
+                        waiter.send(message).ok();
+                    }
+                    None => self.emit(ServerEvent::Response(message)),
+                }
+            }
+            (true, Some(_)) => {
+                self.update_stats(|stats| stats.events_received += 1);
+                self.emit(ServerEvent::Request(message));
+            }
+            (true, None) => {
+                self.update_stats(|stats| stats.events_received += 1);
+                self.emit(ServerEvent::Notification(message));
+            }
+            (false, None) => {
+                debug!(message = %message, "Ignoring WebSocket frame without method or id");
+            }
+        }
+    }
+
+    /// Read frames until the connection closes
+    async fn read_frames(&self, mut reader: SplitStream<WsStream>) {
+        while let Some(frame) = reader.next().await {
+            match frame {
+                Ok(Message::Text(text)) => self.handle_text(text.as_str()),
+                Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => self.emit(ServerEvent::Heartbeat),
+                Ok(Message::Close(frame)) => {
+                    debug!(?frame, "WebSocket closed by server");
+                    break;
+                }
+                Ok(_) => debug!("Ignoring non-text WebSocket frame"),
+                Err(e) => {
+                    error!(error = %e, "WebSocket stream error");
+                    self.emit(ServerEvent::Error(format!("WebSocket stream error: {}", e)));
+                    break;
+                }
+            }
+        }
+
+        // Fail everything still waiting on this connection
+        self.outgoing.lock().take();
+        self.pending.lock().clear();
+    }
+}
+
+/// Forward queued frames to the socket
+async fn write_frames(
+    mut sink: SplitSink<WsStream, Message>,
+    mut receiver: mpsc::UnboundedReceiver<Message>,
+) {
+    while let Some(message) = receiver.recv().await {
+        if let Err(e) = sink.send(message).await {
+            debug!(error = %e, "WebSocket write failed");
+            return;
+        }
+    }
+    // Sender dropped: the transport is disconnecting
+    sink.send(Message::Close(None)).await.ok();
+}
+
+/// Keep the connection alive until `disconnect()`, reconnecting as needed
+async fn run_connection(shared: Arc<Shared>, mut reader: SplitStream<WsStream>) {
+    loop {
+        shared.read_frames(reader).await;
+        if !shared.connected.load(Ordering::SeqCst) {
+            return;
+        }
+
+        warn!(
+            delay_ms = shared.reconnect_delay.as_millis(),
+            "WebSocket disconnected, reconnecting"
+        );
+        shared.emit(ServerEvent::ConnectionLost);
+
+        reader = loop {
+            tokio::time::sleep(shared.reconnect_delay).await;
+            if !shared.connected.load(Ordering::SeqCst) {
+                return;
+            }
+            match shared.open().await {
+                Ok(stream) => {
+                    info!("WebSocket reconnected");
+                    break shared.attach(stream);
+                }
+                Err(e) => {
+                    error!(error = %e, "WebSocket reconnect failed");
+                    shared.emit(ServerEvent::Error(format!(
+                        "WebSocket reconnect failed: {}",
+                        e
+                    )));
+                }
+            }
+        };
+    }
+}
+
+/// rustls configuration trusting the Mozilla root certificates
+fn default_tls_config() -> McpClientResult<Arc<rustls::ClientConfig>> {
+    let roots = rustls::RootCertStore::from_iter(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let config = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .map_err(|e| TransportError::ConnectionFailed(format!("TLS configuration failed: {}", e)))?
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    Ok(Arc::new(config))
+}
+
+#[async_trait]
+impl Transport for WebSocketTransport {
+    fn transport_type(&self) -> TransportType {
+        TransportType::WebSocket
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        TransportCapabilities {
+            streaming: true,
+            bidirectional: true,
+            server_events: true,
+            max_message_size: None,
+            persistent: true,
+        }
+    }
+
+    async fn connect(&self) -> McpClientResult<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+        debug!(endpoint = %self.shared.endpoint, "Connecting WebSocket transport");
+
+        let stream = self.shared.open().await?;
+        let reader = self.shared.attach(stream);
+        self.shared.connected.store(true, Ordering::SeqCst);
+
+        let handle = tokio::spawn(run_connection(Arc::clone(&self.shared), reader));
+        if let Some(previous) = self.connection_handle.lock().replace(handle) {
+            previous.abort();
+        }
+
+        info!("WebSocket transport connected");
+        Ok(())
+    }
+
+    async fn disconnect(&self) -> McpClientResult<()> {
+        debug!("Disconnecting WebSocket transport");
+        self.shared.connected.store(false, Ordering::SeqCst);
+
+        // Dropping the sender makes the writer send a Close frame
+        self.shared.outgoing.lock().take();
+        self.shared.pending.lock().clear();
+
+        if let Some(handle) = self.connection_handle.lock().take() {
+            handle.abort();
+        }
+
+        // Close event sender
+        if let Some(sender) = self.shared.event_sender.lock().take() {
+            sender.send(ServerEvent::ConnectionLost).ok();
+        }
+
+        info!("WebSocket transport disconnected");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.shared.connected.load(Ordering::SeqCst)
+    }
+
+    async fn send_request(&self, request: Value) -> McpClientResult<Value> {
+        if !self.is_connected() {
+            return Err(TransportError::ConnectionFailed("Not connected".to_string()).into());
+        }
+
+        let start_time = Instant::now();
+
+        // Ensure request has an ID
+        let mut request = request;
+        if request.get("id").is_none() {
+            request["id"] = Value::String(self.next_request_id());
+        }
+        let key = request["id"].to_string();
+
+        debug!(
+            method = request.get("method").and_then(|v| v.as_str()),
+            id = %key,
+            "Sending WebSocket request"
+        );
+
+        let (sender, receiver) = oneshot::channel();
+        self.shared.pending.lock().insert(key.clone(), sender);
+        if let Err(e) = self.send_frame(&request) {
+            self.shared.pending.lock().remove(&key);
+            return Err(e);
+        }
+        self.shared.update_stats(|stats| stats.requests_sent += 1);
+
+        let response = match tokio::time::timeout(self.request_timeout, receiver).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(_)) => return Err(TransportError::Closed.into()),
+            Err(_) => {
+                self.shared.pending.lock().remove(&key);
+                self.shared.update_stats(|stats| {
+                    stats.errors += 1;
+                    stats.last_error = Some(format!("Request {} timed out", key));
+                });
+                return Err(crate::error::McpClientError::Timeout);
+            }
+        };
+
+        let elapsed = start_time.elapsed();
+        self.shared.update_stats(|stats| {
+            let new_avg = if stats.responses_received > 0 {
+                (stats.avg_response_time_ms * (stats.responses_received - 1) as f64
+                    + elapsed.as_millis() as f64)
+                    / stats.responses_received as f64
+            } else {
+                elapsed.as_millis() as f64
+            };
+            stats.avg_response_time_ms = new_avg;
+        });
+
+        debug!(
+            elapsed_ms = elapsed.as_millis(),
+            "WebSocket request completed"
+        );
+
+        Ok(response)
+    }
+
+    async fn send_request_with_headers(
+        &self,
+        request: Value,
+    ) -> McpClientResult<TransportResponse> {
+        // Frames carry no headers; the handshake response stands in for them
+        // (a server can return Mcp-Session-Id there)
+        let body = self.send_request(request).await?;
+        let headers = self.shared.handshake_headers.lock().clone();
+        Ok(TransportResponse::new(body, headers))
+    }
+
+    async fn send_notification(&self, notification: Value) -> McpClientResult<()> {
+        if !self.is_connected() {
+            return Err(TransportError::ConnectionFailed("Not connected".to_string()).into());
+        }
+
+        debug!(
+            method = notification.get("method").and_then(|v| v.as_str()),
+            "Sending WebSocket notification"
+        );
+
+        self.send_frame(&notification)?;
+        self.shared
+            .update_stats(|stats| stats.notifications_sent += 1);
+        Ok(())
+    }
+
+    async fn send_delete(&self, session_id: &str) -> McpClientResult<()> {
+        // The session ends with the connection; disconnect() closes it
+        debug!(
+            session_id = session_id,
+            "WebSocket transport has no DELETE; session ends when the connection closes"
+        );
+        Ok(())
+    }
+
+    async fn start_event_listener(&self) -> McpClientResult<EventReceiver> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        *self.shared.event_sender.lock() = Some(sender);
+
+        info!("WebSocket event listener started");
+        Ok(receiver)
+    }
+
+    fn connection_info(&self) -> ConnectionInfo {
+        let endpoint = &self.shared.endpoint;
+        ConnectionInfo {
+            transport_type: self.transport_type(),
+            endpoint: endpoint.to_string(),
+            connected: self.is_connected(),
+            capabilities: self.capabilities(),
+            metadata: serde_json::json!({
+                "scheme": endpoint.scheme(),
+                "host": endpoint.host_str(),
+                "port": endpoint.port(),
+                "tls": endpoint.scheme() == "wss",
+                "last_event_id": *self.shared.last_event_id.lock(),
+            }),
+        }
+    }
+
+    fn set_session_id(&self, session_id: String) {
+        debug!("WebSocket transport: Setting session ID: {}", session_id);
+        *self.shared.session_id.lock() = Some(session_id);
+    }
+
+    fn clear_session_id(&self) {
+        debug!("WebSocket transport: Clearing session ID for re-initialization");
+        *self.shared.session_id.lock() = None;
+    }
+
+    fn statistics(&self) -> TransportStatistics {
+        self.shared.stats.lock().clone()
+    }
+}
+
+impl Drop for WebSocketTransport {
+    fn drop(&mut self) {
+        self.shared.connected.store(false, Ordering::SeqCst);
+        if let Some(handle) = self.connection_handle.lock().take() {
+            handle.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_websocket_transport_creation() {
+        let transport = WebSocketTransport::new("ws://localhost:8080/mcp").unwrap();
+        assert_eq!(transport.transport_type(), TransportType::WebSocket);
+        assert!(!transport.is_connected());
+
+        assert!(WebSocketTransport::new("wss://example.com/mcp").is_ok());
+        assert!(WebSocketTransport::new("http://localhost:8080/mcp").is_err());
+    }
+
+    #[test]
+    fn test_frame_routing_strips_event_id() {
+        let transport = WebSocketTransport::new("ws://localhost:8080/mcp").unwrap();
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        *transport.shared.event_sender.lock() = Some(sender);
+
+        transport.shared.handle_text(
+            r#"{"jsonrpc":"2.0","method":"notifications/progress","params":{},"eventId":"42"}"#,
+        );
+        let Ok(ServerEvent::Notification(json)) = receiver.try_recv() else {
+            panic!("Expected notification event");
+        };
+        assert!(json.get("eventId").is_none());
+        assert_eq!(transport.shared.last_event_id.lock().as_deref(), Some("42"));
+
+        transport
+            .shared
+            .handle_text(r#"{"jsonrpc":"2.0","id":7,"method":"sampling/createMessage"}"#);
+        assert!(matches!(receiver.try_recv(), Ok(ServerEvent::Request(_))));
+
+        // A response nobody is waiting for surfaces as an event
+        transport
+            .shared
+            .handle_text(r#"{"jsonrpc":"2.0","id":"req_9","result":{}}"#);
+        assert!(matches!(receiver.try_recv(), Ok(ServerEvent::Response(_))));
+    }
+}
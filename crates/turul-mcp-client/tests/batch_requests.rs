@@ -0,0 +1,171 @@
+//! `McpClient::batch` over the HTTP transport.
+//!
+//! A wiremock responder plays the server: it answers `initialize`, accepts
+//! notifications, and, like the streamable HTTP server, only takes batches
+//! from clients that accept NDJSON. Batch responses are written one per line
+//! in reverse order so the client has to match them back to requests by id.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde_json::{Value, json};
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+use turul_mcp_client::transport::HttpTransport;
+use turul_mcp_client::{BatchItem, McpClient, McpClientBuilder, McpClientError};
+
+struct BatchServer {
+    /// Number of array bodies received
+    batches: Arc<AtomicUsize>,
+    /// Reject every batch with a single error object
+    reject_batches: bool,
+}
+
+impl BatchServer {
+    fn answer(request: &Value) -> Option<Value> {
+        let id = request.get("id")?.clone();
+        let response = match request["method"].as_str() {
+            Some("tools/call") => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "result": { "tool": request["params"]["name"] }
+            }),
+            _ => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": -32601, "message": "Method not found" }
+            }),
+        };
+        Some(response)
+    }
+}
+
+impl Respond for BatchServer {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body: Value = serde_json::from_slice(&request.body).unwrap();
+
+        if let Value::Array(items) = body {
+            self.batches.fetch_add(1, Ordering::SeqCst);
+            let accepts_ndjson = request
+                .headers
+                .get("accept")
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|accept| accept.contains("application/x-ndjson"));
+            if self.reject_batches || !accepts_ndjson {
+                return ResponseTemplate::new(200).set_body_json(json!({
+                    "jsonrpc": "2.0",
+                    "id": null,
+                    "error": { "code": -32600, "message": "Batch requests are not supported" }
+                }));
+            }
+            let mut responses: Vec<Value> = items.iter().filter_map(Self::answer).collect();
+            if responses.is_empty() {
+                return ResponseTemplate::new(202);
+            }
+            responses.reverse();
+            let lines: String = responses
+                .iter()
+                .map(|response| format!("{}\n", response))
+                .collect();
+            return ResponseTemplate::new(200)
+                .insert_header("Content-Type", "application/x-ndjson")
+                .set_body_string(lines);
+        }
+
+        match body["method"].as_str() {
+            Some("initialize") => ResponseTemplate::new(200)
+                .insert_header("Mcp-Session-Id", "batch-session")
+                .set_body_json(json!({
+                    "jsonrpc": "2.0",
+                    "id": body["id"],
+                    "result": {
+                        "protocolVersion": "2025-11-25",
+                        "capabilities": { "tools": { "listChanged": false } },
+                        "serverInfo": { "name": "batch-server", "version": "1.0.0" }
+                    }
+                })),
+            _ if body.get("id").is_none() => ResponseTemplate::new(202),
+            _ => ResponseTemplate::new(200).set_body_json(Self::answer(&body).unwrap()),
+        }
+    }
+}
+
+async fn connect(reject_batches: bool) -> (MockServer, McpClient, Arc<AtomicUsize>) {
+    let server = MockServer::start().await;
+    let batches = Arc::new(AtomicUsize::new(0));
+    Mock::given(method("POST"))
+        .respond_with(BatchServer {
+            batches: Arc::clone(&batches),
+            reject_batches,
+        })
+        .mount(&server)
+        .await;
+
+    let transport = HttpTransport::new(&format!("{}/mcp", server.uri())).unwrap();
+    let client = McpClientBuilder::new()
+        .with_transport(Box::new(transport))
+        .build();
+    client.connect().await.unwrap();
+    (server, client, batches)
+}
+
+#[tokio::test]
+async fn test_batch_demultiplexes_out_of_order_responses() {
+    let (_server, client, batches) = connect(false).await;
+
+    let results = client
+        .batch(vec![
+            BatchItem::call_tool("first", json!({})),
+            BatchItem::notification("notifications/roots/list_changed", json!({})),
+            BatchItem::request("unknown/method", json!({})),
+            BatchItem::call_tool("second", json!({"x": 1})),
+        ])
+        .await;
+
+    assert_eq!(batches.load(Ordering::SeqCst), 1, "one HTTP request");
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap()["tool"], "first");
+    assert_eq!(results[1].as_ref().unwrap(), &Value::Null);
+    assert!(matches!(
+        results[2],
+        Err(McpClientError::ServerError { code: -32601, .. })
+    ));
+    assert_eq!(results[3].as_ref().unwrap()["tool"], "second");
+}
+
+#[tokio::test]
+async fn test_batch_of_notifications_expects_no_responses() {
+    let (_server, client, batches) = connect(false).await;
+
+    let results = client
+        .batch(vec![
+            BatchItem::notification("notifications/roots/list_changed", json!({})),
+            BatchItem::notification("notifications/roots/list_changed", json!({})),
+        ])
+        .await;
+
+    assert_eq!(batches.load(Ordering::SeqCst), 1);
+    assert!(results.iter().all(|r| matches!(r, Ok(Value::Null))));
+}
+
+#[tokio::test]
+async fn test_batch_rejected_with_single_error_object() {
+    let (_server, client, _batches) = connect(true).await;
+
+    let results = client
+        .batch(vec![
+            BatchItem::call_tool("first", json!({})),
+            BatchItem::call_tool("second", json!({})),
+        ])
+        .await;
+
+    assert_eq!(results.len(), 2);
+    for result in results {
+        let Err(McpClientError::ServerError { code, message, .. }) = result else {
+            panic!("Expected batch rejection, got {:?}", result);
+        };
+        assert_eq!(code, -32600);
+        assert_eq!(message, "Batch requests are not supported");
+    }
+}
@@ -0,0 +1,131 @@
+//! Regression test: sequential requests through one transport reuse a pooled connection.
+//!
+//! `HttpTransport` builds its `reqwest::Client` once, so the keep-alive connection
+//! opened by the first request serves every later one. A client built per request
+//! would open a new TCP connection (and on HTTPS a new TLS handshake) each time,
+//! which dominates cold-path latency against backends like API Gateway.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use turul_mcp_client::transport::Transport;
+use turul_mcp_client::transport::http::HttpTransport;
+
+const SEQUENTIAL_CALLS: usize = 5;
+
+/// Minimal HTTP/1.1 keep-alive server that counts accepted TCP connections
+async fn start_counting_server() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    let counter = Arc::clone(&connections);
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            counter.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(serve_connection(stream));
+        }
+    });
+
+    (format!("http://{}/mcp", addr), connections)
+}
+
+/// Answer every request on the connection until the client closes it
+async fn serve_connection(mut stream: TcpStream) {
+    let mut buf = Vec::new();
+    loop {
+        // Read the request head, then the Content-Length body
+        let head_end = loop {
+            if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                break pos + 4;
+            }
+            let mut chunk = [0u8; 4096];
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+            }
+        };
+        let head = String::from_utf8_lossy(&buf[..head_end]).to_ascii_lowercase();
+        let content_length: usize = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .map(|value| value.trim().parse().unwrap())
+            .unwrap_or(0);
+        while buf.len() < head_end + content_length {
+            let mut chunk = [0u8; 4096];
+            match stream.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+            }
+        }
+        let request: serde_json::Value =
+            serde_json::from_slice(&buf[head_end..head_end + content_length]).unwrap();
+        buf.drain(..head_end + content_length);
+
+        let body =
+            serde_json::json!({ "jsonrpc": "2.0", "id": request["id"], "result": {} }).to_string();
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        if stream.write_all(response.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sequential_requests_reuse_one_connection() {
+    let (url, connections) = start_counting_server().await;
+
+    let transport = HttpTransport::new(&url).unwrap();
+    transport.connect().await.unwrap();
+
+    for i in 0..SEQUENTIAL_CALLS {
+        let response = transport
+            .send_request(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": format!("req_{}", i),
+                "method": "ping",
+                "params": {}
+            }))
+            .await
+            .unwrap();
+        assert_eq!(response["id"], format!("req_{}", i));
+    }
+
+    assert_eq!(
+        connections.load(Ordering::SeqCst),
+        1,
+        "{} sequential requests should share one pooled TCP connection",
+        SEQUENTIAL_CALLS
+    );
+}
+
+#[tokio::test]
+async fn test_client_config_transport_reuses_connection() {
+    let (url, connections) = start_counting_server().await;
+
+    let config = turul_mcp_client::config::ClientConfig::default();
+    let transport = HttpTransport::with_client_config(&url, &config).unwrap();
+    transport.connect().await.unwrap();
+
+    for i in 0..SEQUENTIAL_CALLS {
+        transport
+            .send_request(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": format!("req_{}", i),
+                "method": "ping",
+                "params": {}
+            }))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,193 @@
+//! SSE transport reconnection against a server that is killed and restarted.
+//!
+//! The server keeps its event log outside the listener task, like a server
+//! backed by persistent event storage, and replays everything after the
+//! `Last-Event-ID` a client reconnects with.
+
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::{Value, json};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Notify;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio::time::{sleep, timeout};
+
+use turul_mcp_client::RetryConfig;
+use turul_mcp_client::transport::{EventReceiver, ServerEvent, SseTransport, Transport};
+
+/// Event storage that survives server restarts
+#[derive(Default)]
+struct EventLog {
+    events: Mutex<Vec<Value>>,
+    appended: Notify,
+    /// `Last-Event-ID` header of each GET, in order
+    resumed_from: Mutex<Vec<Option<String>>>,
+}
+
+impl EventLog {
+    fn publish(&self, n: u64) {
+        self.events.lock().unwrap().push(json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/message",
+            "params": { "level": "info", "data": n }
+        }));
+        self.appended.notify_waiters();
+    }
+}
+
+/// Serve the event stream until the task is aborted, which drops every connection
+fn start_server(listener: TcpListener, log: Arc<EventLog>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut connections = JoinSet::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            connections.spawn(serve_stream(socket, Arc::clone(&log)));
+        }
+    })
+}
+
+async fn serve_stream(mut socket: TcpStream, log: Arc<EventLog>) {
+    let mut head = Vec::new();
+    let mut byte = [0u8; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        if socket.read(&mut byte).await.unwrap_or(0) == 0 {
+            return;
+        }
+        head.push(byte[0]);
+    }
+    let last_event_id = String::from_utf8_lossy(&head).lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        name.eq_ignore_ascii_case("last-event-id")
+            .then(|| value.trim().to_string())
+    });
+    log.resumed_from.lock().unwrap().push(last_event_id.clone());
+
+    let headers =
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nTransfer-Encoding: chunked\r\n\r\n";
+    if socket.write_all(headers.as_bytes()).await.is_err() {
+        return;
+    }
+
+    // Event ids are 1-based positions in the log
+    let mut next = last_event_id.and_then(|id| id.parse().ok()).unwrap_or(0);
+    loop {
+        let appended = log.appended.notified();
+        let pending: Vec<Value> = log.events.lock().unwrap()[next..].to_vec();
+        for event in pending {
+            next += 1;
+            let frame = format!("id: {}\nevent: notification\ndata: {}\n\n", next, event);
+            let chunk = format!("{:x}\r\n{}\r\n", frame.len(), frame);
+            if socket.write_all(chunk.as_bytes()).await.is_err() {
+                return;
+            }
+        }
+        appended.await;
+    }
+}
+
+fn retry_config(max_attempts: u32) -> RetryConfig {
+    RetryConfig {
+        max_attempts,
+        initial_delay: Duration::from_millis(50),
+        max_delay: Duration::from_millis(200),
+        backoff_multiplier: 2.0,
+        jitter: 0.0,
+        exponential_backoff: true,
+    }
+}
+
+async fn connect_transport(addr: SocketAddr, retry: RetryConfig) -> (SseTransport, EventReceiver) {
+    let transport = SseTransport::with_endpoints(
+        &format!("http://{}/mcp", addr),
+        &format!("http://{}/mcp/sse", addr),
+    )
+    .unwrap()
+    .with_retry_config(retry);
+    transport.connect().await.unwrap();
+    let events = transport.start_event_listener().await.unwrap();
+    (transport, events)
+}
+
+/// Receive the next event, failing the test on anything but a notification
+async fn next_notification(events: &mut EventReceiver) -> u64 {
+    let event = timeout(Duration::from_secs(5), events.recv())
+        .await
+        .expect("timed out waiting for a notification")
+        .expect("event stream ended");
+    let ServerEvent::Notification(notification) = event else {
+        panic!("consumer should only see notifications, got {:?}", event);
+    };
+    notification["params"]["data"].as_u64().unwrap()
+}
+
+#[tokio::test]
+async fn test_no_events_lost_across_server_restart() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let log = Arc::new(EventLog::default());
+    let server = start_server(listener, Arc::clone(&log));
+
+    let (_transport, mut events) = connect_transport(addr, retry_config(10)).await;
+    for n in 1..=3 {
+        log.publish(n);
+    }
+    for n in 1..=3 {
+        assert_eq!(next_notification(&mut events).await, n);
+    }
+
+    // Kill the server mid-stream; events keep being produced while it is down
+    server.abort();
+    let _ = server.await;
+    log.publish(4);
+    log.publish(5);
+    sleep(Duration::from_millis(300)).await;
+
+    let server = start_server(TcpListener::bind(addr).await.unwrap(), Arc::clone(&log));
+    for n in 4..=5 {
+        assert_eq!(next_notification(&mut events).await, n);
+    }
+    log.publish(6);
+    assert_eq!(next_notification(&mut events).await, 6);
+
+    let resumed_from = log.resumed_from.lock().unwrap().clone();
+    assert_eq!(resumed_from.first(), Some(&None));
+    assert_eq!(resumed_from.last(), Some(&Some("3".to_string())));
+    assert!(events.try_recv().is_err(), "no duplicates or extra events");
+    server.abort();
+}
+
+#[tokio::test]
+async fn test_connection_lost_after_retry_budget_exhausted() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let log = Arc::new(EventLog::default());
+    let server = start_server(listener, Arc::clone(&log));
+
+    let (_transport, mut events) = connect_transport(addr, retry_config(2)).await;
+    log.publish(1);
+    assert_eq!(next_notification(&mut events).await, 1);
+
+    // The server never comes back
+    server.abort();
+    let _ = server.await;
+
+    let mut seen = Vec::new();
+    timeout(Duration::from_secs(5), async {
+        while let Some(event) = events.recv().await {
+            let lost = matches!(event, ServerEvent::ConnectionLost);
+            seen.push(event);
+            if lost {
+                break;
+            }
+        }
+    })
+    .await
+    .expect("connection should be reported lost once retries are exhausted");
+
+    assert!(matches!(
+        seen.as_slice(),
+        [ServerEvent::Error(_), ServerEvent::ConnectionLost]
+    ));
+}
@@ -0,0 +1,148 @@
+//! Stdio transport against a subprocess MCP server.
+//!
+//! The "server" is a few lines of POSIX shell that answer `initialize`, emit a
+//! notification once initialized, and echo the method name of any other
+//! request back as its result.
+
+#![cfg(all(feature = "stdio", unix))]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::json;
+use tokio::time::{sleep, timeout};
+
+use turul_mcp_client::McpClientBuilder;
+use turul_mcp_client::transport::{StdioTransport, Transport};
+
+const ECHO_SERVER: &str = r#"
+while IFS= read -r line; do
+  echo "received $line" >&2
+  id=$(printf '%s' "$line" | sed -n 's/.*"id":\([^,}]*\).*/\1/p')
+  method=$(printf '%s' "$line" | sed -n 's/.*"method":"\([^"]*\)".*/\1/p')
+  case "$method" in
+    initialize)
+      printf '{"jsonrpc":"2.0","id":%s,"result":{"protocolVersion":"2025-11-25","capabilities":{"tools":{"listChanged":false}},"serverInfo":{"name":"stdio-echo","version":"1.0.0"}}}\n' "$id" ;;
+    notifications/initialized)
+      printf '{"jsonrpc":"2.0","method":"notifications/message","params":{"level":"info","data":"ready"}}\n' ;;
+    *)
+      if [ -n "$id" ]; then
+        printf '{"jsonrpc":"2.0","id":%s,"result":{"echo":"%s"}}\n' "$id" "$method"
+      fi ;;
+  esac
+done
+"#;
+
+/// Ignores SIGTERM and never reads stdin, so closing stdin does not stop it
+const STUBBORN_SERVER: &str = "trap '' TERM; while :; do sleep 1; done";
+
+fn echo_server() -> StdioTransport {
+    StdioTransport::new("sh").with_args(["-c", ECHO_SERVER])
+}
+
+fn process_alive(pid: u32) -> bool {
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+#[tokio::test]
+async fn test_initialize_round_trip_through_client() {
+    let notifications = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&notifications);
+    let client = McpClientBuilder::new()
+        .with_transport(Box::new(echo_server()))
+        .on_notification(move |method, _params| {
+            recorded.lock().unwrap().push(method.to_string());
+        })
+        .build();
+
+    client.connect().await.unwrap();
+    assert!(client.is_ready().await);
+
+    let info = client.session_info().await;
+    assert_eq!(info.protocol_version.as_deref(), Some("2025-11-25"));
+    assert!(
+        info.session_id.is_none(),
+        "stdio sessions carry no session ID"
+    );
+    assert!(info.server_capabilities.unwrap().tools.is_some());
+
+    client.ping().await.unwrap();
+
+    timeout(Duration::from_secs(2), async {
+        while !notifications
+            .lock()
+            .unwrap()
+            .contains(&"notifications/message".to_string())
+        {
+            sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("notification from stdout should reach the client");
+
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_request_response_and_clean_exit() {
+    let transport = echo_server();
+    transport.connect().await.unwrap();
+    let pid = transport.process_id().unwrap();
+
+    let (a, b) = tokio::join!(
+        transport.send_request(json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list"})),
+        transport.send_request(json!({"jsonrpc": "2.0", "id": "b", "method": "prompts/list"})),
+    );
+    assert_eq!(a.unwrap()["result"]["echo"], "tools/list");
+    assert_eq!(b.unwrap()["result"]["echo"], "prompts/list");
+
+    // Closing stdin ends the read loop, so the server exits without being killed
+    let started = std::time::Instant::now();
+    transport.disconnect().await.unwrap();
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert!(!process_alive(pid));
+    assert!(transport.process_id().is_none());
+    assert!(
+        transport
+            .send_request(json!({"jsonrpc": "2.0", "id": 2, "method": "ping"}))
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_disconnect_kills_server_that_does_not_exit() {
+    let transport = StdioTransport::new("sh")
+        .with_args(["-c", STUBBORN_SERVER])
+        .with_shutdown_timeout(Duration::from_millis(200));
+    transport.connect().await.unwrap();
+    let pid = transport.process_id().unwrap();
+    assert!(process_alive(pid));
+
+    transport.disconnect().await.unwrap();
+    assert!(!process_alive(pid));
+}
+
+#[tokio::test]
+async fn test_drop_kills_server_that_does_not_exit() {
+    let transport = StdioTransport::new("sh")
+        .with_args(["-c", STUBBORN_SERVER])
+        .with_shutdown_timeout(Duration::from_millis(200));
+    transport.connect().await.unwrap();
+    let pid = transport.process_id().unwrap();
+
+    drop(transport);
+
+    timeout(Duration::from_secs(2), async {
+        while process_alive(pid) {
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("dropped transport should kill the server");
+}
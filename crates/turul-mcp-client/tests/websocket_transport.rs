@@ -0,0 +1,231 @@
+//! WebSocket transport against a local echo server.
+//!
+//! The server answers every request with its params, emits a tagged
+//! notification for `emit`, and drops the connection for `drop`, which
+//! exercises reconnection and `Last-Event-ID` resume.
+
+#![cfg(feature = "websocket")]
+// The handshake callback's error type is fixed by tungstenite
+#![allow(clippy::result_large_err)]
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use tokio::net::TcpListener;
+use tokio::time::timeout;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::handshake::server::{ErrorResponse, Request, Response};
+
+use turul_mcp_client::transport::{ServerEvent, Transport, WebSocketTransport};
+
+/// Headers of each handshake the server accepted, in order
+type Handshakes = Arc<Mutex<Vec<HashMap<String, String>>>>;
+
+async fn start_echo_server() -> (String, Handshakes) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}/mcp", listener.local_addr().unwrap());
+    let handshakes: Handshakes = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded = Arc::clone(&handshakes);
+    tokio::spawn(async move {
+        let mut event_counter = 0;
+        while let Ok((socket, _)) = listener.accept().await {
+            let recorded = Arc::clone(&recorded);
+            let callback = move |request: &Request, response: Response| {
+                record_handshake(&recorded, request, response)
+            };
+            let mut ws = tokio_tungstenite::accept_hdr_async(socket, callback)
+                .await
+                .unwrap();
+
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: Value = serde_json::from_str(text.as_str()).unwrap();
+                let Some(id) = request.get("id").cloned() else {
+                    continue; // notification from the client
+                };
+                match request["method"].as_str() {
+                    Some("drop") => break,
+                    Some("emit") => {
+                        event_counter += 1;
+                        let notification = json!({
+                            "jsonrpc": "2.0",
+                            "method": "notifications/message",
+                            "params": { "level": "info", "data": "emitted" },
+                            "eventId": format!("e-{}", event_counter)
+                        });
+                        ws.send(Message::text(notification.to_string()))
+                            .await
+                            .unwrap();
+                    }
+                    _ => {}
+                }
+                let response = json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "result": { "echo": request.get("params").cloned().unwrap_or(Value::Null) }
+                });
+                ws.send(Message::text(response.to_string())).await.unwrap();
+            }
+        }
+    });
+
+    (url, handshakes)
+}
+
+/// Records the handshake headers and assigns a session ID
+fn record_handshake(
+    recorded: &Handshakes,
+    request: &Request,
+    mut response: Response,
+) -> Result<Response, ErrorResponse> {
+    let headers = request
+        .headers()
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_str().unwrap_or("").to_string()))
+        .collect();
+    recorded.lock().unwrap().push(headers);
+    response
+        .headers_mut()
+        .insert("Mcp-Session-Id", "ws-session-1".parse().unwrap());
+    Ok(response)
+}
+
+fn request(id: &str, method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+}
+
+#[tokio::test]
+async fn test_request_response_and_notifications() {
+    let (url, handshakes) = start_echo_server().await;
+    let transport = WebSocketTransport::new(&url).unwrap();
+    transport.connect().await.unwrap();
+    let mut events = transport.start_event_listener().await.unwrap();
+
+    // Session ID from the handshake response is surfaced as a header
+    let response = transport
+        .send_request_with_headers(request("1", "initialize", json!({})))
+        .await
+        .unwrap();
+    assert_eq!(response.body["id"], "1");
+    assert_eq!(
+        response.headers.get("mcp-session-id").map(String::as_str),
+        Some("ws-session-1")
+    );
+    assert_eq!(
+        handshakes.lock().unwrap()[0]
+            .get("mcp-protocol-version")
+            .map(String::as_str),
+        Some("2025-11-25")
+    );
+
+    // Concurrent requests are matched to their responses by id
+    let (a, b) = tokio::join!(
+        transport.send_request(request("a", "tools/call", json!({"n": 1}))),
+        transport.send_request(request("b", "tools/call", json!({"n": 2}))),
+    );
+    assert_eq!(a.unwrap()["result"]["echo"]["n"], 1);
+    assert_eq!(b.unwrap()["result"]["echo"]["n"], 2);
+
+    // Server notifications arrive on the event channel with eventId stripped
+    transport
+        .send_request(request("2", "emit", json!({})))
+        .await
+        .unwrap();
+    let event = timeout(Duration::from_secs(2), events.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    let ServerEvent::Notification(notification) = event else {
+        panic!("Expected notification, got {:?}", event);
+    };
+    assert_eq!(notification["method"], "notifications/message");
+    assert!(notification.get("eventId").is_none());
+
+    transport
+        .send_notification(json!({"jsonrpc": "2.0", "method": "notifications/initialized"}))
+        .await
+        .unwrap();
+
+    let stats = transport.statistics();
+    assert_eq!(stats.requests_sent, 4);
+    assert_eq!(stats.notifications_sent, 1);
+
+    transport.disconnect().await.unwrap();
+    assert!(!transport.is_connected());
+    assert!(
+        transport
+            .send_request(request("3", "ping", json!({})))
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_reconnects_and_resumes_from_last_event_id() {
+    let (url, handshakes) = start_echo_server().await;
+    let transport = WebSocketTransport::new(&url)
+        .unwrap()
+        .with_reconnect_delay(Duration::from_millis(100));
+    transport.connect().await.unwrap();
+    transport.set_session_id("ws-session-1".to_string());
+    let mut events = transport.start_event_listener().await.unwrap();
+
+    transport
+        .send_request(request("1", "emit", json!({})))
+        .await
+        .unwrap();
+
+    // The server drops the connection without answering
+    let dropped = transport
+        .send_request(request("2", "drop", json!({})))
+        .await;
+    assert!(
+        dropped.is_err(),
+        "in-flight request must fail on disconnect"
+    );
+
+    let lost = timeout(Duration::from_secs(2), async {
+        loop {
+            match events.recv().await {
+                Some(ServerEvent::ConnectionLost) => return true,
+                Some(_) => continue,
+                None => return false,
+            }
+        }
+    })
+    .await
+    .unwrap();
+    assert!(lost);
+
+    // Once reconnected, requests flow again
+    let response = timeout(Duration::from_secs(5), async {
+        loop {
+            if let Ok(response) = transport
+                .send_request(request("3", "ping", json!({})))
+                .await
+            {
+                return response;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("transport should reconnect");
+    assert_eq!(response["id"], "3");
+    assert!(transport.is_connected());
+
+    let handshakes = handshakes.lock().unwrap();
+    assert_eq!(handshakes.len(), 2);
+    assert!(!handshakes[0].contains_key("last-event-id"));
+    assert_eq!(
+        handshakes[1].get("last-event-id").map(String::as_str),
+        Some("e-1")
+    );
+    assert_eq!(
+        handshakes[1].get("mcp-session-id").map(String::as_str),
+        Some("ws-session-1")
+    );
+}
@@ -1,6 +1,9 @@
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Type};
+use syn::{Attribute, Data, DataEnum, DeriveInput, Fields, LitStr, Type};
+
+use crate::utils::scalar_kind;
 
 /// Generate a JsonSchema derive macro that introspects struct fields
 pub fn derive_json_schema(input: DeriveInput) -> TokenStream {
@@ -8,22 +11,243 @@ pub fn derive_json_schema(input: DeriveInput) -> TokenStream {
 
     match input.data {
         Data::Struct(data_struct) => {
-            let schema_impl = generate_struct_schema(name, &data_struct.fields);
+            let fields = match generate_struct_schema(name, &data_struct.fields) {
+                Ok(fields) => fields,
+                Err(err) => return err.to_compile_error(),
+            };
+            let def_name = name.to_string();
 
+            // Nested derived structs are shared under `$defs` and referenced with
+            // `$ref`; ToJsonSchema lets the struct nest inside other schemas and be
+            // used directly as a tool parameter type
             quote! {
                 impl turul_mcp_protocol::schema::JsonSchemaGenerator for #name {
                     fn json_schema() -> turul_mcp_protocol::ToolSchema {
+                        let mut definitions = turul_mcp_protocol::schema::SchemaDefinitions::new();
+                        let defs = &mut definitions;
+                        let (properties, required) = #fields;
+                        turul_mcp_protocol::ToolSchema {
+                            schema_type: "object".to_string(),
+                            properties,
+                            required,
+                            additional: std::collections::HashMap::new(),
+                        }
+                        .with_definitions(definitions)
+                    }
+                }
+
+                impl turul_mcp_protocol::schema::ToJsonSchema for #name {
+                    fn to_json_schema() -> turul_mcp_protocol::schema::JsonSchema {
+                        let mut definitions = turul_mcp_protocol::schema::SchemaDefinitions::new();
+                        let defs = &mut definitions;
+                        let (properties, required) = #fields;
+                        turul_mcp_protocol::schema::JsonSchema::Object {
+                            title: None,
+                            description: None,
+                            properties,
+                            required,
+                            additional_properties: None,
+                        }
+                        .inline_definitions(&definitions)
+                    }
+
+                    fn nested_schema(
+                        defs: &mut turul_mcp_protocol::schema::SchemaDefinitions,
+                    ) -> turul_mcp_protocol::schema::JsonSchema {
+                        if !defs.contains_key(#def_name) {
+                            // Placeholder first, so fields of this type refer back
+                            // instead of expanding forever
+                            defs.insert(
+                                #def_name.to_string(),
+                                turul_mcp_protocol::schema::JsonSchema::object(),
+                            );
+                            let (properties, required) = #fields;
+                            defs.insert(
+                                #def_name.to_string(),
+                                turul_mcp_protocol::schema::JsonSchema::Object {
+                                    title: None,
+                                    description: None,
+                                    properties,
+                                    required,
+                                    additional_properties: None,
+                                },
+                            );
+                        }
+                        turul_mcp_protocol::schema::JsonSchema::reference(#def_name)
+                    }
+                }
+            }
+        }
+        Data::Enum(data_enum) => match generate_enum_schema(&input.attrs, &data_enum) {
+            Ok(schema_impl) => quote! {
+                impl turul_mcp_protocol::schema::ToJsonSchema for #name {
+                    fn to_json_schema() -> turul_mcp_protocol::schema::JsonSchema {
                         #schema_impl
                     }
                 }
+            },
+            Err(err) => err.to_compile_error(),
+        },
+        Data::Union(_) => {
+            syn::Error::new_spanned(name, "JsonSchema can only be derived for structs and enums")
+                .to_compile_error()
+        }
+    }
+}
+
+/// Fieldless enums become a string schema listing the serialized variant names,
+/// honouring `#[serde(rename_all = "...")]`, `#[serde(rename = "...")]` and
+/// `#[serde(skip)]` so the schema matches what serde accepts.
+fn generate_enum_schema(attrs: &[Attribute], data_enum: &DataEnum) -> syn::Result<TokenStream> {
+    let mut rename_all = None;
+    for_each_serde_item(attrs, |meta| {
+        if meta.path.is_ident("rename_all") {
+            let mut rule = None;
+            if meta.input.peek(syn::Token![=]) {
+                rule = Some(meta.value()?.parse::<LitStr>()?);
+            } else {
+                // rename_all(serialize = "...", deserialize = "...")
+                meta.parse_nested_meta(|inner| {
+                    let lit: LitStr = inner.value()?.parse()?;
+                    if inner.path.is_ident("deserialize") {
+                        rule = Some(lit);
+                    }
+                    Ok(())
+                })?;
+            }
+            if let Some(lit) = rule {
+                if case_rule(&lit.value(), "Probe").is_none() {
+                    return Err(syn::Error::new_spanned(
+                        &lit,
+                        format!("unsupported rename_all rule \"{}\"", lit.value()),
+                    ));
+                }
+                rename_all = Some(lit.value());
+            }
+            Ok(())
+        } else {
+            // Other serde container attributes don't affect variant names
+            skip_serde_value(&meta)
+        }
+    })?;
+
+    let mut values = Vec::new();
+    for variant in &data_enum.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!(
+                    "JsonSchema can only be derived for enums with unit variants; \
+                     variant `{}` carries data",
+                    variant.ident
+                ),
+            ));
+        }
+
+        let mut rename = None;
+        let mut skipped = false;
+        for_each_serde_item(&variant.attrs, |meta| {
+            if meta.path.is_ident("rename") && meta.input.peek(syn::Token![=]) {
+                let lit: LitStr = meta.value()?.parse()?;
+                rename = Some(lit.value());
+            } else if meta.path.is_ident("rename") {
+                // rename(serialize = "...", deserialize = "...")
+                meta.parse_nested_meta(|inner| {
+                    let lit: LitStr = inner.value()?.parse()?;
+                    if inner.path.is_ident("deserialize") {
+                        rename = Some(lit.value());
+                    }
+                    Ok(())
+                })?;
+            } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+                skipped = true;
+            } else {
+                skip_serde_value(&meta)?;
             }
+            Ok(())
+        })?;
+        if skipped {
+            continue;
         }
-        _ => syn::Error::new_spanned(name, "JsonSchema can only be derived for structs")
-            .to_compile_error(),
+
+        let variant_name = variant.ident.to_string();
+        let value = match (rename, &rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => case_rule(rule, &variant_name).expect("rule validated above"),
+            (None, None) => variant_name,
+        };
+        values.push(value);
+    }
+
+    if values.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "JsonSchema requires at least one (non-skipped) enum variant",
+        ));
+    }
+
+    Ok(quote! {
+        turul_mcp_protocol::schema::JsonSchema::string_enum(vec![#(#values.to_string()),*])
+    })
+}
+
+/// Visit every `key` / `key = value` item inside `#[serde(...)]` attributes.
+fn for_each_serde_item(
+    attrs: &[Attribute],
+    mut visit: impl FnMut(syn::meta::ParseNestedMeta) -> syn::Result<()>,
+) -> syn::Result<()> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(&mut visit)?;
+    }
+    Ok(())
+}
+
+/// Consume the `= value` or `(...)` part of a serde item we don't interpret.
+fn skip_serde_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        let _: syn::Expr = meta.value()?.parse()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        let _content;
+        syn::parenthesized!(_content in meta.input);
     }
+    Ok(())
 }
 
-fn generate_struct_schema(struct_name: &syn::Ident, fields: &Fields) -> TokenStream {
+/// Apply a serde `rename_all` rule to a PascalCase variant name.
+fn case_rule(rule: &str, variant: &str) -> Option<String> {
+    let snake = || {
+        let mut out = String::new();
+        for (i, ch) in variant.char_indices() {
+            if i > 0 && ch.is_uppercase() {
+                out.push('_');
+            }
+            out.push(ch.to_ascii_lowercase());
+        }
+        out
+    };
+    Some(match rule {
+        "lowercase" => variant.to_ascii_lowercase(),
+        "UPPERCASE" => variant.to_ascii_uppercase(),
+        "PascalCase" => variant.to_string(),
+        "camelCase" => {
+            let mut chars = variant.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        "snake_case" => snake(),
+        "SCREAMING_SNAKE_CASE" => snake().to_ascii_uppercase(),
+        "kebab-case" => snake().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake().replace('_', "-").to_ascii_uppercase(),
+        _ => return None,
+    })
+}
+
+/// Properties and required names of a struct, as a block evaluating to
+/// `(Option<HashMap<String, JsonSchema>>, Option<Vec<String>>)` that registers
+/// nested definitions in the `defs: &mut SchemaDefinitions` in scope.
+fn generate_struct_schema(struct_name: &syn::Ident, fields: &Fields) -> syn::Result<TokenStream> {
     let _schema_comment = format!("Schema for {}", struct_name);
     match fields {
         Fields::Named(fields_named) => {
@@ -33,7 +257,9 @@ fn generate_struct_schema(struct_name: &syn::Ident, fields: &Fields) -> TokenStr
             for field in &fields_named.named {
                 if let Some(field_name) = &field.ident {
                     let field_name_str = field_name.to_string();
+                    let constraints = field_constraints(field)?;
                     let field_schema = generate_field_schema(&field.ty);
+                    let field_schema = quote! { #field_schema #constraints };
 
                     properties.push(quote! {
                         (#field_name_str.to_string(), #field_schema)
@@ -46,32 +272,120 @@ fn generate_struct_schema(struct_name: &syn::Ident, fields: &Fields) -> TokenStr
                 }
             }
 
-            quote! {
+            Ok(quote! {
                 {
                     use std::collections::HashMap;
                     use turul_mcp_protocol::schema::JsonSchema;
 
                     // Generate schema for struct #struct_name
-                    let mut properties = HashMap::new();
+                    let _ = &defs;
+                    let mut properties: HashMap<String, JsonSchema> = HashMap::new();
                     #(
-                        properties.insert(#properties.0, #properties.1);
+                        let (name, schema) = #properties;
+                        properties.insert(name, schema);
                     )*
 
-                    turul_mcp_protocol::ToolSchema {
-                        schema_type: "object".to_string(),
-                        properties: Some(properties),
-                        required: Some(vec![#(#required_fields),*]),
-                        additional: HashMap::new(),
-                    }
+                    (Some(properties), Some(vec![#(#required_fields),*]))
                 }
-            }
+            })
         }
-        _ => {
-            quote! {
-                turul_mcp_protocol::ToolSchema::object()
+        _ => Ok(quote! {
+            {
+                let _ = &defs;
+                (None, None)
             }
-        }
+        }),
+    }
+}
+
+/// Builder calls for a field's `#[schema(minimum = .., maximum = .., min_length = ..,
+/// max_length = ..)]` constraints, rejecting keywords that don't fit its type.
+fn field_constraints(field: &syn::Field) -> syn::Result<TokenStream> {
+    let kind = scalar_kind(&field.ty);
+    let mut calls = Vec::new();
+    let mut minimum = None;
+    let mut maximum = None;
+    let mut min_length = None;
+    let mut max_length = None;
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("schema")) {
+        attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            match key.as_str() {
+                "minimum" | "maximum" => {
+                    if !matches!(kind, Some("number" | "integer")) {
+                        return Err(meta.error(format!("`{}` only applies to numeric fields", key)));
+                    }
+                    let value = parse_number(&meta)?;
+                    if kind == Some("integer") && value.fract() != 0.0 {
+                        return Err(meta.error(format!("`{}` must be a whole number", key)));
+                    }
+                    if key == "minimum" {
+                        minimum = Some(value);
+                        calls.push(quote! { .with_minimum(#value) });
+                    } else {
+                        maximum = Some(value);
+                        calls.push(quote! { .with_maximum(#value) });
+                    }
+                }
+                "min_length" | "max_length" => {
+                    if kind != Some("string") {
+                        return Err(meta.error(format!("`{}` only applies to String fields", key)));
+                    }
+                    let value: u64 = meta.value()?.parse::<syn::LitInt>()?.base10_parse()?;
+                    if key == "min_length" {
+                        min_length = Some(value);
+                        calls.push(quote! { .with_min_length(#value) });
+                    } else {
+                        max_length = Some(value);
+                        calls.push(quote! { .with_max_length(#value) });
+                    }
+                }
+                _ => {
+                    return Err(meta.error(
+                        "unsupported schema attribute; expected `minimum`, `maximum`, \
+                         `min_length` or `max_length`",
+                    ));
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    if let (Some(min), Some(max)) = (minimum, maximum)
+        && min > max
+    {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`minimum` is greater than `maximum`",
+        ));
     }
+    if let (Some(min), Some(max)) = (min_length, max_length)
+        && min > max
+    {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`min_length` is greater than `max_length`",
+        ));
+    }
+
+    Ok(quote! { #(#calls)* })
+}
+
+/// Parse a possibly negative integer or float literal, e.g. `minimum = -1.5`
+fn parse_number(meta: &syn::meta::ParseNestedMeta) -> syn::Result<f64> {
+    let input = meta.value()?;
+    let negative = input.parse::<Option<syn::Token![-]>>()?.is_some();
+    let value = match input.parse::<syn::Lit>()? {
+        syn::Lit::Int(lit) => lit.base10_parse::<f64>()?,
+        syn::Lit::Float(lit) => lit.base10_parse::<f64>()?,
+        other => return Err(syn::Error::new_spanned(other, "expected a number")),
+    };
+    Ok(if negative { -value } else { value })
 }
 
 fn generate_field_schema(ty: &Type) -> TokenStream {
@@ -108,7 +422,19 @@ fn generate_field_schema(ty: &Type) -> TokenStream {
                     }
                     quote! { JsonSchema::array(JsonSchema::string()) }
                 }
-                _ => quote! { JsonSchema::object() },
+                // Nested derived types reuse their own schema; anything else is an object
+                _ => quote! {
+                    {
+                        #[allow(unused_imports)]
+                        use turul_mcp_protocol::schema::{
+                            NestedSchemaFallback as _, NestedSchemaFromTrait as _,
+                        };
+                        (&turul_mcp_protocol::schema::NestedSchemaProbe::<#ty>(
+                            std::marker::PhantomData,
+                        ))
+                            .nested_schema(&mut *defs)
+                    }
+                },
             }
         }
         Type::Reference(type_ref) => {
@@ -178,6 +504,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_struct_registers_shared_definition() {
+        let input: DeriveInput = parse_quote! {
+            struct Address {
+                street: String,
+            }
+        };
+
+        let code = derive_json_schema(input).to_string();
+        assert!(code.contains("fn nested_schema"));
+        assert!(code.contains("with_definitions"));
+        assert!(code.contains("inline_definitions"));
+        assert!(code.contains("reference (\"Address\")"), "got: {}", code);
+    }
+
     #[test]
     fn test_option_type_schemas() {
         // Option<String> → string
@@ -238,17 +579,49 @@ mod tests {
     }
 
     #[test]
-    fn test_enum_should_error() {
+    fn test_unit_enum_schema() {
+        let input: DeriveInput = parse_quote! {
+            #[serde(rename_all = "snake_case")]
+            enum Operation {
+                Add,
+                SquareRoot,
+                #[serde(rename = "minus")]
+                Subtract,
+                #[serde(skip)]
+                Internal,
+            }
+        };
+
+        let code = derive_json_schema(input).to_string();
+        assert!(code.contains("ToJsonSchema"));
+        assert!(code.contains("string_enum"));
+        assert!(code.contains("\"add\""));
+        assert!(code.contains("\"square_root\""));
+        assert!(code.contains("\"minus\""));
+        assert!(!code.contains("\"internal\""));
+    }
+
+    #[test]
+    fn test_data_carrying_enum_should_error() {
         let input: DeriveInput = parse_quote! {
             enum TestEnum {
                 Variant1,
-                Variant2,
+                Variant2(String),
             }
         };
 
-        let result = derive_json_schema(input);
-        // Should contain error message about enums not being supported
-        let result_string = result.to_string();
-        assert!(result_string.contains("JsonSchema can only be derived for structs"));
+        let result_string = derive_json_schema(input).to_string();
+        assert!(result_string.contains("variant `Variant2` carries data"));
+    }
+
+    #[test]
+    fn test_case_rules() {
+        assert_eq!(case_rule("camelCase", "SquareRoot").unwrap(), "squareRoot");
+        assert_eq!(
+            case_rule("SCREAMING-KEBAB-CASE", "SquareRoot").unwrap(),
+            "SQUARE-ROOT"
+        );
+        assert_eq!(case_rule("lowercase", "SquareRoot").unwrap(), "squareroot");
+        assert!(case_rule("Title Case", "SquareRoot").is_none());
     }
 }
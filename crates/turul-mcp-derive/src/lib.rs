@@ -110,6 +110,8 @@
 //! | `tool!` | Declarative tools | Runtime creation |
 //! | `resource!` | Declarative resources | Runtime creation |
 //! | `#[derive(JsonSchema)]` | Schema generation | Type validation |
+//! | `#[derive(McpToolError)]` | Error code mapping | Domain errors → JSON-RPC |
+//! | `#[derive(IntoMcpError)]` | Error code mapping | Same, with `#[mcp(code = ...)]` |
 //!
 //! ## Examples
 //!
@@ -144,6 +146,7 @@ mod roots_derive;
 mod sampling_derive;
 mod tool_attr;
 mod tool_derive;
+mod tool_error_derive;
 mod utils;
 
 #[cfg(test)]
@@ -261,6 +264,117 @@ pub fn derive_mcp_tool(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Derive macro mapping a domain error enum onto `McpError`
+///
+/// Generates `From<YourError> for McpError` so tool bodies can `?`-propagate
+/// domain errors that surface as the intended JSON-RPC error codes.
+///
+/// # Attributes
+///
+/// - `#[mcp_error(code = -32050)]` - JSON-RPC error code for the variant (required)
+/// - `message = "..."` - Message format string; fields are in scope by name
+///   (tuple fields as `_0`, `_1`, ...). Defaults to the variant's `Display` output.
+/// - `data = field` - Field serialized with `serde_json` into the error `data`
+///
+/// Variants without `#[mcp_error]` map to `McpError::ToolExecutionError`
+/// using the `Display` output. The enum must implement `Display` (e.g. via `thiserror`).
+/// Structs are supported too, with `#[mcp_error]` on the struct itself.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use turul_mcp_derive::McpToolError;
+/// use turul_mcp_protocol::McpResult;
+///
+/// #[derive(Debug, McpToolError)]
+/// enum InventoryError {
+///     #[mcp_error(code = -32050)]
+///     NotFound(String),
+///     #[mcp_error(code = -32051, message = "Only {available} of {sku} left", data = available)]
+///     OutOfStock { sku: String, available: u32 },
+///     Internal(String),
+/// }
+///
+/// impl std::fmt::Display for InventoryError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             Self::NotFound(sku) => write!(f, "Item {} not found", sku),
+///             Self::OutOfStock { sku, .. } => write!(f, "Out of stock: {}", sku),
+///             Self::Internal(msg) => write!(f, "{}", msg),
+///         }
+///     }
+/// }
+///
+/// fn reserve(sku: &str) -> Result<u32, InventoryError> {
+///     Err(InventoryError::NotFound(sku.to_string()))
+/// }
+///
+/// fn tool_body() -> McpResult<u32> {
+///     Ok(reserve("widget")?)
+/// }
+/// ```
+#[proc_macro_derive(McpToolError, attributes(mcp_error))]
+pub fn derive_mcp_tool_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tool_error_derive::derive_mcp_tool_error_impl(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derive macro mapping a domain error enum or struct onto `McpError`
+///
+/// The same conversion as [`McpToolError`](derive@McpToolError), configured with
+/// `#[mcp(code = ..., message = ..., data = ...)]` on each enum variant, or on the
+/// struct itself. Without a `message`, the `Display` output becomes the message;
+/// variants (or structs) without `#[mcp]` map to `McpError::ToolExecutionError`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use turul_mcp_derive::IntoMcpError;
+/// use turul_mcp_protocol::McpError;
+///
+/// #[derive(Debug, IntoMcpError)]
+/// enum BillingError {
+///     #[mcp(code = -32010)]
+///     InvoiceNotFound(String),
+///     ProviderDown,
+/// }
+///
+/// impl std::fmt::Display for BillingError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             Self::InvoiceNotFound(id) => write!(f, "Invoice {} not found", id),
+///             Self::ProviderDown => write!(f, "Payment provider unavailable"),
+///         }
+///     }
+/// }
+///
+/// #[derive(Debug, IntoMcpError)]
+/// #[mcp(code = -32020, data = limit)]
+/// struct QuotaExceeded {
+///     limit: u32,
+/// }
+///
+/// impl std::fmt::Display for QuotaExceeded {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "Quota of {} requests exceeded", self.limit)
+///     }
+/// }
+///
+/// let err: McpError = BillingError::InvoiceNotFound("INV-1".to_string()).into();
+/// assert_eq!(err.to_error_object().code, -32010);
+/// let err: McpError = QuotaExceeded { limit: 100 }.into();
+/// assert_eq!(err.to_error_object().code, -32020);
+/// ```
+#[proc_macro_derive(IntoMcpError, attributes(mcp))]
+pub fn derive_into_mcp_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tool_error_derive::derive_into_mcp_error_impl(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 /// Function attribute macro for creating MCP tools
 ///
 /// This macro converts a regular async function into an MCP tool with automatic
@@ -376,6 +490,18 @@ pub fn derive_mcp_resource(input: TokenStream) -> TokenStream {
 /// as output types in MCP tools. It introspects the struct fields and generates
 /// the appropriate schema properties and requirements.
 ///
+/// Structs also implement `ToJsonSchema`, so they can be tool parameters and
+/// nest inside other derived structs with their full schema.
+///
+/// On a fieldless enum it implements `ToJsonSchema` as a string `enum` of the
+/// serde variant names, so the enum can be used as a tool parameter type.
+/// Variants that carry data are rejected at compile time.
+///
+/// Struct fields accept `#[schema(minimum = .., maximum = ..)]` on numbers and
+/// `#[schema(min_length = .., max_length = ..)]` on strings. The bounds appear
+/// in the schema, and tools taking the struct as a parameter reject arguments
+/// that violate them before `execute` runs.
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -391,11 +517,14 @@ pub fn derive_mcp_resource(input: TokenStream) -> TokenStream {
 ///
 /// #[derive(JsonSchema, Serialize, Deserialize)]
 /// struct CalculationMetadata {
+///     #[schema(min_length = 1, max_length = 16)]
 ///     pub precision: String,
+///     #[schema(minimum = 0, maximum = 100)]
+///     pub confidence: u8,
 ///     pub is_exact: bool,
 /// }
 /// ```
-#[proc_macro_derive(JsonSchema)]
+#[proc_macro_derive(JsonSchema, attributes(schema))]
 pub fn derive_json_schema(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     json_schema_derive::derive_json_schema(input).into()
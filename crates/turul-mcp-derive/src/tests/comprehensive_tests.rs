@@ -246,14 +246,16 @@ mod schema_generation_tests {
         let schema = type_to_schema(&ty, &meta);
         let schema_str = schema.to_string();
 
-        // Unknown path types should generate schemars-based schema code
+        // Unknown path types resolve their schema through the probe: a
+        // `ToJsonSchema` impl wins, otherwise schemars is used
         assert!(
-            contains_pattern(&schema_str, "schemars"),
-            "Unknown types should use schemars for schema generation, got: {schema_str}"
+            contains_pattern(&schema_str, "ParamSchemaProbe"),
+            "Unknown types should go through ParamSchemaProbe, got: {schema_str}"
         );
         assert!(
-            contains_pattern(&schema_str, "convert_value_to_json_schema_with_defs"),
-            "Should use the schemars-to-JsonSchema converter, got: {schema_str}"
+            contains_pattern(&schema_str, "ParamSchemaFromTrait")
+                && contains_pattern(&schema_str, "ParamSchemaFromSchemars"),
+            "Should consider both ToJsonSchema and schemars, got: {schema_str}"
         );
     }
 
@@ -5,7 +5,9 @@ use quote::quote;
 use syn::{FnArg, ItemFn, Lit, Meta, Pat, Result, Token, punctuated::Punctuated};
 
 use crate::utils::{
-    extract_param_meta, generate_output_schema_auto, generate_param_extraction, type_to_schema,
+    extract_param_meta, generate_alias_resolution, generate_constraint_check,
+    generate_default_fill, generate_output_schema_auto, generate_param_extraction,
+    generate_pattern_check, param_default_value, type_to_schema,
 };
 
 pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result<TokenStream> {
@@ -20,6 +22,9 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
     let mut destructive = None;
     let mut idempotent = None;
     let mut open_world = None;
+    let mut visible_when = None;
+    let mut localized_descriptions = Vec::new();
+    let mut timeout_ms = None;
 
     for arg in args {
         match arg {
@@ -60,6 +65,13 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
                     }
                 }
             }
+            Meta::NameValue(nv) if nv.path.is_ident("visible_when") => {
+                if let syn::Expr::Lit(expr_lit) = &nv.value
+                    && let Lit::Str(s) = &expr_lit.lit
+                {
+                    visible_when = Some(s.parse::<syn::Path>()?);
+                }
+            }
             Meta::NameValue(nv) if nv.path.is_ident("title") => {
                 if let syn::Expr::Lit(expr_lit) = &nv.value
                     && let Lit::Str(s) = &expr_lit.lit
@@ -74,6 +86,27 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
                     annotation_title = Some(s.value());
                 }
             }
+            Meta::NameValue(nv) if nv.path.is_ident("timeout_ms") => {
+                if let syn::Expr::Lit(expr_lit) = &nv.value
+                    && let Lit::Int(ms) = &expr_lit.lit
+                {
+                    timeout_ms = Some(crate::utils::parse_timeout_ms(ms)?);
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        &nv.value,
+                        "timeout_ms must be an integer number of milliseconds",
+                    ));
+                }
+            }
+            Meta::List(list) if list.path.is_ident("localized_description") => {
+                localized_descriptions
+                    .extend(list.parse_args_with(crate::utils::parse_localized_descriptions)?);
+            }
+            // Bare flags: `read_only` is shorthand for `read_only = true`
+            Meta::Path(path) if path.is_ident("read_only") => read_only = Some(true),
+            Meta::Path(path) if path.is_ident("destructive") => destructive = Some(true),
+            Meta::Path(path) if path.is_ident("idempotent") => idempotent = Some(true),
+            Meta::Path(path) if path.is_ident("open_world") => open_world = Some(true),
             Meta::NameValue(nv) if nv.path.is_ident("read_only") => {
                 if let syn::Expr::Lit(expr_lit) = &nv.value
                     && let Lit::Bool(b) = &expr_lit.lit
@@ -140,6 +173,9 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
         )
     })?;
 
+    let localized_descriptions_method =
+        crate::utils::generate_localized_descriptions_method(&localized_descriptions);
+
     // Use custom output field name or default to "result"
     let output_field_name = output_field_name.unwrap_or_else(|| "result".to_string());
 
@@ -153,8 +189,19 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
         fn_name.span(),
     );
 
+    // Content blocks and whole results pass through untouched; anything else is wrapped
+    let tool_return = match return_type {
+        syn::ReturnType::Type(_, ty) => {
+            classify_tool_return(extract_result_ok_type(ty).unwrap_or(ty.as_ref()))
+        }
+        _ => ToolReturn::Value,
+    };
+
     // Analyze return type for output schema generation with automatic schemars detection
     let output_schema_tokens = match return_type {
+        syn::ReturnType::Type(_, _) if !matches!(tool_return, ToolReturn::Value) => quote! {
+            fn output_schema(&self) -> Option<&turul_mcp_protocol::tools::ToolSchema> { None }
+        },
         syn::ReturnType::Type(_, ty) => {
             // Extract inner type from Result<T, E>
             let schema_type = if let Some(inner_type) = extract_result_ok_type(ty) {
@@ -175,6 +222,9 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
     let mut schema_properties = Vec::new();
     let mut required_fields = Vec::new();
     let mut param_extractions = Vec::new();
+    let mut alias_resolutions = Vec::new();
+    let mut default_fills = Vec::new();
+    let mut argument_checks = Vec::new();
     let mut fn_call_args = Vec::new();
     let mut param_types = Vec::new();
 
@@ -197,6 +247,7 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
 
             // Extract parameter metadata from attributes
             let param_meta = extract_param_meta(&pat_type.attrs)?;
+            let default = param_default_value(param_type, &param_meta)?;
 
             let param_name_str = param_name.to_string();
 
@@ -207,12 +258,32 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
                 (#param_name_str.to_string(), #schema)
             });
 
-            if !param_meta.optional && !is_option_type(param_type) {
+            if !param_meta.optional && !is_option_type(param_type) && default.is_none() {
                 required_fields.push(quote! {
                     #param_name_str.to_string()
                 });
             }
 
+            if !param_meta.aliases.is_empty() {
+                alias_resolutions.push(generate_alias_resolution(
+                    &tool_name,
+                    &param_name_str,
+                    &param_meta,
+                ));
+            }
+
+            if param_meta.pattern.is_some() {
+                argument_checks.push(generate_pattern_check(&param_name_str, &param_meta));
+            }
+            argument_checks.push(generate_constraint_check(
+                &param_name_str,
+                param_type,
+                &schema,
+            ));
+            if let Some(default) = &default {
+                default_fills.push(generate_default_fill(&param_name_str, default));
+            }
+
             // Generate parameter extraction code based on type
             let extraction = generate_param_extraction(param_name, param_type, param_meta.optional);
             param_extractions.push(extraction);
@@ -230,6 +301,17 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
         }
     }
 
+    // Accept former parameter names and fill in defaults before extraction
+    let alias_prelude = if alias_resolutions.is_empty() && default_fills.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let mut args = args;
+            #(#alias_resolutions)*
+            #(#default_fills)*
+        }
+    };
+
     // Rename the function to avoid name collision with the tool constructor
     let mut clean_input = input.clone();
     clean_input
@@ -262,6 +344,7 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
         open_world,
     };
     let annotations_impl = crate::utils::generate_annotations_impl(&struct_name, &annotation_meta);
+    let visibility_method = crate::utils::generate_visibility_method(visible_when.as_ref());
 
     // Generate HasExecution impl based on task_support attribute
     let execution_impl = match task_support.as_deref() {
@@ -297,6 +380,43 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
         },
     };
 
+    let impl_call = crate::utils::generate_deadline_await(
+        &tool_name,
+        timeout_ms,
+        quote! { #impl_fn_name(#(#fn_call_args),*) },
+    );
+
+    // Failures reported as content must reach tools/call intact to become isError results
+    let error_content_passthrough = match return_type {
+        syn::ReturnType::Type(_, ty) if returns_mcp_error(ty) => quote! {
+            Err(e @ turul_mcp_protocol::McpError::ToolErrorContent(_)) => Err(e),
+        },
+        _ => quote! {},
+    };
+
+    let result_conversion = match tool_return {
+        ToolReturn::Content => quote! {
+            Ok(turul_mcp_protocol::tools::CallToolResult::success(result))
+        },
+        ToolReturn::CallToolResult => quote! { Ok(result) },
+        ToolReturn::Value => quote! {
+            // Wrap primitive results to match schema expectations
+            let schema_result = if self.output_schema().is_some() {
+                // Wrap in {field_name: value} to match generated schema
+                serde_json::json!({#output_field_name: result})
+            } else {
+                // No schema - directly serialize the result
+                serde_json::to_value(&result)
+                    .unwrap_or_else(|e| serde_json::json!({
+                        "error": format!("Failed to serialize result: {}", e)
+                    }))
+            };
+
+            // Use smart response builder with automatic structured content
+            turul_mcp_protocol::tools::CallToolResult::from_result_with_schema(&schema_result, self.output_schema())
+        },
+    };
+
     let expanded = quote! {
         // Keep the original function for direct use (with cleaned attributes)
         #clean_input
@@ -329,6 +449,8 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
         #[automatically_derived]
         impl turul_mcp_builders::traits::HasDescription for #struct_name {
             fn description(&self) -> Option<&str> { Some(#tool_description) }
+
+            #localized_descriptions_method
         }
 
         #[automatically_derived]
@@ -363,31 +485,24 @@ pub fn mcp_tool_impl(args: Punctuated<Meta, Token![,]>, input: ItemFn) -> Result
         #[automatically_derived]
         #[async_trait::async_trait]
         impl turul_mcp_server::McpTool for #struct_name {
+            #visibility_method
+
             async fn call(&self, args: serde_json::Value, session: Option<turul_mcp_server::SessionContext>) -> turul_mcp_server::McpResult<turul_mcp_protocol::tools::CallToolResult> {
                 use serde_json::Value;
                 use turul_mcp_builders::traits::HasOutputSchema;
 
+                #alias_prelude
+                #(#argument_checks)*
+
                 // Extract parameters
                 #(#param_extractions)*
 
                 // Call the renamed implementation function
-                match #impl_fn_name(#(#fn_call_args),*).await {
+                match #impl_call {
                     Ok(result) => {
-                        // Wrap primitive results to match schema expectations
-                        let schema_result = if self.output_schema().is_some() {
-                            // Wrap in {field_name: value} to match generated schema
-                            serde_json::json!({#output_field_name: result})
-                        } else {
-                            // No schema - directly serialize the result
-                            serde_json::to_value(&result)
-                                .unwrap_or_else(|e| serde_json::json!({
-                                    "error": format!("Failed to serialize result: {}", e)
-                                }))
-                        };
-
-                        // Use smart response builder with automatic structured content
-                        turul_mcp_protocol::tools::CallToolResult::from_result_with_schema(&schema_result, self.output_schema())
+                        #result_conversion
                     }
+                    #error_content_passthrough
                     Err(e) => Err(turul_mcp_protocol::McpError::tool_execution(&e.to_string()))
                 }
             }
@@ -470,6 +585,64 @@ fn extract_result_ok_type(ty: &syn::Type) -> Option<&syn::Type> {
     None
 }
 
+/// Whether the function's error type is `McpError`, via `McpResult<T>` or `Result<T, McpError>`
+fn returns_mcp_error(ty: &syn::Type) -> bool {
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    if segment.ident == "McpResult" {
+        return true;
+    }
+    if segment.ident == "Result"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(syn::Type::Path(error))) = args.args.iter().nth(1)
+    {
+        return error
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "McpError");
+    }
+    false
+}
+
+/// How a tool function's `Ok` value becomes a `CallToolResult`
+enum ToolReturn {
+    /// Serialized into text and structured content (the default)
+    Value,
+    /// `Vec<ContentBlock>` (or `Vec<ToolResult>`), used as the result's content
+    Content,
+    /// A complete `CallToolResult`, returned as is
+    CallToolResult,
+}
+
+fn classify_tool_return(ty: &syn::Type) -> ToolReturn {
+    let syn::Type::Path(type_path) = ty else {
+        return ToolReturn::Value;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return ToolReturn::Value;
+    };
+    if segment.ident == "CallToolResult" {
+        return ToolReturn::CallToolResult;
+    }
+    if segment.ident == "Vec"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(syn::Type::Path(item))) = args.args.first()
+        && item
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "ContentBlock" || s.ident == "ToolResult")
+    {
+        return ToolReturn::Content;
+    }
+    ToolReturn::Value
+}
+
 /// Generate output schema for function macros with automatic schemars detection
 fn generate_output_schema_auto_for_function(ty: &syn::Type, field_name: &str) -> TokenStream {
     // Use the same auto-detection, but without DeriveInput (function macros don't have it)
@@ -526,6 +699,47 @@ mod tests {
         assert!(output.contains("Web Search"));
     }
 
+    #[test]
+    fn test_function_tool_bare_annotation_flags() {
+        let args: Punctuated<Meta, Token![,]> = parse_quote! {
+            name = "purge", description = "Purge the cache", destructive, idempotent
+        };
+
+        let input: ItemFn = parse_quote! {
+            async fn purge() -> Result<String, String> {
+                Ok(String::new())
+            }
+        };
+
+        let output = mcp_tool_impl(args, input).unwrap().to_string();
+        assert!(output.contains("read_only_hint : None"));
+        assert!(output.contains("destructive_hint : Some (true)"));
+        assert!(output.contains("idempotent_hint : Some (true)"));
+    }
+
+    #[test]
+    fn test_function_tool_timeout_ms() {
+        let args: Punctuated<Meta, Token![,]> = parse_quote! {
+            name = "fetch", description = "Fetch a URL", timeout_ms = 250
+        };
+        let input: ItemFn = parse_quote! {
+            async fn fetch(url: String) -> Result<String, String> {
+                Ok(url)
+            }
+        };
+        let output = mcp_tool_impl(args, input.clone()).unwrap().to_string();
+        assert!(output.contains("run_with_deadline (\"fetch\" , 250u64"));
+
+        let args: Punctuated<Meta, Token![,]> = parse_quote! {
+            name = "fetch", description = "Fetch a URL", timeout_ms = 0
+        };
+        let err = mcp_tool_impl(args, input).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("timeout_ms must be greater than 0")
+        );
+    }
+
     #[test]
     fn test_function_tool_no_annotations() {
         let args: Punctuated<Meta, Token![,]> = parse_quote! {
@@ -5,8 +5,9 @@ use quote::quote;
 use syn::{Data, DeriveInput, Fields, Result};
 
 use crate::utils::{
-    determine_output_field_name, extract_param_meta, extract_tool_meta,
-    generate_output_schema_auto, generate_param_extraction, type_to_schema,
+    determine_output_field_name, extract_param_meta, extract_tool_meta, generate_alias_resolution,
+    generate_constraint_check, generate_default_fill, generate_output_schema_auto,
+    generate_param_extraction, generate_pattern_check, param_default_value, type_to_schema,
 };
 
 /// Auto-determine tool name from struct name (ZERO CONFIGURATION!)
@@ -77,6 +78,9 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
                 destructive: partial.destructive,
                 idempotent: partial.idempotent,
                 open_world: partial.open_world,
+                visible_when: partial.visible_when,
+                localized_descriptions: partial.localized_descriptions,
+                timeout_ms: partial.timeout_ms,
             }
         }
     };
@@ -104,12 +108,16 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
     let mut schema_properties = Vec::new();
     let mut required_fields = Vec::new();
     let mut param_extractions = Vec::new();
+    let mut alias_resolutions = Vec::new();
+    let mut default_fills = Vec::new();
+    let mut argument_checks = Vec::new();
     let mut field_assignments = Vec::new();
 
     for field in fields {
         let field_name = field.ident.as_ref().unwrap();
         let field_type = &field.ty;
         let param_meta = extract_param_meta(&field.attrs)?;
+        let default = param_default_value(field_type, &param_meta)?;
 
         // Generate schema for this field
         let field_name_str = field_name.to_string();
@@ -131,12 +139,32 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
         };
 
         // Only add to required fields if not explicitly optional and not Option<T>
-        if !param_meta.optional && !is_option_type {
+        if !param_meta.optional && !is_option_type && default.is_none() {
             required_fields.push(quote! {
                 #field_name_str.to_string()
             });
         }
 
+        if !param_meta.aliases.is_empty() {
+            alias_resolutions.push(generate_alias_resolution(
+                &tool_meta.name,
+                &field_name_str,
+                &param_meta,
+            ));
+        }
+
+        if param_meta.pattern.is_some() {
+            argument_checks.push(generate_pattern_check(&field_name_str, &param_meta));
+        }
+        argument_checks.push(generate_constraint_check(
+            &field_name_str,
+            field_type,
+            &schema,
+        ));
+        if let Some(default) = &default {
+            default_fills.push(generate_default_fill(&field_name_str, default));
+        }
+
         // Generate parameter extraction code
         let extraction = generate_param_extraction(
             field_name,
@@ -161,15 +189,31 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
     let tool_name = &tool_meta.name;
     let tool_description = &tool_meta.description;
 
+    // Accept former parameter names and fill in defaults before extraction
+    let alias_prelude = if alias_resolutions.is_empty() && default_fills.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let mut args = args;
+            #(#alias_resolutions)*
+            #(#default_fills)*
+        }
+    };
+
     // Generate title expression for HasBaseMetadata::title()
     let tool_title_expr = match &tool_meta.title {
         Some(t) => quote! { Some(#t) },
         None => quote! { None },
     };
 
+    let localized_descriptions_method =
+        crate::utils::generate_localized_descriptions_method(&tool_meta.localized_descriptions);
+
     // Generate annotations impl (centralized via AnnotationMeta)
     let annotations_impl =
         crate::utils::generate_annotations_impl(name, &tool_meta.to_annotation_meta());
+    let visibility_method =
+        crate::utils::generate_visibility_method(tool_meta.visible_when.as_ref());
 
     // Determine the output field name consistently for both schema and runtime
     let runtime_field_name = if let Some(ref output_type) = tool_meta.output_type {
@@ -235,6 +279,12 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
         },
     };
 
+    let execute_call = crate::utils::generate_deadline_await(
+        tool_name,
+        tool_meta.timeout_ms,
+        quote! { instance.execute(session) },
+    );
+
     let expanded = quote! {
         #[automatically_derived]
         // Generate fine-grained trait implementations
@@ -252,6 +302,8 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
             fn description(&self) -> Option<&str> {
                 Some(#tool_description)
             }
+
+            #localized_descriptions_method
         }
 
         impl turul_mcp_builders::traits::HasInputSchema for #name {
@@ -300,6 +352,7 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
                     JsonSchema::boolean()
                 } else if struct_name.contains("List") || struct_name.contains("Array") || struct_name.contains("Search") || struct_name.contains("Query") || struct_name.contains("Find") || struct_name.contains("Batch") {
                     JsonSchema::Array {
+                        title: None,
                         description: Some("Array output (heuristic: tool name suggests collection)".to_string()),
                         items: None,
                         min_items: None,
@@ -311,6 +364,7 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
                     // Default to flexible object schema since we can't reliably determine the type
                     // from struct names alone. This is safer than defaulting to string.
                     JsonSchema::Object {
+                        title: None,
                         description: Some("JSON object or value (zero-config fallback)".to_string()),
                         properties: None,
                         required: None,
@@ -339,6 +393,7 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
                         serde_json::Value::Array(arr) => {
                             let item_schema = arr.first().map(|item| Box::new(infer_schema(item)));
                             JsonSchema::Array {
+                                title: None,
                                 description: Some("Array output".to_string()),
                                 items: item_schema,
                                 min_items: None,
@@ -353,6 +408,7 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
                                 required.push(key.clone());
                             }
                             JsonSchema::Object {
+                                title: None,
                                 description: Some("Generated from runtime object structure".to_string()),
                                 properties: Some(properties),
                                 required: Some(required),
@@ -360,6 +416,7 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
                             }
                         }
                         serde_json::Value::Null => JsonSchema::Object {
+                            title: None,
                             description: Some("JSON value".to_string()),
                             properties: None,
                             required: None,
@@ -395,10 +452,15 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
         #[automatically_derived]
         #[async_trait::async_trait]
         impl turul_mcp_server::McpTool for #name {
+            #visibility_method
+
             async fn call(&self, args: serde_json::Value, session: Option<turul_mcp_server::SessionContext>) -> turul_mcp_server::McpResult<turul_mcp_protocol::tools::CallToolResult> {
                 use serde_json::Value;
                 use turul_mcp_builders::traits::HasOutputSchema;
 
+                #alias_prelude
+                #(#argument_checks)*
+
                 // Extract parameters
                 #(#param_extractions)*
 
@@ -408,7 +470,7 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
                 };
 
                 // Execute with session - user's execute method now receives session
-                match instance.execute(session).await {
+                match #execute_call {
                     Ok(result) => {
                         // Serialize result for output
                         let result_value = serde_json::to_value(&result)
@@ -450,6 +512,7 @@ pub fn derive_mcp_tool_impl(input: DeriveInput) -> Result<TokenStream> {
                                 use std::collections::HashMap;
                                 use turul_mcp_protocol::schema::JsonSchema;
                                 let array_schema = JsonSchema::Array {
+                                    title: None,
                                     description: Some("Array of items".to_string()),
                                     items: None,
                                     min_items: None,
@@ -586,6 +649,43 @@ mod tests {
         assert!(output.contains("ToolAnnotations"));
     }
 
+    #[test]
+    fn test_derive_bare_annotation_flags() {
+        let input: DeriveInput = parse_quote! {
+            #[tool(name = "lookup", description = "Lookup", read_only, idempotent,
+                   open_world = false)]
+            struct LookupTool {
+                key: String,
+            }
+        };
+        let output = derive_mcp_tool_impl(input).unwrap().to_string();
+        assert!(output.contains("read_only_hint : Some (true)"));
+        assert!(output.contains("destructive_hint : None"));
+        assert!(output.contains("idempotent_hint : Some (true)"));
+        assert!(output.contains("open_world_hint : Some (false)"));
+    }
+
+    #[test]
+    fn test_derive_timeout_ms() {
+        let input: DeriveInput = parse_quote! {
+            #[tool(name = "fetch", description = "Fetch", timeout_ms = 1500)]
+            struct FetchTool {
+                url: String,
+            }
+        };
+        let output = derive_mcp_tool_impl(input).unwrap().to_string();
+        assert!(output.contains("run_with_deadline (\"fetch\" , 1500u64"));
+
+        let input: DeriveInput = parse_quote! {
+            #[tool(name = "fetch", description = "Fetch")]
+            struct FetchTool {
+                url: String,
+            }
+        };
+        let output = derive_mcp_tool_impl(input).unwrap().to_string();
+        assert!(!output.contains("run_with_deadline"));
+    }
+
     #[test]
     fn test_derive_title_routes_to_base_metadata() {
         let input: DeriveInput = parse_quote! {
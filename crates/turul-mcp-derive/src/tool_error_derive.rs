@@ -0,0 +1,200 @@
+//! Implementation of #[derive(McpToolError)] and #[derive(IntoMcpError)]
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Expr, Fields, Ident, Lit, LitStr, Result, UnOp};
+
+/// Per-variant (or per-struct) mapping parsed from `#[mcp_error(...)]` / `#[mcp(...)]`
+struct VariantErrorMeta {
+    code: i64,
+    message: Option<LitStr>,
+    data: Option<Ident>,
+}
+
+/// Parse a JSON-RPC error code literal, accepting an optional leading minus sign
+fn parse_error_code(expr: &Expr) -> Result<i64> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => int.base10_parse::<i64>(),
+            other => Err(syn::Error::new_spanned(other, "code must be an integer")),
+        },
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            Ok(-parse_error_code(&unary.expr)?)
+        }
+        Expr::Group(group) => parse_error_code(&group.expr),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "code must be an integer literal, e.g. `code = -32010`",
+        )),
+    }
+}
+
+fn extract_variant_error_meta(
+    attrs: &[Attribute],
+    attr_name: &str,
+) -> Result<Option<VariantErrorMeta>> {
+    let Some(attr) = attrs.iter().find(|a| a.path().is_ident(attr_name)) else {
+        return Ok(None);
+    };
+
+    let mut code = None;
+    let mut message = None;
+    let mut data = None;
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("code") {
+            let value = meta.value()?;
+            let expr: Expr = value.parse()?;
+            code = Some(parse_error_code(&expr)?);
+        } else if meta.path.is_ident("message") {
+            let value = meta.value()?;
+            message = Some(value.parse::<LitStr>()?);
+        } else if meta.path.is_ident("data") {
+            let value = meta.value()?;
+            // Accept both `data = field` and `data = "field"`
+            if value.peek(LitStr) {
+                let s: LitStr = value.parse()?;
+                data = Some(Ident::new(&s.value(), s.span()));
+            } else {
+                data = Some(value.parse::<Ident>()?);
+            }
+        } else {
+            return Err(meta.error(format!(
+                "unsupported {} attribute; expected code, message, or data",
+                attr_name
+            )));
+        }
+        Ok(())
+    })?;
+
+    let code = code.ok_or_else(|| {
+        syn::Error::new_spanned(
+            attr,
+            format!("{} requires a `code`, e.g. `code = -32010`", attr_name),
+        )
+    })?;
+
+    Ok(Some(VariantErrorMeta {
+        code,
+        message,
+        data,
+    }))
+}
+
+pub fn derive_mcp_tool_error_impl(input: DeriveInput) -> Result<TokenStream> {
+    derive_into_mcp_error(input, "McpToolError", "mcp_error")
+}
+
+pub fn derive_into_mcp_error_impl(input: DeriveInput) -> Result<TokenStream> {
+    derive_into_mcp_error(input, "IntoMcpError", "mcp")
+}
+
+/// Bind every field so `message` format strings and `data` can reference them.
+/// Named fields keep their names; tuple fields are bound as `_0`, `_1`, ...
+fn bind_fields(path: TokenStream, fields: &Fields) -> (TokenStream, Vec<Ident>) {
+    match fields {
+        Fields::Named(fields) => {
+            let names: Vec<Ident> = fields
+                .named
+                .iter()
+                .filter_map(|f| f.ident.clone())
+                .collect();
+            (quote! { #path { #(#names),* } }, names)
+        }
+        Fields::Unnamed(fields) => {
+            let names: Vec<Ident> = (0..fields.unnamed.len())
+                .map(|i| format_ident!("_{}", i))
+                .collect();
+            (quote! { #path ( #(#names),* ) }, names)
+        }
+        Fields::Unit => (path, Vec::new()),
+    }
+}
+
+/// Build the `McpError` expression for one variant (or the struct itself)
+fn error_arm(
+    owner: &Ident,
+    pattern: TokenStream,
+    bindings: &[Ident],
+    meta: Option<VariantErrorMeta>,
+) -> Result<TokenStream> {
+    let Some(meta) = meta else {
+        // Unmapped variants fall back to the generic tool execution error
+        return Ok(quote! {
+            #pattern => turul_mcp_protocol::McpError::ToolExecutionError(display),
+        });
+    };
+
+    if let Some(data_field) = &meta.data
+        && !bindings.iter().any(|b| b == data_field)
+    {
+        return Err(syn::Error::new_spanned(
+            data_field,
+            format!("`{}` has no field named `{}`", owner, data_field),
+        ));
+    }
+
+    let code = meta.code;
+    let message = match &meta.message {
+        Some(lit) => quote! { format!(#lit) },
+        None => quote! { display },
+    };
+    let data = match &meta.data {
+        Some(field) => quote! { serde_json::to_value(&#field).ok() },
+        None => quote! { None },
+    };
+
+    Ok(quote! {
+        #pattern => turul_mcp_protocol::McpError::JsonRpcError {
+            code: #code,
+            message: #message,
+            data: #data,
+        },
+    })
+}
+
+fn derive_into_mcp_error(
+    input: DeriveInput,
+    derive_name: &str,
+    attr_name: &str,
+) -> Result<TokenStream> {
+    let type_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut arms = Vec::new();
+    match &input.data {
+        Data::Enum(data_enum) => {
+            for variant in &data_enum.variants {
+                let variant_name = &variant.ident;
+                let (pattern, bindings) =
+                    bind_fields(quote! { #type_name::#variant_name }, &variant.fields);
+                let meta = extract_variant_error_meta(&variant.attrs, attr_name)?;
+                arms.push(error_arm(variant_name, pattern, &bindings, meta)?);
+            }
+        }
+        Data::Struct(data_struct) => {
+            let (pattern, bindings) = bind_fields(quote! { #type_name }, &data_struct.fields);
+            let meta = extract_variant_error_meta(&input.attrs, attr_name)?;
+            arms.push(error_arm(type_name, pattern, &bindings, meta)?);
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                type_name,
+                format!("{} can only be derived for enums and structs", derive_name),
+            ));
+        }
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::std::convert::From<#type_name #ty_generics> for turul_mcp_protocol::McpError #where_clause {
+            #[allow(unused_variables)]
+            fn from(err: #type_name #ty_generics) -> Self {
+                let display = err.to_string();
+                match err {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
@@ -91,6 +91,9 @@ pub struct ToolMeta {
     pub destructive: Option<bool>,
     pub idempotent: Option<bool>,
     pub open_world: Option<bool>,
+    pub visible_when: Option<syn::Path>, // → McpTool::is_visible() predicate
+    pub localized_descriptions: Vec<(String, String)>, // → HasDescription::localized_descriptions()
+    pub timeout_ms: Option<u64>,         // → deadline around execution
 }
 
 impl ToolMeta {
@@ -105,6 +108,81 @@ impl ToolMeta {
     }
 }
 
+/// Parse the `timeout_ms = ...` value, which must be a positive integer literal.
+pub fn parse_timeout_ms(lit: &syn::LitInt) -> Result<u64> {
+    match lit.base10_parse::<u64>()? {
+        0 => Err(syn::Error::new_spanned(
+            lit,
+            "timeout_ms must be greater than 0",
+        )),
+        ms => Ok(ms),
+    }
+}
+
+/// Await the tool body, under `run_with_deadline` when `timeout_ms` is set.
+pub fn generate_deadline_await(
+    tool_name: &str,
+    timeout_ms: Option<u64>,
+    future: TokenStream,
+) -> TokenStream {
+    match timeout_ms {
+        Some(ms) => quote! {
+            turul_mcp_server::tool::run_with_deadline(#tool_name, #ms, #future).await?
+        },
+        None => quote! { #future.await },
+    }
+}
+
+/// Parse the `"fr" = "...", "de" = "..."` list inside `localized_description(...)`.
+pub fn parse_localized_descriptions(
+    input: syn::parse::ParseStream,
+) -> Result<Vec<(String, String)>> {
+    let mut pairs = Vec::new();
+    while !input.is_empty() {
+        let locale: syn::LitStr = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        let text: syn::LitStr = input.parse()?;
+        if locale.value().trim().is_empty() {
+            return Err(syn::Error::new_spanned(locale, "locale must not be empty"));
+        }
+        pairs.push((locale.value(), text.value()));
+        if !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+        }
+    }
+    Ok(pairs)
+}
+
+/// Generate the `HasDescription::localized_descriptions` override, or nothing
+/// when the tool has no translations.
+pub fn generate_localized_descriptions_method(pairs: &[(String, String)]) -> TokenStream {
+    if pairs.is_empty() {
+        return quote! {};
+    }
+    let locales = pairs.iter().map(|(locale, _)| locale);
+    let texts = pairs.iter().map(|(_, text)| text);
+    quote! {
+        fn localized_descriptions(&self) -> Option<&std::collections::HashMap<String, String>> {
+            static LOCALIZED: std::sync::OnceLock<std::collections::HashMap<String, String>> =
+                std::sync::OnceLock::new();
+            Some(LOCALIZED.get_or_init(|| {
+                std::collections::HashMap::from([
+                    #((#locales.to_string(), #texts.to_string())),*
+                ])
+            }))
+        }
+    }
+}
+
+/// Parse a tool annotation hint written as `read_only` (true) or `read_only = <bool>`.
+fn parse_annotation_flag(meta: &syn::meta::ParseNestedMeta) -> Result<bool> {
+    if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+        return Ok(true);
+    }
+    let b: syn::LitBool = meta.value()?.parse()?;
+    Ok(b.value())
+}
+
 pub fn extract_tool_meta(attrs: &[Attribute]) -> Result<ToolMeta> {
     let mut name = None;
     let mut description = None;
@@ -117,6 +195,9 @@ pub fn extract_tool_meta(attrs: &[Attribute]) -> Result<ToolMeta> {
     let mut destructive = None;
     let mut idempotent = None;
     let mut open_world = None;
+    let mut visible_when = None;
+    let mut localized_descriptions = Vec::new();
+    let mut timeout_ms = None;
 
     for attr in attrs {
         if attr.path().is_ident("tool") {
@@ -158,21 +239,23 @@ pub fn extract_tool_meta(attrs: &[Attribute]) -> Result<ToolMeta> {
                     let s: syn::LitStr = value.parse()?;
                     annotation_title = Some(s.value());
                 } else if meta.path.is_ident("read_only") {
-                    let value = meta.value()?;
-                    let b: syn::LitBool = value.parse()?;
-                    read_only = Some(b.value());
+                    read_only = Some(parse_annotation_flag(&meta)?);
                 } else if meta.path.is_ident("destructive") {
-                    let value = meta.value()?;
-                    let b: syn::LitBool = value.parse()?;
-                    destructive = Some(b.value());
+                    destructive = Some(parse_annotation_flag(&meta)?);
                 } else if meta.path.is_ident("idempotent") {
-                    let value = meta.value()?;
-                    let b: syn::LitBool = value.parse()?;
-                    idempotent = Some(b.value());
+                    idempotent = Some(parse_annotation_flag(&meta)?);
                 } else if meta.path.is_ident("open_world") {
+                    open_world = Some(parse_annotation_flag(&meta)?);
+                } else if meta.path.is_ident("visible_when") {
                     let value = meta.value()?;
-                    let b: syn::LitBool = value.parse()?;
-                    open_world = Some(b.value());
+                    let s: syn::LitStr = value.parse()?;
+                    visible_when = Some(s.parse::<syn::Path>()?);
+                } else if meta.path.is_ident("localized_description") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    localized_descriptions.extend(parse_localized_descriptions(&content)?);
+                } else if meta.path.is_ident("timeout_ms") {
+                    timeout_ms = Some(parse_timeout_ms(&meta.value()?.parse()?)?);
                 }
                 Ok(())
             })?;
@@ -219,6 +302,9 @@ pub fn extract_tool_meta(attrs: &[Attribute]) -> Result<ToolMeta> {
         destructive,
         idempotent,
         open_world,
+        visible_when,
+        localized_descriptions,
+        timeout_ms,
     })
 }
 
@@ -234,6 +320,9 @@ pub struct PartialToolMeta {
     pub destructive: Option<bool>,
     pub idempotent: Option<bool>,
     pub open_world: Option<bool>,
+    pub visible_when: Option<syn::Path>,
+    pub localized_descriptions: Vec<(String, String)>,
+    pub timeout_ms: Option<u64>,
 }
 
 pub fn extract_tool_meta_partial(attrs: &[Attribute]) -> PartialToolMeta {
@@ -246,6 +335,9 @@ pub fn extract_tool_meta_partial(attrs: &[Attribute]) -> PartialToolMeta {
     let mut destructive = None;
     let mut idempotent = None;
     let mut open_world = None;
+    let mut visible_when = None;
+    let mut localized_descriptions = Vec::new();
+    let mut timeout_ms = None;
 
     for attr in attrs {
         if attr.path().is_ident("tool") {
@@ -271,21 +363,23 @@ pub fn extract_tool_meta_partial(attrs: &[Attribute]) -> PartialToolMeta {
                     let s: syn::LitStr = value.parse()?;
                     annotation_title = Some(s.value());
                 } else if meta.path.is_ident("read_only") {
-                    let value = meta.value()?;
-                    let b: syn::LitBool = value.parse()?;
-                    read_only = Some(b.value());
+                    read_only = Some(parse_annotation_flag(&meta)?);
                 } else if meta.path.is_ident("destructive") {
-                    let value = meta.value()?;
-                    let b: syn::LitBool = value.parse()?;
-                    destructive = Some(b.value());
+                    destructive = Some(parse_annotation_flag(&meta)?);
                 } else if meta.path.is_ident("idempotent") {
-                    let value = meta.value()?;
-                    let b: syn::LitBool = value.parse()?;
-                    idempotent = Some(b.value());
+                    idempotent = Some(parse_annotation_flag(&meta)?);
                 } else if meta.path.is_ident("open_world") {
+                    open_world = Some(parse_annotation_flag(&meta)?);
+                } else if meta.path.is_ident("visible_when") {
                     let value = meta.value()?;
-                    let b: syn::LitBool = value.parse()?;
-                    open_world = Some(b.value());
+                    let s: syn::LitStr = value.parse()?;
+                    visible_when = Some(s.parse::<syn::Path>()?);
+                } else if meta.path.is_ident("localized_description") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    localized_descriptions.extend(parse_localized_descriptions(&content)?);
+                } else if meta.path.is_ident("timeout_ms") {
+                    timeout_ms = Some(parse_timeout_ms(&meta.value()?.parse()?)?);
                 } else {
                     // Skip name/description — we don't need them here
                     let _ = meta.value().and_then(|v| v.parse::<syn::LitStr>());
@@ -305,6 +399,24 @@ pub fn extract_tool_meta_partial(attrs: &[Attribute]) -> PartialToolMeta {
         destructive,
         idempotent,
         open_world,
+        visible_when,
+        localized_descriptions,
+        timeout_ms,
+    }
+}
+
+/// Generate the `McpTool::is_visible` override for a `visible_when` predicate.
+///
+/// The predicate receives `&SessionContext`; tools are hidden from requests
+/// without a session so a predicate never sees a missing context.
+pub fn generate_visibility_method(visible_when: Option<&syn::Path>) -> TokenStream {
+    match visible_when {
+        Some(predicate) => quote! {
+            fn is_visible(&self, session: Option<&turul_mcp_server::SessionContext>) -> bool {
+                session.is_some_and(|session| #predicate(session))
+            }
+        },
+        None => quote! {},
     }
 }
 
@@ -312,9 +424,17 @@ pub fn extract_tool_meta_partial(attrs: &[Attribute]) -> PartialToolMeta {
 #[derive(Debug, Default)]
 pub struct ParamMeta {
     pub description: Option<String>,
+    /// Human-readable display name emitted as the schema `title`
+    pub title: Option<String>,
     pub optional: bool,
     pub min: Option<f64>,
     pub max: Option<f64>,
+    /// Former names still accepted during argument extraction
+    pub aliases: Vec<String>,
+    /// Regex a string argument must match (checked before `execute`)
+    pub pattern: Option<String>,
+    /// Literal used when the argument is absent, e.g. `10`, `-1.5`, `"en"`, `true`
+    pub default: Option<syn::Expr>,
 }
 
 pub fn extract_param_meta(attrs: &[Attribute]) -> Result<ParamMeta> {
@@ -327,6 +447,10 @@ pub fn extract_param_meta(attrs: &[Attribute]) -> Result<ParamMeta> {
                     let value = nested_meta.value()?;
                     let s: syn::LitStr = value.parse()?;
                     meta.description = Some(s.value());
+                } else if nested_meta.path.is_ident("title") {
+                    let value = nested_meta.value()?;
+                    let s: syn::LitStr = value.parse()?;
+                    meta.title = Some(s.value());
                 } else if nested_meta.path.is_ident("optional") {
                     // Handle both #[param(optional)] and #[param(optional = true/false)]
                     if nested_meta.input.peek(syn::Token![=]) {
@@ -351,6 +475,39 @@ pub fn extract_param_meta(attrs: &[Attribute]) -> Result<ParamMeta> {
                     let value = nested_meta.value()?;
                     let lit: syn::LitFloat = value.parse()?;
                     meta.max = Some(lit.base10_parse()?);
+                } else if nested_meta.path.is_ident("pattern") {
+                    // #[param(pattern = "^[A-Z]{2}$")] — reject malformed regexes at compile time
+                    let value = nested_meta.value()?;
+                    let lit: syn::LitStr = value.parse()?;
+                    if let Err(e) = regex::Regex::new(&lit.value()) {
+                        return Err(syn::Error::new_spanned(
+                            &lit,
+                            format!("invalid pattern regex: {}", e),
+                        ));
+                    }
+                    meta.pattern = Some(lit.value());
+                } else if nested_meta.path.is_ident("default") {
+                    // #[param(default = 10)] — checked against the field type by param_default_value
+                    let value = nested_meta.value()?;
+                    meta.default = Some(value.parse()?);
+                } else if nested_meta.path.is_ident("aliases") {
+                    // #[param(aliases = ["old_name", "older_name"])]
+                    let value = nested_meta.value()?;
+                    let array: syn::ExprArray = value.parse()?;
+                    for elem in array.elems {
+                        match elem {
+                            syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Str(s),
+                                ..
+                            }) => meta.aliases.push(s.value()),
+                            other => {
+                                return Err(syn::Error::new_spanned(
+                                    other,
+                                    "aliases must be string literals",
+                                ));
+                            }
+                        }
+                    }
                 }
                 Ok(())
             })?;
@@ -360,12 +517,248 @@ pub fn extract_param_meta(attrs: &[Attribute]) -> Result<ParamMeta> {
     Ok(meta)
 }
 
+/// Generate the statement that renames an aliased argument to `param_name`.
+///
+/// Returns no tokens when the parameter has no aliases. The generated code
+/// expects a mutable `args` binding in scope.
+pub fn generate_alias_resolution(
+    tool_name: &str,
+    param_name: &str,
+    param_meta: &ParamMeta,
+) -> TokenStream {
+    if param_meta.aliases.is_empty() {
+        return quote! {};
+    }
+    let aliases = &param_meta.aliases;
+    quote! {
+        turul_mcp_builders::tool::resolve_param_alias(#tool_name, &mut args, #param_name, &[#(#aliases),*]);
+    }
+}
+
+/// The JSON type of a scalar Rust type (looking through `Option`), if it has one
+pub fn scalar_kind(ty: &syn::Type) -> Option<&'static str> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    match segment.ident.to_string().as_str() {
+        "Option" => match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => match args.args.first()? {
+                syn::GenericArgument::Type(inner) => scalar_kind(inner),
+                _ => None,
+            },
+            _ => None,
+        },
+        "String" => Some("string"),
+        "f64" | "f32" => Some("number"),
+        "i64" | "i32" | "i16" | "i8" | "u64" | "u32" | "u16" | "u8" | "isize" | "usize" => {
+            Some("integer")
+        }
+        "bool" => Some("boolean"),
+        _ => None,
+    }
+}
+
+/// Type-check `#[param(default = ...)]` against the parameter type.
+///
+/// Returns the default as a suffixed literal (`10i64`, `10f64`, `"en"`,
+/// `true`) ready for `JsonSchema::with_default` and `serde_json::Value::from`,
+/// or `None` when the parameter has no default.
+pub fn param_default_value(ty: &syn::Type, param_meta: &ParamMeta) -> Result<Option<TokenStream>> {
+    let Some(expr) = &param_meta.default else {
+        return Ok(None);
+    };
+    let (negative, lit) = match expr {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => (false, lit),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match inner.as_ref() {
+            syn::Expr::Lit(syn::ExprLit { lit, .. }) => (true, lit),
+            _ => return Err(syn::Error::new_spanned(expr, "default must be a literal")),
+        },
+        _ => return Err(syn::Error::new_spanned(expr, "default must be a literal")),
+    };
+    let mismatch = |expected: &str| {
+        Err(syn::Error::new_spanned(
+            expr,
+            format!("default must be {} to match the parameter type", expected),
+        ))
+    };
+
+    let value = match (scalar_kind(ty), lit) {
+        (Some("string"), syn::Lit::Str(s)) if !negative => quote! { #s },
+        (Some("string"), _) => return mismatch("a string literal"),
+        (Some("boolean"), syn::Lit::Bool(b)) if !negative => quote! { #b },
+        (Some("boolean"), _) => return mismatch("`true` or `false`"),
+        (Some("number"), syn::Lit::Int(_) | syn::Lit::Float(_)) => {
+            let magnitude: f64 = match lit {
+                syn::Lit::Int(i) => i.base10_parse()?,
+                syn::Lit::Float(f) => f.base10_parse()?,
+                _ => unreachable!(),
+            };
+            let value = if negative { -magnitude } else { magnitude };
+            quote! { #value }
+        }
+        (Some("number"), _) => return mismatch("a number"),
+        (Some("integer"), syn::Lit::Int(i)) => {
+            let magnitude: i128 = i.base10_parse()?;
+            let value = if negative { -magnitude } else { magnitude };
+            if !integer_fits(ty, value) {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    format!("default {} is out of range for the parameter type", value),
+                ));
+            }
+            match i64::try_from(value) {
+                Ok(value) => quote! { #value },
+                Err(_) => {
+                    let value = value as u64;
+                    quote! { #value }
+                }
+            }
+        }
+        (Some("integer"), _) => return mismatch("an integer"),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                expr,
+                "default is only supported on number, string and boolean parameters",
+            ));
+        }
+    };
+    Ok(Some(value))
+}
+
+/// Whether `value` fits the integer type `ty` (looking through `Option`)
+fn integer_fits(ty: &syn::Type, value: i128) -> bool {
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    let (min, max) = match segment.ident.to_string().as_str() {
+        "Option" => {
+            if let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+                && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+            {
+                return integer_fits(inner, value);
+            }
+            return false;
+        }
+        "i8" => (i8::MIN as i128, i8::MAX as i128),
+        "i16" => (i16::MIN as i128, i16::MAX as i128),
+        "i32" => (i32::MIN as i128, i32::MAX as i128),
+        "i64" | "isize" => (i64::MIN as i128, i64::MAX as i128),
+        "u8" => (0, u8::MAX as i128),
+        "u16" => (0, u16::MAX as i128),
+        "u32" => (0, u32::MAX as i128),
+        "u64" | "usize" => (0, u64::MAX as i128),
+        _ => return false,
+    };
+    (min..=max).contains(&value)
+}
+
+/// Generate the statement that fills in `#[param(default = ...)]` for an absent argument.
+///
+/// `default` is the literal from [`param_default_value`]. The generated code
+/// expects a mutable `args` binding in scope.
+pub fn generate_default_fill(param_name: &str, default: &TokenStream) -> TokenStream {
+    quote! {
+        turul_mcp_builders::tool::apply_param_default(
+            &mut args,
+            #param_name,
+            serde_json::Value::from(#default),
+        );
+    }
+}
+
+/// Generate the runtime check that a string argument matches `#[param(pattern)]`.
+///
+/// Returns no tokens when the parameter has no pattern. The generated code
+/// expects an `args` binding in scope and a function returning `McpResult`.
+pub fn generate_pattern_check(param_name: &str, param_meta: &ParamMeta) -> TokenStream {
+    let Some(pattern) = &param_meta.pattern else {
+        return quote! {};
+    };
+    quote! {
+        {
+            static PATTERN: std::sync::LazyLock<turul_mcp_builders::regex::Regex> =
+                std::sync::LazyLock::new(|| {
+                    turul_mcp_builders::regex::Regex::new(#pattern)
+                        .expect("pattern validated at macro expansion")
+                });
+            turul_mcp_builders::tool::check_param_pattern(&args, #param_name, &PATTERN)?;
+        }
+    }
+}
+
+/// Generate the runtime check of a nested parameter type's schema constraints.
+///
+/// Returns no tokens for primitive parameters, whose `#[param]` bounds are
+/// advisory. For other types (e.g. structs with `#[derive(JsonSchema)]` and
+/// `#[schema(minimum = ..)]` fields) the generated code checks the argument
+/// against `schema` before extraction; it expects an `args` binding in scope
+/// and a function returning `McpResult`.
+pub fn generate_constraint_check(
+    param_name: &str,
+    ty: &syn::Type,
+    schema: &TokenStream,
+) -> TokenStream {
+    if !is_nested_param_type(ty) {
+        return quote! {};
+    }
+    quote! {
+        turul_mcp_builders::tool::check_param_constraints(&args, #param_name, &#schema)?;
+    }
+}
+
+/// Whether a parameter type (through `Option`, `Vec` and arrays) is a
+/// user-defined type rather than a primitive with a built-in schema
+fn is_nested_param_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(type_path) => {
+            let Some(last_seg) = type_path.path.segments.last() else {
+                return false;
+            };
+            match last_seg.ident.to_string().as_str() {
+                "Option" | "Vec" => match &last_seg.arguments {
+                    syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+                        Some(syn::GenericArgument::Type(inner_ty)) => {
+                            is_nested_param_type(inner_ty)
+                        }
+                        _ => false,
+                    },
+                    _ => false,
+                },
+                "String" | "str" | "f64" | "f32" | "i64" | "i32" | "i16" | "i8" | "u64" | "u32"
+                | "u16" | "u8" | "isize" | "usize" | "bool" => false,
+                _ => true,
+            }
+        }
+        syn::Type::Array(array) => is_nested_param_type(&array.elem),
+        syn::Type::Reference(type_ref) => is_nested_param_type(&type_ref.elem),
+        _ => false,
+    }
+}
+
 /// Generate JSON schema for a Rust type
 pub fn type_to_schema(ty: &syn::Type, param_meta: &ParamMeta) -> TokenStream {
     let description = param_meta
         .description
         .as_ref()
         .map(|d| quote! { .with_description(#d) });
+    let title = param_meta
+        .title
+        .as_ref()
+        .map(|t| quote! { .with_title(#t) });
+    let labels = quote! { #title #description };
+    // Validated by the caller; an invalid default has already been reported
+    let default = param_default_value(ty, param_meta)
+        .ok()
+        .flatten()
+        .map(|d| quote! { .with_default(#d) });
 
     // Match on the last path segment so both `Option<T>` and
     // `std::option::Option<T>` (and `Vec<T>` / `std::vec::Vec<T>`) are handled.
@@ -373,7 +766,7 @@ pub fn type_to_schema(ty: &syn::Type, param_meta: &ParamMeta) -> TokenStream {
         syn::Type::Path(type_path) => {
             let Some(last_seg) = type_path.path.segments.last() else {
                 return quote! {
-                    turul_mcp_protocol::schema::JsonSchema::string() #description
+                    turul_mcp_protocol::schema::JsonSchema::string() #labels
                 };
             };
 
@@ -387,7 +780,7 @@ pub fn type_to_schema(ty: &syn::Type, param_meta: &ParamMeta) -> TokenStream {
                     }
                     // Fallback for malformed Option
                     quote! {
-                        turul_mcp_protocol::schema::JsonSchema::string() #description
+                        turul_mcp_protocol::schema::JsonSchema::string() #labels
                     }
                 }
                 // Vec<T>: array with items schema from inner type
@@ -397,26 +790,30 @@ pub fn type_to_schema(ty: &syn::Type, param_meta: &ParamMeta) -> TokenStream {
                     {
                         let items_schema = type_to_schema(inner_ty, &ParamMeta::default());
                         return quote! {
-                            turul_mcp_protocol::schema::JsonSchema::array(#items_schema) #description
+                            turul_mcp_protocol::schema::JsonSchema::array(#items_schema) #labels
                         };
                     }
                     // Fallback: array of strings
                     quote! {
                         turul_mcp_protocol::schema::JsonSchema::array(
                             turul_mcp_protocol::schema::JsonSchema::string()
-                        ) #description
+                        ) #labels
                     }
                 }
                 "String" | "str" => {
+                    let pattern = param_meta
+                        .pattern
+                        .as_ref()
+                        .map(|p| quote! { .with_pattern(#p) });
                     quote! {
-                        turul_mcp_protocol::schema::JsonSchema::string() #description
+                        turul_mcp_protocol::schema::JsonSchema::string() #labels #pattern #default
                     }
                 }
                 "f64" | "f32" => {
                     let min = param_meta.min.map(|m| quote! { .with_minimum(#m) });
                     let max = param_meta.max.map(|m| quote! { .with_maximum(#m) });
                     quote! {
-                        turul_mcp_protocol::schema::JsonSchema::number() #description #min #max
+                        turul_mcp_protocol::schema::JsonSchema::number() #labels #min #max #default
                     }
                 }
                 "i64" | "i32" | "i16" | "i8" | "u64" | "u32" | "u16" | "u8" | "isize" | "usize" => {
@@ -429,35 +826,29 @@ pub fn type_to_schema(ty: &syn::Type, param_meta: &ParamMeta) -> TokenStream {
                         quote! { .with_maximum(#m_int as f64) }
                     });
                     quote! {
-                        turul_mcp_protocol::schema::JsonSchema::integer() #description #min #max
+                        turul_mcp_protocol::schema::JsonSchema::integer() #labels #min #max #default
                     }
                 }
                 "bool" => {
                     quote! {
-                        turul_mcp_protocol::schema::JsonSchema::boolean() #description
+                        turul_mcp_protocol::schema::JsonSchema::boolean() #labels #default
                     }
                 }
                 _ => {
-                    // Unknown type — use schemars to generate schema at runtime.
-                    // REQUIRES: the type must derive schemars::JsonSchema.
-                    // If it doesn't, compilation fails with a clear error pointing here.
-                    let full_ty = &type_path.path;
+                    // Unknown type — prefer its `ToJsonSchema` impl (enums with
+                    // `#[derive(JsonSchema)]`), otherwise use schemars at runtime.
+                    // REQUIRES: the type implements one of the two traits.
+                    // If it doesn't, compilation fails with an error pointing here.
                     quote! {
                         {
-                            let schemars_schema = turul_mcp_builders::schemars::schema_for!(#full_ty);
-                            let schema_value = serde_json::to_value(&schemars_schema)
-                                .expect("schemars schema should serialize to JSON");
-                            let definitions = schema_value.get("definitions")
-                                .or_else(|| schema_value.get("$defs"))
-                                .and_then(|v| v.as_object())
-                                .map(|obj| obj.iter()
-                                    .map(|(k, v)| (k.clone(), v.clone()))
-                                    .collect::<std::collections::HashMap<String, serde_json::Value>>())
-                                .unwrap_or_default();
-                            turul_mcp_builders::convert_value_to_json_schema_with_defs(
-                                &schema_value,
-                                &definitions,
-                            ) #description
+                            #[allow(unused_imports)]
+                            use turul_mcp_builders::schemars_helpers::{
+                                ParamSchemaFromSchemars as _, ParamSchemaFromTrait as _,
+                            };
+                            (&turul_mcp_builders::schemars_helpers::ParamSchemaProbe::<#ty>(
+                                std::marker::PhantomData,
+                            ))
+                                .param_schema() #labels
                         }
                     }
                 }
@@ -471,12 +862,12 @@ pub fn type_to_schema(ty: &syn::Type, param_meta: &ParamMeta) -> TokenStream {
                 turul_mcp_protocol::schema::JsonSchema::array(#items_schema)
                     .with_min_items(#len as u64)
                     .with_max_items(#len as u64)
-                    #description
+                    #labels
             }
         }
         _ => {
             quote! {
-                turul_mcp_protocol::schema::JsonSchema::string() #description
+                turul_mcp_protocol::schema::JsonSchema::string() #labels
             }
         }
     }
@@ -644,8 +1035,11 @@ fn generate_option_extraction(
         _ => {
             // Check if this is Option<Vec<T>>
             if let syn::Type::Path(inner_path) = inner_type
-                && inner_path.path.segments.len() == 1
-                && inner_path.path.segments[0].ident == "Vec"
+                && inner_path
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "Vec")
             {
                 return quote! {
                     let #field_name: Option<#inner_type> = args.get(#field_name_str)
@@ -735,7 +1129,11 @@ fn generate_required_extraction(
             }
         }
         syn::Type::Path(type_path)
-            if type_path.path.segments.len() == 1 && type_path.path.segments[0].ident == "Vec" =>
+            if type_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == "Vec") =>
         {
             quote! {
                 let #field_name: #field_type = args.get(#field_name_str)
@@ -1064,6 +1462,7 @@ pub fn generate_output_schema_for_type_with_field(ty: &syn::Type, field_name: &s
                             turul_mcp_protocol::tools::ToolSchema::object()
                                 .with_properties(HashMap::from([
                                     (#field_name.to_string(), JsonSchema::Object {
+                                        title: None,
                                         description: Some("JSON object or value".to_string()),
                                         properties: None,
                                         required: None,
@@ -1087,6 +1486,7 @@ pub fn generate_output_schema_for_type_with_field(ty: &syn::Type, field_name: &s
                             turul_mcp_protocol::tools::ToolSchema::object()
                                 .with_properties(HashMap::from([
                                     (#field_name.to_string(), JsonSchema::Array {
+                                        title: None,
                                         description: Some("Array of items".to_string()),
                                         items: None,
                                         min_items: None,
@@ -1401,6 +1801,7 @@ fn extract_struct_schema_content(
         turul_mcp_protocol::tools::ToolSchema::object()
             .with_properties(HashMap::from([
                 (#output_field_name.to_string(), JsonSchema::Object {
+                    title: None,
                     description: None,
                     properties: struct_schema.properties.clone(),
                     required: struct_schema.required.clone(),
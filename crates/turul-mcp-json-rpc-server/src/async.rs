@@ -1,7 +1,8 @@
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
+use futures::future::{AbortHandle, Abortable};
 use serde_json::Value;
 
 #[cfg(feature = "streams")]
@@ -10,10 +11,12 @@ use futures::{Stream, StreamExt};
 use std::pin::Pin;
 
 use crate::{
-    error::JsonRpcError,
+    codec::{JsonCodec, JsonRpcCodec},
+    error::{JsonRpcError, JsonRpcErrorCode, JsonRpcErrorObject},
     notification::JsonRpcNotification,
     request::{JsonRpcRequest, RequestParams},
     response::{JsonRpcMessage, ResponseResult},
+    types::RequestId,
 };
 
 /// Minimal session context for JSON-RPC handlers
@@ -191,6 +194,26 @@ where
 {
     pub handlers: HashMap<String, Arc<dyn JsonRpcHandler<Error = E>>>,
     pub default_handler: Option<Arc<dyn JsonRpcHandler<Error = E>>>,
+    /// Requests currently being handled, keyed by session, with the handle
+    /// that aborts them on `notifications/cancelled`
+    in_flight: Arc<Mutex<HashMap<(String, RequestId), AbortHandle>>>,
+    /// Wire codecs besides JSON that transports may negotiate
+    codecs: Vec<Arc<dyn JsonRpcCodec>>,
+}
+
+/// Method of the notification that cancels an in-flight request by `requestId`
+pub const CANCELLED_NOTIFICATION_METHOD: &str = "notifications/cancelled";
+
+/// Releases an in-flight request id when the response has been produced
+struct InFlightGuard {
+    in_flight: Arc<Mutex<HashMap<(String, RequestId), AbortHandle>>>,
+    key: (String, RequestId),
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.in_flight.lock().unwrap().remove(&self.key);
+    }
 }
 
 impl<E> JsonRpcDispatcher<E>
@@ -201,6 +224,62 @@ where
         Self {
             handlers: HashMap::new(),
             default_handler: None,
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+            codecs: Vec::new(),
+        }
+    }
+
+    /// Accept messages in `codec`'s format in addition to JSON
+    ///
+    /// Transports look the codec up by `Content-Type` with [`codec_for`](Self::codec_for),
+    /// decode the request with it and encode this dispatcher's reply with it, so a
+    /// binary request never passes through JSON text. A codec registered later for
+    /// the same content type takes precedence.
+    pub fn register_codec(&mut self, codec: Arc<dyn JsonRpcCodec>) {
+        self.codecs.insert(0, codec);
+    }
+
+    /// The codec for a `Content-Type` header value: JSON, or a registered codec
+    pub fn codec_for(&self, content_type: &str) -> Option<Arc<dyn JsonRpcCodec>> {
+        if JsonCodec.matches(content_type) {
+            return Some(Arc::new(JsonCodec));
+        }
+        self.codecs
+            .iter()
+            .find(|codec| codec.matches(content_type))
+            .cloned()
+    }
+
+    /// Mark `id` as in flight for `session_id`, or return `None` if it already is
+    fn claim_request_id(
+        &self,
+        session_id: &str,
+        id: &RequestId,
+        abort_handle: AbortHandle,
+    ) -> Option<InFlightGuard> {
+        let key = (session_id.to_string(), id.clone());
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight.contains_key(&key) {
+            return None;
+        }
+        in_flight.insert(key.clone(), abort_handle);
+        Some(InFlightGuard {
+            in_flight: self.in_flight.clone(),
+            key,
+        })
+    }
+
+    /// Abort the in-flight request `id` of `session_id`, dropping its handler future
+    ///
+    /// Returns `false` if no such request is running (it may already have completed).
+    pub fn cancel_request(&self, session_id: &str, id: &RequestId) -> bool {
+        let key = (session_id.to_string(), id.clone());
+        match self.in_flight.lock().unwrap().get(&key) {
+            Some(abort_handle) => {
+                abort_handle.abort();
+                true
+            }
+            None => false,
         }
     }
 
@@ -232,11 +311,34 @@ where
     }
 
     /// Process a JSON-RPC request with session context and return a response
+    ///
+    /// Returns `None` when the request was aborted by `notifications/cancelled`:
+    /// the client has stopped waiting, so no response is sent for it.
     pub async fn handle_request_with_context(
         &self,
         request: JsonRpcRequest,
         session_context: SessionContext,
-    ) -> JsonRpcMessage {
+    ) -> Option<JsonRpcMessage> {
+        // Ids must be unique among a session's outstanding requests; the guard
+        // frees the id once this call returns its response
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        let Some(_guard) =
+            self.claim_request_id(&session_context.session_id, &request.id, abort_handle)
+        else {
+            let error_object = JsonRpcErrorObject::new(
+                JsonRpcErrorCode::InvalidRequest,
+                Some(format!(
+                    "Request id {} is already in flight for this session",
+                    request.id
+                )),
+                None,
+            );
+            return Some(JsonRpcMessage::error(JsonRpcError::new(
+                Some(request.id),
+                error_object,
+            )));
+        };
+
         let handler = self
             .handlers
             .get(&request.method)
@@ -244,24 +346,25 @@ where
 
         match handler {
             Some(handler) => {
-                match handler
-                    .handle(&request.method, request.params, Some(session_context))
-                    .await
-                {
-                    Ok(result) => {
-                        JsonRpcMessage::success(request.id, ResponseResult::Success(result))
-                    }
-                    Err(domain_error) => {
+                let call = handler.handle(&request.method, request.params, Some(session_context));
+                match Abortable::new(call, abort_registration).await {
+                    Ok(Ok(result)) => Some(JsonRpcMessage::success(
+                        request.id,
+                        ResponseResult::Success(result),
+                    )),
+                    Ok(Err(domain_error)) => {
                         // Convert domain error to JSON-RPC error using type-safe conversion
                         let error_object = domain_error.to_error_object();
                         let rpc_error = JsonRpcError::new(Some(request.id.clone()), error_object);
-                        JsonRpcMessage::error(rpc_error)
+                        Some(JsonRpcMessage::error(rpc_error))
                     }
+                    // Cancelled requests are not answered
+                    Err(_aborted) => None,
                 }
             }
             None => {
                 let error = JsonRpcError::method_not_found(request.id.clone(), &request.method);
-                JsonRpcMessage::error(error)
+                Some(JsonRpcMessage::error(error))
             }
         }
     }
@@ -315,11 +418,23 @@ where
     }
 
     /// Process a JSON-RPC notification with session context
+    ///
+    /// `notifications/cancelled` first aborts the session's in-flight request
+    /// named by `requestId`, then reaches any handler registered for it.
     pub async fn handle_notification_with_context(
         &self,
         notification: JsonRpcNotification,
         session_context: Option<SessionContext>,
     ) -> Result<(), E> {
+        if notification.method == CANCELLED_NOTIFICATION_METHOD
+            && let Some(ref context) = session_context
+            && let Some(request_id) = notification
+                .get_param("requestId")
+                .and_then(|id| serde_json::from_value::<RequestId>(id.clone()).ok())
+        {
+            self.cancel_request(&context.session_id, &request_id);
+        }
+
         let handler = self
             .handlers
             .get(&notification.method)
@@ -424,6 +539,116 @@ mod tests {
         assert!(response.is_error());
     }
 
+    struct SlowHandler;
+
+    #[async_trait]
+    impl JsonRpcHandler for SlowHandler {
+        type Error = TestError;
+
+        async fn handle(
+            &self,
+            _method: &str,
+            _params: Option<RequestParams>,
+            _session_context: Option<SessionContext>,
+        ) -> Result<Value, Self::Error> {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            Ok(json!({"result": "slow"}))
+        }
+    }
+
+    fn session(id: &str) -> SessionContext {
+        SessionContext {
+            session_id: id.to_string(),
+            metadata: HashMap::new(),
+            broadcaster: None,
+            timestamp: 0,
+            extensions: HashMap::new(),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dispatcher_rejects_duplicate_in_flight_id() {
+        let mut dispatcher: JsonRpcDispatcher<TestError> = JsonRpcDispatcher::new();
+        dispatcher.register_method("slow".to_string(), SlowHandler);
+        let dispatcher = Arc::new(dispatcher);
+        let request = || JsonRpcRequest::new_no_params(RequestId::Number(7), "slow".to_string());
+
+        let first = tokio::spawn({
+            let dispatcher = dispatcher.clone();
+            async move {
+                dispatcher
+                    .handle_request_with_context(request(), session("s1"))
+                    .await
+            }
+        });
+        tokio::task::yield_now().await;
+
+        let duplicate = dispatcher
+            .handle_request_with_context(request(), session("s1"))
+            .await
+            .unwrap();
+        match duplicate {
+            JsonRpcMessage::Error(error) => {
+                assert_eq!(error.id, Some(RequestId::Number(7)));
+                assert_eq!(error.error.code, -32600);
+            }
+            other => panic!("expected InvalidRequest, got {:?}", other),
+        }
+
+        // The same id in another session is independent
+        let other_session = dispatcher
+            .handle_request_with_context(request(), session("s2"))
+            .await
+            .unwrap();
+        assert!(!other_session.is_error());
+
+        assert!(!first.await.unwrap().unwrap().is_error());
+
+        // Once answered, the id can be reused
+        let reused = dispatcher
+            .handle_request_with_context(request(), session("s1"))
+            .await
+            .unwrap();
+        assert!(!reused.is_error());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancelled_notification_aborts_in_flight_request() {
+        let mut dispatcher: JsonRpcDispatcher<TestError> = JsonRpcDispatcher::new();
+        dispatcher.register_method("slow".to_string(), SlowHandler);
+        let dispatcher = Arc::new(dispatcher);
+
+        let call = tokio::spawn({
+            let dispatcher = dispatcher.clone();
+            async move {
+                let request =
+                    JsonRpcRequest::new_no_params(RequestId::Number(9), "slow".to_string());
+                dispatcher
+                    .handle_request_with_context(request, session("s1"))
+                    .await
+            }
+        });
+        tokio::task::yield_now().await;
+
+        // A cancellation from another session does not touch it
+        assert!(!dispatcher.cancel_request("s2", &RequestId::Number(9)));
+
+        let mut params = HashMap::new();
+        params.insert("requestId".to_string(), json!(9));
+        let cancelled = JsonRpcNotification::new_with_object_params(
+            CANCELLED_NOTIFICATION_METHOD.to_string(),
+            params,
+        );
+        dispatcher
+            .handle_notification_with_context(cancelled, Some(session("s1")))
+            .await
+            .unwrap();
+
+        // The aborted request gets no response at all
+        assert!(call.await.unwrap().is_none());
+        assert!(!dispatcher.cancel_request("s1", &RequestId::Number(9)));
+    }
+
     #[tokio::test]
     async fn test_function_handler() {
         // Test JsonRpcHandler directly
@@ -0,0 +1,269 @@
+//! Wire codecs for JSON-RPC messages
+//!
+//! A codec decodes a request body straight into a [`JsonRpcMessage`] and
+//! encodes the dispatcher's response the same way, with no JSON text in
+//! between. [`JsonCodec`] is the default; with the `msgpack` feature,
+//! [`MessagePackCodec`] carries the same messages as MessagePack for
+//! transports that negotiate it by `Content-Type`. Handlers never see which
+//! codec was used.
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::dispatch::{JsonRpcMessage, parse_json_rpc_message};
+use crate::error::JsonRpcError;
+use crate::notification::JsonRpcNotification;
+use crate::request::{JsonRpcRequest, RequestParams};
+use crate::response::JsonRpcMessage as JsonRpcReply;
+use crate::types::{JsonRpcVersion, RequestId};
+
+/// Content type of the default JSON codec
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Content type of the MessagePack codec
+pub const MSGPACK_CONTENT_TYPE: &str = "application/msgpack";
+
+/// Failure to encode a message
+#[derive(Debug, Error)]
+#[error("Failed to encode {content_type} message: {message}")]
+pub struct CodecError {
+    pub content_type: &'static str,
+    pub message: String,
+}
+
+/// Serialization format of JSON-RPC messages on the wire
+pub trait JsonRpcCodec: Send + Sync {
+    /// Media type this codec reads and writes, e.g. `application/json`
+    fn content_type(&self) -> &'static str;
+
+    /// Decode a request or notification body
+    ///
+    /// Fails with the JSON-RPC error to send back: a parse error for bytes
+    /// that are not a message at all, an invalid request otherwise.
+    fn decode_message(&self, bytes: &[u8]) -> Result<JsonRpcMessage, JsonRpcError>;
+
+    /// Encode a response or error
+    fn encode_reply(&self, reply: &JsonRpcReply) -> Result<Vec<u8>, CodecError>;
+
+    /// Whether a `Content-Type` header value names this codec (parameters ignored)
+    fn matches(&self, content_type: &str) -> bool {
+        content_type
+            .split(';')
+            .next()
+            .is_some_and(|media_type| media_type.trim().eq_ignore_ascii_case(self.content_type()))
+    }
+}
+
+/// JSON, the default codec
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl JsonRpcCodec for JsonCodec {
+    fn content_type(&self) -> &'static str {
+        JSON_CONTENT_TYPE
+    }
+
+    fn decode_message(&self, bytes: &[u8]) -> Result<JsonRpcMessage, JsonRpcError> {
+        let text = std::str::from_utf8(bytes).map_err(|_| JsonRpcError::parse_error())?;
+        parse_json_rpc_message(text)
+    }
+
+    fn encode_reply(&self, reply: &JsonRpcReply) -> Result<Vec<u8>, CodecError> {
+        serde_json::to_vec(reply).map_err(|e| CodecError {
+            content_type: JSON_CONTENT_TYPE,
+            message: e.to_string(),
+        })
+    }
+}
+
+/// A request or notification as it is laid out on the wire
+///
+/// Self-describing binary codecs deserialize into this directly; a present
+/// `id` makes it a request.
+#[cfg_attr(not(feature = "msgpack"), allow(dead_code))]
+#[derive(Deserialize)]
+struct WireMessage {
+    #[serde(rename = "jsonrpc")]
+    version: JsonRpcVersion,
+    id: Option<RequestId>,
+    method: String,
+    params: Option<RequestParams>,
+}
+
+#[cfg_attr(not(feature = "msgpack"), allow(dead_code))]
+impl From<WireMessage> for JsonRpcMessage {
+    fn from(wire: WireMessage) -> Self {
+        match wire.id {
+            Some(id) => JsonRpcMessage::Request(JsonRpcRequest {
+                version: wire.version,
+                id,
+                method: wire.method,
+                params: wire.params,
+            }),
+            None => JsonRpcMessage::Notification(JsonRpcNotification {
+                version: wire.version,
+                method: wire.method,
+                params: wire.params,
+            }),
+        }
+    }
+}
+
+/// MessagePack (`application/msgpack`); objects are encoded as maps keyed by field name
+#[cfg(feature = "msgpack")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MessagePackCodec;
+
+#[cfg(feature = "msgpack")]
+impl JsonRpcCodec for MessagePackCodec {
+    fn content_type(&self) -> &'static str {
+        MSGPACK_CONTENT_TYPE
+    }
+
+    fn decode_message(&self, bytes: &[u8]) -> Result<JsonRpcMessage, JsonRpcError> {
+        match rmp_serde::from_slice::<WireMessage>(bytes) {
+            Ok(wire) => Ok(wire.into()),
+            // Well-formed MessagePack that is not a JSON-RPC message
+            Err(rmp_serde::decode::Error::Syntax(_)) => Err(JsonRpcError::invalid_request(None)),
+            Err(_) => Err(JsonRpcError::parse_error()),
+        }
+    }
+
+    fn encode_reply(&self, reply: &JsonRpcReply) -> Result<Vec<u8>, CodecError> {
+        rmp_serde::to_vec_named(reply).map_err(|e| CodecError {
+            content_type: MSGPACK_CONTENT_TYPE,
+            message: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_codec_round_trip() {
+        let message = JsonCodec
+            .decode_message(br#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#)
+            .unwrap();
+        let JsonRpcMessage::Request(request) = message else {
+            panic!("Expected a request");
+        };
+        assert_eq!(request.id, RequestId::Number(1));
+
+        let reply = JsonRpcReply::success(request.id, json!({}).into());
+        assert_eq!(
+            JsonCodec.encode_reply(&reply).unwrap(),
+            br#"{"jsonrpc":"2.0","id":1,"result":{}}"#
+        );
+
+        assert_eq!(
+            JsonCodec
+                .decode_message(b"{not json")
+                .unwrap_err()
+                .error
+                .code,
+            -32700
+        );
+    }
+
+    #[test]
+    fn test_matches_ignores_parameters_and_case() {
+        assert!(JsonCodec.matches("application/json"));
+        assert!(JsonCodec.matches("Application/JSON; charset=utf-8"));
+        assert!(!JsonCodec.matches("application/msgpack"));
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_round_trip_is_byte_stable() {
+        use crate::response::JsonRpcResponse;
+
+        let request: JsonRpcRequest = serde_json::from_value(json!({
+            "jsonrpc": "2.0",
+            "id": 7,
+            "method": "tools/call",
+            "params": {
+                "name": "resize",
+                "arguments": { "width": 640, "ratio": 1.5, "tags": ["a", "b"], "crop": null }
+            }
+        }))
+        .unwrap();
+        let request_bytes = rmp_serde::to_vec_named(&request).unwrap();
+
+        let JsonRpcMessage::Request(decoded) =
+            MessagePackCodec.decode_message(&request_bytes).unwrap()
+        else {
+            panic!("Expected a request");
+        };
+        assert_eq!(decoded.id, RequestId::Number(7));
+        assert_eq!(decoded.method, "tools/call");
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(&request).unwrap()
+        );
+        // Re-encoding the decoded request reproduces the original bytes
+        assert_eq!(rmp_serde::to_vec_named(&decoded).unwrap(), request_bytes);
+
+        let response = JsonRpcResponse::success(
+            RequestId::Number(7),
+            json!({
+                "content": [{ "type": "text", "text": "résumé ✓" }],
+                "isError": false,
+                "structuredContent": { "bytes": -12, "big": u64::MAX }
+            }),
+        );
+        let reply = JsonRpcReply::Response(response.clone());
+        let reply_bytes = MessagePackCodec.encode_reply(&reply).unwrap();
+        assert_ne!(reply_bytes, JsonCodec.encode_reply(&reply).unwrap());
+
+        let decoded: JsonRpcResponse = rmp_serde::from_slice(&reply_bytes).unwrap();
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(&response).unwrap()
+        );
+        assert_eq!(
+            MessagePackCodec
+                .encode_reply(&JsonRpcReply::Response(decoded))
+                .unwrap(),
+            reply_bytes
+        );
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_decodes_notifications() {
+        let bytes = rmp_serde::to_vec_named(&json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/initialized"
+        }))
+        .unwrap();
+        let message = MessagePackCodec.decode_message(&bytes).unwrap();
+        assert!(message.is_notification());
+        assert_eq!(message.method(), "notifications/initialized");
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_rejects_garbage() {
+        assert_eq!(
+            MessagePackCodec
+                .decode_message(&[0xc1])
+                .unwrap_err()
+                .error
+                .code,
+            -32700
+        );
+        let not_a_message = rmp_serde::to_vec_named(&json!({ "jsonrpc": "2.0", "id": 1 })).unwrap();
+        assert_eq!(
+            MessagePackCodec
+                .decode_message(&not_a_message)
+                .unwrap_err()
+                .error
+                .code,
+            -32600
+        );
+        assert!(MessagePackCodec.matches("application/msgpack"));
+    }
+}
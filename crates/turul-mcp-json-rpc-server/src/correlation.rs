@@ -0,0 +1,243 @@
+//! Correlation between a request and its progress/cancellation notifications.
+//!
+//! `notifications/progress` carries only the opaque `progressToken` chosen by the
+//! requester, and `notifications/cancelled` carries only the request id. A
+//! [`Correlation`] ties the two together (plus the session) by encoding them into
+//! the progress token in a single, parseable format shared by client and server:
+//!
+//! ```text
+//! corr:<session-id>:<n|s>:<request-id>
+//! ```
+//!
+//! The session segment is empty when there is no session; `n`/`s` records whether
+//! the request id was a number or a string so it round-trips exactly.
+//!
+//! ```rust
+//! use turul_mcp_json_rpc_server::{Correlation, RequestId};
+//!
+//! let correlation = Correlation::new(42).with_session("session-abc");
+//! let token = correlation.progress_token();
+//! assert_eq!(token, "corr:session-abc:n:42");
+//!
+//! let parsed = Correlation::parse(&token).unwrap();
+//! assert_eq!(parsed.request_id(), &RequestId::Number(42));
+//! assert_eq!(parsed.session_id(), Some("session-abc"));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+use crate::types::RequestId;
+
+/// Prefix identifying a progress token generated by [`Correlation`]
+pub const CORRELATION_TOKEN_PREFIX: &str = "corr";
+
+/// Error returned when a progress token is not a valid correlation token
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CorrelationError {
+    #[error("Progress token is not a correlation token: {0}")]
+    UnrecognizedFormat(String),
+    #[error("Invalid request id kind '{kind}' in progress token: {token}")]
+    InvalidIdKind { kind: String, token: String },
+    #[error("Invalid numeric request id in progress token: {0}")]
+    InvalidNumericId(String),
+    #[error("Invalid escape sequence in progress token session: {0}")]
+    InvalidEscape(String),
+}
+
+/// Links a request id (and optionally its session) to the progress token used for
+/// its `notifications/progress`, so either side can recover the originating request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Correlation {
+    session_id: Option<String>,
+    request_id: RequestId,
+}
+
+impl Correlation {
+    /// Create a correlation for a request id with no session
+    pub fn new(request_id: impl Into<RequestId>) -> Self {
+        Self {
+            session_id: None,
+            request_id: request_id.into(),
+        }
+    }
+
+    /// Scope the correlation to a session
+    pub fn with_session(mut self, session_id: impl Into<String>) -> Self {
+        self.session_id = Some(session_id.into());
+        self
+    }
+
+    /// The originating request id
+    pub fn request_id(&self) -> &RequestId {
+        &self.request_id
+    }
+
+    /// The session the request was made on, if any
+    pub fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
+    /// Encode this correlation as a `progressToken`
+    pub fn progress_token(&self) -> String {
+        let session = self
+            .session_id
+            .as_deref()
+            .map(escape_session)
+            .unwrap_or_default();
+        let (kind, id) = match &self.request_id {
+            RequestId::Number(n) => ('n', n.to_string()),
+            RequestId::String(s) => ('s', s.clone()),
+        };
+        format!("{}:{}:{}:{}", CORRELATION_TOKEN_PREFIX, session, kind, id)
+    }
+
+    /// Recover a correlation from a `progressToken` produced by [`progress_token`](Self::progress_token)
+    pub fn parse(token: &str) -> Result<Self, CorrelationError> {
+        let unrecognized = || CorrelationError::UnrecognizedFormat(token.to_string());
+
+        let mut parts = token.splitn(4, ':');
+        if parts.next() != Some(CORRELATION_TOKEN_PREFIX) {
+            return Err(unrecognized());
+        }
+        let session = parts.next().ok_or_else(unrecognized)?;
+        let kind = parts.next().ok_or_else(unrecognized)?;
+        let id = parts.next().ok_or_else(unrecognized)?;
+
+        let request_id = match kind {
+            "n" => id
+                .parse::<i64>()
+                .map(RequestId::Number)
+                .map_err(|_| CorrelationError::InvalidNumericId(token.to_string()))?,
+            "s" => RequestId::String(id.to_string()),
+            other => {
+                return Err(CorrelationError::InvalidIdKind {
+                    kind: other.to_string(),
+                    token: token.to_string(),
+                });
+            }
+        };
+
+        let session_id = if session.is_empty() {
+            None
+        } else {
+            Some(
+                unescape_session(session)
+                    .ok_or_else(|| CorrelationError::InvalidEscape(token.to_string()))?,
+            )
+        };
+
+        Ok(Self {
+            session_id,
+            request_id,
+        })
+    }
+}
+
+impl fmt::Display for Correlation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.progress_token())
+    }
+}
+
+impl FromStr for Correlation {
+    type Err = CorrelationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Escape `%` and `:` so a session id cannot break the token's field separators
+fn escape_session(session_id: &str) -> String {
+    session_id.replace('%', "%25").replace(':', "%3A")
+}
+
+fn unescape_session(escaped: &str) -> Option<String> {
+    let mut out = String::with_capacity(escaped.len());
+    let mut rest = escaped;
+    while let Some(pos) = rest.find('%') {
+        out.push_str(&rest[..pos]);
+        match rest.get(pos..pos + 3) {
+            Some("%25") => out.push('%'),
+            Some("%3A") => out.push(':'),
+            _ => return None,
+        }
+        rest = &rest[pos + 3..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_numeric_id_with_session() {
+        let correlation = Correlation::new(7).with_session("0195f3a2-session");
+        let parsed = Correlation::parse(&correlation.progress_token()).unwrap();
+        assert_eq!(parsed, correlation);
+        assert_eq!(parsed.request_id(), &RequestId::Number(7));
+    }
+
+    #[test]
+    fn test_round_trip_string_id_without_session() {
+        let correlation = Correlation::new("req_12");
+        let token = correlation.progress_token();
+        assert_eq!(token, "corr::s:req_12");
+
+        let parsed: Correlation = token.parse().unwrap();
+        assert_eq!(parsed.session_id(), None);
+        assert_eq!(
+            parsed.request_id(),
+            &RequestId::String("req_12".to_string())
+        );
+    }
+
+    #[test]
+    fn test_numeric_looking_string_id_keeps_its_type() {
+        let correlation = Correlation::new("42");
+        let parsed = Correlation::parse(&correlation.progress_token()).unwrap();
+        assert_eq!(parsed.request_id(), &RequestId::String("42".to_string()));
+    }
+
+    #[test]
+    fn test_separators_in_session_and_id_round_trip() {
+        let correlation = Correlation::new("a:b:c").with_session("tenant:1%x");
+        let token = correlation.progress_token();
+        assert_eq!(token, "corr:tenant%3A1%25x:s:a:b:c");
+        assert_eq!(Correlation::parse(&token).unwrap(), correlation);
+    }
+
+    #[test]
+    fn test_mismatched_formats_rejected() {
+        // Hand-rolled formats that predate Correlation
+        assert!(matches!(
+            Correlation::parse("client-session-1"),
+            Err(CorrelationError::UnrecognizedFormat(_))
+        ));
+        assert!(matches!(
+            Correlation::parse("token-123"),
+            Err(CorrelationError::UnrecognizedFormat(_))
+        ));
+        // Right prefix, missing fields
+        assert!(matches!(
+            Correlation::parse("corr:session"),
+            Err(CorrelationError::UnrecognizedFormat(_))
+        ));
+        assert!(matches!(
+            Correlation::parse("corr:session:x:1"),
+            Err(CorrelationError::InvalidIdKind { .. })
+        ));
+        assert!(matches!(
+            Correlation::parse("corr:session:n:abc"),
+            Err(CorrelationError::InvalidNumericId(_))
+        ));
+        assert!(matches!(
+            Correlation::parse("corr:bad%zz:n:1"),
+            Err(CorrelationError::InvalidEscape(_))
+        ));
+    }
+}
@@ -47,7 +47,11 @@ impl JsonRpcMessageResult {
 /// Parse a JSON string into a JSON-RPC message
 pub fn parse_json_rpc_message(json_str: &str) -> Result<JsonRpcMessage, JsonRpcError> {
     let value: Value = serde_json::from_str(json_str).map_err(|_| JsonRpcError::parse_error())?;
+    parse_json_rpc_value(value)
+}
 
+/// Parse an already-decoded JSON value into a JSON-RPC message
+fn parse_json_rpc_value(value: Value) -> Result<JsonRpcMessage, JsonRpcError> {
     // Check if it's a valid JSON-RPC message
     if !value.is_object() {
         return Err(JsonRpcError::invalid_request(None));
@@ -113,10 +117,17 @@ impl JsonRpcMessage {
 }
 
 /// Parse multiple JSON-RPC messages from a single JSON string
-/// This handles both single messages and potential future batch support
+/// A JSON array is parsed element by element; anything else is a single message.
+/// An empty array yields a single invalid-request error.
 pub fn parse_json_rpc_messages(json_str: &str) -> Vec<Result<JsonRpcMessage, JsonRpcError>> {
-    // For now, we only support single messages (JSON-RPC 2.0 removed batch support)
-    vec![parse_json_rpc_message(json_str)]
+    match serde_json::from_str::<Value>(json_str) {
+        Ok(Value::Array(items)) if items.is_empty() => {
+            vec![Err(JsonRpcError::invalid_request(None))]
+        }
+        Ok(Value::Array(items)) => items.into_iter().map(parse_json_rpc_value).collect(),
+        Ok(value) => vec![parse_json_rpc_value(value)],
+        Err(_) => vec![Err(JsonRpcError::parse_error())],
+    }
 }
 
 /// Create a simple success response
@@ -183,6 +194,29 @@ mod tests {
         assert_eq!(error.error.code, -32600); // Invalid request
     }
 
+    #[test]
+    fn test_parse_batch_messages() {
+        let json = r#"[
+            {"jsonrpc": "2.0", "method": "a", "id": 1},
+            {"jsonrpc": "2.0", "method": "b"},
+            {"jsonrpc": "1.0", "method": "c", "id": 3}
+        ]"#;
+        let messages = parse_json_rpc_messages(json);
+
+        assert_eq!(messages.len(), 3);
+        assert!(messages[0].as_ref().unwrap().is_request());
+        assert!(messages[1].as_ref().unwrap().is_notification());
+        assert_eq!(messages[2].as_ref().unwrap_err().error.code, -32600);
+
+        let single = parse_json_rpc_messages(r#"{"jsonrpc": "2.0", "method": "a", "id": 1}"#);
+        assert_eq!(single.len(), 1);
+        assert!(single[0].is_ok());
+
+        let empty = parse_json_rpc_messages("[]");
+        assert_eq!(empty.len(), 1);
+        assert_eq!(empty[0].as_ref().unwrap_err().error.code, -32600);
+    }
+
     #[test]
     fn test_message_result_to_json() {
         let response = create_success_response(RequestId::Number(1), json!({"result": "success"}));
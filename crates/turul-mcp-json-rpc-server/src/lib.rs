@@ -10,6 +10,7 @@
 //! - **Transport Agnostic**: Works with HTTP, TCP, and other transport layers.
 //! - **Async/Await Support**: Full async support with session context
 //! - **Zero Double-Wrapping**: Clean error handling without intermediate wrappers
+//! - **Pluggable Codecs**: JSON by default, MessagePack with the `msgpack` feature
 //!
 //! ## Architecture
 //!
@@ -50,6 +51,8 @@
 //! // let dispatcher = JsonRpcDispatcher::new(); // Actual usage requires ToJsonRpcError trait
 //! ```
 
+pub mod codec;
+pub mod correlation;
 pub mod dispatch;
 pub mod error;
 pub mod notification;
@@ -62,6 +65,12 @@ pub mod types;
 pub mod r#async;
 
 // Re-export main types
+/// Wire codecs for JSON-RPC message bodies
+#[cfg(feature = "msgpack")]
+pub use codec::MessagePackCodec;
+pub use codec::{CodecError, JsonCodec, JsonRpcCodec};
+/// Request/progress-token correlation shared by MCP clients and servers
+pub use correlation::{Correlation, CorrelationError};
 /// JSON-RPC 2.0 error types and standard error codes
 pub use error::{JsonRpcError, JsonRpcErrorCode};
 /// JSON-RPC notification message structure for fire-and-forget communications
@@ -74,7 +83,9 @@ pub use response::{JsonRpcMessage, JsonRpcResponse, ResponseResult};
 pub use types::{JsonRpcVersion, RequestId};
 
 #[cfg(feature = "async")]
-pub use r#async::{JsonRpcDispatcher, JsonRpcHandler, SessionContext};
+pub use r#async::{
+    CANCELLED_NOTIFICATION_METHOD, JsonRpcDispatcher, JsonRpcHandler, SessionContext,
+};
 
 #[cfg(feature = "streams")]
 pub use r#async::streaming::{JsonRpcFrame, StreamingJsonRpcDispatcher, StreamingJsonRpcHandler};
@@ -8,6 +8,7 @@
 //! ```
 
 // Core JSON-RPC types
+pub use crate::correlation::{Correlation, CorrelationError};
 pub use crate::error::{JsonRpcError, JsonRpcErrorCode};
 pub use crate::notification::JsonRpcNotification;
 pub use crate::request::{JsonRpcRequest, RequestParams};
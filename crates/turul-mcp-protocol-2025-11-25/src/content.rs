@@ -3,6 +3,7 @@
 //! This module contains the exact content type definitions from the MCP spec,
 //! ensuring perfect compliance with the TypeScript schema definitions.
 
+use base64::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -222,6 +223,28 @@ impl ContentBlock {
         }
     }
 
+    /// Create an embedded text resource, e.g. a JSON or Markdown document
+    pub fn embedded_text_resource(
+        uri: impl Into<String>,
+        text: impl Into<String>,
+        mime_type: impl Into<String>,
+    ) -> Self {
+        Self::resource(ResourceContents::text_with_mime(uri, text, mime_type))
+    }
+
+    /// Create an embedded binary resource; `bytes` are base64-encoded into the blob
+    pub fn embedded_resource(
+        uri: impl Into<String>,
+        bytes: impl AsRef<[u8]>,
+        mime_type: impl Into<String>,
+    ) -> Self {
+        Self::resource(ResourceContents::blob(
+            uri,
+            BASE64_STANDARD.encode(bytes),
+            mime_type,
+        ))
+    }
+
     /// Create tool use content block
     pub fn tool_use(
         id: impl Into<String>,
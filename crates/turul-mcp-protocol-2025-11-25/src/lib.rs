@@ -74,36 +74,16 @@ pub use meta::{Annotations, Meta};
 mod compliance_test;
 
 // Re-export main types
+pub use elicitation::{
+    ElicitAction, ElicitCreateParams, ElicitCreateRequest, ElicitResult, ElicitationBuilder,
+    ElicitationSchema, PrimitiveSchemaDefinition, StringFormat,
+};
 pub use icons::{Icon, IconTheme};
 pub use initialize::{
     ClientCapabilities, Implementation, InitializeRequest, InitializeResult, ServerCapabilities,
     TasksCancelCapabilities, TasksCapabilities, TasksListCapabilities, TasksRequestCapabilities,
     TasksToolCallCapabilities, TasksToolCapabilities,
 };
-pub use prompts::{
-    GetPromptRequest, GetPromptResult, ListPromptsRequest, ListPromptsResult, Prompt,
-    PromptArgument, PromptMessage,
-};
-pub use resources::{
-    ListResourcesRequest, ListResourcesResult, ReadResourceRequest, ReadResourceResult, Resource,
-    ResourceContent, ResourceSubscription, SubscribeRequest, UnsubscribeRequest,
-};
-pub use tasks::{
-    CancelTaskParams, CancelTaskRequest, CancelTaskResult, CreateTaskResult, GetTaskParams,
-    GetTaskPayloadParams, GetTaskPayloadRequest, GetTaskRequest, GetTaskResult, ListTasksParams,
-    ListTasksRequest, ListTasksResult, Task, TaskMetadata, TaskStatus,
-};
-pub use tools::{
-    CallToolRequest, CallToolResult, ListToolsRequest, ListToolsResult, TaskSupport, Tool,
-    ToolExecution, ToolResult, ToolSchema,
-};
-pub use version::McpVersion;
-// ResourceTemplate functionality is now part of resources module
-// pub use resources::{ResourceTemplate, ListResourceTemplatesRequest, ListResourceTemplatesResult};
-pub use elicitation::{
-    ElicitAction, ElicitCreateParams, ElicitCreateRequest, ElicitResult, ElicitationBuilder,
-    ElicitationSchema, PrimitiveSchemaDefinition, StringFormat,
-};
 pub use json_rpc::{
     JsonRpcError, JsonRpcMessage, JsonRpcNotification, JsonRpcRequest, JsonRpcResponse,
     RequestParams, ResultWithMeta,
@@ -120,11 +100,30 @@ pub use notifications::{
     ToolListChangedNotification,
 };
 pub use ping::{EmptyParams, EmptyResult, PingRequest};
+pub use prompts::{
+    GetPromptRequest, GetPromptResult, ListPromptsRequest, ListPromptsResult, Prompt,
+    PromptArgument, PromptMessage,
+};
+pub use resources::{
+    ListResourceTemplatesParams, ListResourceTemplatesRequest, ListResourceTemplatesResult,
+    ListResourcesRequest, ListResourcesResult, ReadResourceRequest, ReadResourceResult, Resource,
+    ResourceContent, ResourceSubscription, ResourceTemplate, SubscribeRequest, UnsubscribeRequest,
+};
 pub use schema::JsonSchema;
+pub use tasks::{
+    CancelTaskParams, CancelTaskRequest, CancelTaskResult, CreateTaskResult, GetTaskParams,
+    GetTaskPayloadParams, GetTaskPayloadRequest, GetTaskRequest, GetTaskResult, ListTasksParams,
+    ListTasksRequest, ListTasksResult, Task, TaskMetadata, TaskStatus,
+};
+pub use tools::{
+    CallToolRequest, CallToolResult, ListToolsRequest, ListToolsResult, TaskSupport, Tool,
+    ToolExecution, ToolResult, ToolSchema,
+};
 pub use traits::{
     HasData, HasDataParam, HasMeta, HasMetaParam, HasProgressTokenParam, JsonRpcNotificationTrait,
     JsonRpcRequestTrait, JsonRpcResponseTrait, Params, RpcResult,
 };
+pub use version::McpVersion;
 
 // JSON-RPC foundation (legacy - prefer our implementations above)
 pub use turul_mcp_json_rpc_server::{
@@ -181,6 +180,13 @@ pub enum McpError {
     #[error("Tool execution failed: {0}")]
     ToolExecutionError(String),
 
+    /// The tool ran and reports failure with content for the client to show.
+    ///
+    /// `tools/call` answers with a `CallToolResult` whose `isError` is `true`
+    /// rather than a JSON-RPC error.
+    #[error("Tool reported an error: {}", content_text(.0))]
+    ToolErrorContent(Vec<content::ContentBlock>),
+
     #[error("Resource execution failed: {0}")]
     ResourceExecutionError(String),
 
@@ -223,6 +229,18 @@ pub enum McpError {
     },
 }
 
+/// Text blocks joined for messages; other block types are left out
+fn content_text(blocks: &[content::ContentBlock]) -> String {
+    blocks
+        .iter()
+        .filter_map(|block| match block {
+            content::ContentBlock::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 impl From<String> for McpError {
     fn from(message: String) -> Self {
         Self::ToolExecutionError(message)
@@ -264,6 +282,11 @@ impl McpError {
         Self::ToolExecutionError(message.to_string())
     }
 
+    /// Create a tool failure reported as `isError: true` content instead of a JSON-RPC error
+    pub fn tool_error_content(blocks: Vec<content::ContentBlock>) -> Self {
+        Self::ToolErrorContent(blocks)
+    }
+
     /// Create a resource execution error
     pub fn resource_execution(message: &str) -> Self {
         Self::ResourceExecutionError(message.to_string())
@@ -361,6 +384,14 @@ impl McpError {
                 &format!("Tool execution failed: {}", msg),
                 None,
             ),
+            // Outside tools/call the content travels as error data
+            McpError::ToolErrorContent(blocks) => JsonRpcErrorObject::server_error(
+                -32010,
+                &format!("Tool execution failed: {}", content_text(blocks)),
+                serde_json::to_value(blocks)
+                    .ok()
+                    .map(|content| serde_json::json!({ "content": content })),
+            ),
             McpError::ResourceExecutionError(msg) => JsonRpcErrorObject::server_error(
                 -32012,
                 &format!("Resource execution failed: {}", msg),
@@ -430,7 +461,13 @@ impl McpError {
                 code,
                 message,
                 data,
-            } => JsonRpcErrorObject::server_error(*code, message, data.clone()),
+            } => JsonRpcErrorObject {
+                // Built directly: `server_error()` only accepts -32099..=-32000,
+                // but a preserved error may carry any code (e.g. -32602)
+                code: *code,
+                message: message.clone(),
+                data: data.clone(),
+            },
         }
     }
 
@@ -3,7 +3,13 @@
 //! This module provides JSON Schema types used throughout the MCP protocol.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+/// Prefix of `$ref` pointers into a root schema's `$defs`
+pub const DEFS_REF_PREFIX: &str = "#/$defs/";
+
+/// Shared sub-schemas emitted under a root schema's `$defs`, keyed by type name
+pub type SchemaDefinitions = BTreeMap<String, JsonSchema>;
 
 /// Trait for generating JSON schemas from Rust types
 pub trait JsonSchemaGenerator {
@@ -17,6 +23,8 @@ pub trait JsonSchemaGenerator {
 pub enum JsonSchema {
     /// String type
     String {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,32 +35,48 @@ pub enum JsonSchema {
         max_length: Option<u64>,
         #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
         enum_values: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        default: Option<String>,
     },
     /// Number type
     Number {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         minimum: Option<f64>,
         #[serde(skip_serializing_if = "Option::is_none")]
         maximum: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        default: Option<f64>,
     },
     /// Integer type
     Integer {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         minimum: Option<i64>,
         #[serde(skip_serializing_if = "Option::is_none")]
         maximum: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        default: Option<i64>,
     },
     /// Boolean type
     Boolean {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        default: Option<bool>,
     },
     /// Array type
     Array {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,6 +88,8 @@ pub enum JsonSchema {
     },
     /// Object type
     Object {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         description: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -76,93 +102,124 @@ pub enum JsonSchema {
         )]
         additional_properties: Option<bool>,
     },
+    /// Reference to a shared definition, e.g. `{"$ref": "#/$defs/Address"}`
+    #[serde(untagged)]
+    Ref {
+        #[serde(rename = "$ref")]
+        reference: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    },
 }
 
 impl JsonSchema {
     /// Create a string schema
     pub fn string() -> Self {
         Self::String {
+            title: None,
             description: None,
             pattern: None,
             min_length: None,
             max_length: None,
             enum_values: None,
+            default: None,
         }
     }
 
     /// Create a string schema with description
     pub fn string_with_description(description: impl Into<String>) -> Self {
         Self::String {
+            title: None,
             description: Some(description.into()),
             pattern: None,
             min_length: None,
             max_length: None,
             enum_values: None,
+            default: None,
         }
     }
 
     /// Create a string enum schema
     pub fn string_enum(values: Vec<String>) -> Self {
         Self::String {
+            title: None,
             description: None,
             pattern: None,
             min_length: None,
             max_length: None,
             enum_values: Some(values),
+            default: None,
         }
     }
 
     /// Create a number schema
     pub fn number() -> Self {
         Self::Number {
+            title: None,
             description: None,
             minimum: None,
             maximum: None,
+            default: None,
         }
     }
 
     /// Create a number schema with description
     pub fn number_with_description(description: impl Into<String>) -> Self {
         Self::Number {
+            title: None,
             description: Some(description.into()),
             minimum: None,
             maximum: None,
+            default: None,
         }
     }
 
     /// Create an integer schema
     pub fn integer() -> Self {
         Self::Integer {
+            title: None,
             description: None,
             minimum: None,
             maximum: None,
+            default: None,
         }
     }
 
     /// Create an integer schema with description
     pub fn integer_with_description(description: impl Into<String>) -> Self {
         Self::Integer {
+            title: None,
             description: Some(description.into()),
             minimum: None,
             maximum: None,
+            default: None,
         }
     }
 
     /// Create a boolean schema
     pub fn boolean() -> Self {
-        Self::Boolean { description: None }
+        Self::Boolean {
+            title: None,
+            description: None,
+            default: None,
+        }
     }
 
     /// Create a boolean schema with description
     pub fn boolean_with_description(description: impl Into<String>) -> Self {
         Self::Boolean {
+            title: None,
             description: Some(description.into()),
+            default: None,
         }
     }
 
     /// Create an array schema
     pub fn array(items: JsonSchema) -> Self {
         Self::Array {
+            title: None,
             description: None,
             items: Some(Box::new(items)),
             min_items: None,
@@ -173,6 +230,7 @@ impl JsonSchema {
     /// Create an array schema with description
     pub fn array_with_description(items: JsonSchema, description: impl Into<String>) -> Self {
         Self::Array {
+            title: None,
             description: Some(description.into()),
             items: Some(Box::new(items)),
             min_items: None,
@@ -183,6 +241,7 @@ impl JsonSchema {
     /// Create an object schema
     pub fn object() -> Self {
         Self::Object {
+            title: None,
             description: None,
             properties: None,
             required: None,
@@ -193,6 +252,7 @@ impl JsonSchema {
     /// Create an object schema with properties
     pub fn object_with_properties(properties: HashMap<String, JsonSchema>) -> Self {
         Self::Object {
+            title: None,
             description: None,
             properties: Some(properties),
             required: None,
@@ -206,6 +266,7 @@ impl JsonSchema {
         required: Vec<String>,
     ) -> Self {
         Self::Object {
+            title: None,
             description: None,
             properties: Some(properties),
             required: Some(required),
@@ -213,6 +274,92 @@ impl JsonSchema {
         }
     }
 
+    /// Create a `$ref` to the definition `name` under the root schema's `$defs`
+    pub fn reference(name: impl AsRef<str>) -> Self {
+        Self::Ref {
+            reference: format!("{}{}", DEFS_REF_PREFIX, name.as_ref()),
+            title: None,
+            description: None,
+        }
+    }
+
+    /// Replace `$ref`s into `defs` with the schemas they point to
+    ///
+    /// A reference back to a definition that is already being expanded (a
+    /// recursive type) becomes a plain object schema, so the result is always
+    /// finite. Unknown references are left untouched.
+    pub fn inline_definitions(self, defs: &SchemaDefinitions) -> Self {
+        self.inline_with(defs, &mut Vec::new())
+    }
+
+    fn inline_with(self, defs: &SchemaDefinitions, expanding: &mut Vec<String>) -> Self {
+        match self {
+            JsonSchema::Ref {
+                reference,
+                title,
+                description,
+            } => {
+                let Some((name, definition)) = reference
+                    .strip_prefix(DEFS_REF_PREFIX)
+                    .and_then(|name| defs.get_key_value(name))
+                else {
+                    return JsonSchema::Ref {
+                        reference,
+                        title,
+                        description,
+                    };
+                };
+                let mut inlined = if expanding.contains(name) {
+                    JsonSchema::object()
+                } else {
+                    expanding.push(name.clone());
+                    let inlined = definition.clone().inline_with(defs, expanding);
+                    expanding.pop();
+                    inlined
+                };
+                if let Some(title) = title {
+                    inlined = inlined.with_title(title);
+                }
+                if let Some(description) = description {
+                    inlined = inlined.with_description(description);
+                }
+                inlined
+            }
+            JsonSchema::Array {
+                title,
+                description,
+                items,
+                min_items,
+                max_items,
+            } => JsonSchema::Array {
+                title,
+                description,
+                items: items.map(|items| Box::new(items.inline_with(defs, expanding))),
+                min_items,
+                max_items,
+            },
+            JsonSchema::Object {
+                title,
+                description,
+                properties,
+                required,
+                additional_properties,
+            } => JsonSchema::Object {
+                title,
+                description,
+                properties: properties.map(|properties| {
+                    properties
+                        .into_iter()
+                        .map(|(name, schema)| (name, schema.inline_with(defs, expanding)))
+                        .collect()
+                }),
+                required,
+                additional_properties,
+            },
+            other => other,
+        }
+    }
+
     /// Add description to any schema
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         match &mut self {
@@ -222,6 +369,45 @@ impl JsonSchema {
             JsonSchema::Boolean { description: d, .. } => *d = Some(description.into()),
             JsonSchema::Array { description: d, .. } => *d = Some(description.into()),
             JsonSchema::Object { description: d, .. } => *d = Some(description.into()),
+            JsonSchema::Ref { description: d, .. } => *d = Some(description.into()),
+        }
+        self
+    }
+
+    /// Add a human-readable title to any schema
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        match &mut self {
+            JsonSchema::String { title: t, .. } => *t = Some(title.into()),
+            JsonSchema::Number { title: t, .. } => *t = Some(title.into()),
+            JsonSchema::Integer { title: t, .. } => *t = Some(title.into()),
+            JsonSchema::Boolean { title: t, .. } => *t = Some(title.into()),
+            JsonSchema::Array { title: t, .. } => *t = Some(title.into()),
+            JsonSchema::Object { title: t, .. } => *t = Some(title.into()),
+            JsonSchema::Ref { title: t, .. } => *t = Some(title.into()),
+        }
+        self
+    }
+
+    /// Add regex pattern constraint to string schema
+    pub fn with_pattern(mut self, pattern: impl Into<String>) -> Self {
+        if let JsonSchema::String { pattern: p, .. } = &mut self {
+            *p = Some(pattern.into());
+        }
+        self
+    }
+
+    /// Add minimum length constraint to string schema
+    pub fn with_min_length(mut self, min_length: u64) -> Self {
+        if let JsonSchema::String { min_length: m, .. } = &mut self {
+            *m = Some(min_length);
+        }
+        self
+    }
+
+    /// Add maximum length constraint to string schema
+    pub fn with_max_length(mut self, max_length: u64) -> Self {
+        if let JsonSchema::String { max_length: m, .. } = &mut self {
+            *m = Some(max_length);
         }
         self
     }
@@ -246,6 +432,37 @@ impl JsonSchema {
         self
     }
 
+    /// Add a `default` value to a string, number, integer or boolean schema
+    ///
+    /// Ignored when the value doesn't fit the schema type.
+    pub fn with_default(mut self, default: impl Into<serde_json::Value>) -> Self {
+        let default = default.into();
+        match &mut self {
+            JsonSchema::String { default: d, .. } => {
+                if let Some(value) = default.as_str() {
+                    *d = Some(value.to_string());
+                }
+            }
+            JsonSchema::Number { default: d, .. } => {
+                if let Some(value) = default.as_f64() {
+                    *d = Some(value);
+                }
+            }
+            JsonSchema::Integer { default: d, .. } => {
+                if let Some(value) = default.as_i64() {
+                    *d = Some(value);
+                }
+            }
+            JsonSchema::Boolean { default: d, .. } => {
+                if let Some(value) = default.as_bool() {
+                    *d = Some(value);
+                }
+            }
+            _ => {} // Ignore for arrays, objects and references
+        }
+        self
+    }
+
     /// Add properties to object schema
     pub fn with_properties(mut self, properties: HashMap<String, JsonSchema>) -> Self {
         if let JsonSchema::Object { properties: p, .. } = &mut self {
@@ -281,7 +498,19 @@ impl JsonSchema {
 
 /// Converts common Rust types to JsonSchema
 pub trait ToJsonSchema {
+    /// Self-contained schema for this type
     fn to_json_schema() -> JsonSchema;
+
+    /// Schema for this type where it appears inside another schema
+    ///
+    /// Types worth sharing add their schema to `defs` and return a
+    /// [`JsonSchema::reference`] to it, which is how `#[derive(JsonSchema)]`
+    /// structs avoid repeating themselves and terminate recursion. The default
+    /// inlines [`to_json_schema`](Self::to_json_schema).
+    fn nested_schema(defs: &mut SchemaDefinitions) -> JsonSchema {
+        let _ = defs;
+        Self::to_json_schema()
+    }
 }
 
 impl ToJsonSchema for String {
@@ -330,12 +559,51 @@ impl<T: ToJsonSchema> ToJsonSchema for Vec<T> {
     fn to_json_schema() -> JsonSchema {
         JsonSchema::array(T::to_json_schema())
     }
+
+    fn nested_schema(defs: &mut SchemaDefinitions) -> JsonSchema {
+        JsonSchema::array(T::nested_schema(defs))
+    }
 }
 
 impl<T: ToJsonSchema> ToJsonSchema for Option<T> {
     fn to_json_schema() -> JsonSchema {
         T::to_json_schema()
     }
+
+    fn nested_schema(defs: &mut SchemaDefinitions) -> JsonSchema {
+        T::nested_schema(defs)
+    }
+}
+
+/// Schema lookup for struct fields in `#[derive(JsonSchema)]` output.
+///
+/// Generated code calls `(&NestedSchemaProbe::<T>(PhantomData)).nested_schema(&mut defs)`
+/// with both traits in scope. Method resolution picks [`NestedSchemaFromTrait`]
+/// when `T` implements [`ToJsonSchema`] (nested derived structs and enums) and
+/// otherwise autorefs to [`NestedSchemaFallback`], a plain object schema.
+#[doc(hidden)]
+pub struct NestedSchemaProbe<T: ?Sized>(pub std::marker::PhantomData<T>);
+
+#[doc(hidden)]
+pub trait NestedSchemaFromTrait {
+    fn nested_schema(&self, defs: &mut SchemaDefinitions) -> JsonSchema;
+}
+
+impl<T: ToJsonSchema + ?Sized> NestedSchemaFromTrait for NestedSchemaProbe<T> {
+    fn nested_schema(&self, defs: &mut SchemaDefinitions) -> JsonSchema {
+        T::nested_schema(defs)
+    }
+}
+
+#[doc(hidden)]
+pub trait NestedSchemaFallback {
+    fn nested_schema(&self, defs: &mut SchemaDefinitions) -> JsonSchema;
+}
+
+impl<T: ?Sized> NestedSchemaFallback for &NestedSchemaProbe<T> {
+    fn nested_schema(&self, _defs: &mut SchemaDefinitions) -> JsonSchema {
+        JsonSchema::object()
+    }
 }
 
 #[cfg(test)]
@@ -407,6 +675,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_title_serialized_only_when_set() {
+        let schema =
+            JsonSchema::number_with_description("Left operand").with_title("First Operand");
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(json["title"], "First Operand");
+        assert_eq!(json["description"], "Left operand");
+
+        let json = serde_json::to_value(JsonSchema::number()).unwrap();
+        assert!(json.get("title").is_none());
+    }
+
     #[test]
     fn test_enum_schema() {
         let schema = JsonSchema::string_enum(vec!["option1".to_string(), "option2".to_string()]);
@@ -415,6 +695,38 @@ mod tests {
         assert!(json.contains("option2"));
     }
 
+    #[test]
+    fn test_ref_schema_round_trip() {
+        let schema = JsonSchema::reference("Address").with_description("Home address");
+        let json = serde_json::to_value(&schema).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"$ref": "#/$defs/Address", "description": "Home address"})
+        );
+
+        let parsed: JsonSchema = serde_json::from_value(json.clone()).unwrap();
+        assert!(matches!(parsed, JsonSchema::Ref { .. }));
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn test_inline_definitions_terminates_recursion() {
+        let mut node = HashMap::new();
+        node.insert(
+            "children".to_string(),
+            JsonSchema::array(JsonSchema::reference("Node")),
+        );
+        let mut defs = SchemaDefinitions::new();
+        defs.insert("Node".to_string(), JsonSchema::object_with_properties(node));
+
+        let inlined = JsonSchema::reference("Node").inline_definitions(&defs);
+        let json = serde_json::to_value(&inlined).unwrap();
+        assert_eq!(json["type"], "object");
+        assert_eq!(json["properties"]["children"]["items"]["type"], "object");
+        assert!(json["properties"]["children"]["items"]["properties"].is_null());
+        assert!(!json.to_string().contains("$ref"));
+    }
+
     #[test]
     fn test_to_json_schema_trait() {
         assert!(matches!(
@@ -314,6 +314,10 @@ pub struct ListTasksParams {
     /// Optional limit for page size
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    /// Optional status filter (framework extension, not part of the spec);
+    /// only tasks in one of these statuses are listed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Vec<TaskStatus>>,
     /// Meta information (optional _meta field inside params)
     #[serde(rename = "_meta", skip_serializing_if = "Option::is_none")]
     pub meta: Option<HashMap<String, Value>>,
@@ -324,6 +328,7 @@ impl ListTasksParams {
         Self {
             cursor: None,
             limit: None,
+            status: None,
             meta: None,
         }
     }
@@ -338,6 +343,11 @@ impl ListTasksParams {
         self
     }
 
+    pub fn with_status(mut self, status: Vec<TaskStatus>) -> Self {
+        self.status = Some(status);
+        self
+    }
+
     pub fn with_meta(mut self, meta: HashMap<String, Value>) -> Self {
         self.meta = Some(meta);
         self
@@ -384,6 +394,11 @@ impl ListTasksRequest {
         self
     }
 
+    pub fn with_status(mut self, status: Vec<TaskStatus>) -> Self {
+        self.params = self.params.with_status(status);
+        self
+    }
+
     pub fn with_meta(mut self, meta: HashMap<String, Value>) -> Self {
         self.params = self.params.with_meta(meta);
         self
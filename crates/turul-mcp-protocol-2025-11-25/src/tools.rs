@@ -164,6 +164,15 @@ impl ToolSchema {
         self.required = Some(required);
         self
     }
+
+    /// Emit shared sub-schemas under `$defs` (omitted when `definitions` is empty)
+    pub fn with_definitions(mut self, definitions: crate::schema::SchemaDefinitions) -> Self {
+        if !definitions.is_empty() {
+            let defs = serde_json::to_value(definitions).expect("JSON schemas always serialize");
+            self.additional.insert("$defs".to_string(), defs);
+        }
+        self
+    }
 }
 
 /// Tool definition
@@ -813,6 +822,53 @@ mod tests {
         assert!(matches!(resource_result, ToolResult::Resource { .. }));
     }
 
+    #[test]
+    fn test_embedded_resources_round_trip() {
+        use base64::prelude::*;
+
+        let document = json!({ "rows": 2, "columns": ["id", "name"] });
+        let png = [0x89u8, b'P', b'N', b'G', 0x00, 0xff];
+        let result = CallToolResult::success(vec![
+            ToolResult::embedded_text_resource(
+                "file:///report.json",
+                document.to_string(),
+                "application/json",
+            ),
+            ToolResult::embedded_resource("file:///chart.png", png, "image/png"),
+        ]);
+
+        let wire = serde_json::to_value(&result).unwrap();
+        assert_eq!(wire["content"][0]["type"], "resource");
+        assert_eq!(
+            wire["content"][0]["resource"]["mimeType"],
+            "application/json"
+        );
+        assert_eq!(wire["content"][1]["resource"]["blob"], "iVBORwD/");
+
+        let parsed: CallToolResult = serde_json::from_value(wire).unwrap();
+        match &parsed.content[0] {
+            ToolResult::Resource {
+                resource: ResourceContents::Text(text),
+                ..
+            } => {
+                assert_eq!(text.uri, "file:///report.json");
+                let round_tripped: Value = serde_json::from_str(&text.text).unwrap();
+                assert_eq!(round_tripped, document);
+            }
+            other => panic!("expected embedded text resource, got {other:?}"),
+        }
+        match &parsed.content[1] {
+            ToolResult::Resource {
+                resource: ResourceContents::Blob(blob),
+                ..
+            } => {
+                assert_eq!(blob.mime_type.as_deref(), Some("image/png"));
+                assert_eq!(BASE64_STANDARD.decode(&blob.blob).unwrap(), png);
+            }
+            other => panic!("expected embedded blob resource, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_call_tool_response() {
         let response =
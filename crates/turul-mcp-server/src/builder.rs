@@ -31,6 +31,10 @@ pub struct McpServerBuilder {
     /// Tools registered with the server
     tools: HashMap<String, Arc<dyn McpTool>>,
 
+    /// Open duplex calls, shared by duplex tools and their follow-up handlers
+    /// (created when the first duplex tool is registered)
+    duplex_registry: Option<Arc<crate::duplex::DuplexRegistry>>,
+
     /// Resources registered with the server
     resources: HashMap<String, Arc<dyn McpResource>>,
 
@@ -49,9 +53,18 @@ pub struct McpServerBuilder {
     /// Completion providers registered with the server
     completions: HashMap<String, Arc<dyn McpCompletion>>,
 
+    /// Async completion source for `completion/complete` (optional)
+    completion_fn: Option<crate::CompletionFn>,
+
+    /// Cap on values per `completion/complete` response
+    completion_max_values: usize,
+
     /// Loggers registered with the server
     loggers: HashMap<String, Arc<dyn McpLogger>>,
 
+    /// Called after a session changes its level through `logging/setLevel` (optional)
+    log_level_listener: Option<crate::LogLevelListener>,
+
     /// Root providers registered with the server
     root_providers: HashMap<String, Arc<dyn McpRoot>>,
 
@@ -103,16 +116,85 @@ pub struct McpServerBuilder {
     enable_sse: bool,
     #[cfg(feature = "http")]
     allow_unauthenticated_ping: Option<bool>,
+    #[cfg(feature = "http")]
+    cors_config: Option<turul_http_mcp_server::CorsConfig>,
+    #[cfg(feature = "http")]
+    compression_algorithms: Option<Vec<turul_http_mcp_server::ContentEncoding>>,
+    #[cfg(feature = "http")]
+    compression_threshold: Option<usize>,
+    #[cfg(feature = "http")]
+    max_request_body_bytes: Option<usize>,
+    #[cfg(feature = "http")]
+    liveness_path: Option<String>,
+    #[cfg(feature = "http")]
+    readiness_path: Option<String>,
+    #[cfg(feature = "http")]
+    wire_log_level: Option<tracing::Level>,
+    #[cfg(feature = "http")]
+    wire_redact_fields: Option<Vec<String>>,
+    #[cfg(feature = "http")]
+    request_interceptor: Option<turul_http_mcp_server::RequestInterceptor>,
+    #[cfg(feature = "http")]
+    ip_limits: Option<turul_http_mcp_server::IpLimitConfig>,
+    #[cfg(feature = "http")]
+    resource_update_coalesce_window_ms: Option<u64>,
+    #[cfg(feature = "http")]
+    max_streams_per_session: Option<usize>,
+    #[cfg(feature = "http")]
+    json_rpc_codecs: Vec<Arc<dyn turul_mcp_json_rpc_server::JsonRpcCodec>>,
+    #[cfg(feature = "http")]
+    shutdown_grace_period: Option<std::time::Duration>,
+    #[cfg(feature = "otel")]
+    request_tracing: Option<turul_http_mcp_server::RequestTracing>,
+    #[cfg(feature = "tls")]
+    tls: Option<turul_http_mcp_server::TlsConfig>,
 
     /// Validation errors collected during builder configuration
     validation_errors: Vec<String>,
 
+    /// Tools, prompts and static resources registered more than once
+    duplicate_registrations: Vec<String>,
+
     /// Tool change detection mode (default: Static)
     tool_change_mode: crate::ToolChangeMode,
 
+    /// Translations for framework error messages (optional)
+    message_catalog: Option<Arc<crate::locale::MessageCatalog>>,
+
+    /// Default page size for `tools/list`, `resources/list`, `prompts/list` and friends
+    list_page_size: usize,
+
+    /// Resolves tools that are not registered statically (optional)
+    tool_router: Option<Arc<dyn crate::tool::ToolRouter>>,
+
+    /// Advertise `listChanged` for tools, resources and prompts
+    list_changed_notifications: bool,
+
+    /// Time budget of each `tools/call` (optional)
+    request_timeout: Option<std::time::Duration>,
+
+    /// How long `tools/call` results are replayed for a repeated idempotency key (optional)
+    idempotency_window: Option<std::time::Duration>,
+
+    /// Receives an audit record after each `tools/call` (optional)
+    #[cfg(feature = "audit")]
+    audit_sink: Option<Arc<dyn crate::audit::AuditSink>>,
+
+    /// Whether audit records carry hashed or full arguments
+    #[cfg(feature = "audit")]
+    audit_argument_mode: crate::audit::AuditArgumentMode,
+
     /// Server state storage for cross-instance coordination (optional)
     #[cfg(feature = "dynamic-tools")]
     server_state_storage: Option<Arc<dyn turul_mcp_server_state_storage::ServerStateStorage>>,
+
+    /// Watched tool config file (optional)
+    #[cfg(feature = "dynamic-tools")]
+    tool_config_path: Option<std::path::PathBuf>,
+
+    /// How often the watched tool config file is re-read
+    #[cfg(feature = "dynamic-tools")]
+    tool_config_watch_interval: std::time::Duration,
 }
 
 impl McpServerBuilder {
@@ -143,7 +225,10 @@ impl McpServerBuilder {
             "prompts/get".to_string(),
             Arc::new(PromptsGetHandler::new()),
         );
-        handlers.insert("logging/setLevel".to_string(), Arc::new(LoggingHandler));
+        handlers.insert(
+            "logging/setLevel".to_string(),
+            Arc::new(LoggingHandler::new()),
+        );
         handlers.insert("roots/list".to_string(), Arc::new(RootsHandler::new()));
         handlers.insert(
             "sampling/createMessage".to_string(),
@@ -213,13 +298,17 @@ impl McpServerBuilder {
             icons: None,
             capabilities: ServerCapabilities::default(),
             tools,
+            duplex_registry: None,
             resources: HashMap::new(),
             template_resources: Vec::new(),
             prompts: HashMap::new(),
             elicitations: HashMap::new(),
             sampling: HashMap::new(),
             completions: HashMap::new(),
+            completion_fn: None,
+            completion_max_values: crate::MAX_COMPLETION_VALUES,
             loggers: HashMap::new(),
+            log_level_listener: None,
             root_providers: HashMap::new(),
             notifications: HashMap::new(),
             handlers,
@@ -244,10 +333,57 @@ impl McpServerBuilder {
             enable_sse: cfg!(feature = "sse"),
             #[cfg(feature = "http")]
             allow_unauthenticated_ping: None, // Default: use ServerConfig default (true)
+            #[cfg(feature = "http")]
+            cors_config: None, // Default: allow all origins
+            #[cfg(feature = "http")]
+            compression_algorithms: None, // Default: gzip, then deflate
+            #[cfg(feature = "http")]
+            compression_threshold: None, // Default: 1 KiB
+            #[cfg(feature = "http")]
+            max_request_body_bytes: None, // Default: 1 MiB
+            #[cfg(feature = "http")]
+            liveness_path: None, // Default: no health probes
+            #[cfg(feature = "http")]
+            readiness_path: None,
+            #[cfg(feature = "http")]
+            wire_log_level: None, // Default: wire logging off
+            #[cfg(feature = "http")]
+            wire_redact_fields: None,
+            #[cfg(feature = "http")]
+            request_interceptor: None,
+            #[cfg(feature = "http")]
+            ip_limits: None,
+            #[cfg(feature = "http")]
+            resource_update_coalesce_window_ms: None,
+            #[cfg(feature = "http")]
+            max_streams_per_session: None,
+            #[cfg(feature = "http")]
+            json_rpc_codecs: Vec::new(),
+            #[cfg(feature = "http")]
+            shutdown_grace_period: None,
+            #[cfg(feature = "otel")]
+            request_tracing: None,
+            #[cfg(feature = "tls")]
+            tls: None,
             validation_errors: Vec::new(),
+            duplicate_registrations: Vec::new(),
             tool_change_mode: crate::ToolChangeMode::Static,
+            message_catalog: None,
+            list_page_size: DEFAULT_LIST_PAGE_SIZE,
+            tool_router: None,
+            list_changed_notifications: false,
+            request_timeout: None,
+            idempotency_window: None,
+            #[cfg(feature = "audit")]
+            audit_sink: None,
+            #[cfg(feature = "audit")]
+            audit_argument_mode: crate::audit::AuditArgumentMode::default(),
             #[cfg(feature = "dynamic-tools")]
             server_state_storage: None,
+            #[cfg(feature = "dynamic-tools")]
+            tool_config_path: None,
+            #[cfg(feature = "dynamic-tools")]
+            tool_config_watch_interval: crate::tool_config::DEFAULT_CONFIG_WATCH_INTERVAL,
         }
     }
 
@@ -262,6 +398,99 @@ impl McpServerBuilder {
         self
     }
 
+    /// Translate framework error messages for each client's locale.
+    ///
+    /// The locale comes from `_meta.locale` on the request, or else the one the
+    /// client sent with `initialize`. Errors without a matching template keep
+    /// their default message. See [`MessageCatalog`](crate::MessageCatalog).
+    pub fn message_catalog(mut self, catalog: crate::locale::MessageCatalog) -> Self {
+        self.message_catalog = Some(Arc::new(catalog));
+        self
+    }
+
+    /// Set how many items `tools/list`, `resources/list`, `prompts/list`,
+    /// `resources/templates/list` and `roots/list` return per page.
+    ///
+    /// Clients page through with the opaque `nextCursor` from each response.
+    /// A request's own `limit` still takes precedence. Default: 50.
+    pub fn list_page_size(mut self, page_size: usize) -> Self {
+        if page_size == 0 {
+            self.validation_errors
+                .push("list_page_size must be at least 1".to_string());
+        }
+        self.list_page_size = page_size;
+        self
+    }
+
+    /// Resolve tools missing from the static registry through `router`.
+    ///
+    /// `tools/call` falls back to [`ToolRouter::resolve`](crate::ToolRouter::resolve)
+    /// for names no registered tool claims, and `tools/list` includes the router's
+    /// [`list`](crate::ToolRouter::list). Registered tools win on name clashes.
+    /// Enables the tools capability even when no tool is registered.
+    pub fn with_tool_router(mut self, router: Arc<dyn crate::tool::ToolRouter>) -> Self {
+        self.tool_router = Some(router);
+        self
+    }
+
+    /// Advertise `listChanged` on the tools, resources and prompts capabilities.
+    ///
+    /// Declares that these lists may change while sessions are open. Announce each
+    /// change with [`McpServer::notify_tools_changed`](crate::McpServer::notify_tools_changed),
+    /// [`notify_resources_changed`](crate::McpServer::notify_resources_changed) or
+    /// [`notify_prompts_changed`](crate::McpServer::notify_prompts_changed); without
+    /// this opt-in those calls send nothing, since clients were told the lists are fixed.
+    pub fn list_changed_notifications(mut self) -> Self {
+        self.list_changed_notifications = true;
+        self
+    }
+
+    /// Give each `tools/call` a time budget of `timeout`, counted from when the
+    /// transport received the request.
+    ///
+    /// Tools read what is left with [`SessionContext::remaining`](crate::SessionContext::remaining)
+    /// and can skip optional work when it runs low; a client may shorten the
+    /// budget per call with `_meta.timeoutMs`. The budget is advisory: calls that
+    /// overrun it are not cancelled.
+    pub fn request_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Honor idempotency keys on `tools/call`, replaying results for `window`.
+    ///
+    /// A call carrying an `Idempotency-Key` header or `_meta.idempotencyKey` runs
+    /// once per session and key; replays within `window` get the cached result
+    /// instead of running the tool again. Calls without a key are unaffected.
+    /// See [`idempotency`](crate::idempotency).
+    pub fn idempotency_window(mut self, window: std::time::Duration) -> Self {
+        if window.is_zero() {
+            self.validation_errors
+                .push("idempotency_window must be greater than zero".to_string());
+        }
+        self.idempotency_window = Some(window);
+        self
+    }
+
+    /// Write an [`AuditRecord`](crate::audit::AuditRecord) to `sink` after every
+    /// `tools/call`: session, tool, argument digest, outcome and timestamp.
+    ///
+    /// Arguments are recorded as a SHA-256 digest unless
+    /// [`audit_arguments`](Self::audit_arguments) asks for the full value.
+    /// Sink failures are logged and never fail the call.
+    #[cfg(feature = "audit")]
+    pub fn audit_sink<S: crate::audit::AuditSink + 'static>(mut self, sink: S) -> Self {
+        self.audit_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Choose whether audit records hash (default) or fully record tool arguments
+    #[cfg(feature = "audit")]
+    pub fn audit_arguments(mut self, mode: crate::audit::AuditArgumentMode) -> Self {
+        self.audit_argument_mode = mode;
+        self
+    }
+
     /// Set the server state storage backend for cross-instance coordination.
     ///
     /// When provided with `ToolChangeMode::Dynamic`, tool activation state is
@@ -277,6 +506,29 @@ impl McpServerBuilder {
         self
     }
 
+    /// Watch a tool config file and apply edits without a restart.
+    ///
+    /// The file names the compiled tools that should be active and may define
+    /// further tools served by them (see [`tool_config`](crate::tool_config)).
+    /// It is applied when the server starts
+    /// and re-read every [`DEFAULT_CONFIG_WATCH_INTERVAL`](crate::tool_config::DEFAULT_CONFIG_WATCH_INTERVAL);
+    /// each change is applied atomically with a single `notifications/tools/list_changed`.
+    /// Parse errors are logged and keep the previous configuration. The watcher
+    /// stops when the server shuts down or is dropped.
+    /// Requires `ToolChangeMode::Dynamic`.
+    #[cfg(feature = "dynamic-tools")]
+    pub fn watch_config(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.tool_config_path = Some(path.into());
+        self
+    }
+
+    /// Set how often the file given to [`watch_config`](Self::watch_config) is re-read.
+    #[cfg(feature = "dynamic-tools")]
+    pub fn watch_config_interval(mut self, interval: std::time::Duration) -> Self {
+        self.tool_config_watch_interval = interval;
+        self
+    }
+
     /// Sets the server name for identification
     pub fn name(mut self, name: impl Into<String>) -> Self {
         self.name = name.into();
@@ -322,7 +574,9 @@ impl McpServerBuilder {
     /// Registers a tool that clients can execute
     pub fn tool<T: McpTool + 'static>(mut self, tool: T) -> Self {
         let name = tool.name().to_string();
-        self.tools.insert(name, Arc::new(tool));
+        if self.tools.insert(name.clone(), Arc::new(tool)).is_some() {
+            self.record_duplicate(format!("tool '{}'", name));
+        }
         self
     }
 
@@ -422,6 +676,38 @@ impl McpServerBuilder {
         self
     }
 
+    /// Registers a bidirectional streaming tool
+    ///
+    /// The tool is listed and called like any other tool; the first registration also
+    /// installs the `tools/duplex/input` and `tools/duplex/close` handlers that carry
+    /// client input into the running call. See [`crate::duplex`] for the wire exchange.
+    pub fn duplex_tool<T: crate::McpDuplexTool + 'static>(mut self, tool: T) -> Self {
+        let registry = match &self.duplex_registry {
+            Some(registry) => Arc::clone(registry),
+            None => {
+                let registry = Arc::new(crate::duplex::DuplexRegistry::default());
+                self.duplex_registry = Some(Arc::clone(&registry));
+                self = self
+                    .handler(crate::duplex::DuplexInputHandler::new(Arc::clone(
+                        &registry,
+                    )))
+                    .handler(crate::duplex::DuplexCloseHandler::new(Arc::clone(
+                        &registry,
+                    )));
+                registry
+            }
+        };
+        self.tool(crate::duplex::DuplexToolAdapter::new(tool, registry))
+    }
+
+    /// Registers a tool that emits its output incrementally
+    ///
+    /// Clients sending `_meta.streamToken` receive each chunk as it is produced; every
+    /// client gets the aggregated `CallToolResult`. See [`crate::streaming`].
+    pub fn streaming_tool<T: crate::McpStreamingTool + 'static>(self, tool: T) -> Self {
+        self.tool(crate::streaming::StreamingToolAdapter::new(tool))
+    }
+
     /// Add middleware to the request/response processing chain
     ///
     /// **This method is additive** - each call adds a new middleware to the stack.
@@ -511,6 +797,21 @@ impl McpServerBuilder {
         self
     }
 
+    /// Authenticate every request before session lookup and any other middleware (requires "http" feature)
+    ///
+    /// Rejected requests get HTTP 401 with a JSON-RPC error. Tools read the accepted
+    /// caller with [`SessionContext::identity`](crate::SessionContext::identity).
+    #[cfg(feature = "http")]
+    pub fn authenticator(
+        mut self,
+        authenticator: impl turul_http_mcp_server::Authenticator + 'static,
+    ) -> Self {
+        self.middleware_stack.push_front(Arc::new(
+            turul_http_mcp_server::AuthenticatorMiddleware::new(Arc::new(authenticator)),
+        ));
+        self
+    }
+
     /// Register a custom HTTP route (e.g., `.well-known/oauth-protected-resource`)
     ///
     /// Routes are matched by exact path before returning 404.
@@ -632,7 +933,13 @@ impl McpServerBuilder {
                     .push(format!("Invalid resource URI '{}': {}", uri, e));
             } else {
                 tracing::debug!("Successfully added static resource: {}", uri);
-                self.resources.insert(uri, Arc::new(resource));
+                if self
+                    .resources
+                    .insert(uri.clone(), Arc::new(resource))
+                    .is_some()
+                {
+                    self.record_duplicate(format!("resource '{}'", uri));
+                }
             }
         }
 
@@ -833,7 +1140,13 @@ impl McpServerBuilder {
     /// Registers a prompt template for conversation generation
     pub fn prompt<P: McpPrompt + 'static>(mut self, prompt: P) -> Self {
         let name = prompt.name().to_string();
-        self.prompts.insert(name, Arc::new(prompt));
+        if self
+            .prompts
+            .insert(name.clone(), Arc::new(prompt))
+            .is_some()
+        {
+            self.record_duplicate(format!("prompt '{}'", name));
+        }
         self
     }
 
@@ -902,6 +1215,76 @@ impl McpServerBuilder {
         self
     }
 
+    /// Answer `completion/complete` with an async function.
+    ///
+    /// `f` receives the argument name and the partial value typed so far, and
+    /// returns the candidate values, so it can query a live data source and
+    /// filter by prefix. Results are capped by
+    /// [`completion_max_values`](Self::completion_max_values).
+    ///
+    /// ```rust,no_run
+    /// # use turul_mcp_server::McpServer;
+    /// let server = McpServer::builder()
+    ///     .name("tickets")
+    ///     .completion_fn(|argument, partial| async move {
+    ///         let ids = ["TCK-1", "TCK-2", "OPS-7"];
+    ///         Ok(match argument.as_str() {
+    ///             "ticket_id" => ids
+    ///                 .iter()
+    ///                 .filter(|id| id.starts_with(&partial))
+    ///                 .map(|id| id.to_string())
+    ///                 .collect(),
+    ///             _ => Vec::new(),
+    ///         })
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn completion_fn<F, Fut>(mut self, f: F) -> Self
+    where
+        F: Fn(String, String) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = crate::McpResult<Vec<String>>> + Send + 'static,
+    {
+        self.completion_fn = Some(Arc::new(move |argument, partial| {
+            Box::pin(f(argument, partial))
+        }));
+        self
+    }
+
+    /// Most values returned per `completion/complete` response (default and maximum: 100)
+    pub fn completion_max_values(mut self, max_values: usize) -> Self {
+        if max_values == 0 {
+            self.validation_errors
+                .push("completion_max_values must be at least 1".to_string());
+        }
+        self.completion_max_values = max_values;
+        self
+    }
+
+    /// Run `f` whenever a client changes its logging level with `logging/setLevel`.
+    ///
+    /// Each session's `notifications/message` output already follows its own
+    /// level; this hook receives the session ID and new level so the server's
+    /// `tracing` output can follow too, e.g. through an `EnvFilter` reload handle:
+    ///
+    /// ```rust,no_run
+    /// # use turul_mcp_server::{McpServer, tracing_level};
+    /// # fn example(reload: impl Fn(tracing::Level) + Send + Sync + 'static) {
+    /// let server = McpServer::builder()
+    ///     .name("ops")
+    ///     .on_log_level_change(move |_session_id, level| reload(tracing_level(level)))
+    ///     .build()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn on_log_level_change<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str, turul_mcp_protocol::logging::LoggingLevel) + Send + Sync + 'static,
+    {
+        self.log_level_listener = Some(Arc::new(f));
+        self
+    }
+
     /// Register a logger with the server
     pub fn logger<L: McpLogger + 'static>(mut self, logger: L) -> Self {
         let key = format!("logger_{}", self.loggers.len());
@@ -1035,6 +1418,20 @@ impl McpServerBuilder {
         self
     }
 
+    /// Register a handler for a client-sent notification such as `notifications/cancelled`
+    ///
+    /// The handler's result is discarded: notifications never produce a response.
+    /// For `notifications/cancelled` the matching in-flight request is aborted
+    /// before the handler runs, whether or not one is registered.
+    pub fn notification_handler<H: McpHandler + 'static>(
+        mut self,
+        method: impl Into<String>,
+        handler: H,
+    ) -> Self {
+        self.handlers.insert(method.into(), Arc::new(handler));
+        self
+    }
+
     /// Register multiple handlers
     pub fn handlers<H: McpHandler + 'static, I: IntoIterator<Item = H>>(
         mut self,
@@ -1076,12 +1473,12 @@ impl McpServerBuilder {
         let has_resources = !self.resources.is_empty() || !self.template_resources.is_empty();
 
         self.capabilities.resources = Some(ResourcesCapabilities {
-            subscribe: Some(false), // TODO: Implement resource subscriptions
+            subscribe: Some(has_resources),
             list_changed: Some(has_resources),
         });
 
         // Create ResourcesListHandler and add all registered resources
-        let mut list_handler = ResourcesListHandler::new();
+        let mut list_handler = ResourcesListHandler::new().with_page_size(self.list_page_size);
         tracing::debug!(
             "with_resources() - adding {} static resources to list handler",
             self.resources.len()
@@ -1116,16 +1513,25 @@ impl McpServerBuilder {
                 read_handler.add_template_resource_arc(template.clone(), resource.clone());
         }
 
-        // Update notification manager
+        // Subscriptions are accepted for any registered resource or template match
+        let mut subscribe_handler = ResourcesSubscribeHandler::new();
+        for resource in self.resources.values() {
+            subscribe_handler = subscribe_handler.add_resource_arc(resource.clone());
+        }
+        for (template, _) in &self.template_resources {
+            subscribe_handler = subscribe_handler.add_template(template.clone());
+        }
 
-        // Register both handlers
-        self.handler(list_handler).handler(read_handler)
+        self.handler(list_handler)
+            .handler(read_handler)
+            .handler(subscribe_handler)
+            .handler(ResourcesUnsubscribeHandler)
     }
 
     /// Add logging support
     pub fn with_logging(mut self) -> Self {
         self.capabilities.logging = Some(LoggingCapabilities::default());
-        self.handler(LoggingHandler)
+        self.handler(LoggingHandler::new())
     }
 
     /// Add roots support
@@ -1335,6 +1741,15 @@ impl McpServerBuilder {
         self
     }
 
+    /// Set the CORS policy, e.g. a list of allowed origins (requires "http" feature)
+    ///
+    /// Applies while CORS is enabled (the default). Without it every origin is allowed.
+    #[cfg(feature = "http")]
+    pub fn cors_config(mut self, cors: turul_http_mcp_server::CorsConfig) -> Self {
+        self.cors_config = Some(cors);
+        self
+    }
+
     /// Enable/disable SSE (requires "sse" feature)
     #[cfg(feature = "http")]
     pub fn sse(mut self, enable: bool) -> Self {
@@ -1352,6 +1767,232 @@ impl McpServerBuilder {
         self
     }
 
+    /// Set the response encodings offered via `Accept-Encoding`, in order of preference (requires "http" feature)
+    ///
+    /// Default: gzip, then deflate. Pass an empty list to disable compression.
+    #[cfg(feature = "http")]
+    pub fn compression(mut self, algorithms: Vec<turul_http_mcp_server::ContentEncoding>) -> Self {
+        self.compression_algorithms = Some(algorithms);
+        self
+    }
+
+    /// Set the minimum JSON response size, in bytes, that gets compressed (requires "http" feature)
+    #[cfg(feature = "http")]
+    pub fn compression_threshold(mut self, bytes: usize) -> Self {
+        self.compression_threshold = Some(bytes);
+        self
+    }
+
+    /// Set the largest POST body, in bytes, the server will read (requires "http" feature)
+    ///
+    /// Default: 1 MiB. Larger requests are rejected with HTTP 413 and a JSON-RPC
+    /// error before the rest of the body is read.
+    #[cfg(feature = "http")]
+    pub fn max_request_body_bytes(mut self, bytes: usize) -> Self {
+        self.max_request_body_bytes = Some(bytes);
+        self
+    }
+
+    /// Serve liveness probes at `path`, e.g. `/healthz` (requires "http" feature)
+    ///
+    /// Off by default. See [`turul_http_mcp_server::health`].
+    #[cfg(feature = "http")]
+    pub fn liveness_path(mut self, path: impl Into<String>) -> Self {
+        self.liveness_path = Some(path.into());
+        self
+    }
+
+    /// Serve readiness probes at `path`, e.g. `/readyz` (requires "http" feature)
+    ///
+    /// Off by default. Readiness pings the session storage and answers 503 while it fails.
+    #[cfg(feature = "http")]
+    pub fn readiness_path(mut self, path: impl Into<String>) -> Self {
+        self.readiness_path = Some(path.into());
+        self
+    }
+
+    /// Serve liveness at `/healthz` and readiness at `/readyz` (requires "http" feature)
+    #[cfg(feature = "http")]
+    pub fn health_checks(self) -> Self {
+        self.liveness_path(turul_http_mcp_server::DEFAULT_LIVENESS_PATH)
+            .readiness_path(turul_http_mcp_server::DEFAULT_READINESS_PATH)
+    }
+
+    /// Log every inbound and outbound JSON-RPC message as a tracing event at `level` (requires "http" feature)
+    ///
+    /// Events use the `turul_mcp_wire` target. See [`turul_http_mcp_server::wire_log`].
+    #[cfg(feature = "http")]
+    pub fn log_wire_json(mut self, level: tracing::Level) -> Self {
+        self.wire_log_level = Some(level);
+        self
+    }
+
+    /// Replace the object field names masked in wire logs (requires "http" feature)
+    #[cfg(feature = "http")]
+    pub fn redact_wire_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.wire_redact_fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Install a global interceptor for every parsed JSON-RPC request (requires "http" feature)
+    ///
+    /// Raw-protocol counterpart to [`middleware`](Self::middleware): the interceptor can
+    /// mutate the request (inject a default `_meta`, normalize a legacy field) or reject
+    /// it with an `McpError`. Runs after authentication and session validation, before
+    /// the middleware stack and dispatch.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turul_mcp_server::McpServer;
+    /// use turul_mcp_json_rpc_server::RequestParams;
+    /// use serde_json::json;
+    ///
+    /// let builder = McpServer::builder()
+    ///     .name("intercepted")
+    ///     .request_interceptor(|req| {
+    ///         if let Some(RequestParams::Object(params)) = req.params.as_mut() {
+    ///             params
+    ///                 .entry("_meta".to_string())
+    ///                 .or_insert_with(|| json!({"tenant": "default"}));
+    ///         }
+    ///         Ok(())
+    ///     });
+    /// ```
+    #[cfg(feature = "http")]
+    pub fn request_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(&mut turul_mcp_json_rpc_server::JsonRpcRequest) -> std::result::Result<(), McpError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.request_interceptor = Some(Arc::new(interceptor));
+        self
+    }
+
+    /// Trace each JSON-RPC request in an OpenTelemetry span (requires "otel" feature)
+    ///
+    /// One server span per request, named after the method, with the session id,
+    /// the tool name for `tools/call`, and a status taken from the response. The
+    /// parent is read from the `traceparent` header unless another
+    /// [`TraceContextExtractor`](turul_http_mcp_server::TraceContextExtractor) is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turul_mcp_server::McpServer;
+    /// use turul_http_mcp_server::{MetaTraceContext, RequestTracing};
+    ///
+    /// let builder = McpServer::builder()
+    ///     .name("traced")
+    ///     .request_tracing(RequestTracing::global().with_extractor(MetaTraceContext));
+    /// ```
+    #[cfg(feature = "otel")]
+    pub fn request_tracing(mut self, tracing: turul_http_mcp_server::RequestTracing) -> Self {
+        self.request_tracing = Some(tracing);
+        self
+    }
+
+    /// Enforce per-client-IP connection, session, and in-flight limits (requires "http" feature)
+    ///
+    /// Over-limit clients receive `429`; a server at its shared in-flight capacity
+    /// answers `503`. Set trusted proxies to resolve the client from `X-Forwarded-For`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use turul_mcp_server::McpServer;
+    /// use turul_http_mcp_server::IpLimitConfig;
+    ///
+    /// let builder = McpServer::builder()
+    ///     .name("guarded")
+    ///     .ip_limits(
+    ///         IpLimitConfig::new()
+    ///             .max_connections_per_ip(32)
+    ///             .max_sessions_per_ip(8)
+    ///             .max_in_flight_total(256)
+    ///             .trusted_proxies(["10.0.0.1".parse().unwrap()]),
+    ///     );
+    /// ```
+    #[cfg(feature = "http")]
+    pub fn ip_limits(mut self, config: turul_http_mcp_server::IpLimitConfig) -> Self {
+        self.ip_limits = Some(config);
+        self
+    }
+
+    /// Coalesce `notifications/resources/updated` bursts per session and URI
+    ///
+    /// The first update for a URI is sent immediately; updates arriving within the
+    /// next `window_ms` collapse into one notification carrying the latest state,
+    /// sent when the window closes. Default: every update is delivered.
+    #[cfg(feature = "http")]
+    pub fn resource_update_coalesce_window_ms(mut self, window_ms: u64) -> Self {
+        self.resource_update_coalesce_window_ms = Some(window_ms);
+        self
+    }
+
+    /// Limit how many SSE streams one session may hold open at once
+    ///
+    /// Once a session has `max` streams open, another GET stream is refused with
+    /// HTTP 429 while the existing ones carry on; a stream frees its slot when the
+    /// client disconnects. Default: no limit.
+    #[cfg(feature = "http")]
+    pub fn max_streams_per_session(mut self, max: usize) -> Self {
+        self.max_streams_per_session = Some(max);
+        self
+    }
+
+    /// Accept request bodies in `codec`'s format in addition to JSON
+    ///
+    /// A POST whose `Content-Type` names the codec is answered with a single
+    /// response in the same codec, e.g. MessagePack for internal service-to-service
+    /// traffic with `.json_rpc_codec(Arc::new(MessagePackCodec))` (`msgpack`
+    /// feature). JSON stays the default for every other request.
+    #[cfg(feature = "http")]
+    pub fn json_rpc_codec(
+        mut self,
+        codec: Arc<dyn turul_mcp_json_rpc_server::JsonRpcCodec>,
+    ) -> Self {
+        self.json_rpc_codecs.push(codec);
+        self
+    }
+
+    /// How long `McpServer::run_with_shutdown` waits for in-flight requests to
+    /// finish after the shutdown signal (default: 30 seconds)
+    #[cfg(feature = "http")]
+    pub fn shutdown_grace_period(mut self, grace: std::time::Duration) -> Self {
+        self.shutdown_grace_period = Some(grace);
+        self
+    }
+
+    /// Serve HTTPS with the PEM certificate chain and private key at these paths
+    ///
+    /// The files are read when the server starts. Use [`tls_config`](Self::tls_config)
+    /// to also offer HTTP/2 or reload the certificate on `SIGHUP`. Without TLS the
+    /// server speaks plain HTTP.
+    #[cfg(feature = "tls")]
+    pub fn tls(
+        self,
+        cert_path: impl Into<std::path::PathBuf>,
+        key_path: impl Into<std::path::PathBuf>,
+    ) -> Self {
+        self.tls_config(turul_http_mcp_server::TlsConfig::from_pem_files(
+            cert_path, key_path,
+        ))
+    }
+
+    /// Terminate TLS in-process as described by `tls`
+    #[cfg(feature = "tls")]
+    pub fn tls_config(mut self, tls: turul_http_mcp_server::TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
     /// Auto-generate security configuration based on registered resources
     fn build_resource_security(&self) -> crate::security::SecurityMiddleware {
         use crate::security::{AccessLevel, ResourceAccessControl, SecurityMiddleware};
@@ -1486,7 +2127,41 @@ impl McpServerBuilder {
         mime_types
     }
 
+    fn record_duplicate(&mut self, entry: String) {
+        if !self.duplicate_registrations.contains(&entry) {
+            self.duplicate_registrations.push(entry);
+        }
+    }
+
+    /// Resource templates whose patterns differ only in variable names
+    ///
+    /// `file:///{path}` and `file:///{name}` match exactly the same URIs, so
+    /// whichever is registered first would shadow the other.
+    fn template_collisions(&self) -> Vec<String> {
+        let mut seen: HashMap<String, &str> = HashMap::new();
+        let mut collisions = Vec::new();
+        for (template, _) in &self.template_resources {
+            let pattern = template.pattern();
+            let shape =
+                Self::template_to_regex_pattern(pattern).unwrap_or_else(|| pattern.to_string());
+            match seen.get(&shape) {
+                Some(first) => collisions.push(format!(
+                    "resource template '{}' (collides with '{}')",
+                    pattern, first
+                )),
+                None => {
+                    seen.insert(shape, pattern);
+                }
+            }
+        }
+        collisions
+    }
+
     /// Build the MCP server
+    ///
+    /// Fails with `McpError::ConfigurationError` when the configuration is
+    /// inconsistent, including when two tools, prompts or static resources
+    /// share a name or URI, or two resource templates match the same URIs.
     pub fn build(mut self) -> Result<McpServer> {
         // Validate configuration
         if self.name.is_empty() {
@@ -1504,9 +2179,30 @@ impl McpServerBuilder {
             )));
         }
 
+        // Reject registrations that would silently replace or shadow another
+        let mut duplicates = std::mem::take(&mut self.duplicate_registrations);
+        duplicates.extend(self.template_collisions());
+        if !duplicates.is_empty() {
+            return Err(McpError::configuration(&format!(
+                "Duplicate registrations:\n{}",
+                duplicates.join("\n")
+            )));
+        }
+
         // No coherence guard needed: Dynamic mode uses InMemory storage by default
         // when no explicit server_state_storage is provided.
 
+        // Coherence guard: a watched tool config needs the live registry of Dynamic mode
+        #[cfg(feature = "dynamic-tools")]
+        if self.tool_config_path.is_some()
+            && matches!(self.tool_change_mode, crate::ToolChangeMode::Static)
+        {
+            return Err(McpError::configuration(
+                "watch_config() requires ToolChangeMode::Dynamic. \
+                 Use .tool_change_mode(ToolChangeMode::Dynamic) on the builder.",
+            ));
+        }
+
         // Coherence guard: reject taskSupport=required without task runtime
         if self.task_runtime.is_none() {
             for (name, tool) in &self.tools {
@@ -1532,11 +2228,11 @@ impl McpServerBuilder {
         }
 
         // Auto-detect and configure server capabilities based on registered components
-        let has_tools = !self.tools.is_empty();
+        let has_tools = !self.tools.is_empty() || self.tool_router.is_some();
         let has_prompts = !self.prompts.is_empty();
         let has_roots = !self.roots.is_empty();
         let has_elicitations = !self.elicitations.is_empty();
-        let has_completions = !self.completions.is_empty();
+        let has_completions = !self.completions.is_empty() || self.completion_fn.is_some();
         let has_samplings = !self.sampling.is_empty();
         tracing::debug!("🔧 Has sampling configured: {}", has_samplings);
         let has_logging = !self.loggers.is_empty();
@@ -1575,12 +2271,25 @@ impl McpServerBuilder {
         // - File system watchers that update resource availability
         if has_resources {
             self.capabilities.resources = Some(ResourcesCapabilities {
-                subscribe: Some(false), // TODO: Implement resource subscriptions in Phase 5
+                subscribe: Some(true),
                 // Static framework: no dynamic change sources = no list changes
                 list_changed: Some(false),
             });
         }
 
+        // Runtime-changing lists opted in via list_changed_notifications()
+        if self.list_changed_notifications {
+            if let Some(tools) = self.capabilities.tools.as_mut() {
+                tools.list_changed = Some(true);
+            }
+            if let Some(prompts) = self.capabilities.prompts.as_mut() {
+                prompts.list_changed = Some(true);
+            }
+            if let Some(resources) = self.capabilities.resources.as_mut() {
+                resources.list_changed = Some(true);
+            }
+        }
+
         // Elicitation is a client-side capability per MCP 2025-11-25.
         // The server doesn't advertise elicitation support; it requests it from the client.
         let _ = has_elicitations; // suppress unused warning
@@ -1646,7 +2355,7 @@ impl McpServerBuilder {
         // Add RootsHandler if roots were configured
         let mut handlers = self.handlers;
         if !self.roots.is_empty() {
-            let mut roots_handler = RootsHandler::new();
+            let mut roots_handler = RootsHandler::new().with_page_size(self.list_page_size);
             for root in self.roots {
                 roots_handler = roots_handler.add_root(root);
             }
@@ -1655,7 +2364,8 @@ impl McpServerBuilder {
 
         // Add PromptsHandlers if prompts were configured
         if !self.prompts.is_empty() {
-            let mut prompts_list_handler = PromptsListHandler::new();
+            let mut prompts_list_handler =
+                PromptsListHandler::new().with_page_size(self.list_page_size);
             let mut prompts_get_handler = PromptsGetHandler::new();
 
             for prompt in self.prompts.values() {
@@ -1669,14 +2379,34 @@ impl McpServerBuilder {
 
         // Add ResourceTemplatesHandler if template resources were configured
         if !self.template_resources.is_empty() {
-            let resource_templates_handler =
-                ResourceTemplatesHandler::new().with_templates(self.template_resources.clone());
+            let resource_templates_handler = ResourceTemplatesHandler::new()
+                .with_templates(self.template_resources.clone())
+                .with_page_size(self.list_page_size);
             handlers.insert(
                 "resources/templates/list".to_string(),
                 Arc::new(resource_templates_handler),
             );
         }
 
+        // Notify the log level listener from logging/setLevel if one was configured
+        if let Some(listener) = self.log_level_listener {
+            handlers.insert(
+                "logging/setLevel".to_string(),
+                Arc::new(LoggingHandler::new().with_listener(listener)),
+            );
+        }
+
+        // Add DynamicCompletionHandler if a completion function was configured
+        if let Some(completion_fn) = self.completion_fn {
+            handlers.insert(
+                "completion/complete".to_string(),
+                Arc::new(
+                    DynamicCompletionHandler::new(completion_fn)
+                        .with_max_values(self.completion_max_values),
+                ),
+            );
+        }
+
         // Add ProvidedSamplingHandler if sampling providers were configured
         // This replaces the default SamplingHandler with one that actually calls
         // the registered providers' validate_request() and sample() methods
@@ -1723,12 +2453,27 @@ impl McpServerBuilder {
             _ => crate::tool::compute_tool_fingerprint(&self.tools),
         };
 
+        let mut registered_resources: Vec<_> = self
+            .resources
+            .values()
+            .map(|resource| crate::resource::resource_to_descriptor(resource.as_ref()))
+            .collect();
+        registered_resources.sort_by(|a, b| a.uri.cmp(&b.uri));
+        let mut registered_prompts: Vec<_> = self
+            .prompts
+            .values()
+            .map(|prompt| crate::prompt::prompt_to_descriptor(prompt.as_ref()))
+            .collect();
+        registered_prompts.sort_by(|a, b| a.name.cmp(&b.name));
+
         // Create server
         Ok(McpServer::new(
             implementation,
             self.capabilities,
             self.tools,
             handlers,
+            registered_resources,
+            registered_prompts,
             self.instructions,
             self.session_timeout_minutes,
             self.session_cleanup_interval_seconds,
@@ -1738,10 +2483,21 @@ impl McpServerBuilder {
             self.middleware_stack,
             self.route_registry,
             tool_fingerprint,
+            self.message_catalog,
+            self.list_page_size,
+            self.tool_router,
+            self.request_timeout,
+            self.idempotency_window,
+            #[cfg(feature = "audit")]
+            self.audit_sink
+                .map(|sink| crate::audit::AuditLog::new(sink, self.audit_argument_mode)),
             #[cfg(feature = "dynamic-tools")]
             !matches!(self.tool_change_mode, crate::ToolChangeMode::Static),
             #[cfg(feature = "dynamic-tools")]
             self.server_state_storage,
+            #[cfg(feature = "dynamic-tools")]
+            self.tool_config_path
+                .map(|path| (path, self.tool_config_watch_interval)),
             #[cfg(feature = "http")]
             self.bind_address,
             #[cfg(feature = "http")]
@@ -1752,6 +2508,38 @@ impl McpServerBuilder {
             self.enable_sse,
             #[cfg(feature = "http")]
             self.allow_unauthenticated_ping,
+            #[cfg(feature = "http")]
+            self.cors_config,
+            #[cfg(feature = "http")]
+            self.compression_algorithms,
+            #[cfg(feature = "http")]
+            self.compression_threshold,
+            #[cfg(feature = "http")]
+            self.max_request_body_bytes,
+            #[cfg(feature = "http")]
+            self.liveness_path,
+            #[cfg(feature = "http")]
+            self.readiness_path,
+            #[cfg(feature = "http")]
+            self.wire_log_level,
+            #[cfg(feature = "http")]
+            self.wire_redact_fields,
+            #[cfg(feature = "http")]
+            self.request_interceptor,
+            #[cfg(feature = "http")]
+            self.ip_limits,
+            #[cfg(feature = "http")]
+            self.resource_update_coalesce_window_ms,
+            #[cfg(feature = "http")]
+            self.max_streams_per_session,
+            #[cfg(feature = "http")]
+            self.json_rpc_codecs,
+            #[cfg(feature = "http")]
+            self.shutdown_grace_period,
+            #[cfg(feature = "otel")]
+            self.request_tracing,
+            #[cfg(feature = "tls")]
+            self.tls,
         ))
     }
 }
@@ -2488,4 +3276,53 @@ mod tests {
         let tools_cap = server.capabilities.tools.as_ref().unwrap();
         assert_eq!(tools_cap.list_changed, Some(true));
     }
+
+    fn add_tool(description: &str) -> turul_mcp_builders::tool::DynamicTool {
+        turul_mcp_builders::ToolBuilder::new("add")
+            .description(description)
+            .execute(|args| async move { Ok(args) })
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_duplicate_tool_names_rejected() {
+        let Err(err) = McpServerBuilder::new()
+            .name("test")
+            .tool(add_tool("Add two numbers"))
+            .tool(TestTool::new())
+            .tool(add_tool("Add three numbers"))
+            .build()
+        else {
+            panic!("duplicate tool names must fail the build");
+        };
+
+        let message = err.to_string();
+        assert!(matches!(err, McpError::ConfigurationError(_)));
+        assert!(message.contains("Duplicate registrations"), "{}", message);
+        assert!(message.contains("tool 'add'"), "{}", message);
+        assert!(!message.contains("tool 'test'"), "{}", message);
+    }
+
+    #[test]
+    fn test_resource_template_collision_rejected() {
+        let template = crate::uri_template::UriTemplate::new("template://data/{key}.json").unwrap();
+        let Err(err) = McpServerBuilder::new()
+            .name("test")
+            .resource(TemplateTestResource)
+            .template_resource(template, TemplateTestResource)
+            .build()
+        else {
+            panic!("templates matching the same URIs must fail the build");
+        };
+
+        let message = err.to_string();
+        assert!(
+            message.contains(
+                "resource template 'template://data/{key}.json' (collides with 'template://data/{id}.json')"
+            ),
+            "{}",
+            message
+        );
+    }
 }
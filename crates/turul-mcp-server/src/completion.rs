@@ -2,6 +2,10 @@
 //!
 //! This module defines the high-level trait for implementing MCP completion.
 
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use turul_mcp_builders::prelude::*;
 use turul_mcp_protocol::{
@@ -9,6 +13,17 @@ use turul_mcp_protocol::{
     completion::{CompleteRequest, CompleteResult},
 };
 
+pub use turul_mcp_builders::completion::MAX_COMPLETION_VALUES;
+
+/// Future returned by a [`CompletionFn`]
+pub type CompletionFuture = Pin<Box<dyn Future<Output = McpResult<Vec<String>>> + Send>>;
+
+/// Async completion source: `(argument name, partial value) -> candidate values`
+///
+/// The partial value is what the client has typed so far, so the provider can
+/// filter server-side (e.g. with a `LIKE 'prefix%'` query).
+pub type CompletionFn = Arc<dyn Fn(String, String) -> CompletionFuture + Send + Sync>;
+
 /// High-level trait for implementing MCP completion
 ///
 /// McpCompletion extends CompletionDefinition with execution capabilities.
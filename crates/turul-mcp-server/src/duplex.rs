@@ -0,0 +1,585 @@
+//! Bidirectional (duplex) streaming tools
+//!
+//! A duplex tool keeps a single logical `tools/call` open while exchanging many
+//! messages with the client — a REPL, a chat, an interactive wizard. Over
+//! Streamable HTTP the exchange is:
+//!
+//! 1. `tools/call` with `_meta.duplexCallId` — opens the channel. The POST stays
+//!    pending until the tool returns its final `CallToolResult`.
+//! 2. `tools/duplex/input` `{ "callId", "input" }` — delivers `input` to the tool and
+//!    responds once the tool is waiting for its next input (or has finished) with
+//!    every output emitted since: `{ "outputs": [...], "done": bool }`. Omitting
+//!    `input` only collects pending outputs.
+//! 3. `tools/duplex/close` `{ "callId" }` — closes the input side, so
+//!    [`DuplexChannel::recv`] returns `None`, and responds with the remaining
+//!    outputs once the tool has finished.
+//!
+//! Calls are scoped to the session that opened them: a follow-up request from a
+//! different session cannot address another session's call. Outputs that no
+//! follow-up request collects before the tool finishes are dropped, so tools should
+//! carry their final answer in the returned `CallToolResult`.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::sync::{mpsc, watch};
+use tracing::debug;
+use turul_mcp_builders::prelude::*;
+use turul_mcp_protocol::tools::{ToolAnnotations, ToolExecution, ToolSchema};
+use turul_mcp_protocol::{CallToolResult, Icon, McpError, McpResult};
+
+use crate::handlers::McpHandler;
+use crate::session::SessionContext;
+use crate::tool::McpTool;
+
+/// `_meta` key on `tools/call` carrying the client-chosen duplex call id
+pub const DUPLEX_CALL_ID_META_KEY: &str = "duplexCallId";
+
+/// Method delivering client input to an open duplex call
+pub const DUPLEX_INPUT_METHOD: &str = "tools/duplex/input";
+
+/// Method closing the input side of an open duplex call
+pub const DUPLEX_CLOSE_METHOD: &str = "tools/duplex/close";
+
+/// High-level trait for tools that exchange messages with the client mid-call
+///
+/// Metadata comes from [`ToolDefinition`] exactly as for [`McpTool`]; register with
+/// [`McpServerBuilder::duplex_tool`](crate::McpServerBuilder::duplex_tool).
+#[async_trait]
+pub trait McpDuplexTool: ToolDefinition {
+    /// Run the tool, reading client input from and emitting outputs to `channel`
+    async fn run(
+        &self,
+        args: Value,
+        channel: DuplexChannel,
+        session: Option<SessionContext>,
+    ) -> McpResult<CallToolResult>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DuplexPhase {
+    /// Tool is processing (or has not yet asked for input)
+    Busy,
+    /// Tool is blocked in `recv()` waiting for the next input
+    Idle,
+    /// Tool has returned
+    Done,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DuplexStatus {
+    phase: DuplexPhase,
+    /// Number of inputs the tool has received so far
+    consumed: u64,
+}
+
+/// Shared state between the tool's [`DuplexChannel`] and the follow-up handlers
+struct DuplexState {
+    input_tx: Mutex<Option<mpsc::UnboundedSender<Value>>>,
+    outputs: Mutex<Vec<Value>>,
+    status: watch::Sender<DuplexStatus>,
+}
+
+impl DuplexState {
+    fn consumed(&self) -> u64 {
+        self.status.borrow().consumed
+    }
+
+    fn set_phase(&self, phase: DuplexPhase) {
+        self.status.send_modify(|status| status.phase = phase);
+    }
+
+    fn drain_outputs(&self) -> Vec<Value> {
+        std::mem::take(&mut *self.outputs.lock().unwrap())
+    }
+
+    /// Queue an input for the tool; fails once the input side is closed
+    fn push_input(&self, input: Value) -> bool {
+        match self.input_tx.lock().unwrap().as_ref() {
+            Some(tx) => tx.send(input).is_ok(),
+            None => false,
+        }
+    }
+
+    fn close_input(&self) {
+        self.input_tx.lock().unwrap().take();
+    }
+
+    /// Wait until the tool has finished, or is idle having consumed at least
+    /// `consumed` inputs. Returns whether the tool has finished.
+    async fn settle(&self, consumed: u64, until_done: bool) -> bool {
+        let mut rx = self.status.subscribe();
+        let status = rx
+            .wait_for(|status| {
+                status.phase == DuplexPhase::Done
+                    || (!until_done
+                        && status.phase == DuplexPhase::Idle
+                        && status.consumed >= consumed)
+            })
+            .await
+            .map(|status| *status);
+        // The sender lives as long as `self`, so an error means the state is gone
+        status.map(|s| s.phase == DuplexPhase::Done).unwrap_or(true)
+    }
+}
+
+/// Tool-side handle of an open duplex call
+pub struct DuplexChannel {
+    call_id: String,
+    state: Arc<DuplexState>,
+    input_rx: mpsc::UnboundedReceiver<Value>,
+}
+
+impl DuplexChannel {
+    /// Client-chosen id of this call (`_meta.duplexCallId`)
+    pub fn call_id(&self) -> &str {
+        &self.call_id
+    }
+
+    /// Wait for the next client input; `None` once the client has closed the call
+    pub async fn recv(&mut self) -> Option<Value> {
+        self.state.set_phase(DuplexPhase::Idle);
+        let input = self.input_rx.recv().await;
+        if input.is_some() {
+            self.state.status.send_modify(|status| {
+                status.phase = DuplexPhase::Busy;
+                status.consumed += 1;
+            });
+        }
+        input
+    }
+
+    /// Emit an output; it is returned by the client's next input or close request
+    pub fn send(&self, output: impl Into<Value>) {
+        self.state.outputs.lock().unwrap().push(output.into());
+    }
+}
+
+impl Drop for DuplexChannel {
+    fn drop(&mut self) {
+        self.state.set_phase(DuplexPhase::Done);
+    }
+}
+
+/// Open duplex calls keyed by `(session_id, call_id)`
+///
+/// The lock is never held across an await, so a dropped call can deregister
+/// itself synchronously (see [`DuplexCallGuard`]).
+#[derive(Default)]
+pub(crate) struct DuplexRegistry {
+    calls: Mutex<HashMap<(String, String), Arc<DuplexState>>>,
+}
+
+impl DuplexRegistry {
+    fn open(&self, session_id: &str, call_id: &str) -> McpResult<DuplexChannel> {
+        let mut calls = self.calls.lock().unwrap();
+        let key = (session_id.to_string(), call_id.to_string());
+        if calls.contains_key(&key) {
+            return Err(McpError::InvalidParameters(format!(
+                "Duplex call '{}' is already open",
+                call_id
+            )));
+        }
+
+        let (input_tx, input_rx) = mpsc::unbounded_channel();
+        let (status, _) = watch::channel(DuplexStatus {
+            phase: DuplexPhase::Busy,
+            consumed: 0,
+        });
+        let state = Arc::new(DuplexState {
+            input_tx: Mutex::new(Some(input_tx)),
+            outputs: Mutex::new(Vec::new()),
+            status,
+        });
+        calls.insert(key, Arc::clone(&state));
+        debug!(
+            "Opened duplex call '{}' for session {}",
+            call_id, session_id
+        );
+
+        Ok(DuplexChannel {
+            call_id: call_id.to_string(),
+            state,
+            input_rx,
+        })
+    }
+
+    fn get(&self, session_id: &str, call_id: &str) -> McpResult<Arc<DuplexState>> {
+        self.calls
+            .lock()
+            .unwrap()
+            .get(&(session_id.to_string(), call_id.to_string()))
+            .cloned()
+            .ok_or_else(|| {
+                McpError::InvalidParameters(format!("Unknown duplex call '{}'", call_id))
+            })
+    }
+
+    /// Remove the call if it is still the one backed by `state`
+    fn remove(&self, session_id: &str, call_id: &str, state: &Arc<DuplexState>) {
+        let mut calls = self.calls.lock().unwrap();
+        let key = (session_id.to_string(), call_id.to_string());
+        if calls.get(&key).is_some_and(|open| Arc::ptr_eq(open, state)) {
+            calls.remove(&key);
+            debug!(
+                "Closed duplex call '{}' for session {}",
+                call_id, session_id
+            );
+        }
+    }
+}
+
+/// Ends an open call when dropped — when the tool returns, or when the
+/// `tools/call` future is dropped because the client cancelled or disconnected
+struct DuplexCallGuard {
+    registry: Arc<DuplexRegistry>,
+    session_id: String,
+    call_id: String,
+    state: Arc<DuplexState>,
+}
+
+impl Drop for DuplexCallGuard {
+    fn drop(&mut self) {
+        // The tool may have moved its channel elsewhere; the call is over either way
+        self.state.close_input();
+        self.state.set_phase(DuplexPhase::Done);
+        self.registry
+            .remove(&self.session_id, &self.call_id, &self.state);
+    }
+}
+
+/// Adapts an [`McpDuplexTool`] to [`McpTool`] so it is listed and called like any tool
+pub(crate) struct DuplexToolAdapter<T> {
+    inner: T,
+    registry: Arc<DuplexRegistry>,
+}
+
+impl<T> DuplexToolAdapter<T> {
+    pub(crate) fn new(inner: T, registry: Arc<DuplexRegistry>) -> Self {
+        Self { inner, registry }
+    }
+}
+
+impl<T: McpDuplexTool> HasBaseMetadata for DuplexToolAdapter<T> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+    fn title(&self) -> Option<&str> {
+        self.inner.title()
+    }
+}
+
+impl<T: McpDuplexTool> HasDescription for DuplexToolAdapter<T> {
+    fn description(&self) -> Option<&str> {
+        self.inner.description()
+    }
+}
+
+impl<T: McpDuplexTool> HasInputSchema for DuplexToolAdapter<T> {
+    fn input_schema(&self) -> &ToolSchema {
+        self.inner.input_schema()
+    }
+}
+
+impl<T: McpDuplexTool> HasOutputSchema for DuplexToolAdapter<T> {
+    fn output_schema(&self) -> Option<&ToolSchema> {
+        self.inner.output_schema()
+    }
+}
+
+impl<T: McpDuplexTool> HasAnnotations for DuplexToolAdapter<T> {
+    fn annotations(&self) -> Option<&ToolAnnotations> {
+        self.inner.annotations()
+    }
+}
+
+impl<T: McpDuplexTool> HasToolMeta for DuplexToolAdapter<T> {
+    fn tool_meta(&self) -> Option<&HashMap<String, Value>> {
+        self.inner.tool_meta()
+    }
+}
+
+impl<T: McpDuplexTool> HasIcons for DuplexToolAdapter<T> {
+    fn icons(&self) -> Option<&Vec<Icon>> {
+        self.inner.icons()
+    }
+}
+
+impl<T: McpDuplexTool> HasExecution for DuplexToolAdapter<T> {
+    fn execution(&self) -> Option<ToolExecution> {
+        self.inner.execution()
+    }
+}
+
+#[async_trait]
+impl<T: McpDuplexTool> McpTool for DuplexToolAdapter<T> {
+    async fn call(
+        &self,
+        args: Value,
+        session: Option<SessionContext>,
+    ) -> McpResult<CallToolResult> {
+        let session = session.ok_or_else(|| {
+            McpError::SessionError("Duplex tools require an active session".to_string())
+        })?;
+        let call_id = session
+            .request_meta()
+            .and_then(|meta| meta.extra.get(DUPLEX_CALL_ID_META_KEY))
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                McpError::MissingParameter(format!("_meta.{}", DUPLEX_CALL_ID_META_KEY))
+            })?
+            .to_string();
+        let channel = self.registry.open(&session.session_id, &call_id)?;
+        let _guard = DuplexCallGuard {
+            registry: Arc::clone(&self.registry),
+            session_id: session.session_id.clone(),
+            call_id,
+            state: Arc::clone(&channel.state),
+        };
+
+        self.inner.run(args, channel, Some(session)).await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplexInputParams {
+    call_id: String,
+    #[serde(default)]
+    input: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DuplexCloseParams {
+    call_id: String,
+}
+
+fn parse_params<P: serde::de::DeserializeOwned>(params: Option<Value>) -> McpResult<P> {
+    let params = params.ok_or_else(|| McpError::missing_param("callId"))?;
+    serde_json::from_value(params).map_err(|e| McpError::InvalidParameters(e.to_string()))
+}
+
+fn require_session(session: Option<SessionContext>) -> McpResult<SessionContext> {
+    session
+        .ok_or_else(|| McpError::SessionError("Duplex calls require an active session".to_string()))
+}
+
+/// Handler for `tools/duplex/input`
+pub(crate) struct DuplexInputHandler {
+    registry: Arc<DuplexRegistry>,
+}
+
+impl DuplexInputHandler {
+    pub(crate) fn new(registry: Arc<DuplexRegistry>) -> Self {
+        Self { registry }
+    }
+}
+
+#[async_trait]
+impl McpHandler for DuplexInputHandler {
+    async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
+        self.handle_with_session(params, None).await
+    }
+
+    async fn handle_with_session(
+        &self,
+        params: Option<Value>,
+        session: Option<SessionContext>,
+    ) -> McpResult<Value> {
+        let session = require_session(session)?;
+        let params: DuplexInputParams = parse_params(params)?;
+        let state = self.registry.get(&session.session_id, &params.call_id)?;
+
+        let mut target = state.consumed();
+        if let Some(input) = params.input {
+            if !state.push_input(input) {
+                return Err(McpError::InvalidParameters(format!(
+                    "Duplex call '{}' no longer accepts input",
+                    params.call_id
+                )));
+            }
+            target += 1;
+        }
+
+        let done = state.settle(target, false).await;
+        Ok(serde_json::json!({
+            "outputs": state.drain_outputs(),
+            "done": done,
+        }))
+    }
+
+    fn supported_methods(&self) -> Vec<String> {
+        vec![DUPLEX_INPUT_METHOD.to_string()]
+    }
+}
+
+/// Handler for `tools/duplex/close`
+pub(crate) struct DuplexCloseHandler {
+    registry: Arc<DuplexRegistry>,
+}
+
+impl DuplexCloseHandler {
+    pub(crate) fn new(registry: Arc<DuplexRegistry>) -> Self {
+        Self { registry }
+    }
+}
+
+#[async_trait]
+impl McpHandler for DuplexCloseHandler {
+    async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
+        self.handle_with_session(params, None).await
+    }
+
+    async fn handle_with_session(
+        &self,
+        params: Option<Value>,
+        session: Option<SessionContext>,
+    ) -> McpResult<Value> {
+        let session = require_session(session)?;
+        let params: DuplexCloseParams = parse_params(params)?;
+        let state = self.registry.get(&session.session_id, &params.call_id)?;
+
+        state.close_input();
+        let done = state.settle(0, true).await;
+        Ok(serde_json::json!({
+            "outputs": state.drain_outputs(),
+            "done": done,
+        }))
+    }
+
+    fn supported_methods(&self) -> Vec<String> {
+        vec![DUPLEX_CLOSE_METHOD.to_string()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_input_round_trip_settles_when_tool_idle() {
+        let registry = DuplexRegistry::default();
+        let mut channel = registry.open("s1", "c1").unwrap();
+        let state = registry.get("s1", "c1").unwrap();
+
+        let tool = tokio::spawn(async move {
+            while let Some(input) = channel.recv().await {
+                channel.send(serde_json::json!({ "echo": input }));
+            }
+        });
+
+        assert!(state.push_input(serde_json::json!("hello")));
+        let done = tokio::time::timeout(Duration::from_secs(1), state.settle(1, false))
+            .await
+            .unwrap();
+        assert!(!done);
+        assert_eq!(
+            state.drain_outputs(),
+            vec![serde_json::json!({ "echo": "hello" })]
+        );
+
+        state.close_input();
+        let done = tokio::time::timeout(Duration::from_secs(1), state.settle(0, true))
+            .await
+            .unwrap();
+        assert!(done);
+        tool.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_calls_are_scoped_to_session() {
+        let registry = DuplexRegistry::default();
+        let _channel = registry.open("s1", "c1").unwrap();
+
+        assert!(registry.get("s2", "c1").is_err());
+        assert!(registry.open("s1", "c1").is_err());
+        assert!(registry.open("s2", "c1").is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_closed_call_rejects_input() {
+        let registry = DuplexRegistry::default();
+        let _channel = registry.open("s1", "c1").unwrap();
+        let state = registry.get("s1", "c1").unwrap();
+
+        state.close_input();
+        assert!(!state.push_input(serde_json::json!("late")));
+    }
+
+    /// Waits for input until the client closes the call
+    struct DrainTool;
+
+    impl HasBaseMetadata for DrainTool {
+        fn name(&self) -> &str {
+            "drain"
+        }
+    }
+    impl HasDescription for DrainTool {}
+    impl HasInputSchema for DrainTool {
+        fn input_schema(&self) -> &ToolSchema {
+            static SCHEMA: std::sync::OnceLock<ToolSchema> = std::sync::OnceLock::new();
+            SCHEMA.get_or_init(ToolSchema::object)
+        }
+    }
+    impl HasOutputSchema for DrainTool {}
+    impl HasAnnotations for DrainTool {}
+    impl HasToolMeta for DrainTool {}
+    impl HasIcons for DrainTool {}
+    impl HasExecution for DrainTool {}
+
+    #[async_trait]
+    impl McpDuplexTool for DrainTool {
+        async fn run(
+            &self,
+            _args: Value,
+            mut channel: DuplexChannel,
+            _session: Option<SessionContext>,
+        ) -> McpResult<CallToolResult> {
+            while channel.recv().await.is_some() {}
+            Ok(CallToolResult::success(vec![]))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_call_frees_its_id() {
+        let registry = Arc::new(DuplexRegistry::default());
+        let tool = DuplexToolAdapter::new(DrainTool, Arc::clone(&registry));
+        let mut session = SessionContext::new_test();
+        session.set_request_meta(HashMap::from([(
+            DUPLEX_CALL_ID_META_KEY.to_string(),
+            serde_json::json!("c1"),
+        )]));
+        let session_id = session.session_id.clone();
+
+        // Dropping the pending call future is how a cancelled tools/call ends
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(50),
+            tool.call(serde_json::json!({}), Some(session.clone())),
+        )
+        .await;
+        assert!(cancelled.is_err());
+        assert!(registry.get(&session_id, "c1").is_err());
+
+        let reopened =
+            tokio::spawn(async move { tool.call(serde_json::json!({}), Some(session)).await });
+        let state = tokio::time::timeout(Duration::from_secs(1), async {
+            loop {
+                if let Ok(state) = registry.get(&session_id, "c1") {
+                    return state;
+                }
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("the same call id should open again");
+
+        state.close_input();
+        assert!(reopened.await.unwrap().is_ok());
+        assert!(registry.get(&session_id, "c1").is_err());
+    }
+}
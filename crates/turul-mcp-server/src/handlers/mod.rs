@@ -32,6 +32,33 @@ fn extract_limit_from_params(params: &Option<Value>) -> Option<usize> {
     })
 }
 
+/// Default number of items per page for `*/list` endpoints
+pub const DEFAULT_LIST_PAGE_SIZE: usize = 50;
+
+/// Encode the sort key of a page's last item as an opaque `nextCursor`
+///
+/// Cursors name a position in the sorted list rather than an offset, so a page
+/// boundary stays put when items are added or removed between calls.
+pub(crate) fn encode_list_cursor(key: &str) -> turul_mcp_protocol::meta::Cursor {
+    use base64::Engine;
+    turul_mcp_protocol::meta::Cursor::new(
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(key),
+    )
+}
+
+/// Decode a cursor issued by [`encode_list_cursor`]
+///
+/// Cursors this server did not issue are rejected with `InvalidParameters`
+/// (-32602) rather than silently restarting the listing at the first page.
+pub(crate) fn decode_list_cursor(cursor: &turul_mcp_protocol::meta::Cursor) -> McpResult<String> {
+    use base64::Engine;
+    base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(cursor.as_str())
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or_else(|| McpError::InvalidParameters(format!("Invalid cursor '{}'", cursor.as_str())))
+}
+
 /// Generic MCP handler trait
 #[async_trait]
 pub trait McpHandler: Send + Sync {
@@ -86,9 +113,60 @@ impl McpHandler for CompletionHandler {
     }
 }
 
+/// Completion handler backed by an async [`CompletionFn`](crate::CompletionFn)
+///
+/// The provider receives the argument name and partial value from each
+/// `completion/complete` request; its candidates are capped at `max_values`
+/// and `total`/`hasMore` report how many were cut.
+pub struct DynamicCompletionHandler {
+    provider: crate::CompletionFn,
+    max_values: usize,
+}
+
+impl DynamicCompletionHandler {
+    pub fn new(provider: crate::CompletionFn) -> Self {
+        Self {
+            provider,
+            max_values: crate::MAX_COMPLETION_VALUES,
+        }
+    }
+
+    /// Cap the number of values returned (never more than the spec's 100)
+    pub fn with_max_values(mut self, max_values: usize) -> Self {
+        self.max_values = max_values.min(crate::MAX_COMPLETION_VALUES);
+        self
+    }
+}
+
+#[async_trait]
+impl McpHandler for DynamicCompletionHandler {
+    async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
+        use turul_mcp_protocol::completion::{CompleteParams, CompleteResult, CompletionResult};
+
+        let params: CompleteParams =
+            serde_json::from_value(params.ok_or_else(|| McpError::missing_param("params"))?)?;
+        let argument = params.argument;
+
+        let mut values = (self.provider)(argument.name, argument.value).await?;
+        let total = values.len();
+        values.truncate(self.max_values);
+
+        let completion_result = CompletionResult::new(values)
+            .with_total(u32::try_from(total).unwrap_or(u32::MAX))
+            .with_has_more(total > self.max_values);
+        let response = CompleteResult::new(completion_result);
+        serde_json::to_value(response).map_err(McpError::from)
+    }
+
+    fn supported_methods(&self) -> Vec<String> {
+        vec!["completion/complete".to_string()]
+    }
+}
+
 /// Prompts list handler for prompts/list endpoint only
 pub struct PromptsListHandler {
     prompts: HashMap<String, Arc<dyn McpPrompt>>,
+    page_size: usize,
 }
 
 impl Default for PromptsListHandler {
@@ -101,9 +179,16 @@ impl PromptsListHandler {
     pub fn new() -> Self {
         Self {
             prompts: HashMap::new(),
+            page_size: DEFAULT_LIST_PAGE_SIZE,
         }
     }
 
+    /// Number of prompts per page when the request has no `limit`
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
     pub fn add_prompt<P: McpPrompt + 'static>(mut self, prompt: P) -> Self {
         self.prompts
             .insert(prompt.name().to_string(), Arc::new(prompt));
@@ -119,13 +204,20 @@ impl PromptsListHandler {
 #[async_trait]
 impl McpHandler for PromptsListHandler {
     async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
+        self.handle_with_session(params, None).await
+    }
+
+    async fn handle_with_session(
+        &self,
+        params: Option<Value>,
+        session: Option<SessionContext>,
+    ) -> McpResult<Value> {
         // Handle prompts/list with pagination support
-        use turul_mcp_protocol::meta::{Cursor, PaginatedResponse};
+        use turul_mcp_protocol::meta::PaginatedResponse;
         use turul_mcp_protocol::prompts::{ListPromptsParams, ListPromptsResult, Prompt};
 
         // Extract limit from raw params before parsing to typed params (MCP extension field)
         // Clamp to 1000 for DoS protection, reject zero
-        const DEFAULT_PAGE_SIZE: usize = 50;
         const MAX_PAGE_SIZE: usize = 1000;
         const MIN_PAGE_SIZE: usize = 1;
 
@@ -136,7 +228,7 @@ impl McpHandler for PromptsListHandler {
                 ));
             }
             Some(n) => n.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE),
-            None => DEFAULT_PAGE_SIZE,
+            None => self.page_size,
         };
 
         // Parse typed parameters with proper error handling (MCP compliance)
@@ -149,6 +241,13 @@ impl McpHandler for PromptsListHandler {
         };
 
         let cursor = list_params.cursor;
+        let locale = match crate::locale::locale_from_meta(list_params.meta.as_ref()) {
+            Some(locale) => Some(locale),
+            None => match &session {
+                Some(session) => session.locale().await,
+                None => None,
+            },
+        };
 
         debug!(
             "Listing prompts with cursor: {:?}, limit: {}",
@@ -161,7 +260,9 @@ impl McpHandler for PromptsListHandler {
             .values()
             .map(|p| {
                 let mut prompt = Prompt::new(p.name());
-                if let Some(desc) = p.description() {
+                let localized =
+                    crate::locale::localized_text(p.localized_descriptions(), locale.as_deref());
+                if let Some(desc) = localized.or(p.description()) {
                     prompt = prompt.with_description(desc);
                 }
                 // Include arguments from the prompt object
@@ -176,9 +277,9 @@ impl McpHandler for PromptsListHandler {
         all_prompts.sort_by(|a, b| a.name.cmp(&b.name));
 
         // Find starting index based on cursor
-        let start_index = if let Some(cursor) = &cursor {
-            // Cursor contains the last name from previous page
-            let cursor_name = cursor.as_str();
+        let start_index = if let Some(last) = cursor.as_ref().map(decode_list_cursor).transpose()? {
+            // Cursor encodes the last name from previous page
+            let cursor_name = last.as_str();
 
             // Find the position after the cursor name
             all_prompts
@@ -201,7 +302,7 @@ impl McpHandler for PromptsListHandler {
         // Generate next cursor if there are more prompts
         let next_cursor = if has_more {
             // Cursor should be the name of the last item in current page
-            page_prompts.last().map(|p| Cursor::new(&p.name))
+            page_prompts.last().map(|p| encode_list_cursor(&p.name))
         } else {
             None
         };
@@ -361,6 +462,7 @@ pub use crate::prompt::McpPrompt;
 /// Resources list handler for resources/list endpoint only
 pub struct ResourcesListHandler {
     resources: HashMap<String, Arc<dyn McpResource>>,
+    page_size: usize,
 }
 
 impl Default for ResourcesListHandler {
@@ -373,9 +475,16 @@ impl ResourcesListHandler {
     pub fn new() -> Self {
         Self {
             resources: HashMap::new(),
+            page_size: DEFAULT_LIST_PAGE_SIZE,
         }
     }
 
+    /// Number of resources per page when the request has no `limit`
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
     pub fn add_resource<R: McpResource + 'static>(mut self, resource: R) -> Self {
         self.resources
             .insert(resource.uri().to_string(), Arc::new(resource));
@@ -391,12 +500,19 @@ impl ResourcesListHandler {
 #[async_trait]
 impl McpHandler for ResourcesListHandler {
     async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
-        use turul_mcp_protocol::meta::{Cursor, PaginatedResponse};
+        self.handle_with_session(params, None).await
+    }
+
+    async fn handle_with_session(
+        &self,
+        params: Option<Value>,
+        session: Option<SessionContext>,
+    ) -> McpResult<Value> {
+        use turul_mcp_protocol::meta::PaginatedResponse;
         use turul_mcp_protocol::resources::{ListResourcesParams, ListResourcesResult, Resource};
 
         // Extract limit from raw params before parsing to typed params (MCP extension field)
         // Clamp to 1000 for DoS protection, reject zero
-        const DEFAULT_PAGE_SIZE: usize = 50;
         const MAX_PAGE_SIZE: usize = 1000;
         const MIN_PAGE_SIZE: usize = 1;
 
@@ -407,7 +523,7 @@ impl McpHandler for ResourcesListHandler {
                 ));
             }
             Some(n) => n.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE),
-            None => DEFAULT_PAGE_SIZE,
+            None => self.page_size,
         };
 
         // Parse typed parameters with proper error handling (MCP compliance)
@@ -420,6 +536,13 @@ impl McpHandler for ResourcesListHandler {
         };
 
         let cursor = list_params.cursor;
+        let locale = match crate::locale::locale_from_meta(list_params.meta.as_ref()) {
+            Some(locale) => Some(locale),
+            None => match &session {
+                Some(session) => session.locale().await,
+                None => None,
+            },
+        };
 
         debug!(
             "Listing resources with cursor: {:?}, limit: {}",
@@ -430,16 +553,24 @@ impl McpHandler for ResourcesListHandler {
         let mut all_resources: Vec<Resource> = self
             .resources
             .values()
-            .map(|r| resource_to_descriptor(r.as_ref()))
+            .map(|r| {
+                let mut resource = resource_to_descriptor(r.as_ref());
+                let localized =
+                    crate::locale::localized_text(r.localized_descriptions(), locale.as_deref());
+                if let Some(desc) = localized {
+                    resource.description = Some(desc.to_string());
+                }
+                resource
+            })
             .collect();
 
         // Sort by URI to ensure stable pagination ordering (MCP 2025-11-25 requirement)
         all_resources.sort_by(|a, b| a.uri.cmp(&b.uri));
 
         // Find starting index based on cursor
-        let start_index = if let Some(cursor) = &cursor {
-            // Cursor contains the last URI from previous page
-            let cursor_uri = cursor.as_str();
+        let start_index = if let Some(last) = cursor.as_ref().map(decode_list_cursor).transpose()? {
+            // Cursor encodes the last URI from previous page
+            let cursor_uri = last.as_str();
 
             // Find the position after the cursor URI
             all_resources
@@ -462,7 +593,7 @@ impl McpHandler for ResourcesListHandler {
         // Generate next cursor if there are more resources
         let next_cursor = if has_more {
             // Cursor should be the URI of the last item in current page
-            page_resources.last().map(|r| Cursor::new(&r.uri))
+            page_resources.last().map(|r| encode_list_cursor(&r.uri))
         } else {
             None
         };
@@ -748,11 +879,144 @@ impl McpHandler for ResourcesReadHandler {
     }
 }
 
+/// Resources subscribe handler for resources/subscribe endpoint
+///
+/// Records the subscription in session state; `McpServer::notify_resource_updated`
+/// then delivers `notifications/resources/updated` for that URI to this session.
+pub struct ResourcesSubscribeHandler {
+    uris: std::collections::HashSet<String>,
+    uri_registry: crate::uri_template::UriTemplateRegistry,
+}
+
+impl Default for ResourcesSubscribeHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResourcesSubscribeHandler {
+    pub fn new() -> Self {
+        Self {
+            uris: std::collections::HashSet::new(),
+            uri_registry: crate::uri_template::UriTemplateRegistry::new(),
+        }
+    }
+
+    pub fn add_resource_arc(mut self, resource: Arc<dyn McpResource>) -> Self {
+        self.uris.insert(resource.uri().to_string());
+        self
+    }
+
+    /// Accept subscriptions to any URI matching `template`
+    pub fn add_template(mut self, template: crate::uri_template::UriTemplate) -> Self {
+        self.uri_registry.register(template);
+        self
+    }
+
+    fn is_known(&self, uri: &str) -> bool {
+        self.uris.contains(uri) || self.uri_registry.find_matching(uri).is_some()
+    }
+}
+
+#[async_trait]
+impl McpHandler for ResourcesSubscribeHandler {
+    async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
+        self.handle_with_session(params, None).await
+    }
+
+    async fn handle_with_session(
+        &self,
+        params: Option<Value>,
+        session: Option<SessionContext>,
+    ) -> McpResult<Value> {
+        use turul_mcp_protocol::resources::SubscribeParams;
+
+        let params = params.ok_or_else(|| McpError::missing_param("SubscribeParams"))?;
+        let subscribe_params: SubscribeParams = serde_json::from_value(params)?;
+        let session = session
+            .ok_or_else(|| McpError::configuration("Session required for resources/subscribe"))?;
+
+        if !self.is_known(&subscribe_params.uri) {
+            return Err(McpError::ResourceNotFound(subscribe_params.uri));
+        }
+
+        (session.set_state)(
+            &crate::session::resource_subscription_key(&subscribe_params.uri),
+            json!(true),
+        )
+        .await;
+        debug!(
+            "Session {} subscribed to resource {}",
+            session.session_id, subscribe_params.uri
+        );
+
+        Ok(json!({}))
+    }
+
+    fn supported_methods(&self) -> Vec<String> {
+        vec!["resources/subscribe".to_string()]
+    }
+}
+
+/// Resources unsubscribe handler for resources/unsubscribe endpoint
+pub struct ResourcesUnsubscribeHandler;
+
+#[async_trait]
+impl McpHandler for ResourcesUnsubscribeHandler {
+    async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
+        self.handle_with_session(params, None).await
+    }
+
+    async fn handle_with_session(
+        &self,
+        params: Option<Value>,
+        session: Option<SessionContext>,
+    ) -> McpResult<Value> {
+        use turul_mcp_protocol::resources::UnsubscribeParams;
+
+        let params = params.ok_or_else(|| McpError::missing_param("UnsubscribeParams"))?;
+        let unsubscribe_params: UnsubscribeParams = serde_json::from_value(params)?;
+        let session = session
+            .ok_or_else(|| McpError::configuration("Session required for resources/unsubscribe"))?;
+
+        // Unsubscribing from a URI that was never subscribed is a no-op
+        (session.remove_state)(&crate::session::resource_subscription_key(
+            &unsubscribe_params.uri,
+        ))
+        .await;
+        debug!(
+            "Session {} unsubscribed from resource {}",
+            session.session_id, unsubscribe_params.uri
+        );
+
+        Ok(json!({}))
+    }
+
+    fn supported_methods(&self) -> Vec<String> {
+        vec!["resources/unsubscribe".to_string()]
+    }
+}
+
 /// Legacy handler for backward compatibility - use ResourcesListHandler instead
 pub type ResourcesHandler = ResourcesListHandler;
 
 /// Logging handler for logging/setLevel endpoint
-pub struct LoggingHandler;
+#[derive(Default)]
+pub struct LoggingHandler {
+    listener: Option<crate::LogLevelListener>,
+}
+
+impl LoggingHandler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Notify `listener` whenever a session changes its logging level
+    pub fn with_listener(mut self, listener: crate::LogLevelListener) -> Self {
+        self.listener = Some(listener);
+        self
+    }
+}
 
 #[async_trait]
 impl McpHandler for LoggingHandler {
@@ -816,6 +1080,10 @@ impl McpHandler for LoggingHandler {
             ));
         }
 
+        if let Some(listener) = &self.listener {
+            listener(&session_ctx.session_id, set_level_params.level);
+        }
+
         // Send confirmation notification
         session_ctx
             .notify_log(
@@ -841,6 +1109,7 @@ impl McpHandler for LoggingHandler {
 /// Roots handler for roots/list endpoint
 pub struct RootsHandler {
     roots: Vec<turul_mcp_protocol::roots::Root>,
+    page_size: usize,
 }
 
 impl Default for RootsHandler {
@@ -851,7 +1120,16 @@ impl Default for RootsHandler {
 
 impl RootsHandler {
     pub fn new() -> Self {
-        Self { roots: Vec::new() }
+        Self {
+            roots: Vec::new(),
+            page_size: DEFAULT_LIST_PAGE_SIZE,
+        }
+    }
+
+    /// Number of roots per page
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
     }
 
     pub fn add_root(mut self, root: turul_mcp_protocol::roots::Root) -> Self {
@@ -880,13 +1158,12 @@ impl McpHandler for RootsHandler {
         all_roots.sort_by(|a, b| a.uri.cmp(&b.uri));
 
         // Implement cursor-based pagination
-        const DEFAULT_PAGE_SIZE: usize = 50; // MCP suggested default
-        let page_size = DEFAULT_PAGE_SIZE;
+        let page_size = self.page_size;
 
         // Find starting index based on cursor
-        let start_index = if let Some(cursor) = &cursor {
-            // Cursor contains the last URI from previous page
-            let cursor_uri = cursor.as_str();
+        let start_index = if let Some(last) = cursor.as_ref().map(decode_list_cursor).transpose()? {
+            // Cursor encodes the last URI from previous page
+            let cursor_uri = last.as_str();
 
             // Find the position after the cursor URI
             all_roots
@@ -909,7 +1186,7 @@ impl McpHandler for RootsHandler {
         // Generate next cursor if there are more roots
         let next_cursor = if has_more {
             // Cursor should be the URI of the last item in current page
-            page_roots.last().map(|r| Cursor::new(&r.uri))
+            page_roots.last().map(|r| encode_list_cursor(&r.uri))
         } else {
             None
         };
@@ -1056,6 +1333,7 @@ impl McpHandler for ProvidedSamplingHandler {
 /// Resource templates handler for resources/templates/list endpoint
 pub struct ResourceTemplatesHandler {
     templates: Vec<(crate::uri_template::UriTemplate, Arc<dyn McpResource>)>,
+    page_size: usize,
 }
 
 impl Default for ResourceTemplatesHandler {
@@ -1068,9 +1346,16 @@ impl ResourceTemplatesHandler {
     pub fn new() -> Self {
         Self {
             templates: Vec::new(),
+            page_size: DEFAULT_LIST_PAGE_SIZE,
         }
     }
 
+    /// Number of templates per page when the request has no `limit`
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
     pub fn with_templates(
         mut self,
         templates: Vec<(crate::uri_template::UriTemplate, Arc<dyn McpResource>)>,
@@ -1083,11 +1368,8 @@ impl ResourceTemplatesHandler {
 #[async_trait]
 impl McpHandler for ResourceTemplatesHandler {
     async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
-        use turul_mcp_protocol::meta::Cursor;
-
         // Extract limit from raw params before parsing to typed params (MCP extension field)
         // Clamp to 1000 for DoS protection, reject zero
-        const DEFAULT_PAGE_SIZE: usize = 50;
         const MAX_PAGE_SIZE: usize = 1000;
         const MIN_PAGE_SIZE: usize = 1;
 
@@ -1098,7 +1380,7 @@ impl McpHandler for ResourceTemplatesHandler {
                 ));
             }
             Some(n) => n.clamp(MIN_PAGE_SIZE, MAX_PAGE_SIZE),
-            None => DEFAULT_PAGE_SIZE,
+            None => self.page_size,
         };
 
         // Parse typed parameters with proper error handling (MCP compliance)
@@ -1131,27 +1413,16 @@ impl McpHandler for ResourceTemplatesHandler {
         let mut all_templates: Vec<ResourceTemplate> = self
             .templates
             .iter()
-            .map(|(uri_template, resource)| {
-                let template_name = resource.name();
-                let mut template = ResourceTemplate::new(template_name, uri_template.pattern());
-                if let Some(desc) = resource.description() {
-                    template = template.with_description(desc);
-                }
-                // Add MIME type if the resource provides it
-                if let Some(mime_type) = resource.mime_type() {
-                    template = template.with_mime_type(mime_type);
-                }
-                template
-            })
+            .map(|(uri_template, resource)| resource.to_resource_template(uri_template.pattern()))
             .collect();
 
         // Sort by uri_template to ensure stable pagination ordering (MCP 2025-11-25 requirement)
         all_templates.sort_by(|a, b| a.uri_template.cmp(&b.uri_template));
 
         // Find starting index based on cursor
-        let start_index = if let Some(cursor) = &cursor {
-            // Cursor contains the last uri_template from previous page
-            let cursor_template = cursor.as_str();
+        let start_index = if let Some(last) = cursor.as_ref().map(decode_list_cursor).transpose()? {
+            // Cursor encodes the last uri_template from previous page
+            let cursor_template = last.as_str();
 
             // Find the position after the cursor template
             all_templates
@@ -1173,7 +1444,9 @@ impl McpHandler for ResourceTemplatesHandler {
         let has_more = end_index < all_templates.len();
         let next_cursor = if has_more {
             // Next cursor is the last template name in this page
-            page_templates.last().map(|t| Cursor::new(&t.uri_template))
+            page_templates
+                .last()
+                .map(|t| encode_list_cursor(&t.uri_template))
         } else {
             None // No more pages
         };
@@ -0,0 +1,210 @@
+//! Replay protection for `tools/call`
+//!
+//! A client that may retry a call after a network blip attaches an idempotency key,
+//! either as the `Idempotency-Key` HTTP header or as `_meta.idempotencyKey`. With
+//! [`McpServerBuilder::idempotency_window`](crate::McpServerBuilder::idempotency_window)
+//! set, the first call with a key runs the tool and its result is kept for the
+//! window; replays within the same session get that result without running the
+//! tool again. A replay that arrives while the first call is still running waits
+//! for it. Calls that fail with a JSON-RPC error are not cached, so they can be
+//! retried with the same key. Reusing a key for a different tool or different
+//! arguments is rejected with an invalid-params error rather than replayed.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::Value;
+use tokio::sync::OnceCell;
+use tokio::time::Instant;
+use tracing::debug;
+use turul_mcp_protocol::{McpError, McpResult};
+
+/// `_meta` key on `tools/call` carrying the client's idempotency key
+pub const IDEMPOTENCY_KEY_META_KEY: &str = "idempotencyKey";
+
+struct CachedCall {
+    created: Instant,
+    /// [`request_fingerprint`] of the call that claimed the key
+    fingerprint: u64,
+    result: Arc<OnceCell<Value>>,
+}
+
+/// Hash of a `tools/call` tool name and arguments, ignoring object key order
+pub(crate) fn request_fingerprint(name: Option<&Value>, arguments: Option<&Value>) -> u64 {
+    fn hash_value(value: &Value, hasher: &mut DefaultHasher) {
+        match value {
+            Value::Null => 0u8.hash(hasher),
+            Value::Bool(b) => (1u8, b).hash(hasher),
+            Value::Number(n) => (2u8, n.to_string()).hash(hasher),
+            Value::String(s) => (3u8, s).hash(hasher),
+            Value::Array(items) => {
+                (4u8, items.len()).hash(hasher);
+                for item in items {
+                    hash_value(item, hasher);
+                }
+            }
+            Value::Object(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                (5u8, entries.len()).hash(hasher);
+                for (key, item) in entries {
+                    key.hash(hasher);
+                    hash_value(item, hasher);
+                }
+            }
+        }
+    }
+
+    let mut hasher = DefaultHasher::new();
+    hash_value(name.unwrap_or(&Value::Null), &mut hasher);
+    hash_value(arguments.unwrap_or(&Value::Null), &mut hasher);
+    hasher.finish()
+}
+
+/// `tools/call` results keyed on `(session, idempotency key)`
+pub(crate) struct IdempotencyCache {
+    window: Duration,
+    calls: Mutex<HashMap<(String, String), CachedCall>>,
+}
+
+impl IdempotencyCache {
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            calls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return the cached result for `key` in `session_id`, or run `call` and cache it
+    ///
+    /// `fingerprint` identifies the request (see [`request_fingerprint`]); a key
+    /// already claimed by a request with a different fingerprint is an error.
+    pub(crate) async fn get_or_call<F, Fut>(
+        &self,
+        session_id: &str,
+        key: &str,
+        fingerprint: u64,
+        call: F,
+    ) -> McpResult<Value>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = McpResult<Value>>,
+    {
+        let result = {
+            let mut calls = self.calls.lock().unwrap();
+            let now = Instant::now();
+            calls.retain(|_, cached| now.duration_since(cached.created) < self.window);
+            let cached = calls
+                .entry((session_id.to_string(), key.to_string()))
+                .or_insert_with(|| CachedCall {
+                    created: now,
+                    fingerprint,
+                    result: Arc::new(OnceCell::new()),
+                });
+            if cached.fingerprint != fingerprint {
+                debug!(
+                    "Idempotency key {} in session {} reused with a different request",
+                    key, session_id
+                );
+                return Err(McpError::InvalidParameters(
+                    "idempotency key reused with different request".to_string(),
+                ));
+            }
+            Arc::clone(&cached.result)
+        };
+
+        if let Some(cached) = result.get() {
+            debug!(
+                "Replaying tools/call result for idempotency key {} in session {}",
+                key, session_id
+            );
+            return Ok(cached.clone());
+        }
+        result.get_or_try_init(call).await.cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test(start_paused = true)]
+    async fn test_results_are_scoped_to_session_and_expire() {
+        let cache = IdempotencyCache::new(Duration::from_secs(60));
+        let runs = AtomicUsize::new(0);
+        let call = || async {
+            let run = runs.fetch_add(1, Ordering::SeqCst);
+            Ok(json!({ "run": run }))
+        };
+
+        let first = cache.get_or_call("s1", "k", 7, call).await.unwrap();
+        let replay = cache.get_or_call("s1", "k", 7, call).await.unwrap();
+        assert_eq!(first, replay);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        // Same key in another session is a different call
+        cache.get_or_call("s2", "k", 7, call).await.unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        tokio::time::advance(Duration::from_secs(61)).await;
+        let expired = cache.get_or_call("s1", "k", 7, call).await.unwrap();
+        assert_eq!(expired, json!({ "run": 2 }));
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_not_cached() {
+        let cache = IdempotencyCache::new(Duration::from_secs(60));
+
+        let failed = cache
+            .get_or_call("s1", "k", 7, || async {
+                Err(McpError::ToolExecutionError("flaky".to_string()))
+            })
+            .await;
+        assert!(failed.is_err());
+
+        let retried = cache
+            .get_or_call("s1", "k", 7, || async { Ok(json!("ok")) })
+            .await
+            .unwrap();
+        assert_eq!(retried, json!("ok"));
+    }
+
+    #[tokio::test]
+    async fn test_key_reused_with_different_request_is_rejected() {
+        let cache = IdempotencyCache::new(Duration::from_secs(60));
+        let add = request_fingerprint(Some(&json!("add")), Some(&json!({ "a": 1, "b": 2 })));
+        let runs = AtomicUsize::new(0);
+        let call = || async {
+            runs.fetch_add(1, Ordering::SeqCst);
+            Ok(json!("sum"))
+        };
+
+        cache.get_or_call("s1", "k", add, call).await.unwrap();
+
+        // Key order does not change the request
+        let reordered = request_fingerprint(Some(&json!("add")), Some(&json!({ "b": 2, "a": 1 })));
+        assert_eq!(reordered, add);
+        assert_eq!(
+            cache.get_or_call("s1", "k", reordered, call).await.unwrap(),
+            json!("sum")
+        );
+
+        let other_args = request_fingerprint(Some(&json!("add")), Some(&json!({ "a": 1, "b": 3 })));
+        let other_tool = request_fingerprint(Some(&json!("sub")), Some(&json!({ "a": 1, "b": 2 })));
+        for fingerprint in [other_args, other_tool] {
+            let err = cache
+                .get_or_call("s1", "k", fingerprint, call)
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(err, McpError::InvalidParameters(ref msg) if msg == "idempotency key reused with different request")
+            );
+        }
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+    }
+}
@@ -118,8 +118,11 @@
 pub mod builder;
 pub mod cancellation;
 pub mod completion;
+pub mod duplex;
 pub mod elicitation;
 pub mod handlers;
+pub mod idempotency;
+pub mod locale;
 pub mod logging;
 pub mod middleware;
 pub mod notifications;
@@ -129,14 +132,20 @@ pub mod roots;
 pub mod sampling;
 pub mod server;
 pub mod session;
+pub mod streaming;
 pub mod task;
 pub mod tool;
 #[cfg(feature = "dynamic-tools")]
+pub mod tool_config;
+#[cfg(feature = "dynamic-tools")]
 pub mod tool_registry;
 // Re-export session storage from separate crate (breaks circular dependency)
 pub use turul_mcp_session_storage as session_storage;
 // Re-export task storage from separate crate
 pub use turul_mcp_task_storage as task_storage;
+// Audit trail of tool calls (sinks live in the session storage crate)
+#[cfg(feature = "audit")]
+pub use turul_mcp_session_storage::audit;
 pub mod dispatch;
 pub mod prelude;
 pub mod security;
@@ -154,15 +163,19 @@ pub use builder::McpServerBuilder;
 /// Cancellation handle for cooperative task cancellation
 pub use cancellation::CancellationHandle;
 /// Completion provider for text generation requests
-pub use completion::McpCompletion;
+pub use completion::{CompletionFn, MAX_COMPLETION_VALUES, McpCompletion};
 /// Request dispatching and middleware support for MCP operations
 pub use dispatch::{DispatchContext, DispatchMiddleware, McpDispatcher};
+/// Bidirectional streaming tools exchanging messages with the client mid-call
+pub use duplex::{DuplexChannel, McpDuplexTool};
 /// Elicitation handler for interactive form-based data collection
 pub use elicitation::McpElicitation;
 /// Collection of built-in MCP request handlers
 pub use handlers::*;
+/// Message catalog for localized framework error messages
+pub use locale::MessageCatalog;
 /// Logging provider for structured application logs
-pub use logging::McpLogger;
+pub use logging::{LogLevelListener, McpLogger, tracing_level};
 /// Notification system for real-time client updates via SSE
 pub use notifications::McpNotification;
 /// Prompt provider for generating conversation templates
@@ -175,7 +188,7 @@ pub use roots::McpRoot;
 pub use sampling::McpSampling;
 /// Security middleware and access control components
 pub use security::{
-    AccessLevel, InputValidator, RateLimitConfig, ResourceAccessControl, SecurityMiddleware,
+    AccessLevel, InputValidator, Quota, RateLimitConfig, ResourceAccessControl, SecurityMiddleware,
 };
 /// Core MCP server and session-aware handlers
 pub use server::{
@@ -183,7 +196,11 @@ pub use server::{
     SessionAwareToolHandler,
 };
 /// Session management and context for stateful operations
-pub use session::{SessionContext, SessionEvent, SessionEventDispatcher, SessionManager};
+pub use session::{
+    ProgressReporter, SessionContext, SessionEvent, SessionEventDispatcher, SessionManager,
+};
+/// Streaming tools emitting content chunks as they are produced
+pub use streaming::{ChunkSink, McpStreamingTool};
 /// Task executor abstraction for pluggable execution backends
 pub use task::executor::{TaskExecutor, TaskHandle};
 /// Task handlers for tasks/get, tasks/list, tasks/cancel, tasks/result
@@ -194,10 +211,15 @@ pub use task::handlers::{
 pub use task::runtime::TaskRuntime;
 /// Default Tokio-based task executor
 pub use task::tokio_executor::TokioTaskExecutor;
-/// Tool trait for executable MCP functions
-pub use tool::McpTool;
+/// Token a tool polls to stop work its request no longer needs
+pub use tokio_util::sync::CancellationToken;
 /// Stable fingerprint of the registered tool set for session versioning
 pub use tool::compute_tool_fingerprint;
+/// Tool trait for executable MCP functions
+pub use tool::{LazyTool, McpTool, ToolRouter};
+/// Hot-reloads the active tool set from a watched config file
+#[cfg(feature = "dynamic-tools")]
+pub use tool_config::ToolConfigWatcher;
 /// Runtime tool activation mode — controls fingerprint vs live notification behavior
 #[cfg(feature = "dynamic-tools")]
 pub use tool_registry::ToolRegistry;
@@ -232,14 +254,25 @@ pub enum ToolChangeMode {
 pub use turul_mcp_session_storage::SessionView;
 
 // Re-export foundational types
+/// MessagePack wire codec for `McpServerBuilder::json_rpc_codec`
+#[cfg(feature = "msgpack")]
+pub use turul_mcp_json_rpc_server::MessagePackCodec;
+
+/// In-process TLS termination for `McpServerBuilder::tls_config`
+#[cfg(feature = "tls")]
+pub use turul_http_mcp_server::TlsConfig;
 /// JSON-RPC 2.0 request dispatcher and handler trait for protocol operations
-pub use turul_mcp_json_rpc_server::{JsonRpcDispatcher, JsonRpcHandler};
+pub use turul_mcp_json_rpc_server::{
+    Correlation, JsonCodec, JsonRpcCodec, JsonRpcDispatcher, JsonRpcHandler,
+};
 /// Core MCP protocol types, errors, and specification compliance
 pub use turul_mcp_protocol::*;
 
 // Re-export builder pattern for Level 3 tool creation
+/// Dynamic resource creation, including file-backed resources
+pub use turul_mcp_builders::resource::{DynamicResource, ResourceBuilder};
 /// Dynamic tool creation with runtime configuration and type-safe builders
-pub use turul_mcp_builders::tool::{DynamicTool, DynamicToolFn, ToolBuilder};
+pub use turul_mcp_builders::tool::{DynamicTool, DynamicToolFn, FallibleToolFn, ToolBuilder};
 
 // Explicitly re-export error types for convenience
 /// Domain error type for MCP operations with protocol conversion support
@@ -273,12 +306,22 @@ impl McpTool for DynamicTool {
         use turul_mcp_builders::prelude::HasOutputSchema;
         use turul_mcp_protocol::tools::CallToolResult;
 
-        match self.execute(args).await {
-            Ok(result) => {
-                // Use smart response builder with automatic structured content
-                CallToolResult::from_result_with_schema(&result, self.output_schema())
-            }
-            Err(e) => Err(McpError::tool_execution(&e)),
-        }
+        // Errors pass through as-is so typed McpErrors keep their JSON-RPC codes
+        let result = self.try_execute(args).await?;
+        // Use smart response builder with automatic structured content
+        CallToolResult::from_result_with_schema(&result, self.output_schema())
+    }
+}
+
+/// Implements McpResource for DynamicResource so builder-made resources can be
+/// registered with `McpServer::builder().resource(...)`
+#[async_trait::async_trait]
+impl McpResource for DynamicResource {
+    async fn read(
+        &self,
+        _params: Option<serde_json::Value>,
+        _session: Option<&SessionContext>,
+    ) -> McpResult<Vec<turul_mcp_protocol::resources::ResourceContent>> {
+        Ok(vec![DynamicResource::read(self).await?])
     }
 }
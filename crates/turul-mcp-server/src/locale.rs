@@ -0,0 +1,365 @@
+//! Per-session locale negotiation and localized framework messages
+//!
+//! A client declares its preferred locale as `_meta.locale` on `initialize`
+//! (remembered for the session) or on any later request (applies to that
+//! request only). Tools, prompts and resources can offer translated
+//! descriptions through `localized_descriptions()`; the list handlers pick the
+//! best match and fall back to the default description.
+//!
+//! Framework error messages are localized through a [`MessageCatalog`]
+//! registered with `McpServer::builder().message_catalog(...)`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::Value;
+use turul_mcp_json_rpc_server::{JsonRpcHandler, RequestParams, SessionContext};
+use turul_mcp_protocol::McpError;
+
+use crate::session::SessionManager;
+
+/// Session state key holding the locale declared in `initialize`
+pub const LOCALE_STATE_KEY: &str = "mcp:locale";
+
+/// Read `_meta.locale` from request params, ignoring blank values
+pub fn locale_from_params(params: &Value) -> Option<String> {
+    params
+        .get("_meta")?
+        .get("locale")?
+        .as_str()
+        .map(str::trim)
+        .filter(|locale| !locale.is_empty())
+        .map(String::from)
+}
+
+/// Read `locale` from an already-parsed `_meta` map
+pub(crate) fn locale_from_meta(meta: Option<&HashMap<String, Value>>) -> Option<String> {
+    meta?
+        .get("locale")?
+        .as_str()
+        .map(str::trim)
+        .filter(|locale| !locale.is_empty())
+        .map(String::from)
+}
+
+fn normalize(tag: &str) -> String {
+    tag.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+/// Pick the available locale tag that best serves `requested`.
+///
+/// Matching is case-insensitive and treats `_` like `-`. An exact match wins,
+/// then progressively shorter prefixes of the request (`fr-CA` → `fr`), then any
+/// tag with the same primary language (`fr` → `fr-FR`, first in sort order).
+pub fn best_match<'a>(
+    available: impl IntoIterator<Item = &'a str>,
+    requested: &str,
+) -> Option<&'a str> {
+    let available: Vec<(&str, String)> = available
+        .into_iter()
+        .map(|tag| (tag, normalize(tag)))
+        .collect();
+    let requested = normalize(requested);
+
+    let mut prefix = requested.as_str();
+    loop {
+        if let Some((tag, _)) = available.iter().find(|(_, norm)| norm == prefix) {
+            return Some(tag);
+        }
+        match prefix.rfind('-') {
+            Some(cut) => prefix = &prefix[..cut],
+            None => break,
+        }
+    }
+
+    let language = requested.split('-').next().unwrap_or_default();
+    available
+        .iter()
+        .filter(|(_, norm)| norm.split('-').next() == Some(language))
+        .map(|(tag, _)| *tag)
+        .min()
+}
+
+/// Select the translation for `locale`, or `None` to keep the default text
+pub fn localized_text<'a>(
+    translations: Option<&'a HashMap<String, String>>,
+    locale: Option<&str>,
+) -> Option<&'a str> {
+    let (translations, locale) = (translations?, locale?);
+    let tag = best_match(translations.keys().map(String::as_str), locale)?;
+    translations.get(tag).map(String::as_str)
+}
+
+/// Translated templates for framework error messages
+///
+/// Templates are keyed by locale and message key; `{placeholder}` fields are
+/// filled from the error. Keys and their placeholders:
+///
+/// | Key | Placeholders |
+/// |-----|--------------|
+/// | `tool_not_found` | `{name}` |
+/// | `prompt_not_found` | `{name}` |
+/// | `resource_not_found` | `{uri}` |
+/// | `resource_access_denied` | `{uri}` |
+/// | `missing_parameter` | `{param}` |
+/// | `invalid_parameter_type` | `{param}`, `{expected}`, `{actual}` |
+/// | `parameter_out_of_range` | `{param}`, `{value}`, `{constraint}` |
+/// | `invalid_parameters` | `{message}` |
+/// | `tool_execution_failed` | `{message}` |
+/// | `validation_error` | `{message}` |
+/// | `session_error` | `{message}` |
+///
+/// The JSON-RPC error code and data are unchanged; errors without a template
+/// for the session's locale keep their English message.
+///
+/// ```rust
+/// use turul_mcp_server::MessageCatalog;
+///
+/// let catalog = MessageCatalog::new()
+///     .message("fr", "tool_not_found", "Outil introuvable : {name}")
+///     .message("fr", "missing_parameter", "Paramètre requis manquant : {param}");
+/// assert_eq!(
+///     catalog.lookup("fr-CA", "tool_not_found"),
+///     Some("Outil introuvable : {name}")
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageCatalog {
+    messages: HashMap<String, HashMap<String, String>>,
+}
+
+impl MessageCatalog {
+    /// Create an empty catalog
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a message template for `locale`
+    pub fn message(
+        mut self,
+        locale: impl Into<String>,
+        key: impl Into<String>,
+        template: impl Into<String>,
+    ) -> Self {
+        self.messages
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), template.into());
+        self
+    }
+
+    /// Find the template for `key` in the catalog locale that best matches `locale`
+    pub fn lookup(&self, locale: &str, key: &str) -> Option<&str> {
+        let tag = best_match(self.messages.keys().map(String::as_str), locale)?;
+        self.messages.get(tag)?.get(key).map(String::as_str)
+    }
+
+    /// Replace the error's message with the translation for `locale`, if any
+    pub fn localize_error(&self, error: McpError, locale: &str) -> McpError {
+        let Some((key, fields)) = message_fields(&error) else {
+            return error;
+        };
+        let Some(template) = self.lookup(locale, key) else {
+            return error;
+        };
+        let message = fields
+            .iter()
+            .fold(template.to_string(), |message, (name, value)| {
+                message.replace(&format!("{{{}}}", name), value)
+            });
+        let object = error.to_error_object();
+        McpError::JsonRpcError {
+            code: object.code,
+            message,
+            data: object.data,
+        }
+    }
+}
+
+/// Catalog key and template fields for the framework errors that can be localized
+fn message_fields(error: &McpError) -> Option<(&'static str, Vec<(&'static str, &str)>)> {
+    Some(match error {
+        McpError::ToolNotFound(name) => ("tool_not_found", vec![("name", name)]),
+        McpError::PromptNotFound(name) => ("prompt_not_found", vec![("name", name)]),
+        McpError::ResourceNotFound(uri) => ("resource_not_found", vec![("uri", uri)]),
+        McpError::ResourceAccessDenied(uri) => ("resource_access_denied", vec![("uri", uri)]),
+        McpError::MissingParameter(param) => ("missing_parameter", vec![("param", param)]),
+        McpError::InvalidParameterType {
+            param,
+            expected,
+            actual,
+        } => (
+            "invalid_parameter_type",
+            vec![("param", param), ("expected", expected), ("actual", actual)],
+        ),
+        McpError::ParameterOutOfRange {
+            param,
+            value,
+            constraint,
+        } => (
+            "parameter_out_of_range",
+            vec![
+                ("param", param),
+                ("value", value),
+                ("constraint", constraint),
+            ],
+        ),
+        McpError::InvalidParameters(message) => ("invalid_parameters", vec![("message", message)]),
+        McpError::ToolExecutionError(message) => {
+            ("tool_execution_failed", vec![("message", message)])
+        }
+        McpError::ValidationError(message) => ("validation_error", vec![("message", message)]),
+        McpError::SessionError(message) => ("session_error", vec![("message", message)]),
+        _ => return None,
+    })
+}
+
+/// Wraps a JSON-RPC handler so its errors are translated for the caller's locale
+pub(crate) struct LocalizedErrors<H> {
+    inner: H,
+    catalog: Option<Arc<MessageCatalog>>,
+    session_manager: Arc<SessionManager>,
+}
+
+impl<H> LocalizedErrors<H> {
+    pub(crate) fn new(
+        inner: H,
+        catalog: Option<Arc<MessageCatalog>>,
+        session_manager: Arc<SessionManager>,
+    ) -> Self {
+        Self {
+            inner,
+            catalog,
+            session_manager,
+        }
+    }
+}
+
+#[async_trait]
+impl<H> JsonRpcHandler for LocalizedErrors<H>
+where
+    H: JsonRpcHandler<Error = McpError>,
+{
+    type Error = McpError;
+
+    async fn handle(
+        &self,
+        method: &str,
+        params: Option<RequestParams>,
+        session_context: Option<SessionContext>,
+    ) -> Result<Value, McpError> {
+        let Some(catalog) = &self.catalog else {
+            return self.inner.handle(method, params, session_context).await;
+        };
+
+        let request_locale = params
+            .as_ref()
+            .and_then(|params| locale_from_params(&params.to_value()));
+        let session_id = session_context.as_ref().map(|ctx| ctx.session_id.clone());
+
+        let error = match self.inner.handle(method, params, session_context).await {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+
+        let locale = match (request_locale, session_id) {
+            (Some(locale), _) => Some(locale),
+            (None, Some(session_id)) => self
+                .session_manager
+                .get_session_state(&session_id, LOCALE_STATE_KEY)
+                .await
+                .and_then(|value| value.as_str().map(String::from)),
+            (None, None) => None,
+        };
+        Err(match locale {
+            Some(locale) => catalog.localize_error(error, &locale),
+            None => error,
+        })
+    }
+
+    async fn handle_notification(
+        &self,
+        method: &str,
+        params: Option<RequestParams>,
+        session_context: Option<SessionContext>,
+    ) -> Result<(), McpError> {
+        self.inner
+            .handle_notification(method, params, session_context)
+            .await
+    }
+
+    fn supported_methods(&self) -> Vec<String> {
+        self.inner.supported_methods()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn translations() -> HashMap<String, String> {
+        HashMap::from([
+            ("fr".to_string(), "Additionner deux nombres".to_string()),
+            ("pt-BR".to_string(), "Somar dois números".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_best_match_prefers_exact_then_prefix_then_language() {
+        let available = ["fr", "fr-CA", "pt-BR", "de-DE"];
+        assert_eq!(best_match(available, "fr-CA"), Some("fr-CA"));
+        assert_eq!(best_match(available, "fr_ch"), Some("fr"));
+        assert_eq!(best_match(available, "pt"), Some("pt-BR"));
+        assert_eq!(best_match(available, "DE"), Some("de-DE"));
+        assert_eq!(best_match(available, "es"), None);
+    }
+
+    #[test]
+    fn test_localized_text_falls_back_to_none() {
+        let translations = translations();
+        assert_eq!(
+            localized_text(Some(&translations), Some("fr-FR")),
+            Some("Additionner deux nombres")
+        );
+        assert_eq!(localized_text(Some(&translations), Some("ja")), None);
+        assert_eq!(localized_text(Some(&translations), None), None);
+        assert_eq!(localized_text(None, Some("fr")), None);
+    }
+
+    #[test]
+    fn test_locale_from_params() {
+        assert_eq!(
+            locale_from_params(&json!({"_meta": {"locale": "fr-CA"}})).as_deref(),
+            Some("fr-CA")
+        );
+        assert_eq!(locale_from_params(&json!({"_meta": {"locale": " "}})), None);
+        assert_eq!(locale_from_params(&json!({"cursor": "x"})), None);
+    }
+
+    #[test]
+    fn test_catalog_localizes_error_and_keeps_code() {
+        let catalog = MessageCatalog::new().message(
+            "fr",
+            "invalid_parameter_type",
+            "Type invalide pour « {param} » : {expected} attendu, {actual} reçu",
+        );
+
+        let error = McpError::invalid_param_type("a", "number", "string");
+        let code = error.to_error_object().code;
+        let localized = catalog.localize_error(error, "fr-FR");
+        let object = localized.to_error_object();
+        assert_eq!(object.code, code);
+        assert_eq!(
+            object.message,
+            "Type invalide pour « a » : number attendu, string reçu"
+        );
+
+        // No French template for this key, and no German catalog at all
+        let untouched = catalog.localize_error(McpError::ToolNotFound("x".into()), "fr");
+        assert!(matches!(untouched, McpError::ToolNotFound(_)));
+        let untouched = catalog.localize_error(McpError::invalid_param_type("a", "b", "c"), "de");
+        assert!(matches!(untouched, McpError::InvalidParameterType { .. }));
+    }
+}
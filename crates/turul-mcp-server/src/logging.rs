@@ -2,6 +2,8 @@
 //!
 //! This module defines the high-level trait for implementing MCP logging.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use serde_json::Value;
 use turul_mcp_builders::prelude::*;
@@ -104,6 +106,29 @@ pub fn logger_to_notification(
     notification
 }
 
+/// Callback run after a session changes its level through `logging/setLevel`
+///
+/// Receives the session ID and the new minimum level. Register one with
+/// [`McpServerBuilder::on_log_level_change`](crate::McpServerBuilder::on_log_level_change),
+/// e.g. to reload a `tracing_subscriber::EnvFilter` so the server's own
+/// tracing output follows the client's requested verbosity.
+pub type LogLevelListener = Arc<dyn Fn(&str, LoggingLevel) + Send + Sync>;
+
+/// Map an MCP logging level onto the closest `tracing` level
+///
+/// `notice` folds into `INFO`; `critical`, `alert` and `emergency` fold into `ERROR`.
+pub fn tracing_level(level: LoggingLevel) -> tracing::Level {
+    match level {
+        LoggingLevel::Debug => tracing::Level::DEBUG,
+        LoggingLevel::Info | LoggingLevel::Notice => tracing::Level::INFO,
+        LoggingLevel::Warning => tracing::Level::WARN,
+        LoggingLevel::Error
+        | LoggingLevel::Critical
+        | LoggingLevel::Alert
+        | LoggingLevel::Emergency => tracing::Level::ERROR,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
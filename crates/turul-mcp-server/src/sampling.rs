@@ -9,6 +9,10 @@ use turul_mcp_protocol::{
     sampling::{CreateMessageRequest, CreateMessageResult},
 };
 
+/// How long [`SessionContext::create_message`](crate::SessionContext::create_message)
+/// waits for the client to answer
+pub const DEFAULT_SAMPLING_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
 /// High-level trait for implementing MCP sampling
 ///
 /// McpSampling extends SamplingDefinition with execution capabilities.
@@ -15,7 +15,53 @@ use std::time::{Duration, Instant};
 use crate::SessionContext;
 use turul_mcp_protocol::McpError;
 
+/// Request budget for a single rate-limit bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    /// Maximum requests per window
+    pub max_requests: u32,
+    /// Time window duration
+    pub window_duration: Duration,
+    /// Burst allowance (temporary exceeding of rate limit)
+    pub burst_size: u32,
+}
+
+impl Quota {
+    /// `max_requests` per `window_duration`, with no burst allowance
+    pub fn new(max_requests: u32, window_duration: Duration) -> Self {
+        Self {
+            max_requests,
+            window_duration,
+            burst_size: 0,
+        }
+    }
+
+    /// `max_requests` per minute, with no burst allowance
+    pub fn per_minute(max_requests: u32) -> Self {
+        Self::new(max_requests, Duration::from_secs(60))
+    }
+
+    pub fn with_burst(mut self, burst_size: u32) -> Self {
+        self.burst_size = burst_size;
+        self
+    }
+}
+
 /// Rate limiting configuration
+///
+/// Requests are counted per `(session_id, method)`. Methods listed in
+/// `per_method` get their own quota; every other method falls back to the
+/// default quota given by `max_requests`, `window_duration` and `burst_size`.
+///
+/// ```rust
+/// use turul_mcp_server::security::{Quota, RateLimitConfig};
+///
+/// let config = RateLimitConfig::default()
+///     .with_method_quota("sampling/createMessage", Quota::per_minute(5))
+///     .with_method_quota("tools/list", Quota::per_minute(100));
+/// assert_eq!(config.quota_for("sampling/createMessage").max_requests, 5);
+/// assert_eq!(config.quota_for("tools/call"), config.default_quota());
+/// ```
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
     /// Maximum requests per window
@@ -24,6 +70,8 @@ pub struct RateLimitConfig {
     pub window_duration: Duration,
     /// Burst allowance (temporary exceeding of rate limit)
     pub burst_size: u32,
+    /// Method name -> quota, overriding the default for that method
+    pub per_method: HashMap<String, Quota>,
 }
 
 impl Default for RateLimitConfig {
@@ -32,18 +80,44 @@ impl Default for RateLimitConfig {
             max_requests: 100,
             window_duration: Duration::from_secs(60),
             burst_size: 10,
+            per_method: HashMap::new(),
         }
     }
 }
 
+impl RateLimitConfig {
+    /// Set the quota for one method, overriding the default
+    pub fn with_method_quota(mut self, method: impl Into<String>, quota: Quota) -> Self {
+        self.per_method.insert(method.into(), quota);
+        self
+    }
+
+    /// Quota applied to methods without an entry in `per_method`
+    pub fn default_quota(&self) -> Quota {
+        Quota {
+            max_requests: self.max_requests,
+            window_duration: self.window_duration,
+            burst_size: self.burst_size,
+        }
+    }
+
+    /// Quota that applies to `method`
+    pub fn quota_for(&self, method: &str) -> Quota {
+        self.per_method
+            .get(method)
+            .copied()
+            .unwrap_or_else(|| self.default_quota())
+    }
+}
+
 /// Rate limiter implementation using sliding window
 // Type alias for complex rate limiter bucket type
-type SessionBuckets = Arc<Mutex<HashMap<String, (Vec<Instant>, u32)>>>;
+type SessionBuckets = Arc<Mutex<HashMap<(String, String), (Vec<Instant>, u32)>>>;
 
 #[derive(Debug)]
 pub struct RateLimiter {
     config: RateLimitConfig,
-    // Session ID -> (request_times, burst_count)
+    // (Session ID, method) -> (request_times, burst_count)
     session_buckets: SessionBuckets,
 }
 
@@ -55,25 +129,26 @@ impl RateLimiter {
         }
     }
 
-    /// Check if request is allowed for the given session
-    pub fn check_rate_limit(&self, session_id: &str) -> Result<(), McpError> {
+    /// Check if a request to `method` is allowed for the given session
+    pub fn check_rate_limit(&self, session_id: &str, method: &str) -> Result<(), McpError> {
+        let quota = self.config.quota_for(method);
         let mut buckets = self.session_buckets.lock().unwrap();
         let now = Instant::now();
 
         let (request_times, burst_count) = buckets
-            .entry(session_id.to_string())
+            .entry((session_id.to_string(), method.to_string()))
             .or_insert_with(|| (Vec::new(), 0));
 
         // Clean old requests outside the window
-        request_times.retain(|&time| now.duration_since(time) < self.config.window_duration);
+        request_times.retain(|&time| now.duration_since(time) < quota.window_duration);
 
         // Allow request and record timestamp first
         request_times.push(now);
 
         // Check if we're over the limit after adding this request
-        if request_times.len() > self.config.max_requests as usize {
+        if request_times.len() > quota.max_requests as usize {
             // Check burst allowance
-            if *burst_count < self.config.burst_size {
+            if *burst_count < quota.burst_size {
                 *burst_count += 1;
                 return Ok(());
             }
@@ -83,16 +158,16 @@ impl RateLimiter {
 
             return Err(McpError::param_out_of_range(
                 "request_rate",
-                &format!("{} requests", request_times.len() + 1),
+                &format!("{} '{}' requests", request_times.len() + 1, method),
                 &format!(
                     "max {} requests per {:?}",
-                    self.config.max_requests, self.config.window_duration
+                    quota.max_requests, quota.window_duration
                 ),
             ));
         }
 
         // Reset burst count if we're below the limit
-        if request_times.len() < (self.config.max_requests as f32 * 0.8) as usize {
+        if request_times.len() < (quota.max_requests as f32 * 0.8) as usize {
             *burst_count = 0;
         }
 
@@ -104,8 +179,9 @@ impl RateLimiter {
         let mut buckets = self.session_buckets.lock().unwrap();
         let now = Instant::now();
 
-        buckets.retain(|_, (request_times, _)| {
-            request_times.retain(|&time| now.duration_since(time) < self.config.window_duration);
+        buckets.retain(|(_, method), (request_times, _)| {
+            let window = self.config.quota_for(method).window_duration;
+            request_times.retain(|&time| now.duration_since(time) < window);
             !request_times.is_empty()
         });
     }
@@ -394,7 +470,7 @@ impl SecurityMiddleware {
         if let Some(rate_limiter) = &self.rate_limiter
             && let Some(session) = session
         {
-            rate_limiter.check_rate_limit(&session.session_id)?;
+            rate_limiter.check_rate_limit(&session.session_id, method)?;
         }
 
         // Input validation
@@ -452,19 +528,20 @@ mod tests {
             max_requests: 3,
             window_duration: Duration::from_secs(60),
             burst_size: 1,
+            per_method: HashMap::new(),
         };
         let limiter = RateLimiter::new(config);
 
         // First 3 requests should succeed
-        assert!(limiter.check_rate_limit("session1").is_ok());
-        assert!(limiter.check_rate_limit("session1").is_ok());
-        assert!(limiter.check_rate_limit("session1").is_ok());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_ok());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_ok());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_ok());
 
         // 4th request should succeed due to burst
-        assert!(limiter.check_rate_limit("session1").is_ok());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_ok());
 
         // 5th request should fail
-        assert!(limiter.check_rate_limit("session1").is_err());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_err());
     }
 
     #[test]
@@ -473,17 +550,102 @@ mod tests {
             max_requests: 2,
             window_duration: Duration::from_secs(60),
             burst_size: 0,
+            per_method: HashMap::new(),
         };
         let limiter = RateLimiter::new(config);
 
         // Different sessions should have independent limits
-        assert!(limiter.check_rate_limit("session1").is_ok());
-        assert!(limiter.check_rate_limit("session1").is_ok());
-        assert!(limiter.check_rate_limit("session1").is_err());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_ok());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_ok());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_err());
+
+        assert!(limiter.check_rate_limit("session2", "tools/call").is_ok());
+        assert!(limiter.check_rate_limit("session2", "tools/call").is_ok());
+        assert!(limiter.check_rate_limit("session2", "tools/call").is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_per_method_quota() {
+        let config = RateLimitConfig {
+            max_requests: 10,
+            window_duration: Duration::from_secs(60),
+            burst_size: 0,
+            per_method: HashMap::new(),
+        }
+        .with_method_quota("sampling/createMessage", Quota::per_minute(2));
+        let limiter = RateLimiter::new(config);
+
+        // Listed method uses its own budget
+        assert!(
+            limiter
+                .check_rate_limit("session1", "sampling/createMessage")
+                .is_ok()
+        );
+        assert!(
+            limiter
+                .check_rate_limit("session1", "sampling/createMessage")
+                .is_ok()
+        );
+        let err = limiter
+            .check_rate_limit("session1", "sampling/createMessage")
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("sampling/createMessage"),
+            "{}",
+            err
+        );
+
+        // Unlisted methods fall back to the default quota, in their own bucket
+        for _ in 0..10 {
+            assert!(limiter.check_rate_limit("session1", "tools/list").is_ok());
+        }
+        assert!(limiter.check_rate_limit("session1", "tools/list").is_err());
+        assert!(limiter.check_rate_limit("session1", "tools/call").is_ok());
+
+        // Other sessions are unaffected
+        assert!(
+            limiter
+                .check_rate_limit("session2", "sampling/createMessage")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_security_middleware_method_budget_exhausted() {
+        let session = SessionContext {
+            session_id: "test-session".to_string(),
+            get_state: Arc::new(|_| Box::pin(futures::future::ready(None))),
+            set_state: Arc::new(|_, _| Box::pin(futures::future::ready(()))),
+            remove_state: Arc::new(|_| Box::pin(futures::future::ready(None))),
+            is_initialized: Arc::new(|| Box::pin(futures::future::ready(true))),
+            send_notification: Arc::new(|_| Box::pin(futures::future::ready(()))),
+            broadcaster: None,
+            extensions: std::collections::HashMap::new(),
+            request_meta: None,
+            response_meta: std::sync::Arc::default(),
+            cancellation_token: tokio_util::sync::CancellationToken::new(),
+        };
+        let middleware = SecurityMiddleware::new().with_rate_limiting(
+            RateLimitConfig::default().with_method_quota("resources/read", Quota::per_minute(1)),
+        );
+        let params = json!({"uri": "file:///data/test.json"});
 
-        assert!(limiter.check_rate_limit("session2").is_ok());
-        assert!(limiter.check_rate_limit("session2").is_ok());
-        assert!(limiter.check_rate_limit("session2").is_err());
+        assert!(
+            middleware
+                .validate_request("resources/read", Some(&params), Some(&session))
+                .is_ok()
+        );
+        let err = middleware
+            .validate_request("resources/read", Some(&params), Some(&session))
+            .unwrap_err();
+        assert_eq!(err.to_error_object().code, -32602);
+
+        // A different method on the same session still succeeds
+        assert!(
+            middleware
+                .validate_request("resources/list", None, Some(&session))
+                .is_ok()
+        );
     }
 
     #[test]
@@ -572,6 +734,9 @@ mod tests {
             send_notification: Arc::new(|_| Box::pin(futures::future::ready(()))),
             broadcaster: None,
             extensions: std::collections::HashMap::new(),
+            request_meta: None,
+            response_meta: std::sync::Arc::default(),
+            cancellation_token: tokio_util::sync::CancellationToken::new(),
         };
 
         let middleware = SecurityMiddleware::new();
@@ -10,6 +10,7 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
 use crate::handlers::McpHandler;
@@ -31,6 +32,15 @@ impl SessionEventDispatcher for StreamManagerEventDispatcher {
         event_type: String,
         data: serde_json::Value,
     ) -> std::result::Result<(), String> {
+        // Resource updates go through the coalescing path when a window is configured
+        if event_type == "notifications/resources/updated" {
+            return self
+                .stream_manager
+                .broadcast_resource_updated(session_id, data)
+                .await
+                .map(|_| ())
+                .map_err(|e| e.to_string());
+        }
         self.stream_manager
             .broadcast_to_session(session_id, event_type, data)
             .await
@@ -80,6 +90,12 @@ pub struct McpServer {
     tools: HashMap<String, Arc<dyn McpTool>>,
     /// Registered handlers
     handlers: HashMap<String, Arc<dyn McpHandler>>,
+    /// Metadata of the registered tools, sorted by name
+    registered_tools: Vec<turul_mcp_protocol::Tool>,
+    /// Metadata of the registered static resources, sorted by URI
+    registered_resources: Vec<turul_mcp_protocol::resources::Resource>,
+    /// Metadata of the registered prompts, sorted by name
+    registered_prompts: Vec<turul_mcp_protocol::prompts::Prompt>,
     /// Session manager for state persistence
     session_manager: Arc<SessionManager>,
     /// Session storage backend (shared between SessionManager and HTTP layer)
@@ -96,12 +112,31 @@ pub struct McpServer {
     route_registry: Arc<turul_http_mcp_server::RouteRegistry>,
     /// Stable fingerprint of the registered tool set for session versioning
     tool_fingerprint: String,
+    /// Translations for framework error messages
+    message_catalog: Option<Arc<crate::locale::MessageCatalog>>,
+    /// Default page size for `tools/list`
+    list_page_size: usize,
+    /// Tools registered after build, falling back to the builder's router
+    runtime_tools: Arc<crate::tool::RuntimeTools>,
+    /// Time budget of each `tools/call`, exposed to tools as a deadline
+    request_timeout: Option<std::time::Duration>,
+    /// How long `tools/call` results are replayed for a repeated idempotency key
+    idempotency_window: Option<std::time::Duration>,
+    /// Audit trail written after each `tools/call`
+    #[cfg(feature = "audit")]
+    audit_log: Option<crate::audit::AuditLog>,
     /// Dynamic tool registry (only in Dynamic mode)
     #[cfg(feature = "dynamic-tools")]
     tool_registry: Option<Arc<crate::tool_registry::ToolRegistry>>,
     /// Whether cross-instance coordination is enabled (explicit storage was provided)
     #[cfg(feature = "dynamic-tools")]
     coordination_enabled: bool,
+    /// Watched tool config file and re-read interval (Dynamic mode only)
+    #[cfg(feature = "dynamic-tools")]
+    tool_config_watch: Option<(std::path::PathBuf, std::time::Duration)>,
+    /// Running tool config watcher; started once, aborted on shutdown or drop
+    #[cfg(feature = "dynamic-tools")]
+    tool_config_watcher: tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>,
 
     // HTTP configuration (if enabled)
     #[cfg(feature = "http")]
@@ -114,6 +149,38 @@ pub struct McpServer {
     enable_sse: bool,
     #[cfg(feature = "http")]
     allow_unauthenticated_ping: Option<bool>,
+    #[cfg(feature = "http")]
+    cors_config: Option<turul_http_mcp_server::CorsConfig>,
+    #[cfg(feature = "http")]
+    compression_algorithms: Option<Vec<turul_http_mcp_server::ContentEncoding>>,
+    #[cfg(feature = "http")]
+    compression_threshold: Option<usize>,
+    #[cfg(feature = "http")]
+    max_request_body_bytes: Option<usize>,
+    #[cfg(feature = "http")]
+    liveness_path: Option<String>,
+    #[cfg(feature = "http")]
+    readiness_path: Option<String>,
+    #[cfg(feature = "http")]
+    wire_log_level: Option<tracing::Level>,
+    #[cfg(feature = "http")]
+    wire_redact_fields: Option<Vec<String>>,
+    #[cfg(feature = "http")]
+    request_interceptor: Option<turul_http_mcp_server::RequestInterceptor>,
+    #[cfg(feature = "http")]
+    ip_limits: Option<turul_http_mcp_server::IpLimitConfig>,
+    #[cfg(feature = "http")]
+    resource_update_coalesce_window_ms: Option<u64>,
+    #[cfg(feature = "http")]
+    max_streams_per_session: Option<usize>,
+    #[cfg(feature = "http")]
+    json_rpc_codecs: Vec<Arc<dyn turul_mcp_json_rpc_server::JsonRpcCodec>>,
+    #[cfg(feature = "http")]
+    shutdown_grace_period: Option<std::time::Duration>,
+    #[cfg(feature = "otel")]
+    request_tracing: Option<turul_http_mcp_server::RequestTracing>,
+    #[cfg(feature = "tls")]
+    tls: Option<turul_http_mcp_server::TlsConfig>,
 }
 
 impl McpServer {
@@ -124,6 +191,8 @@ impl McpServer {
         capabilities: ServerCapabilities,
         tools: HashMap<String, Arc<dyn McpTool>>,
         handlers: HashMap<String, Arc<dyn McpHandler>>,
+        registered_resources: Vec<turul_mcp_protocol::resources::Resource>,
+        registered_prompts: Vec<turul_mcp_protocol::prompts::Prompt>,
         instructions: Option<String>,
         session_timeout_minutes: Option<u64>,
         session_cleanup_interval_seconds: Option<u64>,
@@ -133,15 +202,47 @@ impl McpServer {
         middleware_stack: crate::middleware::MiddlewareStack,
         route_registry: Arc<turul_http_mcp_server::RouteRegistry>,
         tool_fingerprint: String,
+        message_catalog: Option<Arc<crate::locale::MessageCatalog>>,
+        list_page_size: usize,
+        tool_router: Option<Arc<dyn crate::tool::ToolRouter>>,
+        request_timeout: Option<std::time::Duration>,
+        idempotency_window: Option<std::time::Duration>,
+        #[cfg(feature = "audit")] audit_log: Option<crate::audit::AuditLog>,
         #[cfg(feature = "dynamic-tools")] dynamic_tools: bool,
         #[cfg(feature = "dynamic-tools")] server_state_storage: Option<
             Arc<dyn turul_mcp_server_state_storage::ServerStateStorage>,
         >,
+        #[cfg(feature = "dynamic-tools")] tool_config_watch: Option<(
+            std::path::PathBuf,
+            std::time::Duration,
+        )>,
         #[cfg(feature = "http")] bind_address: SocketAddr,
         #[cfg(feature = "http")] mcp_path: String,
         #[cfg(feature = "http")] enable_cors: bool,
         #[cfg(feature = "http")] enable_sse: bool,
         #[cfg(feature = "http")] allow_unauthenticated_ping: Option<bool>,
+        #[cfg(feature = "http")] cors_config: Option<turul_http_mcp_server::CorsConfig>,
+        #[cfg(feature = "http")] compression_algorithms: Option<
+            Vec<turul_http_mcp_server::ContentEncoding>,
+        >,
+        #[cfg(feature = "http")] compression_threshold: Option<usize>,
+        #[cfg(feature = "http")] max_request_body_bytes: Option<usize>,
+        #[cfg(feature = "http")] liveness_path: Option<String>,
+        #[cfg(feature = "http")] readiness_path: Option<String>,
+        #[cfg(feature = "http")] wire_log_level: Option<tracing::Level>,
+        #[cfg(feature = "http")] wire_redact_fields: Option<Vec<String>>,
+        #[cfg(feature = "http")] request_interceptor: Option<
+            turul_http_mcp_server::RequestInterceptor,
+        >,
+        #[cfg(feature = "http")] ip_limits: Option<turul_http_mcp_server::IpLimitConfig>,
+        #[cfg(feature = "http")] resource_update_coalesce_window_ms: Option<u64>,
+        #[cfg(feature = "http")] max_streams_per_session: Option<usize>,
+        #[cfg(feature = "http")] json_rpc_codecs: Vec<
+            Arc<dyn turul_mcp_json_rpc_server::JsonRpcCodec>,
+        >,
+        #[cfg(feature = "http")] shutdown_grace_period: Option<std::time::Duration>,
+        #[cfg(feature = "otel")] request_tracing: Option<turul_http_mcp_server::RequestTracing>,
+        #[cfg(feature = "tls")] tls: Option<turul_http_mcp_server::TlsConfig>,
     ) -> Self {
         // Create session manager with server capabilities, custom timeouts, and storage
         let session_manager = match &session_storage {
@@ -210,11 +311,20 @@ impl McpServer {
             None
         };
 
+        let mut registered_tools: Vec<_> = tools
+            .values()
+            .map(|tool| tool_to_descriptor(tool.as_ref()))
+            .collect();
+        registered_tools.sort_by(|a, b| a.name.cmp(&b.name));
+
         Self {
             implementation,
             capabilities,
             tools,
             handlers,
+            registered_tools,
+            registered_resources,
+            registered_prompts,
             session_manager,
             session_storage,
             task_runtime,
@@ -223,10 +333,21 @@ impl McpServer {
             middleware_stack,
             route_registry,
             tool_fingerprint,
+            message_catalog,
+            list_page_size,
+            runtime_tools: Arc::new(crate::tool::RuntimeTools::new(tool_router)),
+            request_timeout,
+            idempotency_window,
+            #[cfg(feature = "audit")]
+            audit_log,
             #[cfg(feature = "dynamic-tools")]
             tool_registry,
             #[cfg(feature = "dynamic-tools")]
             coordination_enabled,
+            #[cfg(feature = "dynamic-tools")]
+            tool_config_watch,
+            #[cfg(feature = "dynamic-tools")]
+            tool_config_watcher: tokio::sync::Mutex::new(None),
             #[cfg(feature = "http")]
             bind_address,
             #[cfg(feature = "http")]
@@ -237,6 +358,38 @@ impl McpServer {
             enable_sse,
             #[cfg(feature = "http")]
             allow_unauthenticated_ping,
+            #[cfg(feature = "http")]
+            cors_config,
+            #[cfg(feature = "http")]
+            compression_algorithms,
+            #[cfg(feature = "http")]
+            compression_threshold,
+            #[cfg(feature = "http")]
+            max_request_body_bytes,
+            #[cfg(feature = "http")]
+            liveness_path,
+            #[cfg(feature = "http")]
+            readiness_path,
+            #[cfg(feature = "http")]
+            wire_log_level,
+            #[cfg(feature = "http")]
+            wire_redact_fields,
+            #[cfg(feature = "http")]
+            request_interceptor,
+            #[cfg(feature = "http")]
+            ip_limits,
+            #[cfg(feature = "http")]
+            resource_update_coalesce_window_ms,
+            #[cfg(feature = "http")]
+            max_streams_per_session,
+            #[cfg(feature = "http")]
+            json_rpc_codecs,
+            #[cfg(feature = "http")]
+            shutdown_grace_period,
+            #[cfg(feature = "otel")]
+            request_tracing,
+            #[cfg(feature = "tls")]
+            tls,
         }
     }
 
@@ -308,12 +461,227 @@ impl McpServer {
         }
     }
 
+    /// Notify sessions subscribed to `uri` that the resource changed.
+    ///
+    /// Sends `notifications/resources/updated` over SSE to exactly the sessions that
+    /// called `resources/subscribe` for `uri` and have not unsubscribed or ended.
+    /// Returns the number of sessions notified.
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Arc;
+    /// # use turul_mcp_server::McpServer;
+    /// # async fn example(server: Arc<McpServer>) -> turul_mcp_server::McpResult<()> {
+    /// let notified = server.notify_resource_updated("file:///config.json").await?;
+    /// println!("{notified} subscribers notified");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn notify_resource_updated(&self, uri: &str) -> Result<usize> {
+        self.session_manager
+            .notify_resource_updated(uri)
+            .await
+            .map_err(|e| McpError::transport(&e))
+    }
+
+    /// Add a tool to the running server.
+    ///
+    /// The tool is served by `tools/list` and `tools/call` from the next request on,
+    /// and every session is sent `notifications/tools/list_changed` (when the server
+    /// advertises `tools.listChanged`, see
+    /// [`McpServerBuilder::list_changed_notifications`](crate::McpServerBuilder::list_changed_notifications)).
+    /// A runtime tool with the same name is replaced; a tool registered on the
+    /// builder cannot be.
+    ///
+    /// Fails with `McpError::ConfigurationError` when the server was built without
+    /// the tools capability (no tool or router on the builder): its clients would
+    /// never list or call the tool.
+    ///
+    /// ```rust,no_run
+    /// # use std::sync::Arc;
+    /// # use turul_mcp_server::{McpServer, McpTool};
+    /// # async fn example(server: Arc<McpServer>, plugin_tool: Arc<dyn McpTool>) -> turul_mcp_server::McpResult<()> {
+    /// server.register_tool(plugin_tool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn register_tool(&self, tool: Arc<dyn McpTool>) -> Result<()> {
+        let name = tool.name().to_string();
+        if self.capabilities.tools.is_none() {
+            return Err(McpError::ConfigurationError(format!(
+                "Cannot register tool '{}': the server does not advertise the tools capability; \
+                 register a tool or tool router on the builder",
+                name
+            )));
+        }
+        if self.tools.contains_key(&name) {
+            return Err(McpError::InvalidParameters(format!(
+                "Tool '{}' is registered on the builder and cannot be replaced",
+                name
+            )));
+        }
+        self.runtime_tools.insert(tool);
+        info!("Registered tool '{}' at runtime", name);
+        self.announce_tools_changed().await;
+        Ok(())
+    }
+
+    /// Remove a tool added with [`register_tool`](Self::register_tool).
+    ///
+    /// Returns `false` if no runtime tool has that name. Calls already running
+    /// finish; later `tools/call` requests for it fail with tool-not-found. Tools
+    /// registered on the builder cannot be removed.
+    pub async fn deregister_tool(&self, name: &str) -> Result<bool> {
+        if self.tools.contains_key(name) {
+            return Err(McpError::InvalidParameters(format!(
+                "Tool '{}' is registered on the builder and cannot be removed",
+                name
+            )));
+        }
+        if self.runtime_tools.remove(name).is_none() {
+            return Ok(false);
+        }
+        info!("Deregistered tool '{}' at runtime", name);
+        self.announce_tools_changed().await;
+        Ok(true)
+    }
+
+    /// Notify sessions of a runtime tool change; the change itself already happened
+    async fn announce_tools_changed(&self) {
+        if let Err(e) = self.notify_tools_changed().await {
+            warn!("Failed to send notifications/tools/list_changed: {}", e);
+        }
+    }
+
+    /// Tell every session that the tool list changed.
+    ///
+    /// Sends `notifications/tools/list_changed` over SSE to all live sessions so
+    /// clients re-fetch `tools/list`. Does nothing unless the server advertises
+    /// `tools.listChanged` (see
+    /// [`McpServerBuilder::list_changed_notifications`](crate::McpServerBuilder::list_changed_notifications)).
+    pub async fn notify_tools_changed(&self) -> Result<()> {
+        let list_changed = self
+            .capabilities
+            .tools
+            .as_ref()
+            .and_then(|c| c.list_changed);
+        self.notify_list_changed("notifications/tools/list_changed", list_changed)
+            .await
+    }
+
+    /// Tell every session that the resource list changed.
+    ///
+    /// Like [`notify_tools_changed`](Self::notify_tools_changed), for
+    /// `notifications/resources/list_changed` and `resources.listChanged`.
+    pub async fn notify_resources_changed(&self) -> Result<()> {
+        let list_changed = self
+            .capabilities
+            .resources
+            .as_ref()
+            .and_then(|c| c.list_changed);
+        self.notify_list_changed("notifications/resources/list_changed", list_changed)
+            .await
+    }
+
+    /// Tell every session that the prompt list changed.
+    ///
+    /// Like [`notify_tools_changed`](Self::notify_tools_changed), for
+    /// `notifications/prompts/list_changed` and `prompts.listChanged`.
+    pub async fn notify_prompts_changed(&self) -> Result<()> {
+        let list_changed = self
+            .capabilities
+            .prompts
+            .as_ref()
+            .and_then(|c| c.list_changed);
+        self.notify_list_changed("notifications/prompts/list_changed", list_changed)
+            .await
+    }
+
+    async fn notify_list_changed(&self, method: &str, list_changed: Option<bool>) -> Result<()> {
+        if list_changed != Some(true) {
+            debug!("Not sending {}: listChanged is not advertised", method);
+            return Ok(());
+        }
+        let notification = turul_mcp_protocol::JsonRpcNotification::new(method.to_string());
+        let data = serde_json::to_value(&notification)?;
+        self.session_manager
+            .broadcast_event(crate::session::SessionEvent::Custom {
+                event_type: method.to_string(),
+                data,
+            })
+            .await
+            .map_err(|e| McpError::transport(&e))
+    }
+
+    /// Translate handler errors through the configured message catalog
+    fn localized<H>(&self, handler: H) -> crate::locale::LocalizedErrors<H> {
+        crate::locale::LocalizedErrors::new(
+            handler,
+            self.message_catalog.clone(),
+            Arc::clone(&self.session_manager),
+        )
+    }
+
+    /// Apply the watched tool config (if any) and keep watching it.
+    ///
+    /// Every transport entry point calls this; only the first call starts a watcher.
+    #[cfg(feature = "dynamic-tools")]
+    async fn start_tool_config_watcher(&self) {
+        if let (Some(registry), Some((path, interval))) =
+            (&self.tool_registry, &self.tool_config_watch)
+        {
+            let mut watcher = self.tool_config_watcher.lock().await;
+            if watcher.is_some() {
+                return;
+            }
+            *watcher = Some(
+                crate::tool_config::ToolConfigWatcher::new(path.clone(), Arc::clone(registry))
+                    .with_interval(*interval)
+                    .start()
+                    .await,
+            );
+            info!(
+                "Dynamic: watching tool config {} (interval: {:?})",
+                path.display(),
+                interval
+            );
+        }
+    }
+
+    /// Stop the tool config watcher, if one is running.
+    #[cfg(feature = "dynamic-tools")]
+    async fn stop_tool_config_watcher(&self) {
+        if let Some(handle) = self.tool_config_watcher.lock().await.take() {
+            handle.abort();
+            debug!("Dynamic: stopped watching tool config");
+        }
+    }
+
     /// Get the dynamic tool registry, if in Dynamic mode.
     #[cfg(feature = "dynamic-tools")]
     pub fn tool_registry(&self) -> Option<&Arc<crate::tool_registry::ToolRegistry>> {
         self.tool_registry.as_ref()
     }
 
+    /// Metadata of every tool registered on the builder, sorted by name
+    ///
+    /// Reflects the server as built; tools resolved through a
+    /// [`ToolRouter`](crate::ToolRouter) or toggled at runtime are not included.
+    pub fn registered_tools(&self) -> Vec<&turul_mcp_protocol::Tool> {
+        self.registered_tools.iter().collect()
+    }
+
+    /// Metadata of every static resource registered on the builder, sorted by URI
+    ///
+    /// Template resources are listed by `resources/templates/list` and not here.
+    pub fn registered_resources(&self) -> Vec<&turul_mcp_protocol::resources::Resource> {
+        self.registered_resources.iter().collect()
+    }
+
+    /// Metadata of every prompt registered on the builder, sorted by name
+    pub fn registered_prompts(&self) -> Vec<&turul_mcp_protocol::prompts::Prompt> {
+        self.registered_prompts.iter().collect()
+    }
+
     /// Get the server's configured capabilities
     pub fn capabilities(&self) -> &turul_mcp_protocol::ServerCapabilities {
         &self.capabilities
@@ -339,9 +707,45 @@ impl McpServer {
         }
     }
 
+    /// Run the server until `shutdown` completes, then drain in-flight requests
+    ///
+    /// The listener stops accepting connections, open SSE streams receive a final
+    /// event and close, and active requests get up to the builder's
+    /// `shutdown_grace_period` (default 30 seconds) to finish.
+    ///
+    /// ```rust,no_run
+    /// # use turul_mcp_server::McpServer;
+    /// # async fn example(server: McpServer) -> turul_mcp_server::McpResult<()> {
+    /// server
+    ///     .run_with_shutdown(async {
+    ///         tokio::signal::ctrl_c().await.ok();
+    ///     })
+    ///     .await
+    /// # }
+    /// ```
+    pub async fn run_with_shutdown(&self, shutdown: impl Future<Output = ()>) -> Result<()> {
+        #[cfg(feature = "http")]
+        {
+            self.serve_http(shutdown).await
+        }
+        #[cfg(not(feature = "http"))]
+        {
+            let _ = shutdown;
+            Err(McpError::configuration(
+                "No transport available. Enable the 'http' feature to use HTTP transport.",
+            ))
+        }
+    }
+
     /// Run the server with HTTP transport (requires "http" feature)
     #[cfg(feature = "http")]
     pub async fn run_http(&self) -> Result<()> {
+        self.serve_http(std::future::pending()).await
+    }
+
+    /// HTTP transport shared by `run_http` and `run_with_shutdown`
+    #[cfg(feature = "http")]
+    async fn serve_http(&self, shutdown: impl Future<Output = ()>) -> Result<()> {
         info!(
             "Starting MCP server: {} v{}",
             self.implementation.name, self.implementation.version
@@ -352,6 +756,35 @@ impl McpServer {
             info!("SSE notifications: enabled at GET {}", self.mcp_path);
         }
 
+        let http_server = self.build_http_server().await;
+
+        let result = http_server.run_with_shutdown(shutdown).await;
+
+        #[cfg(feature = "dynamic-tools")]
+        self.stop_tool_config_watcher().await;
+
+        result.map_err(|http_err| match http_err {
+            turul_http_mcp_server::HttpMcpError::Mcp(mcp_err) => mcp_err,
+            turul_http_mcp_server::HttpMcpError::Http(http_err) => {
+                McpError::transport(&http_err.to_string())
+            }
+            turul_http_mcp_server::HttpMcpError::JsonRpc(rpc_err) => {
+                McpError::json_rpc_protocol(&rpc_err.to_string())
+            }
+            turul_http_mcp_server::HttpMcpError::Serialization(ser_err) => {
+                McpError::SerializationError(ser_err)
+            }
+            turul_http_mcp_server::HttpMcpError::Io(io_err) => McpError::IoError(io_err),
+            turul_http_mcp_server::HttpMcpError::InvalidRequest(msg) => {
+                McpError::InvalidParameters(msg)
+            }
+        })?;
+        Ok(())
+    }
+
+    /// Assemble the HTTP server and start the background work it relies on
+    #[cfg(feature = "http")]
+    async fn build_http_server(&self) -> turul_http_mcp_server::HttpMcpServer {
         // Start session cleanup task
         let _cleanup_task = self.session_manager.clone().start_cleanup_task();
 
@@ -406,6 +839,10 @@ impl McpServer {
             }
         }
 
+        // Apply and watch the tool config file (Dynamic mode only)
+        #[cfg(feature = "dynamic-tools")]
+        self.start_tool_config_watcher().await;
+
         // Create session-aware tool handler (with optional task runtime for async execution)
         let mut tool_handler = SessionAwareToolHandler::new(
             self.tools.clone(),
@@ -415,6 +852,17 @@ impl McpServer {
         if let Some(ref runtime) = self.task_runtime {
             tool_handler = tool_handler.with_task_runtime(Arc::clone(runtime));
         }
+        tool_handler = tool_handler.with_tool_router(self.runtime_tools.clone());
+        if let Some(timeout) = self.request_timeout {
+            tool_handler = tool_handler.with_request_timeout(timeout);
+        }
+        if let Some(window) = self.idempotency_window {
+            tool_handler = tool_handler.with_idempotency_window(window);
+        }
+        #[cfg(feature = "audit")]
+        if let Some(ref audit_log) = self.audit_log {
+            tool_handler = tool_handler.with_audit_log(audit_log.clone());
+        }
         #[cfg(feature = "dynamic-tools")]
         if let Some(ref registry) = self.tool_registry {
             tool_handler = tool_handler.with_tool_registry(Arc::clone(registry));
@@ -451,27 +899,109 @@ impl McpServer {
                 .tool_notifier(Arc::new(SessionManagerToolNotifier {
                     session_manager: Arc::clone(&self.session_manager),
                 }))
-                .register_handler(vec!["initialize".to_string()], init_handler)
-                .register_handler(vec!["tools/list".to_string()], {
-                    let mut lth = ListToolsHandler::new_with_session_manager(
-                        self.tools.clone(),
-                        self.session_manager.clone(),
-                        self.strict_lifecycle,
-                        self.task_runtime.is_some(),
-                    );
-                    #[cfg(feature = "dynamic-tools")]
-                    if let Some(ref registry) = self.tool_registry {
-                        lth = lth.with_tool_registry(Arc::clone(registry));
-                    }
-                    lth
-                })
-                .register_handler(vec!["tools/call".to_string()], tool_handler);
+                .register_handler(vec!["initialize".to_string()], self.localized(init_handler))
+                .register_handler(
+                    vec!["tools/list".to_string()],
+                    self.localized({
+                        let mut lth = ListToolsHandler::new_with_session_manager(
+                            self.tools.clone(),
+                            self.session_manager.clone(),
+                            self.strict_lifecycle,
+                            self.task_runtime.is_some(),
+                        )
+                        .with_page_size(self.list_page_size);
+                        lth = lth.with_tool_router(self.runtime_tools.clone());
+                        #[cfg(feature = "dynamic-tools")]
+                        if let Some(ref registry) = self.tool_registry {
+                            lth = lth.with_tool_registry(Arc::clone(registry));
+                        }
+                        lth
+                    }),
+                )
+                .register_handler(vec!["tools/call".to_string()], self.localized(tool_handler));
 
         // Pass allow_unauthenticated_ping config to HTTP layer
         if let Some(allow) = self.allow_unauthenticated_ping {
             builder = builder.allow_unauthenticated_ping(allow);
         }
 
+        // Pass CORS policy to HTTP layer
+        if let Some(ref cors) = self.cors_config {
+            builder = builder.cors_config(cors.clone());
+        }
+
+        // Pass response compression config to HTTP layer
+        if let Some(ref algorithms) = self.compression_algorithms {
+            builder = builder.compression(algorithms.clone());
+        }
+        if let Some(bytes) = self.compression_threshold {
+            builder = builder.compression_threshold(bytes);
+        }
+
+        // Pass request body limit to HTTP layer
+        if let Some(bytes) = self.max_request_body_bytes {
+            builder = builder.max_request_body_bytes(bytes);
+        }
+
+        // Pass health probe paths to HTTP layer
+        if let Some(ref path) = self.liveness_path {
+            builder = builder.liveness_path(path.clone());
+        }
+        if let Some(ref path) = self.readiness_path {
+            builder = builder.readiness_path(path.clone());
+        }
+
+        // Pass wire-level JSON logging to HTTP layer
+        if let Some(level) = self.wire_log_level {
+            builder = builder.log_wire_json(level);
+        }
+        if let Some(ref fields) = self.wire_redact_fields {
+            builder = builder.redact_wire_fields(fields.clone());
+        }
+
+        // Pass global request interceptor to HTTP layer
+        if let Some(ref interceptor) = self.request_interceptor {
+            let interceptor = Arc::clone(interceptor);
+            builder = builder.request_interceptor(move |req| interceptor(req));
+        }
+
+        // Pass OpenTelemetry request tracing to HTTP layer
+        #[cfg(feature = "otel")]
+        if let Some(ref tracing) = self.request_tracing {
+            builder = builder.request_tracing(tracing.clone());
+        }
+
+        // Pass per-client-IP limits to HTTP layer
+        if let Some(ref ip_limits) = self.ip_limits {
+            builder = builder.ip_limits(ip_limits.clone());
+        }
+
+        // Pass resource-update coalescing window to HTTP layer
+        if let Some(window_ms) = self.resource_update_coalesce_window_ms {
+            builder = builder.resource_update_coalesce_window_ms(window_ms);
+        }
+
+        // Pass per-session SSE stream limit to HTTP layer
+        if let Some(max) = self.max_streams_per_session {
+            builder = builder.max_streams_per_session(max);
+        }
+
+        // Pass negotiable request/response codecs to HTTP layer
+        for codec in &self.json_rpc_codecs {
+            builder = builder.codec(Arc::clone(codec));
+        }
+
+        // Pass shutdown drain deadline to HTTP layer
+        if let Some(grace) = self.shutdown_grace_period {
+            builder = builder.shutdown_grace_period(grace);
+        }
+
+        // Pass in-process TLS termination to HTTP layer
+        #[cfg(feature = "tls")]
+        if let Some(ref tls) = self.tls {
+            builder = builder.tls_config(tls.clone());
+        }
+
         // Register all MCP handlers with session awareness
         for (method, handler) in &self.handlers {
             let bridge_handler = SessionAwareMcpHandlerBridge::new(
@@ -479,7 +1009,8 @@ impl McpServer {
                 self.session_manager.clone(),
                 self.strict_lifecycle,
             );
-            builder = builder.register_handler(vec![method.clone()], bridge_handler);
+            builder =
+                builder.register_handler(vec![method.clone()], self.localized(bridge_handler));
         }
 
         // Register special initialized notification handler that can mark sessions as initialized
@@ -508,23 +1039,25 @@ impl McpServer {
             self.setup_sse_event_bridge().await;
         }
 
-        http_server.run().await.map_err(|http_err| match http_err {
-            turul_http_mcp_server::HttpMcpError::Mcp(mcp_err) => mcp_err,
-            turul_http_mcp_server::HttpMcpError::Http(http_err) => {
-                McpError::transport(&http_err.to_string())
-            }
-            turul_http_mcp_server::HttpMcpError::JsonRpc(rpc_err) => {
-                McpError::json_rpc_protocol(&rpc_err.to_string())
-            }
-            turul_http_mcp_server::HttpMcpError::Serialization(ser_err) => {
-                McpError::SerializationError(ser_err)
-            }
-            turul_http_mcp_server::HttpMcpError::Io(io_err) => McpError::IoError(io_err),
-            turul_http_mcp_server::HttpMcpError::InvalidRequest(msg) => {
-                McpError::InvalidParameters(msg)
-            }
-        })?;
-        Ok(())
+        http_server
+    }
+
+    /// Serve this server's MCP endpoint in-process, without binding a socket
+    ///
+    /// The returned service runs the same handlers, session management and SSE
+    /// notifications as [`run`](Self::run); pair it with the client crate's
+    /// `InProcessTransport` to drive the server from the same process.
+    ///
+    /// ```rust,no_run
+    /// # use turul_mcp_server::McpServer;
+    /// # async fn example(server: McpServer) {
+    /// let service = server.in_process_service().await;
+    /// # let _ = service;
+    /// # }
+    /// ```
+    #[cfg(feature = "http")]
+    pub async fn in_process_service(&self) -> turul_http_mcp_server::McpService {
+        self.build_http_server().await.service()
     }
 
     /// Install the awaited event dispatcher backed by StreamManager.
@@ -649,6 +1182,10 @@ impl McpServer {
             }
         }
 
+        // Apply and watch the tool config file (Dynamic mode only)
+        #[cfg(feature = "dynamic-tools")]
+        self.start_tool_config_watcher().await;
+
         // Create session-aware tool handler (with optional task runtime for async execution)
         let mut tool_handler = SessionAwareToolHandler::new(
             self.tools.clone(),
@@ -658,6 +1195,17 @@ impl McpServer {
         if let Some(ref runtime) = self.task_runtime {
             tool_handler = tool_handler.with_task_runtime(Arc::clone(runtime));
         }
+        tool_handler = tool_handler.with_tool_router(self.runtime_tools.clone());
+        if let Some(timeout) = self.request_timeout {
+            tool_handler = tool_handler.with_request_timeout(timeout);
+        }
+        if let Some(window) = self.idempotency_window {
+            tool_handler = tool_handler.with_idempotency_window(window);
+        }
+        #[cfg(feature = "audit")]
+        if let Some(ref audit_log) = self.audit_log {
+            tool_handler = tool_handler.with_audit_log(audit_log.clone());
+        }
         #[cfg(feature = "dynamic-tools")]
         if let Some(ref registry) = self.tool_registry {
             tool_handler = tool_handler.with_tool_registry(Arc::clone(registry));
@@ -694,57 +1242,140 @@ impl McpServer {
                 .tool_notifier(Arc::new(SessionManagerToolNotifier {
                     session_manager: Arc::clone(&self.session_manager),
                 }))
-                .register_handler(vec!["initialize".to_string()], init_handler)
-                .register_handler(vec!["tools/list".to_string()], {
-                    let mut lth = ListToolsHandler::new_with_session_manager(
-                        self.tools.clone(),
-                        self.session_manager.clone(),
-                        self.strict_lifecycle,
-                        self.task_runtime.is_some(),
-                    );
-                    #[cfg(feature = "dynamic-tools")]
-                    if let Some(ref registry) = self.tool_registry {
-                        lth = lth.with_tool_registry(Arc::clone(registry));
-                    }
-                    lth
-                })
-                .register_handler(vec!["tools/call".to_string()], tool_handler);
+                .register_handler(vec!["initialize".to_string()], self.localized(init_handler))
+                .register_handler(
+                    vec!["tools/list".to_string()],
+                    self.localized({
+                        let mut lth = ListToolsHandler::new_with_session_manager(
+                            self.tools.clone(),
+                            self.session_manager.clone(),
+                            self.strict_lifecycle,
+                            self.task_runtime.is_some(),
+                        )
+                        .with_page_size(self.list_page_size);
+                        lth = lth.with_tool_router(self.runtime_tools.clone());
+                        #[cfg(feature = "dynamic-tools")]
+                        if let Some(ref registry) = self.tool_registry {
+                            lth = lth.with_tool_registry(Arc::clone(registry));
+                        }
+                        lth
+                    }),
+                )
+                .register_handler(vec!["tools/call".to_string()], self.localized(tool_handler));
 
         // Pass allow_unauthenticated_ping config to HTTP layer
         if let Some(allow) = self.allow_unauthenticated_ping {
             builder = builder.allow_unauthenticated_ping(allow);
         }
 
-        // TODO investigate if this also adds the tools/list and tools/call handlers
-        // Register all MCP handlers with session awareness
-        for (method, handler) in &self.handlers {
-            let bridge_handler = SessionAwareMcpHandlerBridge::new(
-                handler.clone(),
-                self.session_manager.clone(),
-                self.strict_lifecycle,
-            );
-            builder = builder.register_handler(vec![method.clone()], bridge_handler);
+        // Pass CORS policy to HTTP layer
+        if let Some(ref cors) = self.cors_config {
+            builder = builder.cors_config(cors.clone());
         }
 
-        // Register special initialized notification handler that can mark sessions as initialized
-        use crate::handlers::InitializedNotificationHandler;
-        let initialized_handler = InitializedNotificationHandler::new(self.session_manager.clone());
-        let initialized_bridge = SessionAwareMcpHandlerBridge::new(
-            Arc::new(initialized_handler),
-            self.session_manager.clone(),
-            self.strict_lifecycle,
-        );
-        builder = builder.register_handler(
-            vec!["notifications/initialized".to_string()],
-            initialized_bridge,
-        );
+        // Pass response compression config to HTTP layer
+        if let Some(ref algorithms) = self.compression_algorithms {
+            builder = builder.compression(algorithms.clone());
+        }
+        if let Some(bytes) = self.compression_threshold {
+            builder = builder.compression_threshold(bytes);
+        }
 
-        let http_server = builder.build();
+        // Pass request body limit to HTTP layer
+        if let Some(bytes) = self.max_request_body_bytes {
+            builder = builder.max_request_body_bytes(bytes);
+        }
 
-        // Run server in background task
-        let server_task = {
-            let server = http_server.clone();
-            tokio::spawn(async move { server.run().await })
+        // Pass health probe paths to HTTP layer
+        if let Some(ref path) = self.liveness_path {
+            builder = builder.liveness_path(path.clone());
+        }
+        if let Some(ref path) = self.readiness_path {
+            builder = builder.readiness_path(path.clone());
+        }
+
+        // Pass wire-level JSON logging to HTTP layer
+        if let Some(level) = self.wire_log_level {
+            builder = builder.log_wire_json(level);
+        }
+        if let Some(ref fields) = self.wire_redact_fields {
+            builder = builder.redact_wire_fields(fields.clone());
+        }
+
+        // Pass global request interceptor to HTTP layer
+        if let Some(ref interceptor) = self.request_interceptor {
+            let interceptor = Arc::clone(interceptor);
+            builder = builder.request_interceptor(move |req| interceptor(req));
+        }
+
+        // Pass OpenTelemetry request tracing to HTTP layer
+        #[cfg(feature = "otel")]
+        if let Some(ref tracing) = self.request_tracing {
+            builder = builder.request_tracing(tracing.clone());
+        }
+
+        // Pass per-client-IP limits to HTTP layer
+        if let Some(ref ip_limits) = self.ip_limits {
+            builder = builder.ip_limits(ip_limits.clone());
+        }
+
+        // Pass resource-update coalescing window to HTTP layer
+        if let Some(window_ms) = self.resource_update_coalesce_window_ms {
+            builder = builder.resource_update_coalesce_window_ms(window_ms);
+        }
+
+        // Pass per-session SSE stream limit to HTTP layer
+        if let Some(max) = self.max_streams_per_session {
+            builder = builder.max_streams_per_session(max);
+        }
+
+        // Pass negotiable request/response codecs to HTTP layer
+        for codec in &self.json_rpc_codecs {
+            builder = builder.codec(Arc::clone(codec));
+        }
+
+        // Pass shutdown drain deadline to HTTP layer
+        if let Some(grace) = self.shutdown_grace_period {
+            builder = builder.shutdown_grace_period(grace);
+        }
+
+        // Pass in-process TLS termination to HTTP layer
+        #[cfg(feature = "tls")]
+        if let Some(ref tls) = self.tls {
+            builder = builder.tls_config(tls.clone());
+        }
+
+        // TODO investigate if this also adds the tools/list and tools/call handlers
+        // Register all MCP handlers with session awareness
+        for (method, handler) in &self.handlers {
+            let bridge_handler = SessionAwareMcpHandlerBridge::new(
+                handler.clone(),
+                self.session_manager.clone(),
+                self.strict_lifecycle,
+            );
+            builder =
+                builder.register_handler(vec![method.clone()], self.localized(bridge_handler));
+        }
+
+        // Register special initialized notification handler that can mark sessions as initialized
+        use crate::handlers::InitializedNotificationHandler;
+        let initialized_handler = InitializedNotificationHandler::new(self.session_manager.clone());
+        let initialized_bridge = SessionAwareMcpHandlerBridge::new(
+            Arc::new(initialized_handler),
+            self.session_manager.clone(),
+            self.strict_lifecycle,
+        );
+        builder = builder.register_handler(
+            vec!["notifications/initialized".to_string()],
+            initialized_bridge,
+        );
+
+        let http_server = builder.build();
+
+        // Run server in background task
+        let server_task = {
+            let server = http_server.clone();
+            tokio::spawn(async move { server.run().await })
         };
 
         Ok((http_server, server_task))
@@ -972,77 +1603,45 @@ impl SessionAwareInitializeHandler {
         self
     }
 
+    /// Protocol versions this server can speak, oldest first
+    const SUPPORTED_VERSIONS: [McpVersion; 4] = [
+        McpVersion::V2024_11_05,
+        McpVersion::V2025_03_26,
+        McpVersion::V2025_06_18,
+        McpVersion::V2025_11_25,
+    ];
+
     /// Negotiate protocol version with client
     ///
-    /// Server supports backward compatibility with older protocol versions.
-    /// The negotiation follows this priority:
-    /// 1. Use client's requested version if server supports it
-    /// 2. Use the highest version both client and server support
-    /// 3. Fall back to minimum compatible version
+    /// Per the MCP lifecycle spec, a requested version the server supports is
+    /// echoed back. Any other version, older or newer, is answered with the newest
+    /// version the server supports and the client decides whether to proceed.
+    /// Only a `protocolVersion` that is not a `YYYY-MM-DD` date is rejected.
     fn negotiate_version(&self, client_version: &str) -> std::result::Result<McpVersion, String> {
-        use turul_mcp_protocol::version::McpVersion;
-
-        // Try to parse client's requested version
-        let requested_version = match client_version.parse::<McpVersion>() {
-            Ok(version) => version,
-            Err(_) => {
-                // Unknown version - check if it's newer than what we support
-                // If it looks like a valid date format but we don't know it,
-                // we need to check if it's likely newer or older than our range
-                if client_version.matches('-').count() == 2 {
-                    // Check if it's likely newer than our latest supported version
-                    if client_version > McpVersion::LATEST.as_str() {
-                        // Assume it's newer, use latest as fallback
-                        McpVersion::LATEST
-                    } else if client_version < "2024-11-05" {
-                        // Too old, we don't support versions before our minimum
-                        return Err(format!(
-                            "Cannot negotiate compatible version with client version {} (server requires at least {})",
-                            client_version, "2024-11-05"
-                        ));
-                    } else {
-                        // Unknown version between our supported range - shouldn't happen
-                        return Err(format!("Unknown protocol version: {}", client_version));
-                    }
-                } else {
-                    return Err(format!(
-                        "Invalid protocol version format: {}",
-                        client_version
-                    ));
-                }
-            }
-        };
-
-        // Define server's supported versions (all versions from 2024-11-05 to current)
-        let supported_versions = [
-            McpVersion::V2024_11_05,
-            McpVersion::V2025_03_26,
-            McpVersion::V2025_06_18,
-            McpVersion::V2025_11_25,
-        ];
-
-        // Strategy 1: If server supports client's requested version, use it
-        if supported_versions.contains(&requested_version) {
-            return Ok(requested_version);
+        if let Ok(version) = client_version.parse::<McpVersion>()
+            && Self::SUPPORTED_VERSIONS.contains(&version)
+        {
+            return Ok(version);
         }
 
-        // Strategy 2: Find highest supported version ≤ client requested version
-        // This allows clients to request newer versions while falling back gracefully
-        let compatible_versions: Vec<_> = supported_versions
-            .iter()
-            .filter(|&&v| v <= requested_version)
-            .collect();
-
-        if let Some(&&best_version) = compatible_versions.iter().max() {
-            Ok(best_version)
-        } else {
-            // Strategy 3: No compatible version found - client too old
-            Err(format!(
-                "Cannot negotiate compatible version with client version {} (server requires at least {})",
-                client_version,
-                supported_versions.iter().min().unwrap()
-            ))
+        let is_date = client_version.len() == 10
+            && client_version.char_indices().all(|(i, c)| match i {
+                4 | 7 => c == '-',
+                _ => c.is_ascii_digit(),
+            });
+        if !is_date {
+            return Err(format!(
+                "Invalid protocol version format: {}",
+                client_version
+            ));
         }
+
+        let newest = Self::SUPPORTED_VERSIONS[Self::SUPPORTED_VERSIONS.len() - 1];
+        warn!(
+            "Client requested unsupported protocol version {}, offering {}",
+            client_version, newest
+        );
+        Ok(newest)
     }
 
     /// Adjust server capabilities based on negotiated protocol version
@@ -1099,6 +1698,9 @@ impl JsonRpcHandler for SessionAwareInitializeHandler {
         }
 
         // Parse initialize request
+        let locale = params
+            .as_ref()
+            .and_then(|params| crate::locale::locale_from_params(&params.to_value()));
         let request = if let Some(params) = params {
             let params_value = params.to_value();
             serde_json::from_value::<InitializeRequest>(params_value).map_err(|e| {
@@ -1121,7 +1723,7 @@ impl JsonRpcHandler for SessionAwareInitializeHandler {
             }
             Err(e) => {
                 error!("Protocol version negotiation failed: {}", e);
-                return Err(McpError::ConfigurationError(format!(
+                return Err(McpError::InvalidParameters(format!(
                     "Version negotiation failed: {}",
                     e
                 )));
@@ -1206,6 +1808,16 @@ impl JsonRpcHandler for SessionAwareInitializeHandler {
             )
             .await;
 
+        if let Some(locale) = locale {
+            self.session_manager
+                .set_session_state(
+                    &session_id,
+                    crate::locale::LOCALE_STATE_KEY,
+                    serde_json::Value::String(locale),
+                )
+                .await;
+        }
+
         self.session_manager
             .set_session_state(
                 &session_id,
@@ -1321,6 +1933,8 @@ pub struct ListToolsHandler {
     session_manager: Option<Arc<SessionManager>>,
     strict_lifecycle: bool,
     has_tasks: bool,
+    page_size: usize,
+    tool_router: Option<Arc<dyn crate::tool::ToolRouter>>,
     #[cfg(feature = "dynamic-tools")]
     tool_registry: Option<Arc<crate::tool_registry::ToolRegistry>>,
 }
@@ -1332,6 +1946,8 @@ impl ListToolsHandler {
             session_manager: None,
             strict_lifecycle: false,
             has_tasks,
+            page_size: crate::handlers::DEFAULT_LIST_PAGE_SIZE,
+            tool_router: None,
             #[cfg(feature = "dynamic-tools")]
             tool_registry: None,
         }
@@ -1348,17 +1964,49 @@ impl ListToolsHandler {
             session_manager: Some(session_manager),
             strict_lifecycle,
             has_tasks,
+            page_size: crate::handlers::DEFAULT_LIST_PAGE_SIZE,
+            tool_router: None,
             #[cfg(feature = "dynamic-tools")]
             tool_registry: None,
         }
     }
 
+    /// Number of tools per page when the request has no `limit`
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// List the router's tools alongside the registered ones
+    pub fn with_tool_router(mut self, router: Arc<dyn crate::tool::ToolRouter>) -> Self {
+        self.tool_router = Some(router);
+        self
+    }
+
     /// Set a dynamic tool registry for Dynamic mode.
     #[cfg(feature = "dynamic-tools")]
     pub fn with_tool_registry(mut self, registry: Arc<crate::tool_registry::ToolRegistry>) -> Self {
         self.tool_registry = Some(registry);
         self
     }
+
+    /// Find the tool `tools/call` would run for `name`, so listing and calling
+    /// agree on which tools a session can see
+    async fn resolve_tool(&self, name: &str) -> Option<Arc<dyn McpTool>> {
+        #[cfg(feature = "dynamic-tools")]
+        let registered = match self.tool_registry {
+            Some(ref registry) => registry.get_tool(name).await,
+            None => self.tools.get(name).cloned(),
+        };
+        #[cfg(not(feature = "dynamic-tools"))]
+        let registered = self.tools.get(name).cloned();
+
+        match (registered, &self.tool_router) {
+            (Some(tool), _) => Some(tool),
+            (None, Some(router)) => router.resolve(name).await,
+            (None, None) => None,
+        }
+    }
 }
 
 #[async_trait]
@@ -1371,7 +2019,7 @@ impl JsonRpcHandler for ListToolsHandler {
         params: Option<turul_mcp_json_rpc_server::RequestParams>,
         session_context: Option<turul_mcp_json_rpc_server::r#async::SessionContext>,
     ) -> std::result::Result<serde_json::Value, McpError> {
-        use turul_mcp_protocol::meta::{Cursor, PaginatedResponse};
+        use turul_mcp_protocol::meta::PaginatedResponse;
 
         debug!("Handling {} request", method);
 
@@ -1439,6 +2087,58 @@ impl JsonRpcHandler for ListToolsHandler {
             t
         };
 
+        // Merge routed tools; the stable sort keeps registered tools ahead of routed
+        // ones with the same name, so dedup drops the routed duplicate
+        if let Some(ref router) = self.tool_router {
+            tools.extend(router.list().await);
+            tools.sort_by(|a, b| a.name.cmp(&b.name));
+            tools.dedup_by(|later, earlier| later.name == earlier.name);
+        }
+
+        // Hide tools whose visibility predicate rejects this session
+        let mcp_session = match (&self.session_manager, session_context) {
+            (Some(session_manager), Some(json_rpc_ctx)) => {
+                Some(SessionContext::from_json_rpc_with_broadcaster(
+                    json_rpc_ctx,
+                    session_manager.get_storage(),
+                ))
+            }
+            _ => None,
+        };
+        let mut visible = Vec::with_capacity(tools.len());
+        for tool in tools {
+            if self
+                .resolve_tool(&tool.name)
+                .await
+                .is_none_or(|resolved| resolved.is_visible(mcp_session.as_ref()))
+            {
+                visible.push(tool);
+            }
+        }
+        let mut tools = visible;
+
+        // Swap in descriptions for the client's locale, keeping the default when none match
+        let locale = match crate::locale::locale_from_meta(list_params.meta.as_ref()) {
+            Some(locale) => Some(locale),
+            None => match &mcp_session {
+                Some(session) => session.locale().await,
+                None => None,
+            },
+        };
+        if locale.is_some() {
+            for tool in &mut tools {
+                let localized = self.tools.get(&tool.name).and_then(|registered| {
+                    crate::locale::localized_text(
+                        registered.localized_descriptions(),
+                        locale.as_deref(),
+                    )
+                });
+                if let Some(description) = localized {
+                    tool.description = Some(description.to_string());
+                }
+            }
+        }
+
         // Strip execution field when server has no task capability (truthful advertisement)
         if !self.has_tasks {
             for tool in &mut tools {
@@ -1447,7 +2147,6 @@ impl JsonRpcHandler for ListToolsHandler {
         }
 
         // Implement cursor-based pagination
-        const DEFAULT_PAGE_SIZE: usize = 50; // MCP suggested default
         const MAX_LIMIT: u32 = 100; // Framework-specific DoS protection
 
         // Validate limit parameter - MCP spec requires positive integer
@@ -1463,12 +2162,16 @@ impl JsonRpcHandler for ListToolsHandler {
         let page_size = list_params
             .limit
             .map(|l| std::cmp::min(l, MAX_LIMIT) as usize)
-            .unwrap_or(DEFAULT_PAGE_SIZE);
+            .unwrap_or(self.page_size);
 
         // Find starting index based on cursor
-        let start_index = if let Some(cursor) = &cursor {
-            // Cursor contains the last tool name from previous page
-            let cursor_name = cursor.as_str();
+        let start_index = if let Some(last) = cursor
+            .as_ref()
+            .map(crate::handlers::decode_list_cursor)
+            .transpose()?
+        {
+            // Cursor encodes the last tool name from previous page
+            let cursor_name = last.as_str();
             // Find the position after the cursor name (first tool > cursor)
             tools
                 .iter()
@@ -1490,7 +2193,9 @@ impl JsonRpcHandler for ListToolsHandler {
         // Generate next cursor if there are more tools
         let next_cursor = if has_more {
             // Cursor should be the name of the last item in current page
-            page_tools.last().map(|t| Cursor::new(&t.name))
+            page_tools
+                .last()
+                .map(|t| crate::handlers::encode_list_cursor(&t.name))
         } else {
             None
         };
@@ -1547,6 +2252,11 @@ pub struct SessionAwareToolHandler {
     /// Optional task runtime — when present AND request has `params.task`,
     /// the handler creates a task and executes asynchronously.
     task_runtime: Option<Arc<crate::task::runtime::TaskRuntime>>,
+    tool_router: Option<Arc<dyn crate::tool::ToolRouter>>,
+    request_timeout: Option<std::time::Duration>,
+    idempotency_cache: Option<Arc<crate::idempotency::IdempotencyCache>>,
+    #[cfg(feature = "audit")]
+    audit_log: Option<crate::audit::AuditLog>,
     #[cfg(feature = "dynamic-tools")]
     tool_registry: Option<Arc<crate::tool_registry::ToolRegistry>>,
 }
@@ -1562,6 +2272,11 @@ impl SessionAwareToolHandler {
             session_manager,
             strict_lifecycle,
             task_runtime: None,
+            tool_router: None,
+            request_timeout: None,
+            idempotency_cache: None,
+            #[cfg(feature = "audit")]
+            audit_log: None,
             #[cfg(feature = "dynamic-tools")]
             tool_registry: None,
         }
@@ -1572,6 +2287,31 @@ impl SessionAwareToolHandler {
         self
     }
 
+    /// Resolve names missing from the registered tools through `router`
+    pub fn with_tool_router(mut self, router: Arc<dyn crate::tool::ToolRouter>) -> Self {
+        self.tool_router = Some(router);
+        self
+    }
+
+    /// Give every `tools/call` a deadline `timeout` after the transport received it
+    pub fn with_request_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Replay results of calls repeating an idempotency key within `window`
+    pub fn with_idempotency_window(mut self, window: std::time::Duration) -> Self {
+        self.idempotency_cache = Some(Arc::new(crate::idempotency::IdempotencyCache::new(window)));
+        self
+    }
+
+    /// Write an audit record after every `tools/call`
+    #[cfg(feature = "audit")]
+    pub fn with_audit_log(mut self, audit_log: crate::audit::AuditLog) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
     /// Set a dynamic tool registry for Dynamic mode.
     #[cfg(feature = "dynamic-tools")]
     pub fn with_tool_registry(mut self, registry: Arc<crate::tool_registry::ToolRegistry>) -> Self {
@@ -1589,6 +2329,127 @@ impl JsonRpcHandler for SessionAwareToolHandler {
         method: &str,
         params: Option<turul_mcp_json_rpc_server::RequestParams>,
         session_context: Option<turul_mcp_json_rpc_server::r#async::SessionContext>,
+    ) -> std::result::Result<serde_json::Value, McpError> {
+        // Every transport drops this future when the client abandons the call
+        // (`notifications/cancelled`, a disconnect), which fires the tool's token
+        let cancellation = CancellationToken::new();
+        let cancel_on_drop = cancellation.clone().drop_guard();
+        let result = self
+            .call_tool_audited(method, params, session_context, cancellation)
+            .await;
+        cancel_on_drop.disarm();
+        result
+    }
+
+    fn supported_methods(&self) -> Vec<String> {
+        vec!["tools/call".to_string()]
+    }
+}
+
+impl SessionAwareToolHandler {
+    /// Run `tools/call`, writing an audit record when an audit log is configured
+    async fn call_tool_audited(
+        &self,
+        method: &str,
+        params: Option<turul_mcp_json_rpc_server::RequestParams>,
+        session_context: Option<turul_mcp_json_rpc_server::r#async::SessionContext>,
+        cancellation: CancellationToken,
+    ) -> std::result::Result<serde_json::Value, McpError> {
+        #[cfg(feature = "audit")]
+        if let Some(ref audit_log) = self.audit_log {
+            let session_id = session_context.as_ref().map(|ctx| ctx.session_id.clone());
+            let tool = params
+                .as_ref()
+                .and_then(|p| p.get("name"))
+                .and_then(|name| name.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let arguments = params
+                .as_ref()
+                .and_then(|p| p.get("arguments"))
+                .cloned()
+                .unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new()));
+
+            let result = self
+                .call_tool_once(method, params, session_context, cancellation)
+                .await;
+
+            use crate::audit::AuditOutcome;
+            let outcome = match &result {
+                Ok(value) if value.get("isError") == Some(&serde_json::Value::Bool(true)) => {
+                    AuditOutcome::ToolError
+                }
+                Ok(_) => AuditOutcome::Success,
+                Err(e) => AuditOutcome::Error {
+                    message: e.to_string(),
+                },
+            };
+            audit_log
+                .record_tool_call(session_id, &tool, &arguments, outcome)
+                .await;
+            return result;
+        }
+
+        self.call_tool_once(method, params, session_context, cancellation)
+            .await
+    }
+
+    /// Run `tools/call`, replaying the cached result when an idempotency key repeats
+    async fn call_tool_once(
+        &self,
+        method: &str,
+        params: Option<turul_mcp_json_rpc_server::RequestParams>,
+        session_context: Option<turul_mcp_json_rpc_server::r#async::SessionContext>,
+        cancellation: CancellationToken,
+    ) -> std::result::Result<serde_json::Value, McpError> {
+        let Some(cache) = &self.idempotency_cache else {
+            return self
+                .call_tool(method, params, session_context, cancellation)
+                .await;
+        };
+
+        let key = params
+            .as_ref()
+            .and_then(|p| p.get("_meta"))
+            .and_then(|meta| meta.get(crate::idempotency::IDEMPOTENCY_KEY_META_KEY))
+            .and_then(|key| key.as_str())
+            .map(str::to_string);
+        #[cfg(feature = "http")]
+        let key = key.or_else(|| {
+            session_context
+                .as_ref()?
+                .extensions
+                .get(turul_http_mcp_server::IDEMPOTENCY_KEY_EXTENSION)?
+                .as_str()
+                .map(str::to_string)
+        });
+        let session_id = session_context.as_ref().map(|ctx| ctx.session_id.clone());
+
+        match (key, session_id) {
+            (Some(key), Some(session_id)) => {
+                let fingerprint = crate::idempotency::request_fingerprint(
+                    params.as_ref().and_then(|p| p.get("name")),
+                    params.as_ref().and_then(|p| p.get("arguments")),
+                );
+                cache
+                    .get_or_call(&session_id, &key, fingerprint, || {
+                        self.call_tool(method, params, session_context, cancellation)
+                    })
+                    .await
+            }
+            _ => {
+                self.call_tool(method, params, session_context, cancellation)
+                    .await
+            }
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        method: &str,
+        params: Option<turul_mcp_json_rpc_server::RequestParams>,
+        session_context: Option<turul_mcp_json_rpc_server::r#async::SessionContext>,
+        cancellation: CancellationToken,
     ) -> std::result::Result<serde_json::Value, McpError> {
         debug!("Handling {} request with session support", method);
 
@@ -1633,32 +2494,32 @@ impl JsonRpcHandler for SessionAwareToolHandler {
         // Use the parameter extraction pattern from the other project
         use turul_mcp_protocol::param_extraction::extract_params;
 
-        let call_params: turul_mcp_protocol::tools::CallToolParams = extract_params(params)?;
+        let mut call_params: turul_mcp_protocol::tools::CallToolParams = extract_params(params)?;
 
         // Find the tool — from live registry in Dynamic mode, or static map otherwise.
         // In both cases, we clone the Arc and release any lock before the await boundary.
         #[cfg(feature = "dynamic-tools")]
-        let tool: Arc<dyn McpTool> = if let Some(ref registry) = self.tool_registry {
-            registry
-                .get_tool(&call_params.name)
-                .await
-                .ok_or_else(|| McpError::ToolNotFound(call_params.name.clone()))?
+        let registered: Option<Arc<dyn McpTool>> = if let Some(ref registry) = self.tool_registry {
+            registry.get_tool(&call_params.name).await
         } else {
-            Arc::clone(
-                self.tools
-                    .get(&call_params.name)
-                    .ok_or_else(|| McpError::ToolNotFound(call_params.name.clone()))?,
-            )
+            self.tools.get(&call_params.name).cloned()
         };
         #[cfg(not(feature = "dynamic-tools"))]
-        let tool: Arc<dyn McpTool> = Arc::clone(
-            self.tools
-                .get(&call_params.name)
-                .ok_or_else(|| McpError::ToolNotFound(call_params.name.clone()))?,
-        );
+        let registered: Option<Arc<dyn McpTool>> = self.tools.get(&call_params.name).cloned();
+
+        // Fall back to the router for names no registered tool claims
+        let tool: Arc<dyn McpTool> = match (registered, &self.tool_router) {
+            (Some(tool), _) => Some(tool),
+            (None, Some(router)) => router.resolve(&call_params.name).await,
+            (None, None) => None,
+        }
+        .ok_or_else(|| McpError::ToolNotFound(call_params.name.clone()))?;
+
+        // Transport's receipt time, the start of the request's time budget
+        let received_at_ms = session_context.as_ref().map(|ctx| ctx.timestamp as i64);
 
         // Convert JSON-RPC SessionContext to MCP SessionContext for tool execution
-        let mcp_session_context = if let Some(json_rpc_ctx) = session_context {
+        let mut mcp_session_context = if let Some(json_rpc_ctx) = session_context {
             debug!(
                 "Converting JSON-RPC session context for tool call: session_id={}",
                 json_rpc_ctx.session_id
@@ -1672,6 +2533,28 @@ impl JsonRpcHandler for SessionAwareToolHandler {
             None
         };
 
+        // Hidden tools are reported exactly like unknown ones so their existence is not leaked
+        if !tool.is_visible(mcp_session_context.as_ref()) {
+            return Err(McpError::ToolNotFound(call_params.name.clone()));
+        }
+
+        if let Some(ctx) = mcp_session_context.as_mut() {
+            ctx.cancellation_token = cancellation;
+        }
+
+        // Expose the request's _meta to the tool
+        if let (Some(ctx), Some(meta)) = (mcp_session_context.as_mut(), call_params.meta.take()) {
+            ctx.set_request_meta(meta);
+        }
+        if let (Some(ctx), Some(received_at_ms)) = (mcp_session_context.as_mut(), received_at_ms) {
+            let received_at_ms = if received_at_ms > 0 {
+                received_at_ms
+            } else {
+                chrono::Utc::now().timestamp_millis()
+            };
+            ctx.set_deadline(received_at_ms, self.request_timeout);
+        }
+
         // Build arguments Value
         let args = call_params
             .arguments
@@ -1765,7 +2648,16 @@ impl JsonRpcHandler for SessionAwareToolHandler {
 
             let work: crate::task::executor::BoxedTaskWork = Box::new(move || {
                 Box::pin(async move {
-                    let outcome = match tool.call(args, mcp_session_context).await {
+                    // The request has been answered; from here `tasks/cancel` dropping
+                    // this future is what fires the tool's token
+                    let cancel_on_drop = mcp_session_context
+                        .as_ref()
+                        .map(|ctx| ctx.cancellation_token.clone().drop_guard());
+                    let result = call_with_response_meta(&tool, args, mcp_session_context).await;
+                    if let Some(cancel_on_drop) = cancel_on_drop {
+                        cancel_on_drop.disarm();
+                    }
+                    let outcome = match result {
                         Ok(result) => match serde_json::to_value(&result) {
                             Ok(value) => TaskOutcome::Success(value),
                             Err(e) => TaskOutcome::Error {
@@ -1821,7 +2713,7 @@ impl JsonRpcHandler for SessionAwareToolHandler {
             serde_json::to_value(result).map_err(McpError::SerializationError)
         } else {
             // Synchronous execution (no task augmentation or no runtime)
-            match tool.call(args, mcp_session_context).await {
+            match call_with_response_meta(&tool, args, mcp_session_context).await {
                 Ok(response) => {
                     serde_json::to_value(response).map_err(McpError::SerializationError)
                 }
@@ -1832,9 +2724,36 @@ impl JsonRpcHandler for SessionAwareToolHandler {
             }
         }
     }
+}
 
-    fn supported_methods(&self) -> Vec<String> {
-        vec!["tools/call".to_string()]
+/// Run `tool` and merge any `_meta` it attached through its session into the result
+async fn call_with_response_meta(
+    tool: &Arc<dyn McpTool>,
+    args: serde_json::Value,
+    session: Option<SessionContext>,
+) -> McpResult<CallToolResult> {
+    let Some(session) = session else {
+        return tool_error_as_result(tool.call(args, None).await);
+    };
+    let result = tool_error_as_result(tool.call(args, Some(session.clone())).await)?;
+    Ok(session.apply_response_meta(result))
+}
+
+/// Turn a failure the tool reported as content into an `isError` result;
+/// every other error stays a JSON-RPC error
+fn tool_error_as_result(result: McpResult<CallToolResult>) -> McpResult<CallToolResult> {
+    match result {
+        Err(McpError::ToolErrorContent(content)) => Ok(CallToolResult::error(content)),
+        other => other,
+    }
+}
+
+#[cfg(feature = "dynamic-tools")]
+impl Drop for McpServer {
+    fn drop(&mut self) {
+        if let Some(handle) = self.tool_config_watcher.get_mut().take() {
+            handle.abort();
+        }
     }
 }
 
@@ -1861,20 +2780,35 @@ mod tests {
     use turul_mcp_protocol::tools::{CallToolResult, ToolResult}; // HasBaseMetadata, HasDescription, etc.
 
     struct TestTool {
+        name: String,
         input_schema: ToolSchema,
+        hidden: bool,
     }
 
     impl TestTool {
         fn new() -> Self {
+            Self::named("test")
+        }
+
+        fn named(name: impl Into<String>) -> Self {
             Self {
+                name: name.into(),
                 input_schema: ToolSchema::object(),
+                hidden: false,
+            }
+        }
+
+        fn hidden(name: impl Into<String>) -> Self {
+            Self {
+                hidden: true,
+                ..Self::named(name)
             }
         }
     }
 
     impl HasBaseMetadata for TestTool {
         fn name(&self) -> &str {
-            "test"
+            &self.name
         }
         fn title(&self) -> Option<&str> {
             Some("Test Tool")
@@ -1916,6 +2850,10 @@ mod tests {
 
     #[async_trait]
     impl McpTool for TestTool {
+        fn is_visible(&self, _session: Option<&crate::SessionContext>) -> bool {
+            !self.hidden
+        }
+
         async fn call(
             &self,
             _args: Value,
@@ -1927,6 +2865,40 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "dynamic-tools")]
+    #[tokio::test]
+    async fn test_tool_config_watcher_starts_once_and_stops_on_shutdown() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tools.json");
+        std::fs::write(&path, r#"{"tools": ["test"]}"#).unwrap();
+
+        let server = McpServer::builder()
+            .name("watch-test")
+            .tool(TestTool::new())
+            .tool_change_mode(crate::ToolChangeMode::Dynamic)
+            .watch_config(&path)
+            .bind_address("127.0.0.1:0".parse().unwrap())
+            .build()
+            .unwrap();
+
+        let watcher_id = |server: &McpServer| {
+            server
+                .tool_config_watcher
+                .try_lock()
+                .unwrap()
+                .as_ref()
+                .map(|handle| handle.id())
+        };
+
+        let _first = server.in_process_service().await;
+        let started = watcher_id(&server).expect("watcher should be running");
+        let _second = server.in_process_service().await;
+        assert_eq!(watcher_id(&server), Some(started));
+
+        server.run_with_shutdown(async {}).await.unwrap();
+        assert_eq!(watcher_id(&server), None);
+    }
+
     #[test]
     fn test_server_creation() {
         let server = McpServer::builder()
@@ -1954,6 +2926,81 @@ mod tests {
         assert_eq!(response.tools[0].name, "test");
     }
 
+    #[tokio::test]
+    async fn test_list_tools_cursor_walks_every_page_once() {
+        let mut tools: HashMap<String, Arc<dyn McpTool>> = HashMap::new();
+        for i in 0..10 {
+            let name = format!("tool_{:02}", i);
+            tools.insert(name.clone(), Arc::new(TestTool::named(name)));
+        }
+        let handler = ListToolsHandler::new(tools, false).with_page_size(4);
+
+        let mut seen = Vec::new();
+        let mut page_sizes = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let params = cursor.as_ref().map(|cursor| {
+                turul_mcp_json_rpc_server::RequestParams::Object(HashMap::from([(
+                    "cursor".to_string(),
+                    Value::String(cursor.clone()),
+                )]))
+            });
+            let result = handler.handle("tools/list", params, None).await.unwrap();
+            let response: ListToolsResult = serde_json::from_value(result).unwrap();
+            page_sizes.push(response.tools.len());
+            seen.extend(response.tools.into_iter().map(|tool| tool.name));
+
+            match response.next_cursor {
+                Some(next) => {
+                    // Cursors are opaque, not the raw tool name
+                    assert!(!next.as_str().starts_with("tool_"));
+                    cursor = Some(next.as_str().to_string());
+                }
+                None => break,
+            }
+        }
+
+        assert_eq!(page_sizes, vec![4, 4, 2]);
+        let expected: Vec<String> = (0..10).map(|i| format!("tool_{:02}", i)).collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[tokio::test]
+    async fn test_list_tools_rejects_unknown_cursor() {
+        let tools: HashMap<String, Arc<dyn McpTool>> = HashMap::from([(
+            "test".to_string(),
+            Arc::new(TestTool::new()) as Arc<dyn McpTool>,
+        )]);
+        let handler = ListToolsHandler::new(tools, false);
+
+        for cursor in ["not a cursor!", "invalid_cursor_value"] {
+            let params = turul_mcp_json_rpc_server::RequestParams::Object(HashMap::from([(
+                "cursor".to_string(),
+                Value::String(cursor.to_string()),
+            )]));
+            let error = handler
+                .handle("tools/list", Some(params), None)
+                .await
+                .unwrap_err();
+            assert!(
+                matches!(error, McpError::InvalidParameters(_)),
+                "cursor {:?} gave {:?}",
+                cursor,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_zero_list_page_size_is_rejected() {
+        let result = McpServer::builder()
+            .name("test-server")
+            .version("1.0.0")
+            .list_page_size(0)
+            .build();
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_tool_handler() {
         let mut tools: HashMap<String, Arc<dyn McpTool>> = HashMap::new();
@@ -1982,4 +3029,379 @@ mod tests {
             assert_eq!(text, "test result");
         }
     }
+
+    /// Synthesizes an `echo.<suffix>` tool for any suffix on demand
+    struct EchoRouter;
+
+    impl EchoRouter {
+        fn echo_tool(name: &str) -> crate::DynamicTool {
+            let suffix = name.trim_start_matches("echo.").to_string();
+            turul_mcp_builders::tool::ToolBuilder::new(name)
+                .description(format!("Echoes text under {suffix}"))
+                .string_param("text", "Text to echo")
+                .execute(move |args| {
+                    let suffix = suffix.clone();
+                    async move {
+                        let text = args["text"].as_str().unwrap_or_default();
+                        Ok(serde_json::json!(format!("{suffix}: {text}")))
+                    }
+                })
+                .build()
+                .unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl crate::ToolRouter for EchoRouter {
+        async fn resolve(&self, name: &str) -> Option<Arc<dyn McpTool>> {
+            match name.strip_prefix("echo.") {
+                Some(suffix) if !suffix.is_empty() => Some(Arc::new(Self::echo_tool(name))),
+                _ => None,
+            }
+        }
+
+        async fn list(&self) -> Vec<Tool> {
+            ["echo.alpha", "echo.beta", "test"]
+                .into_iter()
+                .map(|name| Self::echo_tool(name).to_tool())
+                .collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_tools_merges_router_tools() {
+        let mut tools: HashMap<String, Arc<dyn McpTool>> = HashMap::new();
+        tools.insert("test".to_string(), Arc::new(TestTool::new()));
+
+        let handler = ListToolsHandler::new(tools, false).with_tool_router(Arc::new(EchoRouter));
+        let result = handler.handle("tools/list", None, None).await.unwrap();
+
+        let response: ListToolsResult = serde_json::from_value(result).unwrap();
+        let names: Vec<&str> = response.tools.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["echo.alpha", "echo.beta", "test"]);
+        // The registered tool shadows the router's tool of the same name
+        assert_eq!(
+            response.tools[2].description.as_deref(),
+            Some("Test tool for unit tests")
+        );
+    }
+
+    /// Lists and serves a tool no session can see
+    struct HiddenRouter;
+
+    #[async_trait]
+    impl crate::ToolRouter for HiddenRouter {
+        async fn resolve(&self, name: &str) -> Option<Arc<dyn McpTool>> {
+            (name == "routed_secret").then(|| Arc::new(TestTool::hidden(name)) as Arc<dyn McpTool>)
+        }
+
+        async fn list(&self) -> Vec<Tool> {
+            vec![TestTool::hidden("routed_secret").to_tool()]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_tools_hides_invisible_runtime_and_routed_tools() {
+        let mut tools: HashMap<String, Arc<dyn McpTool>> = HashMap::new();
+        tools.insert("test".to_string(), Arc::new(TestTool::new()));
+        tools.insert("secret".to_string(), Arc::new(TestTool::hidden("secret")));
+        let runtime_tools = crate::tool::RuntimeTools::new(Some(Arc::new(HiddenRouter)));
+        runtime_tools.insert(Arc::new(TestTool::hidden("runtime_secret")));
+        runtime_tools.insert(Arc::new(TestTool::named("runtime_open")));
+
+        let handler = ListToolsHandler::new(tools, false).with_tool_router(Arc::new(runtime_tools));
+        let result = handler.handle("tools/list", None, None).await.unwrap();
+
+        let response: ListToolsResult = serde_json::from_value(result).unwrap();
+        let names: Vec<&str> = response.tools.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["runtime_open", "test"]);
+    }
+
+    #[tokio::test]
+    async fn test_tool_router_resolves_unregistered_names() {
+        let mut tools: HashMap<String, Arc<dyn McpTool>> = HashMap::new();
+        tools.insert("test".to_string(), Arc::new(TestTool::new()));
+        let session_manager = Arc::new(SessionManager::new(ServerCapabilities::default()));
+        let handler = SessionAwareToolHandler::new(tools, session_manager, false)
+            .with_tool_router(Arc::new(EchoRouter));
+        let call = |name: &str| {
+            turul_mcp_json_rpc_server::RequestParams::Object(
+                [
+                    ("name".to_string(), serde_json::json!(name)),
+                    ("arguments".to_string(), serde_json::json!({ "text": "hi" })),
+                ]
+                .into_iter()
+                .collect(),
+            )
+        };
+
+        // Materialized on demand, even though the router never listed it
+        let result = handler
+            .handle("tools/call", Some(call("echo.gamma")), None)
+            .await
+            .unwrap();
+        let response: CallToolResult = serde_json::from_value(result).unwrap();
+        match &response.content[0] {
+            ToolResult::Text { text, .. } => assert!(text.contains("gamma: hi"), "{text}"),
+            other => panic!("expected text, got {other:?}"),
+        }
+
+        // Registered tools are still served from the static registry
+        let result = handler
+            .handle("tools/call", Some(call("test")), None)
+            .await
+            .unwrap();
+        let response: CallToolResult = serde_json::from_value(result).unwrap();
+        assert!(
+            matches!(&response.content[0], ToolResult::Text { text, .. } if text == "test result")
+        );
+
+        let missing = handler
+            .handle("tools/call", Some(call("jira.search")), None)
+            .await;
+        assert!(matches!(missing, Err(McpError::ToolNotFound(name)) if name == "jira.search"));
+    }
+
+    #[test]
+    fn test_tool_router_enables_tools_capability() {
+        let server = McpServer::builder()
+            .name("router-server")
+            .version("1.0.0")
+            .with_tool_router(Arc::new(EchoRouter))
+            .build()
+            .unwrap();
+
+        assert!(server.tools.is_empty());
+        assert!(server.capabilities.tools.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_register_tool_requires_tools_capability() {
+        let server = McpServer::builder()
+            .name("toolless-server")
+            .version("1.0.0")
+            .build()
+            .unwrap();
+        assert!(server.capabilities.tools.is_none());
+
+        let err = server
+            .register_tool(Arc::new(TestTool::new()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, McpError::ConfigurationError(_)));
+        assert!(!server.deregister_tool("test").await.unwrap());
+    }
+
+    async fn french_session(
+        session_manager: &Arc<SessionManager>,
+    ) -> turul_mcp_json_rpc_server::SessionContext {
+        let session_id = session_manager.create_session().await;
+        session_manager
+            .set_session_state(
+                &session_id,
+                crate::locale::LOCALE_STATE_KEY,
+                serde_json::json!("fr-FR"),
+            )
+            .await;
+        turul_mcp_json_rpc_server::SessionContext {
+            session_id,
+            metadata: HashMap::new(),
+            broadcaster: None,
+            timestamp: 0,
+            extensions: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_tools_uses_session_locale() {
+        let add = turul_mcp_builders::ToolBuilder::new("add")
+            .description("Add two numbers")
+            .localized_description("fr", "Additionner deux nombres")
+            .localized_description("de", "Zwei Zahlen addieren")
+            .execute(|args| async move { Ok(args) })
+            .build()
+            .unwrap();
+        let mut tools: HashMap<String, Arc<dyn McpTool>> = HashMap::new();
+        tools.insert("add".to_string(), Arc::new(add));
+        tools.insert("test".to_string(), Arc::new(TestTool::new()));
+
+        let session_manager = Arc::new(SessionManager::new(ServerCapabilities::default()));
+        let handler = ListToolsHandler::new_with_session_manager(
+            tools,
+            session_manager.clone(),
+            false,
+            false,
+        );
+        let descriptions = |result: Value| -> Vec<Option<String>> {
+            let response: ListToolsResult = serde_json::from_value(result).unwrap();
+            response.tools.into_iter().map(|t| t.description).collect()
+        };
+
+        // French session: translated where provided, default otherwise
+        let ctx = french_session(&session_manager).await;
+        let result = handler
+            .handle("tools/list", None, Some(ctx.clone()))
+            .await
+            .unwrap();
+        assert_eq!(
+            descriptions(result),
+            [
+                Some("Additionner deux nombres".to_string()),
+                Some("Test tool for unit tests".to_string())
+            ]
+        );
+
+        // A request-level locale overrides the session locale
+        let params = turul_mcp_json_rpc_server::RequestParams::Object(
+            [("_meta".to_string(), serde_json::json!({"locale": "de"}))]
+                .into_iter()
+                .collect(),
+        );
+        let result = handler
+            .handle("tools/list", Some(params), Some(ctx))
+            .await
+            .unwrap();
+        assert_eq!(
+            descriptions(result)[0].as_deref(),
+            Some("Zwei Zahlen addieren")
+        );
+
+        // No locale: default descriptions
+        let result = handler.handle("tools/list", None, None).await.unwrap();
+        assert_eq!(descriptions(result)[0].as_deref(), Some("Add two numbers"));
+    }
+
+    #[tokio::test]
+    async fn test_message_catalog_localizes_tool_errors() {
+        let mut tools: HashMap<String, Arc<dyn McpTool>> = HashMap::new();
+        tools.insert("test".to_string(), Arc::new(TestTool::new()));
+        let session_manager = Arc::new(SessionManager::new(ServerCapabilities::default()));
+        let catalog = crate::locale::MessageCatalog::new().message(
+            "fr",
+            "tool_not_found",
+            "Outil introuvable : {name}",
+        );
+        let handler = crate::locale::LocalizedErrors::new(
+            SessionAwareToolHandler::new(tools, session_manager.clone(), false),
+            Some(Arc::new(catalog)),
+            session_manager.clone(),
+        );
+        let params = || {
+            turul_mcp_json_rpc_server::RequestParams::Object(
+                [("name".to_string(), serde_json::json!("missing"))]
+                    .into_iter()
+                    .collect(),
+            )
+        };
+
+        let ctx = french_session(&session_manager).await;
+        let error = handler
+            .handle("tools/call", Some(params()), Some(ctx))
+            .await
+            .unwrap_err();
+        let expected_code = McpError::ToolNotFound(String::new()).to_error_object().code;
+        let object = error.to_error_object();
+        assert_eq!(object.code, expected_code);
+        assert_eq!(object.message, "Outil introuvable : missing");
+
+        // Without a locale the default English message is kept
+        let error = handler
+            .handle("tools/call", Some(params()), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, McpError::ToolNotFound(_)));
+    }
+
+    async fn initialize_requesting(
+        protocol_version: &str,
+    ) -> (
+        std::result::Result<Value, McpError>,
+        Arc<SessionManager>,
+        String,
+    ) {
+        let session_manager = Arc::new(SessionManager::new(ServerCapabilities::default()));
+        let session_id = session_manager.create_session().await;
+        let handler = SessionAwareInitializeHandler::new(
+            Implementation::new("test-server", "1.0.0"),
+            ServerCapabilities::default(),
+            None,
+            Arc::clone(&session_manager),
+            false,
+            String::new(),
+        );
+        let params = turul_mcp_json_rpc_server::RequestParams::Object(
+            [
+                (
+                    "protocolVersion".to_string(),
+                    serde_json::json!(protocol_version),
+                ),
+                ("capabilities".to_string(), serde_json::json!({})),
+                (
+                    "clientInfo".to_string(),
+                    serde_json::json!({"name": "test-client", "version": "1.0.0"}),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let context = turul_mcp_json_rpc_server::r#async::SessionContext {
+            session_id: session_id.clone(),
+            metadata: HashMap::new(),
+            broadcaster: None,
+            timestamp: 0,
+            extensions: HashMap::new(),
+        };
+        let result = handler
+            .handle("initialize", Some(params), Some(context))
+            .await;
+        (result, session_manager, session_id)
+    }
+
+    #[tokio::test]
+    async fn test_initialize_echoes_supported_version() {
+        for requested in ["2024-11-05", "2025-06-18", "2025-11-25"] {
+            let (result, session_manager, session_id) = initialize_requesting(requested).await;
+            assert_eq!(result.unwrap()["protocolVersion"], requested);
+            assert_eq!(
+                session_manager
+                    .get_session_state(&session_id, "mcp_version")
+                    .await,
+                Some(serde_json::json!(requested))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_initialize_downgrades_unsupported_version_to_newest() {
+        // A newer spec revision, and an unknown date inside the supported range
+        for requested in ["2026-06-01", "2025-09-01"] {
+            let (result, session_manager, session_id) = initialize_requesting(requested).await;
+            assert_eq!(
+                result.unwrap()["protocolVersion"],
+                McpVersion::LATEST.as_str()
+            );
+            assert_eq!(
+                session_manager
+                    .get_session_state(&session_id, "mcp_version")
+                    .await,
+                Some(serde_json::json!(McpVersion::LATEST.as_str()))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_initialize_offers_newest_version_to_incompatible_client() {
+        // The server answers with what it supports; the client decides to disconnect
+        let (result, _, _) = initialize_requesting("2020-01-01").await;
+        assert_eq!(
+            result.unwrap()["protocolVersion"],
+            McpVersion::LATEST.as_str()
+        );
+
+        let (result, _, _) = initialize_requesting("latest").await;
+        let Err(McpError::InvalidParameters(message)) = result else {
+            panic!("Expected invalid parameters, got {:?}", result);
+        };
+        assert!(message.contains("Invalid protocol version format: latest"));
+    }
 }
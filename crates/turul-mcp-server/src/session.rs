@@ -18,14 +18,30 @@ use std::time::{Duration, Instant};
 use async_trait::async_trait;
 use serde_json::Value;
 use tokio::sync::{RwLock, broadcast};
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+use turul_mcp_protocol::meta::Meta;
 use turul_mcp_protocol::{ClientCapabilities, Implementation, McpVersion, ServerCapabilities};
 use turul_mcp_session_storage::{SessionStorage, SessionStorageError, SessionView};
 
 type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
 
+/// Session state key marking a `resources/subscribe` to `uri`
+///
+/// Subscriptions live in session state, so they follow the session across
+/// instances and disappear with it when it is deleted or expires.
+pub(crate) fn resource_subscription_key(uri: &str) -> String {
+    format!("mcp:resources:subscription:{}", uri)
+}
+
+/// Request-scoped extension holding the current request's deadline (Unix milliseconds)
+pub(crate) const REQUEST_DEADLINE_EXTENSION: &str = "__turul_internal.deadline_ms";
+
+/// `_meta` key a client may send with `tools/call` to shorten its time budget
+pub const TIMEOUT_META_KEY: &str = "timeoutMs";
+
 /// Session context provided automatically to tools and handlers
 ///
 /// ## Async API
@@ -65,6 +81,61 @@ pub struct SessionContext {
     /// Populated by transport from `RequestContext.extensions` via JSON-RPC `SessionContext`.
     /// Never persisted to session storage — exists only for the duration of one request.
     pub extensions: HashMap<String, Value>,
+    /// `_meta` the client sent with the current request
+    pub request_meta: Option<Meta>,
+    /// `_meta` fields the handler attached to the current result, shared by all clones
+    pub response_meta: Arc<std::sync::Mutex<HashMap<String, Value>>>,
+    /// Cancelled when the client abandons the current request
+    pub cancellation_token: CancellationToken,
+}
+
+/// Sends `notifications/progress` for one `progressToken` over the session's stream
+///
+/// Obtained from [`SessionContext::progress`].
+#[derive(Clone)]
+pub struct ProgressReporter {
+    session: SessionContext,
+    token: Option<String>,
+}
+
+impl ProgressReporter {
+    /// The token progress is reported against, if any
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Report `current` progress, optionally out of `total`, with an optional message
+    ///
+    /// `current` should increase with every call. Does nothing when there is no token.
+    pub async fn report(&self, current: f64, total: Option<f64>, message: Option<&str>) {
+        let Some(token) = &self.token else {
+            debug!(
+                "No progressToken for session {}, dropping progress {}",
+                self.session.session_id, current
+            );
+            return;
+        };
+
+        let mut other = HashMap::new();
+        other.insert("progressToken".to_string(), serde_json::json!(token));
+        other.insert("progress".to_string(), serde_json::json!(current));
+        if let Some(total) = total {
+            other.insert("total".to_string(), serde_json::json!(total));
+        }
+        if let Some(message) = message {
+            other.insert("message".to_string(), serde_json::json!(message));
+        }
+
+        let params = turul_mcp_protocol::RequestParams { meta: None, other };
+        let notification =
+            turul_mcp_protocol::JsonRpcNotification::new("notifications/progress".to_string())
+                .with_params(params);
+        self.session
+            .notify(SessionEvent::Notification(
+                serde_json::to_value(notification).unwrap(),
+            ))
+            .await;
+    }
 }
 
 impl SessionContext {
@@ -223,6 +294,9 @@ impl SessionContext {
             send_notification,
             broadcaster,
             extensions: json_rpc_ctx.extensions,
+            request_meta: None,
+            response_meta: Arc::default(),
+            cancellation_token: CancellationToken::new(),
         }
     }
 
@@ -241,6 +315,143 @@ impl SessionContext {
         self.extensions.get(key)
     }
 
+    /// Get the `_meta` object sent with the current `tools/call` request, if any
+    ///
+    /// Fields outside the structured ones (`progressToken`, `cursor`, ...) are in
+    /// [`Meta::extra`], e.g. a correlation id the client wants echoed back.
+    pub fn request_meta(&self) -> Option<&Meta> {
+        self.request_meta.as_ref()
+    }
+
+    /// Token cancelled when the client abandons the current `tools/call`
+    ///
+    /// Fires on `notifications/cancelled` for the request or when the client
+    /// disconnects before the result is sent. Async tools need not check it:
+    /// their future is dropped either way. Work the drop cannot stop, such as
+    /// a `spawn_blocking` closure, should poll
+    /// [`is_cancelled`](CancellationToken::is_cancelled) and give up early.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
+    }
+
+    /// Attach a `_meta` field to the result of the current `tools/call`
+    ///
+    /// The dispatcher merges attached fields into the result's `_meta` with
+    /// [`Meta::merge_request_extras`], so structured keys such as `progressToken`
+    /// are ignored. A field set here replaces one of the same name the tool put
+    /// on its `CallToolResult`.
+    pub fn set_response_meta(&self, key: impl Into<String>, value: impl Into<Value>) {
+        self.response_meta
+            .lock()
+            .unwrap()
+            .insert(key.into(), value.into());
+    }
+
+    /// Get the `progressToken` the client attached to the current `tools/call`, if any
+    pub fn progress_token(&self) -> Option<&str> {
+        Some(self.request_meta()?.progress_token.as_ref()?.as_str())
+    }
+
+    /// Record the `_meta` sent with the current request
+    ///
+    /// A `_meta` whose structured fields do not parse (e.g. a numeric
+    /// `progressToken`) keeps its other fields.
+    pub(crate) fn set_request_meta(&mut self, fields: HashMap<String, Value>) {
+        let meta = serde_json::from_value(Value::Object(fields.clone().into_iter().collect()))
+            .unwrap_or_else(|_| Meta::new().merge_request_extras(Some(&fields)));
+        self.request_meta = Some(meta);
+    }
+
+    /// Merge the `_meta` fields attached with [`set_response_meta`](Self::set_response_meta)
+    /// into `result`
+    pub(crate) fn apply_response_meta(
+        &self,
+        mut result: turul_mcp_protocol::tools::CallToolResult,
+    ) -> turul_mcp_protocol::tools::CallToolResult {
+        let attached = std::mem::take(&mut *self.response_meta.lock().unwrap());
+        if attached.is_empty() {
+            return result;
+        }
+        let merged = Meta::new().merge_request_extras(Some(&attached));
+        result
+            .meta
+            .get_or_insert_with(HashMap::new)
+            .extend(merged.extra);
+        result
+    }
+
+    /// Recover the originating request id and session from the current `progressToken`
+    ///
+    /// Returns `None` when the client sent no token or one not produced by
+    /// [`Correlation`](crate::Correlation).
+    pub fn progress_correlation(&self) -> Option<crate::Correlation> {
+        crate::Correlation::parse(self.progress_token()?).ok()
+    }
+
+    /// When the current request runs out of time, if it has a budget
+    ///
+    /// The budget is the server's
+    /// [`request_timeout`](crate::McpServerBuilder::request_timeout) counted from
+    /// when the transport received the request, shortened by a `_meta.timeoutMs`
+    /// hint on `tools/call` if the client sent one.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.remaining().map(|remaining| Instant::now() + remaining)
+    }
+
+    /// Time left before [`deadline`](Self::deadline); zero once it has passed
+    ///
+    /// Lets a tool skip optional, expensive work when little time is left.
+    pub fn remaining(&self) -> Option<Duration> {
+        let deadline_ms = self.extensions.get(REQUEST_DEADLINE_EXTENSION)?.as_i64()?;
+        let left_ms = deadline_ms - chrono::Utc::now().timestamp_millis();
+        Some(Duration::from_millis(left_ms.max(0) as u64))
+    }
+
+    /// Record the deadline of the current request received at `received_at_ms`
+    ///
+    /// The earlier of `timeout` and the request's `_meta.timeoutMs` wins; with
+    /// neither the request has no deadline.
+    pub(crate) fn set_deadline(&mut self, received_at_ms: i64, timeout: Option<Duration>) {
+        let hint = self
+            .request_meta()
+            .and_then(|meta| meta.extra.get(TIMEOUT_META_KEY))
+            .and_then(Value::as_u64)
+            .map(Duration::from_millis);
+        let budget = match (timeout, hint) {
+            (Some(timeout), Some(hint)) => Some(timeout.min(hint)),
+            (budget, None) | (None, budget) => budget,
+        };
+        if let Some(budget) = budget {
+            let deadline_ms = received_at_ms.saturating_add(budget.as_millis() as i64);
+            self.extensions.insert(
+                REQUEST_DEADLINE_EXTENSION.to_string(),
+                serde_json::json!(deadline_ms),
+            );
+        }
+    }
+
+    /// Get the client's preferred locale for this request
+    ///
+    /// A `_meta.locale` on the current `tools/call` wins over the locale the
+    /// client declared in `initialize`. See [`crate::locale`].
+    pub async fn locale(&self) -> Option<String> {
+        if let Some(locale) = self
+            .request_meta()
+            .and_then(|meta| meta.extra.get("locale"))
+            .and_then(Value::as_str)
+        {
+            return Some(locale.to_string());
+        }
+        self.get_typed_state(crate::locale::LOCALE_STATE_KEY).await
+    }
+
+    /// Get the caller identity established by the server's
+    /// [`Authenticator`](turul_http_mcp_server::Authenticator), if one is installed
+    #[cfg(feature = "http")]
+    pub fn identity(&self) -> Option<turul_http_mcp_server::Identity> {
+        self.get_typed_extension(turul_http_mcp_server::IDENTITY_EXTENSION)
+    }
+
     /// Get a typed request-scoped extension value by key
     pub fn get_typed_extension<T: serde::de::DeserializeOwned>(&self, key: &str) -> Option<T> {
         self.extensions
@@ -333,6 +544,9 @@ impl SessionContext {
             send_notification,
             broadcaster: None,
             extensions: HashMap::new(),
+            request_meta: None,
+            response_meta: Arc::default(),
+            cancellation_token: CancellationToken::new(),
         }
     }
 
@@ -402,6 +616,112 @@ impl SessionContext {
         .await;
     }
 
+    /// Get a [`ProgressReporter`] for a long-running operation
+    ///
+    /// Uses `token` if given, otherwise the `progressToken` from the current request's
+    /// `_meta`. With neither, the reporter sends nothing: clients only expect progress
+    /// for requests they attached a token to.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(session: turul_mcp_server::SessionContext) {
+    /// let progress = session.progress(None);
+    /// progress.report(1.0, Some(3.0), Some("Downloading")).await;
+    /// # }
+    /// ```
+    pub fn progress(&self, token: Option<&str>) -> ProgressReporter {
+        ProgressReporter {
+            token: token.or_else(|| self.progress_token()).map(str::to_string),
+            session: self.clone(),
+        }
+    }
+
+    /// Ask the client to run an LLM completion via `sampling/createMessage`
+    ///
+    /// The request goes down the session's SSE stream and the call waits up to
+    /// [`DEFAULT_SAMPLING_TIMEOUT`](crate::sampling::DEFAULT_SAMPLING_TIMEOUT) for the
+    /// client to POST its answer. Fails with [`McpError::InvalidCapability`] when the
+    /// client did not declare `sampling` at initialize, and surfaces an error the
+    /// client returns as [`McpError::JsonRpcError`].
+    ///
+    /// [`McpError::InvalidCapability`]: turul_mcp_protocol::McpError::InvalidCapability
+    /// [`McpError::JsonRpcError`]: turul_mcp_protocol::McpError::JsonRpcError
+    pub async fn create_message(
+        &self,
+        params: turul_mcp_protocol::sampling::CreateMessageParams,
+    ) -> crate::McpResult<turul_mcp_protocol::sampling::CreateMessageResult> {
+        self.create_message_with_timeout(params, crate::sampling::DEFAULT_SAMPLING_TIMEOUT)
+            .await
+    }
+
+    /// [`create_message`](Self::create_message) with an explicit timeout
+    pub async fn create_message_with_timeout(
+        &self,
+        params: turul_mcp_protocol::sampling::CreateMessageParams,
+        timeout: Duration,
+    ) -> crate::McpResult<turul_mcp_protocol::sampling::CreateMessageResult> {
+        let capabilities: Option<ClientCapabilities> =
+            self.get_typed_state("client_capabilities").await;
+        if capabilities.and_then(|c| c.sampling).is_none() {
+            return Err(turul_mcp_protocol::McpError::InvalidCapability(
+                "client did not declare the sampling capability".to_string(),
+            ));
+        }
+
+        let result = self
+            .request_client(
+                "sampling/createMessage",
+                serde_json::to_value(params)?,
+                timeout,
+            )
+            .await?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    /// Send a JSON-RPC request to the client and return the `result` it answers with
+    async fn request_client(
+        &self,
+        method: &str,
+        params: Value,
+        timeout: Duration,
+    ) -> crate::McpResult<Value> {
+        use turul_http_mcp_server::notification_bridge::SharedNotificationBroadcaster;
+        use turul_mcp_protocol::McpError;
+
+        let broadcaster = self
+            .broadcaster
+            .as_ref()
+            .and_then(|b| b.downcast_ref::<SharedNotificationBroadcaster>())
+            .ok_or_else(|| {
+                McpError::SessionError(format!(
+                    "session {} has no stream to send {} on",
+                    self.session_id, method
+                ))
+            })?;
+        let mut response = broadcaster
+            .request_client(&self.session_id, method, params, timeout)
+            .await
+            .map_err(|e| McpError::TransportError(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(McpError::JsonRpcError {
+                code: error
+                    .get("code")
+                    .and_then(Value::as_i64)
+                    .unwrap_or(turul_mcp_json_rpc_server::error_codes::INTERNAL_ERROR),
+                message: error
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                data: error.get("data").cloned(),
+            });
+        }
+        Ok(response
+            .get_mut("result")
+            .map(Value::take)
+            .unwrap_or(Value::Null))
+    }
+
     /// Send a logging message notification (with session-aware level filtering)
     pub async fn notify_log(
         &self,
@@ -1659,6 +1979,69 @@ impl SessionManager {
         }
     }
 
+    /// List the live sessions subscribed to `uri` via `resources/subscribe`.
+    ///
+    /// Enumerates sessions from the storage backend and skips terminated ones.
+    pub async fn resource_subscribers(
+        &self,
+        uri: &str,
+    ) -> std::result::Result<Vec<String>, String> {
+        let key = resource_subscription_key(uri);
+        let all_ids = self
+            .storage
+            .list_sessions()
+            .await
+            .map_err(|e| format!("Failed to list sessions from storage: {}", e))?;
+
+        let mut subscribers = Vec::new();
+        for session_id in all_ids {
+            if let Ok(Some(info)) = self.storage.get_session(&session_id).await
+                && !info.is_terminated()
+                && info.state.contains_key(&key)
+            {
+                subscribers.push(session_id);
+            }
+        }
+        Ok(subscribers)
+    }
+
+    /// Send `notifications/resources/updated` for `uri` to every subscribed session.
+    ///
+    /// Sessions that never subscribed to `uri` receive nothing. Returns the number of
+    /// sessions notified, or `Err` if enumeration or any dispatch fails.
+    pub async fn notify_resource_updated(&self, uri: &str) -> std::result::Result<usize, String> {
+        let mut other = HashMap::new();
+        other.insert("uri".to_string(), serde_json::json!(uri));
+        let params = turul_mcp_protocol::RequestParams { meta: None, other };
+        let notification = turul_mcp_protocol::JsonRpcNotification::new(
+            "notifications/resources/updated".to_string(),
+        )
+        .with_params(params);
+        let data = serde_json::to_value(&notification).map_err(|e| e.to_string())?;
+
+        let subscribers = self.resource_subscribers(uri).await?;
+        let mut dispatch_errors = Vec::new();
+        for session_id in &subscribers {
+            if let Err(e) = self
+                .dispatch_custom_event(session_id, notification.method.clone(), data.clone())
+                .await
+            {
+                dispatch_errors.push(format!("session {}: {}", session_id, e));
+            }
+        }
+        debug!(
+            "Resource {} updated: notified {} subscribed sessions",
+            uri,
+            subscribers.len()
+        );
+
+        if dispatch_errors.is_empty() {
+            Ok(subscribers.len())
+        } else {
+            Err(dispatch_errors.join("; "))
+        }
+    }
+
     /// Get active session count
     pub async fn session_count(&self) -> usize {
         // Get count from storage backend for authoritative answer
@@ -1752,6 +2135,9 @@ impl SessionManager {
             send_notification,
             broadcaster: None, // Old SessionManager doesn't have broadcaster
             extensions: HashMap::new(),
+            request_meta: None,
+            response_meta: Arc::default(),
+            cancellation_token: CancellationToken::new(),
         })
     }
 
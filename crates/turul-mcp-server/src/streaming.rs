@@ -0,0 +1,238 @@
+//! Streaming tools emitting incremental output
+//!
+//! A streaming tool pushes content chunks into a [`ChunkSink`] as it produces them
+//! (log tailing, token-by-token LLM output) instead of building one final result.
+//! A client opts in by sending `_meta.streamToken` with `tools/call`; each chunk is
+//! then sent on the session's stream — the POST SSE response when the client
+//! accepts `text/event-stream` — as
+//!
+//! ```text
+//! notifications/tools/chunk     { "streamToken", "sequence", "content": [ContentBlock] }
+//! notifications/tools/complete  { "streamToken", "chunks", "isError" }
+//! ```
+//!
+//! followed by the `tools/call` response. That response always carries every
+//! chunk's content in order, so clients that send no `streamToken` (or read plain
+//! JSON) get the same aggregated `CallToolResult` a regular tool would return.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use tracing::debug;
+use turul_mcp_builders::prelude::*;
+use turul_mcp_protocol::tools::{ToolAnnotations, ToolExecution, ToolSchema};
+use turul_mcp_protocol::{CallToolResult, ContentBlock, Icon, McpResult};
+
+use crate::session::{SessionContext, SessionEvent};
+use crate::tool::McpTool;
+
+/// `_meta` key on `tools/call` carrying the client-chosen stream token
+pub const STREAM_TOKEN_META_KEY: &str = "streamToken";
+
+/// Notification carrying one chunk of a streaming tool's output
+pub const TOOL_CHUNK_METHOD: &str = "notifications/tools/chunk";
+
+/// Notification sent once a streaming tool has finished, before its result
+pub const TOOL_COMPLETE_METHOD: &str = "notifications/tools/complete";
+
+/// High-level trait for tools that emit their output incrementally
+///
+/// Metadata comes from [`ToolDefinition`] exactly as for [`McpTool`]; register with
+/// [`McpServerBuilder::streaming_tool`](crate::McpServerBuilder::streaming_tool).
+#[async_trait]
+pub trait McpStreamingTool: ToolDefinition {
+    /// Run the tool, pushing output chunks into `sink`
+    ///
+    /// Everything sent to `sink` becomes the content of the final `CallToolResult`.
+    async fn run(
+        &self,
+        args: Value,
+        sink: ChunkSink,
+        session: Option<SessionContext>,
+    ) -> McpResult<()>;
+}
+
+/// Tool-side handle for emitting output chunks
+///
+/// Cheap to clone, so chunks can be pushed from spawned tasks.
+#[derive(Clone)]
+pub struct ChunkSink {
+    session: Option<SessionContext>,
+    token: Option<String>,
+    content: Arc<Mutex<Vec<ContentBlock>>>,
+}
+
+impl ChunkSink {
+    fn new(session: Option<SessionContext>) -> Self {
+        let token = session
+            .as_ref()
+            .and_then(|session| session.request_meta())
+            .and_then(|meta| meta.extra.get(STREAM_TOKEN_META_KEY))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        Self {
+            session,
+            token,
+            content: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// The client's stream token; `None` when chunks are only aggregated
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Emit a content chunk
+    pub async fn send(&self, chunk: ContentBlock) {
+        let sequence = {
+            let mut content = self.content.lock().unwrap();
+            content.push(chunk.clone());
+            content.len() - 1
+        };
+        self.notify(
+            TOOL_CHUNK_METHOD,
+            json!({ "sequence": sequence, "content": [chunk] }),
+        )
+        .await;
+    }
+
+    /// Emit a text chunk
+    pub async fn send_text(&self, text: impl Into<String>) {
+        self.send(ContentBlock::text(text)).await;
+    }
+
+    async fn complete(&self, is_error: bool) -> Vec<ContentBlock> {
+        let content = std::mem::take(&mut *self.content.lock().unwrap());
+        self.notify(
+            TOOL_COMPLETE_METHOD,
+            json!({ "chunks": content.len(), "isError": is_error }),
+        )
+        .await;
+        content
+    }
+
+    async fn notify(&self, method: &str, fields: Value) {
+        let (Some(session), Some(token)) = (&self.session, &self.token) else {
+            return;
+        };
+
+        let mut other: HashMap<String, Value> = serde_json::from_value(fields).unwrap();
+        other.insert(STREAM_TOKEN_META_KEY.to_string(), json!(token));
+        let params = turul_mcp_protocol::RequestParams { meta: None, other };
+        let notification =
+            turul_mcp_protocol::JsonRpcNotification::new(method.to_string()).with_params(params);
+        debug!(
+            "Streaming {} for token {} to session {}",
+            method, token, session.session_id
+        );
+        session
+            .notify(SessionEvent::Notification(
+                serde_json::to_value(notification).unwrap(),
+            ))
+            .await;
+    }
+}
+
+/// Adapts an [`McpStreamingTool`] to [`McpTool`] so it is listed and called like any tool
+pub(crate) struct StreamingToolAdapter<T> {
+    inner: T,
+}
+
+impl<T> StreamingToolAdapter<T> {
+    pub(crate) fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: McpStreamingTool> HasBaseMetadata for StreamingToolAdapter<T> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+    fn title(&self) -> Option<&str> {
+        self.inner.title()
+    }
+}
+
+impl<T: McpStreamingTool> HasDescription for StreamingToolAdapter<T> {
+    fn description(&self) -> Option<&str> {
+        self.inner.description()
+    }
+}
+
+impl<T: McpStreamingTool> HasInputSchema for StreamingToolAdapter<T> {
+    fn input_schema(&self) -> &ToolSchema {
+        self.inner.input_schema()
+    }
+}
+
+impl<T: McpStreamingTool> HasOutputSchema for StreamingToolAdapter<T> {
+    fn output_schema(&self) -> Option<&ToolSchema> {
+        self.inner.output_schema()
+    }
+}
+
+impl<T: McpStreamingTool> HasAnnotations for StreamingToolAdapter<T> {
+    fn annotations(&self) -> Option<&ToolAnnotations> {
+        self.inner.annotations()
+    }
+}
+
+impl<T: McpStreamingTool> HasToolMeta for StreamingToolAdapter<T> {
+    fn tool_meta(&self) -> Option<&HashMap<String, Value>> {
+        self.inner.tool_meta()
+    }
+}
+
+impl<T: McpStreamingTool> HasIcons for StreamingToolAdapter<T> {
+    fn icons(&self) -> Option<&Vec<Icon>> {
+        self.inner.icons()
+    }
+}
+
+impl<T: McpStreamingTool> HasExecution for StreamingToolAdapter<T> {
+    fn execution(&self) -> Option<ToolExecution> {
+        self.inner.execution()
+    }
+}
+
+#[async_trait]
+impl<T: McpStreamingTool> McpTool for StreamingToolAdapter<T> {
+    async fn call(
+        &self,
+        args: Value,
+        session: Option<SessionContext>,
+    ) -> McpResult<CallToolResult> {
+        let sink = ChunkSink::new(session.clone());
+        let outcome = self.inner.run(args, sink.clone(), session).await;
+        let content = sink.complete(outcome.is_err()).await;
+        outcome.map(|()| CallToolResult::success(content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_chunks_are_aggregated_in_order() {
+        let sink = ChunkSink::new(None);
+        assert!(sink.token().is_none());
+
+        let producer = sink.clone();
+        for line in ["one", "two", "three"] {
+            producer.send_text(line).await;
+        }
+
+        let content = sink.complete(false).await;
+        assert_eq!(
+            serde_json::to_value(&content).unwrap(),
+            json!([
+                { "type": "text", "text": "one" },
+                { "type": "text", "text": "two" },
+                { "type": "text", "text": "three" }
+            ])
+        );
+    }
+}
@@ -129,9 +129,12 @@ impl McpHandler for TasksListHandler {
         debug!(
             cursor = ?cursor_ref,
             limit = ?list_params.limit,
+            status = ?list_params.status,
             "tasks/list request"
         );
 
+        let status_filter = list_params.status.as_deref();
+
         // If we have a session, scope to that session's tasks
         let page = if let Some(session_ctx) = &session {
             self.runtime
@@ -139,10 +142,13 @@ impl McpHandler for TasksListHandler {
                     &session_ctx.session_id.to_string(),
                     cursor_ref,
                     list_params.limit,
+                    status_filter,
                 )
                 .await
         } else {
-            self.runtime.list_tasks(cursor_ref, list_params.limit).await
+            self.runtime
+                .list_tasks(cursor_ref, list_params.limit, status_filter)
+                .await
         }
         .map_err(|e| McpError::ToolExecutionError(e.to_string()))?;
 
@@ -208,11 +214,12 @@ impl McpHandler for TasksCancelHandler {
                     ))
                 }
                 turul_mcp_task_storage::TaskStorageError::InvalidTransition {
-                    current,
-                    requested,
+                    from,
+                    attempted,
+                    ..
                 } => McpError::InvalidParameters(format!(
                     "Cannot cancel task: invalid transition {:?} -> {:?}",
-                    current, requested
+                    from, attempted
                 )),
                 other => McpError::ToolExecutionError(other.to_string()),
             })?;
@@ -449,6 +456,39 @@ mod tests {
         assert_eq!(tasks.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_tasks_list_handler_status_filter() {
+        let runtime = create_test_runtime();
+        let handler = TasksListHandler::new(Arc::clone(&runtime));
+
+        let working = create_test_task(&runtime).await;
+        let done = create_test_task(&runtime).await;
+        runtime
+            .complete_task(
+                &done.task_id,
+                TaskOutcome::Success(serde_json::json!({"result": "done"})),
+                TaskStatus::Completed,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let params = serde_json::json!({ "status": ["completed"] });
+        let result = handler.handle(Some(params)).await.unwrap();
+        let tasks = result["tasks"].as_array().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["taskId"], done.task_id);
+
+        let params = serde_json::json!({ "status": ["working", "input_required"] });
+        let result = handler.handle(Some(params)).await.unwrap();
+        let tasks = result["tasks"].as_array().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["taskId"], working.task_id);
+
+        let params = serde_json::json!({ "status": ["unknown"] });
+        assert!(handler.handle(Some(params)).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_tasks_cancel_handler() {
         let runtime = create_test_runtime();
@@ -160,24 +160,26 @@ impl TaskRuntime {
         self.storage.get_task_result(task_id).await
     }
 
-    /// List tasks with pagination.
+    /// List tasks with pagination, optionally restricted to the given statuses.
     pub async fn list_tasks(
         &self,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<turul_mcp_task_storage::TaskListPage, TaskStorageError> {
-        self.storage.list_tasks(cursor, limit).await
+        self.storage.list_tasks(cursor, limit, status_filter).await
     }
 
-    /// List tasks for a specific session.
+    /// List tasks for a specific session, optionally restricted to the given statuses.
     pub async fn list_tasks_for_session(
         &self,
         session_id: &str,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<turul_mcp_task_storage::TaskListPage, TaskStorageError> {
         self.storage
-            .list_tasks_for_session(session_id, cursor, limit)
+            .list_tasks_for_session(session_id, cursor, limit, status_filter)
             .await
     }
 
@@ -301,7 +303,7 @@ mod tests {
         runtime.register_task(task1).await.unwrap();
         runtime.register_task(task2).await.unwrap();
 
-        let page = runtime.list_tasks(None, None).await.unwrap();
+        let page = runtime.list_tasks(None, None, None).await.unwrap();
         assert_eq!(page.tasks.len(), 2);
     }
 }
@@ -142,7 +142,7 @@ async fn test_pagination_cursor_consistency() {
 
 #[tokio::test]
 async fn test_pagination_with_invalid_cursor() {
-    // Test that invalid cursors are handled gracefully
+    // Test that cursors the server did not issue are rejected, not treated as page 1
     let mut handler = ResourcesListHandler::new();
 
     for i in 1..=5 {
@@ -150,15 +150,14 @@ async fn test_pagination_with_invalid_cursor() {
         handler = handler.add_resource(resource);
     }
 
-    // Test with invalid cursor (should start from beginning)
     let invalid_params = json!({ "cursor": "invalid_cursor_value" });
-    let response = handler.handle(Some(invalid_params)).await.unwrap();
-    let data: PaginatedResponse<ListResourcesResult> = serde_json::from_value(response).unwrap();
+    let error = handler.handle(Some(invalid_params)).await.unwrap_err();
 
-    // Should return all resources (graceful fallback)
-    assert_eq!(data.data.resources.len(), 5);
-    assert!(data.meta.is_some());
-    assert_eq!(data.meta.as_ref().unwrap().has_more, Some(false));
+    // Surfaces as JSON-RPC -32602 Invalid params
+    assert!(matches!(
+        error,
+        turul_mcp_protocol::McpError::InvalidParameters(_)
+    ));
 }
 
 #[tokio::test]
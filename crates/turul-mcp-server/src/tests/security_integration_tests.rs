@@ -172,6 +172,9 @@ fn create_test_session() -> SessionContext {
         send_notification: Arc::new(|_| Box::pin(futures::future::ready(()))),
         broadcaster: None,
         extensions: std::collections::HashMap::new(),
+        request_meta: None,
+        response_meta: std::sync::Arc::default(),
+        cancellation_token: tokio_util::sync::CancellationToken::new(),
     }
 }
 
@@ -330,6 +333,7 @@ async fn test_rate_limiting_integration() {
         max_requests: 4,
         window_duration: Duration::from_secs(60),
         burst_size: 0,
+        ..Default::default()
     };
 
     let access_control = ResourceAccessControl {
@@ -269,7 +269,7 @@ mod logging_handler_tests {
 
         let context = manager.create_session_context(&session_id);
 
-        let handler = LoggingHandler;
+        let handler = LoggingHandler::new();
 
         // Test SetLevelRequest params
         let params = json!({
@@ -290,7 +290,7 @@ mod logging_handler_tests {
 
     #[tokio::test]
     async fn test_logging_handler_without_session_context() {
-        let handler = LoggingHandler;
+        let handler = LoggingHandler::new();
 
         let params = json!({
             "level": "warning"
@@ -311,7 +311,7 @@ mod logging_handler_tests {
         let session_id = manager.create_session().await;
         let context = manager.create_session_context(&session_id);
 
-        let handler = LoggingHandler;
+        let handler = LoggingHandler::new();
 
         // Test with missing params
         let result = handler.handle_with_session(None, context).await;
@@ -340,7 +340,7 @@ mod logging_handler_tests {
             .await
             .unwrap();
 
-        let handler = LoggingHandler;
+        let handler = LoggingHandler::new();
 
         let test_levels = vec![
             ("debug", LoggingLevel::Debug),
@@ -3,7 +3,7 @@
 //! This module defines the high-level trait for implementing MCP tools.
 
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
 use serde_json::Value;
@@ -19,6 +19,14 @@ use crate::session::SessionContext;
 /// consistency between concrete Tool structs and dynamic implementations.
 #[async_trait]
 pub trait McpTool: ToolDefinition {
+    /// Whether this tool is visible to the given session
+    ///
+    /// Hidden tools are omitted from `tools/list` and calls to them fail as if
+    /// the tool did not exist. Defaults to visible for every session.
+    fn is_visible(&self, _session: Option<&SessionContext>) -> bool {
+        true
+    }
+
     /// Execute the tool with full session support
     ///
     /// This is the primary execution method that tools should implement.
@@ -27,6 +35,203 @@ pub trait McpTool: ToolDefinition {
     -> McpResult<CallToolResult>;
 }
 
+/// Resolves tools that are not in the static registry
+///
+/// Register one with [`McpServerBuilder::with_tool_router`](crate::McpServerBuilder::with_tool_router)
+/// to serve tools discovered at runtime, such as a plugin's `github.*` namespace.
+/// `tools/call` consults the router only for names no registered tool claims, and
+/// `tools/list` merges [`list`](Self::list) with the registered tools.
+#[async_trait]
+pub trait ToolRouter: Send + Sync {
+    /// Materialize the tool called `name`, or `None` if the router does not serve it
+    async fn resolve(&self, name: &str) -> Option<Arc<dyn McpTool>>;
+
+    /// Descriptors of the tools this router currently advertises
+    async fn list(&self) -> Vec<turul_mcp_protocol::Tool>;
+}
+
+/// Tools registered on a running server, in front of the builder's router
+///
+/// Backs [`McpServer::register_tool`](crate::McpServer::register_tool). Calls
+/// and listings take a snapshot under a read lock, so they see the set either
+/// before or after a registration, never in between.
+pub(crate) struct RuntimeTools {
+    tools: RwLock<HashMap<String, Arc<dyn McpTool>>>,
+    fallback: Option<Arc<dyn ToolRouter>>,
+}
+
+impl RuntimeTools {
+    pub(crate) fn new(fallback: Option<Arc<dyn ToolRouter>>) -> Self {
+        Self {
+            tools: RwLock::new(HashMap::new()),
+            fallback,
+        }
+    }
+
+    /// Add or replace `tool`, returning the tool it replaced
+    pub(crate) fn insert(&self, tool: Arc<dyn McpTool>) -> Option<Arc<dyn McpTool>> {
+        let name = tool.name().to_string();
+        self.tools.write().unwrap().insert(name, tool)
+    }
+
+    /// Remove the tool called `name`, returning it if it was registered
+    pub(crate) fn remove(&self, name: &str) -> Option<Arc<dyn McpTool>> {
+        self.tools.write().unwrap().remove(name)
+    }
+}
+
+#[async_trait]
+impl ToolRouter for RuntimeTools {
+    async fn resolve(&self, name: &str) -> Option<Arc<dyn McpTool>> {
+        let registered = self.tools.read().unwrap().get(name).cloned();
+        match (registered, &self.fallback) {
+            (Some(tool), _) => Some(tool),
+            (None, Some(router)) => router.resolve(name).await,
+            (None, None) => None,
+        }
+    }
+
+    async fn list(&self) -> Vec<turul_mcp_protocol::Tool> {
+        let mut tools: Vec<turul_mcp_protocol::Tool> = self
+            .tools
+            .read()
+            .unwrap()
+            .values()
+            .map(|tool| tool.to_tool())
+            .collect();
+        if let Some(ref router) = self.fallback {
+            tools.extend(router.list().await);
+        }
+        tools
+    }
+}
+
+/// A tool whose implementation is constructed on first call
+///
+/// The descriptor is advertised by `tools/list` and used for the tool fingerprint,
+/// so listing never runs the factory. The first `tools/call` builds the tool and
+/// the instance is reused for every later call. Use it to keep expensive tools
+/// (SDK clients, large schemas, model handles) off the cold-start path.
+///
+/// The descriptor should match what the built tool reports; only the descriptor
+/// is ever advertised.
+///
+/// ```rust
+/// use turul_mcp_server::{LazyTool, McpTool};
+/// use turul_mcp_builders::ToolBuilder;
+/// use turul_mcp_protocol::{Tool, ToolSchema};
+///
+/// let descriptor = Tool::new("report", ToolSchema::object()).with_description("Build a report");
+/// let tool = LazyTool::new(descriptor, || {
+///     ToolBuilder::new("report")
+///         .description("Build a report")
+///         .execute(|_args| async move { Ok(serde_json::json!("done")) })
+///         .build()
+///         .unwrap()
+/// });
+/// assert!(!tool.is_initialized());
+/// ```
+pub struct LazyTool {
+    descriptor: turul_mcp_protocol::Tool,
+    factory: Box<dyn Fn() -> Arc<dyn McpTool> + Send + Sync>,
+    tool: std::sync::OnceLock<Arc<dyn McpTool>>,
+}
+
+impl LazyTool {
+    /// Advertise `descriptor` and build the tool with `factory` on first call
+    pub fn new<T, F>(descriptor: turul_mcp_protocol::Tool, factory: F) -> Self
+    where
+        T: McpTool + 'static,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self {
+            descriptor,
+            factory: Box::new(move || Arc::new(factory()) as Arc<dyn McpTool>),
+            tool: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Whether the factory has run
+    pub fn is_initialized(&self) -> bool {
+        self.tool.get().is_some()
+    }
+
+    fn get(&self) -> &Arc<dyn McpTool> {
+        self.tool.get_or_init(|| (self.factory)())
+    }
+}
+
+impl std::fmt::Debug for LazyTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyTool")
+            .field("name", &self.descriptor.name)
+            .field("initialized", &self.is_initialized())
+            .finish()
+    }
+}
+
+impl HasBaseMetadata for LazyTool {
+    fn name(&self) -> &str {
+        self.descriptor.name()
+    }
+    fn title(&self) -> Option<&str> {
+        self.descriptor.title()
+    }
+}
+
+impl HasDescription for LazyTool {
+    fn description(&self) -> Option<&str> {
+        self.descriptor.description()
+    }
+}
+
+impl HasInputSchema for LazyTool {
+    fn input_schema(&self) -> &turul_mcp_protocol::ToolSchema {
+        self.descriptor.input_schema()
+    }
+}
+
+impl HasOutputSchema for LazyTool {
+    fn output_schema(&self) -> Option<&turul_mcp_protocol::ToolSchema> {
+        self.descriptor.output_schema()
+    }
+}
+
+impl HasAnnotations for LazyTool {
+    fn annotations(&self) -> Option<&turul_mcp_protocol::tools::ToolAnnotations> {
+        self.descriptor.annotations()
+    }
+}
+
+impl HasToolMeta for LazyTool {
+    fn tool_meta(&self) -> Option<&HashMap<String, Value>> {
+        self.descriptor.tool_meta()
+    }
+}
+
+impl HasIcons for LazyTool {
+    fn icons(&self) -> Option<&Vec<turul_mcp_protocol::icons::Icon>> {
+        self.descriptor.icons()
+    }
+}
+
+impl HasExecution for LazyTool {
+    fn execution(&self) -> Option<turul_mcp_protocol::tools::ToolExecution> {
+        self.descriptor.execution()
+    }
+}
+
+#[async_trait]
+impl McpTool for LazyTool {
+    async fn call(
+        &self,
+        args: Value,
+        session: Option<SessionContext>,
+    ) -> McpResult<CallToolResult> {
+        self.get().call(args, session).await
+    }
+}
+
 /// Converts an McpTool trait object to a protocol Tool descriptor
 ///
 /// This is now a thin wrapper around the ToolDefinition::to_tool() method
@@ -83,6 +288,26 @@ pub fn compute_tool_fingerprint(tools: &HashMap<String, Arc<dyn McpTool>>) -> St
     format!("{:016x}", hash)
 }
 
+/// Run a tool's future under a deadline
+///
+/// When `timeout_ms` elapses the future is dropped, releasing whatever it
+/// holds, and `McpError::ToolExecutionError` is returned. Used by
+/// `#[mcp_tool(timeout_ms = ...)]` and `#[tool(timeout_ms = ...)]`.
+pub async fn run_with_deadline<F: std::future::Future>(
+    tool_name: &str,
+    timeout_ms: u64,
+    future: F,
+) -> McpResult<F::Output> {
+    tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), future)
+        .await
+        .map_err(|_| {
+            turul_mcp_protocol::McpError::tool_execution(&format!(
+                "Tool '{}' timed out after {}ms",
+                tool_name, timeout_ms
+            ))
+        })
+}
+
 /// Recursively sort all object keys in a JSON value for deterministic serialization.
 /// HashMap iteration order is non-deterministic in Rust; this ensures identical
 /// logical structures always produce identical JSON strings.
@@ -750,4 +975,53 @@ mod tests {
             "Annotation changes must affect fingerprint"
         );
     }
+
+    #[tokio::test]
+    async fn test_run_with_deadline() {
+        let fast = run_with_deadline("fast", 1_000, async { 42 })
+            .await
+            .unwrap();
+        assert_eq!(fast, 42);
+
+        let err = run_with_deadline("slow", 10, std::future::pending::<()>())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            turul_mcp_protocol::McpError::ToolExecutionError(_)
+        ));
+        assert!(err.to_string().contains("Tool 'slow' timed out after 10ms"));
+    }
+
+    #[tokio::test]
+    async fn test_lazy_tool_factory_runs_on_first_call_only() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let builds = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&builds);
+        let descriptor = turul_mcp_protocol::Tool::new("test", TestTool::new().input_schema)
+            .with_description("A test tool");
+        let lazy = LazyTool::new(descriptor, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            TestTool::new()
+        });
+
+        // Listing and fingerprinting use the descriptor only
+        assert_eq!(lazy.to_tool().name, "test");
+        assert_eq!(lazy.description(), Some("A test tool"));
+        let tool: Arc<dyn McpTool> = Arc::new(lazy);
+        compute_tool_fingerprint(&tool_map(vec![Arc::clone(&tool)]));
+        assert_eq!(builds.load(Ordering::SeqCst), 0);
+
+        for message in ["one", "two"] {
+            let result = tool
+                .call(serde_json::json!({ "message": message }), None)
+                .await
+                .unwrap();
+            assert!(
+                matches!(&result.content[0], ToolResult::Text { text, .. } if text == &format!("Test: {}", message))
+            );
+        }
+        assert_eq!(builds.load(Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,510 @@
+//! Hot-Reloadable Tool Configuration
+//!
+//! Watches a JSON file naming the compiled tools that should be active, plus
+//! any tools the file defines itself, and applies edits to the
+//! [`ToolRegistry`] without a restart:
+//!
+//! ```json
+//! {
+//!   "tools": ["add", "multiply"],
+//!   "definitions": [
+//!     {
+//!       "name": "sum",
+//!       "description": "Add two numbers",
+//!       "inputSchema": { "type": "object", "properties": { "a": { "type": "number" }, "b": { "type": "number" } } },
+//!       "handler": "add"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Tool implementations are compiled into the binary. A definition advertises
+//! its own name, title, description and input schema, and its calls run the
+//! compiled `handler` tool, so editing a definition changes what clients see
+//! without a rebuild. Each edit is diffed against the live registry and applied
+//! with [`ToolRegistry::apply_tool_config`] — one atomic swap and a single
+//! `notifications/tools/list_changed`. A file that fails to parse, names a tool
+//! that was never compiled, or defines a tool under a compiled tool's name is
+//! logged and the previous configuration stays in effect.
+//!
+//! This module is gated behind the `dynamic-tools` feature flag.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value;
+use tracing::{debug, info, warn};
+use turul_mcp_builders::prelude::*;
+use turul_mcp_protocol::{CallToolResult, McpResult};
+
+use crate::McpTool;
+use crate::session::SessionContext;
+use crate::tool_registry::{ToolRegistry, ToolRegistryError, ToolSetChange};
+
+/// How often a watched tool config file is re-read by default
+pub const DEFAULT_CONFIG_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Parsed contents of a tool config file
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolConfig {
+    /// Names of the compiled tools that should be active
+    pub tools: Vec<String>,
+    /// Tools defined by the file; each is active while it is defined
+    #[serde(default)]
+    pub definitions: Vec<ToolDefinition>,
+}
+
+/// A tool defined in a config file and served by a compiled handler
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ToolDefinition {
+    /// Name the tool is advertised under; must not be a compiled tool's name
+    pub name: String,
+    /// Optional display title
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Optional human-readable description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// JSON Schema advertised for the tool's arguments
+    pub input_schema: Value,
+    /// Compiled tool that executes calls to this tool
+    pub handler: String,
+}
+
+impl ToolConfig {
+    /// Parse a tool config from JSON
+    pub fn parse(contents: &str) -> Result<Self, ToolRegistryError> {
+        serde_json::from_str(contents).map_err(|e| ToolRegistryError::InvalidConfig(e.to_string()))
+    }
+
+    /// The configured tools as a set
+    pub fn tool_set(&self) -> HashSet<String> {
+        self.tools.iter().cloned().collect()
+    }
+}
+
+/// A config-defined tool: the file's descriptor in front of a compiled handler
+///
+/// Calls, visibility and the output schema come from the handler; everything
+/// else is advertised as written in the [`ToolDefinition`].
+pub struct ConfiguredTool {
+    definition: ToolDefinition,
+    descriptor: turul_mcp_protocol::Tool,
+    handler: Arc<dyn McpTool>,
+}
+
+impl ConfiguredTool {
+    /// Build the tool for `definition`, executed by `handler`
+    pub fn new(
+        definition: ToolDefinition,
+        handler: Arc<dyn McpTool>,
+    ) -> Result<Self, ToolRegistryError> {
+        let input_schema =
+            serde_json::from_value(definition.input_schema.clone()).map_err(|e| {
+                ToolRegistryError::InvalidConfig(format!(
+                    "tool '{}' has an invalid inputSchema: {}",
+                    definition.name, e
+                ))
+            })?;
+        let mut descriptor = turul_mcp_protocol::Tool::new(&definition.name, input_schema);
+        descriptor.title = definition.title.clone();
+        descriptor.description = definition.description.clone();
+        descriptor.output_schema = handler.output_schema().cloned();
+        descriptor.execution = handler.execution();
+        Ok(Self {
+            definition,
+            descriptor,
+            handler,
+        })
+    }
+
+    /// The definition this tool was built from
+    pub fn definition(&self) -> &ToolDefinition {
+        &self.definition
+    }
+}
+
+impl std::fmt::Debug for ConfiguredTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfiguredTool")
+            .field("name", &self.definition.name)
+            .field("handler", &self.definition.handler)
+            .finish()
+    }
+}
+
+impl HasBaseMetadata for ConfiguredTool {
+    fn name(&self) -> &str {
+        self.descriptor.name()
+    }
+    fn title(&self) -> Option<&str> {
+        self.descriptor.title()
+    }
+}
+
+impl HasDescription for ConfiguredTool {
+    fn description(&self) -> Option<&str> {
+        self.descriptor.description()
+    }
+}
+
+impl HasInputSchema for ConfiguredTool {
+    fn input_schema(&self) -> &turul_mcp_protocol::ToolSchema {
+        self.descriptor.input_schema()
+    }
+}
+
+impl HasOutputSchema for ConfiguredTool {
+    fn output_schema(&self) -> Option<&turul_mcp_protocol::ToolSchema> {
+        self.descriptor.output_schema()
+    }
+}
+
+impl HasAnnotations for ConfiguredTool {
+    fn annotations(&self) -> Option<&turul_mcp_protocol::tools::ToolAnnotations> {
+        None
+    }
+}
+
+impl HasToolMeta for ConfiguredTool {
+    fn tool_meta(&self) -> Option<&std::collections::HashMap<String, Value>> {
+        None
+    }
+}
+
+impl HasIcons for ConfiguredTool {}
+
+impl HasExecution for ConfiguredTool {
+    fn execution(&self) -> Option<turul_mcp_protocol::tools::ToolExecution> {
+        self.descriptor.execution()
+    }
+}
+
+#[async_trait]
+impl McpTool for ConfiguredTool {
+    fn is_visible(&self, session: Option<&SessionContext>) -> bool {
+        self.handler.is_visible(session)
+    }
+
+    async fn call(
+        &self,
+        args: Value,
+        session: Option<SessionContext>,
+    ) -> McpResult<CallToolResult> {
+        self.handler.call(args, session).await
+    }
+}
+
+/// Re-reads a tool config file and applies changes to a [`ToolRegistry`]
+pub struct ToolConfigWatcher {
+    path: PathBuf,
+    registry: Arc<ToolRegistry>,
+    interval: Duration,
+}
+
+impl ToolConfigWatcher {
+    /// Create a watcher for `path` that checks every [`DEFAULT_CONFIG_WATCH_INTERVAL`]
+    pub fn new(path: impl Into<PathBuf>, registry: Arc<ToolRegistry>) -> Self {
+        Self {
+            path: path.into(),
+            registry,
+            interval: DEFAULT_CONFIG_WATCH_INTERVAL,
+        }
+    }
+
+    /// Set how often the file is re-read
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Read the file and apply it to the registry.
+    ///
+    /// On any error the registry is left untouched.
+    pub async fn reload(&self) -> Result<ToolSetChange, ToolRegistryError> {
+        let contents = self.read().await?;
+        self.apply(&contents).await
+    }
+
+    /// Apply the file now, then keep watching it in the background.
+    ///
+    /// The file is re-read every interval and re-applied whenever its contents
+    /// change. Returns a `JoinHandle` that can be used to stop watching.
+    pub async fn start(self) -> tokio::task::JoinHandle<()> {
+        let mut last_seen = self.read().await.ok();
+        match &last_seen {
+            Some(contents) => self.apply_logged(contents).await,
+            None => warn!(
+                "Tool config {} is not readable; keeping compiled tool set",
+                self.path.display()
+            ),
+        }
+
+        tokio::spawn(async move {
+            // Use sleep instead of interval to avoid the immediate first tick
+            loop {
+                tokio::time::sleep(self.interval).await;
+
+                let current = match self.read().await {
+                    Ok(contents) => contents,
+                    Err(e) => {
+                        debug!("{}", e);
+                        continue;
+                    }
+                };
+                if last_seen.as_deref() == Some(current.as_str()) {
+                    continue;
+                }
+                self.apply_logged(&current).await;
+                last_seen = Some(current);
+            }
+        })
+    }
+
+    async fn read(&self) -> Result<String, ToolRegistryError> {
+        tokio::fs::read_to_string(&self.path).await.map_err(|e| {
+            ToolRegistryError::InvalidConfig(format!("cannot read {}: {}", self.path.display(), e))
+        })
+    }
+
+    async fn apply(&self, contents: &str) -> Result<ToolSetChange, ToolRegistryError> {
+        let config = ToolConfig::parse(contents)?;
+        self.registry
+            .apply_tool_config(&config.tool_set(), &config.definitions)
+            .await
+    }
+
+    async fn apply_logged(&self, contents: &str) {
+        match self.apply(contents).await {
+            Ok(change) if change.is_empty() => {
+                debug!("Tool config {} unchanged", self.path.display());
+            }
+            Ok(change) => info!(
+                "Applied tool config {} (activated: {:?}, deactivated: {:?}, defined: {:?}, updated: {:?}, removed: {:?})",
+                self.path.display(),
+                change.activated,
+                change.deactivated,
+                change.defined,
+                change.updated,
+                change.removed
+            ),
+            Err(e) => warn!(
+                "Ignoring tool config {}: {}. Previous configuration kept.",
+                self.path.display(),
+                e
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpTool;
+    use crate::session::{SessionEvent, SessionManager};
+    use std::collections::HashMap;
+    use turul_mcp_builders::ToolBuilder;
+
+    fn test_tool(name: &str) -> Arc<dyn McpTool> {
+        Arc::new(
+            ToolBuilder::new(name)
+                .description("test tool")
+                .execute(|_| async { Ok(serde_json::json!({})) })
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn test_registry(session_manager: Arc<SessionManager>) -> Arc<ToolRegistry> {
+        let tools: HashMap<String, Arc<dyn McpTool>> = ["alpha", "beta", "gamma"]
+            .into_iter()
+            .map(|name| (name.to_string(), test_tool(name)))
+            .collect();
+        Arc::new(ToolRegistry::new(
+            tools,
+            session_manager,
+            Arc::new(turul_mcp_server_state_storage::InMemoryServerStateStorage::new()),
+        ))
+    }
+
+    fn test_session_manager() -> Arc<SessionManager> {
+        Arc::new(SessionManager::new(
+            turul_mcp_protocol::ServerCapabilities::default(),
+        ))
+    }
+
+    async fn active_names(registry: &ToolRegistry) -> Vec<String> {
+        registry
+            .list_active_tools()
+            .await
+            .into_iter()
+            .map(|tool| tool.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_config() {
+        assert_eq!(
+            ToolConfig::parse(r#"{"tools": ["alpha"]}"#).unwrap().tools,
+            ["alpha"]
+        );
+        assert!(matches!(
+            ToolConfig::parse(r#"{"tools": "alpha"}"#),
+            Err(ToolRegistryError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            ToolConfig::parse(r#"{"tools": [], "extra": 1}"#),
+            Err(ToolRegistryError::InvalidConfig(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_reload_keeps_previous_config_on_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tools.json");
+        let registry = test_registry(test_session_manager());
+        let watcher = ToolConfigWatcher::new(&path, registry.clone());
+
+        std::fs::write(&path, r#"{"tools": ["alpha", "beta"]}"#).unwrap();
+        let change = watcher.reload().await.unwrap();
+        assert_eq!(change.deactivated, ["gamma"]);
+
+        std::fs::write(&path, r#"{"tools": ["alpha""#).unwrap();
+        assert!(watcher.reload().await.is_err());
+        std::fs::write(&path, r#"{"tools": ["alpha", "missing"]}"#).unwrap();
+        assert!(matches!(
+            watcher.reload().await,
+            Err(ToolRegistryError::NotCompiled(name)) if name == "missing"
+        ));
+
+        assert_eq!(active_names(&registry).await, ["alpha", "beta"]);
+    }
+
+    fn sum_config(description: &str) -> String {
+        serde_json::json!({
+            "tools": ["alpha"],
+            "definitions": [{
+                "name": "sum",
+                "description": description,
+                "inputSchema": {"type": "object", "properties": {"a": {"type": "number"}}},
+                "handler": "beta"
+            }]
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_reload_defines_updates_and_removes_config_tools() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tools.json");
+        let registry = test_registry(test_session_manager());
+        let watcher = ToolConfigWatcher::new(&path, registry.clone());
+
+        std::fs::write(&path, sum_config("Add numbers")).unwrap();
+        let change = watcher.reload().await.unwrap();
+        assert_eq!(change.defined, ["sum"]);
+        assert_eq!(active_names(&registry).await, ["alpha", "sum"]);
+        let sum = registry
+            .get_tool("sum")
+            .await
+            .expect("sum should be callable");
+        assert_eq!(sum.description(), Some("Add numbers"));
+        assert!(
+            sum.input_schema()
+                .properties
+                .as_ref()
+                .unwrap()
+                .contains_key("a")
+        );
+        assert!(sum.call(serde_json::json!({"a": 1}), None).await.is_ok());
+
+        let fingerprint = registry.fingerprint().await;
+        std::fs::write(&path, sum_config("Add two numbers")).unwrap();
+        let change = watcher.reload().await.unwrap();
+        assert_eq!(change.updated, ["sum"]);
+        assert!(change.defined.is_empty());
+        assert_ne!(registry.fingerprint().await, fingerprint);
+        let sum = registry.get_tool("sum").await.unwrap();
+        assert_eq!(sum.description(), Some("Add two numbers"));
+
+        std::fs::write(&path, r#"{"tools": ["alpha"]}"#).unwrap();
+        let change = watcher.reload().await.unwrap();
+        assert_eq!(change.removed, ["sum"]);
+        assert!(registry.get_tool("sum").await.is_none());
+        assert_eq!(active_names(&registry).await, ["alpha"]);
+    }
+
+    #[tokio::test]
+    async fn test_reload_rejects_invalid_definitions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tools.json");
+        let registry = test_registry(test_session_manager());
+        let watcher = ToolConfigWatcher::new(&path, registry.clone());
+        std::fs::write(&path, sum_config("Add numbers")).unwrap();
+        watcher.reload().await.unwrap();
+
+        let invalid = [
+            (
+                serde_json::json!({"name": "gamma", "inputSchema": {"type": "object"}, "handler": "beta"}),
+                "redefines a compiled tool",
+            ),
+            (
+                serde_json::json!({"name": "sum", "inputSchema": {"type": "object"}, "handler": "missing"}),
+                "names an unknown handler",
+            ),
+            (
+                serde_json::json!({"name": "sum", "inputSchema": "object", "handler": "beta"}),
+                "has an invalid schema",
+            ),
+        ];
+        for (definition, reason) in invalid {
+            let config = serde_json::json!({"tools": [], "definitions": [definition]});
+            std::fs::write(&path, config.to_string()).unwrap();
+            assert!(watcher.reload().await.is_err(), "definition {}", reason);
+        }
+
+        assert_eq!(active_names(&registry).await, ["alpha", "sum"]);
+        let sum = registry.get_tool("sum").await.unwrap();
+        assert_eq!(sum.description(), Some("Add numbers"));
+    }
+
+    #[tokio::test]
+    async fn test_watcher_applies_edit_and_notifies() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tools.json");
+        std::fs::write(&path, r#"{"tools": ["alpha"]}"#).unwrap();
+
+        let session_manager = test_session_manager();
+        session_manager.create_session().await;
+        let registry = test_registry(session_manager.clone());
+
+        let handle = ToolConfigWatcher::new(&path, registry.clone())
+            .with_interval(Duration::from_millis(10))
+            .start()
+            .await;
+        assert_eq!(active_names(&registry).await, ["alpha"]);
+
+        let mut receiver = session_manager.subscribe_all_session_events();
+        std::fs::write(&path, r#"{"tools": ["alpha", "gamma"]}"#).unwrap();
+
+        let event_type = tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                if let Ok((_, SessionEvent::Custom { event_type, .. })) = receiver.recv().await {
+                    return event_type;
+                }
+            }
+        })
+        .await
+        .expect("edit should broadcast a notification");
+        assert_eq!(event_type, "notifications/tools/list_changed");
+        assert_eq!(active_names(&registry).await, ["alpha", "gamma"]);
+
+        handle.abort();
+    }
+}
@@ -17,12 +17,14 @@ use tracing::{debug, info, warn};
 
 use crate::session::SessionManager;
 use crate::tool::{McpTool, compute_tool_fingerprint, tool_to_descriptor};
+use crate::tool_config::{ConfiguredTool, ToolDefinition};
 
 /// In-process mutable tool registry for runtime activation/deactivation.
 ///
 /// All tool implementations are compiled into the binary and registered at build time.
-/// This registry controls which of those compiled tools are currently **active** —
-/// it does not support adding new tool implementations at runtime.
+/// This registry controls which of those compiled tools are currently **active**,
+/// and which config-defined tools ([`ToolDefinition`]) are advertised in front of
+/// them — it does not support adding new tool implementations at runtime.
 ///
 /// # Concurrency
 ///
@@ -31,7 +33,7 @@ use crate::tool::{McpTool, compute_tool_fingerprint, tool_to_descriptor};
 pub struct ToolRegistry {
     /// All compiled tools (immutable after construction)
     compiled_tools: HashMap<String, Arc<dyn McpTool>>,
-    /// Mutable state: active tool set + fingerprint under a single lock.
+    /// Mutable state: active tool set, config-defined tools + fingerprint under a single lock.
     /// This ensures the fingerprint always matches the active set — no TOCTOU window.
     state: RwLock<ToolState>,
     /// SessionManager for broadcasting change events (transport-agnostic)
@@ -48,6 +50,8 @@ pub struct ToolRegistry {
 /// Active tool set and its corresponding fingerprint, kept consistent under one lock.
 struct ToolState {
     active: HashSet<String>,
+    /// Config-defined tools; active for as long as they are defined
+    defined: HashMap<String, Arc<ConfiguredTool>>,
     fingerprint: String,
 }
 
@@ -62,7 +66,7 @@ impl ToolRegistry {
         server_state: Arc<dyn turul_mcp_server_state_storage::ServerStateStorage>,
     ) -> Self {
         let active: HashSet<String> = compiled_tools.keys().cloned().collect();
-        let fingerprint = Self::compute_fingerprint_for(&compiled_tools, &active);
+        let fingerprint = Self::compute_fingerprint_for(&compiled_tools, &active, &HashMap::new());
 
         // TTL for check_for_changes() — default 10 seconds, configurable via env var
         let check_ttl_secs: u64 = std::env::var("TURUL_TOOL_CHECK_TTL_SECS")
@@ -74,6 +78,7 @@ impl ToolRegistry {
             compiled_tools,
             state: RwLock::new(ToolState {
                 active,
+                defined: HashMap::new(),
                 fingerprint,
             }),
             session_manager,
@@ -97,8 +102,7 @@ impl ToolRegistry {
             let inserted = state.active.insert(name.to_string());
             if inserted {
                 // Recompute fingerprint atomically under the same write lock
-                state.fingerprint =
-                    Self::compute_fingerprint_for(&self.compiled_tools, &state.active);
+                state.fingerprint = self.fingerprint_of(&state);
             }
             inserted
         }; // write lock released here — active set + fingerprint are consistent
@@ -128,8 +132,7 @@ impl ToolRegistry {
             let removed = state.active.remove(name);
             if removed {
                 // Recompute fingerprint atomically under the same write lock
-                state.fingerprint =
-                    Self::compute_fingerprint_for(&self.compiled_tools, &state.active);
+                state.fingerprint = self.fingerprint_of(&state);
             }
             removed
         }; // write lock released here — active set + fingerprint are consistent
@@ -145,6 +148,140 @@ impl ToolRegistry {
         Ok(changed)
     }
 
+    /// Replace the active tool set in one step.
+    ///
+    /// Every name must be a compiled tool, otherwise nothing changes. The new set
+    /// and its fingerprint are swapped under a single write lock and at most one
+    /// `notifications/tools/list_changed` is broadcast, so clients never observe
+    /// a partially applied set. Config-defined tools are left as they are.
+    pub async fn set_active_tools(
+        &self,
+        names: &HashSet<String>,
+    ) -> Result<ToolSetChange, ToolRegistryError> {
+        self.check_compiled(names)?;
+        self.replace_state(names, None).await
+    }
+
+    /// Replace the active tool set and the config-defined tools in one step.
+    ///
+    /// Like [`set_active_tools`](Self::set_active_tools), but also swaps in
+    /// `definitions`: new ones are added, edited ones replace their previous
+    /// version and missing ones are removed, all under the same write lock and
+    /// with at most one `notifications/tools/list_changed`. A definition whose
+    /// name is a compiled tool, whose `handler` is not compiled, or whose schema
+    /// is invalid rejects the whole config and nothing changes.
+    ///
+    /// Config-defined tools are local to this instance; only the compiled
+    /// active set is persisted for cross-instance coordination.
+    pub async fn apply_tool_config(
+        &self,
+        names: &HashSet<String>,
+        definitions: &[ToolDefinition],
+    ) -> Result<ToolSetChange, ToolRegistryError> {
+        self.check_compiled(names)?;
+
+        let mut defined: HashMap<String, Arc<ConfiguredTool>> = HashMap::new();
+        for definition in definitions {
+            if self.compiled_tools.contains_key(&definition.name) {
+                return Err(ToolRegistryError::InvalidConfig(format!(
+                    "tool '{}' is already compiled and cannot be redefined",
+                    definition.name
+                )));
+            }
+            let handler = self
+                .compiled_tools
+                .get(&definition.handler)
+                .ok_or_else(|| ToolRegistryError::NotCompiled(definition.handler.clone()))?;
+            let tool = ConfiguredTool::new(definition.clone(), Arc::clone(handler))?;
+            if defined
+                .insert(definition.name.clone(), Arc::new(tool))
+                .is_some()
+            {
+                return Err(ToolRegistryError::InvalidConfig(format!(
+                    "tool '{}' is defined more than once",
+                    definition.name
+                )));
+            }
+        }
+
+        self.replace_state(names, Some(defined)).await
+    }
+
+    /// Reject any name that is not a compiled tool.
+    fn check_compiled(&self, names: &HashSet<String>) -> Result<(), ToolRegistryError> {
+        let mut unknown: Vec<&String> = names
+            .iter()
+            .filter(|name| !self.compiled_tools.contains_key(*name))
+            .collect();
+        unknown.sort();
+        match unknown.first() {
+            Some(name) => Err(ToolRegistryError::NotCompiled((*name).clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Swap in a validated active set (and, if given, config-defined tools),
+    /// then notify clients and persist the compiled changes.
+    async fn replace_state(
+        &self,
+        names: &HashSet<String>,
+        defined: Option<HashMap<String, Arc<ConfiguredTool>>>,
+    ) -> Result<ToolSetChange, ToolRegistryError> {
+        let change = {
+            let mut state = self.state.write().await;
+            let mut change = ToolSetChange {
+                activated: names.difference(&state.active).cloned().collect(),
+                deactivated: state.active.difference(names).cloned().collect(),
+                ..Default::default()
+            };
+            if let Some(ref defined) = defined {
+                for (name, tool) in defined {
+                    match state.defined.get(name) {
+                        None => change.defined.push(name.clone()),
+                        Some(old) if old.definition() != tool.definition() => {
+                            change.updated.push(name.clone())
+                        }
+                        Some(_) => {}
+                    }
+                }
+                change.removed = state
+                    .defined
+                    .keys()
+                    .filter(|name| !defined.contains_key(*name))
+                    .cloned()
+                    .collect();
+            }
+            change.sort();
+            if !change.is_empty() {
+                state.active = names.clone();
+                if let Some(defined) = defined {
+                    state.defined = defined;
+                }
+                // Recompute fingerprint atomically under the same write lock
+                state.fingerprint = self.fingerprint_of(&state);
+            }
+            change
+        }; // write lock released here — active set + fingerprint are consistent
+
+        if change.is_empty() {
+            debug!("Active tool set unchanged");
+            return Ok(change);
+        }
+
+        self.broadcast_notification().await?;
+        info!(
+            "Active tool set replaced (activated: {:?}, deactivated: {:?}, defined: {:?}, updated: {:?}, removed: {:?})",
+            change.activated, change.deactivated, change.defined, change.updated, change.removed
+        );
+        for name in &change.activated {
+            self.persist_entity_change(name, true).await;
+        }
+        for name in &change.deactivated {
+            self.persist_entity_change(name, false).await;
+        }
+        Ok(change)
+    }
+
     /// List all currently active tools as protocol `Tool` descriptors.
     pub async fn list_active_tools(&self) -> Vec<turul_mcp_protocol::Tool> {
         let state = self.state.read().await;
@@ -153,18 +290,27 @@ impl ToolRegistry {
             .iter()
             .filter(|(name, _)| state.active.contains(*name))
             .map(|(_, tool)| tool_to_descriptor(tool.as_ref()))
+            .chain(
+                state
+                    .defined
+                    .values()
+                    .map(|tool| tool_to_descriptor(tool.as_ref())),
+            )
             .collect();
         // Sort for deterministic output (matches tools/list behavior)
         tools.sort_by(|a, b| a.name.cmp(&b.name));
         tools
     }
 
-    /// Get an active tool by name. Returns None if the tool is inactive or not compiled.
+    /// Get an active tool by name. Returns None if the tool is inactive or neither
+    /// compiled nor config-defined.
     ///
     /// Clones the Arc under the read lock, then releases. Safe to call across await points.
     pub async fn get_tool(&self, name: &str) -> Option<Arc<dyn McpTool>> {
         let state = self.state.read().await;
-        if state.active.contains(name) {
+        if let Some(tool) = state.defined.get(name) {
+            Some(Arc::clone(tool) as Arc<dyn McpTool>)
+        } else if state.active.contains(name) {
             self.compiled_tools.get(name).cloned()
         } else {
             None
@@ -292,7 +438,7 @@ impl ToolRegistry {
         // Update in-memory state
         let mut state = self.state.write().await;
         state.active = active_ids.into_iter().collect();
-        state.fingerprint = Self::compute_fingerprint_for(&self.compiled_tools, &state.active);
+        state.fingerprint = self.fingerprint_of(&state);
 
         Ok(())
     }
@@ -416,15 +562,26 @@ impl ToolRegistry {
         })
     }
 
-    /// Compute fingerprint for a given active tool subset.
+    /// Fingerprint of everything `state` advertises.
+    fn fingerprint_of(&self, state: &ToolState) -> String {
+        Self::compute_fingerprint_for(&self.compiled_tools, &state.active, &state.defined)
+    }
+
+    /// Compute fingerprint for a given active tool subset plus config-defined tools.
     fn compute_fingerprint_for(
         compiled: &HashMap<String, Arc<dyn McpTool>>,
         active: &HashSet<String>,
+        defined: &HashMap<String, Arc<ConfiguredTool>>,
     ) -> String {
         let active_tools: HashMap<String, Arc<dyn McpTool>> = compiled
             .iter()
             .filter(|(name, _)| active.contains(*name))
             .map(|(name, tool)| (name.clone(), Arc::clone(tool)))
+            .chain(
+                defined
+                    .iter()
+                    .map(|(name, tool)| (name.clone(), Arc::clone(tool) as Arc<dyn McpTool>)),
+            )
             .collect();
         compute_tool_fingerprint(&active_tools)
     }
@@ -443,6 +600,44 @@ pub enum ToolRegistryError {
 
     #[error("Notification persistence failed: {0}")]
     NotificationFailed(String),
+
+    #[error("Invalid tool config: {0}")]
+    InvalidConfig(String),
+}
+
+/// Tools changed by [`ToolRegistry::set_active_tools`] or
+/// [`ToolRegistry::apply_tool_config`], sorted by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToolSetChange {
+    /// Compiled tools switched on
+    pub activated: Vec<String>,
+    /// Compiled tools switched off
+    pub deactivated: Vec<String>,
+    /// Config-defined tools added
+    pub defined: Vec<String>,
+    /// Config-defined tools whose definition changed
+    pub updated: Vec<String>,
+    /// Config-defined tools no longer defined
+    pub removed: Vec<String>,
+}
+
+impl ToolSetChange {
+    /// Returns `true` if nothing clients can see changed
+    pub fn is_empty(&self) -> bool {
+        self.activated.is_empty()
+            && self.deactivated.is_empty()
+            && self.defined.is_empty()
+            && self.updated.is_empty()
+            && self.removed.is_empty()
+    }
+
+    fn sort(&mut self) {
+        self.activated.sort();
+        self.deactivated.sort();
+        self.defined.sort();
+        self.updated.sort();
+        self.removed.sort();
+    }
 }
 
 /// Result of syncing local tool state against shared storage.
@@ -1351,6 +1546,33 @@ mod tests {
         );
     }
 
+    /// Requirement: set_active_tools() swaps several tools with exactly 1 event,
+    /// and rejects unknown names without touching the active set.
+    #[tokio::test]
+    async fn test_set_active_tools_is_atomic() {
+        let (sm, dispatcher) = test_session_manager_with_dispatcher();
+        sm.set_event_dispatcher(dispatcher.clone()).await;
+        let _session_id = sm.create_session().await;
+
+        let registry = ToolRegistry::new(test_tools(), sm, test_storage());
+
+        let wanted: HashSet<String> = ["alpha".to_string()].into();
+        let change = registry.set_active_tools(&wanted).await.unwrap();
+        assert_eq!(change.deactivated, vec!["beta", "gamma"]);
+        assert!(change.activated.is_empty());
+        assert_eq!(dispatcher.event_count().await, 1);
+
+        let unknown: HashSet<String> = ["alpha".to_string(), "delta".to_string()].into();
+        let err = registry.set_active_tools(&unknown).await.unwrap_err();
+        assert!(matches!(err, ToolRegistryError::NotCompiled(name) if name == "delta"));
+        assert_eq!(registry.list_active_tools().await.len(), 1);
+        assert_eq!(dispatcher.event_count().await, 1);
+
+        let unchanged = registry.set_active_tools(&wanted).await.unwrap();
+        assert!(unchanged.is_empty());
+        assert_eq!(dispatcher.event_count().await, 1);
+    }
+
     /// Requirement: check_for_changes() MUST persist exactly 1 event per session
     /// when fingerprint mismatch is detected.
     #[tokio::test]
@@ -2,6 +2,8 @@
 //!
 //! This module provides RFC 6570-inspired URI template support for dynamic MCP resources.
 //! It enables patterns like `file:///user/{user_id}.json` with strict validation.
+//! A `{var}` placeholder matches a single path segment, while `{var*}` greedily
+//! captures the remainder of the URI including slashes (e.g. `file:///repo/{path*}`).
 
 use regex::Regex;
 use std::collections::HashMap;
@@ -18,6 +20,10 @@ pub struct UriTemplate {
     regex: Regex,
     /// Variable names in order of appearance
     variables: Vec<String>,
+    /// Number of multi-segment `{var*}` placeholders
+    wildcards: usize,
+    /// Number of literal (non-placeholder) characters in the pattern
+    literal_len: usize,
     /// Validation rules for each variable
     validators: HashMap<String, VariableValidator>,
     /// MIME type mapping based on file extension
@@ -101,6 +107,8 @@ impl UriTemplate {
             pattern: pattern.to_string(),
             regex: Regex::new("").unwrap(), // Placeholder
             variables: Vec::new(),
+            wildcards: 0,
+            literal_len: pattern.len(),
             validators: HashMap::new(),
             mime_type: Self::detect_mime_type(pattern),
         };
@@ -129,12 +137,23 @@ impl UriTemplate {
         let mut regex_pattern = regex::escape(&self.pattern);
 
         for captures in var_regex.captures_iter(&self.pattern) {
-            let var_name = captures.get(1).unwrap().as_str();
+            let placeholder = captures.get(0).unwrap().as_str();
+            let raw_name = captures.get(1).unwrap().as_str();
+            self.literal_len -= placeholder.len();
+
+            // {var*} spans segments, {var} stays within one
+            let (var_name, group) = match raw_name.strip_suffix('*') {
+                Some(name) => {
+                    self.wildcards += 1;
+                    (name, "(.+)")
+                }
+                None => (raw_name, "([^/]+)"),
+            };
             self.variables.push(var_name.to_string());
 
             // Replace {var_name} with capture group
-            let escaped_var = regex::escape(&format!("{{{}}}", var_name));
-            regex_pattern = regex_pattern.replace(&escaped_var, "([^/]+)");
+            let escaped_var = regex::escape(placeholder);
+            regex_pattern = regex_pattern.replacen(&escaped_var, group, 1);
         }
 
         // Anchor the pattern
@@ -189,7 +208,9 @@ impl UriTemplate {
             }
 
             // Replace variable in pattern
-            result = result.replace(&format!("{{{}}}", var_name), value);
+            result = result
+                .replace(&format!("{{{}}}", var_name), value)
+                .replace(&format!("{{{}*}}", var_name), value);
         }
 
         Ok(result)
@@ -250,6 +271,20 @@ impl UriTemplate {
     pub fn variables(&self) -> &[String] {
         &self.variables
     }
+
+    /// Number of multi-segment `{var*}` placeholders in the pattern
+    pub fn wildcard_count(&self) -> usize {
+        self.wildcards
+    }
+
+    /// Ordering used to pick between templates matching the same URI:
+    /// fewer wildcards first, then more literal characters, then pattern text
+    fn specificity_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.wildcards
+            .cmp(&other.wildcards)
+            .then_with(|| other.literal_len.cmp(&self.literal_len))
+            .then_with(|| self.pattern.cmp(&other.pattern))
+    }
 }
 
 /// Registry for managing URI templates
@@ -270,8 +305,14 @@ impl UriTemplateRegistry {
     }
 
     /// Find template that matches the given URI
+    ///
+    /// When several templates match, the most specific one wins: fewest `{var*}`
+    /// wildcards, then most literal characters, then lexical pattern order.
     pub fn find_matching(&self, uri: &str) -> Option<&UriTemplate> {
-        self.templates.iter().find(|t| t.matches(uri))
+        self.templates
+            .iter()
+            .filter(|t| t.matches(uri))
+            .min_by(|a, b| a.specificity_cmp(b))
     }
 
     /// Get all registered templates
@@ -404,6 +445,91 @@ mod tests {
         assert_eq!(found.unwrap().pattern(), "file:///user/{user_id}.json");
     }
 
+    #[test]
+    fn test_wildcard_captures_multiple_segments() {
+        let template = UriTemplate::new("file:///repo/{path*}").unwrap();
+        assert_eq!(template.variables(), &["path"]);
+        assert_eq!(template.wildcard_count(), 1);
+
+        let vars = template.extract("file:///repo/src/lib/mod.rs").unwrap();
+        assert_eq!(vars.get("path"), Some(&"src/lib/mod.rs".to_string()));
+
+        let mut resolve_vars = HashMap::new();
+        resolve_vars.insert("path".to_string(), "src/lib/mod.rs".to_string());
+        assert_eq!(
+            template.resolve(&resolve_vars).unwrap(),
+            "file:///repo/src/lib/mod.rs"
+        );
+    }
+
+    #[test]
+    fn test_single_segment_variable_does_not_span_slashes() {
+        let template = UriTemplate::new("file:///repo/{name}").unwrap();
+        assert_eq!(template.wildcard_count(), 0);
+        assert!(template.matches("file:///repo/README.md"));
+        assert!(!template.matches("file:///repo/src/lib.rs"));
+    }
+
+    #[test]
+    fn test_wildcard_with_trailing_literal() {
+        let template = UriTemplate::new("file:///repo/{owner}/{path*}.rs").unwrap();
+        let vars = template
+            .extract("file:///repo/alice/src/bin/main.rs")
+            .unwrap();
+        assert_eq!(vars.get("owner"), Some(&"alice".to_string()));
+        assert_eq!(vars.get("path"), Some(&"src/bin/main".to_string()));
+    }
+
+    #[test]
+    fn test_registry_prefers_fewer_wildcards() {
+        let mut registry = UriTemplateRegistry::new();
+        // Register the broad template first so order alone would pick it
+        registry.register(UriTemplate::new("file:///repo/{path*}").unwrap());
+        registry.register(UriTemplate::new("file:///repo/{dir}/{file}").unwrap());
+        registry.register(UriTemplate::new("file:///repo/{dir}/{rest*}").unwrap());
+
+        let found = registry.find_matching("file:///repo/src/lib.rs").unwrap();
+        assert_eq!(found.pattern(), "file:///repo/{dir}/{file}");
+
+        // Only the wildcard templates match deeper paths; the one with more literal text wins
+        let found = registry
+            .find_matching("file:///repo/src/lib/mod.rs")
+            .unwrap();
+        assert_eq!(found.pattern(), "file:///repo/{dir}/{rest*}");
+
+        let found = registry.find_matching("file:///repo/README.md").unwrap();
+        assert_eq!(found.pattern(), "file:///repo/{path*}");
+    }
+
+    #[test]
+    fn test_registry_ordering_is_independent_of_registration_order() {
+        let patterns = ["file:///docs/{a*}", "file:///docs/{b*}"];
+        for order in [[0, 1], [1, 0]] {
+            let mut registry = UriTemplateRegistry::new();
+            for i in order {
+                registry.register(UriTemplate::new(patterns[i]).unwrap());
+            }
+            let found = registry.find_matching("file:///docs/x/y").unwrap();
+            assert_eq!(found.pattern(), "file:///docs/{a*}");
+        }
+    }
+
+    #[test]
+    fn test_wildcard_non_matching_uri() {
+        let mut registry = UriTemplateRegistry::new();
+        registry.register(UriTemplate::new("file:///repo/{path*}").unwrap());
+
+        // Wrong prefix and an empty remainder both miss
+        assert!(registry.find_matching("file:///other/src/lib.rs").is_none());
+        assert!(registry.find_matching("file:///repo/").is_none());
+        assert!(
+            UriTemplate::new("file:///repo/{path*}")
+                .unwrap()
+                .extract("file:///other/src/lib.rs")
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_mime_type_detection() {
         assert_eq!(
@@ -0,0 +1,415 @@
+//! Audit Trail for Tool Invocations
+//!
+//! An [`AuditSink`] receives one [`AuditRecord`] per `tools/call`: who called
+//! which tool, a digest (or full copy) of the arguments, and the outcome.
+//! [`InMemoryAuditSink`] keeps records in memory; with the `sqlite` feature,
+//! [`SqliteAuditSink`] appends them to an `audit_log` table.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+/// Errors raised while writing or reading audit records
+#[derive(Debug, thiserror::Error)]
+pub enum AuditError {
+    #[cfg(feature = "sqlite")]
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("Audit sink error: {0}")]
+    Sink(String),
+}
+
+/// How tool arguments are captured in an [`AuditRecord`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuditArgumentMode {
+    /// Record only a SHA-256 digest, keeping PII out of the audit trail
+    #[default]
+    Hash,
+    /// Record the arguments verbatim alongside the digest
+    Full,
+}
+
+/// Result of an audited tool call
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The tool returned a result
+    Success,
+    /// The tool ran and reported failure with an `isError` result
+    ToolError,
+    /// The call was answered with a JSON-RPC error
+    Error { message: String },
+}
+
+impl AuditOutcome {
+    /// Short label stored alongside the record (`success`, `tool_error`, `error`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::ToolError => "tool_error",
+            Self::Error { .. } => "error",
+        }
+    }
+}
+
+/// One audited tool invocation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Session that made the call, if any
+    pub session_id: Option<String>,
+    /// JSON-RPC method (`tools/call`)
+    pub method: String,
+    /// Requested tool name
+    pub tool: String,
+    /// Hex SHA-256 digest of the canonical (key-sorted) JSON arguments
+    pub args_hash: String,
+    /// The arguments themselves, only with [`AuditArgumentMode::Full`]
+    pub arguments: Option<Value>,
+    /// What came of the call
+    pub outcome: AuditOutcome,
+    /// When the call completed
+    pub timestamp: DateTime<Utc>,
+}
+
+impl AuditRecord {
+    /// Build a `tools/call` record, capturing `arguments` according to `mode`
+    pub fn tool_call(
+        session_id: Option<String>,
+        tool: impl Into<String>,
+        arguments: &Value,
+        mode: AuditArgumentMode,
+        outcome: AuditOutcome,
+    ) -> Self {
+        Self {
+            session_id,
+            method: "tools/call".to_string(),
+            tool: tool.into(),
+            args_hash: hash_arguments(arguments),
+            arguments: (mode == AuditArgumentMode::Full).then(|| arguments.clone()),
+            outcome,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Hex SHA-256 digest of `arguments` with object keys sorted, so equal
+/// arguments hash equally whatever order the client sent them in
+pub fn hash_arguments(arguments: &Value) -> String {
+    let mut canonical = String::new();
+    write_canonical(arguments, &mut canonical);
+    format!("{:x}", Sha256::digest(canonical.as_bytes()))
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+/// Destination for audit records
+#[async_trait]
+pub trait AuditSink: Send + Sync {
+    /// Persist one record
+    async fn record(&self, record: AuditRecord) -> Result<(), AuditError>;
+}
+
+/// Audit sink plus the argument capture mode, as configured on a server
+#[derive(Clone)]
+pub struct AuditLog {
+    sink: Arc<dyn AuditSink>,
+    mode: AuditArgumentMode,
+}
+
+impl AuditLog {
+    pub fn new(sink: Arc<dyn AuditSink>, mode: AuditArgumentMode) -> Self {
+        Self { sink, mode }
+    }
+
+    /// Argument capture mode
+    pub fn mode(&self) -> AuditArgumentMode {
+        self.mode
+    }
+
+    /// Record a `tools/call`; sink failures are logged, never surfaced to the caller
+    pub async fn record_tool_call(
+        &self,
+        session_id: Option<String>,
+        tool: &str,
+        arguments: &Value,
+        outcome: AuditOutcome,
+    ) {
+        let record = AuditRecord::tool_call(session_id, tool, arguments, self.mode, outcome);
+        if let Err(e) = self.sink.record(record).await {
+            warn!(tool = tool, error = %e, "Failed to write audit record");
+        }
+    }
+}
+
+impl std::fmt::Debug for AuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLog")
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+/// In-memory audit sink for development and testing
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAuditSink {
+    records: Arc<Mutex<Vec<AuditRecord>>>,
+}
+
+impl InMemoryAuditSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All records written so far, oldest first
+    pub fn records(&self) -> Vec<AuditRecord> {
+        self.records.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl AuditSink for InMemoryAuditSink {
+    async fn record(&self, record: AuditRecord) -> Result<(), AuditError> {
+        self.records.lock().unwrap().push(record);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+pub use sqlite_sink::SqliteAuditSink;
+
+#[cfg(feature = "sqlite")]
+mod sqlite_sink {
+    use std::path::Path;
+
+    use async_trait::async_trait;
+    use sqlx::sqlite::SqliteConnectOptions;
+    use sqlx::{Row, SqlitePool};
+
+    use super::{AuditError, AuditOutcome, AuditRecord, AuditSink};
+
+    /// SQLite audit sink appending to an `audit_log` table
+    #[derive(Debug, Clone)]
+    pub struct SqliteAuditSink {
+        pool: SqlitePool,
+    }
+
+    impl SqliteAuditSink {
+        /// Open (creating if missing) the database at `path`
+        pub async fn connect(path: impl AsRef<Path>) -> Result<Self, AuditError> {
+            let options = SqliteConnectOptions::new()
+                .filename(path)
+                .create_if_missing(true);
+            Self::new(SqlitePool::connect_with(options).await?).await
+        }
+
+        /// Use an existing pool, creating the `audit_log` table if needed
+        pub async fn new(pool: SqlitePool) -> Result<Self, AuditError> {
+            sqlx::query(
+                r#"
+                CREATE TABLE IF NOT EXISTS audit_log (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    session_id TEXT,
+                    method TEXT NOT NULL,
+                    tool TEXT NOT NULL,
+                    args_hash TEXT NOT NULL,
+                    arguments TEXT,
+                    outcome TEXT NOT NULL,
+                    error_message TEXT,
+                    timestamp TEXT NOT NULL
+                )
+                "#,
+            )
+            .execute(&pool)
+            .await?;
+            Ok(Self { pool })
+        }
+
+        /// All records in insertion order
+        pub async fn records(&self) -> Result<Vec<AuditRecord>, AuditError> {
+            let rows = sqlx::query(
+                "SELECT session_id, method, tool, args_hash, arguments, outcome, error_message, timestamp \
+                 FROM audit_log ORDER BY id",
+            )
+            .fetch_all(&self.pool)
+            .await?;
+
+            rows.into_iter()
+                .map(|row| {
+                    let outcome = match row.try_get::<String, _>("outcome")?.as_str() {
+                        "success" => AuditOutcome::Success,
+                        "tool_error" => AuditOutcome::ToolError,
+                        _ => AuditOutcome::Error {
+                            message: row
+                                .try_get::<Option<String>, _>("error_message")?
+                                .unwrap_or_default(),
+                        },
+                    };
+                    let arguments = row
+                        .try_get::<Option<String>, _>("arguments")?
+                        .map(|text| serde_json::from_str(&text))
+                        .transpose()?;
+                    let timestamp = row.try_get::<String, _>("timestamp")?;
+                    Ok(AuditRecord {
+                        session_id: row.try_get("session_id")?,
+                        method: row.try_get("method")?,
+                        tool: row.try_get("tool")?,
+                        args_hash: row.try_get("args_hash")?,
+                        arguments,
+                        outcome,
+                        timestamp: chrono::DateTime::parse_from_rfc3339(&timestamp)
+                            .map_err(|e| AuditError::Sink(format!("Invalid timestamp: {}", e)))?
+                            .with_timezone(&chrono::Utc),
+                    })
+                })
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl AuditSink for SqliteAuditSink {
+        async fn record(&self, record: AuditRecord) -> Result<(), AuditError> {
+            let arguments = record
+                .arguments
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()?;
+            let error_message = match &record.outcome {
+                AuditOutcome::Error { message } => Some(message.as_str()),
+                _ => None,
+            };
+
+            sqlx::query(
+                "INSERT INTO audit_log \
+                 (session_id, method, tool, args_hash, arguments, outcome, error_message, timestamp) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&record.session_id)
+            .bind(&record.method)
+            .bind(&record.tool)
+            .bind(&record.args_hash)
+            .bind(arguments)
+            .bind(record.outcome.as_str())
+            .bind(error_message)
+            .bind(record.timestamp.to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_hash_ignores_key_order() {
+        let a = json!({ "user": "alice", "limit": 10, "filter": { "b": 1, "a": 2 } });
+        let b = json!({ "filter": { "a": 2, "b": 1 }, "limit": 10, "user": "alice" });
+        assert_eq!(hash_arguments(&a), hash_arguments(&b));
+        assert_ne!(
+            hash_arguments(&a),
+            hash_arguments(&json!({ "user": "bob" }))
+        );
+        assert_eq!(hash_arguments(&a).len(), 64);
+    }
+
+    #[test]
+    fn test_hash_mode_omits_arguments() {
+        let args = json!({ "email": "alice@example.com" });
+        let hashed = AuditRecord::tool_call(
+            None,
+            "lookup",
+            &args,
+            AuditArgumentMode::Hash,
+            AuditOutcome::Success,
+        );
+        assert!(hashed.arguments.is_none());
+
+        let full = AuditRecord::tool_call(
+            None,
+            "lookup",
+            &args,
+            AuditArgumentMode::Full,
+            AuditOutcome::Success,
+        );
+        assert_eq!(full.arguments, Some(args));
+        assert_eq!(full.args_hash, hashed.args_hash);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_sqlite_sink_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let sink = SqliteAuditSink::connect(dir.path().join("audit.db"))
+            .await
+            .unwrap();
+
+        let ok = AuditRecord::tool_call(
+            Some("session-1".to_string()),
+            "add",
+            &json!({ "a": 1 }),
+            AuditArgumentMode::Full,
+            AuditOutcome::Success,
+        );
+        let failed = AuditRecord::tool_call(
+            Some("session-1".to_string()),
+            "divide",
+            &json!({ "b": 0 }),
+            AuditArgumentMode::Hash,
+            AuditOutcome::Error {
+                message: "division by zero".to_string(),
+            },
+        );
+        sink.record(ok.clone()).await.unwrap();
+        sink.record(failed.clone()).await.unwrap();
+
+        let records = sink.records().await.unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].tool, "add");
+        assert_eq!(records[0].arguments, ok.arguments);
+        assert_eq!(records[0].outcome, AuditOutcome::Success);
+        assert_eq!(records[0].timestamp, ok.timestamp);
+        assert_eq!(records[1].outcome, failed.outcome);
+        assert_eq!(records[1].args_hash, failed.args_hash);
+        assert!(records[1].arguments.is_none());
+    }
+}
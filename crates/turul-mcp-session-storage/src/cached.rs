@@ -0,0 +1,610 @@
+//! In-process read cache over a session storage backend.
+//!
+//! [`CachedSessionStorage`] keeps recently read sessions in a bounded LRU map so a
+//! warm process (a reused AWS Lambda container, for example) can answer repeated
+//! `get_session` calls for the same session without a backend round-trip. Only
+//! `get_session` is served from the cache; every write goes straight to the backend
+//! and then evicts the written session, so a process always sees its own writes.
+//!
+//! Other processes sharing the backend may write a session this process has cached;
+//! [`SessionCacheConfig::ttl`] bounds how long such a stale copy can be served.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use serde_json::Value;
+use tokio::time::Instant;
+use tracing::trace;
+use turul_mcp_protocol::ServerCapabilities;
+
+use crate::snapshot::SessionSnapshot;
+use crate::traits::{SessionInfo, SessionStorage, SseEvent};
+
+/// Configuration for [`CachedSessionStorage`]
+#[derive(Debug, Clone)]
+pub struct SessionCacheConfig {
+    /// Most sessions kept; the least recently used is evicted first
+    pub max_entries: usize,
+    /// How long a cached session is served before it is read again
+    pub ttl: Duration,
+    /// Session inactivity timeout in minutes; sessions past it are never served
+    /// from the cache
+    pub session_timeout_minutes: Option<u64>,
+}
+
+impl Default for SessionCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 1_000,
+            ttl: Duration::from_secs(30),
+            session_timeout_minutes: None,
+        }
+    }
+}
+
+struct CacheEntry {
+    session: SessionInfo,
+    cached_at: Instant,
+    last_used: u64,
+}
+
+enum Lookup {
+    Hit(Box<SessionInfo>),
+    /// Not cached; `generation` is the invalidation count at lookup time
+    Miss {
+        generation: u64,
+    },
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    /// Monotonic use counter for LRU ordering
+    clock: u64,
+    /// Bumped on every invalidation so a read that raced a write is not cached
+    generation: u64,
+}
+
+/// Session storage that caches `get_session` results in process
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use turul_mcp_session_storage::{
+///     BoxedSessionStorage, CachedSessionStorage, InMemorySessionStorage, SessionCacheConfig,
+/// };
+///
+/// let backend: Arc<BoxedSessionStorage> = Arc::new(InMemorySessionStorage::new());
+/// let storage: Arc<BoxedSessionStorage> = Arc::new(CachedSessionStorage::new(
+///     backend,
+///     SessionCacheConfig::default(),
+/// ));
+/// ```
+pub struct CachedSessionStorage<S: ?Sized> {
+    inner: Arc<S>,
+    config: SessionCacheConfig,
+    state: Mutex<CacheState>,
+}
+
+impl<S: SessionStorage + ?Sized> CachedSessionStorage<S> {
+    /// Cache reads from `inner`
+    pub fn new(inner: Arc<S>, config: SessionCacheConfig) -> Self {
+        Self {
+            inner,
+            config,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// The wrapped backend
+    pub fn inner(&self) -> &Arc<S> {
+        &self.inner
+    }
+
+    /// Number of sessions currently cached
+    pub fn cached_sessions(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Drop one session from the cache
+    pub fn invalidate(&self, session_id: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+        state.entries.remove(session_id);
+    }
+
+    /// Drop every cached session
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+        state.entries.clear();
+    }
+
+    fn is_expired(&self, session: &SessionInfo) -> bool {
+        self.config
+            .session_timeout_minutes
+            .is_some_and(|minutes| session.is_expired(minutes))
+    }
+
+    /// Cached copy of a session, if present and still fresh
+    fn lookup(&self, session_id: &str) -> Lookup {
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+        let mut stale = false;
+        if let Some(entry) = state.entries.get_mut(session_id) {
+            if entry.cached_at.elapsed() < self.config.ttl && !self.is_expired(&entry.session) {
+                entry.last_used = clock;
+                return Lookup::Hit(Box::new(entry.session.clone()));
+            }
+            stale = true;
+        }
+        if stale {
+            state.entries.remove(session_id);
+        }
+        Lookup::Miss {
+            generation: state.generation,
+        }
+    }
+
+    fn insert(&self, session: &SessionInfo, generation: u64) {
+        if self.config.max_entries == 0 || self.is_expired(session) {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        if state.generation != generation {
+            return;
+        }
+        if !state.entries.contains_key(&session.session_id)
+            && state.entries.len() >= self.config.max_entries
+            && let Some(oldest) = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(id, _)| id.clone())
+        {
+            state.entries.remove(&oldest);
+        }
+        state.clock += 1;
+        let last_used = state.clock;
+        state.entries.insert(
+            session.session_id.clone(),
+            CacheEntry {
+                session: session.clone(),
+                cached_at: Instant::now(),
+                last_used,
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl<S: SessionStorage + ?Sized> SessionStorage for CachedSessionStorage<S> {
+    type Error = S::Error;
+
+    fn backend_name(&self) -> &'static str {
+        self.inner.backend_name()
+    }
+
+    async fn create_session(
+        &self,
+        capabilities: ServerCapabilities,
+    ) -> Result<SessionInfo, Self::Error> {
+        self.inner.create_session(capabilities).await
+    }
+
+    async fn create_session_with_id(
+        &self,
+        session_id: String,
+        capabilities: ServerCapabilities,
+    ) -> Result<SessionInfo, Self::Error> {
+        let result = self
+            .inner
+            .create_session_with_id(session_id.clone(), capabilities)
+            .await;
+        self.invalidate(&session_id);
+        result
+    }
+
+    async fn get_session(&self, session_id: &str) -> Result<Option<SessionInfo>, Self::Error> {
+        let generation = match self.lookup(session_id) {
+            Lookup::Hit(session) => {
+                trace!("Session {} served from cache", session_id);
+                return Ok(Some(*session));
+            }
+            Lookup::Miss { generation } => generation,
+        };
+        let session = self.inner.get_session(session_id).await?;
+        if let Some(session) = &session {
+            self.insert(session, generation);
+        }
+        Ok(session)
+    }
+
+    async fn update_session(&self, session_info: SessionInfo) -> Result<(), Self::Error> {
+        let session_id = session_info.session_id.clone();
+        let result = self.inner.update_session(session_info).await;
+        self.invalidate(&session_id);
+        result
+    }
+
+    async fn touch_session(&self, session_id: &str) -> Result<bool, Self::Error> {
+        let result = self.inner.touch_session(session_id).await;
+        self.invalidate(session_id);
+        result
+    }
+
+    async fn set_session_state(
+        &self,
+        session_id: &str,
+        key: &str,
+        value: Value,
+    ) -> Result<(), Self::Error> {
+        let result = self.inner.set_session_state(session_id, key, value).await;
+        self.invalidate(session_id);
+        result
+    }
+
+    async fn set_session_state_versioned(
+        &self,
+        session_id: &str,
+        key: &str,
+        value: Value,
+        expected_version: u64,
+    ) -> Result<u64, Self::Error> {
+        let result = self
+            .inner
+            .set_session_state_versioned(session_id, key, value, expected_version)
+            .await;
+        self.invalidate(session_id);
+        result
+    }
+
+    async fn get_session_state(
+        &self,
+        session_id: &str,
+        key: &str,
+    ) -> Result<Option<Value>, Self::Error> {
+        self.inner.get_session_state(session_id, key).await
+    }
+
+    async fn remove_session_state(
+        &self,
+        session_id: &str,
+        key: &str,
+    ) -> Result<Option<Value>, Self::Error> {
+        let result = self.inner.remove_session_state(session_id, key).await;
+        self.invalidate(session_id);
+        result
+    }
+
+    async fn delete_session(&self, session_id: &str) -> Result<bool, Self::Error> {
+        let result = self.inner.delete_session(session_id).await;
+        self.invalidate(session_id);
+        result
+    }
+
+    async fn list_sessions(&self) -> Result<Vec<String>, Self::Error> {
+        self.inner.list_sessions().await
+    }
+
+    async fn store_event(
+        &self,
+        session_id: &str,
+        event: SseEvent,
+    ) -> Result<SseEvent, Self::Error> {
+        self.inner.store_event(session_id, event).await
+    }
+
+    async fn get_events_after(
+        &self,
+        session_id: &str,
+        after_event_id: u64,
+    ) -> Result<Vec<SseEvent>, Self::Error> {
+        self.inner
+            .get_events_after(session_id, after_event_id)
+            .await
+    }
+
+    async fn get_recent_events(
+        &self,
+        session_id: &str,
+        limit: usize,
+    ) -> Result<Vec<SseEvent>, Self::Error> {
+        self.inner.get_recent_events(session_id, limit).await
+    }
+
+    async fn delete_events_before(
+        &self,
+        session_id: &str,
+        before_event_id: u64,
+    ) -> Result<u64, Self::Error> {
+        self.inner
+            .delete_events_before(session_id, before_event_id)
+            .await
+    }
+
+    async fn expire_sessions(&self, older_than: SystemTime) -> Result<Vec<String>, Self::Error> {
+        let expired = self.inner.expire_sessions(older_than).await?;
+        for session_id in &expired {
+            self.invalidate(session_id);
+        }
+        Ok(expired)
+    }
+
+    async fn session_count(&self) -> Result<usize, Self::Error> {
+        self.inner.session_count().await
+    }
+
+    async fn event_count(&self) -> Result<usize, Self::Error> {
+        self.inner.event_count().await
+    }
+
+    async fn maintenance(&self) -> Result<(), Self::Error> {
+        let result = self.inner.maintenance().await;
+        self.clear();
+        result
+    }
+
+    async fn ping(&self) -> Result<(), Self::Error> {
+        self.inner.ping().await
+    }
+
+    async fn export_session(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<SessionSnapshot>, Self::Error> {
+        self.inner.export_session(session_id).await
+    }
+
+    async fn import_session(&self, snapshot: SessionSnapshot) -> Result<SessionInfo, Self::Error> {
+        let session_id = snapshot.session.session_id.clone();
+        let result = self.inner.import_session(snapshot).await;
+        self.invalidate(&session_id);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InMemorySessionStorage, SessionStorageError};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// In-memory backend that counts `get_session` round-trips
+    #[derive(Default)]
+    struct CountingStorage {
+        inner: InMemorySessionStorage,
+        reads: AtomicUsize,
+    }
+
+    impl CountingStorage {
+        fn reads(&self) -> usize {
+            self.reads.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl SessionStorage for CountingStorage {
+        type Error = SessionStorageError;
+
+        fn backend_name(&self) -> &'static str {
+            "Counting"
+        }
+        async fn create_session(
+            &self,
+            caps: ServerCapabilities,
+        ) -> Result<SessionInfo, Self::Error> {
+            self.inner.create_session(caps).await
+        }
+        async fn create_session_with_id(
+            &self,
+            id: String,
+            caps: ServerCapabilities,
+        ) -> Result<SessionInfo, Self::Error> {
+            self.inner.create_session_with_id(id, caps).await
+        }
+        async fn get_session(&self, id: &str) -> Result<Option<SessionInfo>, Self::Error> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            self.inner.get_session(id).await
+        }
+        async fn update_session(&self, info: SessionInfo) -> Result<(), Self::Error> {
+            self.inner.update_session(info).await
+        }
+        async fn set_session_state(
+            &self,
+            id: &str,
+            key: &str,
+            value: Value,
+        ) -> Result<(), Self::Error> {
+            self.inner.set_session_state(id, key, value).await
+        }
+        async fn set_session_state_versioned(
+            &self,
+            id: &str,
+            key: &str,
+            value: Value,
+            expected_version: u64,
+        ) -> Result<u64, Self::Error> {
+            self.inner
+                .set_session_state_versioned(id, key, value, expected_version)
+                .await
+        }
+        async fn get_session_state(
+            &self,
+            id: &str,
+            key: &str,
+        ) -> Result<Option<Value>, Self::Error> {
+            self.inner.get_session_state(id, key).await
+        }
+        async fn remove_session_state(
+            &self,
+            id: &str,
+            key: &str,
+        ) -> Result<Option<Value>, Self::Error> {
+            self.inner.remove_session_state(id, key).await
+        }
+        async fn delete_session(&self, id: &str) -> Result<bool, Self::Error> {
+            self.inner.delete_session(id).await
+        }
+        async fn list_sessions(&self) -> Result<Vec<String>, Self::Error> {
+            self.inner.list_sessions().await
+        }
+        async fn store_event(&self, id: &str, event: SseEvent) -> Result<SseEvent, Self::Error> {
+            self.inner.store_event(id, event).await
+        }
+        async fn get_events_after(
+            &self,
+            id: &str,
+            after: u64,
+        ) -> Result<Vec<SseEvent>, Self::Error> {
+            self.inner.get_events_after(id, after).await
+        }
+        async fn get_recent_events(
+            &self,
+            id: &str,
+            limit: usize,
+        ) -> Result<Vec<SseEvent>, Self::Error> {
+            self.inner.get_recent_events(id, limit).await
+        }
+        async fn delete_events_before(&self, id: &str, before: u64) -> Result<u64, Self::Error> {
+            self.inner.delete_events_before(id, before).await
+        }
+        async fn expire_sessions(
+            &self,
+            older_than: SystemTime,
+        ) -> Result<Vec<String>, Self::Error> {
+            self.inner.expire_sessions(older_than).await
+        }
+        async fn session_count(&self) -> Result<usize, Self::Error> {
+            self.inner.session_count().await
+        }
+        async fn event_count(&self) -> Result<usize, Self::Error> {
+            self.inner.event_count().await
+        }
+        async fn maintenance(&self) -> Result<(), Self::Error> {
+            self.inner.maintenance().await
+        }
+    }
+
+    fn cached(
+        config: SessionCacheConfig,
+    ) -> (Arc<CountingStorage>, CachedSessionStorage<CountingStorage>) {
+        let backend = Arc::new(CountingStorage::default());
+        (
+            Arc::clone(&backend),
+            CachedSessionStorage::new(backend, config),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_second_invocation_skips_backend_read() {
+        let (backend, storage) = cached(SessionCacheConfig::default());
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+
+        // Invocation 1: cold read goes to the backend
+        let first = storage.get_session(&session.session_id).await.unwrap();
+        assert_eq!(backend.reads(), 1);
+
+        // Invocation 2 in the same warm container: served from the cache
+        let second = storage.get_session(&session.session_id).await.unwrap();
+        assert_eq!(backend.reads(), 1);
+        assert_eq!(first.unwrap().session_id, second.unwrap().session_id);
+    }
+
+    #[tokio::test]
+    async fn test_writes_invalidate_cached_session() {
+        let (backend, storage) = cached(SessionCacheConfig::default());
+        let id = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap()
+            .session_id;
+        storage.get_session(&id).await.unwrap();
+
+        storage
+            .set_session_state(&id, "counter", serde_json::json!(1))
+            .await
+            .unwrap();
+        let session = storage.get_session(&id).await.unwrap().unwrap();
+        assert_eq!(backend.reads(), 2);
+        assert_eq!(session.state["counter"], 1);
+
+        storage.delete_session(&id).await.unwrap();
+        assert!(storage.get_session(&id).await.unwrap().is_none());
+        assert_eq!(backend.reads(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_entries_expire_after_ttl() {
+        let (backend, storage) = cached(SessionCacheConfig {
+            ttl: Duration::from_secs(10),
+            ..SessionCacheConfig::default()
+        });
+        let id = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap()
+            .session_id;
+
+        storage.get_session(&id).await.unwrap();
+        tokio::time::advance(Duration::from_secs(5)).await;
+        storage.get_session(&id).await.unwrap();
+        assert_eq!(backend.reads(), 1);
+
+        tokio::time::advance(Duration::from_secs(6)).await;
+        storage.get_session(&id).await.unwrap();
+        assert_eq!(backend.reads(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired_sessions_are_not_cached() {
+        let (backend, storage) = cached(SessionCacheConfig {
+            session_timeout_minutes: Some(1),
+            ..SessionCacheConfig::default()
+        });
+        let mut session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        session.last_activity -= 2 * 60 * 1000;
+        storage.update_session(session.clone()).await.unwrap();
+
+        storage.get_session(&session.session_id).await.unwrap();
+        storage.get_session(&session.session_id).await.unwrap();
+        assert_eq!(backend.reads(), 2);
+        assert_eq!(storage.cached_sessions(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_least_recently_used_session_is_evicted() {
+        let (backend, storage) = cached(SessionCacheConfig {
+            max_entries: 2,
+            ..SessionCacheConfig::default()
+        });
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let session = storage
+                .create_session(ServerCapabilities::default())
+                .await
+                .unwrap();
+            ids.push(session.session_id);
+        }
+
+        storage.get_session(&ids[0]).await.unwrap();
+        storage.get_session(&ids[1]).await.unwrap();
+        storage.get_session(&ids[0]).await.unwrap(); // ids[1] is now least recent
+        storage.get_session(&ids[2]).await.unwrap(); // evicts ids[1]
+        assert_eq!(backend.reads(), 3);
+        assert_eq!(storage.cached_sessions(), 2);
+
+        storage.get_session(&ids[0]).await.unwrap();
+        assert_eq!(backend.reads(), 3);
+        storage.get_session(&ids[1]).await.unwrap();
+        assert_eq!(backend.reads(), 4);
+    }
+}
@@ -11,6 +11,26 @@
 //!
 //! Legacy tables (pre-v0.3.4) use snake_case and are auto-detected via
 //! `describe_table()` key schema inspection. Both conventions are fully supported.
+//!
+//! ## Single-Table Layout
+//!
+//! With `DynamoDbConfig::single_table`, sessions and their events live in one
+//! table that `DynamoDbTaskStorage` (with `DynamoDbTaskConfig::single_table`
+//! and the same `table_name`) can share:
+//!
+//! | Item    | `PK`           | `SK`                   | `entityType` |
+//! |---------|----------------|------------------------|--------------|
+//! | Session | `SESSION#<id>` | `SESSION`              | `session`    |
+//! | Event   | `SESSION#<id>` | `EVENT#<20-digit id>`  | `event`      |
+//! | Task    | `TASK#<id>`    | `TASK`                 | `task`       |
+//!
+//! - **Key schema**: PK=`PK` (S), SK=`SK` (S)
+//! - **GSI `TaskSessionIndex`**: PK=`sessionId` (S), SK=`taskCreatedAt` (S)
+//! - **GSI `TaskStatusIndex`**: PK=`status` (S), SK=`taskCreatedAt` (S)
+//! - **TTL attribute**: `ttlEpoch` (N, Unix epoch seconds) on every item
+//!
+//! Only task items carry `taskCreatedAt`, so both GSIs stay task-only. Either
+//! storage creates the full layout when `create_tables` is set.
 
 use std::collections::HashMap;
 #[allow(unused_imports)] // Ordering used in non-DynamoDB cfg fallback
@@ -56,7 +76,7 @@ pub(crate) struct SessionAttrNames {
     pub created_at: &'static str,
     pub is_initialized: &'static str,
     // Single-word attributes (unchanged across conventions):
-    // state, metadata, ttl
+    // state, metadata, version, ttl
 }
 
 impl SessionAttrNames {
@@ -142,6 +162,31 @@ fn get_attr<'a>(
     item.get(primary).or_else(|| item.get(fallback))
 }
 
+/// Attribute and key names of the single-table layout (see module docs).
+#[cfg(feature = "dynamodb")]
+pub(crate) mod single_table {
+    pub const PARTITION_KEY: &str = "PK";
+    pub const SORT_KEY: &str = "SK";
+    pub const ENTITY_TYPE: &str = "entityType";
+    pub const TTL_EPOCH: &str = "ttlEpoch";
+    pub const TASK_CREATED_AT: &str = "taskCreatedAt";
+    pub const TASK_SESSION_INDEX: &str = "TaskSessionIndex";
+    pub const TASK_STATUS_INDEX: &str = "TaskStatusIndex";
+    pub const SESSION_ENTITY: &str = "session";
+    pub const EVENT_ENTITY: &str = "event";
+    pub const SESSION_SORT_KEY: &str = "SESSION";
+    pub const EVENT_SORT_KEY_PREFIX: &str = "EVENT#";
+
+    pub fn session_partition_key(session_id: &str) -> String {
+        format!("SESSION#{}", session_id)
+    }
+
+    /// Zero-padded so events sort numerically within a session partition
+    pub fn event_sort_key(event_id: u64) -> String {
+        format!("{}{:020}", EVENT_SORT_KEY_PREFIX, event_id)
+    }
+}
+
 /// Configuration for DynamoDB session storage
 #[derive(Debug, Clone)]
 pub struct DynamoDbConfig {
@@ -168,6 +213,10 @@ pub struct DynamoDbConfig {
     /// When true and a table is missing: CreateTable + UpdateTimeToLive + wait for ACTIVE.
     /// When false and a table is missing: returns TableNotFound error.
     pub create_tables: bool,
+    /// Store sessions and events in one table keyed by `PK`/`SK`, shareable with
+    /// `DynamoDbTaskStorage` (see the module docs for the key schema).
+    /// Set the same `table_name` on both configs to share the table.
+    pub single_table: bool,
 }
 
 impl Default for DynamoDbConfig {
@@ -182,6 +231,7 @@ impl Default for DynamoDbConfig {
             enable_encryption: true,
             verify_tables: false,
             create_tables: false,
+            single_table: false,
         }
     }
 }
@@ -231,11 +281,112 @@ impl DynamoDbSessionStorage {
     fn event_attrs(&self) -> &'static EventAttrNames {
         EventAttrNames::for_convention(self.event_naming)
     }
+
+    /// Primary key of a session item for the configured table layout.
+    fn session_key(&self, session_id: &str) -> HashMap<String, AttributeValue> {
+        if self.config.single_table {
+            HashMap::from([
+                (
+                    single_table::PARTITION_KEY.to_string(),
+                    AttributeValue::S(single_table::session_partition_key(session_id)),
+                ),
+                (
+                    single_table::SORT_KEY.to_string(),
+                    AttributeValue::S(single_table::SESSION_SORT_KEY.to_string()),
+                ),
+            ])
+        } else {
+            HashMap::from([(
+                self.session_attrs().session_id.to_string(),
+                AttributeValue::S(session_id.to_string()),
+            )])
+        }
+    }
+
+    /// TTL attribute (epoch seconds) written on session and event items.
+    fn ttl_attribute(&self) -> &'static str {
+        if self.config.single_table {
+            single_table::TTL_EPOCH
+        } else {
+            "ttl"
+        }
+    }
+
+    /// Table holding events: the shared table in single-table mode.
+    fn events_table_name(&self) -> String {
+        if self.config.single_table {
+            self.config.table_name.clone()
+        } else {
+            format!("{}-events", self.config.table_name)
+        }
+    }
+
+    /// Scan of the main table, restricted to one entity type in single-table mode.
+    fn entity_scan(
+        &self,
+        entity: &str,
+    ) -> aws_sdk_dynamodb::operation::scan::builders::ScanFluentBuilder {
+        let scan = self.client.scan().table_name(&self.config.table_name);
+        if !self.config.single_table {
+            return scan;
+        }
+        scan.filter_expression("#entityType = :entityType")
+            .expression_attribute_names("#entityType", single_table::ENTITY_TYPE)
+            .expression_attribute_values(":entityType", AttributeValue::S(entity.to_string()))
+    }
+
+    /// Query over all events of a session, in event ID order.
+    fn event_query(
+        &self,
+        event_table: &str,
+        session_id: &str,
+    ) -> aws_sdk_dynamodb::operation::query::builders::QueryFluentBuilder {
+        let query = self.client.query().table_name(event_table);
+        if self.config.single_table {
+            query
+                .key_condition_expression("#pk = :pk AND begins_with(#sk, :prefix)")
+                .expression_attribute_names("#pk", single_table::PARTITION_KEY)
+                .expression_attribute_names("#sk", single_table::SORT_KEY)
+                .expression_attribute_values(
+                    ":pk",
+                    AttributeValue::S(single_table::session_partition_key(session_id)),
+                )
+                .expression_attribute_values(
+                    ":prefix",
+                    AttributeValue::S(single_table::EVENT_SORT_KEY_PREFIX.to_string()),
+                )
+        } else {
+            query
+                .key_condition_expression(format!("{} = :sid", self.event_attrs().session_id))
+                .expression_attribute_values(":sid", AttributeValue::S(session_id.to_string()))
+        }
+    }
+
+    /// Sort key attribute of event items, used for duplicate-ID conditional writes.
+    fn event_sort_key_attr(&self, ea: &'static EventAttrNames) -> &'static str {
+        if self.config.single_table {
+            single_table::SORT_KEY
+        } else {
+            ea.event_id
+        }
+    }
+
+    /// Ensure the events table exists when `verify_tables` is set.
+    /// Nothing to do in single-table mode, where events share the sessions table.
+    async fn verify_events_table(&self, event_table: &str) -> Result<(), SessionStorageError> {
+        if self.config.verify_tables && !self.config.single_table {
+            self.ensure_events_table_exists(event_table).await?;
+        }
+        Ok(())
+    }
 }
 
 impl DynamoDbSessionStorage {
     /// Get the event table name from environment variable or default pattern
     fn get_event_table_name(&self) -> String {
+        if self.config.single_table {
+            return self.config.table_name.clone();
+        }
         std::env::var("MCP_SESSION_EVENT_TABLE")
             .unwrap_or_else(|_| format!("{}-events", self.config.table_name))
     }
@@ -367,6 +518,10 @@ impl DynamoDbSessionStorage {
                     // Enable TTL after table becomes active
                     self.enable_ttl().await?;
 
+                    if self.config.single_table {
+                        return Ok(());
+                    }
+
                     // Also create the events table upfront
                     let event_table = self.get_event_table_name();
                     warn!(
@@ -436,6 +591,10 @@ impl DynamoDbSessionStorage {
             Projection, ProjectionType, ScalarAttributeType,
         };
 
+        if self.config.single_table {
+            return self.create_single_table().await;
+        }
+
         let sa = self.session_attrs();
         info!(
             "Creating DynamoDB table: {} (naming: {:?})",
@@ -511,6 +670,85 @@ impl DynamoDbSessionStorage {
         }
     }
 
+    /// Create the shared single-table layout, including the task GSIs, so the
+    /// table is usable by both session and task storage whichever creates it.
+    #[cfg(feature = "dynamodb")]
+    async fn create_single_table(&self) -> Result<(), DynamoDbError> {
+        use aws_sdk_dynamodb::types::{
+            AttributeDefinition, BillingMode, GlobalSecondaryIndex, KeySchemaElement, KeyType,
+            Projection, ProjectionType, ScalarAttributeType,
+        };
+
+        info!(
+            "Creating single-table DynamoDB layout: {}",
+            self.config.table_name
+        );
+
+        let key = |name: &str, key_type: KeyType| {
+            KeySchemaElement::builder()
+                .attribute_name(name)
+                .key_type(key_type)
+                .build()
+                .map_err(|e| DynamoDbError::AwsError(e.to_string()))
+        };
+        let string_attr = |name: &str| {
+            AttributeDefinition::builder()
+                .attribute_name(name)
+                .attribute_type(ScalarAttributeType::S)
+                .build()
+                .map_err(|e| DynamoDbError::AwsError(e.to_string()))
+        };
+        let gsi = |index_name: &str, hash: &str| -> Result<GlobalSecondaryIndex, DynamoDbError> {
+            GlobalSecondaryIndex::builder()
+                .index_name(index_name)
+                .key_schema(key(hash, KeyType::Hash)?)
+                .key_schema(key(single_table::TASK_CREATED_AT, KeyType::Range)?)
+                .projection(
+                    Projection::builder()
+                        .projection_type(ProjectionType::All)
+                        .build(),
+                )
+                .build()
+                .map_err(|e| DynamoDbError::AwsError(e.to_string()))
+        };
+
+        let attribute_definitions = vec![
+            string_attr(single_table::PARTITION_KEY)?,
+            string_attr(single_table::SORT_KEY)?,
+            string_attr("sessionId")?,
+            string_attr("status")?,
+            string_attr(single_table::TASK_CREATED_AT)?,
+        ];
+
+        self.client
+            .create_table()
+            .table_name(&self.config.table_name)
+            .key_schema(key(single_table::PARTITION_KEY, KeyType::Hash)?)
+            .key_schema(key(single_table::SORT_KEY, KeyType::Range)?)
+            .set_attribute_definitions(Some(attribute_definitions))
+            .billing_mode(BillingMode::PayPerRequest)
+            .global_secondary_indexes(gsi(single_table::TASK_SESSION_INDEX, "sessionId")?)
+            .global_secondary_indexes(gsi(single_table::TASK_STATUS_INDEX, "status")?)
+            .send()
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to create table '{}': {}",
+                    self.config.table_name, err
+                );
+                DynamoDbError::AwsError(format!(
+                    "Failed to create table '{}': {}",
+                    self.config.table_name, err
+                ))
+            })?;
+
+        info!(
+            "Successfully initiated table creation: {}",
+            self.config.table_name
+        );
+        Ok(())
+    }
+
     /// Enable TTL on the DynamoDB table
     #[cfg(feature = "dynamodb")]
     async fn enable_ttl(&self) -> Result<(), DynamoDbError> {
@@ -519,7 +757,7 @@ impl DynamoDbSessionStorage {
         info!("Enabling TTL on DynamoDB table: {}", self.config.table_name);
 
         let ttl_spec = TimeToLiveSpecification::builder()
-            .attribute_name("ttl")
+            .attribute_name(self.ttl_attribute())
             .enabled(true)
             .build()
             .map_err(|e| DynamoDbError::AwsError(e.to_string()))?;
@@ -934,9 +1172,14 @@ impl DynamoDbSessionStorage {
         use aws_sdk_dynamodb::types::AttributeValue;
 
         let sa = self.session_attrs();
-        let mut item = HashMap::new();
+        let mut item = self.session_key(&session.session_id);
 
-        // Primary key
+        if self.config.single_table {
+            item.insert(
+                single_table::ENTITY_TYPE.to_string(),
+                AttributeValue::S(single_table::SESSION_ENTITY.to_string()),
+            );
+        }
         item.insert(
             sa.session_id.to_string(),
             AttributeValue::S(session.session_id.clone()),
@@ -977,6 +1220,10 @@ impl DynamoDbSessionStorage {
             "metadata".to_string(),
             AttributeValue::S(serde_json::to_string(&session.metadata)?),
         );
+        item.insert(
+            "version".to_string(),
+            AttributeValue::N(session.version.to_string()),
+        );
 
         // TTL attribute for automatic cleanup
         let ttl = SystemTime::now()
@@ -984,7 +1231,10 @@ impl DynamoDbSessionStorage {
             .unwrap()
             .as_secs()
             + (self.config.session_ttl_minutes * 60);
-        item.insert("ttl".to_string(), AttributeValue::N(ttl.to_string()));
+        item.insert(
+            self.ttl_attribute().to_string(),
+            AttributeValue::N(ttl.to_string()),
+        );
 
         Ok(item)
     }
@@ -1064,6 +1314,13 @@ impl DynamoDbSessionStorage {
             .transpose()?
             .unwrap_or_default();
 
+        // Items written before versioning have no version attribute
+        let version = item
+            .get("version")
+            .and_then(|v| v.as_n().ok())
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+
         Ok(SessionInfo {
             session_id,
             client_capabilities,
@@ -1073,6 +1330,7 @@ impl DynamoDbSessionStorage {
             last_activity,
             is_initialized,
             metadata,
+            version,
         })
     }
 
@@ -1124,6 +1382,7 @@ impl DynamoDbSessionStorage {
             "metadata".to_string(),
             serde_json::to_value(&session.metadata)?,
         );
+        item.insert("version".to_string(), Value::Number(session.version.into()));
 
         // TTL attribute for automatic cleanup
         let ttl = SystemTime::now()
@@ -1201,6 +1460,8 @@ impl DynamoDbSessionStorage {
             .transpose()?
             .unwrap_or_default();
 
+        let version = item.get("version").and_then(|v| v.as_u64()).unwrap_or(0);
+
         Ok(SessionInfo {
             session_id,
             client_capabilities,
@@ -1210,11 +1471,19 @@ impl DynamoDbSessionStorage {
             last_activity,
             is_initialized,
             metadata,
+            version,
         })
     }
 
     /// Public method to create both DynamoDB tables (for setup utilities)
     pub async fn create_tables(&self) -> Result<(), DynamoDbError> {
+        if self.config.single_table {
+            info!("Creating single-table DynamoDB layout");
+            self.create_table().await?;
+            self.wait_for_table_active().await?;
+            return self.enable_ttl().await;
+        }
+
         info!("Creating both DynamoDB tables: session and events");
 
         // Create main session table
@@ -1239,7 +1508,7 @@ impl DynamoDbSessionStorage {
         #[cfg(feature = "dynamodb")]
         {
             let main_table = &self.config.table_name;
-            let event_table = format!("{}-events", self.config.table_name);
+            let event_table = self.events_table_name();
 
             info!(
                 "Deleting DynamoDB tables: {} and {}",
@@ -1258,6 +1527,10 @@ impl DynamoDbSessionStorage {
                 Err(err) => warn!("Failed to delete table '{}': {}", main_table, err),
             }
 
+            if self.config.single_table {
+                return Ok(());
+            }
+
             // Delete events table
             match self
                 .client
@@ -1388,13 +1661,7 @@ impl SessionStorage for DynamoDbSessionStorage {
     async fn get_session(&self, session_id: &str) -> Result<Option<SessionInfo>, Self::Error> {
         #[cfg(feature = "dynamodb")]
         {
-            use aws_sdk_dynamodb::types::AttributeValue;
-
-            let sa = self.session_attrs();
-            let key = HashMap::from([(
-                sa.session_id.to_string(),
-                AttributeValue::S(session_id.to_string()),
-            )]);
+            let key = self.session_key(session_id);
 
             match self
                 .client
@@ -1441,7 +1708,11 @@ impl SessionStorage for DynamoDbSessionStorage {
     async fn update_session(&self, session_info: SessionInfo) -> Result<(), Self::Error> {
         #[cfg(feature = "dynamodb")]
         {
-            let item = self.session_to_dynamodb_item(&session_info)?;
+            // A full rewrite counts as one write on top of the version the caller read
+            let item = self.session_to_dynamodb_item(&SessionInfo {
+                version: session_info.version + 1,
+                ..session_info.clone()
+            })?;
 
             match self
                 .client
@@ -1478,6 +1749,63 @@ impl SessionStorage for DynamoDbSessionStorage {
         }
     }
 
+    async fn touch_session(&self, session_id: &str) -> Result<bool, Self::Error> {
+        #[cfg(feature = "dynamodb")]
+        {
+            let sa = self.session_attrs();
+            let key_attr = if self.config.single_table {
+                single_table::PARTITION_KEY
+            } else {
+                sa.session_id
+            };
+            let ttl = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                + (self.config.session_ttl_minutes * 60);
+
+            // Only the activity timestamp and TTL are written; state is left alone
+            let result = self
+                .client
+                .update_item()
+                .table_name(&self.config.table_name)
+                .set_key(Some(self.session_key(session_id)))
+                .update_expression("SET #last_activity = :timestamp, #ttl = :ttl")
+                .condition_expression("attribute_exists(#key)")
+                .expression_attribute_names("#last_activity", sa.last_activity)
+                .expression_attribute_names("#ttl", self.ttl_attribute())
+                .expression_attribute_names("#key", key_attr)
+                .expression_attribute_values(
+                    ":timestamp",
+                    AttributeValue::N(chrono::Utc::now().timestamp_millis().to_string()),
+                )
+                .expression_attribute_values(":ttl", AttributeValue::N(ttl.to_string()))
+                .send()
+                .await;
+
+            match result {
+                Ok(_) => {
+                    debug!("Touched session in DynamoDB: {}", session_id);
+                    Ok(true)
+                }
+                Err(err) if format!("{err:?}").contains("ConditionalCheckFailed") => Ok(false),
+                Err(err) => {
+                    error!("Failed to touch session in DynamoDB: {}", err);
+                    Err(SessionStorageError::DatabaseError(format!(
+                        "Failed to touch session '{}': {}",
+                        session_id, err
+                    )))
+                }
+            }
+        }
+
+        #[cfg(not(feature = "dynamodb"))]
+        {
+            debug!("Touching session in DynamoDB (placeholder): {}", session_id);
+            Ok(true)
+        }
+    }
+
     async fn set_session_state(
         &self,
         session_id: &str,
@@ -1491,10 +1819,7 @@ impl SessionStorage for DynamoDbSessionStorage {
             let sa = self.session_attrs();
 
             // First, get the current session to retrieve existing state
-            let session_key = HashMap::from([(
-                sa.session_id.to_string(),
-                AttributeValue::S(session_id.to_string()),
-            )]);
+            let session_key = self.session_key(session_id);
 
             let current_item = match self
                 .client
@@ -1534,10 +1859,12 @@ impl SessionStorage for DynamoDbSessionStorage {
                 .map_err(|e| SessionStorageError::SerializationError(e.to_string()))?;
 
             // Update the session with new state
-            let update_expression = "SET #state = :state, #last_activity = :timestamp";
+            let update_expression =
+                "SET #state = :state, #last_activity = :timestamp ADD #version :one";
             let expression_attribute_names = HashMap::from([
                 ("#state".to_string(), "state".to_string()),
                 ("#last_activity".to_string(), sa.last_activity.to_string()),
+                ("#version".to_string(), "version".to_string()),
             ]);
             let expression_attribute_values = HashMap::from([
                 (":state".to_string(), AttributeValue::S(updated_state_json)),
@@ -1545,6 +1872,7 @@ impl SessionStorage for DynamoDbSessionStorage {
                     ":timestamp".to_string(),
                     AttributeValue::N(chrono::Utc::now().timestamp_millis().to_string()),
                 ),
+                (":one".to_string(), AttributeValue::N("1".to_string())),
             ]);
 
             match self
@@ -1585,6 +1913,115 @@ impl SessionStorage for DynamoDbSessionStorage {
         }
     }
 
+    async fn set_session_state_versioned(
+        &self,
+        session_id: &str,
+        key: &str,
+        value: Value,
+        expected_version: u64,
+    ) -> Result<u64, Self::Error> {
+        #[cfg(feature = "dynamodb")]
+        {
+            use aws_sdk_dynamodb::types::ReturnValue;
+
+            let sa = self.session_attrs();
+            let session_key = self.session_key(session_id);
+
+            let current_item = self
+                .client
+                .get_item()
+                .table_name(&self.config.table_name)
+                .set_key(Some(session_key.clone()))
+                .consistent_read(true)
+                .send()
+                .await
+                .map_err(|err| {
+                    SessionStorageError::DatabaseError(format!(
+                        "Failed to get session '{}': {}",
+                        session_id, err
+                    ))
+                })?
+                .item
+                .ok_or_else(|| SessionStorageError::SessionNotFound(session_id.to_string()))?;
+
+            let mut current_state: HashMap<String, Value> = current_item
+                .get("state")
+                .and_then(|v| v.as_s().ok())
+                .map(|s| serde_json::from_str(s))
+                .transpose()
+                .map_err(|e| SessionStorageError::SerializationError(e.to_string()))?
+                .unwrap_or_default();
+            current_state.insert(key.to_string(), value);
+            let updated_state_json = serde_json::to_string(&current_state)
+                .map_err(|e| SessionStorageError::SerializationError(e.to_string()))?;
+
+            // Unversioned items read as version 0
+            let condition = if expected_version == 0 {
+                "attribute_not_exists(#version) OR #version = :expected"
+            } else {
+                "#version = :expected"
+            };
+
+            let result = self
+                .client
+                .update_item()
+                .table_name(&self.config.table_name)
+                .set_key(Some(session_key))
+                .update_expression(
+                    "SET #state = :state, #last_activity = :timestamp ADD #version :one",
+                )
+                .condition_expression(condition)
+                .expression_attribute_names("#state", "state")
+                .expression_attribute_names("#last_activity", sa.last_activity)
+                .expression_attribute_names("#version", "version")
+                .expression_attribute_values(":state", AttributeValue::S(updated_state_json))
+                .expression_attribute_values(
+                    ":timestamp",
+                    AttributeValue::N(chrono::Utc::now().timestamp_millis().to_string()),
+                )
+                .expression_attribute_values(":one", AttributeValue::N("1".to_string()))
+                .expression_attribute_values(
+                    ":expected",
+                    AttributeValue::N(expected_version.to_string()),
+                )
+                .return_values(ReturnValue::UpdatedNew)
+                .send()
+                .await;
+
+            match result {
+                Ok(output) => Ok(output
+                    .attributes
+                    .as_ref()
+                    .and_then(|attrs| attrs.get("version"))
+                    .and_then(|v| v.as_n().ok())
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or(expected_version + 1)),
+                Err(err) if format!("{err:?}").contains("ConditionalCheckFailed") => {
+                    Err(SessionStorageError::ConcurrentModification(format!(
+                        "session {} is no longer at version {}",
+                        session_id, expected_version
+                    )))
+                }
+                Err(err) => {
+                    error!("Failed to set versioned session state in DynamoDB: {}", err);
+                    Err(SessionStorageError::DatabaseError(format!(
+                        "Failed to set session state '{}' -> '{}': {}",
+                        session_id, key, err
+                    )))
+                }
+            }
+        }
+
+        #[cfg(not(feature = "dynamodb"))]
+        {
+            debug!(
+                "Setting versioned session state in DynamoDB (placeholder): {} -> {}",
+                session_id, key
+            );
+            Ok(expected_version + 1)
+        }
+    }
+
     async fn get_session_state(
         &self,
         session_id: &str,
@@ -1640,23 +2077,25 @@ impl SessionStorage for DynamoDbSessionStorage {
 
             if current_value.is_some() {
                 let sa = self.session_attrs();
-                let session_key = HashMap::from([(
-                    sa.session_id.to_string(),
-                    AttributeValue::S(session_id.to_string()),
-                )]);
+                let session_key = self.session_key(session_id);
 
                 // Use UpdateExpression to remove the key from the state map
                 let update_expression =
-                    "REMOVE #state.#key SET #last_activity = :timestamp".to_string();
+                    "REMOVE #state.#key SET #last_activity = :timestamp ADD #version :one"
+                        .to_string();
                 let expression_attribute_names = HashMap::from([
                     ("#state".to_string(), "state".to_string()),
                     ("#key".to_string(), key.to_string()),
                     ("#last_activity".to_string(), sa.last_activity.to_string()),
+                    ("#version".to_string(), "version".to_string()),
+                ]);
+                let expression_attribute_values = HashMap::from([
+                    (
+                        ":timestamp".to_string(),
+                        AttributeValue::N(chrono::Utc::now().timestamp_millis().to_string()),
+                    ),
+                    (":one".to_string(), AttributeValue::N("1".to_string())),
                 ]);
-                let expression_attribute_values = HashMap::from([(
-                    ":timestamp".to_string(),
-                    AttributeValue::N(chrono::Utc::now().timestamp_millis().to_string()),
-                )]);
 
                 match self
                     .client
@@ -1706,13 +2145,7 @@ impl SessionStorage for DynamoDbSessionStorage {
     async fn delete_session(&self, session_id: &str) -> Result<bool, Self::Error> {
         #[cfg(feature = "dynamodb")]
         {
-            use aws_sdk_dynamodb::types::AttributeValue;
-
-            let sa = self.session_attrs();
-            let key = HashMap::from([(
-                sa.session_id.to_string(),
-                AttributeValue::S(session_id.to_string()),
-            )]);
+            let key = self.session_key(session_id);
 
             match self
                 .client
@@ -1753,9 +2186,7 @@ impl SessionStorage for DynamoDbSessionStorage {
 
             // Note: Scan is expensive for large tables - consider using pagination
             match self
-                .client
-                .scan()
-                .table_name(&self.config.table_name)
+                .entity_scan(single_table::SESSION_ENTITY)
                 .projection_expression(self.session_attrs().session_id)
                 .send()
                 .await
@@ -1801,14 +2232,10 @@ impl SessionStorage for DynamoDbSessionStorage {
         {
             use aws_sdk_dynamodb::types::AttributeValue;
 
-            let sa = self.session_attrs();
             let ea = self.event_attrs();
 
             // Check if session exists first
-            let session_key = HashMap::from([(
-                sa.session_id.to_string(),
-                AttributeValue::S(session_id.to_string()),
-            )]);
+            let session_key = self.session_key(session_id);
 
             let session_exists = match self
                 .client
@@ -1827,11 +2254,8 @@ impl SessionStorage for DynamoDbSessionStorage {
                 return Err(SessionStorageError::SessionNotFound(session_id.to_string()));
             }
 
-            let event_table = format!("{}-events", self.config.table_name);
-
-            if self.config.verify_tables {
-                self.ensure_events_table_exists(&event_table).await?;
-            }
+            let event_table = self.events_table_name();
+            self.verify_events_table(&event_table).await?;
 
             let data_json = serde_json::to_string(&event.data)
                 .map_err(|e| SessionStorageError::SerializationError(e.to_string()))?;
@@ -1846,12 +2270,8 @@ impl SessionStorage for DynamoDbSessionStorage {
                 // Lambda instances; it does NOT prevent races — the condition_expression
                 // on the subsequent PutItem plus MAX_RETRIES is the race-control path.
                 let max_query = self
-                    .client
-                    .query()
-                    .table_name(&event_table)
+                    .event_query(&event_table, session_id)
                     .consistent_read(true)
-                    .key_condition_expression(format!("{} = :sid", ea.session_id))
-                    .expression_attribute_values(":sid", AttributeValue::S(session_id.to_string()))
                     .scan_index_forward(false) // descending — max first
                     .limit(1)
                     .projection_expression(ea.event_id)
@@ -1890,7 +2310,7 @@ impl SessionStorage for DynamoDbSessionStorage {
                     ),
                     ("data".to_string(), AttributeValue::S(data_json.clone())),
                     (
-                        "ttl".to_string(),
+                        self.ttl_attribute().to_string(),
                         AttributeValue::N(
                             (SystemTime::now()
                                 .duration_since(SystemTime::UNIX_EPOCH)
@@ -1906,13 +2326,31 @@ impl SessionStorage for DynamoDbSessionStorage {
                     item.insert("retry".to_string(), AttributeValue::N(retry.to_string()));
                 }
 
+                if self.config.single_table {
+                    item.insert(
+                        single_table::PARTITION_KEY.to_string(),
+                        AttributeValue::S(single_table::session_partition_key(session_id)),
+                    );
+                    item.insert(
+                        single_table::SORT_KEY.to_string(),
+                        AttributeValue::S(single_table::event_sort_key(event.id)),
+                    );
+                    item.insert(
+                        single_table::ENTITY_TYPE.to_string(),
+                        AttributeValue::S(single_table::EVENT_ENTITY.to_string()),
+                    );
+                }
+
                 // 3. Conditional PutItem — attribute_not_exists prevents duplicate eventId
                 match self
                     .client
                     .put_item()
                     .table_name(&event_table)
                     .set_item(Some(item))
-                    .condition_expression(format!("attribute_not_exists({})", ea.event_id))
+                    .condition_expression(format!(
+                        "attribute_not_exists({})",
+                        self.event_sort_key_attr(ea)
+                    ))
                     .send()
                     .await
                 {
@@ -1964,31 +2402,51 @@ impl SessionStorage for DynamoDbSessionStorage {
         {
             use aws_sdk_dynamodb::types::AttributeValue;
 
-            let event_table = format!("{}-events", self.config.table_name);
-
-            if self.config.verify_tables {
-                self.ensure_events_table_exists(&event_table).await?;
-            }
+            let event_table = self.events_table_name();
+            self.verify_events_table(&event_table).await?;
 
             let ea = self.event_attrs();
 
             // Query events for this session where event_id > after_event_id
-            let query_result = self
-                .client
-                .query()
-                .table_name(&event_table)
-                .key_condition_expression(format!(
-                    "{} = :session_id AND {} > :after_event_id",
-                    ea.session_id, ea.event_id
-                ))
-                .expression_attribute_values(
-                    ":session_id",
-                    AttributeValue::S(session_id.to_string()),
-                )
-                .expression_attribute_values(
-                    ":after_event_id",
-                    AttributeValue::N(after_event_id.to_string()),
-                )
+            let query = if self.config.single_table {
+                self.client
+                    .query()
+                    .table_name(&event_table)
+                    .key_condition_expression("#pk = :pk AND #sk BETWEEN :from AND :to")
+                    .expression_attribute_names("#pk", single_table::PARTITION_KEY)
+                    .expression_attribute_names("#sk", single_table::SORT_KEY)
+                    .expression_attribute_values(
+                        ":pk",
+                        AttributeValue::S(single_table::session_partition_key(session_id)),
+                    )
+                    .expression_attribute_values(
+                        ":from",
+                        AttributeValue::S(single_table::event_sort_key(
+                            after_event_id.saturating_add(1),
+                        )),
+                    )
+                    .expression_attribute_values(
+                        ":to",
+                        AttributeValue::S(single_table::event_sort_key(u64::MAX)),
+                    )
+            } else {
+                self.client
+                    .query()
+                    .table_name(&event_table)
+                    .key_condition_expression(format!(
+                        "{} = :session_id AND {} > :after_event_id",
+                        ea.session_id, ea.event_id
+                    ))
+                    .expression_attribute_values(
+                        ":session_id",
+                        AttributeValue::S(session_id.to_string()),
+                    )
+                    .expression_attribute_values(
+                        ":after_event_id",
+                        AttributeValue::N(after_event_id.to_string()),
+                    )
+            };
+            let query_result = query
                 .scan_index_forward(true) // Sort by event_id ascending
                 .consistent_read(true) // Use strongly consistent reads for resumability
                 .send()
@@ -2061,26 +2519,14 @@ impl SessionStorage for DynamoDbSessionStorage {
     ) -> Result<Vec<SseEvent>, Self::Error> {
         #[cfg(feature = "dynamodb")]
         {
-            use aws_sdk_dynamodb::types::AttributeValue;
-
-            let event_table = format!("{}-events", self.config.table_name);
-
-            if self.config.verify_tables {
-                self.ensure_events_table_exists(&event_table).await?;
-            }
+            let event_table = self.events_table_name();
+            self.verify_events_table(&event_table).await?;
 
             let ea = self.event_attrs();
 
             // Query recent events for this session, ordered by event_id DESC (most recent first)
             let query_result = self
-                .client
-                .query()
-                .table_name(&event_table)
-                .key_condition_expression(format!("{} = :session_id", ea.session_id))
-                .expression_attribute_values(
-                    ":session_id",
-                    AttributeValue::S(session_id.to_string()),
-                )
+                .event_query(&event_table, session_id)
                 .scan_index_forward(false) // Sort by event_id descending (most recent first)
                 .limit(limit as i32)
                 .consistent_read(true) // Use strongly consistent reads to see just-written events
@@ -2224,9 +2670,7 @@ impl SessionStorage for DynamoDbSessionStorage {
             debug!("Would scan DynamoDB table to count sessions");
 
             match self
-                .client
-                .scan()
-                .table_name(&self.config.table_name)
+                .entity_scan(single_table::SESSION_ENTITY)
                 .select(aws_sdk_dynamodb::types::Select::Count)
                 .send()
                 .await
@@ -2257,7 +2701,7 @@ impl SessionStorage for DynamoDbSessionStorage {
         #[cfg(feature = "dynamodb")]
         {
             // In production with separate events table, this would scan that table
-            let event_table = format!("{}-events", self.config.table_name);
+            let event_table = self.events_table_name();
             debug!("Would count events in DynamoDB table: {}", event_table);
 
             // For now, return 0 as we're not implementing the full events table
@@ -2576,6 +3020,42 @@ mod tests {
         assert_eq!(restored.is_initialized, session.is_initialized);
     }
 
+    #[tokio::test]
+    async fn test_single_table_session_item_round_trip() {
+        let mut storage =
+            storage_with_naming(NamingConvention::CamelCase, NamingConvention::CamelCase).await;
+        storage.config.single_table = true;
+        let session = SessionInfo::with_id("abc".to_string());
+        let item = storage.session_to_dynamodb_item(&session).unwrap();
+
+        assert_eq!(item["PK"].as_s().unwrap(), "SESSION#abc");
+        assert_eq!(item["SK"].as_s().unwrap(), "SESSION");
+        assert_eq!(item["entityType"].as_s().unwrap(), "session");
+        assert!(item.contains_key("ttlEpoch"));
+        assert!(
+            !item.contains_key("ttl"),
+            "ttl is reserved for task items in the shared table"
+        );
+        assert_eq!(storage.session_key("abc").len(), 2);
+        assert_eq!(storage.events_table_name(), storage.config.table_name);
+
+        let restored = storage.dynamodb_item_to_session(&item).unwrap();
+        assert_eq!(restored.session_id, "abc");
+        assert_eq!(restored.created_at, session.created_at);
+    }
+
+    #[test]
+    fn test_single_table_event_sort_keys_order_numerically() {
+        assert_eq!(
+            single_table::event_sort_key(7),
+            "EVENT#00000000000000000007"
+        );
+        assert!(single_table::event_sort_key(9) < single_table::event_sort_key(10));
+        assert!(
+            single_table::event_sort_key(u64::MAX - 1) < single_table::event_sort_key(u64::MAX)
+        );
+    }
+
     #[tokio::test]
     async fn test_snake_case_session_item_round_trip() {
         let storage =
@@ -2739,7 +3219,10 @@ mod tests {
         // Last-Event-ID replay from any instance.
         let event = SseEvent::new("message".to_string(), serde_json::json!({"n": 1}));
         writer.store_event(&session_id, event).await.unwrap();
-        let recent = cold_reader.get_recent_events(&session_id, 10).await.unwrap();
+        let recent = cold_reader
+            .get_recent_events(&session_id, 10)
+            .await
+            .unwrap();
         assert!(
             !recent.is_empty(),
             "RYW violation: stored event not visible to fresh reader"
@@ -2748,4 +3231,24 @@ mod tests {
         // Cleanup
         writer.delete_session(&session_id).await.unwrap();
     }
+
+    // === Parity tests ===
+
+    #[tokio::test]
+    #[ignore = "requires DynamoDB"]
+    async fn parity_touch_updates_activity_only() {
+        let storage = DynamoDbSessionStorage::with_config(DynamoDbConfig::default())
+            .await
+            .unwrap();
+        crate::parity_tests::test_touch_updates_activity_only(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "requires DynamoDB"]
+    async fn parity_versioned_state_rejects_stale_writer() {
+        let storage = DynamoDbSessionStorage::with_config(DynamoDbConfig::default())
+            .await
+            .unwrap();
+        crate::parity_tests::test_versioned_state_rejects_stale_writer(&storage).await;
+    }
 }
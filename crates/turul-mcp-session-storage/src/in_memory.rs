@@ -15,7 +15,7 @@ use async_trait::async_trait;
 use tokio::sync::RwLock;
 use tracing::{debug, info};
 
-use crate::{SessionInfo, SessionStorage, SessionStorageError, SseEvent};
+use crate::{SessionInfo, SessionSnapshot, SessionStorage, SessionStorageError, SseEvent};
 use turul_mcp_protocol::ServerCapabilities;
 
 /// In-memory storage for sessions and events (SSE compliant)
@@ -197,12 +197,26 @@ impl SessionStorage for InMemorySessionStorage {
         Ok(sessions.get(session_id).cloned())
     }
 
-    async fn update_session(&self, session_info: SessionInfo) -> Result<(), Self::Error> {
+    async fn update_session(&self, mut session_info: SessionInfo) -> Result<(), Self::Error> {
         let mut sessions = self.sessions.write().await;
+        if let Some(current) = sessions.get(&session_info.session_id) {
+            session_info.version = current.version + 1;
+        }
         sessions.insert(session_info.session_id.clone(), session_info);
         Ok(())
     }
 
+    async fn touch_session(&self, session_id: &str) -> Result<bool, Self::Error> {
+        let mut sessions = self.sessions.write().await;
+        match sessions.get_mut(session_id) {
+            Some(session) => {
+                session.touch();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     async fn set_session_state(
         &self,
         session_id: &str,
@@ -213,6 +227,7 @@ impl SessionStorage for InMemorySessionStorage {
 
         if let Some(session) = sessions.get_mut(session_id) {
             session.state.insert(key.to_string(), value);
+            session.version += 1;
             session.touch(); // Update last activity
             Ok(())
         } else {
@@ -220,6 +235,30 @@ impl SessionStorage for InMemorySessionStorage {
         }
     }
 
+    async fn set_session_state_versioned(
+        &self,
+        session_id: &str,
+        key: &str,
+        value: serde_json::Value,
+        expected_version: u64,
+    ) -> Result<u64, Self::Error> {
+        let mut sessions = self.sessions.write().await;
+
+        let Some(session) = sessions.get_mut(session_id) else {
+            return Err(SessionStorageError::SessionNotFound(session_id.to_string()));
+        };
+        if session.version != expected_version {
+            return Err(SessionStorageError::ConcurrentModification(format!(
+                "session {} is at version {}, expected {}",
+                session_id, session.version, expected_version
+            )));
+        }
+        session.state.insert(key.to_string(), value);
+        session.version += 1;
+        session.touch();
+        Ok(session.version)
+    }
+
     async fn get_session_state(
         &self,
         session_id: &str,
@@ -243,6 +282,7 @@ impl SessionStorage for InMemorySessionStorage {
 
         if let Some(session) = sessions.get_mut(session_id) {
             let removed = session.state.remove(key);
+            session.version += 1;
             session.touch(); // Update last activity
             Ok(removed)
         } else {
@@ -267,6 +307,30 @@ impl SessionStorage for InMemorySessionStorage {
         Ok(removed)
     }
 
+    async fn import_session(&self, snapshot: SessionSnapshot) -> Result<SessionInfo, Self::Error> {
+        let session = snapshot.session;
+        let session_id = session.session_id.clone();
+        let mut sessions = self.sessions.write().await;
+        let mut events = self.events.write().await;
+
+        if !sessions.contains_key(&session_id) && sessions.len() >= self.config.max_sessions {
+            return Err(SessionStorageError::MaxSessionsReached(
+                self.config.max_sessions,
+            ));
+        }
+
+        // Keep the original event IDs so Last-Event-ID resumption replays as it did
+        if let Some(last_id) = snapshot.events.iter().map(|event| event.id).max() {
+            self.event_counter.fetch_max(last_id + 1, Ordering::SeqCst);
+        }
+
+        sessions.insert(session_id.clone(), session.clone());
+        events.insert(session_id.clone(), snapshot.events);
+
+        debug!("Imported session snapshot: {}", session_id);
+        Ok(session)
+    }
+
     async fn list_sessions(&self) -> Result<Vec<String>, Self::Error> {
         let sessions = self.sessions.read().await;
         Ok(sessions.keys().cloned().collect())
@@ -510,4 +574,18 @@ mod tests {
         let recent = storage.get_recent_events(&session_id, 10).await.unwrap();
         assert_eq!(recent.len(), 2);
     }
+
+    // === Parity tests ===
+
+    #[tokio::test]
+    async fn parity_touch_updates_activity_only() {
+        let storage = InMemorySessionStorage::new();
+        crate::parity_tests::test_touch_updates_activity_only(&storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_versioned_state_rejects_stale_writer() {
+        let storage = InMemorySessionStorage::new();
+        crate::parity_tests::test_versioned_state_rejects_stale_writer(&storage).await;
+    }
 }
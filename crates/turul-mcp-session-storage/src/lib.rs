@@ -28,6 +28,15 @@ pub use traits::*;
 mod session_view;
 pub use session_view::SessionView;
 
+mod snapshot;
+pub use snapshot::{REDACTED_VALUE, RedactionPolicy, SESSION_SNAPSHOT_VERSION, SessionSnapshot};
+
+mod migration;
+pub use migration::{MIGRATED_FROM_METADATA_KEY, migrate_sessions};
+
+mod cached;
+pub use cached::{CachedSessionStorage, SessionCacheConfig};
+
 // Implementations
 pub mod in_memory;
 pub mod prelude;
@@ -41,22 +50,40 @@ pub mod postgres;
 #[cfg(feature = "dynamodb")]
 pub mod dynamodb;
 
+#[cfg(feature = "audit")]
+pub mod audit;
+
+// Parity test suite (shared across all backends)
+#[cfg(test)]
+pub(crate) mod parity_tests;
+
 // Re-export for convenience
 /// In-memory session storage implementation for development and testing
 pub use in_memory::{InMemoryConfig, InMemoryError, InMemorySessionStorage, InMemoryStats};
 
 #[cfg(feature = "sqlite")]
 /// SQLite-backed session storage for file-based persistence
-pub use sqlite::{SqliteConfig, SqliteError, SqliteSessionStorage};
+pub use sqlite::{SqliteConfig, SqliteError, SqliteSessionStorage, SqliteVacuumMode};
 
 #[cfg(feature = "postgres")]
 /// PostgreSQL-backed session storage for production deployments
-pub use postgres::{PostgresConfig, PostgresError, PostgresSessionStorage};
+pub use postgres::{PoolStats, PostgresConfig, PostgresError, PostgresSessionStorage};
 
 #[cfg(feature = "dynamodb")]
 /// DynamoDB-backed session storage for AWS serverless deployments
 pub use dynamodb::{DynamoDbConfig, DynamoDbError, DynamoDbSessionStorage};
 
+#[cfg(feature = "audit")]
+/// Audit trail of tool invocations with pluggable sinks
+pub use audit::{
+    AuditArgumentMode, AuditError, AuditLog, AuditOutcome, AuditRecord, AuditSink,
+    InMemoryAuditSink,
+};
+
+#[cfg(all(feature = "audit", feature = "sqlite"))]
+/// SQLite-backed audit sink
+pub use audit::SqliteAuditSink;
+
 /// Convenience type alias for session storage results
 pub type StorageResult<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
@@ -0,0 +1,216 @@
+//! Moving live sessions between storage backends.
+//!
+//! [`migrate_sessions`] copies every session from one backend to another, e.g. when
+//! promoting a deployment from SQLite to PostgreSQL, so connected clients keep their
+//! state instead of having to re-initialize.
+
+use serde_json::json;
+use tracing::{debug, info};
+
+use crate::StorageResult;
+use crate::traits::{SessionStorage, SessionStorageError};
+
+/// Session metadata key marking a completed migration, set to the source backend name.
+///
+/// It is written after the session's state and events, so a session that reached the
+/// destination without it was cut off mid-import.
+pub const MIGRATED_FROM_METADATA_KEY: &str = "__migration__:source";
+
+/// Copy all sessions, with their state, metadata, and retained events, from `from` to `to`.
+///
+/// Each session goes through [`export_session`](SessionStorage::export_session) on the
+/// source and [`import_session`](SessionStorage::import_session) on the destination, then
+/// gets [`MIGRATED_FROM_METADATA_KEY`] as its last write. Sessions in the destination that
+/// carry the marker are skipped, so an interrupted migration can simply be re-run; a
+/// session left half-imported by the interruption has no marker and is imported again.
+/// Sessions that disappear from the source mid-run are skipped too.
+///
+/// Returns the number of sessions migrated by this call. Destinations that allocate
+/// their own event IDs (everything except the in-memory backend) renumber events, so
+/// clients resuming with a `Last-Event-ID` from the old backend may see a replay gap.
+/// SQL backends also stamp `created_at` at import time rather than keeping the original.
+///
+/// ```rust,no_run
+/// use turul_mcp_session_storage::{InMemorySessionStorage, migrate_sessions};
+///
+/// # async fn example(dev: &InMemorySessionStorage, prod: &InMemorySessionStorage) -> turul_mcp_session_storage::StorageResult<()> {
+/// let migrated = migrate_sessions(dev, prod).await?;
+/// println!("Migrated {migrated} sessions");
+/// # Ok(())
+/// # }
+/// ```
+pub async fn migrate_sessions(
+    from: &dyn SessionStorage<Error = SessionStorageError>,
+    to: &dyn SessionStorage<Error = SessionStorageError>,
+) -> StorageResult<u64> {
+    let session_ids = from.list_sessions().await?;
+    let mut migrated = 0u64;
+
+    for session_id in session_ids {
+        match to.get_session(&session_id).await? {
+            Some(existing) if existing.metadata.contains_key(MIGRATED_FROM_METADATA_KEY) => {
+                debug!(
+                    "Session {} already in {}, skipping",
+                    session_id,
+                    to.backend_name()
+                );
+                continue;
+            }
+            Some(_) => debug!(
+                "Session {} only partially imported into {}, importing again",
+                session_id,
+                to.backend_name()
+            ),
+            None => {}
+        }
+        let Some(mut snapshot) = from.export_session(&session_id).await? else {
+            debug!("Session {} removed during migration, skipping", session_id);
+            continue;
+        };
+        // A marker from an earlier hop must not make this import look complete
+        snapshot.session.metadata.remove(MIGRATED_FROM_METADATA_KEY);
+
+        let mut session = to.import_session(snapshot).await?;
+        session.metadata.insert(
+            MIGRATED_FROM_METADATA_KEY.to_string(),
+            json!(from.backend_name()),
+        );
+        to.update_session(session).await?;
+        migrated += 1;
+    }
+
+    info!(
+        "Migrated {} sessions from {} to {}",
+        migrated,
+        from.backend_name(),
+        to.backend_name()
+    );
+    Ok(migrated)
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::{InMemorySessionStorage, SqliteConfig, SqliteSessionStorage, SseEvent};
+    use serde_json::json;
+    use turul_mcp_protocol::ServerCapabilities;
+
+    #[tokio::test]
+    async fn test_migrate_in_memory_to_sqlite() {
+        let source = InMemorySessionStorage::new();
+        let mut session_ids = Vec::new();
+        for n in 0..3 {
+            let mut session = source
+                .create_session(ServerCapabilities::default())
+                .await
+                .unwrap();
+            session.is_initialized = true;
+            session.state.insert("user".to_string(), json!(n));
+            session
+                .metadata
+                .insert("user_agent".to_string(), json!("dev-client/1.0"));
+            source.update_session(session.clone()).await.unwrap();
+            for i in 0..=n {
+                source
+                    .store_event(
+                        &session.session_id,
+                        SseEvent::new("message".to_string(), json!({"i": i})),
+                    )
+                    .await
+                    .unwrap();
+            }
+            session_ids.push(session.session_id);
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let destination = SqliteSessionStorage::with_config(SqliteConfig {
+            database_path: dir.path().join("sessions.db"),
+            verify_tables: true,
+            create_tables: true,
+            ..SqliteConfig::default()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(migrate_sessions(&source, &destination).await.unwrap(), 3);
+        assert_eq!(destination.session_count().await.unwrap(), 3);
+
+        for session_id in &session_ids {
+            let original = source.get_session(session_id).await.unwrap().unwrap();
+            let migrated = destination.get_session(session_id).await.unwrap().unwrap();
+            assert_eq!(migrated.state, original.state);
+            let mut metadata = migrated.metadata.clone();
+            assert_eq!(
+                metadata.remove(MIGRATED_FROM_METADATA_KEY),
+                Some(json!(source.backend_name()))
+            );
+            assert_eq!(metadata, original.metadata);
+            assert_eq!(migrated.is_initialized, original.is_initialized);
+
+            let original_events = source.get_events_after(session_id, 0).await.unwrap();
+            let migrated_events = destination.get_events_after(session_id, 0).await.unwrap();
+            assert_eq!(migrated_events.len(), original_events.len());
+            for (migrated, original) in migrated_events.iter().zip(&original_events) {
+                assert_eq!(migrated.event_type, original.event_type);
+                assert_eq!(migrated.data, original.data);
+            }
+        }
+
+        // Re-running only picks up sessions created since
+        source
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        assert_eq!(migrate_sessions(&source, &destination).await.unwrap(), 1);
+        assert_eq!(migrate_sessions(&source, &destination).await.unwrap(), 0);
+        assert_eq!(destination.session_count().await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_rerun_reimports_partially_imported_session() {
+        let source = InMemorySessionStorage::new();
+        let mut session = source
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        session.is_initialized = true;
+        session.state.insert("cart".to_string(), json!(["apple"]));
+        source.update_session(session.clone()).await.unwrap();
+        for i in 0..3 {
+            source
+                .store_event(
+                    &session.session_id,
+                    SseEvent::new("message".to_string(), json!({"i": i})),
+                )
+                .await
+                .unwrap();
+        }
+
+        // The previous run died right after `import_session` created the session
+        let destination = InMemorySessionStorage::new();
+        destination
+            .create_session_with_id(session.session_id.clone(), ServerCapabilities::default())
+            .await
+            .unwrap();
+
+        assert_eq!(migrate_sessions(&source, &destination).await.unwrap(), 1);
+        let migrated = destination
+            .get_session(&session.session_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(migrated.state, session.state);
+        assert!(migrated.is_initialized);
+        assert_eq!(
+            destination
+                .get_events_after(&session.session_id, 0)
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+
+        // Once complete, the session is left alone
+        assert_eq!(migrate_sessions(&source, &destination).await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,129 @@
+//! Shared parity test functions for session storage backends.
+//!
+//! Each test takes a `&dyn SessionStorage` so the same assertions apply to InMemory,
+//! SQLite, PostgreSQL, and DynamoDB backends. Backend-specific test modules call
+//! these functions with their own storage instance.
+//!
+//! This module is `pub(crate)` and only compiled in test builds.
+
+use crate::traits::{SessionStorage, SessionStorageError};
+use serde_json::json;
+use turul_mcp_protocol::ServerCapabilities;
+
+/// `touch_session` moves `last_activity` forward and leaves everything else alone.
+pub async fn test_touch_updates_activity_only(
+    storage: &dyn SessionStorage<Error = SessionStorageError>,
+) {
+    let session = storage
+        .create_session(ServerCapabilities::default())
+        .await
+        .unwrap();
+    let session_id = session.session_id.clone();
+    storage
+        .set_session_state(&session_id, "counter", json!(7))
+        .await
+        .unwrap();
+
+    // Backdate activity so the touch is observable at millisecond resolution
+    let mut stale = storage.get_session(&session_id).await.unwrap().unwrap();
+    stale.last_activity -= 60_000;
+    stale.metadata.insert("client".to_string(), json!("parity"));
+    storage.update_session(stale.clone()).await.unwrap();
+
+    assert!(storage.touch_session(&session_id).await.unwrap());
+
+    let touched = storage.get_session(&session_id).await.unwrap().unwrap();
+    assert!(touched.last_activity > stale.last_activity);
+    assert_eq!(touched.state, stale.state);
+    assert_eq!(touched.state.get("counter"), Some(&json!(7)));
+    assert_eq!(touched.metadata, stale.metadata);
+    assert_eq!(touched.is_initialized, stale.is_initialized);
+    assert_eq!(touched.created_at, stale.created_at);
+
+    assert!(
+        !storage
+            .touch_session("parity-missing-session")
+            .await
+            .unwrap()
+    );
+
+    storage.delete_session(&session_id).await.unwrap();
+}
+
+/// Versioned writes succeed against the current version and reject stale writers.
+pub async fn test_versioned_state_rejects_stale_writer(
+    storage: &dyn SessionStorage<Error = SessionStorageError>,
+) {
+    let session = storage
+        .create_session(ServerCapabilities::default())
+        .await
+        .unwrap();
+    let session_id = session.session_id.clone();
+    let read = storage.get_session(&session_id).await.unwrap().unwrap();
+
+    let v1 = storage
+        .set_session_state_versioned(&session_id, "cart", json!(["apple"]), read.version)
+        .await
+        .unwrap();
+    assert_eq!(v1, read.version + 1);
+    assert_eq!(
+        storage
+            .get_session(&session_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .version,
+        v1
+    );
+
+    // Two writers read the same version; exactly one of them lands
+    let (first, second) = tokio::join!(
+        storage.set_session_state_versioned(&session_id, "cart", json!(["apple", "pear"]), v1),
+        storage.set_session_state_versioned(&session_id, "cart", json!(["apple", "fig"]), v1),
+    );
+    let (winner, loser) = match (first, second) {
+        (Ok(version), Err(err)) => ((version, json!(["apple", "pear"])), err),
+        (Err(err), Ok(version)) => ((version, json!(["apple", "fig"])), err),
+        (first, second) => panic!("expected one conflict, got {first:?} and {second:?}"),
+    };
+    assert!(matches!(
+        loser,
+        SessionStorageError::ConcurrentModification(_)
+    ));
+    assert_eq!(winner.0, v1 + 1);
+    let current = storage.get_session(&session_id).await.unwrap().unwrap();
+    assert_eq!(current.version, winner.0);
+    assert_eq!(current.state.get("cart"), Some(&winner.1));
+
+    // Unversioned writes still move the version on
+    storage
+        .set_session_state(&session_id, "other", json!(true))
+        .await
+        .unwrap();
+    let after = storage.get_session(&session_id).await.unwrap().unwrap();
+    assert!(after.version > winner.0);
+    let stale = storage
+        .set_session_state_versioned(&session_id, "cart", json!([]), winner.0)
+        .await;
+    assert!(matches!(
+        stale,
+        Err(SessionStorageError::ConcurrentModification(_))
+    ));
+    assert_eq!(
+        storage
+            .get_session_state(&session_id, "cart")
+            .await
+            .unwrap(),
+        Some(winner.1)
+    );
+
+    let missing = storage
+        .set_session_state_versioned("parity-missing-session", "cart", json!([]), 1)
+        .await;
+    assert!(matches!(
+        missing,
+        Err(SessionStorageError::SessionNotFound(_))
+    ));
+
+    storage.delete_session(&session_id).await.unwrap();
+}
@@ -8,6 +8,7 @@
 //! ```
 
 // Core trait and types
+pub use crate::snapshot::{RedactionPolicy, SessionSnapshot};
 pub use crate::traits::{
     BoxedSessionStorage, SessionInfo, SessionStorage, SessionStorageBuilder, SessionStorageError,
     SseEvent,
@@ -18,16 +19,16 @@ pub use crate::in_memory::{InMemoryConfig, InMemoryError, InMemorySessionStorage
 
 // Optional implementations
 #[cfg(feature = "sqlite")]
-pub use crate::sqlite::{SqliteConfig, SqliteError, SqliteSessionStorage};
+pub use crate::sqlite::{SqliteConfig, SqliteError, SqliteSessionStorage, SqliteVacuumMode};
 
 #[cfg(feature = "postgres")]
-pub use crate::postgres::{PostgresConfig, PostgresError, PostgresSessionStorage};
+pub use crate::postgres::{PoolStats, PostgresConfig, PostgresError, PostgresSessionStorage};
 
 #[cfg(feature = "dynamodb")]
 pub use crate::dynamodb::{DynamoDbConfig, DynamoDbError, DynamoDbSessionStorage};
 
 // Convenience functions
-pub use crate::{StorageResult, create_default_storage, create_memory_storage};
+pub use crate::{StorageResult, create_default_storage, create_memory_storage, migrate_sessions};
 
 #[cfg(feature = "sqlite")]
 pub use crate::{create_sqlite_storage, create_sqlite_storage_with_config};
@@ -0,0 +1,282 @@
+//! Session snapshots for diagnostics and reproduction.
+//!
+//! [`SessionStorage::export_session`](crate::SessionStorage::export_session) captures a
+//! session's info, state, metadata, and retained SSE events as a [`SessionSnapshot`];
+//! [`SessionStorage::import_session`](crate::SessionStorage::import_session) recreates it
+//! in another store, typically a dev machine's in-memory backend. Snapshots are a
+//! debugging aid, not a migration format: they cover one session and carry a
+//! [`SESSION_SNAPSHOT_VERSION`] so older tooling refuses dumps it cannot read.
+//!
+//! Notification subscriptions are held by the HTTP layer's stream manager, not in
+//! session storage, so they are not part of a snapshot.
+//!
+//! ```rust
+//! use turul_mcp_session_storage::{RedactionPolicy, SessionSnapshot};
+//! use turul_mcp_session_storage::prelude::*;
+//!
+//! # async fn example(storage: &InMemorySessionStorage) -> Result<(), SessionStorageError> {
+//! if let Some(mut snapshot) = storage.export_session("session-123").await? {
+//!     snapshot.redact(&RedactionPolicy::new().state_key("api_token"));
+//!     let json = snapshot.to_json()?;
+//!
+//!     // Later, on a dev machine
+//!     let dev_storage = InMemorySessionStorage::new();
+//!     dev_storage.import_session(SessionSnapshot::from_json(&json)?).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+
+use crate::traits::{SessionInfo, SessionStorageError, SseEvent};
+
+/// Snapshot format version written by [`SessionSnapshot::new`].
+pub const SESSION_SNAPSHOT_VERSION: u32 = 1;
+
+/// Value substituted for redacted state and metadata entries.
+pub const REDACTED_VALUE: &str = "[REDACTED]";
+
+/// Serializable dump of one session for debugging and reproduction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    /// Snapshot format version (see [`SESSION_SNAPSHOT_VERSION`])
+    pub format_version: u32,
+    /// When the snapshot was taken (Unix millis)
+    pub exported_at: u64,
+    /// Backend the session was exported from (e.g. "InMemory", "SQLite")
+    pub source_backend: String,
+    /// Session info, including capabilities, state, and metadata
+    pub session: SessionInfo,
+    /// Retained SSE events, oldest first
+    pub events: Vec<SseEvent>,
+    /// Entries replaced by [`SessionSnapshot::redact`], as `state.<key>` / `metadata.<key>`
+    #[serde(default)]
+    pub redacted: Vec<String>,
+}
+
+impl SessionSnapshot {
+    /// Build a snapshot of `session` and its `events`, stamped with the current time.
+    pub fn new(source_backend: &str, session: SessionInfo, events: Vec<SseEvent>) -> Self {
+        Self {
+            format_version: SESSION_SNAPSHOT_VERSION,
+            exported_at: chrono::Utc::now().timestamp_millis() as u64,
+            source_backend: source_backend.to_string(),
+            session,
+            events,
+            redacted: Vec::new(),
+        }
+    }
+
+    /// Replace the state and metadata values named by `policy` with [`REDACTED_VALUE`].
+    pub fn redact(&mut self, policy: &RedactionPolicy) {
+        redact_entries(
+            &mut self.session.state,
+            &policy.state_keys,
+            "state",
+            &mut self.redacted,
+        );
+        redact_entries(
+            &mut self.session.metadata,
+            &policy.metadata_keys,
+            "metadata",
+            &mut self.redacted,
+        );
+    }
+
+    /// Serialize as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, SessionStorageError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parse a snapshot, rejecting format versions newer than this build understands.
+    pub fn from_json(json: &str) -> Result<Self, SessionStorageError> {
+        let snapshot: Self = serde_json::from_str(json)?;
+        if snapshot.format_version > SESSION_SNAPSHOT_VERSION {
+            return Err(SessionStorageError::InvalidData(format!(
+                "Unsupported session snapshot version {} (supported: {})",
+                snapshot.format_version, SESSION_SNAPSHOT_VERSION
+            )));
+        }
+        Ok(snapshot)
+    }
+}
+
+fn redact_entries(
+    entries: &mut HashMap<String, Value>,
+    keys: &BTreeSet<String>,
+    section: &str,
+    redacted: &mut Vec<String>,
+) {
+    for key in keys {
+        if let Some(value) = entries.get_mut(key) {
+            *value = Value::String(REDACTED_VALUE.to_string());
+            redacted.push(format!("{}.{}", section, key));
+        }
+    }
+}
+
+/// Which session state and metadata keys to blank out before a snapshot is shared.
+#[derive(Debug, Clone, Default)]
+pub struct RedactionPolicy {
+    state_keys: BTreeSet<String>,
+    metadata_keys: BTreeSet<String>,
+}
+
+impl RedactionPolicy {
+    /// Create a policy that redacts nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Redact the session state entry `key`.
+    pub fn state_key(mut self, key: impl Into<String>) -> Self {
+        self.state_keys.insert(key.into());
+        self
+    }
+
+    /// Redact the session metadata entry `key`.
+    pub fn metadata_key(mut self, key: impl Into<String>) -> Self {
+        self.metadata_keys.insert(key.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InMemorySessionStorage, SessionStorage};
+    use serde_json::json;
+    use turul_mcp_protocol::{ClientCapabilities, ServerCapabilities};
+
+    async fn populated_storage() -> (InMemorySessionStorage, String) {
+        let storage = InMemorySessionStorage::new();
+        let mut session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        session.client_capabilities = Some(ClientCapabilities::default());
+        session.is_initialized = true;
+        session
+            .state
+            .insert("cart".to_string(), json!({"items": [1, 2, 3]}));
+        session
+            .state
+            .insert("api_token".to_string(), json!("secret-token"));
+        session
+            .metadata
+            .insert("user_agent".to_string(), json!("test-client/1.0"));
+        storage.update_session(session.clone()).await.unwrap();
+
+        for n in 0..3 {
+            storage
+                .store_event(
+                    &session.session_id,
+                    SseEvent::new("message".to_string(), json!({"n": n})),
+                )
+                .await
+                .unwrap();
+        }
+
+        (storage, session.session_id)
+    }
+
+    #[tokio::test]
+    async fn test_export_import_round_trip() {
+        let (storage, session_id) = populated_storage().await;
+        let original = storage.get_session(&session_id).await.unwrap().unwrap();
+        let original_events = storage.get_events_after(&session_id, 0).await.unwrap();
+
+        let snapshot = storage.export_session(&session_id).await.unwrap().unwrap();
+        assert_eq!(snapshot.format_version, SESSION_SNAPSHOT_VERSION);
+        assert_eq!(snapshot.source_backend, "InMemory");
+        assert_eq!(snapshot.events.len(), 3);
+
+        let json = snapshot.to_json().unwrap();
+        let fresh = InMemorySessionStorage::new();
+        fresh
+            .import_session(SessionSnapshot::from_json(&json).unwrap())
+            .await
+            .unwrap();
+
+        let imported = fresh.get_session(&session_id).await.unwrap().unwrap();
+        assert_eq!(imported.state, original.state);
+        assert_eq!(imported.metadata, original.metadata);
+        assert_eq!(imported.created_at, original.created_at);
+        assert_eq!(imported.last_activity, original.last_activity);
+        assert!(imported.is_initialized);
+        assert!(imported.client_capabilities.is_some());
+
+        let imported_events = fresh.get_events_after(&session_id, 0).await.unwrap();
+        assert_eq!(imported_events.len(), original_events.len());
+        for (imported, original) in imported_events.iter().zip(&original_events) {
+            assert_eq!(imported.id, original.id);
+            assert_eq!(imported.timestamp, original.timestamp);
+            assert_eq!(imported.event_type, original.event_type);
+            assert_eq!(imported.data, original.data);
+        }
+
+        // Resuming from a recorded Last-Event-ID replays the same tail
+        let tail = fresh
+            .get_events_after(&session_id, original_events[0].id)
+            .await
+            .unwrap();
+        assert_eq!(tail.len(), 2);
+
+        // New events continue after the imported ones
+        let next = fresh
+            .store_event(&session_id, SseEvent::new("message".to_string(), json!({})))
+            .await
+            .unwrap();
+        assert!(next.id > original_events[2].id);
+    }
+
+    #[tokio::test]
+    async fn test_export_missing_session() {
+        let storage = InMemorySessionStorage::new();
+        assert!(storage.export_session("missing").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_redaction_policy() {
+        let (storage, session_id) = populated_storage().await;
+        let mut snapshot = storage.export_session(&session_id).await.unwrap().unwrap();
+
+        snapshot.redact(
+            &RedactionPolicy::new()
+                .state_key("api_token")
+                .state_key("not_present")
+                .metadata_key("user_agent"),
+        );
+
+        assert_eq!(snapshot.session.state["api_token"], json!(REDACTED_VALUE));
+        assert_eq!(snapshot.session.state["cart"], json!({"items": [1, 2, 3]}));
+        assert_eq!(
+            snapshot.session.metadata["user_agent"],
+            json!(REDACTED_VALUE)
+        );
+        assert_eq!(
+            snapshot.redacted,
+            vec![
+                "state.api_token".to_string(),
+                "metadata.user_agent".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_newer_format_version() {
+        let mut snapshot = SessionSnapshot::new("InMemory", SessionInfo::new(), Vec::new());
+        snapshot.format_version = SESSION_SNAPSHOT_VERSION + 1;
+        let json = serde_json::to_string(&snapshot).unwrap();
+
+        match SessionSnapshot::from_json(&json) {
+            Err(SessionStorageError::InvalidData(message)) => {
+                assert!(message.contains("Unsupported session snapshot version"))
+            }
+            other => panic!("expected InvalidData, got {:?}", other),
+        }
+    }
+}
@@ -6,9 +6,11 @@
 
 use async_trait::async_trait;
 use serde_json::Value;
-use sqlx::{Row, SqlitePool, sqlite::SqliteConnectOptions};
+use sqlx::sqlite::{SqliteAutoVacuum, SqliteConnectOptions};
+use sqlx::{Row, SqlitePool};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::SystemTime;
 use thiserror::Error;
 use tracing::{debug, info, warn};
@@ -35,6 +37,23 @@ pub enum SqliteError {
     Migration(String),
 }
 
+/// How [`SqliteSessionStorage::compact`] reclaims free pages left by deletions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqliteVacuumMode {
+    /// Rebuild the database file with `VACUUM`.
+    ///
+    /// Rewrites every live page and needs up to the database size again in temporary
+    /// disk space, so its I/O cost grows with the whole database, not just the garbage.
+    #[default]
+    Full,
+    /// Release free pages with `PRAGMA incremental_vacuum`.
+    ///
+    /// Opens the database with `auto_vacuum = INCREMENTAL`, so compaction only touches
+    /// free pages. A database created without it is converted by one full `VACUUM`
+    /// the first time it is compacted.
+    Incremental,
+}
+
 /// Configuration for SQLite session storage
 #[derive(Debug, Clone)]
 pub struct SqliteConfig {
@@ -48,8 +67,11 @@ pub struct SqliteConfig {
     pub session_timeout_minutes: u32,
     /// Event cleanup interval in minutes
     pub cleanup_interval_minutes: u32,
-    /// Maximum events to keep per session (for memory management)
+    /// Maximum events to keep per session; older events are pruned on `store_event`
     pub max_events_per_session: u32,
+    /// Events older than this many minutes are pruned on `store_event` and
+    /// background cleanup (`None` = events are only bounded by count)
+    pub event_ttl_minutes: Option<u32>,
     /// Verify table existence at startup and run migrations.
     /// When false, tables are assumed to exist.
     pub verify_tables: bool,
@@ -58,6 +80,10 @@ pub struct SqliteConfig {
     pub create_tables: bool,
     /// Create database file if it doesn't exist
     pub create_database_if_missing: bool,
+    /// Background compaction interval in minutes (`None` = only on [`compact`](SqliteSessionStorage::compact))
+    pub compaction_interval_minutes: Option<u32>,
+    /// How compaction reclaims free pages
+    pub vacuum_mode: SqliteVacuumMode,
 }
 
 impl Default for SqliteConfig {
@@ -69,9 +95,12 @@ impl Default for SqliteConfig {
             session_timeout_minutes: 30,
             cleanup_interval_minutes: 5,
             max_events_per_session: 1000,
+            event_ttl_minutes: None,
             verify_tables: false,
             create_tables: false,
             create_database_if_missing: true, // SQLite defaults to creating database
+            compaction_interval_minutes: None,
+            vacuum_mode: SqliteVacuumMode::Full,
         }
     }
 }
@@ -80,6 +109,8 @@ impl Default for SqliteConfig {
 pub struct SqliteSessionStorage {
     pool: SqlitePool,
     config: SqliteConfig,
+    /// Serializes manual and background compaction
+    compaction_lock: Arc<tokio::sync::Mutex<()>>,
 }
 
 impl SqliteSessionStorage {
@@ -106,15 +137,22 @@ impl SqliteSessionStorage {
         }
 
         // Build connection options with configurable create_if_missing
-        let connect_options = SqliteConnectOptions::new()
+        let mut connect_options = SqliteConnectOptions::new()
             .filename(&config.database_path)
             .create_if_missing(config.create_database_if_missing);
+        if config.vacuum_mode == SqliteVacuumMode::Incremental {
+            connect_options = connect_options.auto_vacuum(SqliteAutoVacuum::Incremental);
+        }
 
         // Create connection pool
         let pool = SqlitePool::connect_with(connect_options).await?;
 
         let verify = config.verify_tables;
-        let storage = Self { pool, config };
+        let storage = Self {
+            pool,
+            config,
+            compaction_lock: Arc::new(tokio::sync::Mutex::new(())),
+        };
 
         // Run database migrations if verification is enabled
         if verify {
@@ -123,6 +161,7 @@ impl SqliteSessionStorage {
 
         // Start background cleanup task
         storage.start_cleanup_task().await;
+        storage.start_compaction_task();
 
         info!("SQLite session storage initialized successfully");
         Ok(storage)
@@ -143,13 +182,26 @@ impl SqliteSessionStorage {
                 created_at INTEGER NOT NULL,
                 last_activity INTEGER NOT NULL,
                 is_initialized BOOLEAN NOT NULL DEFAULT FALSE,
-                metadata TEXT NOT NULL DEFAULT '{}'
+                metadata TEXT NOT NULL DEFAULT '{}',
+                version INTEGER NOT NULL DEFAULT 1
             )
         "#,
         )
         .execute(&self.pool)
         .await?;
 
+        // Databases created before session versioning lack the column
+        let has_version: bool = sqlx::query_scalar(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('sessions') WHERE name = 'version'",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        if !has_version {
+            sqlx::query("ALTER TABLE sessions ADD COLUMN version INTEGER NOT NULL DEFAULT 1")
+                .execute(&self.pool)
+                .await?;
+        }
+
         // Create events table
         sqlx::query(
             r#"
@@ -209,6 +261,138 @@ impl SqliteSessionStorage {
     }
 }
 
+impl SqliteSessionStorage {
+    /// Reclaim free pages left behind by deleted sessions and events, shrinking the file.
+    ///
+    /// Runs on a single pooled connection; other connections wait on SQLite's busy
+    /// timeout until it finishes. See [`SqliteVacuumMode`] for the I/O cost of each mode.
+    pub async fn compact(&self) -> Result<(), SqliteError> {
+        let _guard = self.compaction_lock.lock().await;
+        compact_database(&self.pool, self.config.vacuum_mode).await
+    }
+
+    /// Start background compaction when `compaction_interval_minutes` is set
+    fn start_compaction_task(&self) {
+        let Some(interval_minutes) = self.config.compaction_interval_minutes.filter(|m| *m > 0)
+        else {
+            return;
+        };
+        let pool = self.pool.clone();
+        let mode = self.config.vacuum_mode;
+        let lock = Arc::clone(&self.compaction_lock);
+
+        tokio::spawn(async move {
+            let duration = std::time::Duration::from_secs(interval_minutes as u64 * 60);
+
+            loop {
+                tokio::time::sleep(duration).await;
+
+                let _guard = lock.lock().await;
+                if let Err(e) = compact_database(&pool, mode).await {
+                    warn!("Background compaction failed: {}", e);
+                }
+            }
+        });
+    }
+}
+
+/// Run `VACUUM` or `PRAGMA incremental_vacuum` on one dedicated connection
+async fn compact_database(pool: &SqlitePool, mode: SqliteVacuumMode) -> Result<(), SqliteError> {
+    // VACUUM cannot run inside a transaction, so hold one connection for the whole routine
+    let mut conn = pool.acquire().await?;
+    let pages_before: i64 = sqlx::query_scalar("PRAGMA page_count")
+        .fetch_one(&mut *conn)
+        .await?;
+
+    match mode {
+        SqliteVacuumMode::Full => {
+            sqlx::query("VACUUM").execute(&mut *conn).await?;
+        }
+        SqliteVacuumMode::Incremental => {
+            // 2 = INCREMENTAL; any other value means the file predates the setting
+            let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+                .fetch_one(&mut *conn)
+                .await?;
+            if auto_vacuum == 2 {
+                sqlx::query("PRAGMA incremental_vacuum")
+                    .execute(&mut *conn)
+                    .await?;
+            } else {
+                sqlx::query("PRAGMA auto_vacuum = INCREMENTAL")
+                    .execute(&mut *conn)
+                    .await?;
+                sqlx::query("VACUUM").execute(&mut *conn).await?;
+            }
+        }
+    }
+
+    // In WAL mode the main file only shrinks once the WAL is checkpointed
+    sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+        .execute(&mut *conn)
+        .await?;
+
+    let pages_after: i64 = sqlx::query_scalar("PRAGMA page_count")
+        .fetch_one(&mut *conn)
+        .await?;
+    debug!(
+        "Compacted SQLite session database: {} -> {} pages",
+        pages_before, pages_after
+    );
+    Ok(())
+}
+
+/// Timestamp (Unix millis) before which events have outlived `event_ttl_minutes`
+fn event_ttl_cutoff(config: &SqliteConfig) -> Option<i64> {
+    config
+        .event_ttl_minutes
+        .map(|minutes| chrono::Utc::now().timestamp_millis() - minutes as i64 * 60 * 1000)
+}
+
+/// Delete a session's events beyond `max_events_per_session` and any past
+/// `event_ttl_minutes`. Event IDs are never reused, so `Last-Event-ID` resume
+/// keeps working for every event still inside the retention window.
+async fn prune_session_events(
+    pool: &SqlitePool,
+    config: &SqliteConfig,
+    session_id: &str,
+) -> Result<u64, SqliteError> {
+    // A NULL cutoff (no TTL) makes the timestamp comparison false
+    let deleted = sqlx::query(
+        r#"
+        DELETE FROM events WHERE session_id = ? AND (
+            id NOT IN (
+                SELECT id FROM events WHERE session_id = ? ORDER BY id DESC LIMIT ?
+            )
+            OR timestamp < ?
+        )
+    "#,
+    )
+    .bind(session_id)
+    .bind(session_id)
+    .bind(config.max_events_per_session as i64)
+    .bind(event_ttl_cutoff(config))
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    Ok(deleted)
+}
+
+/// Delete events of every session that are past `event_ttl_minutes`
+async fn delete_expired_events(
+    pool: &SqlitePool,
+    config: &SqliteConfig,
+) -> Result<u64, SqliteError> {
+    let Some(cutoff) = event_ttl_cutoff(config) else {
+        return Ok(0);
+    };
+    Ok(sqlx::query("DELETE FROM events WHERE timestamp < ?")
+        .bind(cutoff)
+        .execute(pool)
+        .await?
+        .rows_affected())
+}
+
 /// Background cleanup for expired sessions and old events
 async fn cleanup_expired_data(pool: &SqlitePool, config: &SqliteConfig) -> Result<(), SqliteError> {
     let now = chrono::Utc::now().timestamp_millis() as u64;
@@ -239,7 +423,8 @@ async fn cleanup_expired_data(pool: &SqlitePool, config: &SqliteConfig) -> Resul
     .bind(config.max_events_per_session as i64)
     .execute(pool)
     .await?
-    .rows_affected();
+    .rows_affected()
+        + delete_expired_events(pool, config).await?;
 
     if deleted_events > 0 {
         debug!("Cleaned up {} old events", deleted_events);
@@ -319,7 +504,7 @@ impl SessionStorage for SqliteSessionStorage {
         let row = sqlx::query(
             r#"
             SELECT session_id, client_capabilities, server_capabilities, state,
-                   created_at, last_activity, is_initialized, metadata
+                   created_at, last_activity, is_initialized, metadata, version
             FROM sessions WHERE session_id = ?
         "#,
         )
@@ -354,6 +539,7 @@ impl SessionStorage for SqliteSessionStorage {
                     last_activity: row.get::<i64, _>("last_activity") as u64,
                     is_initialized: row.get("is_initialized"),
                     metadata,
+                    version: row.get::<i64, _>("version") as u64,
                 }))
             }
             None => Ok(None),
@@ -385,7 +571,8 @@ impl SessionStorage for SqliteSessionStorage {
                 state = ?,
                 last_activity = ?,
                 is_initialized = ?,
-                metadata = ?
+                metadata = ?,
+                version = version + 1
             WHERE session_id = ?
         "#,
         )
@@ -409,6 +596,18 @@ impl SessionStorage for SqliteSessionStorage {
         Ok(())
     }
 
+    async fn touch_session(&self, session_id: &str) -> Result<bool, Self::Error> {
+        let rows_affected =
+            sqlx::query("UPDATE sessions SET last_activity = ? WHERE session_id = ?")
+                .bind(chrono::Utc::now().timestamp_millis())
+                .bind(session_id)
+                .execute(&self.pool)
+                .await?
+                .rows_affected();
+
+        Ok(rows_affected > 0)
+    }
+
     async fn set_session_state(
         &self,
         session_id: &str,
@@ -431,7 +630,9 @@ impl SessionStorage for SqliteSessionStorage {
         let new_state_json = serde_json::to_string(&state)?;
         let now = chrono::Utc::now().timestamp_millis();
 
-        sqlx::query("UPDATE sessions SET state = ?, last_activity = ? WHERE session_id = ?")
+        sqlx::query(
+            "UPDATE sessions SET state = ?, last_activity = ?, version = version + 1 WHERE session_id = ?",
+        )
             .bind(new_state_json)
             .bind(now)
             .bind(session_id)
@@ -441,6 +642,56 @@ impl SessionStorage for SqliteSessionStorage {
         Ok(())
     }
 
+    async fn set_session_state_versioned(
+        &self,
+        session_id: &str,
+        key: &str,
+        value: Value,
+        expected_version: u64,
+    ) -> Result<u64, Self::Error> {
+        let row = sqlx::query("SELECT state, version FROM sessions WHERE session_id = ?")
+            .bind(session_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| crate::sqlite::SqliteError::SessionNotFound(session_id.to_string()))?;
+
+        let version = row.get::<i64, _>("version") as u64;
+        if version != expected_version {
+            return Err(SessionStorageError::ConcurrentModification(format!(
+                "session {} is at version {}, expected {}",
+                session_id, version, expected_version
+            )));
+        }
+
+        let mut state: HashMap<String, Value> =
+            serde_json::from_str(&row.get::<String, _>("state"))?;
+        state.insert(key.to_string(), value);
+
+        // The version guard rejects anyone who wrote between our read and this update
+        let rows_affected = sqlx::query(
+            r#"
+            UPDATE sessions SET state = ?, last_activity = ?, version = version + 1
+            WHERE session_id = ? AND version = ?
+        "#,
+        )
+        .bind(serde_json::to_string(&state)?)
+        .bind(chrono::Utc::now().timestamp_millis())
+        .bind(session_id)
+        .bind(expected_version as i64)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(SessionStorageError::ConcurrentModification(format!(
+                "session {} changed while writing version {}",
+                session_id, expected_version
+            )));
+        }
+
+        Ok(expected_version + 1)
+    }
+
     async fn get_session_state(
         &self,
         session_id: &str,
@@ -481,7 +732,9 @@ impl SessionStorage for SqliteSessionStorage {
         let new_state_json = serde_json::to_string(&state)?;
         let now = chrono::Utc::now().timestamp_millis();
 
-        sqlx::query("UPDATE sessions SET state = ?, last_activity = ? WHERE session_id = ?")
+        sqlx::query(
+            "UPDATE sessions SET state = ?, last_activity = ?, version = version + 1 WHERE session_id = ?",
+        )
             .bind(new_state_json)
             .bind(now)
             .bind(session_id)
@@ -550,6 +803,11 @@ impl SessionStorage for SqliteSessionStorage {
         .fetch_one(&self.pool)
         .await?;
 
+        let pruned = prune_session_events(&self.pool, &self.config, session_id).await?;
+        if pruned > 0 {
+            debug!("Pruned {} events for session: {}", pruned, session_id);
+        }
+
         event.id = row_id as u64;
         Ok(event)
     }
@@ -678,6 +936,11 @@ impl SessionStorage for SqliteSessionStorage {
         Ok(count as usize)
     }
 
+    async fn ping(&self) -> Result<(), Self::Error> {
+        sqlx::query("SELECT 1").execute(&self.pool).await?;
+        Ok(())
+    }
+
     async fn maintenance(&self) -> Result<(), Self::Error> {
         let now = chrono::Utc::now().timestamp_millis() as u64;
         let expiration_threshold = now - (self.config.session_timeout_minutes as u64 * 60 * 1000);
@@ -710,7 +973,8 @@ impl SessionStorage for SqliteSessionStorage {
         .bind(self.config.max_events_per_session as i64)
         .execute(&self.pool)
         .await?
-        .rows_affected();
+        .rows_affected()
+            + delete_expired_events(&self.pool, &self.config).await?;
 
         if deleted_events > 0 {
             debug!("Maintenance: Cleaned up {} old events", deleted_events);
@@ -839,4 +1103,286 @@ mod tests {
         let all_events = storage.get_events_after(&session_id, 0).await.unwrap();
         assert_eq!(all_events.len(), 2);
     }
+
+    async fn create_file_storage(
+        dir: &tempfile::TempDir,
+        config: SqliteConfig,
+    ) -> SqliteSessionStorage {
+        SqliteSessionStorage::with_config(SqliteConfig {
+            database_path: dir.path().join("sessions.db"),
+            verify_tables: true,
+            create_tables: true,
+            ..config
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_store_event_prunes_oldest_beyond_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = create_file_storage(
+            &dir,
+            SqliteConfig {
+                max_events_per_session: 3,
+                ..SqliteConfig::default()
+            },
+        )
+        .await;
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        let other = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        storage
+            .store_event(
+                &other.session_id,
+                SseEvent::new("test".to_string(), json!(0)),
+            )
+            .await
+            .unwrap();
+
+        let mut ids = Vec::new();
+        for n in 0..5 {
+            let event = SseEvent::new("test".to_string(), json!(n));
+            ids.push(
+                storage
+                    .store_event(&session.session_id, event)
+                    .await
+                    .unwrap()
+                    .id,
+            );
+        }
+
+        let retained = storage
+            .get_events_after(&session.session_id, 0)
+            .await
+            .unwrap();
+        let retained_ids: Vec<u64> = retained.iter().map(|e| e.id).collect();
+        assert_eq!(retained_ids, ids[2..]);
+
+        // Resume from an event inside the retention window
+        let resumed = storage
+            .get_events_after(&session.session_id, ids[2])
+            .await
+            .unwrap();
+        assert_eq!(resumed.len(), 2);
+        assert_eq!(resumed[0].data, json!(3));
+
+        // The cap applies per session
+        assert_eq!(
+            storage
+                .get_events_after(&other.session_id, 0)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_store_event_prunes_events_past_ttl() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = create_file_storage(
+            &dir,
+            SqliteConfig {
+                event_ttl_minutes: Some(10),
+                ..SqliteConfig::default()
+            },
+        )
+        .await;
+        let session = storage
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+
+        let mut stale = SseEvent::new("test".to_string(), json!("stale"));
+        stale.timestamp -= 11 * 60 * 1000;
+        storage
+            .store_event(&session.session_id, stale)
+            .await
+            .unwrap();
+        let fresh = storage
+            .store_event(
+                &session.session_id,
+                SseEvent::new("test".to_string(), json!("fresh")),
+            )
+            .await
+            .unwrap();
+
+        let events = storage
+            .get_events_after(&session.session_id, 0)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, fresh.id);
+    }
+
+    #[tokio::test]
+    async fn test_compact_shrinks_database_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let database_path = dir.path().join("sessions.db");
+        let storage = SqliteSessionStorage::with_config(SqliteConfig {
+            database_path: database_path.clone(),
+            verify_tables: true,
+            create_tables: true,
+            ..SqliteConfig::default()
+        })
+        .await
+        .unwrap();
+
+        let payload = json!("x".repeat(4096));
+        let mut session_ids = Vec::new();
+        for _ in 0..200 {
+            let session = storage
+                .create_session(ServerCapabilities::default())
+                .await
+                .unwrap();
+            storage
+                .set_session_state(&session.session_id, "payload", payload.clone())
+                .await
+                .unwrap();
+            session_ids.push(session.session_id);
+        }
+        for session_id in &session_ids {
+            storage.delete_session(session_id).await.unwrap();
+        }
+
+        // Deletions only move pages to the freelist; the file keeps its size
+        let size_before = std::fs::metadata(&database_path).unwrap().len();
+        storage.compact().await.unwrap();
+        let size_after = std::fs::metadata(&database_path).unwrap().len();
+
+        assert!(
+            size_after < size_before,
+            "expected compaction to shrink the file ({} -> {} bytes)",
+            size_before,
+            size_after
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_session_snapshot() {
+        let source = crate::InMemorySessionStorage::new();
+        let session = source
+            .create_session(ServerCapabilities::default())
+            .await
+            .unwrap();
+        source
+            .set_session_state(&session.session_id, "cart", json!({"items": [1, 2]}))
+            .await
+            .unwrap();
+        for n in 0..2 {
+            source
+                .store_event(
+                    &session.session_id,
+                    SseEvent::new("message".to_string(), json!({"n": n})),
+                )
+                .await
+                .unwrap();
+        }
+        let snapshot = source
+            .export_session(&session.session_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let storage = SqliteSessionStorage::with_config(SqliteConfig {
+            database_path: dir.path().join("sessions.db"),
+            verify_tables: true,
+            create_tables: true,
+            ..SqliteConfig::default()
+        })
+        .await
+        .unwrap();
+        storage.import_session(snapshot.clone()).await.unwrap();
+
+        let imported = storage
+            .export_session(&session.session_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(imported.source_backend, "SQLite");
+        assert_eq!(imported.session.state, snapshot.session.state);
+        assert_eq!(imported.events.len(), 2);
+        for (imported, original) in imported.events.iter().zip(&snapshot.events) {
+            assert_eq!(imported.event_type, original.event_type);
+            assert_eq!(imported.data, original.data);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_migrate_adds_version_column_to_existing_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sessions.db");
+        {
+            let options = SqliteConnectOptions::new()
+                .filename(&path)
+                .create_if_missing(true);
+            let pool = SqlitePool::connect_with(options).await.unwrap();
+            sqlx::query(
+                r#"
+                CREATE TABLE sessions (
+                    session_id TEXT PRIMARY KEY,
+                    client_capabilities TEXT,
+                    server_capabilities TEXT NOT NULL,
+                    state TEXT NOT NULL DEFAULT '{}',
+                    created_at INTEGER NOT NULL,
+                    last_activity INTEGER NOT NULL,
+                    is_initialized BOOLEAN NOT NULL DEFAULT FALSE,
+                    metadata TEXT NOT NULL DEFAULT '{}'
+                )
+            "#,
+            )
+            .execute(&pool)
+            .await
+            .unwrap();
+            let now = chrono::Utc::now().timestamp_millis();
+            sqlx::query(
+                "INSERT INTO sessions (session_id, server_capabilities, created_at, last_activity) VALUES ('legacy', '{}', ?, ?)",
+            )
+            .bind(now)
+            .bind(now)
+            .execute(&pool)
+            .await
+            .unwrap();
+            pool.close().await;
+        }
+
+        let storage = SqliteSessionStorage::with_config(SqliteConfig {
+            database_path: path,
+            verify_tables: true,
+            ..SqliteConfig::default()
+        })
+        .await
+        .unwrap();
+
+        let legacy = storage.get_session("legacy").await.unwrap().unwrap();
+        assert_eq!(legacy.version, 1);
+        let version = storage
+            .set_session_state_versioned("legacy", "key", json!(1), 1)
+            .await
+            .unwrap();
+        assert_eq!(version, 2);
+    }
+
+    // === Parity tests ===
+
+    #[tokio::test]
+    async fn parity_touch_updates_activity_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = create_file_storage(&dir, SqliteConfig::default()).await;
+        crate::parity_tests::test_touch_updates_activity_only(&storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_versioned_state_rejects_stale_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = create_file_storage(&dir, SqliteConfig::default()).await;
+        crate::parity_tests::test_versioned_state_rejects_stale_writer(&storage).await;
+    }
 }
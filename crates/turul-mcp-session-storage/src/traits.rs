@@ -17,8 +17,13 @@ use uuid::Uuid;
 
 use turul_mcp_protocol::{ClientCapabilities, ServerCapabilities};
 
+use crate::snapshot::SessionSnapshot;
+
 // Note: SessionEvent removed to avoid circular dependency
 
+/// Session id looked up by the default [`SessionStorage::ping`]; never issued
+const PING_SESSION_ID: &str = "__turul_ping__";
+
 /// Comprehensive session information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionInfo {
@@ -38,6 +43,10 @@ pub struct SessionInfo {
     pub is_initialized: bool,
     /// Session metadata (connection info, user agent, etc.)
     pub metadata: HashMap<String, Value>,
+    /// Write counter, bumped by every session update (see
+    /// [`SessionStorage::set_session_state_versioned`])
+    #[serde(default)]
+    pub version: u64,
 }
 
 impl Default for SessionInfo {
@@ -59,6 +68,7 @@ impl SessionInfo {
             last_activity: now,
             is_initialized: false,
             metadata: HashMap::new(),
+            version: 1,
         }
     }
 
@@ -74,6 +84,7 @@ impl SessionInfo {
             last_activity: now,
             is_initialized: false,
             metadata: HashMap::new(),
+            version: 1,
         }
     }
 
@@ -131,20 +142,23 @@ impl SseEvent {
     /// with no event name or "message". Custom event names are discarded.
     /// We use "message" for all JSON-RPC notifications to ensure compatibility.
     ///
-    /// CRITICAL: Keepalive events use comment syntax (": keepalive\n\n") to preserve
+    /// CRITICAL: Keepalive events use comment syntax (": ping\n\n") to preserve
     /// Last-Event-ID for MCP resumability. Including "id: 0" would reset the client's
     /// Last-Event-ID, causing full event replay on reconnection.
     pub fn format(&self) -> String {
         // Special handling for keepalives: use comment syntax (no id, no data)
         // This preserves Last-Event-ID for proper MCP resumability
         if self.event_type == "ping" || self.event_type == "keepalive" {
-            return ": keepalive\n\n".to_string();
+            return ": ping\n\n".to_string();
         }
 
         let mut result = String::new();
 
-        // Event ID for resumability (only for real events, not keepalives)
-        result.push_str(&format!("id: {}\n", self.id));
+        // Event ID for resumability (only for stored events: id 0 was never
+        // persisted and would reset the client's Last-Event-ID)
+        if self.id != 0 {
+            result.push_str(&format!("id: {}\n", self.id));
+        }
 
         // Use "message" for all JSON-RPC notifications (MCP Inspector compatible)
         result.push_str("event: message\n");
@@ -240,6 +254,20 @@ pub trait SessionStorage: Send + Sync {
     /// Update entire session info
     async fn update_session(&self, session_info: SessionInfo) -> Result<(), Self::Error>;
 
+    /// Record activity on a session, extending its expiry without rewriting it
+    ///
+    /// Returns `false` if the session does not exist. The default implementation
+    /// reads and rewrites the whole session; backends override it with a targeted
+    /// update of the activity timestamp.
+    async fn touch_session(&self, session_id: &str) -> Result<bool, Self::Error> {
+        let Some(mut session) = self.get_session(session_id).await? else {
+            return Ok(false);
+        };
+        session.touch();
+        self.update_session(session).await?;
+        Ok(true)
+    }
+
     /// Update session state value
     async fn set_session_state(
         &self,
@@ -248,6 +276,20 @@ pub trait SessionStorage: Send + Sync {
         value: Value,
     ) -> Result<(), Self::Error>;
 
+    /// Update a session state value only if the session is still at `expected_version`
+    ///
+    /// `expected_version` is the [`SessionInfo::version`] the caller last read. On
+    /// success the new version is returned; if another write got there first this
+    /// fails with [`SessionStorageError::ConcurrentModification`] and nothing is
+    /// written, so the caller can re-read and retry.
+    async fn set_session_state_versioned(
+        &self,
+        session_id: &str,
+        key: &str,
+        value: Value,
+        expected_version: u64,
+    ) -> Result<u64, Self::Error>;
+
     /// Get session state value
     async fn get_session_state(
         &self,
@@ -312,6 +354,57 @@ pub trait SessionStorage: Send + Sync {
 
     /// Perform maintenance tasks (compaction, cleanup, etc.)
     async fn maintenance(&self) -> Result<(), Self::Error>;
+
+    /// Check the backend is reachable with one cheap round-trip
+    ///
+    /// Used by readiness probes. The default looks up a session that never exists;
+    /// override it when the backend has a cheaper ping.
+    async fn ping(&self) -> Result<(), Self::Error> {
+        self.get_session(PING_SESSION_ID).await.map(|_| ())
+    }
+
+    // ============================================================================
+    // Diagnostics
+    // ============================================================================
+
+    /// Capture a session and its retained events for debugging.
+    ///
+    /// Returns `None` if the session does not exist. Apply a
+    /// [`RedactionPolicy`](crate::RedactionPolicy) with [`SessionSnapshot::redact`]
+    /// before sharing the snapshot.
+    async fn export_session(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<SessionSnapshot>, Self::Error> {
+        let Some(session) = self.get_session(session_id).await? else {
+            return Ok(None);
+        };
+        let events = self.get_events_after(session_id, 0).await?;
+        Ok(Some(SessionSnapshot::new(
+            self.backend_name(),
+            session,
+            events,
+        )))
+    }
+
+    /// Recreate a session from a snapshot, replacing any session with the same ID.
+    ///
+    /// Events are stored in snapshot order. The default implementation goes through
+    /// `store_event`, so backends that allocate event IDs assign fresh ones.
+    async fn import_session(&self, snapshot: SessionSnapshot) -> Result<SessionInfo, Self::Error> {
+        let session = snapshot.session;
+        self.delete_session(&session.session_id).await?;
+        self.create_session_with_id(
+            session.session_id.clone(),
+            session.server_capabilities.clone().unwrap_or_default(),
+        )
+        .await?;
+        self.update_session(session.clone()).await?;
+        for event in snapshot.events {
+            self.store_event(&session.session_id, event).await?;
+        }
+        Ok(session)
+    }
 }
 
 /// Result type for session storage operations
@@ -524,11 +617,17 @@ mod tests {
 
         let keepalive_formatted = keepalive.format();
         // Keepalives use comment syntax to preserve Last-Event-ID
-        assert_eq!(keepalive_formatted, ": keepalive\n\n");
+        assert_eq!(keepalive_formatted, ": ping\n\n");
         assert!(keepalive_formatted.starts_with(":")); // Comment-style
         assert!(!keepalive_formatted.contains("id:")); // No ID field
         assert!(!keepalive_formatted.contains("event:")); // No event field
         assert!(!keepalive_formatted.contains("data:")); // No data field
+
+        // Unpersisted events carry data but no id, so Last-Event-ID is unchanged
+        let unstored = SseEvent::new("shutdown".to_string(), serde_json::json!({"bye": true}));
+        let unstored_formatted = unstored.format();
+        assert!(!unstored_formatted.contains("id:"));
+        assert!(unstored_formatted.contains("data: {\"bye\":true}"));
     }
 
     #[test]
@@ -10,16 +10,42 @@
 //! - **GSI `SessionIndex`**: PK=`sessionId`, SK=`createdAt`
 //! - **GSI `StatusIndex`**: PK=`status`, SK=`createdAt`
 //! - **TTL attribute**: `ttlEpoch` (N, Unix epoch seconds)
+//! - **Progress chunks**: `progress` (L of JSON-encoded S), appended with `list_append`
 //!
 //! Legacy tables (pre-v0.3.4) use snake_case and are auto-detected via
 //! `describe_table()` key schema inspection. Both conventions are fully supported.
+//!
+//! ## Single-Table Layout
+//!
+//! With `DynamoDbTaskConfig::single_table`, tasks share one table with
+//! `DynamoDbSessionStorage` (configured with `DynamoDbConfig::single_table` and
+//! the same `table_name`). Every item carries a namespaced key and an
+//! `entityType` discriminator:
+//!
+//! | Item    | `PK`           | `SK`                   | `entityType` |
+//! |---------|----------------|------------------------|--------------|
+//! | Session | `SESSION#<id>` | `SESSION`              | `session`    |
+//! | Event   | `SESSION#<id>` | `EVENT#<20-digit id>`  | `event`      |
+//! | Task    | `TASK#<id>`    | `TASK`                 | `task`       |
+//!
+//! - **Key schema**: PK=`PK` (S), SK=`SK` (S)
+//! - **GSI `TaskSessionIndex`**: PK=`sessionId`, SK=`taskCreatedAt`
+//! - **GSI `TaskStatusIndex`**: PK=`status`, SK=`taskCreatedAt`
+//! - **TTL attribute**: `ttlEpoch` (N, Unix epoch seconds) on every item
+//!
+//! `taskCreatedAt` is a copy of `createdAt` written only on task items, so both
+//! GSIs are sparse and never return sessions or events. Scans add an
+//! `entityType = task` filter.
 
 use crate::error::TaskStorageError;
+use crate::observer::{TaskObserver, notify_transition};
 use crate::state_machine;
-use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage};
+use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage, status_conflict_error};
 use async_trait::async_trait;
 use chrono::Utc;
+use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 use turul_mcp_protocol::TaskStatus;
 
@@ -118,6 +144,23 @@ fn get_attr<'a>(
     item.get(primary).or_else(|| item.get(fallback))
 }
 
+/// Attribute and key names of the single-table layout (see module docs).
+#[cfg(feature = "dynamodb")]
+mod single_table {
+    pub const PARTITION_KEY: &str = "PK";
+    pub const SORT_KEY: &str = "SK";
+    pub const ENTITY_TYPE: &str = "entityType";
+    pub const TASK_ENTITY: &str = "task";
+    pub const TASK_SORT_KEY: &str = "TASK";
+    pub const TASK_CREATED_AT: &str = "taskCreatedAt";
+    pub const TASK_SESSION_INDEX: &str = "TaskSessionIndex";
+    pub const TASK_STATUS_INDEX: &str = "TaskStatusIndex";
+
+    pub fn task_partition_key(task_id: &str) -> String {
+        format!("TASK#{}", task_id)
+    }
+}
+
 /// Configuration for DynamoDB task storage.
 #[derive(Debug, Clone)]
 pub struct DynamoDbTaskConfig {
@@ -137,6 +180,11 @@ pub struct DynamoDbTaskConfig {
     pub max_tasks: usize,
     /// Default page size for list operations.
     pub default_page_size: u32,
+    /// Observer notified of committed status transitions.
+    pub observer: Option<Arc<dyn TaskObserver>>,
+    /// Store tasks in a table shared with `DynamoDbSessionStorage`, keyed by
+    /// `PK`/`SK` (see the module docs for the key schema).
+    pub single_table: bool,
 }
 
 impl Default for DynamoDbTaskConfig {
@@ -149,10 +197,20 @@ impl Default for DynamoDbTaskConfig {
             create_tables: false,
             max_tasks: 10_000,
             default_page_size: 50,
+            observer: None,
+            single_table: false,
         }
     }
 }
 
+impl DynamoDbTaskConfig {
+    /// Notify `observer` of every status transition committed by `update_task_status`.
+    pub fn with_observer(mut self, observer: Arc<dyn TaskObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+}
+
 /// DynamoDB-backed task storage implementation.
 ///
 /// Uses a single table with two GSIs (SessionIndex, StatusIndex) and
@@ -174,6 +232,77 @@ impl DynamoDbTaskStorage {
     fn attrs(&self) -> &'static TaskAttrNames {
         TaskAttrNames::for_convention(self.naming)
     }
+
+    /// Primary key of a task item for the configured table layout.
+    fn task_key(&self, task_id: &str) -> HashMap<String, AttributeValue> {
+        if self.config.single_table {
+            HashMap::from([
+                (
+                    single_table::PARTITION_KEY.to_string(),
+                    AttributeValue::S(single_table::task_partition_key(task_id)),
+                ),
+                (
+                    single_table::SORT_KEY.to_string(),
+                    AttributeValue::S(single_table::TASK_SORT_KEY.to_string()),
+                ),
+            ])
+        } else {
+            HashMap::from([(
+                self.attrs().task_id.to_string(),
+                AttributeValue::S(task_id.to_string()),
+            )])
+        }
+    }
+
+    fn session_index(&self) -> &'static str {
+        if self.config.single_table {
+            single_table::TASK_SESSION_INDEX
+        } else {
+            "SessionIndex"
+        }
+    }
+
+    fn status_index(&self) -> &'static str {
+        if self.config.single_table {
+            single_table::TASK_STATUS_INDEX
+        } else {
+            "StatusIndex"
+        }
+    }
+
+    /// Restrict a scan filter to task items when the table is shared.
+    #[allow(clippy::type_complexity)]
+    fn task_scan_filter(
+        &self,
+        filter: Option<(
+            String,
+            HashMap<String, String>,
+            HashMap<String, AttributeValue>,
+        )>,
+    ) -> Option<(
+        String,
+        HashMap<String, String>,
+        HashMap<String, AttributeValue>,
+    )> {
+        if !self.config.single_table {
+            return filter;
+        }
+        let (expression, mut names, mut values) = filter.unwrap_or_default();
+        names.insert(
+            "#entityType".to_string(),
+            single_table::ENTITY_TYPE.to_string(),
+        );
+        values.insert(
+            ":entityType".to_string(),
+            AttributeValue::S(single_table::TASK_ENTITY.to_string()),
+        );
+        let expression = if expression.is_empty() {
+            "#entityType = :entityType".to_string()
+        } else {
+            format!("#entityType = :entityType AND ({})", expression)
+        };
+        Some((expression, names, values))
+    }
 }
 
 fn status_to_str(status: TaskStatus) -> &'static str {
@@ -186,6 +315,41 @@ fn status_to_str(status: TaskStatus) -> &'static str {
     }
 }
 
+/// Build a `#status IN (...)` filter expression with its attribute names and values.
+///
+/// Returns `None` when no filter applies. DynamoDB evaluates filter expressions after
+/// `Limit`, so a filtered page may hold fewer than `limit` tasks; `next_cursor` still
+/// advances past everything examined, so paging until it is `None` sees every match.
+#[cfg(feature = "dynamodb")]
+#[allow(clippy::type_complexity)]
+fn status_filter_expression(
+    status_filter: Option<&[TaskStatus]>,
+) -> Option<(
+    String,
+    HashMap<String, String>,
+    HashMap<String, AttributeValue>,
+)> {
+    let statuses = status_filter.filter(|statuses| !statuses.is_empty())?;
+    let placeholders: Vec<String> = (0..statuses.len())
+        .map(|i| format!(":status_filter_{}", i))
+        .collect();
+    let values = placeholders
+        .iter()
+        .zip(statuses)
+        .map(|(placeholder, status)| {
+            (
+                placeholder.clone(),
+                AttributeValue::S(status_to_str(*status).to_string()),
+            )
+        })
+        .collect();
+    Some((
+        format!("#status IN ({})", placeholders.join(", ")),
+        HashMap::from([("#status".to_string(), "status".to_string())]),
+        values,
+    ))
+}
+
 fn str_to_status(s: &str) -> Result<TaskStatus, TaskStorageError> {
     match s {
         "working" => Ok(TaskStatus::Working),
@@ -208,6 +372,24 @@ fn task_record_to_item(
 ) -> HashMap<String, AttributeValue> {
     let mut item = HashMap::new();
 
+    if config.single_table {
+        item.insert(
+            single_table::PARTITION_KEY.to_string(),
+            AttributeValue::S(single_table::task_partition_key(&record.task_id)),
+        );
+        item.insert(
+            single_table::SORT_KEY.to_string(),
+            AttributeValue::S(single_table::TASK_SORT_KEY.to_string()),
+        );
+        item.insert(
+            single_table::ENTITY_TYPE.to_string(),
+            AttributeValue::S(single_table::TASK_ENTITY.to_string()),
+        );
+        item.insert(
+            single_table::TASK_CREATED_AT.to_string(),
+            AttributeValue::S(record.created_at.clone()),
+        );
+    }
     item.insert(
         attrs.task_id.to_string(),
         AttributeValue::S(record.task_id.clone()),
@@ -288,6 +470,66 @@ fn task_record_to_item(
     item
 }
 
+/// Attribute holding a task's progress chunks, a list of JSON strings.
+#[cfg(feature = "dynamodb")]
+const PROGRESS_ATTR: &str = "progress";
+
+/// Turn a task item into an `UpdateItem` expression that overwrites every record
+/// attribute except the key, removing optional ones absent from `item`. Unlike
+/// `PutItem`, this leaves the progress list in place.
+#[cfg(feature = "dynamodb")]
+#[allow(clippy::type_complexity)]
+fn replace_record_expression(
+    mut item: HashMap<String, AttributeValue>,
+    attrs: &TaskAttrNames,
+) -> (
+    String,
+    HashMap<String, String>,
+    HashMap<String, AttributeValue>,
+) {
+    let mut record_attrs = vec![
+        attrs.session_id,
+        "status",
+        attrs.status_message,
+        attrs.created_at,
+        attrs.last_updated_at,
+        "ttl",
+        attrs.ttl_epoch,
+        attrs.poll_interval,
+        attrs.original_method,
+        attrs.original_params,
+        "result",
+        "meta",
+    ];
+    // Single-table items carry the GSI sort key alongside createdAt
+    if item.contains_key(single_table::TASK_CREATED_AT) {
+        record_attrs.push(single_table::TASK_CREATED_AT);
+    }
+
+    let mut sets = Vec::new();
+    let mut removes = Vec::new();
+    let mut names = HashMap::new();
+    let mut values = HashMap::new();
+    for (i, attr) in record_attrs.iter().enumerate() {
+        let name = format!("#attr{}", i);
+        names.insert(name.clone(), attr.to_string());
+        match item.remove(*attr) {
+            Some(value) => {
+                let placeholder = format!(":attr{}", i);
+                sets.push(format!("{} = {}", name, placeholder));
+                values.insert(placeholder, value);
+            }
+            None => removes.push(name),
+        }
+    }
+
+    let mut expression = format!("SET {}", sets.join(", "));
+    if !removes.is_empty() {
+        expression.push_str(&format!(" REMOVE {}", removes.join(", ")));
+    }
+    (expression, names, values)
+}
+
 #[cfg(feature = "dynamodb")]
 fn item_to_task_record(
     item: &HashMap<String, AttributeValue>,
@@ -545,6 +787,10 @@ impl DynamoDbTaskStorage {
             Projection, ProjectionType, ScalarAttributeType,
         };
 
+        if self.config.single_table {
+            return self.create_single_table().await;
+        }
+
         let attrs = self.attrs();
         info!(
             "Creating DynamoDB table: {} (naming: {:?})",
@@ -663,6 +909,87 @@ impl DynamoDbTaskStorage {
         }
     }
 
+    /// Create the single-table layout shared with session storage: `PK`/`SK`
+    /// keys plus the task GSIs on `taskCreatedAt`.
+    #[cfg(feature = "dynamodb")]
+    async fn create_single_table(&self) -> Result<(), TaskStorageError> {
+        use aws_sdk_dynamodb::types::{
+            AttributeDefinition, BillingMode, GlobalSecondaryIndex, KeySchemaElement, KeyType,
+            Projection, ProjectionType, ScalarAttributeType,
+        };
+
+        let attrs = self.attrs();
+        info!(
+            "Creating single-table DynamoDB layout: {}",
+            self.config.table_name
+        );
+
+        let key = |name: &str, key_type: KeyType| {
+            KeySchemaElement::builder()
+                .attribute_name(name)
+                .key_type(key_type)
+                .build()
+                .map_err(|e| TaskStorageError::DatabaseError(e.to_string()))
+        };
+        let string_attr = |name: &str| {
+            AttributeDefinition::builder()
+                .attribute_name(name)
+                .attribute_type(ScalarAttributeType::S)
+                .build()
+                .map_err(|e| TaskStorageError::DatabaseError(e.to_string()))
+        };
+        let gsi =
+            |index_name: &str, hash: &str| -> Result<GlobalSecondaryIndex, TaskStorageError> {
+                GlobalSecondaryIndex::builder()
+                    .index_name(index_name)
+                    .key_schema(key(hash, KeyType::Hash)?)
+                    .key_schema(key(single_table::TASK_CREATED_AT, KeyType::Range)?)
+                    .projection(
+                        Projection::builder()
+                            .projection_type(ProjectionType::All)
+                            .build(),
+                    )
+                    .build()
+                    .map_err(|e| TaskStorageError::DatabaseError(e.to_string()))
+            };
+
+        let attribute_definitions = vec![
+            string_attr(single_table::PARTITION_KEY)?,
+            string_attr(single_table::SORT_KEY)?,
+            string_attr(attrs.session_id)?,
+            string_attr("status")?,
+            string_attr(single_table::TASK_CREATED_AT)?,
+        ];
+
+        self.client
+            .create_table()
+            .table_name(&self.config.table_name)
+            .key_schema(key(single_table::PARTITION_KEY, KeyType::Hash)?)
+            .key_schema(key(single_table::SORT_KEY, KeyType::Range)?)
+            .set_attribute_definitions(Some(attribute_definitions))
+            .billing_mode(BillingMode::PayPerRequest)
+            .global_secondary_indexes(gsi(single_table::TASK_SESSION_INDEX, attrs.session_id)?)
+            .global_secondary_indexes(gsi(single_table::TASK_STATUS_INDEX, "status")?)
+            .send()
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to create table '{}': {}",
+                    self.config.table_name, err
+                );
+                TaskStorageError::DatabaseError(format!(
+                    "Failed to create table '{}': {}",
+                    self.config.table_name, err
+                ))
+            })?;
+
+        info!(
+            "Successfully initiated table creation: {}",
+            self.config.table_name
+        );
+        Ok(())
+    }
+
     /// Ensure TTL is enabled on the table, enabling it if necessary.
     #[cfg(feature = "dynamodb")]
     async fn ensure_ttl_enabled(&self) -> Result<(), TaskStorageError> {
@@ -819,6 +1146,73 @@ impl DynamoDbTaskStorage {
         )))
     }
 
+    /// Delete tasks by id with `BatchWriteItem`, 25 keys per request.
+    ///
+    /// Unprocessed items are retried with a short backoff. Returns the number of
+    /// delete requests DynamoDB accepted.
+    #[cfg(feature = "dynamodb")]
+    async fn batch_delete_tasks(&self, task_ids: &[String]) -> Result<u64, TaskStorageError> {
+        use aws_sdk_dynamodb::types::{DeleteRequest, WriteRequest};
+        use tokio::time::{Duration, sleep};
+
+        const BATCH_SIZE: usize = 25;
+        const MAX_ATTEMPTS: u32 = 5;
+
+        let mut deleted = 0u64;
+
+        for chunk in task_ids.chunks(BATCH_SIZE) {
+            let mut pending = chunk
+                .iter()
+                .map(|task_id| {
+                    let key = self.task_key(task_id);
+                    DeleteRequest::builder()
+                        .set_key(Some(key))
+                        .build()
+                        .map(|delete| WriteRequest::builder().delete_request(delete).build())
+                        .map_err(|e| TaskStorageError::DatabaseError(e.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for attempt in 1..=MAX_ATTEMPTS {
+                let submitted = pending.len() as u64;
+                let output = self
+                    .client
+                    .batch_write_item()
+                    .request_items(&self.config.table_name, pending)
+                    .send()
+                    .await
+                    .map_err(|err| {
+                        error!("Failed to batch delete tasks from DynamoDB: {}", err);
+                        TaskStorageError::DatabaseError(format!(
+                            "Failed to batch delete tasks: {}",
+                            err
+                        ))
+                    })?;
+
+                pending = output
+                    .unprocessed_items()
+                    .and_then(|items| items.get(&self.config.table_name))
+                    .cloned()
+                    .unwrap_or_default();
+                deleted += submitted - pending.len() as u64;
+
+                if pending.is_empty() {
+                    break;
+                }
+                if attempt == MAX_ATTEMPTS {
+                    return Err(TaskStorageError::DatabaseError(format!(
+                        "{} task deletes left unprocessed after {} attempts",
+                        pending.len(),
+                        MAX_ATTEMPTS
+                    )));
+                }
+                sleep(Duration::from_millis(50 * 2u64.pow(attempt))).await;
+            }
+        }
+
+        Ok(deleted)
+    }
+
     fn now_iso8601() -> String {
         Utc::now().to_rfc3339()
     }
@@ -896,10 +1290,7 @@ impl TaskStorage for DynamoDbTaskStorage {
         #[cfg(feature = "dynamodb")]
         {
             let attrs = self.attrs();
-            let key = HashMap::from([(
-                attrs.task_id.to_string(),
-                AttributeValue::S(task_id.to_string()),
-            )]);
+            let key = self.task_key(task_id);
 
             match self
                 .client
@@ -942,13 +1333,19 @@ impl TaskStorage for DynamoDbTaskStorage {
         {
             let attrs = self.attrs();
             let item = task_record_to_item(&task, &self.config, attrs);
+            let key = self.task_key(&task.task_id);
+            // UpdateItem rather than PutItem so progress chunks survive the replacement
+            let (update_expr, expr_names, expr_values) = replace_record_expression(item, attrs);
 
             match self
                 .client
-                .put_item()
+                .update_item()
                 .table_name(&self.config.table_name)
-                .set_item(Some(item))
+                .set_key(Some(key))
+                .update_expression(update_expr)
                 .condition_expression(format!("attribute_exists({})", attrs.task_id))
+                .set_expression_attribute_names(Some(expr_names))
+                .set_expression_attribute_values(Some(expr_values))
                 .send()
                 .await
             {
@@ -983,11 +1380,7 @@ impl TaskStorage for DynamoDbTaskStorage {
     async fn delete_task(&self, task_id: &str) -> Result<bool, TaskStorageError> {
         #[cfg(feature = "dynamodb")]
         {
-            let attrs = self.attrs();
-            let key = HashMap::from([(
-                attrs.task_id.to_string(),
-                AttributeValue::S(task_id.to_string()),
-            )]);
+            let key = self.task_key(task_id);
 
             match self
                 .client
@@ -1029,6 +1422,7 @@ impl TaskStorage for DynamoDbTaskStorage {
         &self,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError> {
         #[cfg(feature = "dynamodb")]
         {
@@ -1073,6 +1467,15 @@ impl TaskStorage for DynamoDbTaskStorage {
                 .table_name(&self.config.table_name)
                 .limit(limit as i32);
 
+            if let Some((filter, names, values)) =
+                self.task_scan_filter(status_filter_expression(status_filter))
+            {
+                builder = builder
+                    .filter_expression(filter)
+                    .set_expression_attribute_names(Some(names))
+                    .set_expression_attribute_values(Some(values));
+            }
+
             if let Some(start_key) = exclusive_start_key {
                 builder = builder.set_exclusive_start_key(Some(start_key));
             }
@@ -1127,7 +1530,7 @@ impl TaskStorage for DynamoDbTaskStorage {
 
         #[cfg(not(feature = "dynamodb"))]
         {
-            let _ = (cursor, limit);
+            let _ = (cursor, limit, status_filter);
             Err(TaskStorageError::Generic(
                 "DynamoDB feature is not enabled".to_string(),
             ))
@@ -1153,6 +1556,7 @@ impl TaskStorage for DynamoDbTaskStorage {
             // Validate state machine transition
             state_machine::validate_transition(current.status, new_status)?;
 
+            let mut from = current.status;
             let expected_status = status_to_str(current.status).to_string();
             let new_status_str = status_to_str(new_status).to_string();
             let now = Self::now_iso8601();
@@ -1190,12 +1594,9 @@ impl TaskStorage for DynamoDbTaskStorage {
                 );
             }
 
-            let key = HashMap::from([(
-                task_attrs.task_id.to_string(),
-                AttributeValue::S(task_id.to_string()),
-            )]);
+            let key = self.task_key(task_id);
 
-            match self
+            let updated = match self
                 .client
                 .update_item()
                 .table_name(&self.config.table_name)
@@ -1229,6 +1630,7 @@ impl TaskStorage for DynamoDbTaskStorage {
 
                         // Re-validate transition with fresh state
                         state_machine::validate_transition(refreshed.status, new_status)?;
+                        from = refreshed.status;
 
                         let retry_expected = status_to_str(refreshed.status).to_string();
                         let retry_new = status_to_str(new_status).to_string();
@@ -1267,10 +1669,7 @@ impl TaskStorage for DynamoDbTaskStorage {
                             );
                         }
 
-                        let retry_key = HashMap::from([(
-                            task_attrs.task_id.to_string(),
-                            AttributeValue::S(task_id.to_string()),
-                        )]);
+                        let retry_key = self.task_key(task_id);
 
                         match self
                             .client
@@ -1307,7 +1706,10 @@ impl TaskStorage for DynamoDbTaskStorage {
                         )))
                     }
                 }
-            }
+            }?;
+
+            notify_transition(self.config.observer.as_ref(), task_id, from, new_status).await;
+            Ok(updated)
         }
 
         #[cfg(not(feature = "dynamodb"))]
@@ -1319,6 +1721,111 @@ impl TaskStorage for DynamoDbTaskStorage {
         }
     }
 
+    async fn update_task_status_if(
+        &self,
+        task_id: &str,
+        expected: TaskStatus,
+        new_status: TaskStatus,
+        status_message: Option<String>,
+    ) -> Result<TaskRecord, TaskStorageError> {
+        #[cfg(feature = "dynamodb")]
+        {
+            let task_attrs = self.attrs();
+
+            let current = self
+                .get_task(task_id)
+                .await?
+                .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+            if current.status != expected {
+                return Err(TaskStorageError::StatusConflict {
+                    task_id: task_id.to_string(),
+                    expected,
+                    actual: current.status,
+                });
+            }
+
+            state_machine::validate_transition(expected, new_status)?;
+
+            let mut update_expr = "SET #status = :new_status, #last_updated_at = :now".to_string();
+            let expr_names = HashMap::from([
+                ("#status".to_string(), "status".to_string()),
+                (
+                    "#last_updated_at".to_string(),
+                    task_attrs.last_updated_at.to_string(),
+                ),
+                (
+                    "#status_message".to_string(),
+                    task_attrs.status_message.to_string(),
+                ),
+            ]);
+            let mut expr_values: HashMap<String, AttributeValue> = HashMap::from([
+                (
+                    ":new_status".to_string(),
+                    AttributeValue::S(status_to_str(new_status).to_string()),
+                ),
+                (":now".to_string(), AttributeValue::S(Self::now_iso8601())),
+                (
+                    ":expected_status".to_string(),
+                    AttributeValue::S(status_to_str(expected).to_string()),
+                ),
+            ]);
+
+            if let Some(ref msg) = status_message {
+                update_expr.push_str(", #status_message = :msg");
+                expr_values.insert(":msg".to_string(), AttributeValue::S(msg.clone()));
+            } else {
+                update_expr.push_str(" REMOVE #status_message");
+            }
+
+            let key = self.task_key(task_id);
+
+            // No retry: a failed condition means the caller's expectation is stale
+            let updated = match self
+                .client
+                .update_item()
+                .table_name(&self.config.table_name)
+                .set_key(Some(key))
+                .update_expression(&update_expr)
+                .condition_expression("#status = :expected_status")
+                .set_expression_attribute_names(Some(expr_names))
+                .set_expression_attribute_values(Some(expr_values))
+                .return_values(aws_sdk_dynamodb::types::ReturnValue::AllNew)
+                .send()
+                .await
+            {
+                Ok(output) => match output.attributes() {
+                    Some(returned) => item_to_task_record(returned, task_attrs)?,
+                    None => self
+                        .get_task(task_id)
+                        .await?
+                        .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?,
+                },
+                Err(err) => {
+                    let err_str = err.to_string();
+                    if err_str.contains("ConditionalCheckFailed") {
+                        return Err(status_conflict_error(self, task_id, expected).await);
+                    }
+                    error!("Failed to update task status in DynamoDB: {}", err);
+                    return Err(TaskStorageError::DatabaseError(format!(
+                        "Failed to update task '{}' status: {}",
+                        task_id, err
+                    )));
+                }
+            };
+
+            notify_transition(self.config.observer.as_ref(), task_id, expected, new_status).await;
+            Ok(updated)
+        }
+
+        #[cfg(not(feature = "dynamodb"))]
+        {
+            let _ = (task_id, expected, new_status, status_message);
+            Err(TaskStorageError::Generic(
+                "DynamoDB feature is not enabled".to_string(),
+            ))
+        }
+    }
+
     async fn store_task_result(
         &self,
         task_id: &str,
@@ -1330,10 +1837,7 @@ impl TaskStorage for DynamoDbTaskStorage {
             let result_json = serde_json::to_string(&result)?;
             let now = Self::now_iso8601();
 
-            let key = HashMap::from([(
-                task_attrs.task_id.to_string(),
-                AttributeValue::S(task_id.to_string()),
-            )]);
+            let key = self.task_key(task_id);
 
             let update_expr = "SET #result = :result, #last_updated_at = :now";
             let expr_names = HashMap::from([
@@ -1400,6 +1904,134 @@ impl TaskStorage for DynamoDbTaskStorage {
         Ok(task.result)
     }
 
+    async fn append_task_progress(
+        &self,
+        task_id: &str,
+        chunk: Value,
+    ) -> Result<(), TaskStorageError> {
+        #[cfg(feature = "dynamodb")]
+        {
+            let task_attrs = self.attrs();
+            let chunk_json = serde_json::to_string(&chunk)?;
+
+            let key = self.task_key(task_id);
+            let expr_names = HashMap::from([
+                ("#progress".to_string(), PROGRESS_ATTR.to_string()),
+                ("#task_id".to_string(), task_attrs.task_id.to_string()),
+                ("#status".to_string(), "status".to_string()),
+            ]);
+            let expr_values = HashMap::from([
+                (":empty".to_string(), AttributeValue::L(Vec::new())),
+                (
+                    ":chunk".to_string(),
+                    AttributeValue::L(vec![AttributeValue::S(chunk_json)]),
+                ),
+                (
+                    ":working".to_string(),
+                    AttributeValue::S(status_to_str(TaskStatus::Working).to_string()),
+                ),
+                (
+                    ":input_required".to_string(),
+                    AttributeValue::S(status_to_str(TaskStatus::InputRequired).to_string()),
+                ),
+            ]);
+
+            match self
+                .client
+                .update_item()
+                .table_name(&self.config.table_name)
+                .set_key(Some(key))
+                .update_expression(
+                    "SET #progress = list_append(if_not_exists(#progress, :empty), :chunk)",
+                )
+                .condition_expression(
+                    "attribute_exists(#task_id) AND #status IN (:working, :input_required)",
+                )
+                .set_expression_attribute_names(Some(expr_names))
+                .set_expression_attribute_values(Some(expr_values))
+                .send()
+                .await
+            {
+                Ok(_) => Ok(()),
+                Err(err) => {
+                    let err_str = err.to_string();
+                    if err_str.contains("ConditionalCheckFailed") {
+                        match self.get_task(task_id).await? {
+                            Some(task) => Err(TaskStorageError::TerminalState(task.status)),
+                            None => Err(TaskStorageError::TaskNotFound(task_id.to_string())),
+                        }
+                    } else {
+                        error!("Failed to append task progress in DynamoDB: {}", err);
+                        Err(TaskStorageError::DatabaseError(format!(
+                            "Failed to append progress for task '{}': {}",
+                            task_id, err
+                        )))
+                    }
+                }
+            }
+        }
+
+        #[cfg(not(feature = "dynamodb"))]
+        {
+            let _ = (task_id, chunk);
+            Err(TaskStorageError::Generic(
+                "DynamoDB feature is not enabled".to_string(),
+            ))
+        }
+    }
+
+    async fn get_task_progress(&self, task_id: &str) -> Result<Vec<Value>, TaskStorageError> {
+        #[cfg(feature = "dynamodb")]
+        {
+            let task_attrs = self.attrs();
+            let key = self.task_key(task_id);
+
+            let output = self
+                .client
+                .get_item()
+                .table_name(&self.config.table_name)
+                .set_key(Some(key))
+                .projection_expression("#task_id, #progress")
+                .expression_attribute_names("#task_id", task_attrs.task_id)
+                .expression_attribute_names("#progress", PROGRESS_ATTR)
+                .consistent_read(true)
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to get task progress from DynamoDB: {}", err);
+                    TaskStorageError::DatabaseError(format!(
+                        "Failed to get progress for task '{}': {}",
+                        task_id, err
+                    ))
+                })?;
+
+            let item = output
+                .item()
+                .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+            let Some(AttributeValue::L(chunks)) = item.get(PROGRESS_ATTR) else {
+                return Ok(Vec::new());
+            };
+            chunks
+                .iter()
+                .map(|chunk| match chunk {
+                    AttributeValue::S(json) => Ok(serde_json::from_str(json)?),
+                    other => Err(TaskStorageError::SerializationError(format!(
+                        "Unexpected progress chunk attribute: {:?}",
+                        other
+                    ))),
+                })
+                .collect()
+        }
+
+        #[cfg(not(feature = "dynamodb"))]
+        {
+            let _ = task_id;
+            Err(TaskStorageError::Generic(
+                "DynamoDB feature is not enabled".to_string(),
+            ))
+        }
+    }
+
     async fn expire_tasks(&self) -> Result<Vec<String>, TaskStorageError> {
         #[cfg(feature = "dynamodb")]
         {
@@ -1419,7 +2051,7 @@ impl TaskStorage for DynamoDbTaskStorage {
                     .client
                     .query()
                     .table_name(&self.config.table_name)
-                    .index_name("StatusIndex")
+                    .index_name(self.status_index())
                     .key_condition_expression("#status = :status")
                     .expression_attribute_names("#status", "status")
                     .set_expression_attribute_values(Some(expr_values))
@@ -1465,17 +2097,119 @@ impl TaskStorage for DynamoDbTaskStorage {
         }
     }
 
+    async fn purge_terminal_tasks(
+        &self,
+        older_than: chrono::Duration,
+    ) -> Result<u64, TaskStorageError> {
+        #[cfg(feature = "dynamodb")]
+        {
+            let attrs = self.attrs();
+            let cutoff = Utc::now() - older_than;
+            let names = HashMap::from([
+                ("#status".to_string(), "status".to_string()),
+                ("#task_id".to_string(), attrs.task_id.to_string()),
+                (
+                    "#last_updated_at".to_string(),
+                    attrs.last_updated_at.to_string(),
+                ),
+            ]);
+            let values = HashMap::from([
+                (
+                    ":completed".to_string(),
+                    AttributeValue::S("completed".to_string()),
+                ),
+                (
+                    ":failed".to_string(),
+                    AttributeValue::S("failed".to_string()),
+                ),
+                (
+                    ":cancelled".to_string(),
+                    AttributeValue::S("cancelled".to_string()),
+                ),
+            ]);
+
+            let (filter, names, values) = self
+                .task_scan_filter(Some((
+                    "#status IN (:completed, :failed, :cancelled)".to_string(),
+                    names,
+                    values,
+                )))
+                .expect("task_scan_filter keeps an existing filter");
+
+            // Scan terminal tasks (keys and timestamps only), then delete the stale ones in batches
+            let mut stale = Vec::new();
+            let mut exclusive_start_key = None;
+            loop {
+                let output = self
+                    .client
+                    .scan()
+                    .table_name(&self.config.table_name)
+                    .filter_expression(&filter)
+                    .projection_expression("#task_id, #last_updated_at")
+                    .set_expression_attribute_names(Some(names.clone()))
+                    .set_expression_attribute_values(Some(values.clone()))
+                    .set_exclusive_start_key(exclusive_start_key)
+                    .send()
+                    .await
+                    .map_err(|err| {
+                        error!("Failed to scan terminal tasks from DynamoDB: {}", err);
+                        TaskStorageError::DatabaseError(format!(
+                            "Failed to scan terminal tasks: {}",
+                            err
+                        ))
+                    })?;
+
+                for item in output.items() {
+                    let task_id = item.get(attrs.task_id).and_then(|v| v.as_s().ok());
+                    let updated = item
+                        .get(attrs.last_updated_at)
+                        .and_then(|v| v.as_s().ok())
+                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok());
+                    if let (Some(task_id), Some(updated)) = (task_id, updated)
+                        && updated.with_timezone(&Utc) < cutoff
+                    {
+                        stale.push(task_id.clone());
+                    }
+                }
+
+                exclusive_start_key = output.last_evaluated_key().cloned();
+                if exclusive_start_key.is_none() {
+                    break;
+                }
+            }
+
+            let purged = self.batch_delete_tasks(&stale).await?;
+            if purged > 0 {
+                info!("Purged {} terminal tasks from DynamoDB", purged);
+            }
+            Ok(purged)
+        }
+
+        #[cfg(not(feature = "dynamodb"))]
+        {
+            let _ = older_than;
+            Err(TaskStorageError::Generic(
+                "DynamoDB feature is not enabled".to_string(),
+            ))
+        }
+    }
+
     async fn task_count(&self) -> Result<usize, TaskStorageError> {
         #[cfg(feature = "dynamodb")]
         {
-            match self
+            let mut builder = self
                 .client
                 .scan()
                 .table_name(&self.config.table_name)
-                .select(aws_sdk_dynamodb::types::Select::Count)
-                .send()
-                .await
-            {
+                .select(aws_sdk_dynamodb::types::Select::Count);
+            if let Some((filter, names, values)) = self.task_scan_filter(None) {
+                builder = builder
+                    .filter_expression(filter)
+                    .set_expression_attribute_names(Some(names))
+                    .set_expression_attribute_values(Some(values));
+            }
+
+            match builder.send().await {
                 Ok(output) => {
                     let count = output.count() as usize;
                     debug!("DynamoDB task count: {}", count);
@@ -1510,6 +2244,7 @@ impl TaskStorage for DynamoDbTaskStorage {
         session_id: &str,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError> {
         #[cfg(feature = "dynamodb")]
         {
@@ -1545,7 +2280,7 @@ impl TaskStorage for DynamoDbTaskStorage {
                     None
                 };
 
-            let expr_values = HashMap::from([(
+            let mut expr_values = HashMap::from([(
                 ":session_id".to_string(),
                 AttributeValue::S(session_id.to_string()),
             )]);
@@ -1554,12 +2289,19 @@ impl TaskStorage for DynamoDbTaskStorage {
                 .client
                 .query()
                 .table_name(&self.config.table_name)
-                .index_name("SessionIndex")
+                .index_name(self.session_index())
                 .key_condition_expression(format!("{} = :session_id", attrs.session_id))
-                .set_expression_attribute_values(Some(expr_values))
                 .scan_index_forward(true)
                 .limit(limit as i32);
 
+            if let Some((filter, names, values)) = status_filter_expression(status_filter) {
+                expr_values.extend(values);
+                builder = builder
+                    .filter_expression(filter)
+                    .set_expression_attribute_names(Some(names));
+            }
+            builder = builder.set_expression_attribute_values(Some(expr_values));
+
             if let Some(start_key) = exclusive_start_key {
                 builder = builder.set_exclusive_start_key(Some(start_key));
             }
@@ -1616,7 +2358,7 @@ impl TaskStorage for DynamoDbTaskStorage {
 
         #[cfg(not(feature = "dynamodb"))]
         {
-            let _ = (session_id, cursor, limit);
+            let _ = (session_id, cursor, limit, status_filter);
             Err(TaskStorageError::Generic(
                 "DynamoDB feature is not enabled".to_string(),
             ))
@@ -1641,7 +2383,7 @@ impl TaskStorage for DynamoDbTaskStorage {
                     .client
                     .query()
                     .table_name(&self.config.table_name)
-                    .index_name("StatusIndex")
+                    .index_name(self.status_index())
                     .key_condition_expression("#status = :status")
                     .expression_attribute_names("#status", "status")
                     .set_expression_attribute_values(Some(expr_values))
@@ -1660,10 +2402,7 @@ impl TaskStorage for DynamoDbTaskStorage {
                                         (now - updated.with_timezone(&Utc)).num_milliseconds();
                                     if age_ms > max_age_ms as i64 {
                                         // Mark as Failed using conditional update
-                                        let key = HashMap::from([(
-                                            task_attrs.task_id.to_string(),
-                                            AttributeValue::S(record.task_id.clone()),
-                                        )]);
+                                        let key = self.task_key(&record.task_id);
                                         let update_now = Self::now_iso8601();
 
                                         let update_result = self
@@ -1816,6 +2555,34 @@ mod tests {
         assert_round_trip(NamingConvention::SnakeCase);
     }
 
+    #[tokio::test]
+    async fn test_single_table_task_item_round_trip() {
+        let record = full_test_record();
+        let config = DynamoDbTaskConfig {
+            single_table: true,
+            ..Default::default()
+        };
+        let attrs = TaskAttrNames::for_convention(NamingConvention::CamelCase);
+        let item = task_record_to_item(&record, &config, attrs);
+
+        assert_eq!(item["PK"].as_s().unwrap(), "TASK#test-task-123");
+        assert_eq!(item["SK"].as_s().unwrap(), "TASK");
+        assert_eq!(item["entityType"].as_s().unwrap(), "task");
+        assert_eq!(item["taskCreatedAt"], item["createdAt"]);
+        assert_eq!(item["sessionId"].as_s().unwrap(), "session-456");
+        assert!(item.contains_key("ttlEpoch"));
+
+        // The replacement update rewrites the GSI sort key but never the item key
+        let (expression, names, _) = replace_record_expression(item.clone(), attrs);
+        assert!(names.values().any(|name| name == "taskCreatedAt"));
+        assert!(!names.values().any(|name| name == "PK" || name == "SK"));
+        assert!(!expression.contains("REMOVE"));
+
+        let restored = item_to_task_record(&item, attrs).unwrap();
+        assert_eq!(restored.task_id, record.task_id);
+        assert_eq!(restored.created_at, record.created_at);
+    }
+
     #[tokio::test]
     async fn test_dynamodb_item_conversion_round_trip() {
         // Default: CamelCase (new convention)
@@ -2171,4 +2938,39 @@ mod tests {
         let storage = std::sync::Arc::new(DynamoDbTaskStorage::new().await.unwrap());
         crate::parity_tests::test_concurrent_status_updates(storage).await;
     }
+
+    #[tokio::test]
+    #[ignore = "Requires AWS DynamoDB connection"]
+    async fn parity_status_compare_and_set() {
+        let storage = std::sync::Arc::new(DynamoDbTaskStorage::new().await.unwrap());
+        crate::parity_tests::test_status_compare_and_set(storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires AWS DynamoDB connection"]
+    async fn parity_task_progress() {
+        let storage = DynamoDbTaskStorage::new().await.unwrap();
+        crate::parity_tests::test_task_progress(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires AWS DynamoDB connection"]
+    async fn parity_watch_task_until_terminal() {
+        let storage = DynamoDbTaskStorage::new().await.unwrap();
+        crate::parity_tests::test_watch_task_until_terminal(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires AWS DynamoDB connection"]
+    async fn parity_purge_terminal_tasks() {
+        let storage = DynamoDbTaskStorage::new().await.unwrap();
+        crate::parity_tests::test_purge_terminal_tasks(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires AWS DynamoDB connection"]
+    async fn parity_status_filtered_listing() {
+        let storage = DynamoDbTaskStorage::new().await.unwrap();
+        crate::parity_tests::test_status_filtered_listing(&storage).await;
+    }
 }
@@ -10,10 +10,12 @@ pub enum TaskStorageError {
     #[error("Task not found: {0}")]
     TaskNotFound(String),
 
-    #[error("Invalid state transition: {current:?} -> {requested:?}")]
+    #[error("Invalid state transition: {from:?} -> {attempted:?} (allowed: {allowed:?})")]
     InvalidTransition {
-        current: TaskStatus,
-        requested: TaskStatus,
+        from: TaskStatus,
+        attempted: TaskStatus,
+        /// Statuses `from` may move to (see [`allowed_transitions`](crate::state_machine::allowed_transitions))
+        allowed: Vec<TaskStatus>,
     },
 
     #[error("Task is in terminal state: {0:?}")]
@@ -28,6 +30,15 @@ pub enum TaskStorageError {
     #[error("Concurrent modification: {0}")]
     ConcurrentModification(String),
 
+    #[error("Status conflict on task {task_id}: expected {expected:?}, found {actual:?}")]
+    StatusConflict {
+        task_id: String,
+        /// Status the caller required (see [`TaskStorage::update_task_status_if`](crate::TaskStorage::update_task_status_if))
+        expected: TaskStatus,
+        /// Status actually stored when the update was attempted
+        actual: TaskStatus,
+    },
+
     #[error("Database error: {0}")]
     DatabaseError(String),
 
@@ -50,3 +61,10 @@ impl From<sqlx::Error> for TaskStorageError {
         TaskStorageError::DatabaseError(err.to_string())
     }
 }
+
+#[cfg(feature = "redis")]
+impl From<redis::RedisError> for TaskStorageError {
+    fn from(err: redis::RedisError) -> Self {
+        TaskStorageError::DatabaseError(err.to_string())
+    }
+}
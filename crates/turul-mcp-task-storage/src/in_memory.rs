@@ -1,16 +1,20 @@
 //! In-memory task storage backend.
 //!
 //! Suitable for development, testing, and single-instance deployments.
-//! Tasks are stored in a `HashMap` behind an `RwLock`.
+//! Tasks are stored in a `HashMap` behind an `RwLock`. `watch_task` subscribers
+//! are fed from a `tokio::sync::watch` channel per watched task.
 
 use crate::error::TaskStorageError;
+use crate::observer::{TaskObserver, notify_transition};
 use crate::state_machine;
 use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage};
 use async_trait::async_trait;
 use chrono::Utc;
+use futures::stream::{self, BoxStream, StreamExt};
+use serde_json::Value;
 use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{RwLock, watch};
 use turul_mcp_protocol::TaskStatus;
 use uuid::Uuid;
 
@@ -21,6 +25,8 @@ pub struct InMemoryTaskConfig {
     pub max_tasks: usize,
     /// Default page size for list operations
     pub default_page_size: u32,
+    /// Observer notified of committed status transitions.
+    pub observer: Option<Arc<dyn TaskObserver>>,
 }
 
 impl Default for InMemoryTaskConfig {
@@ -28,10 +34,19 @@ impl Default for InMemoryTaskConfig {
         Self {
             max_tasks: 10_000,
             default_page_size: 50,
+            observer: None,
         }
     }
 }
 
+impl InMemoryTaskConfig {
+    /// Notify `observer` of every status transition committed by `update_task_status`.
+    pub fn with_observer(mut self, observer: Arc<dyn TaskObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+}
+
 /// In-memory task storage backend.
 ///
 /// Uses `Arc<RwLock<HashMap>>` for concurrent access.
@@ -39,6 +54,9 @@ impl Default for InMemoryTaskConfig {
 #[derive(Clone)]
 pub struct InMemoryTaskStorage {
     tasks: Arc<RwLock<HashMap<String, TaskRecord>>>,
+    watchers: Arc<Mutex<HashMap<String, watch::Sender<TaskRecord>>>>,
+    /// Progress chunks per task, kept beside the record so `update_task` cannot drop them.
+    progress: Arc<Mutex<HashMap<String, Vec<Value>>>>,
     config: InMemoryTaskConfig,
 }
 
@@ -47,6 +65,8 @@ impl InMemoryTaskStorage {
     pub fn new() -> Self {
         Self {
             tasks: Arc::new(RwLock::new(HashMap::new())),
+            watchers: Arc::new(Mutex::new(HashMap::new())),
+            progress: Arc::new(Mutex::new(HashMap::new())),
             config: InMemoryTaskConfig::default(),
         }
     }
@@ -55,6 +75,8 @@ impl InMemoryTaskStorage {
     pub fn with_config(config: InMemoryTaskConfig) -> Self {
         Self {
             tasks: Arc::new(RwLock::new(HashMap::new())),
+            watchers: Arc::new(Mutex::new(HashMap::new())),
+            progress: Arc::new(Mutex::new(HashMap::new())),
             config,
         }
     }
@@ -67,6 +89,104 @@ impl InMemoryTaskStorage {
     fn now_iso8601() -> String {
         Utc::now().to_rfc3339()
     }
+
+    fn matches_status_filter(task: &TaskRecord, status_filter: Option<&[TaskStatus]>) -> bool {
+        match status_filter {
+            Some(statuses) if !statuses.is_empty() => statuses.contains(&task.status),
+            _ => true,
+        }
+    }
+
+    /// Publish a task's new state to any `watch_task` subscribers.
+    ///
+    /// Called while holding the `tasks` write lock so watchers observe writes in order.
+    fn notify_watchers(&self, task: &TaskRecord) {
+        let watchers = self.watchers.lock().unwrap();
+        if let Some(sender) = watchers.get(&task.task_id) {
+            sender.send_replace(task.clone());
+        }
+    }
+
+    /// Release per-task state for a removed task: closes its watch channel, ending
+    /// subscribers' streams, and drops its progress chunks.
+    fn release_task(&self, task_id: &str) {
+        self.watchers.lock().unwrap().remove(task_id);
+        self.progress.lock().unwrap().remove(task_id);
+    }
+
+    /// Number of tasks that currently have at least one live `watch_task` subscriber.
+    pub fn watched_task_count(&self) -> usize {
+        self.watchers.lock().unwrap().len()
+    }
+
+    /// Shared body of `update_task_status` / `update_task_status_if`; the check
+    /// against `expected` happens under the same write lock as the mutation.
+    async fn set_status(
+        &self,
+        task_id: &str,
+        expected: Option<TaskStatus>,
+        new_status: TaskStatus,
+        status_message: Option<String>,
+    ) -> Result<TaskRecord, TaskStorageError> {
+        let mut tasks = self.tasks.write().await;
+
+        let task = tasks
+            .get_mut(task_id)
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+
+        if let Some(expected) = expected
+            && task.status != expected
+        {
+            return Err(TaskStorageError::StatusConflict {
+                task_id: task_id.to_string(),
+                expected,
+                actual: task.status,
+            });
+        }
+
+        // Validate state machine transition
+        state_machine::validate_transition(task.status, new_status)?;
+
+        let from = task.status;
+        task.status = new_status;
+        task.status_message = status_message;
+        task.last_updated_at = Self::now_iso8601();
+
+        let task = task.clone();
+        self.notify_watchers(&task);
+        drop(tasks);
+
+        notify_transition(self.config.observer.as_ref(), task_id, from, new_status).await;
+        Ok(task)
+    }
+}
+
+/// Subscriber side of a `watch_task` stream.
+///
+/// On drop, removes the task's channel once its last subscriber has gone away.
+struct WatchSubscription {
+    task_id: String,
+    receiver: Option<watch::Receiver<TaskRecord>>,
+    watchers: Arc<Mutex<HashMap<String, watch::Sender<TaskRecord>>>>,
+}
+
+impl Drop for WatchSubscription {
+    fn drop(&mut self) {
+        drop(self.receiver.take());
+        let mut watchers = self.watchers.lock().unwrap();
+        if watchers
+            .get(&self.task_id)
+            .is_some_and(|sender| sender.receiver_count() == 0)
+        {
+            watchers.remove(&self.task_id);
+        }
+    }
+}
+
+enum WatchState {
+    Start,
+    Watching(WatchSubscription),
+    Done,
 }
 
 impl Default for InMemoryTaskStorage {
@@ -110,12 +230,14 @@ impl TaskStorage for InMemoryTaskStorage {
         if !tasks.contains_key(&task.task_id) {
             return Err(TaskStorageError::TaskNotFound(task.task_id.clone()));
         }
+        self.notify_watchers(&task);
         tasks.insert(task.task_id.clone(), task);
         Ok(())
     }
 
     async fn delete_task(&self, task_id: &str) -> Result<bool, TaskStorageError> {
         let mut tasks = self.tasks.write().await;
+        self.release_task(task_id);
         Ok(tasks.remove(task_id).is_some())
     }
 
@@ -123,12 +245,17 @@ impl TaskStorage for InMemoryTaskStorage {
         &self,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError> {
         let tasks = self.tasks.read().await;
         let limit = limit.unwrap_or(self.config.default_page_size) as usize;
 
-        // Sort by (created_at, task_id) for deterministic ordering
-        let mut sorted: Vec<&TaskRecord> = tasks.values().collect();
+        // Filter before the cursor is applied so pagination counts only matching tasks,
+        // then sort by (created_at, task_id) for deterministic ordering
+        let mut sorted: Vec<&TaskRecord> = tasks
+            .values()
+            .filter(|t| Self::matches_status_filter(t, status_filter))
+            .collect();
         sorted.sort_by(|a, b| {
             a.created_at
                 .cmp(&b.created_at)
@@ -172,20 +299,19 @@ impl TaskStorage for InMemoryTaskStorage {
         new_status: TaskStatus,
         status_message: Option<String>,
     ) -> Result<TaskRecord, TaskStorageError> {
-        let mut tasks = self.tasks.write().await;
-
-        let task = tasks
-            .get_mut(task_id)
-            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
-
-        // Validate state machine transition
-        state_machine::validate_transition(task.status, new_status)?;
-
-        task.status = new_status;
-        task.status_message = status_message;
-        task.last_updated_at = Self::now_iso8601();
+        self.set_status(task_id, None, new_status, status_message)
+            .await
+    }
 
-        Ok(task.clone())
+    async fn update_task_status_if(
+        &self,
+        task_id: &str,
+        expected: TaskStatus,
+        new_status: TaskStatus,
+        status_message: Option<String>,
+    ) -> Result<TaskRecord, TaskStorageError> {
+        self.set_status(task_id, Some(expected), new_status, status_message)
+            .await
     }
 
     async fn store_task_result(
@@ -202,6 +328,8 @@ impl TaskStorage for InMemoryTaskStorage {
         task.result = Some(result);
         task.last_updated_at = Self::now_iso8601();
 
+        let task = task.clone();
+        self.notify_watchers(&task);
         Ok(())
     }
 
@@ -218,6 +346,45 @@ impl TaskStorage for InMemoryTaskStorage {
         Ok(task.result.clone())
     }
 
+    async fn append_task_progress(
+        &self,
+        task_id: &str,
+        chunk: Value,
+    ) -> Result<(), TaskStorageError> {
+        // Hold the read lock so the task cannot be removed mid-append
+        let tasks = self.tasks.read().await;
+
+        let task = tasks
+            .get(task_id)
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+        if state_machine::is_terminal(task.status) {
+            return Err(TaskStorageError::TerminalState(task.status));
+        }
+
+        self.progress
+            .lock()
+            .unwrap()
+            .entry(task_id.to_string())
+            .or_default()
+            .push(chunk);
+        Ok(())
+    }
+
+    async fn get_task_progress(&self, task_id: &str) -> Result<Vec<Value>, TaskStorageError> {
+        let tasks = self.tasks.read().await;
+        if !tasks.contains_key(task_id) {
+            return Err(TaskStorageError::TaskNotFound(task_id.to_string()));
+        }
+
+        Ok(self
+            .progress
+            .lock()
+            .unwrap()
+            .get(task_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
     async fn expire_tasks(&self) -> Result<Vec<String>, TaskStorageError> {
         let mut tasks = self.tasks.write().await;
         let now = Utc::now();
@@ -240,12 +407,38 @@ impl TaskStorage for InMemoryTaskStorage {
 
         for id in to_expire {
             tasks.remove(&id);
+            self.release_task(&id);
             expired.push(id);
         }
 
         Ok(expired)
     }
 
+    async fn purge_terminal_tasks(
+        &self,
+        older_than: chrono::Duration,
+    ) -> Result<u64, TaskStorageError> {
+        let mut tasks = self.tasks.write().await;
+        let cutoff = Utc::now() - older_than;
+
+        let to_purge: Vec<String> = tasks
+            .values()
+            .filter(|t| state_machine::is_terminal(t.status))
+            .filter(|t| {
+                chrono::DateTime::parse_from_rfc3339(&t.last_updated_at)
+                    .is_ok_and(|updated| updated.with_timezone(&Utc) < cutoff)
+            })
+            .map(|t| t.task_id.clone())
+            .collect();
+
+        for id in &to_purge {
+            tasks.remove(id);
+            self.release_task(id);
+        }
+
+        Ok(to_purge.len() as u64)
+    }
+
     async fn task_count(&self) -> Result<usize, TaskStorageError> {
         let tasks = self.tasks.read().await;
         Ok(tasks.len())
@@ -261,14 +454,16 @@ impl TaskStorage for InMemoryTaskStorage {
         session_id: &str,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError> {
         let tasks = self.tasks.read().await;
         let limit = limit.unwrap_or(self.config.default_page_size) as usize;
 
-        // Filter by session_id, sort by (created_at, task_id) for deterministic ordering
+        // Filter by session_id and status, sort by (created_at, task_id) for deterministic ordering
         let mut sorted: Vec<&TaskRecord> = tasks
             .values()
             .filter(|t| t.session_id.as_deref() == Some(session_id))
+            .filter(|t| Self::matches_status_filter(t, status_filter))
             .collect();
         sorted.sort_by(|a, b| {
             a.created_at
@@ -321,6 +516,7 @@ impl TaskStorage for InMemoryTaskStorage {
                     task.status = TaskStatus::Failed;
                     task.status_message = Some("Server restarted — task interrupted".to_string());
                     task.last_updated_at = Self::now_iso8601();
+                    self.notify_watchers(task);
                     recovered.push(task.task_id.clone());
                 }
             }
@@ -328,6 +524,60 @@ impl TaskStorage for InMemoryTaskStorage {
 
         Ok(recovered)
     }
+
+    fn watch_task<'a>(
+        &'a self,
+        task_id: &'a str,
+    ) -> BoxStream<'a, Result<TaskRecord, TaskStorageError>> {
+        stream::unfold(WatchState::Start, move |state| async move {
+            match state {
+                WatchState::Start => {
+                    // Subscribe under the read lock so no write can slip in between
+                    // the initial snapshot and the subscription.
+                    let tasks = self.tasks.read().await;
+                    let Some(task) = tasks.get(task_id).cloned() else {
+                        let err = TaskStorageError::TaskNotFound(task_id.to_string());
+                        return Some((Err(err), WatchState::Done));
+                    };
+                    if state_machine::is_terminal(task.status) {
+                        return Some((Ok(task), WatchState::Done));
+                    }
+                    let receiver = self
+                        .watchers
+                        .lock()
+                        .unwrap()
+                        .entry(task_id.to_string())
+                        .or_insert_with(|| watch::channel(task.clone()).0)
+                        .subscribe();
+                    drop(tasks);
+
+                    let subscription = WatchSubscription {
+                        task_id: task_id.to_string(),
+                        receiver: Some(receiver),
+                        watchers: Arc::clone(&self.watchers),
+                    };
+                    Some((Ok(task), WatchState::Watching(subscription)))
+                }
+                WatchState::Watching(mut subscription) => {
+                    let receiver = subscription.receiver.as_mut()?;
+                    if receiver.changed().await.is_err() {
+                        // Sender dropped: the task was deleted or expired
+                        let err = TaskStorageError::TaskNotFound(task_id.to_string());
+                        return Some((Err(err), WatchState::Done));
+                    }
+                    let task = receiver.borrow_and_update().clone();
+                    let next = if state_machine::is_terminal(task.status) {
+                        WatchState::Done
+                    } else {
+                        WatchState::Watching(subscription)
+                    };
+                    Some((Ok(task), next))
+                }
+                WatchState::Done => None,
+            }
+        })
+        .boxed()
+    }
 }
 
 #[cfg(test)]
@@ -538,7 +788,7 @@ mod tests {
         }
 
         // Page 1: limit 2
-        let page1 = storage.list_tasks(None, Some(2)).await.unwrap();
+        let page1 = storage.list_tasks(None, Some(2), None).await.unwrap();
         assert_eq!(page1.tasks.len(), 2);
         assert_eq!(page1.tasks[0].task_id, "task-0");
         assert_eq!(page1.tasks[1].task_id, "task-1");
@@ -546,7 +796,7 @@ mod tests {
 
         // Page 2: using cursor from page 1
         let page2 = storage
-            .list_tasks(page1.next_cursor.as_deref(), Some(2))
+            .list_tasks(page1.next_cursor.as_deref(), Some(2), None)
             .await
             .unwrap();
         assert_eq!(page2.tasks.len(), 2);
@@ -555,7 +805,7 @@ mod tests {
 
         // Page 3: last page
         let page3 = storage
-            .list_tasks(page2.next_cursor.as_deref(), Some(2))
+            .list_tasks(page2.next_cursor.as_deref(), Some(2), None)
             .await
             .unwrap();
         assert_eq!(page3.tasks.len(), 1);
@@ -563,6 +813,114 @@ mod tests {
         assert!(page3.next_cursor.is_none());
     }
 
+    /// Records every transition it observes
+    #[derive(Default)]
+    struct RecordingObserver {
+        transitions: Mutex<Vec<(String, TaskStatus, TaskStatus)>>,
+    }
+
+    #[async_trait]
+    impl TaskObserver for RecordingObserver {
+        async fn on_transition(&self, task_id: &str, from: TaskStatus, to: TaskStatus) {
+            self.transitions
+                .lock()
+                .unwrap()
+                .push((task_id.to_string(), from, to));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_sees_committed_transitions_in_order() {
+        let observer = Arc::new(RecordingObserver::default());
+        let storage = InMemoryTaskStorage::with_config(
+            InMemoryTaskConfig::default().with_observer(observer.clone()),
+        );
+        storage
+            .create_task(make_task("observed", None))
+            .await
+            .unwrap();
+
+        storage
+            .update_task_status("observed", TaskStatus::InputRequired, None)
+            .await
+            .unwrap();
+        storage
+            .update_task_status("observed", TaskStatus::Working, None)
+            .await
+            .unwrap();
+        storage
+            .update_task_status("observed", TaskStatus::Completed, None)
+            .await
+            .unwrap();
+
+        // Rejected transitions never reach the observer
+        assert!(
+            storage
+                .update_task_status("observed", TaskStatus::Working, None)
+                .await
+                .is_err()
+        );
+        assert!(
+            storage
+                .update_task_status("missing", TaskStatus::Completed, None)
+                .await
+                .is_err()
+        );
+
+        assert_eq!(
+            *observer.transitions.lock().unwrap(),
+            vec![
+                (
+                    "observed".to_string(),
+                    TaskStatus::Working,
+                    TaskStatus::InputRequired
+                ),
+                (
+                    "observed".to_string(),
+                    TaskStatus::InputRequired,
+                    TaskStatus::Working
+                ),
+                (
+                    "observed".to_string(),
+                    TaskStatus::Working,
+                    TaskStatus::Completed
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_status_filter() {
+        let storage = InMemoryTaskStorage::new();
+
+        for i in 0..6 {
+            let mut task =
+                make_task_with_time(&format!("task-{}", i), &format!("2025-01-01T00:00:0{}Z", i));
+            if i % 2 == 1 {
+                task.status = TaskStatus::Completed;
+            }
+            storage.create_task(task).await.unwrap();
+        }
+
+        // The cursor and limit apply to matching tasks only
+        let working = [TaskStatus::Working];
+        let page1 = storage
+            .list_tasks(None, Some(2), Some(&working))
+            .await
+            .unwrap();
+        let ids: Vec<&str> = page1.tasks.iter().map(|t| t.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-0", "task-2"]);
+        assert!(page1.next_cursor.is_some());
+
+        let page2 = storage
+            .list_tasks(page1.next_cursor.as_deref(), Some(2), Some(&working))
+            .await
+            .unwrap();
+        let ids: Vec<&str> = page2.tasks.iter().map(|t| t.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-4"]);
+        assert!(page2.next_cursor.is_none());
+    }
+
     #[tokio::test]
     async fn test_session_binding() {
         let storage = InMemoryTaskStorage::new();
@@ -581,20 +939,20 @@ mod tests {
             .unwrap();
 
         let session1_tasks = storage
-            .list_tasks_for_session("session-1", None, None)
+            .list_tasks_for_session("session-1", None, None, None)
             .await
             .unwrap();
         assert_eq!(session1_tasks.tasks.len(), 2);
 
         let session2_tasks = storage
-            .list_tasks_for_session("session-2", None, None)
+            .list_tasks_for_session("session-2", None, None, None)
             .await
             .unwrap();
         assert_eq!(session2_tasks.tasks.len(), 1);
         assert_eq!(session2_tasks.tasks[0].task_id, "task-c");
 
         let empty = storage
-            .list_tasks_for_session("session-3", None, None)
+            .list_tasks_for_session("session-3", None, None, None)
             .await
             .unwrap();
         assert_eq!(empty.tasks.len(), 0);
@@ -804,6 +1162,65 @@ mod tests {
         assert!(uuid::Uuid::parse_str(&id1).is_ok());
     }
 
+    #[tokio::test]
+    async fn test_watch_task_cleans_up_when_subscriber_drops() {
+        use futures::StreamExt;
+
+        let storage = InMemoryTaskStorage::new();
+        storage
+            .create_task(make_task("task-watch", None))
+            .await
+            .unwrap();
+
+        let mut first = storage.watch_task("task-watch");
+        let mut second = storage.watch_task("task-watch");
+        assert_eq!(
+            first.next().await.unwrap().unwrap().status,
+            TaskStatus::Working
+        );
+        assert_eq!(
+            second.next().await.unwrap().unwrap().status,
+            TaskStatus::Working
+        );
+        assert_eq!(storage.watched_task_count(), 1);
+
+        drop(first);
+        assert_eq!(storage.watched_task_count(), 1);
+
+        storage
+            .update_task_status("task-watch", TaskStatus::InputRequired, None)
+            .await
+            .unwrap();
+        let update = second.next().await.unwrap().unwrap();
+        assert_eq!(update.status, TaskStatus::InputRequired);
+
+        drop(second);
+        assert_eq!(storage.watched_task_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_watch_task_ends_when_task_deleted() {
+        use futures::StreamExt;
+
+        let storage = InMemoryTaskStorage::new();
+        storage
+            .create_task(make_task("task-gone", None))
+            .await
+            .unwrap();
+
+        let mut stream = storage.watch_task("task-gone");
+        assert!(stream.next().await.unwrap().is_ok());
+
+        storage.delete_task("task-gone").await.unwrap();
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(TaskStorageError::TaskNotFound(_)))
+        ));
+        assert!(stream.next().await.is_none());
+        drop(stream);
+        assert_eq!(storage.watched_task_count(), 0);
+    }
+
     // === Parity tests ===
 
     #[tokio::test]
@@ -874,4 +1291,34 @@ mod tests {
         let storage = std::sync::Arc::new(InMemoryTaskStorage::new());
         crate::parity_tests::test_concurrent_status_updates(storage).await;
     }
+
+    #[tokio::test]
+    async fn parity_status_compare_and_set() {
+        let storage = std::sync::Arc::new(InMemoryTaskStorage::new());
+        crate::parity_tests::test_status_compare_and_set(storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_task_progress() {
+        let storage = InMemoryTaskStorage::new();
+        crate::parity_tests::test_task_progress(&storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_watch_task_until_terminal() {
+        let storage = InMemoryTaskStorage::new();
+        crate::parity_tests::test_watch_task_until_terminal(&storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_purge_terminal_tasks() {
+        let storage = InMemoryTaskStorage::new();
+        crate::parity_tests::test_purge_terminal_tasks(&storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_status_filtered_listing() {
+        let storage = InMemoryTaskStorage::new();
+        crate::parity_tests::test_status_filtered_listing(&storage).await;
+    }
 }
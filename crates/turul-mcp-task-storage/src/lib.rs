@@ -48,11 +48,13 @@
 //! - **`TaskRecord`**: Persistence model (serializable, no runtime handles)
 //! - **`TaskOutcome`**: Success/Error result stored for `tasks/result` retrieval
 //! - **State machine**: Validates transitions per MCP spec lifecycle
+//! - **`TaskObserver`**: Hook for committed status transitions (metrics, audit logs)
 
 // Core modules
 pub mod error;
 #[cfg(feature = "in-memory")]
 pub mod in_memory;
+pub mod observer;
 pub mod prelude;
 pub mod state_machine;
 pub mod traits;
@@ -62,6 +64,8 @@ pub mod traits;
 pub mod dynamodb;
 #[cfg(feature = "postgres")]
 pub mod postgres;
+#[cfg(feature = "redis")]
+pub mod redis;
 #[cfg(feature = "sqlite")]
 pub mod sqlite;
 
@@ -74,15 +78,18 @@ pub(crate) mod parity_tests;
 pub use error::TaskStorageError;
 #[cfg(feature = "in-memory")]
 pub use in_memory::{InMemoryTaskConfig, InMemoryTaskStorage};
-pub use state_machine::{is_terminal, validate_transition};
+pub use observer::TaskObserver;
+pub use state_machine::{allowed_transitions, is_terminal, validate_transition};
 pub use traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage};
 
+#[cfg(feature = "redis")]
+pub use crate::redis::{RedisTaskConfig, RedisTaskStorage};
 #[cfg(feature = "dynamodb")]
 pub use dynamodb::{DynamoDbTaskConfig, DynamoDbTaskStorage};
 #[cfg(feature = "postgres")]
-pub use postgres::{PostgresTaskConfig, PostgresTaskStorage};
+pub use postgres::{PoolStats, PostgresTaskConfig, PostgresTaskStorage};
 #[cfg(feature = "sqlite")]
-pub use sqlite::{SqliteTaskConfig, SqliteTaskStorage};
+pub use sqlite::{SqliteTaskConfig, SqliteTaskStorage, SqliteVacuumMode};
 
 /// Create a default in-memory task storage instance for development and testing.
 #[cfg(feature = "in-memory")]
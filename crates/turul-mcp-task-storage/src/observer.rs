@@ -0,0 +1,59 @@
+//! Task state-transition observers.
+//!
+//! A [`TaskObserver`] is notified after a backend commits a status change made
+//! through [`TaskStorage::update_task_status`](crate::TaskStorage::update_task_status).
+//! Transitions are validated by [`validate_transition`](crate::validate_transition)
+//! before they are written, so observers only ever see legal transitions. Use it
+//! for metrics and audit logs.
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use turul_mcp_protocol::TaskStatus;
+//! use turul_mcp_task_storage::prelude::*;
+//!
+//! struct AuditLog;
+//!
+//! #[async_trait::async_trait]
+//! impl TaskObserver for AuditLog {
+//!     async fn on_transition(&self, task_id: &str, from: TaskStatus, to: TaskStatus) {
+//!         println!("task {task_id}: {from:?} -> {to:?}");
+//!     }
+//! }
+//!
+//! let storage = InMemoryTaskStorage::with_config(
+//!     InMemoryTaskConfig::default().with_observer(Arc::new(AuditLog)),
+//! );
+//! ```
+
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+use turul_mcp_protocol::TaskStatus;
+
+/// Receives task status transitions once they are committed to storage.
+#[async_trait]
+pub trait TaskObserver: Send + Sync {
+    /// Called after `task_id` moved from `from` to `to`.
+    ///
+    /// Runs inline on the `update_task_status` call, after the backend has released
+    /// its locks; keep it quick or hand work off to a background task.
+    async fn on_transition(&self, task_id: &str, from: TaskStatus, to: TaskStatus);
+}
+
+impl fmt::Debug for dyn TaskObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TaskObserver")
+    }
+}
+
+/// Notify the configured observer, if any, of a committed transition.
+pub(crate) async fn notify_transition(
+    observer: Option<&Arc<dyn TaskObserver>>,
+    task_id: &str,
+    from: TaskStatus,
+    to: TaskStatus,
+) {
+    if let Some(observer) = observer {
+        observer.on_transition(task_id, from, to).await;
+    }
+}
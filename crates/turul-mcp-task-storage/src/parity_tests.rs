@@ -127,9 +127,14 @@ pub async fn test_state_machine_enforcement(storage: &dyn TaskStorage) {
         .await
         .unwrap_err();
     match err {
-        TaskStorageError::InvalidTransition { current, requested } => {
-            assert_eq!(current, TaskStatus::Working);
-            assert_eq!(requested, TaskStatus::Working);
+        TaskStorageError::InvalidTransition {
+            from,
+            attempted,
+            allowed,
+        } => {
+            assert_eq!(from, TaskStatus::Working);
+            assert_eq!(attempted, TaskStatus::Working);
+            assert!(!allowed.contains(&TaskStatus::Working));
         }
         other => panic!("Expected InvalidTransition, got: {:?}", other),
     }
@@ -213,7 +218,7 @@ pub async fn test_cursor_determinism(storage: &dyn TaskStorage) {
 
     loop {
         let page = storage
-            .list_tasks_for_session("cursor-session", cursor.as_deref(), Some(3))
+            .list_tasks_for_session("cursor-session", cursor.as_deref(), Some(3), None)
             .await
             .unwrap();
 
@@ -233,6 +238,86 @@ pub async fn test_cursor_determinism(storage: &dyn TaskStorage) {
     );
 }
 
+/// A status-filtered listing returns only matching tasks, in `(created_at, task_id)`
+/// order, and paginating it yields exactly the matching tasks with no gaps.
+pub async fn test_status_filtered_listing(storage: &dyn TaskStorage) {
+    let statuses = [
+        TaskStatus::Working,
+        TaskStatus::Completed,
+        TaskStatus::InputRequired,
+        TaskStatus::Failed,
+        TaskStatus::Working,
+        TaskStatus::Cancelled,
+        TaskStatus::InputRequired,
+        TaskStatus::Completed,
+        TaskStatus::Working,
+    ];
+    for (i, status) in statuses.iter().enumerate() {
+        let mut task = make_task(
+            &format!("parity-filter-{:02}", i),
+            Some("filter-session"),
+            &format!("2025-06-01T12:00:{:02}Z", i),
+        );
+        task.status = *status;
+        storage.create_task(task).await.unwrap();
+    }
+
+    let active = [TaskStatus::Working, TaskStatus::InputRequired];
+    let expected: Vec<String> = [0, 2, 4, 6, 8]
+        .iter()
+        .map(|i| format!("parity-filter-{:02}", i))
+        .collect();
+
+    // Single page
+    let page = storage
+        .list_tasks_for_session("filter-session", None, None, Some(&active))
+        .await
+        .unwrap();
+    let ids: Vec<String> = page.tasks.iter().map(|t| t.task_id.clone()).collect();
+    assert_eq!(
+        ids, expected,
+        "Filtered listing must hold only active tasks, in order"
+    );
+
+    // Paginated with a page size that does not divide the match count
+    let mut collected = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = storage
+            .list_tasks_for_session("filter-session", cursor.as_deref(), Some(2), Some(&active))
+            .await
+            .unwrap();
+        assert!(
+            page.tasks.iter().all(|t| active.contains(&t.status)),
+            "Every page must respect the status filter"
+        );
+        collected.extend(page.tasks.iter().map(|t| t.task_id.clone()));
+        if page.next_cursor.is_none() {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    assert_eq!(
+        collected, expected,
+        "Filtered pagination must not skip or repeat tasks"
+    );
+
+    // A single terminal status
+    let page = storage
+        .list_tasks_for_session("filter-session", None, None, Some(&[TaskStatus::Completed]))
+        .await
+        .unwrap();
+    let ids: Vec<&str> = page.tasks.iter().map(|t| t.task_id.as_str()).collect();
+    assert_eq!(ids, vec!["parity-filter-01", "parity-filter-07"]);
+
+    // An empty filter lists every status
+    let page = storage
+        .list_tasks_for_session("filter-session", None, None, Some(&[]))
+        .await
+        .unwrap();
+    assert_eq!(page.tasks.len(), statuses.len());
+}
+
 /// Tasks from session A never appear in session B listing.
 pub async fn test_session_scoping(storage: &dyn TaskStorage) {
     let t1 = make_task(
@@ -256,7 +341,7 @@ pub async fn test_session_scoping(storage: &dyn TaskStorage) {
     storage.create_task(t3).await.unwrap();
 
     let page_a = storage
-        .list_tasks_for_session("scope-sess-A", None, None)
+        .list_tasks_for_session("scope-sess-A", None, None, None)
         .await
         .unwrap();
     assert_eq!(page_a.tasks.len(), 2);
@@ -268,14 +353,14 @@ pub async fn test_session_scoping(storage: &dyn TaskStorage) {
     );
 
     let page_b = storage
-        .list_tasks_for_session("scope-sess-B", None, None)
+        .list_tasks_for_session("scope-sess-B", None, None, None)
         .await
         .unwrap();
     assert_eq!(page_b.tasks.len(), 1);
     assert_eq!(page_b.tasks[0].task_id, "parity-scope-b1");
 
     let page_empty = storage
-        .list_tasks_for_session("scope-sess-C", None, None)
+        .list_tasks_for_session("scope-sess-C", None, None, None)
         .await
         .unwrap();
     assert_eq!(page_empty.tasks.len(), 0);
@@ -493,8 +578,9 @@ pub async fn test_error_mapping_parity(storage: &dyn TaskStorage) {
         matches!(
             err,
             TaskStorageError::InvalidTransition {
-                current: TaskStatus::Working,
-                requested: TaskStatus::Working
+                from: TaskStatus::Working,
+                attempted: TaskStatus::Working,
+                ..
             }
         ),
         "Expected InvalidTransition(Working->Working), got: {:?}",
@@ -634,3 +720,326 @@ pub async fn test_concurrent_status_updates(storage: Arc<dyn TaskStorage>) {
         "No phantom duplicate records should be created by concurrent updates"
     );
 }
+
+/// Parity: two workers racing `update_task_status_if` from the same expected status —
+/// exactly one wins, the other gets `StatusConflict` naming the winner's status.
+pub async fn test_status_compare_and_set(storage: Arc<dyn TaskStorage>) {
+    let task = make_task("parity-cas-1", None, "2025-06-01T00:00:00Z");
+    storage.create_task(task).await.unwrap();
+
+    // 1. Race Working -> Completed against Working -> Failed
+    let barrier = Arc::new(tokio::sync::Barrier::new(2));
+    let spawn_update = |new_status: TaskStatus, message: &str| {
+        let storage = Arc::clone(&storage);
+        let barrier = Arc::clone(&barrier);
+        let message = message.to_string();
+        tokio::spawn(async move {
+            barrier.wait().await;
+            storage
+                .update_task_status_if(
+                    "parity-cas-1",
+                    TaskStatus::Working,
+                    new_status,
+                    Some(message),
+                )
+                .await
+        })
+    };
+    let complete = spawn_update(TaskStatus::Completed, "completer");
+    let fail = spawn_update(TaskStatus::Failed, "failer");
+
+    let (complete, fail) = tokio::join!(complete, fail);
+    let complete = complete.expect("completer panicked");
+    let fail = fail.expect("failer panicked");
+
+    // 2. Exactly one succeeds; the loser sees the winner's status
+    let (winner, loser_err) = match (complete, fail) {
+        (Ok(winner), Err(e)) | (Err(e), Ok(winner)) => (winner, e),
+        (Ok(_), Ok(_)) => panic!("Both compare-and-set updates succeeded"),
+        (Err(e1), Err(e2)) => panic!(
+            "Both compare-and-set updates failed.\n  err1: {:?}\n  err2: {:?}",
+            e1, e2
+        ),
+    };
+    match loser_err {
+        TaskStorageError::StatusConflict {
+            task_id,
+            expected,
+            actual,
+        } => {
+            assert_eq!(task_id, "parity-cas-1");
+            assert_eq!(expected, TaskStatus::Working);
+            assert_eq!(actual, winner.status);
+        }
+        other => panic!("Expected StatusConflict for the loser, got: {:?}", other),
+    }
+
+    // 3. The winner's write is what persisted
+    let stored = storage.get_task("parity-cas-1").await.unwrap().unwrap();
+    assert_eq!(stored.status, winner.status);
+    assert_eq!(stored.status_message, winner.status_message);
+
+    // 4. A stale expectation on an uncontended task is rejected without writing
+    let task = make_task("parity-cas-2", None, "2025-06-01T00:00:01Z");
+    storage.create_task(task).await.unwrap();
+    let result = storage
+        .update_task_status_if(
+            "parity-cas-2",
+            TaskStatus::InputRequired,
+            TaskStatus::Working,
+            None,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(TaskStorageError::StatusConflict {
+            actual: TaskStatus::Working,
+            ..
+        })
+    ));
+    let unchanged = storage.get_task("parity-cas-2").await.unwrap().unwrap();
+    assert_eq!(unchanged.status, TaskStatus::Working);
+
+    // 5. A matching expectation still goes through the state machine
+    let result = storage
+        .update_task_status_if("parity-cas-1", winner.status, TaskStatus::Working, None)
+        .await;
+    assert!(matches!(result, Err(TaskStorageError::TerminalState(_))));
+
+    // 6. Missing tasks report TaskNotFound
+    let result = storage
+        .update_task_status_if(
+            "parity-cas-missing",
+            TaskStatus::Working,
+            TaskStatus::Completed,
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(TaskStorageError::TaskNotFound(_))));
+}
+
+/// Parity: progress chunks accumulate in order, coexist with the final outcome, stop
+/// at a terminal status, and are removed with their task.
+pub async fn test_task_progress(storage: &dyn TaskStorage) {
+    let task = make_task("parity-progress-1", None, "2025-06-01T00:00:00Z");
+    storage.create_task(task.clone()).await.unwrap();
+    assert!(
+        storage
+            .get_task_progress("parity-progress-1")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    // 1. Chunks come back in append order
+    for n in 0..3 {
+        storage
+            .append_task_progress("parity-progress-1", json!({"chunk": n}))
+            .await
+            .unwrap();
+    }
+    let expected = vec![
+        json!({"chunk": 0}),
+        json!({"chunk": 1}),
+        json!({"chunk": 2}),
+    ];
+    assert_eq!(
+        storage
+            .get_task_progress("parity-progress-1")
+            .await
+            .unwrap(),
+        expected
+    );
+
+    // 2. Record replacement and the final outcome leave chunks untouched
+    let mut replaced = task.clone();
+    replaced.status_message = Some("replaced".to_string());
+    storage.update_task(replaced).await.unwrap();
+    storage
+        .store_task_result(
+            "parity-progress-1",
+            TaskOutcome::Success(json!({"done": true})),
+        )
+        .await
+        .unwrap();
+    storage
+        .update_task_status("parity-progress-1", TaskStatus::Completed, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        storage
+            .get_task_progress("parity-progress-1")
+            .await
+            .unwrap(),
+        expected
+    );
+    assert!(matches!(
+        storage.get_task_result("parity-progress-1").await.unwrap(),
+        Some(TaskOutcome::Success(_))
+    ));
+
+    // 3. Terminal tasks accept no more chunks
+    let result = storage
+        .append_task_progress("parity-progress-1", json!({"chunk": 3}))
+        .await;
+    assert!(matches!(
+        result,
+        Err(TaskStorageError::TerminalState(TaskStatus::Completed))
+    ));
+
+    // 4. Missing tasks report TaskNotFound
+    assert!(matches!(
+        storage
+            .append_task_progress("parity-progress-missing", json!({}))
+            .await,
+        Err(TaskStorageError::TaskNotFound(_))
+    ));
+    assert!(matches!(
+        storage.get_task_progress("parity-progress-missing").await,
+        Err(TaskStorageError::TaskNotFound(_))
+    ));
+
+    // 5. Chunks go with their task; a task recreated under the same ID starts empty
+    assert!(storage.delete_task("parity-progress-1").await.unwrap());
+    assert!(matches!(
+        storage.get_task_progress("parity-progress-1").await,
+        Err(TaskStorageError::TaskNotFound(_))
+    ));
+    storage.create_task(task).await.unwrap();
+    assert!(
+        storage
+            .get_task_progress("parity-progress-1")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
+
+/// Parity: `watch_task` yields the current record, then each update, and ends at
+/// a terminal status; watching a missing task yields a single `TaskNotFound`.
+pub async fn test_watch_task_until_terminal(storage: &dyn TaskStorage) {
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    let mut task = make_task("parity-watch-1", None, "2025-06-01T00:00:00Z");
+    task.poll_interval = Some(10);
+    storage.create_task(task).await.unwrap();
+
+    let watcher = async {
+        let mut stream = storage.watch_task("parity-watch-1");
+        let mut seen = Vec::new();
+        while let Some(item) = stream.next().await {
+            seen.push(item.unwrap().status);
+        }
+        seen
+    };
+
+    let updater = async {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        storage
+            .update_task_status(
+                "parity-watch-1",
+                TaskStatus::InputRequired,
+                Some("need input".to_string()),
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        storage
+            .update_task_status("parity-watch-1", TaskStatus::Completed, None)
+            .await
+            .unwrap();
+    };
+
+    let (seen, ()) = tokio::time::timeout(Duration::from_secs(5), async {
+        tokio::join!(watcher, updater)
+    })
+    .await
+    .expect("watch stream must end once the task is terminal");
+
+    assert_eq!(
+        seen,
+        vec![
+            TaskStatus::Working,
+            TaskStatus::InputRequired,
+            TaskStatus::Completed
+        ]
+    );
+
+    // Watching a terminal task yields it once and ends
+    let mut stream = storage.watch_task("parity-watch-1");
+    let record = stream.next().await.unwrap().unwrap();
+    assert_eq!(record.status, TaskStatus::Completed);
+    assert!(stream.next().await.is_none());
+    drop(stream);
+
+    // Watching a missing task yields TaskNotFound and ends
+    let mut stream = storage.watch_task("parity-watch-missing");
+    assert!(matches!(
+        stream.next().await,
+        Some(Err(TaskStorageError::TaskNotFound(_)))
+    ));
+    assert!(stream.next().await.is_none());
+}
+
+/// Parity: `purge_terminal_tasks` deletes only terminal tasks last updated before
+/// the cutoff and reports how many it removed.
+pub async fn test_purge_terminal_tasks(storage: &dyn TaskStorage) {
+    let old = "2020-01-01T00:00:00Z";
+    for (task_id, status) in [
+        ("parity-purge-completed", TaskStatus::Completed),
+        ("parity-purge-failed", TaskStatus::Failed),
+        ("parity-purge-cancelled", TaskStatus::Cancelled),
+        ("parity-purge-working", TaskStatus::Working),
+    ] {
+        let mut task = make_task(task_id, None, old);
+        task.status = status;
+        storage.create_task(task).await.unwrap();
+    }
+    let mut recent = make_task(
+        "parity-purge-recent",
+        None,
+        &chrono::Utc::now().to_rfc3339(),
+    );
+    recent.status = TaskStatus::Completed;
+    storage.create_task(recent).await.unwrap();
+
+    // Shared database backends may hold other stale terminal tasks, so only a lower bound is exact
+    let purged = storage
+        .purge_terminal_tasks(chrono::Duration::hours(1))
+        .await
+        .unwrap();
+    assert!(purged >= 3, "expected at least 3 purged, got {}", purged);
+
+    for task_id in [
+        "parity-purge-completed",
+        "parity-purge-failed",
+        "parity-purge-cancelled",
+    ] {
+        assert!(storage.get_task(task_id).await.unwrap().is_none());
+    }
+    // Non-terminal tasks survive regardless of age; recent terminal tasks survive the cutoff
+    assert!(
+        storage
+            .get_task("parity-purge-working")
+            .await
+            .unwrap()
+            .is_some()
+    );
+    assert!(
+        storage
+            .get_task("parity-purge-recent")
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    // Nothing left to purge at this cutoff
+    assert_eq!(
+        storage
+            .purge_terminal_tasks(chrono::Duration::hours(1))
+            .await
+            .unwrap(),
+        0
+    );
+}
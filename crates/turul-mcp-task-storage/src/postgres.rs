@@ -5,14 +5,17 @@
 //! requiring task sharing and coordination.
 
 use crate::error::TaskStorageError;
+use crate::observer::{TaskObserver, notify_transition};
 use crate::state_machine;
-use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage};
+use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage, status_conflict_error};
 use async_trait::async_trait;
 use chrono::Utc;
+use serde::Serialize;
 use serde_json::Value;
 use sqlx::postgres::PgRow;
-use sqlx::{PgPool, Row};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{debug, info, warn};
 use turul_mcp_protocol::TaskStatus;
 
@@ -41,6 +44,8 @@ pub struct PostgresTaskConfig {
     pub create_tables: bool,
     /// Statement timeout in seconds
     pub statement_timeout_secs: u32,
+    /// Observer notified of committed status transitions.
+    pub observer: Option<Arc<dyn TaskObserver>>,
 }
 
 impl Default for PostgresTaskConfig {
@@ -56,10 +61,19 @@ impl Default for PostgresTaskConfig {
             verify_tables: false,
             create_tables: false,
             statement_timeout_secs: 30,
+            observer: None,
         }
     }
 }
 
+impl PostgresTaskConfig {
+    /// Notify `observer` of every status transition committed by `update_task_status`.
+    pub fn with_observer(mut self, observer: Arc<dyn TaskObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+}
+
 /// PostgreSQL-backed task storage implementation.
 ///
 /// Uses connection pooling, optimistic locking via a `version` column,
@@ -70,6 +84,35 @@ pub struct PostgresTaskStorage {
     config: PostgresTaskConfig,
 }
 
+/// Snapshot of the connection pool behind a PostgreSQL storage backend.
+///
+/// sqlx does not report how many callers are queued for a connection;
+/// `in_use == max_size` is the signal that new queries have started to wait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PoolStats {
+    /// Open connections, idle or in use
+    pub size: u32,
+    /// Open connections not currently checked out
+    pub idle: u32,
+    /// Connections currently checked out
+    pub in_use: u32,
+    /// Configured `max_connections`
+    pub max_size: u32,
+}
+
+impl PoolStats {
+    fn from_pool(pool: &PgPool) -> Self {
+        let size = pool.size();
+        let idle = pool.num_idle() as u32;
+        Self {
+            size,
+            idle,
+            in_use: size.saturating_sub(idle),
+            max_size: pool.options().get_max_connections(),
+        }
+    }
+}
+
 impl PostgresTaskStorage {
     /// Create a new PostgreSQL task storage with default configuration.
     pub async fn new() -> Result<Self, TaskStorageError> {
@@ -123,6 +166,11 @@ impl PostgresTaskStorage {
         Ok(storage)
     }
 
+    /// Current connection pool usage, for exporting to metrics.
+    pub fn pool_stats(&self) -> PoolStats {
+        PoolStats::from_pool(&self.pool)
+    }
+
     /// Run database schema migrations.
     async fn migrate(&self) -> Result<(), TaskStorageError> {
         debug!("Running PostgreSQL task storage migrations");
@@ -171,6 +219,25 @@ impl PostgresTaskStorage {
             debug!("Partial index creation note: {}", e);
         }
 
+        // Progress chunks, ordered by `seq` and removed with their task
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_progress (
+                seq BIGSERIAL PRIMARY KEY,
+                task_id TEXT NOT NULL REFERENCES tasks (task_id) ON DELETE CASCADE,
+                chunk JSONB NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_task_progress_task ON task_progress (task_id, seq)",
+        )
+        .execute(&self.pool)
+        .await?;
+
         debug!("PostgreSQL task storage migrations completed");
         Ok(())
     }
@@ -194,6 +261,85 @@ impl PostgresTaskStorage {
         });
     }
 
+    /// Fetch one page of tasks ordered by `(created_at, task_id)`.
+    ///
+    /// Session scoping and the status filter are applied in the `WHERE` clause, so
+    /// pages and cursors only ever cover matching tasks.
+    async fn fetch_task_page(
+        &self,
+        session_id: Option<&str>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
+    ) -> Result<TaskListPage, TaskStorageError> {
+        let limit = limit.unwrap_or(self.config.default_page_size) as i64;
+
+        // Two-step cursor: resolve cursor → (created_at, task_id), then paginate.
+        // Cursor not found: start from beginning (graceful degradation).
+        let cursor_position = match cursor {
+            Some(cursor_id) => {
+                sqlx::query("SELECT created_at, task_id FROM tasks WHERE task_id = $1")
+                    .bind(cursor_id)
+                    .fetch_optional(&self.pool)
+                    .await?
+                    .map(|row| {
+                        (
+                            row.get::<String, _>("created_at"),
+                            row.get::<String, _>("task_id"),
+                        )
+                    })
+            }
+            None => None,
+        };
+
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT task_id, session_id, status, status_message,
+                   created_at, last_updated_at, ttl, poll_interval,
+                   original_method, original_params, result, meta, version
+            FROM tasks
+            WHERE 1 = 1"#,
+        );
+        if let Some(session_id) = session_id {
+            query.push(" AND session_id = ").push_bind(session_id);
+        }
+        if let Some(statuses) = status_filter.filter(|statuses| !statuses.is_empty()) {
+            query.push(" AND status IN (");
+            let mut separated = query.separated(", ");
+            for status in statuses {
+                separated.push_bind(status_to_str(*status));
+            }
+            separated.push_unseparated(")");
+        }
+        if let Some((cursor_created_at, cursor_task_id)) = cursor_position {
+            query
+                .push(" AND (created_at, task_id) > (")
+                .push_bind(cursor_created_at)
+                .push(", ")
+                .push_bind(cursor_task_id)
+                .push(")");
+        }
+        query
+            .push(" ORDER BY created_at ASC, task_id ASC LIMIT ")
+            .push_bind(limit);
+
+        let rows = query.build().fetch_all(&self.pool).await?;
+
+        let tasks: Vec<TaskRecord> = rows
+            .iter()
+            .map(row_to_task_record)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Determine next_cursor: if we got a full page, there may be more
+        let next_cursor = if tasks.len() as i64 == limit {
+            tasks.last().map(|t| t.task_id.clone())
+        } else {
+            None
+        };
+
+        Ok(TaskListPage { tasks, next_cursor })
+    }
+
     fn now_iso8601() -> String {
         Utc::now().to_rfc3339()
     }
@@ -447,85 +593,10 @@ impl TaskStorage for PostgresTaskStorage {
         &self,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError> {
-        let limit = limit.unwrap_or(self.config.default_page_size) as i64;
-
-        let rows = if let Some(cursor_id) = cursor {
-            // Two-step cursor: resolve cursor → (created_at, task_id), then paginate
-            let cursor_row =
-                sqlx::query("SELECT created_at, task_id FROM tasks WHERE task_id = $1")
-                    .bind(cursor_id)
-                    .fetch_optional(&self.pool)
-                    .await?;
-
-            match cursor_row {
-                Some(ref cr) => {
-                    let cursor_created_at: String = cr.get("created_at");
-                    let cursor_task_id: String = cr.get("task_id");
-
-                    sqlx::query(
-                        r#"
-                        SELECT task_id, session_id, status, status_message,
-                               created_at, last_updated_at, ttl, poll_interval,
-                               original_method, original_params, result, meta, version
-                        FROM tasks
-                        WHERE (created_at, task_id) > ($1, $2)
-                        ORDER BY created_at ASC, task_id ASC
-                        LIMIT $3
-                        "#,
-                    )
-                    .bind(&cursor_created_at)
-                    .bind(&cursor_task_id)
-                    .bind(limit)
-                    .fetch_all(&self.pool)
-                    .await?
-                }
-                // Cursor not found: start from beginning (graceful degradation)
-                None => {
-                    sqlx::query(
-                        r#"
-                        SELECT task_id, session_id, status, status_message,
-                               created_at, last_updated_at, ttl, poll_interval,
-                               original_method, original_params, result, meta, version
-                        FROM tasks
-                        ORDER BY created_at ASC, task_id ASC
-                        LIMIT $1
-                        "#,
-                    )
-                    .bind(limit)
-                    .fetch_all(&self.pool)
-                    .await?
-                }
-            }
-        } else {
-            sqlx::query(
-                r#"
-                SELECT task_id, session_id, status, status_message,
-                       created_at, last_updated_at, ttl, poll_interval,
-                       original_method, original_params, result, meta, version
-                FROM tasks
-                ORDER BY created_at ASC, task_id ASC
-                LIMIT $1
-                "#,
-            )
-            .bind(limit)
-            .fetch_all(&self.pool)
-            .await?
-        };
-
-        let tasks: Vec<TaskRecord> = rows
-            .iter()
-            .map(row_to_task_record)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        // Determine next_cursor: if we got a full page, there may be more
-        let next_cursor = if tasks.len() as i64 == limit {
-            tasks.last().map(|t| t.task_id.clone())
-        } else {
-            None
-        };
-
-        Ok(TaskListPage { tasks, next_cursor })
+        self.fetch_task_page(None, cursor, limit, status_filter)
+            .await
     }
 
     async fn update_task_status(
@@ -578,12 +649,77 @@ impl TaskStorage for PostgresTaskStorage {
             )));
         }
 
+        notify_transition(
+            self.config.observer.as_ref(),
+            task_id,
+            current_status,
+            new_status,
+        )
+        .await;
+
         // Step 4: Fetch the updated record
         self.get_task(task_id)
             .await?
             .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))
     }
 
+    async fn update_task_status_if(
+        &self,
+        task_id: &str,
+        expected: TaskStatus,
+        new_status: TaskStatus,
+        status_message: Option<String>,
+    ) -> Result<TaskRecord, TaskStorageError> {
+        let current_row = sqlx::query("SELECT status FROM tasks WHERE task_id = $1")
+            .bind(task_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+
+        let current_status_str: String = current_row.get("status");
+        let current_status = str_to_status(&current_status_str)?;
+        if current_status != expected {
+            return Err(TaskStorageError::StatusConflict {
+                task_id: task_id.to_string(),
+                expected,
+                actual: current_status,
+            });
+        }
+
+        state_machine::validate_transition(expected, new_status)?;
+
+        // Guard on the expected status itself rather than the version
+        let now = Self::now_iso8601();
+        let rows_affected = sqlx::query(
+            r#"
+            UPDATE tasks SET
+                status = $1,
+                status_message = $2,
+                last_updated_at = $3,
+                version = version + 1
+            WHERE task_id = $4 AND status = $5
+            "#,
+        )
+        .bind(status_to_str(new_status))
+        .bind(&status_message)
+        .bind(&now)
+        .bind(task_id)
+        .bind(status_to_str(expected))
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(status_conflict_error(self, task_id, expected).await);
+        }
+
+        notify_transition(self.config.observer.as_ref(), task_id, expected, new_status).await;
+
+        self.get_task(task_id)
+            .await?
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))
+    }
+
     async fn store_task_result(
         &self,
         task_id: &str,
@@ -632,6 +768,58 @@ impl TaskStorage for PostgresTaskStorage {
         }
     }
 
+    async fn append_task_progress(
+        &self,
+        task_id: &str,
+        chunk: Value,
+    ) -> Result<(), TaskStorageError> {
+        // Insert only while the task exists and is still running
+        let rows_affected = sqlx::query(
+            r#"
+            INSERT INTO task_progress (task_id, chunk)
+            SELECT task_id, $1 FROM tasks
+            WHERE task_id = $2 AND status IN ('working', 'input_required')
+            "#,
+        )
+        .bind(&chunk)
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            let status: Option<String> =
+                sqlx::query_scalar("SELECT status FROM tasks WHERE task_id = $1")
+                    .bind(task_id)
+                    .fetch_optional(&self.pool)
+                    .await?;
+            return Err(match status {
+                Some(status) => TaskStorageError::TerminalState(str_to_status(&status)?),
+                None => TaskStorageError::TaskNotFound(task_id.to_string()),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn get_task_progress(&self, task_id: &str) -> Result<Vec<Value>, TaskStorageError> {
+        let exists: Option<String> =
+            sqlx::query_scalar("SELECT task_id FROM tasks WHERE task_id = $1")
+                .bind(task_id)
+                .fetch_optional(&self.pool)
+                .await?;
+        if exists.is_none() {
+            return Err(TaskStorageError::TaskNotFound(task_id.to_string()));
+        }
+
+        let chunks: Vec<Value> =
+            sqlx::query_scalar("SELECT chunk FROM task_progress WHERE task_id = $1 ORDER BY seq")
+                .bind(task_id)
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(chunks)
+    }
+
     async fn expire_tasks(&self) -> Result<Vec<String>, TaskStorageError> {
         let mut tx = self.pool.begin().await?;
 
@@ -654,6 +842,31 @@ impl TaskStorage for PostgresTaskStorage {
         Ok(expired_ids)
     }
 
+    async fn purge_terminal_tasks(
+        &self,
+        older_than: chrono::Duration,
+    ) -> Result<u64, TaskStorageError> {
+        let cutoff = (Utc::now() - older_than).to_rfc3339();
+
+        let purged = sqlx::query(
+            r#"
+            DELETE FROM tasks
+            WHERE status IN ('completed', 'failed', 'cancelled')
+              AND last_updated_at::timestamptz < $1::timestamptz
+            "#,
+        )
+        .bind(&cutoff)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if purged > 0 {
+            info!("Purged {} terminal tasks", purged);
+        }
+
+        Ok(purged)
+    }
+
     async fn task_count(&self) -> Result<usize, TaskStorageError> {
         let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tasks")
             .fetch_one(&self.pool)
@@ -677,88 +890,10 @@ impl TaskStorage for PostgresTaskStorage {
         session_id: &str,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError> {
-        let limit = limit.unwrap_or(self.config.default_page_size) as i64;
-
-        let rows = if let Some(cursor_id) = cursor {
-            let cursor_row =
-                sqlx::query("SELECT created_at, task_id FROM tasks WHERE task_id = $1")
-                    .bind(cursor_id)
-                    .fetch_optional(&self.pool)
-                    .await?;
-
-            match cursor_row {
-                Some(ref cr) => {
-                    let cursor_created_at: String = cr.get("created_at");
-                    let cursor_task_id: String = cr.get("task_id");
-
-                    sqlx::query(
-                        r#"
-                        SELECT task_id, session_id, status, status_message,
-                               created_at, last_updated_at, ttl, poll_interval,
-                               original_method, original_params, result, meta, version
-                        FROM tasks
-                        WHERE session_id = $1
-                          AND (created_at, task_id) > ($2, $3)
-                        ORDER BY created_at ASC, task_id ASC
-                        LIMIT $4
-                        "#,
-                    )
-                    .bind(session_id)
-                    .bind(&cursor_created_at)
-                    .bind(&cursor_task_id)
-                    .bind(limit)
-                    .fetch_all(&self.pool)
-                    .await?
-                }
-                None => {
-                    sqlx::query(
-                        r#"
-                        SELECT task_id, session_id, status, status_message,
-                               created_at, last_updated_at, ttl, poll_interval,
-                               original_method, original_params, result, meta, version
-                        FROM tasks
-                        WHERE session_id = $1
-                        ORDER BY created_at ASC, task_id ASC
-                        LIMIT $2
-                        "#,
-                    )
-                    .bind(session_id)
-                    .bind(limit)
-                    .fetch_all(&self.pool)
-                    .await?
-                }
-            }
-        } else {
-            sqlx::query(
-                r#"
-                SELECT task_id, session_id, status, status_message,
-                       created_at, last_updated_at, ttl, poll_interval,
-                       original_method, original_params, result, meta, version
-                FROM tasks
-                WHERE session_id = $1
-                ORDER BY created_at ASC, task_id ASC
-                LIMIT $2
-                "#,
-            )
-            .bind(session_id)
-            .bind(limit)
-            .fetch_all(&self.pool)
-            .await?
-        };
-
-        let tasks: Vec<TaskRecord> = rows
-            .iter()
-            .map(row_to_task_record)
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let next_cursor = if tasks.len() as i64 == limit {
-            tasks.last().map(|t| t.task_id.clone())
-        } else {
-            None
-        };
-
-        Ok(TaskListPage { tasks, next_cursor })
+        self.fetch_task_page(Some(session_id), cursor, limit, status_filter)
+            .await
     }
 
     async fn recover_stuck_tasks(&self, max_age_ms: u64) -> Result<Vec<String>, TaskStorageError> {
@@ -941,7 +1076,7 @@ mod tests {
         }
 
         // Page 1: limit 2
-        let page1 = storage.list_tasks(None, Some(2)).await.unwrap();
+        let page1 = storage.list_tasks(None, Some(2), None).await.unwrap();
         assert_eq!(page1.tasks.len(), 2);
         assert_eq!(page1.tasks[0].task_id, "pg-test-page-0");
         assert_eq!(page1.tasks[1].task_id, "pg-test-page-1");
@@ -949,7 +1084,7 @@ mod tests {
 
         // Page 2
         let page2 = storage
-            .list_tasks(page1.next_cursor.as_deref(), Some(2))
+            .list_tasks(page1.next_cursor.as_deref(), Some(2), None)
             .await
             .unwrap();
         assert_eq!(page2.tasks.len(), 2);
@@ -958,7 +1093,7 @@ mod tests {
 
         // Page 3: last page
         let page3 = storage
-            .list_tasks(page2.next_cursor.as_deref(), Some(2))
+            .list_tasks(page2.next_cursor.as_deref(), Some(2), None)
             .await
             .unwrap();
         assert_eq!(page3.tasks.len(), 1);
@@ -993,20 +1128,20 @@ mod tests {
             .unwrap();
 
         let alpha = storage
-            .list_tasks_for_session("session-alpha", None, None)
+            .list_tasks_for_session("session-alpha", None, None, None)
             .await
             .unwrap();
         assert_eq!(alpha.tasks.len(), 2);
 
         let beta = storage
-            .list_tasks_for_session("session-beta", None, None)
+            .list_tasks_for_session("session-beta", None, None, None)
             .await
             .unwrap();
         assert_eq!(beta.tasks.len(), 1);
         assert_eq!(beta.tasks[0].task_id, "pg-test-sess-c");
 
         let empty = storage
-            .list_tasks_for_session("session-gamma", None, None)
+            .list_tasks_for_session("session-gamma", None, None, None)
             .await
             .unwrap();
         assert_eq!(empty.tasks.len(), 0);
@@ -1108,6 +1243,25 @@ mod tests {
         storage.delete_task("pg-test-result-1").await.unwrap();
     }
 
+    #[tokio::test]
+    #[ignore] // Requires PostgreSQL instance
+    async fn test_pool_stats_track_checked_out_connections() {
+        let storage = create_test_storage().await.unwrap();
+        let baseline = storage.pool_stats().in_use;
+
+        let (first, second) = tokio::join!(storage.pool.acquire(), storage.pool.acquire());
+        let (first, second) = (first.unwrap(), second.unwrap());
+        let stats = storage.pool_stats();
+        assert!(stats.in_use >= baseline + 2);
+        assert_eq!(stats.size, stats.idle + stats.in_use);
+        assert!(stats.size <= stats.max_size);
+
+        first.close().await.unwrap();
+        second.close().await.unwrap();
+        assert!(storage.pool_stats().in_use <= stats.in_use - 2);
+        assert!(serde_json::to_value(storage.pool_stats()).unwrap()["max_size"].is_number());
+    }
+
     // === Parity tests (all require Docker PostgreSQL) ===
 
     #[tokio::test]
@@ -1194,4 +1348,39 @@ mod tests {
         let storage = std::sync::Arc::new(create_test_storage().await.unwrap());
         crate::parity_tests::test_concurrent_status_updates(storage).await;
     }
+
+    #[tokio::test]
+    #[ignore = "Requires PostgreSQL connection"]
+    async fn parity_status_compare_and_set() {
+        let storage = std::sync::Arc::new(create_test_storage().await.unwrap());
+        crate::parity_tests::test_status_compare_and_set(storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires PostgreSQL connection"]
+    async fn parity_task_progress() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_task_progress(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires PostgreSQL connection"]
+    async fn parity_watch_task_until_terminal() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_watch_task_until_terminal(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires PostgreSQL connection"]
+    async fn parity_purge_terminal_tasks() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_purge_terminal_tasks(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires PostgreSQL connection"]
+    async fn parity_status_filtered_listing() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_status_filtered_listing(&storage).await;
+    }
 }
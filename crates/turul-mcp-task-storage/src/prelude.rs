@@ -9,9 +9,12 @@ pub use crate::dynamodb::{DynamoDbTaskConfig, DynamoDbTaskStorage};
 pub use crate::error::TaskStorageError;
 #[cfg(feature = "in-memory")]
 pub use crate::in_memory::{InMemoryTaskConfig, InMemoryTaskStorage};
+pub use crate::observer::TaskObserver;
 #[cfg(feature = "postgres")]
-pub use crate::postgres::{PostgresTaskConfig, PostgresTaskStorage};
+pub use crate::postgres::{PoolStats, PostgresTaskConfig, PostgresTaskStorage};
+#[cfg(feature = "redis")]
+pub use crate::redis::{RedisTaskConfig, RedisTaskStorage};
 #[cfg(feature = "sqlite")]
-pub use crate::sqlite::{SqliteTaskConfig, SqliteTaskStorage};
+pub use crate::sqlite::{SqliteTaskConfig, SqliteTaskStorage, SqliteVacuumMode};
 pub use crate::state_machine::{is_terminal, validate_transition};
 pub use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage};
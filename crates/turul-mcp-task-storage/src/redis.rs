@@ -0,0 +1,994 @@
+//! Redis task storage backend.
+//!
+//! Shares tasks across server instances through a single Redis deployment.
+//! Each task is a JSON document under `task:{id}`; sorted sets scored by
+//! creation time back cursor pagination, with ties ordered by task ID so
+//! listings follow `(created_at, task_id)` like the SQL backends.
+//!
+//! Key layout (every key carries the optional [`RedisTaskConfig::key_prefix`]):
+//!
+//! | Key | Type | Contents |
+//! |-----|------|----------|
+//! | `task:{id}` | string | JSON-encoded [`TaskRecord`] |
+//! | `task:{id}:progress` | list | JSON-encoded progress chunks, oldest first |
+//! | `tasks` | sorted set | every task ID, scored by `created_at` (ms) |
+//! | `session:{session_id}:tasks` | sorted set | the session's task IDs, same scoring |
+//! | `task_sessions` | hash | task ID → session ID, for index cleanup |
+//!
+//! A record's `ttl` becomes the expiry of its `task:{id}` key, counted from when
+//! the task is stored; a progress list is given the same expiry. Index entries
+//! left behind by keys Redis expired on its own are swept by
+//! [`TaskStorage::expire_tasks`], which also removes any task whose
+//! `created_at + ttl` has passed. Requires Redis 6.0 or later (`SET ... KEEPTTL`).
+
+use crate::error::TaskStorageError;
+use crate::observer::{TaskObserver, notify_transition};
+use crate::state_machine;
+use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, Script};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+use turul_mcp_protocol::TaskStatus;
+
+/// Number of index entries loaded per round trip when scanning all tasks.
+const SCAN_BATCH_SIZE: isize = 500;
+
+/// Attempts made by read-modify-write operations that retry on conflicts.
+const MAX_SWAP_ATTEMPTS: usize = 5;
+
+/// Replace `KEYS[1]` with `ARGV[2]` only if it still holds `ARGV[1]`, keeping its expiry.
+///
+/// Returns 1 on success, 0 if another writer changed the record, -1 if it is gone.
+const COMPARE_AND_SWAP_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if not current then
+    return -1
+end
+if current ~= ARGV[1] then
+    return 0
+end
+redis.call('SET', KEYS[1], ARGV[2], 'KEEPTTL')
+return 1
+"#;
+
+/// Push `ARGV[1]` onto the progress list `KEYS[2]` if task record `KEYS[1]` exists
+/// and is not terminal, giving the list the record's remaining expiry.
+///
+/// Returns 1 on success, 0 if the task is terminal, -1 if it is gone.
+const APPEND_PROGRESS_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if not current then
+    return -1
+end
+local status = cjson.decode(current).status
+if status ~= 'working' and status ~= 'input_required' then
+    return 0
+end
+redis.call('RPUSH', KEYS[2], ARGV[1])
+local ttl = redis.call('PTTL', KEYS[1])
+if ttl > 0 then
+    redis.call('PEXPIRE', KEYS[2], ttl)
+end
+return 1
+"#;
+
+/// Configuration for Redis task storage.
+#[derive(Debug, Clone)]
+pub struct RedisTaskConfig {
+    /// Redis connection URL (e.g. "redis://127.0.0.1:6379/0")
+    pub redis_url: String,
+    /// Prefix prepended to every key (e.g. "mcp:"), for sharing a database
+    pub key_prefix: Option<String>,
+    /// Background cleanup interval in minutes
+    pub cleanup_interval_minutes: u32,
+    /// Maximum number of tasks to store (0 = unlimited)
+    pub max_tasks: usize,
+    /// Default page size for list operations
+    pub default_page_size: u32,
+    /// Observer notified of committed status transitions.
+    pub observer: Option<Arc<dyn TaskObserver>>,
+}
+
+impl Default for RedisTaskConfig {
+    fn default() -> Self {
+        Self {
+            redis_url: "redis://127.0.0.1:6379".to_string(),
+            key_prefix: None,
+            cleanup_interval_minutes: 5,
+            max_tasks: 10_000,
+            default_page_size: 50,
+            observer: None,
+        }
+    }
+}
+
+impl RedisTaskConfig {
+    /// Notify `observer` of every status transition committed by `update_task_status`.
+    pub fn with_observer(mut self, observer: Arc<dyn TaskObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+}
+
+/// Redis-backed task storage implementation.
+///
+/// Uses a reconnecting multiplexed connection, compare-and-swap Lua scripts for
+/// optimistic locking, and sorted-set indexes for cursor-based pagination.
+#[derive(Clone)]
+pub struct RedisTaskStorage {
+    conn: ConnectionManager,
+    config: RedisTaskConfig,
+    swap_script: Script,
+    append_progress_script: Script,
+}
+
+impl RedisTaskStorage {
+    /// Create a new Redis task storage with default configuration.
+    pub async fn new() -> Result<Self, TaskStorageError> {
+        Self::with_config(RedisTaskConfig::default()).await
+    }
+
+    /// Create a new Redis task storage with custom configuration.
+    pub async fn with_config(config: RedisTaskConfig) -> Result<Self, TaskStorageError> {
+        info!(
+            "Initializing Redis task storage at {}",
+            mask_redis_url(&config.redis_url)
+        );
+
+        let client = redis::Client::open(config.redis_url.as_str())?;
+        let conn = ConnectionManager::new(client).await?;
+
+        let storage = Self {
+            conn,
+            config,
+            swap_script: Script::new(COMPARE_AND_SWAP_SCRIPT),
+            append_progress_script: Script::new(APPEND_PROGRESS_SCRIPT),
+        };
+
+        storage.start_cleanup_task();
+
+        info!("Redis task storage initialized successfully");
+        Ok(storage)
+    }
+
+    /// Start background cleanup task for expired tasks.
+    fn start_cleanup_task(&self) {
+        let storage = self.clone();
+        let period =
+            std::time::Duration::from_secs(self.config.cleanup_interval_minutes as u64 * 60);
+
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+
+            loop {
+                interval.tick().await;
+
+                if let Err(e) = storage.expire_tasks().await {
+                    warn!("Background task cleanup failed: {}", e);
+                }
+            }
+        });
+    }
+
+    fn key(&self, name: &str) -> String {
+        match &self.config.key_prefix {
+            Some(prefix) => format!("{}{}", prefix, name),
+            None => name.to_string(),
+        }
+    }
+
+    fn task_key(&self, task_id: &str) -> String {
+        self.key(&format!("task:{}", task_id))
+    }
+
+    fn progress_key(&self, task_id: &str) -> String {
+        self.key(&format!("task:{}:progress", task_id))
+    }
+
+    fn index_key(&self) -> String {
+        self.key("tasks")
+    }
+
+    fn session_index_key(&self, session_id: &str) -> String {
+        self.key(&format!("session:{}:tasks", session_id))
+    }
+
+    fn sessions_key(&self) -> String {
+        self.key("task_sessions")
+    }
+
+    /// Load the raw JSON for each task ID, `None` where the key no longer exists.
+    async fn load_raw(&self, task_ids: &[String]) -> Result<Vec<Option<String>>, TaskStorageError> {
+        if task_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let keys: Vec<String> = task_ids.iter().map(|id| self.task_key(id)).collect();
+        let raw = redis::cmd("MGET")
+            .arg(&keys)
+            .query_async(&mut self.conn.clone())
+            .await?;
+        Ok(raw)
+    }
+
+    /// Load every indexed task ID with its raw JSON (`None` if the key has expired).
+    async fn scan_raw(&self) -> Result<Vec<(String, Option<String>)>, TaskStorageError> {
+        let mut conn = self.conn.clone();
+        let index = self.index_key();
+        let mut entries = Vec::new();
+        let mut start = 0;
+
+        loop {
+            let ids: Vec<String> = conn
+                .zrange(&index, start, start + SCAN_BATCH_SIZE - 1)
+                .await?;
+            if ids.is_empty() {
+                break;
+            }
+            start += ids.len() as isize;
+
+            let raw = self.load_raw(&ids).await?;
+            let batch_len = ids.len() as isize;
+            entries.extend(ids.into_iter().zip(raw));
+
+            if batch_len < SCAN_BATCH_SIZE {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Fetch one page of tasks ordered by `(created_at, task_id)`.
+    ///
+    /// Session scoping picks the index; the status filter is applied while
+    /// walking it, so pages and cursors only ever cover matching tasks.
+    async fn fetch_task_page(
+        &self,
+        session_id: Option<&str>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
+    ) -> Result<TaskListPage, TaskStorageError> {
+        let limit = limit.unwrap_or(self.config.default_page_size) as usize;
+        let status_filter = status_filter.filter(|statuses| !statuses.is_empty());
+        let index = match session_id {
+            Some(session_id) => self.session_index_key(session_id),
+            None => self.index_key(),
+        };
+        let mut conn = self.conn.clone();
+
+        // Resume after the cursor's rank.
+        // Cursor not found: start from beginning (graceful degradation).
+        let mut start: isize = match cursor {
+            Some(cursor_id) => conn
+                .zrank::<_, _, Option<isize>>(&index, cursor_id)
+                .await?
+                .map_or(0, |rank| rank + 1),
+            None => 0,
+        };
+
+        let mut tasks = Vec::new();
+        while tasks.len() < limit {
+            let batch_size = (limit - tasks.len()) as isize;
+            let ids: Vec<String> = conn.zrange(&index, start, start + batch_size - 1).await?;
+            if ids.is_empty() {
+                break;
+            }
+            start += ids.len() as isize;
+            let exhausted = (ids.len() as isize) < batch_size;
+
+            // IDs whose keys Redis already expired are skipped until expire_tasks sweeps them
+            for raw in self.load_raw(&ids).await?.into_iter().flatten() {
+                let record: TaskRecord = serde_json::from_str(&raw)?;
+                if status_filter.is_none_or(|statuses| statuses.contains(&record.status)) {
+                    tasks.push(record);
+                }
+            }
+
+            if exhausted {
+                break;
+            }
+        }
+
+        // Determine next_cursor: if we got a full page, there may be more
+        let next_cursor = if tasks.len() == limit {
+            tasks.last().map(|t| t.task_id.clone())
+        } else {
+            None
+        };
+
+        Ok(TaskListPage { tasks, next_cursor })
+    }
+
+    /// Replace the stored JSON for `task_id` if it still equals `expected`.
+    async fn swap_task(
+        &self,
+        task_id: &str,
+        expected: &str,
+        record: &TaskRecord,
+    ) -> Result<(), TaskStorageError> {
+        let updated = serde_json::to_string(record)?;
+        let outcome: i64 = self
+            .swap_script
+            .key(self.task_key(task_id))
+            .arg(expected)
+            .arg(&updated)
+            .invoke_async(&mut self.conn.clone())
+            .await?;
+
+        match outcome {
+            1 => Ok(()),
+            0 => Err(TaskStorageError::ConcurrentModification(format!(
+                "Task {} was modified by another writer",
+                task_id
+            ))),
+            _ => Err(TaskStorageError::TaskNotFound(task_id.to_string())),
+        }
+    }
+
+    /// Delete a task's record and index entries. Returns whether the record existed.
+    async fn remove_task(&self, task_id: &str) -> Result<bool, TaskStorageError> {
+        let mut conn = self.conn.clone();
+        let session_id: Option<String> = conn.hget(self.sessions_key(), task_id).await?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .del(self.task_key(task_id))
+            .del(self.progress_key(task_id))
+            .ignore()
+            .zrem(self.index_key(), task_id)
+            .ignore()
+            .hdel(self.sessions_key(), task_id)
+            .ignore();
+        if let Some(session_id) = &session_id {
+            pipe.zrem(self.session_index_key(session_id), task_id)
+                .ignore();
+        }
+
+        let (deleted,): (u64,) = pipe.query_async(&mut conn).await?;
+        Ok(deleted > 0)
+    }
+
+    fn now_iso8601() -> String {
+        Utc::now().to_rfc3339()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Parse an RFC 3339 timestamp into Unix milliseconds.
+fn timestamp_millis(timestamp: &str) -> Option<i64> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|dt| dt.timestamp_millis())
+}
+
+/// Whether `record`'s TTL, counted from `created_at`, has elapsed at `now_ms`.
+fn is_expired(record: &TaskRecord, now_ms: i64) -> bool {
+    match (record.ttl, timestamp_millis(&record.created_at)) {
+        (Some(ttl), Some(created_ms)) => created_ms.saturating_add(ttl) < now_ms,
+        _ => false,
+    }
+}
+
+/// Mask sensitive information in Redis URL for logging.
+fn mask_redis_url(url: &str) -> String {
+    if let Some(at_pos) = url.find('@') {
+        let (prefix, suffix) = url.split_at(at_pos);
+        if let Some(colon_pos) = prefix.rfind(':') {
+            format!("{}:***{}", &prefix[..colon_pos], suffix)
+        } else {
+            url.to_string()
+        }
+    } else {
+        url.to_string()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TaskStorage implementation
+// ---------------------------------------------------------------------------
+
+#[async_trait]
+impl TaskStorage for RedisTaskStorage {
+    fn backend_name(&self) -> &'static str {
+        "redis"
+    }
+
+    async fn create_task(&self, mut task: TaskRecord) -> Result<TaskRecord, TaskStorageError> {
+        // Check max_tasks limit, sweeping expired index entries before giving up
+        if self.config.max_tasks > 0 && self.task_count().await? >= self.config.max_tasks {
+            self.expire_tasks().await?;
+            if self.task_count().await? >= self.config.max_tasks {
+                return Err(TaskStorageError::MaxTasksReached(self.config.max_tasks));
+            }
+        }
+
+        // Ensure timestamps are set
+        if task.created_at.is_empty() {
+            task.created_at = Self::now_iso8601();
+        }
+        if task.last_updated_at.is_empty() {
+            task.last_updated_at = task.created_at.clone();
+        }
+
+        let score = timestamp_millis(&task.created_at).ok_or_else(|| {
+            TaskStorageError::SerializationError(format!(
+                "Invalid created_at timestamp: {}",
+                task.created_at
+            ))
+        })?;
+        let json = serde_json::to_string(&task)?;
+        let mut conn = self.conn.clone();
+
+        let mut set = redis::cmd("SET");
+        set.arg(self.task_key(&task.task_id)).arg(&json).arg("NX");
+        if let Some(ttl) = task.ttl {
+            set.arg("PX").arg(ttl.max(1));
+        }
+        let stored: Option<String> = set.query_async(&mut conn).await?;
+        if stored.is_none() {
+            return Err(TaskStorageError::DatabaseError(format!(
+                "Task {} already exists",
+                task.task_id
+            )));
+        }
+
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .zadd(self.index_key(), &task.task_id, score)
+            .ignore();
+        if let Some(session_id) = &task.session_id {
+            pipe.zadd(self.session_index_key(session_id), &task.task_id, score)
+                .ignore()
+                .hset(self.sessions_key(), &task.task_id, session_id)
+                .ignore();
+        }
+        pipe.query_async::<()>(&mut conn).await?;
+
+        Ok(task)
+    }
+
+    async fn get_task(&self, task_id: &str) -> Result<Option<TaskRecord>, TaskStorageError> {
+        let raw: Option<String> = self.conn.clone().get(self.task_key(task_id)).await?;
+
+        match raw {
+            Some(raw) => Ok(Some(serde_json::from_str(&raw)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn update_task(&self, task: TaskRecord) -> Result<(), TaskStorageError> {
+        let existing = self
+            .get_task(&task.task_id)
+            .await?
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task.task_id.clone()))?;
+
+        let score = timestamp_millis(&task.created_at).ok_or_else(|| {
+            TaskStorageError::SerializationError(format!(
+                "Invalid created_at timestamp: {}",
+                task.created_at
+            ))
+        })?;
+        let json = serde_json::to_string(&task)?;
+        let task_key = self.task_key(&task.task_id);
+
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .cmd("SET")
+            .arg(&task_key)
+            .arg(&json)
+            .arg("XX")
+            .arg("KEEPTTL");
+        // A changed TTL restarts the key's expiry; an unchanged one keeps counting down
+        if task.ttl != existing.ttl {
+            let progress_key = self.progress_key(&task.task_id);
+            match task.ttl {
+                Some(ttl) => pipe
+                    .pexpire(&task_key, ttl.max(1))
+                    .ignore()
+                    .pexpire(&progress_key, ttl.max(1))
+                    .ignore(),
+                None => pipe
+                    .persist(&task_key)
+                    .ignore()
+                    .persist(&progress_key)
+                    .ignore(),
+            };
+        }
+        pipe.zadd(self.index_key(), &task.task_id, score).ignore();
+        if let Some(session_id) = existing
+            .session_id
+            .as_ref()
+            .filter(|_| existing.session_id != task.session_id)
+        {
+            pipe.zrem(self.session_index_key(session_id), &task.task_id)
+                .ignore()
+                .hdel(self.sessions_key(), &task.task_id)
+                .ignore();
+        }
+        if let Some(session_id) = &task.session_id {
+            pipe.zadd(self.session_index_key(session_id), &task.task_id, score)
+                .ignore()
+                .hset(self.sessions_key(), &task.task_id, session_id)
+                .ignore();
+        }
+
+        let (stored,): (Option<String>,) = pipe.query_async(&mut self.conn.clone()).await?;
+        if stored.is_none() {
+            return Err(TaskStorageError::TaskNotFound(task.task_id));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_task(&self, task_id: &str) -> Result<bool, TaskStorageError> {
+        self.remove_task(task_id).await
+    }
+
+    async fn list_tasks(
+        &self,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
+    ) -> Result<TaskListPage, TaskStorageError> {
+        self.fetch_task_page(None, cursor, limit, status_filter)
+            .await
+    }
+
+    async fn update_task_status(
+        &self,
+        task_id: &str,
+        new_status: TaskStatus,
+        status_message: Option<String>,
+    ) -> Result<TaskRecord, TaskStorageError> {
+        // Step 1: Read the current record
+        let raw: String = self
+            .conn
+            .clone()
+            .get::<_, Option<String>>(self.task_key(task_id))
+            .await?
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+        let mut record: TaskRecord = serde_json::from_str(&raw)?;
+        let current_status = record.status;
+
+        // Step 2: Validate state machine transition
+        state_machine::validate_transition(current_status, new_status)?;
+
+        // Step 3: Swap in the new record only if nobody changed it meanwhile
+        record.status = new_status;
+        record.status_message = status_message;
+        record.last_updated_at = Self::now_iso8601();
+        self.swap_task(task_id, &raw, &record).await?;
+
+        notify_transition(
+            self.config.observer.as_ref(),
+            task_id,
+            current_status,
+            new_status,
+        )
+        .await;
+
+        Ok(record)
+    }
+
+    async fn update_task_status_if(
+        &self,
+        task_id: &str,
+        expected: TaskStatus,
+        new_status: TaskStatus,
+        status_message: Option<String>,
+    ) -> Result<TaskRecord, TaskStorageError> {
+        let task_key = self.task_key(task_id);
+        let mut attempts = 0;
+
+        loop {
+            let raw: String = self
+                .conn
+                .clone()
+                .get::<_, Option<String>>(&task_key)
+                .await?
+                .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+            let mut record: TaskRecord = serde_json::from_str(&raw)?;
+            if record.status != expected {
+                return Err(TaskStorageError::StatusConflict {
+                    task_id: task_id.to_string(),
+                    expected,
+                    actual: record.status,
+                });
+            }
+
+            state_machine::validate_transition(expected, new_status)?;
+
+            record.status = new_status;
+            record.status_message = status_message.clone();
+            record.last_updated_at = Self::now_iso8601();
+
+            // The swap compares the whole record, so a concurrent write to another
+            // field also fails it; re-read and re-check the status in that case.
+            attempts += 1;
+            match self.swap_task(task_id, &raw, &record).await {
+                Ok(()) => {
+                    notify_transition(self.config.observer.as_ref(), task_id, expected, new_status)
+                        .await;
+                    return Ok(record);
+                }
+                Err(TaskStorageError::ConcurrentModification(_))
+                    if attempts < MAX_SWAP_ATTEMPTS =>
+                {
+                    debug!(
+                        "Re-checking status of task {} after a concurrent write",
+                        task_id
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn store_task_result(
+        &self,
+        task_id: &str,
+        result: TaskOutcome,
+    ) -> Result<(), TaskStorageError> {
+        let task_key = self.task_key(task_id);
+        let mut attempts = 0;
+
+        loop {
+            let raw: String = self
+                .conn
+                .clone()
+                .get::<_, Option<String>>(&task_key)
+                .await?
+                .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+            let mut record: TaskRecord = serde_json::from_str(&raw)?;
+            record.result = Some(result.clone());
+            record.last_updated_at = Self::now_iso8601();
+
+            attempts += 1;
+            match self.swap_task(task_id, &raw, &record).await {
+                Err(TaskStorageError::ConcurrentModification(_))
+                    if attempts < MAX_SWAP_ATTEMPTS =>
+                {
+                    debug!(
+                        "Retrying result store for task {} after a concurrent write",
+                        task_id
+                    );
+                }
+                outcome => return outcome,
+            }
+        }
+    }
+
+    async fn get_task_result(
+        &self,
+        task_id: &str,
+    ) -> Result<Option<TaskOutcome>, TaskStorageError> {
+        let record = self
+            .get_task(task_id)
+            .await?
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+
+        Ok(record.result)
+    }
+
+    async fn append_task_progress(
+        &self,
+        task_id: &str,
+        chunk: Value,
+    ) -> Result<(), TaskStorageError> {
+        let outcome: i64 = self
+            .append_progress_script
+            .key(self.task_key(task_id))
+            .key(self.progress_key(task_id))
+            .arg(serde_json::to_string(&chunk)?)
+            .invoke_async(&mut self.conn.clone())
+            .await?;
+
+        match outcome {
+            1 => Ok(()),
+            0 => {
+                let status = self
+                    .get_task(task_id)
+                    .await?
+                    .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?
+                    .status;
+                Err(TaskStorageError::TerminalState(status))
+            }
+            _ => Err(TaskStorageError::TaskNotFound(task_id.to_string())),
+        }
+    }
+
+    async fn get_task_progress(&self, task_id: &str) -> Result<Vec<Value>, TaskStorageError> {
+        let (exists, chunks): (bool, Vec<String>) = redis::pipe()
+            .atomic()
+            .exists(self.task_key(task_id))
+            .lrange(self.progress_key(task_id), 0, -1)
+            .query_async(&mut self.conn.clone())
+            .await?;
+        if !exists {
+            return Err(TaskStorageError::TaskNotFound(task_id.to_string()));
+        }
+
+        chunks
+            .iter()
+            .map(|chunk| Ok(serde_json::from_str(chunk)?))
+            .collect()
+    }
+
+    async fn expire_tasks(&self) -> Result<Vec<String>, TaskStorageError> {
+        let now_ms = Utc::now().timestamp_millis();
+        let mut expired_ids = Vec::new();
+
+        for (task_id, raw) in self.scan_raw().await? {
+            let expired = match raw {
+                // Redis already expired the key; only its index entries remain
+                None => true,
+                Some(raw) => is_expired(&serde_json::from_str(&raw)?, now_ms),
+            };
+            if expired {
+                self.remove_task(&task_id).await?;
+                expired_ids.push(task_id);
+            }
+        }
+
+        if !expired_ids.is_empty() {
+            debug!("Expired {} tasks", expired_ids.len());
+        }
+
+        Ok(expired_ids)
+    }
+
+    async fn purge_terminal_tasks(
+        &self,
+        older_than: chrono::Duration,
+    ) -> Result<u64, TaskStorageError> {
+        let cutoff_ms = (Utc::now() - older_than).timestamp_millis();
+        let mut purged = 0;
+
+        for (task_id, raw) in self.scan_raw().await? {
+            let Some(raw) = raw else { continue };
+            let record: TaskRecord = serde_json::from_str(&raw)?;
+            let stale = timestamp_millis(&record.last_updated_at)
+                .is_some_and(|updated_ms| updated_ms < cutoff_ms);
+            if state_machine::is_terminal(record.status)
+                && stale
+                && self.remove_task(&task_id).await?
+            {
+                purged += 1;
+            }
+        }
+
+        if purged > 0 {
+            info!("Purged {} terminal tasks", purged);
+        }
+
+        Ok(purged)
+    }
+
+    async fn task_count(&self) -> Result<usize, TaskStorageError> {
+        let count: usize = self.conn.clone().zcard(self.index_key()).await?;
+        Ok(count)
+    }
+
+    async fn maintenance(&self) -> Result<(), TaskStorageError> {
+        self.expire_tasks().await?;
+        debug!("Redis task maintenance completed");
+        Ok(())
+    }
+
+    async fn list_tasks_for_session(
+        &self,
+        session_id: &str,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
+    ) -> Result<TaskListPage, TaskStorageError> {
+        self.fetch_task_page(Some(session_id), cursor, limit, status_filter)
+            .await
+    }
+
+    async fn recover_stuck_tasks(&self, max_age_ms: u64) -> Result<Vec<String>, TaskStorageError> {
+        let cutoff_ms = Utc::now().timestamp_millis() - max_age_ms as i64;
+        let now = Self::now_iso8601();
+        let mut recovered_ids = Vec::new();
+
+        for (task_id, raw) in self.scan_raw().await? {
+            let Some(raw) = raw else { continue };
+            let mut record: TaskRecord = serde_json::from_str(&raw)?;
+            let stuck = timestamp_millis(&record.last_updated_at)
+                .is_some_and(|updated_ms| updated_ms < cutoff_ms);
+            if state_machine::is_terminal(record.status) || !stuck {
+                continue;
+            }
+
+            record.status = TaskStatus::Failed;
+            record.status_message = Some("Server restarted — task interrupted".to_string());
+            record.last_updated_at = now.clone();
+            match self.swap_task(&task_id, &raw, &record).await {
+                Ok(()) => recovered_ids.push(task_id),
+                // Updated or deleted since the scan, so no longer stuck
+                Err(TaskStorageError::ConcurrentModification(_))
+                | Err(TaskStorageError::TaskNotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        if !recovered_ids.is_empty() {
+            info!("Recovered {} stuck tasks", recovered_ids.len());
+        }
+
+        Ok(recovered_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> RedisTaskConfig {
+        RedisTaskConfig {
+            redis_url: std::env::var("TEST_REDIS_URL")
+                .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string()),
+            // Fresh namespace per storage so runs don't see each other's tasks
+            key_prefix: Some(format!("turul-test-{}:", uuid::Uuid::now_v7())),
+            ..RedisTaskConfig::default()
+        }
+    }
+
+    async fn create_test_storage() -> Result<RedisTaskStorage, TaskStorageError> {
+        RedisTaskStorage::with_config(test_config()).await
+    }
+
+    #[test]
+    fn test_mask_redis_url() {
+        assert_eq!(
+            mask_redis_url("redis://:secret@cache.internal:6379/0"),
+            "redis://:***@cache.internal:6379/0"
+        );
+        assert_eq!(
+            mask_redis_url("redis://127.0.0.1:6379"),
+            "redis://127.0.0.1:6379"
+        );
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let mut record = crate::parity_tests::make_task("t", None, "2020-01-01T00:00:00Z");
+        let created_ms = timestamp_millis(&record.created_at).unwrap();
+        assert!(
+            !is_expired(&record, created_ms + 1_000),
+            "no TTL never expires"
+        );
+
+        record.ttl = Some(500);
+        assert!(!is_expired(&record, created_ms + 500));
+        assert!(is_expired(&record, created_ms + 501));
+    }
+
+    // === Parity tests (all require a Redis server) ===
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_create_and_retrieve() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_create_and_retrieve(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_state_machine_enforcement() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_state_machine_enforcement(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_terminal_state_rejection() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_terminal_state_rejection(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_cursor_determinism() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_cursor_determinism(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_session_scoping() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_session_scoping(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_ttl_expiry() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_ttl_expiry(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_task_result_round_trip() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_task_result_round_trip(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_recover_stuck_tasks() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_recover_stuck_tasks(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_max_tasks_limit() {
+        let config = RedisTaskConfig {
+            max_tasks: 5,
+            ..test_config()
+        };
+        let storage = RedisTaskStorage::with_config(config).await.unwrap();
+        crate::parity_tests::test_max_tasks_limit(&storage, 5).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_error_mapping() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_error_mapping_parity(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_concurrent_status_updates() {
+        let storage = std::sync::Arc::new(create_test_storage().await.unwrap());
+        crate::parity_tests::test_concurrent_status_updates(storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_status_compare_and_set() {
+        let storage = std::sync::Arc::new(create_test_storage().await.unwrap());
+        crate::parity_tests::test_status_compare_and_set(storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_task_progress() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_task_progress(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_watch_task_until_terminal() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_watch_task_until_terminal(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_purge_terminal_tasks() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_purge_terminal_tasks(&storage).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Redis connection"]
+    async fn parity_status_filtered_listing() {
+        let storage = create_test_storage().await.unwrap();
+        crate::parity_tests::test_status_filtered_listing(&storage).await;
+    }
+}
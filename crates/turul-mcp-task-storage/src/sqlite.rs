@@ -5,18 +5,37 @@
 //! across server restarts.
 
 use crate::error::TaskStorageError;
+use crate::observer::{TaskObserver, notify_transition};
 use crate::state_machine;
-use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage};
+use crate::traits::{TaskListPage, TaskOutcome, TaskRecord, TaskStorage, status_conflict_error};
 use async_trait::async_trait;
 use chrono::Utc;
 use serde_json::Value;
-use sqlx::sqlite::SqliteConnectOptions;
-use sqlx::{Row, SqlitePool};
+use sqlx::sqlite::{SqliteAutoVacuum, SqliteConnectOptions};
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tracing::{debug, info, warn};
 use turul_mcp_protocol::TaskStatus;
 
+/// How [`SqliteTaskStorage::compact`] reclaims free pages left by deleted tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqliteVacuumMode {
+    /// Rebuild the database file with `VACUUM`.
+    ///
+    /// Rewrites every live page and needs up to the database size again in temporary
+    /// disk space, so its I/O cost grows with the whole database, not just the garbage.
+    #[default]
+    Full,
+    /// Release free pages with `PRAGMA incremental_vacuum`.
+    ///
+    /// Opens the database with `auto_vacuum = INCREMENTAL`, so compaction only touches
+    /// free pages. A database created without it is converted by one full `VACUUM`
+    /// the first time it is compacted.
+    Incremental,
+}
+
 /// Configuration for SQLite task storage.
 #[derive(Debug, Clone)]
 pub struct SqliteTaskConfig {
@@ -40,6 +59,12 @@ pub struct SqliteTaskConfig {
     pub create_tables: bool,
     /// Create database file if it doesn't exist
     pub create_database_if_missing: bool,
+    /// Background compaction interval in minutes (`None` = only on [`compact`](SqliteTaskStorage::compact))
+    pub compaction_interval_minutes: Option<u32>,
+    /// How compaction reclaims free pages
+    pub vacuum_mode: SqliteVacuumMode,
+    /// Observer notified of committed status transitions.
+    pub observer: Option<Arc<dyn TaskObserver>>,
 }
 
 impl Default for SqliteTaskConfig {
@@ -54,14 +79,27 @@ impl Default for SqliteTaskConfig {
             verify_tables: false,
             create_tables: false,
             create_database_if_missing: true,
+            compaction_interval_minutes: None,
+            vacuum_mode: SqliteVacuumMode::Full,
+            observer: None,
         }
     }
 }
 
+impl SqliteTaskConfig {
+    /// Notify `observer` of every status transition committed by `update_task_status`.
+    pub fn with_observer(mut self, observer: Arc<dyn TaskObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+}
+
 /// SQLite-backed task storage implementation.
 pub struct SqliteTaskStorage {
     pool: SqlitePool,
     config: SqliteTaskConfig,
+    /// Serializes manual and background compaction.
+    compaction_lock: Arc<tokio::sync::Mutex<()>>,
 }
 
 fn status_to_str(status: TaskStatus) -> &'static str {
@@ -165,18 +203,26 @@ impl SqliteTaskStorage {
                 .await
                 .map_err(|e| TaskStorageError::DatabaseError(e.to_string()))?
         } else {
-            let connect_options = SqliteConnectOptions::new()
+            let mut connect_options = SqliteConnectOptions::new()
                 .filename(&config.database_path)
                 .create_if_missing(config.create_database_if_missing);
+            if config.vacuum_mode == SqliteVacuumMode::Incremental {
+                connect_options = connect_options.auto_vacuum(SqliteAutoVacuum::Incremental);
+            }
             SqlitePool::connect_with(connect_options)
                 .await
                 .map_err(|e| TaskStorageError::DatabaseError(e.to_string()))?
         };
 
-        let storage = Self { pool, config };
+        let storage = Self {
+            pool,
+            config,
+            compaction_lock: Arc::new(tokio::sync::Mutex::new(())),
+        };
 
         storage.migrate().await?;
         storage.start_cleanup_task();
+        storage.start_compaction_task();
 
         info!("SQLite task storage initialized successfully");
         Ok(storage)
@@ -221,6 +267,26 @@ impl SqliteTaskStorage {
             .execute(&self.pool)
             .await?;
 
+        // Progress chunks; `seq` (the rowid) orders them. sqlx enables foreign keys on
+        // every connection, so chunks go with their task on any delete path.
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS task_progress (
+                seq INTEGER PRIMARY KEY,
+                task_id TEXT NOT NULL REFERENCES tasks (task_id) ON DELETE CASCADE,
+                chunk TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        sqlx::query(
+            "CREATE INDEX IF NOT EXISTS idx_task_progress_task ON task_progress (task_id, seq)",
+        )
+        .execute(&self.pool)
+        .await?;
+
         debug!("Task storage database migrations completed");
         Ok(())
     }
@@ -243,11 +309,174 @@ impl SqliteTaskStorage {
         });
     }
 
+    /// Reclaim free pages left behind by deleted tasks, shrinking the database file.
+    ///
+    /// Runs on a single pooled connection; other connections wait on SQLite's busy
+    /// timeout until it finishes. See [`SqliteVacuumMode`] for the I/O cost of each mode.
+    pub async fn compact(&self) -> Result<(), TaskStorageError> {
+        let _guard = self.compaction_lock.lock().await;
+        compact_database(&self.pool, self.config.vacuum_mode).await
+    }
+
+    /// Start background compaction when `compaction_interval_minutes` is set.
+    fn start_compaction_task(&self) {
+        let Some(interval_mins) = self.config.compaction_interval_minutes.filter(|m| *m > 0) else {
+            return;
+        };
+        let pool = self.pool.clone();
+        let mode = self.config.vacuum_mode;
+        let lock = Arc::clone(&self.compaction_lock);
+
+        tokio::spawn(async move {
+            let duration = std::time::Duration::from_secs(interval_mins as u64 * 60);
+
+            loop {
+                tokio::time::sleep(duration).await;
+
+                let _guard = lock.lock().await;
+                if let Err(e) = compact_database(&pool, mode).await {
+                    warn!("Task storage background compaction failed: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Fetch one page of tasks ordered by `(created_at, task_id)`.
+    ///
+    /// Session scoping and the status filter are applied in the `WHERE` clause, so
+    /// pages and cursors only ever cover matching tasks.
+    async fn fetch_task_page(
+        &self,
+        session_id: Option<&str>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
+    ) -> Result<TaskListPage, TaskStorageError> {
+        let limit = limit.unwrap_or(self.config.default_page_size) as i64;
+        // Fetch limit + 1 to determine if there's a next page
+        let fetch_limit = limit + 1;
+
+        // Two-step cursor resolution: look up the cursor task's (created_at, task_id).
+        // If the cursor doesn't exist, start from the beginning (graceful degradation).
+        let cursor_position = match cursor {
+            Some(cursor_id) => {
+                sqlx::query("SELECT created_at, task_id FROM tasks WHERE task_id = ?")
+                    .bind(cursor_id)
+                    .fetch_optional(&self.pool)
+                    .await?
+                    .map(|row| {
+                        (
+                            row.get::<String, _>("created_at"),
+                            row.get::<String, _>("task_id"),
+                        )
+                    })
+            }
+            None => None,
+        };
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT task_id, session_id, status, status_message, created_at,
+                   last_updated_at, ttl, poll_interval, original_method,
+                   original_params, result, meta
+            FROM tasks
+            WHERE 1 = 1"#,
+        );
+        if let Some(session_id) = session_id {
+            query.push(" AND session_id = ").push_bind(session_id);
+        }
+        if let Some(statuses) = status_filter.filter(|statuses| !statuses.is_empty()) {
+            query.push(" AND status IN (");
+            let mut separated = query.separated(", ");
+            for status in statuses {
+                separated.push_bind(status_to_str(*status));
+            }
+            separated.push_unseparated(")");
+        }
+        if let Some((cursor_created_at, cursor_task_id)) = cursor_position {
+            query
+                .push(" AND (created_at, task_id) > (")
+                .push_bind(cursor_created_at)
+                .push(", ")
+                .push_bind(cursor_task_id)
+                .push(")");
+        }
+        query
+            .push(" ORDER BY created_at ASC, task_id ASC LIMIT ")
+            .push_bind(fetch_limit);
+
+        let rows = query.build().fetch_all(&self.pool).await?;
+
+        let has_more = rows.len() as i64 > limit;
+        let take_count = if has_more { limit as usize } else { rows.len() };
+
+        let mut tasks = Vec::with_capacity(take_count);
+        for row in rows.into_iter().take(take_count) {
+            tasks.push(row_to_task_record(row)?);
+        }
+
+        let next_cursor = if has_more {
+            tasks.last().map(|t| t.task_id.clone())
+        } else {
+            None
+        };
+
+        Ok(TaskListPage { tasks, next_cursor })
+    }
+
     fn now_iso8601() -> String {
         Utc::now().to_rfc3339()
     }
 }
 
+/// Run `VACUUM` or `PRAGMA incremental_vacuum` on one dedicated connection.
+async fn compact_database(
+    pool: &SqlitePool,
+    mode: SqliteVacuumMode,
+) -> Result<(), TaskStorageError> {
+    // VACUUM cannot run inside a transaction, so hold one connection for the whole routine
+    let mut conn = pool.acquire().await?;
+    let pages_before: i64 = sqlx::query_scalar("PRAGMA page_count")
+        .fetch_one(&mut *conn)
+        .await?;
+
+    match mode {
+        SqliteVacuumMode::Full => {
+            sqlx::query("VACUUM").execute(&mut *conn).await?;
+        }
+        SqliteVacuumMode::Incremental => {
+            // 2 = INCREMENTAL; any other value means the file predates the setting
+            let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+                .fetch_one(&mut *conn)
+                .await?;
+            if auto_vacuum == 2 {
+                sqlx::query("PRAGMA incremental_vacuum")
+                    .execute(&mut *conn)
+                    .await?;
+            } else {
+                sqlx::query("PRAGMA auto_vacuum = INCREMENTAL")
+                    .execute(&mut *conn)
+                    .await?;
+                sqlx::query("VACUUM").execute(&mut *conn).await?;
+            }
+        }
+    }
+
+    // In WAL mode the main file only shrinks once the WAL is checkpointed
+    sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+        .execute(&mut *conn)
+        .await?;
+
+    let pages_after: i64 = sqlx::query_scalar("PRAGMA page_count")
+        .fetch_one(&mut *conn)
+        .await?;
+    debug!(
+        "Compacted SQLite task database: {} -> {} pages",
+        pages_before, pages_after
+    );
+    Ok(())
+}
+
 /// Background cleanup: expire tasks that have exceeded their TTL.
 async fn run_cleanup(pool: &SqlitePool) -> Result<(), TaskStorageError> {
     let deleted = sqlx::query(
@@ -412,88 +641,10 @@ impl TaskStorage for SqliteTaskStorage {
         &self,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError> {
-        let limit = limit.unwrap_or(self.config.default_page_size) as i64;
-        // Fetch limit + 1 to determine if there's a next page
-        let fetch_limit = limit + 1;
-
-        let rows = if let Some(cursor_id) = cursor {
-            // Two-step cursor resolution: look up the cursor task's (created_at, task_id)
-            let cursor_row = sqlx::query("SELECT created_at, task_id FROM tasks WHERE task_id = ?")
-                .bind(cursor_id)
-                .fetch_optional(&self.pool)
-                .await?;
-
-            match cursor_row {
-                Some(crow) => {
-                    let cursor_created_at: String = crow.get("created_at");
-                    let cursor_task_id: String = crow.get("task_id");
-
-                    sqlx::query(
-                        r#"
-                        SELECT task_id, session_id, status, status_message, created_at,
-                               last_updated_at, ttl, poll_interval, original_method,
-                               original_params, result, meta
-                        FROM tasks
-                        WHERE (created_at, task_id) > (?, ?)
-                        ORDER BY created_at ASC, task_id ASC
-                        LIMIT ?
-                        "#,
-                    )
-                    .bind(&cursor_created_at)
-                    .bind(&cursor_task_id)
-                    .bind(fetch_limit)
-                    .fetch_all(&self.pool)
-                    .await?
-                }
-                None => {
-                    // Cursor not found — start from beginning (graceful degradation)
-                    sqlx::query(
-                        r#"
-                        SELECT task_id, session_id, status, status_message, created_at,
-                               last_updated_at, ttl, poll_interval, original_method,
-                               original_params, result, meta
-                        FROM tasks
-                        ORDER BY created_at ASC, task_id ASC
-                        LIMIT ?
-                        "#,
-                    )
-                    .bind(fetch_limit)
-                    .fetch_all(&self.pool)
-                    .await?
-                }
-            }
-        } else {
-            sqlx::query(
-                r#"
-                SELECT task_id, session_id, status, status_message, created_at,
-                       last_updated_at, ttl, poll_interval, original_method,
-                       original_params, result, meta
-                FROM tasks
-                ORDER BY created_at ASC, task_id ASC
-                LIMIT ?
-                "#,
-            )
-            .bind(fetch_limit)
-            .fetch_all(&self.pool)
-            .await?
-        };
-
-        let has_more = rows.len() as i64 > limit;
-        let take_count = if has_more { limit as usize } else { rows.len() };
-
-        let mut tasks = Vec::with_capacity(take_count);
-        for row in rows.into_iter().take(take_count) {
-            tasks.push(row_to_task_record(row)?);
-        }
-
-        let next_cursor = if has_more {
-            tasks.last().map(|t| t.task_id.clone())
-        } else {
-            None
-        };
-
-        Ok(TaskListPage { tasks, next_cursor })
+        self.fetch_task_page(None, cursor, limit, status_filter)
+            .await
     }
 
     async fn update_task_status(
@@ -518,15 +669,33 @@ impl TaskStorage for SqliteTaskStorage {
         let now = Self::now_iso8601();
         let new_status_str = status_to_str(new_status);
 
-        sqlx::query(
-            "UPDATE tasks SET status = ?, status_message = ?, last_updated_at = ? WHERE task_id = ?",
+        // Guard on the validated status so a concurrent writer cannot slip in between
+        let rows_affected = sqlx::query(
+            "UPDATE tasks SET status = ?, status_message = ?, last_updated_at = ? WHERE task_id = ? AND status = ?",
         )
         .bind(new_status_str)
         .bind(&status_message)
         .bind(&now)
         .bind(task_id)
+        .bind(&current_status_str)
         .execute(&self.pool)
-        .await?;
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(TaskStorageError::ConcurrentModification(format!(
+                "Task {} was modified by another writer",
+                task_id
+            )));
+        }
+
+        notify_transition(
+            self.config.observer.as_ref(),
+            task_id,
+            current_status,
+            new_status,
+        )
+        .await;
 
         // Return updated record
         self.get_task(task_id)
@@ -534,6 +703,55 @@ impl TaskStorage for SqliteTaskStorage {
             .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))
     }
 
+    async fn update_task_status_if(
+        &self,
+        task_id: &str,
+        expected: TaskStatus,
+        new_status: TaskStatus,
+        status_message: Option<String>,
+    ) -> Result<TaskRecord, TaskStorageError> {
+        let current_row = sqlx::query("SELECT status FROM tasks WHERE task_id = ?")
+            .bind(task_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))?;
+
+        let current_status_str: String = current_row.get("status");
+        let current_status = str_to_status(&current_status_str)?;
+        if current_status != expected {
+            return Err(TaskStorageError::StatusConflict {
+                task_id: task_id.to_string(),
+                expected,
+                actual: current_status,
+            });
+        }
+
+        state_machine::validate_transition(expected, new_status)?;
+
+        let now = Self::now_iso8601();
+        let rows_affected = sqlx::query(
+            "UPDATE tasks SET status = ?, status_message = ?, last_updated_at = ? WHERE task_id = ? AND status = ?",
+        )
+        .bind(status_to_str(new_status))
+        .bind(&status_message)
+        .bind(&now)
+        .bind(task_id)
+        .bind(status_to_str(expected))
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(status_conflict_error(self, task_id, expected).await);
+        }
+
+        notify_transition(self.config.observer.as_ref(), task_id, expected, new_status).await;
+
+        self.get_task(task_id)
+            .await?
+            .ok_or_else(|| TaskStorageError::TaskNotFound(task_id.to_string()))
+    }
+
     async fn store_task_result(
         &self,
         task_id: &str,
@@ -575,6 +793,63 @@ impl TaskStorage for SqliteTaskStorage {
         }
     }
 
+    async fn append_task_progress(
+        &self,
+        task_id: &str,
+        chunk: Value,
+    ) -> Result<(), TaskStorageError> {
+        let chunk_json = serde_json::to_string(&chunk)?;
+
+        // Insert only while the task exists and is still running
+        let rows_affected = sqlx::query(
+            r#"
+            INSERT INTO task_progress (task_id, chunk)
+            SELECT task_id, ? FROM tasks
+            WHERE task_id = ? AND status IN ('working', 'input_required')
+            "#,
+        )
+        .bind(&chunk_json)
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            let status: Option<String> =
+                sqlx::query_scalar("SELECT status FROM tasks WHERE task_id = ?")
+                    .bind(task_id)
+                    .fetch_optional(&self.pool)
+                    .await?;
+            return Err(match status {
+                Some(status) => TaskStorageError::TerminalState(str_to_status(&status)?),
+                None => TaskStorageError::TaskNotFound(task_id.to_string()),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn get_task_progress(&self, task_id: &str) -> Result<Vec<Value>, TaskStorageError> {
+        let exists: Option<String> =
+            sqlx::query_scalar("SELECT task_id FROM tasks WHERE task_id = ?")
+                .bind(task_id)
+                .fetch_optional(&self.pool)
+                .await?;
+        if exists.is_none() {
+            return Err(TaskStorageError::TaskNotFound(task_id.to_string()));
+        }
+
+        let chunks: Vec<String> =
+            sqlx::query_scalar("SELECT chunk FROM task_progress WHERE task_id = ? ORDER BY seq")
+                .bind(task_id)
+                .fetch_all(&self.pool)
+                .await?;
+        chunks
+            .iter()
+            .map(|chunk| Ok(serde_json::from_str(chunk)?))
+            .collect()
+    }
+
     async fn expire_tasks(&self) -> Result<Vec<String>, TaskStorageError> {
         // Find expired task IDs first
         let expired_ids: Vec<String> = sqlx::query_scalar(
@@ -604,6 +879,31 @@ impl TaskStorage for SqliteTaskStorage {
         Ok(expired_ids)
     }
 
+    async fn purge_terminal_tasks(
+        &self,
+        older_than: chrono::Duration,
+    ) -> Result<u64, TaskStorageError> {
+        let cutoff = (Utc::now() - older_than).to_rfc3339();
+
+        let purged = sqlx::query(
+            r#"
+            DELETE FROM tasks
+            WHERE status IN ('completed', 'failed', 'cancelled')
+              AND julianday(last_updated_at) < julianday(?)
+            "#,
+        )
+        .bind(&cutoff)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if purged > 0 {
+            debug!("Purged {} terminal tasks", purged);
+        }
+
+        Ok(purged)
+    }
+
     async fn task_count(&self) -> Result<usize, TaskStorageError> {
         let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tasks")
             .fetch_one(&self.pool)
@@ -622,90 +922,10 @@ impl TaskStorage for SqliteTaskStorage {
         session_id: &str,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError> {
-        let limit = limit.unwrap_or(self.config.default_page_size) as i64;
-        let fetch_limit = limit + 1;
-
-        let rows = if let Some(cursor_id) = cursor {
-            let cursor_row = sqlx::query("SELECT created_at, task_id FROM tasks WHERE task_id = ?")
-                .bind(cursor_id)
-                .fetch_optional(&self.pool)
-                .await?;
-
-            match cursor_row {
-                Some(crow) => {
-                    let cursor_created_at: String = crow.get("created_at");
-                    let cursor_task_id: String = crow.get("task_id");
-
-                    sqlx::query(
-                        r#"
-                        SELECT task_id, session_id, status, status_message, created_at,
-                               last_updated_at, ttl, poll_interval, original_method,
-                               original_params, result, meta
-                        FROM tasks
-                        WHERE session_id = ? AND (created_at, task_id) > (?, ?)
-                        ORDER BY created_at ASC, task_id ASC
-                        LIMIT ?
-                        "#,
-                    )
-                    .bind(session_id)
-                    .bind(&cursor_created_at)
-                    .bind(&cursor_task_id)
-                    .bind(fetch_limit)
-                    .fetch_all(&self.pool)
-                    .await?
-                }
-                None => {
-                    sqlx::query(
-                        r#"
-                        SELECT task_id, session_id, status, status_message, created_at,
-                               last_updated_at, ttl, poll_interval, original_method,
-                               original_params, result, meta
-                        FROM tasks
-                        WHERE session_id = ?
-                        ORDER BY created_at ASC, task_id ASC
-                        LIMIT ?
-                        "#,
-                    )
-                    .bind(session_id)
-                    .bind(fetch_limit)
-                    .fetch_all(&self.pool)
-                    .await?
-                }
-            }
-        } else {
-            sqlx::query(
-                r#"
-                SELECT task_id, session_id, status, status_message, created_at,
-                       last_updated_at, ttl, poll_interval, original_method,
-                       original_params, result, meta
-                FROM tasks
-                WHERE session_id = ?
-                ORDER BY created_at ASC, task_id ASC
-                LIMIT ?
-                "#,
-            )
-            .bind(session_id)
-            .bind(fetch_limit)
-            .fetch_all(&self.pool)
-            .await?
-        };
-
-        let has_more = rows.len() as i64 > limit;
-        let take_count = if has_more { limit as usize } else { rows.len() };
-
-        let mut tasks = Vec::with_capacity(take_count);
-        for row in rows.into_iter().take(take_count) {
-            tasks.push(row_to_task_record(row)?);
-        }
-
-        let next_cursor = if has_more {
-            tasks.last().map(|t| t.task_id.clone())
-        } else {
-            None
-        };
-
-        Ok(TaskListPage { tasks, next_cursor })
+        self.fetch_task_page(Some(session_id), cursor, limit, status_filter)
+            .await
     }
 
     async fn recover_stuck_tasks(&self, max_age_ms: u64) -> Result<Vec<String>, TaskStorageError> {
@@ -881,9 +1101,11 @@ mod tests {
             .await
             .unwrap_err();
         match err {
-            TaskStorageError::InvalidTransition { current, requested } => {
-                assert_eq!(current, TaskStatus::Working);
-                assert_eq!(requested, TaskStatus::Working);
+            TaskStorageError::InvalidTransition {
+                from, attempted, ..
+            } => {
+                assert_eq!(from, TaskStatus::Working);
+                assert_eq!(attempted, TaskStatus::Working);
             }
             other => panic!("Expected InvalidTransition, got: {:?}", other),
         }
@@ -911,7 +1133,7 @@ mod tests {
         }
 
         // Page 1: limit 2
-        let page1 = storage.list_tasks(None, Some(2)).await.unwrap();
+        let page1 = storage.list_tasks(None, Some(2), None).await.unwrap();
         assert_eq!(page1.tasks.len(), 2);
         assert_eq!(page1.tasks[0].task_id, "task-0");
         assert_eq!(page1.tasks[1].task_id, "task-1");
@@ -919,7 +1141,7 @@ mod tests {
 
         // Page 2: using cursor from page 1
         let page2 = storage
-            .list_tasks(page1.next_cursor.as_deref(), Some(2))
+            .list_tasks(page1.next_cursor.as_deref(), Some(2), None)
             .await
             .unwrap();
         assert_eq!(page2.tasks.len(), 2);
@@ -929,7 +1151,7 @@ mod tests {
 
         // Page 3: last page
         let page3 = storage
-            .list_tasks(page2.next_cursor.as_deref(), Some(2))
+            .list_tasks(page2.next_cursor.as_deref(), Some(2), None)
             .await
             .unwrap();
         assert_eq!(page3.tasks.len(), 1);
@@ -938,13 +1160,46 @@ mod tests {
 
         // Invalid cursor falls back to beginning
         let fallback = storage
-            .list_tasks(Some("no-such-id"), Some(2))
+            .list_tasks(Some("no-such-id"), Some(2), None)
             .await
             .unwrap();
         assert_eq!(fallback.tasks.len(), 2);
         assert_eq!(fallback.tasks[0].task_id, "task-0");
     }
 
+    #[tokio::test]
+    async fn test_sqlite_list_tasks_status_filter() {
+        let storage = create_temp_sqlite_storage().await;
+
+        for i in 0..6 {
+            let mut task =
+                make_task_with_time(&format!("task-{}", i), &format!("2025-01-01T00:00:0{}Z", i));
+            if i % 3 == 1 {
+                task.status = TaskStatus::InputRequired;
+            } else if i % 3 == 2 {
+                task.status = TaskStatus::Failed;
+            }
+            storage.create_task(task).await.unwrap();
+        }
+
+        let active = [TaskStatus::Working, TaskStatus::InputRequired];
+        let page1 = storage
+            .list_tasks(None, Some(3), Some(&active))
+            .await
+            .unwrap();
+        let ids: Vec<&str> = page1.tasks.iter().map(|t| t.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-0", "task-1", "task-3"]);
+        assert!(page1.next_cursor.is_some());
+
+        let page2 = storage
+            .list_tasks(page1.next_cursor.as_deref(), Some(3), Some(&active))
+            .await
+            .unwrap();
+        let ids: Vec<&str> = page2.tasks.iter().map(|t| t.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-4"]);
+        assert!(page2.next_cursor.is_none());
+    }
+
     #[tokio::test]
     async fn test_sqlite_session_scoping() {
         let storage = create_temp_sqlite_storage().await;
@@ -967,26 +1222,26 @@ mod tests {
             .unwrap();
 
         let s1 = storage
-            .list_tasks_for_session("session-1", None, None)
+            .list_tasks_for_session("session-1", None, None, None)
             .await
             .unwrap();
         assert_eq!(s1.tasks.len(), 2);
 
         let s2 = storage
-            .list_tasks_for_session("session-2", None, None)
+            .list_tasks_for_session("session-2", None, None, None)
             .await
             .unwrap();
         assert_eq!(s2.tasks.len(), 1);
         assert_eq!(s2.tasks[0].task_id, "task-c");
 
         let empty = storage
-            .list_tasks_for_session("session-3", None, None)
+            .list_tasks_for_session("session-3", None, None, None)
             .await
             .unwrap();
         assert_eq!(empty.tasks.len(), 0);
 
         // All tasks visible via global list
-        let all = storage.list_tasks(None, None).await.unwrap();
+        let all = storage.list_tasks(None, None, None).await.unwrap();
         assert_eq!(all.tasks.len(), 4);
     }
 
@@ -1121,6 +1376,44 @@ mod tests {
         assert_eq!(done.status, TaskStatus::Completed);
     }
 
+    #[tokio::test]
+    async fn test_sqlite_compact_shrinks_database_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let database_path = dir.path().join("tasks.db");
+        let storage = SqliteTaskStorage::with_config(SqliteTaskConfig {
+            database_path: database_path.clone(),
+            vacuum_mode: SqliteVacuumMode::Incremental,
+            ..SqliteTaskConfig::default()
+        })
+        .await
+        .unwrap();
+
+        let params = json!({ "payload": "x".repeat(4096) });
+        for i in 0..200 {
+            let mut task = make_task(&format!("compact-{}", i), None);
+            task.original_params = Some(params.clone());
+            storage.create_task(task).await.unwrap();
+        }
+        for i in 0..200 {
+            storage
+                .delete_task(&format!("compact-{}", i))
+                .await
+                .unwrap();
+        }
+
+        // Deletions only move pages to the freelist; the file keeps its size
+        let size_before = std::fs::metadata(&database_path).unwrap().len();
+        storage.compact().await.unwrap();
+        let size_after = std::fs::metadata(&database_path).unwrap().len();
+
+        assert!(
+            size_after < size_before,
+            "expected compaction to shrink the file ({} -> {} bytes)",
+            size_before,
+            size_after
+        );
+    }
+
     #[tokio::test]
     async fn test_sqlite_max_tasks() {
         let config = SqliteTaskConfig {
@@ -1267,4 +1560,34 @@ mod tests {
         let storage = std::sync::Arc::new(create_temp_sqlite_storage().await);
         crate::parity_tests::test_concurrent_status_updates(storage).await;
     }
+
+    #[tokio::test]
+    async fn parity_status_compare_and_set() {
+        let storage = std::sync::Arc::new(create_temp_sqlite_storage().await);
+        crate::parity_tests::test_status_compare_and_set(storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_task_progress() {
+        let storage = create_temp_sqlite_storage().await;
+        crate::parity_tests::test_task_progress(&storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_watch_task_until_terminal() {
+        let storage = create_temp_sqlite_storage().await;
+        crate::parity_tests::test_watch_task_until_terminal(&storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_purge_terminal_tasks() {
+        let storage = create_temp_sqlite_storage().await;
+        crate::parity_tests::test_purge_terminal_tasks(&storage).await;
+    }
+
+    #[tokio::test]
+    async fn parity_status_filtered_listing() {
+        let storage = create_temp_sqlite_storage().await;
+        crate::parity_tests::test_status_filtered_listing(&storage).await;
+    }
 }
@@ -11,34 +11,46 @@
 use crate::error::TaskStorageError;
 use turul_mcp_protocol::TaskStatus;
 
+/// Statuses a task in `status` may move to next.
+///
+/// Empty for terminal statuses. Useful for offering only the legal actions in a UI.
+pub fn allowed_transitions(status: TaskStatus) -> &'static [TaskStatus] {
+    match status {
+        TaskStatus::Working => &[
+            TaskStatus::InputRequired,
+            TaskStatus::Completed,
+            TaskStatus::Failed,
+            TaskStatus::Cancelled,
+        ],
+        TaskStatus::InputRequired => &[
+            TaskStatus::Working,
+            TaskStatus::Completed,
+            TaskStatus::Failed,
+            TaskStatus::Cancelled,
+        ],
+        TaskStatus::Completed | TaskStatus::Failed | TaskStatus::Cancelled => &[],
+    }
+}
+
 /// Validate a task status transition per MCP 2025-11-25 lifecycle rules.
 ///
-/// Returns `Ok(())` if the transition is valid, or `Err(TaskStorageError)` if not.
+/// Returns `Ok(())` if the transition is valid. Otherwise returns
+/// `TaskStorageError::TerminalState` when `from` is terminal, or
+/// `TaskStorageError::InvalidTransition` carrying the statuses `from` could move to.
 pub fn validate_transition(from: TaskStatus, to: TaskStatus) -> Result<(), TaskStorageError> {
-    match from {
-        TaskStatus::Working => match to {
-            TaskStatus::InputRequired
-            | TaskStatus::Completed
-            | TaskStatus::Failed
-            | TaskStatus::Cancelled => Ok(()),
-            TaskStatus::Working => Err(TaskStorageError::InvalidTransition {
-                current: from,
-                requested: to,
-            }),
-        },
-        TaskStatus::InputRequired => match to {
-            TaskStatus::Working
-            | TaskStatus::Completed
-            | TaskStatus::Failed
-            | TaskStatus::Cancelled => Ok(()),
-            TaskStatus::InputRequired => Err(TaskStorageError::InvalidTransition {
-                current: from,
-                requested: to,
-            }),
-        },
-        TaskStatus::Completed | TaskStatus::Failed | TaskStatus::Cancelled => {
-            Err(TaskStorageError::TerminalState(from))
-        }
+    if is_terminal(from) {
+        return Err(TaskStorageError::TerminalState(from));
+    }
+
+    let allowed = allowed_transitions(from);
+    if allowed.contains(&to) {
+        Ok(())
+    } else {
+        Err(TaskStorageError::InvalidTransition {
+            from,
+            attempted: to,
+            allowed: allowed.to_vec(),
+        })
     }
 }
 
@@ -64,7 +76,18 @@ mod tests {
 
     #[test]
     fn test_invalid_working_to_working() {
-        assert!(validate_transition(TaskStatus::Working, TaskStatus::Working).is_err());
+        match validate_transition(TaskStatus::Working, TaskStatus::Working).unwrap_err() {
+            TaskStorageError::InvalidTransition {
+                from,
+                attempted,
+                allowed,
+            } => {
+                assert_eq!(from, TaskStatus::Working);
+                assert_eq!(attempted, TaskStatus::Working);
+                assert_eq!(allowed, allowed_transitions(TaskStatus::Working));
+            }
+            other => panic!("Expected InvalidTransition, got: {:?}", other),
+        }
     }
 
     #[test]
@@ -109,6 +132,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_allowed_transitions_working() {
+        assert_eq!(
+            allowed_transitions(TaskStatus::Working),
+            [
+                TaskStatus::InputRequired,
+                TaskStatus::Completed,
+                TaskStatus::Failed,
+                TaskStatus::Cancelled,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_allowed_transitions_input_required() {
+        assert_eq!(
+            allowed_transitions(TaskStatus::InputRequired),
+            [
+                TaskStatus::Working,
+                TaskStatus::Completed,
+                TaskStatus::Failed,
+                TaskStatus::Cancelled,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_allowed_transitions_terminal_is_empty() {
+        assert!(allowed_transitions(TaskStatus::Completed).is_empty());
+        assert!(allowed_transitions(TaskStatus::Failed).is_empty());
+        assert!(allowed_transitions(TaskStatus::Cancelled).is_empty());
+    }
+
+    #[test]
+    fn test_allowed_transitions_agree_with_validate_transition() {
+        let all = [
+            TaskStatus::Working,
+            TaskStatus::InputRequired,
+            TaskStatus::Completed,
+            TaskStatus::Failed,
+            TaskStatus::Cancelled,
+        ];
+        for from in all {
+            for to in all {
+                assert_eq!(
+                    validate_transition(from, to).is_ok(),
+                    allowed_transitions(from).contains(&to),
+                    "{:?} -> {:?}",
+                    from,
+                    to
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_is_terminal() {
         assert!(!is_terminal(TaskStatus::Working));
@@ -4,12 +4,18 @@
 //! across different backends (InMemory, SQLite, PostgreSQL, DynamoDB).
 
 use crate::error::TaskStorageError;
+use crate::state_machine;
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::time::Duration;
 use turul_mcp_protocol::TaskStatus;
 
+/// Polling interval used by the default `watch_task` when a task has no `poll_interval`.
+pub const DEFAULT_WATCH_POLL_INTERVAL_MS: u64 = 1_000;
+
 /// The outcome of a task's underlying request.
 ///
 /// Stored by `TaskStorage`, returned verbatim by the `tasks/result` handler.
@@ -122,10 +128,15 @@ pub trait TaskStorage: Send + Sync {
     // === Task Listing (paginated) ===
 
     /// List tasks with cursor-based pagination.
+    ///
+    /// When `status_filter` is non-empty, only tasks in one of those statuses are
+    /// returned; the filter is applied before pagination so cursors stay consistent.
+    /// `None` (or an empty slice) lists tasks in every status.
     async fn list_tasks(
         &self,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError>;
 
     // === Task Status Updates (state machine enforcement) ===
@@ -142,6 +153,21 @@ pub trait TaskStorage: Send + Sync {
         status_message: Option<String>,
     ) -> Result<TaskRecord, TaskStorageError>;
 
+    /// Compare-and-set variant of [`update_task_status`](Self::update_task_status).
+    ///
+    /// Applies the change only if the stored status currently equals `expected`,
+    /// atomically with respect to other writers. Returns
+    /// `TaskStorageError::StatusConflict` (carrying the status actually found) when
+    /// it does not, so two workers racing on the same task cannot both win.
+    /// Transitions are still validated by the state machine.
+    async fn update_task_status_if(
+        &self,
+        task_id: &str,
+        expected: TaskStatus,
+        new_status: TaskStatus,
+        status_message: Option<String>,
+    ) -> Result<TaskRecord, TaskStorageError>;
+
     // === Result Storage ===
 
     /// Store the outcome of the underlying request for a task.
@@ -155,11 +181,37 @@ pub trait TaskStorage: Send + Sync {
     async fn get_task_result(&self, task_id: &str)
     -> Result<Option<TaskOutcome>, TaskStorageError>;
 
+    // === Progress ===
+
+    /// Append a chunk of partial output to a task that has not finished yet.
+    ///
+    /// Chunks are kept in append order, independently of the final `TaskOutcome`,
+    /// and live until the task itself is deleted, expired, or purged. Returns
+    /// `TaskStorageError::TerminalState` once the task has reached a terminal status.
+    async fn append_task_progress(
+        &self,
+        task_id: &str,
+        chunk: Value,
+    ) -> Result<(), TaskStorageError>;
+
+    /// Get every progress chunk appended to a task, oldest first.
+    async fn get_task_progress(&self, task_id: &str) -> Result<Vec<Value>, TaskStorageError>;
+
     // === Cleanup ===
 
     /// Expire tasks that have exceeded their TTL. Returns IDs of expired tasks.
     async fn expire_tasks(&self) -> Result<Vec<String>, TaskStorageError>;
 
+    /// Delete terminal tasks (completed, failed, cancelled) whose `last_updated_at`
+    /// is older than `older_than`. Returns the number of tasks removed.
+    ///
+    /// Unlike TTL expiry this is honored by every backend; it runs only when called,
+    /// so operators schedule it themselves.
+    async fn purge_terminal_tasks(
+        &self,
+        older_than: chrono::Duration,
+    ) -> Result<u64, TaskStorageError>;
+
     /// Get the total number of tasks in storage.
     async fn task_count(&self) -> Result<usize, TaskStorageError>;
 
@@ -169,11 +221,14 @@ pub trait TaskStorage: Send + Sync {
     // === Session Binding ===
 
     /// List tasks bound to a specific session, with cursor-based pagination.
+    ///
+    /// `status_filter` behaves as in [`list_tasks`](Self::list_tasks).
     async fn list_tasks_for_session(
         &self,
         session_id: &str,
         cursor: Option<&str>,
         limit: Option<u32>,
+        status_filter: Option<&[TaskStatus]>,
     ) -> Result<TaskListPage, TaskStorageError>;
 
     // === Recovery ===
@@ -183,4 +238,84 @@ pub trait TaskStorage: Send + Sync {
     /// Called on server startup to recover from unclean shutdown.
     /// Returns the IDs of tasks that were marked as failed.
     async fn recover_stuck_tasks(&self, max_age_ms: u64) -> Result<Vec<String>, TaskStorageError>;
+
+    // === Change Notification ===
+
+    /// Stream updates to a single task until it reaches a terminal status.
+    ///
+    /// The first item is the task's current record; each later item is a newer
+    /// snapshot. The stream ends after yielding a record whose status is terminal
+    /// per [`state_machine::is_terminal`]. If the task does not exist (or is deleted
+    /// while being watched) a single `TaskNotFound` error is yielded and the stream
+    /// ends. Intermediate states may be coalesced — subscribers see the latest
+    /// snapshot, not every write.
+    ///
+    /// The default implementation polls `get_task` at the task's `poll_interval`
+    /// (falling back to [`DEFAULT_WATCH_POLL_INTERVAL_MS`]). Backends with native
+    /// change notification should override it. Dropping the stream stops the watch.
+    fn watch_task<'a>(
+        &'a self,
+        task_id: &'a str,
+    ) -> BoxStream<'a, Result<TaskRecord, TaskStorageError>> {
+        // State: (last yielded snapshot, finished)
+        stream::unfold(
+            (None::<TaskRecord>, false),
+            move |(last, finished)| async move {
+                if finished {
+                    return None;
+                }
+                loop {
+                    if let Some(previous) = &last {
+                        let interval = previous
+                            .poll_interval
+                            .unwrap_or(DEFAULT_WATCH_POLL_INTERVAL_MS);
+                        tokio::time::sleep(Duration::from_millis(interval)).await;
+                    }
+
+                    let current = match self.get_task(task_id).await {
+                        Ok(Some(task)) => task,
+                        Ok(None) => {
+                            let err = TaskStorageError::TaskNotFound(task_id.to_string());
+                            return Some((Err(err), (last, true)));
+                        }
+                        Err(e) => return Some((Err(e), (last, true))),
+                    };
+
+                    let changed = last.as_ref().is_none_or(|previous| {
+                        previous.status != current.status
+                            || previous.status_message != current.status_message
+                            || previous.last_updated_at != current.last_updated_at
+                    });
+                    if changed {
+                        let done = state_machine::is_terminal(current.status);
+                        return Some((Ok(current.clone()), (Some(current), done)));
+                    }
+                }
+            },
+        )
+        .boxed()
+    }
+}
+
+/// Error for an `update_task_status_if` whose guarded write matched nothing:
+/// the task was deleted, or its status moved away from `expected`, after it was read.
+#[cfg(any(feature = "sqlite", feature = "postgres", feature = "dynamodb"))]
+pub(crate) async fn status_conflict_error(
+    storage: &dyn TaskStorage,
+    task_id: &str,
+    expected: TaskStatus,
+) -> TaskStorageError {
+    match storage.get_task(task_id).await {
+        Ok(Some(task)) if task.status != expected => TaskStorageError::StatusConflict {
+            task_id: task_id.to_string(),
+            expected,
+            actual: task.status,
+        },
+        Ok(Some(_)) => TaskStorageError::ConcurrentModification(format!(
+            "Task {} was modified by another writer",
+            task_id
+        )),
+        Ok(None) => TaskStorageError::TaskNotFound(task_id.to_string()),
+        Err(e) => e,
+    }
 }
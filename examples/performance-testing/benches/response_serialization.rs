@@ -0,0 +1,97 @@
+//! Response Serialization Benchmarks
+//!
+//! Compares the HTTP transport's JSON-RPC response builders for a `tools/call`
+//! result: `jsonrpc_success_response` serializes with `serde_json::to_vec`, while
+//! `jsonrpc_response_with_session` writes into the transport's pooled buffers.
+//! Heap allocations per response are reported for each.
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde_json::{Value, json};
+use turul_http_mcp_server::json_rpc_responses::{
+    jsonrpc_response_with_session, jsonrpc_success_response,
+};
+use turul_mcp_json_rpc_server::{JsonRpcResponse, RequestId};
+use turul_mcp_protocol::tools::{CallToolResult, ToolResult};
+
+/// Global allocator that counts allocations
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// A `tools/call` result carrying `size` bytes of text plus structured content
+fn tool_result(size: usize) -> Value {
+    let result = CallToolResult::success(vec![ToolResult::text("x".repeat(size))])
+        .with_structured_content(json!({ "rows": size / 16, "ok": true }));
+    serde_json::to_value(result).unwrap()
+}
+
+/// Average allocations per call of `respond`, after warming up
+///
+/// Inputs are cloned before counting, so only building the response is measured.
+fn allocations_per_response<T: Clone, R>(input: &T, respond: impl Fn(T) -> R) -> f64 {
+    const RUNS: usize = 1000;
+    for _ in 0..10 {
+        drop(respond(input.clone()));
+    }
+    let inputs = vec![input.clone(); RUNS];
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for input in inputs {
+        // Dropped before the next run, as hyper drops a body once it is written
+        drop(black_box(respond(input)));
+    }
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) as f64 / RUNS as f64
+}
+
+fn response_serialization_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("response_serialization");
+
+    for size in [256, 4 * 1024, 64 * 1024] {
+        let result = tool_result(size);
+        let response = JsonRpcResponse::success(RequestId::Number(1), result.clone());
+        let to_vec = |result: Value| jsonrpc_success_response(RequestId::Number(1), result);
+        let pooled = |response: JsonRpcResponse| jsonrpc_response_with_session(response, None);
+
+        println!(
+            "allocations per {size} byte response: to_vec {:.2}, pooled {:.2}",
+            allocations_per_response(&result, to_vec),
+            allocations_per_response(&response, pooled),
+        );
+
+        group.bench_with_input(BenchmarkId::new("to_vec", size), &result, |b, result| {
+            b.iter_batched(|| result.clone(), to_vec, BatchSize::SmallInput)
+        });
+        group.bench_with_input(
+            BenchmarkId::new("pooled", size),
+            &response,
+            |b, response| b.iter_batched(|| response.clone(), pooled, BatchSize::SmallInput),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, response_serialization_benchmarks);
+criterion_main!(benches);
@@ -12,7 +12,7 @@ use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 use turul_mcp_protocol::logging::LoggingLevel;
-use turul_mcp_server::SessionContext;
+use turul_mcp_server::{CancellationToken, SessionContext};
 
 /// Create a mock session context for benchmarking
 fn create_session_context() -> SessionContext {
@@ -43,6 +43,9 @@ fn create_session_context() -> SessionContext {
         send_notification: Arc::new(|_| Box::pin(async {})),
         broadcaster: None,
         extensions: std::collections::HashMap::new(),
+        request_meta: None,
+        response_meta: Arc::default(),
+        cancellation_token: CancellationToken::new(),
     }
 }
 
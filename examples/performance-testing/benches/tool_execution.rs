@@ -154,6 +154,9 @@ fn tool_execution_benchmarks(c: &mut Criterion) {
                 send_notification: Arc::new(|_| Box::pin(async {})),
                 broadcaster: None,
                 extensions: std::collections::HashMap::new(),
+                request_meta: None,
+                response_meta: Arc::default(),
+                cancellation_token: turul_mcp_server::CancellationToken::new(),
             };
 
             let args = json!({"value": black_box(1)});
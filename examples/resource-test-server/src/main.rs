@@ -18,7 +18,7 @@
 //!
 //! ### Advanced Resources (Features)
 //! - `file:///session/info.json` - Returns session ID and metadata (session-aware)
-//! - `file:///subscribe/updates.json` - Resource for testing subscription behavior (returns MethodNotFound)
+//! - `file:///subscribe/updates.json` - Resource for testing `resources/subscribe`
 //! - `file:///notify/trigger.json` - Triggers list change notifications via SSE
 //! - `file:///multi/contents.txt` - Returns multiple ResourceContent items
 //! - `file:///paginated/items.json` - Supports cursor-based pagination
@@ -1666,9 +1666,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("");
     info!("   🚀 Advanced Resources (Features):");
     info!("      • file:///session/info.json - Session-aware resource");
-    info!(
-        "      • file:///subscribe/updates.json - Test subscription resource (returns MethodNotFound)"
-    );
+    info!("      • file:///subscribe/updates.json - Test subscription resource");
     info!("      • file:///notify/trigger.json - SSE notification triggers");
     info!("      • file:///multi/contents.txt - Multiple ResourceContent items");
     info!("      • file:///paginated/items.json - Cursor-based pagination");
@@ -39,6 +39,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         enable_encryption: true,
         verify_tables: true,
         create_tables: true, // Always true for setup
+        single_table: false,
     };
 
     // Initialize DynamoDB session storage
@@ -42,6 +42,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         enable_encryption: true,
         verify_tables: true,
         create_tables: false, // Not creating, just connecting
+        single_table: false,
     };
 
     // Initialize DynamoDB session storage
@@ -148,6 +148,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         enable_encryption: true,
         verify_tables: true,
         create_tables: true,
+        single_table: false,
     };
 
     info!("AWS DynamoDB Configuration:");
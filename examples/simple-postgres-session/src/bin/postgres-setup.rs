@@ -32,6 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         session_timeout_minutes: 60,
         cleanup_interval_minutes: 10,
         max_events_per_session: 1000,
+        event_ttl_minutes: None,
         enable_pooling_optimizations: true,
         statement_timeout_secs: 30,
         verify_tables: true,
@@ -33,9 +33,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         session_timeout_minutes: 30,
         cleanup_interval_minutes: 5,
         max_events_per_session: 500,
+        event_ttl_minutes: None,
         verify_tables: true,
         create_tables: true,              // Always true for setup
         create_database_if_missing: true, // Always true for setup
+        ..SqliteConfig::default()
     };
 
     // Initialize SQLite session storage (this will create tables)
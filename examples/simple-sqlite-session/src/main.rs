@@ -17,7 +17,7 @@ use std::sync::Arc;
 use tracing::{debug, error, info};
 use turul_mcp_derive::McpTool;
 use turul_mcp_server::{McpResult, McpServer, SessionContext};
-use turul_mcp_session_storage::{SqliteConfig, SqliteSessionStorage};
+use turul_mcp_session_storage::{SqliteConfig, SqliteSessionStorage, SqliteVacuumMode};
 
 /// Tool that stores a key-value pair in this session's SQLite storage
 #[derive(McpTool, Default)]
@@ -155,9 +155,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         session_timeout_minutes: 30,
         cleanup_interval_minutes: 5,
         max_events_per_session: 500,
+        event_ttl_minutes: None,
         verify_tables: true,
         create_tables: true,
         create_database_if_missing: true,
+        compaction_interval_minutes: Some(60),
+        vacuum_mode: SqliteVacuumMode::Incremental,
     };
 
     info!(
@@ -26,6 +26,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         session_timeout_minutes: 60,        // Session TTL (default: 30)
         cleanup_interval_minutes: 5,        // Background cleanup interval (default: 5)
         max_events_per_session: 1000,       // Max SSE events per session (default: 1000)
+        event_ttl_minutes: Some(60),        // Prune events older than this (default: None)
         enable_pooling_optimizations: true,  // Pool tuning (default: true)
         statement_timeout_secs: 30,         // Query timeout (default: 30)
         verify_tables: true,
@@ -0,0 +1,116 @@
+//! E2E tests for the typed resource and prompt methods on `McpClient`.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use turul_mcp_builders::ResourceBuilder;
+use turul_mcp_client::{McpClient, McpClientBuilder};
+use turul_mcp_derive::McpPrompt;
+use turul_mcp_protocol::{ContentBlock, ResourceContent};
+use turul_mcp_server::prelude::*;
+
+#[derive(McpPrompt)]
+#[prompt(name = "greeting", description = "Greet someone by name")]
+struct GreetingPrompt {
+    #[argument(name = "name", description = "Who to greet", required = true)]
+    name: String,
+}
+
+#[async_trait]
+impl McpPrompt for GreetingPrompt {
+    async fn render(&self, args: Option<HashMap<String, Value>>) -> McpResult<Vec<PromptMessage>> {
+        let name = args
+            .as_ref()
+            .and_then(|args| args.get("name"))
+            .and_then(Value::as_str)
+            .unwrap_or(&self.name);
+        Ok(vec![PromptMessage::user_text(format!("Hello, {}!", name))])
+    }
+}
+
+async fn connect() -> McpClient {
+    let url = spawn_server(
+        McpServer::builder()
+            .name("client-typed-test")
+            .version("1.0.0")
+            .resource(
+                ResourceBuilder::new("file:///app/config.json")
+                    .name("app_config")
+                    .description("Application configuration")
+                    .json_content(json!({ "version": "1.0.0" }))
+                    .build()
+                    .unwrap(),
+            )
+            .prompt(GreetingPrompt {
+                name: "world".to_string(),
+            }),
+    )
+    .await;
+
+    let client = McpClientBuilder::new().with_url(&url).unwrap().build();
+    client.connect().await.unwrap();
+    client
+}
+
+#[tokio::test]
+async fn test_list_and_read_resources() {
+    let client = connect().await;
+
+    let page = client.list_resources_paginated(None).await.unwrap();
+    assert_eq!(page.resources.len(), 1);
+    assert_eq!(page.resources[0].uri, "file:///app/config.json");
+    assert_eq!(page.resources[0].name, "app_config");
+    assert!(page.next_cursor.is_none());
+
+    let cached = client.list_resources().await.unwrap();
+    assert_eq!(cached.len(), 1);
+
+    let result = client
+        .read_resource("file:///app/config.json")
+        .await
+        .unwrap();
+    assert_eq!(result.contents.len(), 1);
+    match &result.contents[0] {
+        ResourceContent::Text(text) => {
+            assert_eq!(text.uri, "file:///app/config.json");
+            let value: Value = serde_json::from_str(&text.text).unwrap();
+            assert_eq!(value["version"], "1.0.0");
+        }
+        other => panic!("expected text content, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_list_and_get_prompts() {
+    let client = connect().await;
+
+    let page = client.list_prompts_paginated(None).await.unwrap();
+    assert_eq!(page.prompts.len(), 1);
+    assert_eq!(page.prompts[0].name, "greeting");
+    let arguments = page.prompts[0].arguments.as_ref().unwrap();
+    assert_eq!(arguments[0].name, "name");
+    assert!(page.next_cursor.is_none());
+
+    let cached = client.list_prompts().await.unwrap();
+    assert_eq!(cached.len(), 1);
+
+    let result = client
+        .get_prompt("greeting", Some(json!({ "name": "Ada" })))
+        .await
+        .unwrap();
+    assert_eq!(result.messages.len(), 1);
+    match &result.messages[0].content {
+        ContentBlock::Text { text, .. } => assert_eq!(text, "Hello, Ada!"),
+        other => panic!("expected text content, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_unknown_resource_and_prompt_are_errors() {
+    let client = connect().await;
+
+    assert!(client.read_resource("file:///missing.txt").await.is_err());
+    assert!(client.get_prompt("missing", None).await.is_err());
+}
@@ -0,0 +1,138 @@
+//! E2E tests for `McpServerBuilder::completion_fn`: `completion/complete`
+//! answered by an async provider that filters a live data source by prefix.
+
+use std::sync::{Arc, Mutex};
+
+use serde_json::{Value, json};
+use turul_mcp_client::transport::{InProcessTransport, Transport};
+use turul_mcp_server::McpServer;
+
+const TICKETS: [&str; 5] = ["TCK-101", "TCK-102", "TCK-205", "OPS-7", "TCK-300"];
+
+/// Initialize a session against a server whose completions come from `TICKETS`,
+/// recording each provider call; returns the transport and the advertised capabilities
+async fn connect(
+    max_values: Option<usize>,
+) -> (InProcessTransport, Value, Arc<Mutex<Vec<(String, String)>>>) {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&calls);
+
+    let mut builder = McpServer::builder()
+        .name("completion-test")
+        .version("1.0.0")
+        .completion_fn(move |argument, partial| {
+            recorded
+                .lock()
+                .unwrap()
+                .push((argument.clone(), partial.clone()));
+            async move {
+                if argument != "ticket_id" {
+                    return Ok(Vec::new());
+                }
+                Ok(TICKETS
+                    .iter()
+                    .filter(|id| id.starts_with(&partial))
+                    .map(|id| id.to_string())
+                    .collect())
+            }
+        });
+    if let Some(max_values) = max_values {
+        builder = builder.completion_max_values(max_values);
+    }
+    let server = builder.build().unwrap();
+
+    let transport = InProcessTransport::new(server.in_process_service().await);
+    transport.connect().await.unwrap();
+    let initialized = transport
+        .send_request(json!({
+            "jsonrpc": "2.0",
+            "id": "init",
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "completion-test-client", "version": "1.0.0" }
+            }
+        }))
+        .await
+        .unwrap();
+    transport
+        .send_notification(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .await
+        .unwrap();
+
+    let capabilities = initialized["result"]["capabilities"].clone();
+    (transport, capabilities, calls)
+}
+
+async fn complete(transport: &InProcessTransport, argument: &str, partial: &str) -> Value {
+    let response = transport
+        .send_request(json!({
+            "jsonrpc": "2.0",
+            "id": format!("complete-{}-{}", argument, partial),
+            "method": "completion/complete",
+            "params": {
+                "ref": { "type": "ref/prompt", "name": "triage" },
+                "argument": { "name": argument, "value": partial }
+            }
+        }))
+        .await
+        .unwrap();
+    response["result"].clone()
+}
+
+#[tokio::test]
+async fn test_provider_filters_by_prefix() {
+    let (transport, capabilities, calls) = connect(None).await;
+    assert!(
+        capabilities.get("completions").is_some(),
+        "{}",
+        capabilities
+    );
+
+    let result = complete(&transport, "ticket_id", "TCK-1").await;
+    assert_eq!(
+        result["completion"]["values"],
+        json!(["TCK-101", "TCK-102"])
+    );
+    assert_eq!(result["completion"]["total"], 2);
+    assert_eq!(result["completion"]["hasMore"], false);
+
+    let result = complete(&transport, "ticket_id", "OPS").await;
+    assert_eq!(result["completion"]["values"], json!(["OPS-7"]));
+
+    let result = complete(&transport, "assignee", "TCK").await;
+    assert_eq!(result["completion"]["values"], json!([]));
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [
+            ("ticket_id".to_string(), "TCK-1".to_string()),
+            ("ticket_id".to_string(), "OPS".to_string()),
+            ("assignee".to_string(), "TCK".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_results_are_capped_at_max_values() {
+    let (transport, _, _) = connect(Some(2)).await;
+
+    let result = complete(&transport, "ticket_id", "TCK").await;
+    assert_eq!(
+        result["completion"]["values"],
+        json!(["TCK-101", "TCK-102"])
+    );
+    assert_eq!(result["completion"]["total"], 4);
+    assert_eq!(result["completion"]["hasMore"], true);
+}
+
+#[test]
+fn test_zero_max_values_is_rejected() {
+    let result = McpServer::builder()
+        .name("completion-test")
+        .completion_fn(|_, _| async { Ok(Vec::new()) })
+        .completion_max_values(0)
+        .build();
+    assert!(result.is_err());
+}
@@ -6,19 +6,13 @@
 //! Per MCP spec, servers may return either `application/json` or `text/event-stream`
 //! depending on the Accept header. These tests assert wire-format consistency.
 
+use mcp_e2e_shared::spawn_server;
 use serde_json::{Value, json};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::time::sleep;
 use turul_mcp_server::McpServer;
 use turul_mcp_session_storage::InMemorySessionStorage;
 
 async fn start_negotiation_test_server() -> String {
-    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
-    let addr = listener.local_addr().unwrap();
-    let server_url = format!("http://127.0.0.1:{}/mcp", addr.port());
-    drop(listener);
-
     use turul_mcp_derive::mcp_tool;
     use turul_mcp_protocol::McpResult;
 
@@ -28,23 +22,14 @@ async fn start_negotiation_test_server() -> String {
     }
 
     let session_storage = Arc::new(InMemorySessionStorage::new());
-    let server = McpServer::builder()
-        .name("content-type-negotiation-test")
-        .version("1.0.0")
-        .tool_fn(echo)
-        .with_session_storage(session_storage)
-        .bind_address(addr)
-        .build()
-        .unwrap();
-
-    tokio::spawn(async move {
-        if let Err(e) = server.run().await {
-            eprintln!("Server error: {}", e);
-        }
-    });
-
-    sleep(Duration::from_millis(200)).await;
-    server_url
+    spawn_server(
+        McpServer::builder()
+            .name("content-type-negotiation-test")
+            .version("1.0.0")
+            .tool_fn(echo)
+            .with_session_storage(session_storage),
+    )
+    .await
 }
 
 /// Complete the MCP handshake and return the session ID.
@@ -521,6 +521,25 @@ impl ReaderTool {
     }
 }
 
+/// Derive macro: annotation hints written as bare flags
+#[derive(McpTool)]
+#[tool(
+    name = "list_keys",
+    description = "List stored keys",
+    read_only,
+    idempotent
+)]
+struct ListKeysTool {
+    #[param(description = "Key prefix")]
+    prefix: String,
+}
+
+impl ListKeysTool {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(self.prefix.clone())
+    }
+}
+
 /// Function macro: tool with annotations
 #[mcp_tool(
     name = "web_search",
@@ -533,6 +552,45 @@ async fn web_search(query: String) -> McpResult<String> {
     Ok(format!("Results for: {}", query))
 }
 
+/// Function macro: annotation hints written as bare flags
+#[mcp_tool(
+    name = "purge_cache",
+    description = "Purge the cache",
+    destructive,
+    idempotent
+)]
+async fn purge_cache() -> McpResult<String> {
+    Ok("purged".to_string())
+}
+
+/// Derive macro: description translations
+#[derive(McpTool)]
+#[tool(
+    name = "convert",
+    description = "Convert units",
+    localized_description("fr" = "Convertir des unités", "pt-BR" = "Converter unidades")
+)]
+struct ConvertTool {
+    #[param(description = "Value to convert")]
+    value: f64,
+}
+
+impl ConvertTool {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<f64> {
+        Ok(self.value)
+    }
+}
+
+/// Function macro: description translations
+#[mcp_tool(
+    name = "translate_me",
+    description = "Say hello",
+    localized_description("fr" = "Dire bonjour")
+)]
+async fn translate_me() -> McpResult<String> {
+    Ok("hello".to_string())
+}
+
 /// Function macro: tool with no annotations
 #[mcp_tool(name = "echo", description = "Echo input")]
 async fn echo(text: String) -> McpResult<String> {
@@ -606,6 +664,51 @@ fn test_derive_partial_annotations() {
     assert!(annotations.title.is_none());
 }
 
+#[test]
+fn test_bare_annotation_flags() {
+    let tool = ListKeysTool {
+        prefix: String::new(),
+    };
+    let annotations = tool.annotations().expect("annotations should be Some");
+    assert_eq!(annotations.read_only_hint, Some(true));
+    assert_eq!(annotations.idempotent_hint, Some(true));
+    assert!(annotations.destructive_hint.is_none());
+    assert!(annotations.open_world_hint.is_none());
+
+    let tool_def = purge_cache().to_tool();
+    let json = serde_json::to_value(&tool_def).unwrap();
+    assert_eq!(
+        json["annotations"],
+        json!({"destructiveHint": true, "idempotentHint": true})
+    );
+}
+
+#[test]
+fn test_localized_descriptions() {
+    let tool = ConvertTool { value: 1.0 };
+    let translations = tool
+        .localized_descriptions()
+        .expect("translations should be Some");
+    assert_eq!(translations.len(), 2);
+    assert_eq!(translations["fr"], "Convertir des unités");
+    assert_eq!(translations["pt-BR"], "Converter unidades");
+    assert_eq!(tool.description(), Some("Convert units"));
+
+    let translations = translate_me()
+        .localized_descriptions()
+        .cloned()
+        .expect("translations should be Some");
+    assert_eq!(translations["fr"], "Dire bonjour");
+
+    assert!(
+        ListKeysTool {
+            prefix: String::new()
+        }
+        .localized_descriptions()
+        .is_none()
+    );
+}
+
 #[test]
 fn test_function_macro_annotations_in_to_tool() {
     let tool = web_search();
@@ -798,3 +901,121 @@ async fn test_server_tools_list_handler_includes_annotations() {
     assert!(plain_json.get("annotations").is_none());
     assert!(plain_json.get("title").is_none());
 }
+
+/// Nested parameter types using the framework's own `#[derive(JsonSchema)]`
+#[derive(serde::Deserialize, turul_mcp_derive::JsonSchema)]
+struct RetryPolicy {
+    attempts: u32,
+    backoff_ms: Option<u64>,
+}
+
+#[derive(serde::Deserialize, turul_mcp_derive::JsonSchema)]
+struct JobConfig {
+    name: String,
+    tags: Vec<String>,
+    retry: RetryPolicy,
+}
+
+/// Function macro: vector parameter
+#[mcp_tool(name = "sum_values", description = "Sum a list of numbers")]
+async fn sum_values(#[param(description = "Numbers to add")] values: Vec<f64>) -> McpResult<f64> {
+    Ok(values.iter().sum())
+}
+
+/// Function macro: nested struct parameter
+#[mcp_tool(name = "schedule_job", description = "Schedule a job")]
+async fn schedule_job(
+    #[param(description = "Job configuration")] config: JobConfig,
+    #[param(description = "Extra policies", optional)] fallbacks: Option<Vec<RetryPolicy>>,
+) -> McpResult<String> {
+    Ok(format!(
+        "{} [{}] x{}/{}ms +{}",
+        config.name,
+        config.tags.join(","),
+        config.retry.attempts,
+        config.retry.backoff_ms.unwrap_or(0),
+        fallbacks.map_or(0, |f| f.len())
+    ))
+}
+
+fn first_structured_value(
+    response: &turul_mcp_protocol::tools::CallToolResult,
+) -> serde_json::Value {
+    let structured = response
+        .structured_content
+        .as_ref()
+        .expect("structured content should be present");
+    structured
+        .as_object()
+        .unwrap()
+        .values()
+        .next()
+        .unwrap()
+        .clone()
+}
+
+#[tokio::test]
+async fn test_function_macro_vec_param() {
+    let tool = sum_values();
+    let schema = serde_json::to_value(tool.input_schema()).unwrap();
+    assert_eq!(
+        schema["properties"]["values"],
+        json!({"type": "array", "items": {"type": "number"}, "description": "Numbers to add"})
+    );
+
+    let response = tool
+        .call(json!({"values": [1.5, 2.0, 3.5]}), None)
+        .await
+        .unwrap();
+    assert_eq!(first_structured_value(&response), json!(7.0));
+
+    let err = tool
+        .call(json!({"values": [1.0, "two"]}), None)
+        .await
+        .expect_err("non-numeric items must be rejected");
+    assert!(err.to_string().contains("values"));
+}
+
+#[tokio::test]
+async fn test_function_macro_nested_struct_param() {
+    let tool = schedule_job();
+    let schema = serde_json::to_value(tool.input_schema()).unwrap();
+    let config = &schema["properties"]["config"];
+    assert_eq!(config["type"], "object");
+    assert_eq!(config["description"], "Job configuration");
+    assert_eq!(config["properties"]["name"]["type"], "string");
+    assert_eq!(
+        config["properties"]["tags"],
+        json!({"type": "array", "items": {"type": "string"}})
+    );
+    let retry = &config["properties"]["retry"];
+    assert_eq!(retry["type"], "object");
+    assert_eq!(retry["properties"]["attempts"]["type"], "integer");
+    assert_eq!(retry["required"], json!(["attempts"]));
+    assert_eq!(
+        schema["properties"]["fallbacks"]["items"]["properties"]["attempts"]["type"],
+        "integer"
+    );
+    assert_eq!(schema["required"], json!(["config"]));
+
+    let response = tool
+        .call(
+            json!({
+                "config": {"name": "nightly", "tags": ["db", "backup"], "retry": {"attempts": 3}},
+                "fallbacks": [{"attempts": 1, "backoff_ms": 500}]
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        first_structured_value(&response),
+        json!("nightly [db,backup] x3/0ms +1")
+    );
+
+    let err = tool
+        .call(json!({"config": {"name": "nightly"}}), None)
+        .await
+        .expect_err("incomplete nested struct must be rejected");
+    assert!(err.to_string().contains("config"));
+}
@@ -0,0 +1,143 @@
+//! Tests for #[derive(McpToolError)] / #[derive(IntoMcpError)] domain error → JSON-RPC error mapping
+
+use serde_json::json;
+use turul_mcp_derive::{IntoMcpError, McpTool, McpToolError};
+use turul_mcp_protocol::McpError;
+use turul_mcp_server::{McpResult, McpTool as McpToolTrait, SessionContext};
+
+#[derive(Debug, thiserror::Error, McpToolError)]
+enum InventoryError {
+    #[error("item {0} not found")]
+    #[mcp_error(code = -32050)]
+    NotFound(String),
+
+    #[error("out of stock: {sku}")]
+    #[mcp_error(code = -32051, message = "Only {available} units of {sku} left", data = available)]
+    OutOfStock { sku: String, available: u32 },
+
+    #[error("inventory backend unavailable")]
+    Unavailable,
+}
+
+fn reserve(sku: &str, quantity: u32) -> Result<u32, InventoryError> {
+    match sku {
+        "widget" if quantity <= 3 => Ok(3 - quantity),
+        "widget" => Err(InventoryError::OutOfStock {
+            sku: sku.to_string(),
+            available: 3,
+        }),
+        "offline" => Err(InventoryError::Unavailable),
+        _ => Err(InventoryError::NotFound(sku.to_string())),
+    }
+}
+
+#[derive(McpTool, Default)]
+#[tool(name = "reserve", description = "Reserve stock for an item")]
+struct ReserveTool {
+    #[param(description = "Item SKU")]
+    sku: String,
+    #[param(description = "Quantity to reserve")]
+    quantity: u32,
+}
+
+impl ReserveTool {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<u32> {
+        let remaining = reserve(&self.sku, self.quantity)?;
+        Ok(remaining)
+    }
+}
+
+#[tokio::test]
+async fn test_propagated_variant_yields_declared_code_and_message() {
+    let tool = ReserveTool::default();
+    let err = tool
+        .call(json!({"sku": "gadget", "quantity": 1}), None)
+        .await
+        .expect_err("unknown SKU should fail");
+
+    let obj = err.to_error_object();
+    assert_eq!(obj.code, -32050);
+    assert_eq!(obj.message, "item gadget not found");
+    assert!(obj.data.is_none());
+}
+
+#[tokio::test]
+async fn test_variant_message_template_and_data() {
+    let tool = ReserveTool::default();
+    let err = tool
+        .call(json!({"sku": "widget", "quantity": 5}), None)
+        .await
+        .expect_err("over-reservation should fail");
+
+    match &err {
+        McpError::JsonRpcError {
+            code,
+            message,
+            data,
+        } => {
+            assert_eq!(*code, -32051);
+            assert_eq!(message, "Only 3 units of widget left");
+            assert_eq!(data, &Some(json!(3)));
+        }
+        other => panic!("Expected JsonRpcError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unmapped_variant_falls_back_to_tool_execution_error() {
+    let err: McpError = InventoryError::Unavailable.into();
+    assert!(matches!(
+        err,
+        McpError::ToolExecutionError(ref msg) if msg == "inventory backend unavailable"
+    ));
+    assert_eq!(err.to_error_object().code, -32010);
+}
+
+#[derive(Debug, thiserror::Error, IntoMcpError)]
+enum PaymentError {
+    #[error("card declined: {reason}")]
+    #[mcp(code = -32010)]
+    Declined { reason: String },
+
+    #[error("payment provider unavailable")]
+    ProviderDown,
+}
+
+#[derive(Debug, thiserror::Error, IntoMcpError)]
+#[error("spending limit of {limit} exceeded")]
+#[mcp(code = -32011, data = limit)]
+struct LimitExceeded {
+    limit: u64,
+}
+
+#[test]
+fn test_into_mcp_error_enum_variant_code() {
+    let err: McpError = PaymentError::Declined {
+        reason: "expired".to_string(),
+    }
+    .into();
+    let obj = err.to_error_object();
+    assert_eq!(obj.code, -32010);
+    assert_eq!(obj.message, "card declined: expired");
+
+    let err: McpError = PaymentError::ProviderDown.into();
+    assert!(matches!(err, McpError::ToolExecutionError(_)));
+}
+
+#[test]
+fn test_into_mcp_error_struct_code_and_data() {
+    let err: McpError = LimitExceeded { limit: 500 }.into();
+    let obj = err.to_error_object();
+    assert_eq!(obj.code, -32011);
+    assert_eq!(obj.message, "spending limit of 500 exceeded");
+    assert_eq!(obj.data, Some(json!(500)));
+}
+
+#[test]
+fn test_mcp_tool_error_derive_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("ui/tool_error_pass.rs");
+    t.compile_fail("ui/tool_error_missing_code.rs");
+    t.pass("ui/into_mcp_error_pass.rs");
+    t.compile_fail("ui/into_mcp_error_missing_code.rs");
+}
@@ -0,0 +1,231 @@
+//! E2E test for bidirectional (duplex) streaming tools over Streamable HTTP.
+//!
+//! Drives an echo-REPL tool through several round-trips within a single
+//! `tools/call`: the call stays pending while the client sends follow-up
+//! `tools/duplex/input` requests referencing the call id, and completes after a
+//! `tools/duplex/close` request.
+
+use async_trait::async_trait;
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use turul_mcp_builders::prelude::*;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_protocol::tools::{CallToolResult, ToolAnnotations, ToolResult, ToolSchema};
+use turul_mcp_server::{DuplexChannel, McpDuplexTool, McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+struct EchoRepl {
+    input_schema: ToolSchema,
+}
+
+impl HasBaseMetadata for EchoRepl {
+    fn name(&self) -> &str {
+        "echo_repl"
+    }
+}
+
+impl HasDescription for EchoRepl {
+    fn description(&self) -> Option<&str> {
+        Some("Echoes every input until the client closes the call")
+    }
+}
+
+impl HasInputSchema for EchoRepl {
+    fn input_schema(&self) -> &ToolSchema {
+        &self.input_schema
+    }
+}
+
+impl HasOutputSchema for EchoRepl {}
+
+impl HasAnnotations for EchoRepl {
+    fn annotations(&self) -> Option<&ToolAnnotations> {
+        None
+    }
+}
+
+impl HasToolMeta for EchoRepl {
+    fn tool_meta(&self) -> Option<&HashMap<String, Value>> {
+        None
+    }
+}
+
+impl HasIcons for EchoRepl {}
+impl HasExecution for EchoRepl {}
+
+#[async_trait]
+impl McpDuplexTool for EchoRepl {
+    async fn run(
+        &self,
+        _args: Value,
+        mut channel: DuplexChannel,
+        _session: Option<SessionContext>,
+    ) -> McpResult<CallToolResult> {
+        channel.send(json!({ "prompt": "> " }));
+
+        let mut turns = 0;
+        while let Some(input) = channel.recv().await {
+            turns += 1;
+            channel.send(json!({ "echo": input, "turn": turns }));
+        }
+
+        Ok(CallToolResult::success(vec![ToolResult::text(format!(
+            "{} turns",
+            turns
+        ))]))
+    }
+}
+
+async fn start_duplex_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("duplex-test")
+            .version("1.0.0")
+            .duplex_tool(EchoRepl {
+                input_schema: ToolSchema::object(),
+            })
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+async fn post(client: &reqwest::Client, url: &str, session_id: Option<&str>, body: Value) -> Value {
+    let mut request = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25");
+    if let Some(session_id) = session_id {
+        request = request.header("Mcp-Session-Id", session_id);
+    }
+    let response = request.json(&body).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_echo_repl_round_trips_within_one_call() {
+    let server_url = start_duplex_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server_url).await;
+
+    // Open the call; the POST stays pending until the tool finishes
+    let call = {
+        let client = client.clone();
+        let server_url = server_url.clone();
+        let session_id = session_id.clone();
+        tokio::spawn(async move {
+            post(
+                &client,
+                &server_url,
+                Some(&session_id),
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": "tools/call",
+                    "id": 2,
+                    "params": {
+                        "name": "echo_repl",
+                        "arguments": {},
+                        "_meta": { "duplexCallId": "repl-1" }
+                    }
+                }),
+            )
+            .await
+        })
+    };
+
+    // Collect the banner once the call is registered
+    let mut banner = None;
+    for _ in 0..50 {
+        let body = post(
+            &client,
+            &server_url,
+            Some(&session_id),
+            json!({
+                "jsonrpc": "2.0",
+                "method": "tools/duplex/input",
+                "id": 10,
+                "params": { "callId": "repl-1" }
+            }),
+        )
+        .await;
+        if body.get("result").is_some() {
+            banner = Some(body);
+            break;
+        }
+        sleep(Duration::from_millis(20)).await;
+    }
+    let banner = banner.expect("duplex call should open");
+    assert_eq!(banner["result"]["outputs"], json!([{ "prompt": "> " }]));
+    assert_eq!(banner["result"]["done"], false);
+
+    for (turn, line) in ["alpha", "beta", "gamma"].iter().enumerate() {
+        let body = post(
+            &client,
+            &server_url,
+            Some(&session_id),
+            json!({
+                "jsonrpc": "2.0",
+                "method": "tools/duplex/input",
+                "id": 11 + turn,
+                "params": { "callId": "repl-1", "input": line }
+            }),
+        )
+        .await;
+        assert_eq!(
+            body["result"]["outputs"],
+            json!([{ "echo": line, "turn": turn + 1 }]),
+            "unexpected outputs for turn {}: {}",
+            turn + 1,
+            body
+        );
+        assert_eq!(body["result"]["done"], false);
+    }
+
+    let closed = post(
+        &client,
+        &server_url,
+        Some(&session_id),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "tools/duplex/close",
+            "id": 20,
+            "params": { "callId": "repl-1" }
+        }),
+    )
+    .await;
+    assert_eq!(closed["result"]["outputs"], json!([]));
+    assert_eq!(closed["result"]["done"], true);
+
+    let result = tokio::time::timeout(Duration::from_secs(5), call)
+        .await
+        .expect("tools/call should complete after close")
+        .unwrap();
+    assert_eq!(result["id"], 2);
+    assert_eq!(result["result"]["content"][0]["text"], "3 turns");
+}
+
+#[tokio::test]
+async fn test_duplex_input_for_unknown_call_is_rejected() {
+    let server_url = start_duplex_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server_url).await;
+
+    let body = post(
+        &client,
+        &server_url,
+        Some(&session_id),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "tools/duplex/input",
+            "id": 3,
+            "params": { "callId": "missing", "input": "hi" }
+        }),
+    )
+    .await;
+    assert_eq!(body["error"]["code"], -32602);
+}
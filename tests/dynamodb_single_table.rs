@@ -0,0 +1,144 @@
+//! Session and task storage sharing one DynamoDB table.
+//!
+//! Requires a local DynamoDB (e.g. `docker run -p 8000:8000 amazon/dynamodb-local`):
+//!
+//! ```bash
+//! AWS_ENDPOINT_URL=http://localhost:8000 AWS_ACCESS_KEY_ID=local AWS_SECRET_ACCESS_KEY=local \
+//!     cargo test --test dynamodb_single_table -- --ignored
+//! ```
+
+use chrono::Utc;
+use serde_json::json;
+use turul_mcp_protocol::{ServerCapabilities, TaskStatus};
+use turul_mcp_session_storage::{DynamoDbConfig, DynamoDbSessionStorage, SessionStorage, SseEvent};
+use turul_mcp_task_storage::{DynamoDbTaskConfig, DynamoDbTaskStorage, TaskRecord, TaskStorage};
+
+fn make_task(task_id: &str, session_id: &str) -> TaskRecord {
+    let now = Utc::now().to_rfc3339();
+    TaskRecord {
+        task_id: task_id.to_string(),
+        session_id: Some(session_id.to_string()),
+        status: TaskStatus::Working,
+        status_message: None,
+        created_at: now.clone(),
+        last_updated_at: now,
+        ttl: None,
+        poll_interval: None,
+        original_method: "tools/call".to_string(),
+        original_params: None,
+        result: None,
+        meta: None,
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires local DynamoDB (AWS_ENDPOINT_URL)"]
+async fn test_sessions_events_and_tasks_share_one_table() {
+    let table_name = format!("mcp-single-table-{}", uuid::Uuid::now_v7());
+
+    let sessions = DynamoDbSessionStorage::with_config(DynamoDbConfig {
+        table_name: table_name.clone(),
+        verify_tables: true,
+        create_tables: true,
+        single_table: true,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let tasks = DynamoDbTaskStorage::with_config(DynamoDbTaskConfig {
+        table_name: table_name.clone(),
+        verify_tables: true,
+        single_table: true,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    let session_a = sessions
+        .create_session(ServerCapabilities::default())
+        .await
+        .unwrap()
+        .session_id;
+    let session_b = sessions
+        .create_session(ServerCapabilities::default())
+        .await
+        .unwrap()
+        .session_id;
+
+    for n in 0..12 {
+        let event = SseEvent::new("message".to_string(), json!({ "n": n }));
+        let stored = sessions.store_event(&session_a, event).await.unwrap();
+        assert_eq!(stored.id, n + 1);
+    }
+
+    for (task_id, session_id) in [
+        ("task-1", &session_a),
+        ("task-2", &session_a),
+        ("task-3", &session_b),
+    ] {
+        tasks
+            .create_task(make_task(task_id, session_id))
+            .await
+            .unwrap();
+    }
+
+    // Queries stay within their own entity type
+    let mut session_ids = sessions.list_sessions().await.unwrap();
+    session_ids.sort();
+    let mut expected = vec![session_a.clone(), session_b.clone()];
+    expected.sort();
+    assert_eq!(session_ids, expected);
+    assert_eq!(sessions.session_count().await.unwrap(), 2);
+    assert_eq!(tasks.task_count().await.unwrap(), 3);
+
+    let page = tasks.list_tasks(None, None, None).await.unwrap();
+    assert_eq!(page.tasks.len(), 3);
+
+    let page = tasks
+        .list_tasks_for_session(&session_a, None, None, None)
+        .await
+        .unwrap();
+    let ids: Vec<_> = page.tasks.iter().map(|t| t.task_id.as_str()).collect();
+    assert_eq!(ids, ["task-1", "task-2"]);
+
+    // Events sort numerically past single digits
+    let after: Vec<u64> = sessions
+        .get_events_after(&session_a, 9)
+        .await
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(after, [10, 11, 12]);
+    let recent: Vec<u64> = sessions
+        .get_recent_events(&session_a, 2)
+        .await
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(recent, [11, 12]);
+
+    // A session ID reused as a task ID never collides
+    tasks
+        .create_task(make_task(&session_b, &session_b))
+        .await
+        .unwrap();
+    assert!(sessions.get_session(&session_b).await.unwrap().is_some());
+    assert!(tasks.get_task(&session_b).await.unwrap().is_some());
+
+    tasks
+        .update_task_status(&session_b, TaskStatus::Completed, None)
+        .await
+        .unwrap();
+    let working = tasks
+        .list_tasks(None, None, Some(&[TaskStatus::Working]))
+        .await
+        .unwrap();
+    assert_eq!(working.tasks.len(), 3);
+
+    assert!(tasks.delete_task(&session_b).await.unwrap());
+    assert!(sessions.get_session(&session_b).await.unwrap().is_some());
+
+    sessions.delete_tables().await.unwrap();
+}
@@ -761,6 +761,17 @@ async fn test_broadcast_storage_enumeration_failure_propagates() {
         ) -> std::result::Result<(), Self::Error> {
             self.inner.set_session_state(id, key, value).await
         }
+        async fn set_session_state_versioned(
+            &self,
+            id: &str,
+            key: &str,
+            value: serde_json::Value,
+            expected_version: u64,
+        ) -> std::result::Result<u64, Self::Error> {
+            self.inner
+                .set_session_state_versioned(id, key, value, expected_version)
+                .await
+        }
         async fn get_session_state(
             &self,
             id: &str,
@@ -0,0 +1,180 @@
+//! E2E tests for graceful shutdown (`McpServer::run_with_shutdown`).
+//!
+//! Verifies that after the shutdown signal the listener stops accepting
+//! connections, an in-flight tool call still completes, open GET SSE streams
+//! receive a final event and end, and draining gives up after the configured
+//! grace period.
+
+use mcp_e2e_shared::{build_on_free_port, open_session, wait_for_server};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, timeout};
+use tracing_test::traced_test;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::McpServer;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "slow_echo", description = "Echo text after a delay")]
+async fn slow_echo(
+    #[param(description = "Text to echo")] text: String,
+    #[param(description = "Delay in milliseconds")] delay_ms: u64,
+) -> McpResult<String> {
+    sleep(Duration::from_millis(delay_ms)).await;
+    Ok(text)
+}
+
+struct RunningServer {
+    url: String,
+    shutdown: oneshot::Sender<()>,
+    handle: JoinHandle<McpResult<()>>,
+}
+
+async fn start_server(grace: Duration) -> RunningServer {
+    let (server, url) = build_on_free_port(
+        McpServer::builder()
+            .name("graceful-shutdown-test")
+            .version("1.0.0")
+            .tool_fn(slow_echo)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+            .sse(true)
+            .shutdown_grace_period(grace),
+    )
+    .await;
+
+    let (shutdown, signal) = oneshot::channel::<()>();
+    let handle = tokio::spawn(async move {
+        server
+            .run_with_shutdown(async {
+                signal.await.ok();
+            })
+            .await
+    });
+
+    wait_for_server(&url).await;
+    RunningServer {
+        url,
+        shutdown,
+        handle,
+    }
+}
+
+fn spawn_slow_call(
+    client: &reqwest::Client,
+    server_url: &str,
+    session_id: &str,
+    delay_ms: u64,
+) -> JoinHandle<reqwest::Result<Value>> {
+    let request = client
+        .post(server_url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", session_id)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 2,
+            "params": {
+                "name": "slow_echo",
+                "arguments": { "text": "finished", "delay_ms": delay_ms }
+            }
+        }));
+    tokio::spawn(async move { request.send().await?.json().await })
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_in_flight_call_completes_after_shutdown_signal() {
+    let server = start_server(Duration::from_secs(5)).await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server.url).await;
+
+    let call = spawn_slow_call(&client, &server.url, &session_id, 500);
+    sleep(Duration::from_millis(150)).await;
+    server.shutdown.send(()).unwrap();
+
+    let body = call.await.unwrap().expect("in-flight call must complete");
+    assert!(
+        body.to_string().contains("finished"),
+        "unexpected body: {}",
+        body
+    );
+
+    timeout(Duration::from_secs(5), server.handle)
+        .await
+        .expect("server should stop once drained")
+        .unwrap()
+        .unwrap();
+    assert!(logs_contain("draining in-flight requests"));
+    assert!(logs_contain("Drain complete"));
+
+    // The listener is gone
+    let refused = reqwest::Client::new()
+        .post(&server.url)
+        .json(&json!({"jsonrpc": "2.0", "method": "ping", "id": 3}))
+        .send()
+        .await;
+    assert!(refused.is_err(), "no new connections after shutdown");
+}
+
+#[tokio::test]
+async fn test_sse_stream_receives_final_event() {
+    let server = start_server(Duration::from_secs(5)).await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server.url).await;
+
+    let mut stream = client
+        .get(&server.url)
+        .header("Accept", "text/event-stream")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(stream.status(), 200);
+
+    server.shutdown.send(()).unwrap();
+
+    let received = timeout(Duration::from_secs(5), async {
+        let mut received = String::new();
+        while let Some(chunk) = stream.chunk().await.unwrap() {
+            received.push_str(&String::from_utf8_lossy(&chunk));
+        }
+        received
+    })
+    .await
+    .expect("SSE stream should end after shutdown");
+
+    assert!(received.contains("Server shutting down"), "{}", received);
+    assert!(!received.contains("id: 0"), "{}", received);
+
+    timeout(Duration::from_secs(5), server.handle)
+        .await
+        .expect("server should stop once drained")
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_drain_gives_up_after_grace_period() {
+    let server = start_server(Duration::from_millis(200)).await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server.url).await;
+
+    let call = spawn_slow_call(&client, &server.url, &session_id, 10_000);
+    sleep(Duration::from_millis(150)).await;
+    server.shutdown.send(()).unwrap();
+
+    timeout(Duration::from_secs(2), server.handle)
+        .await
+        .expect("server must not wait past the grace period")
+        .unwrap()
+        .unwrap();
+    assert!(logs_contain("Drain timed out"));
+    call.abort();
+}
@@ -0,0 +1,207 @@
+//! E2E tests for the per-request `Authenticator` hook on the HTTP transport.
+
+use async_trait::async_trait;
+use hyper::HeaderMap;
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use turul_http_mcp_server::middleware::extract_bearer_token;
+use turul_http_mcp_server::{AuthError, Authenticator, Identity};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+const TOKEN: &str = "gateway-issued-token";
+
+/// Accepts exactly one bearer token
+struct OneToken;
+
+#[async_trait]
+impl Authenticator for OneToken {
+    async fn authenticate(&self, headers: &HeaderMap) -> Result<Identity, AuthError> {
+        let token = headers
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(extract_bearer_token)
+            .ok_or(AuthError::MissingCredentials)?;
+        if token == TOKEN {
+            Ok(Identity::new("user-42").with_claim("scope", json!("tools:call")))
+        } else {
+            Err(AuthError::InvalidCredentials("unknown token".to_string()))
+        }
+    }
+}
+
+#[mcp_tool(name = "whoami", description = "Report the authenticated caller")]
+async fn whoami(session: Option<SessionContext>) -> McpResult<Value> {
+    let identity = session.and_then(|session| session.identity());
+    Ok(json!({
+        "subject": identity.as_ref().map(|identity| identity.subject.clone()),
+        "scope": identity.as_ref().and_then(|identity| identity.claim("scope").cloned()),
+    }))
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("auth-test")
+            .version("1.0.0")
+            .tool_fn(whoami)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+            .authenticator(OneToken),
+    )
+    .await
+}
+
+async fn post(
+    url: &str,
+    token: Option<&str>,
+    session_id: Option<&str>,
+    body: Value,
+) -> reqwest::Response {
+    let mut request = reqwest::Client::new()
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25");
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    if let Some(session_id) = session_id {
+        request = request.header("Mcp-Session-Id", session_id);
+    }
+    request.json(&body).send().await.unwrap()
+}
+
+fn initialize_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "initialize",
+        "id": 1,
+        "params": {
+            "protocolVersion": "2025-11-25",
+            "capabilities": {},
+            "clientInfo": { "name": "auth-client", "version": "1.0.0" }
+        }
+    })
+}
+
+async fn assert_unauthenticated(response: reqwest::Response) {
+    assert_eq!(response.status(), 401);
+    assert!(
+        response.headers()["WWW-Authenticate"]
+            .to_str()
+            .unwrap()
+            .starts_with("Bearer")
+    );
+    assert!(response.headers().get("Mcp-Session-Id").is_none());
+    let error: Value = response.json().await.unwrap();
+    assert_eq!(error["jsonrpc"], "2.0");
+    assert_eq!(error["error"]["code"], -32001);
+}
+
+#[tokio::test]
+async fn test_valid_token_reaches_tools_as_identity() {
+    let url = start_server().await;
+
+    let response = post(&url, Some(TOKEN), None, initialize_request()).await;
+    assert_eq!(response.status(), 200);
+    let session_id = response.headers()["Mcp-Session-Id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    post(
+        &url,
+        Some(TOKEN),
+        Some(&session_id),
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )
+    .await;
+
+    let response = post(
+        &url,
+        Some(TOKEN),
+        Some(&session_id),
+        json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 2,
+            "params": { "name": "whoami", "arguments": {} }
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    let frame: Value = response.json().await.unwrap();
+    let caller = &frame["result"]["structuredContent"]["result"];
+    assert_eq!(caller["subject"], "user-42");
+    assert_eq!(caller["scope"], "tools:call");
+}
+
+#[tokio::test]
+async fn test_missing_or_unknown_token_is_rejected_with_401() {
+    let url = start_server().await;
+
+    assert_unauthenticated(post(&url, None, None, initialize_request()).await).await;
+    assert_unauthenticated(post(&url, Some("forged"), None, initialize_request()).await).await;
+
+    // A session opened with the right token does not let later requests skip it
+    let response = post(&url, Some(TOKEN), None, initialize_request()).await;
+    let session_id = response.headers()["Mcp-Session-Id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let response = post(
+        &url,
+        Some("forged"),
+        Some(&session_id),
+        json!({ "jsonrpc": "2.0", "method": "tools/list", "id": 2 }),
+    )
+    .await;
+    assert_unauthenticated(response).await;
+}
+
+#[tokio::test]
+async fn test_get_and_delete_without_valid_token_are_rejected_with_401() {
+    let url = start_server().await;
+
+    let response = post(&url, Some(TOKEN), None, initialize_request()).await;
+    let session_id = response.headers()["Mcp-Session-Id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let client = reqwest::Client::new();
+
+    // Knowing the session ID is not enough to open the notification stream
+    let response = client
+        .get(&url)
+        .header("Accept", "text/event-stream")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .send()
+        .await
+        .unwrap();
+    assert_unauthenticated(response).await;
+
+    // ...or to terminate the session
+    for token in [None, Some("forged")] {
+        let mut request = client
+            .delete(&url)
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .header("Mcp-Session-Id", &session_id);
+        if let Some(token) = token {
+            request = request.bearer_auth(token);
+        }
+        assert_unauthenticated(request.send().await.unwrap()).await;
+    }
+
+    // The session survived and still answers the rightful caller
+    let response = post(
+        &url,
+        Some(TOKEN),
+        Some(&session_id),
+        json!({ "jsonrpc": "2.0", "method": "tools/list", "id": 2 }),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+}
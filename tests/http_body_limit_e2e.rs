@@ -0,0 +1,128 @@
+//! E2E tests for the request body limit on the streamable HTTP POST path.
+
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::McpServer;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+const LIMIT: usize = 2048;
+
+#[mcp_tool(name = "echo", description = "Echo the input back")]
+async fn echo(#[param(description = "Text to echo")] text: String) -> McpResult<String> {
+    Ok(text)
+}
+
+struct Client {
+    client: reqwest::Client,
+    url: String,
+    session_id: String,
+}
+
+impl Client {
+    async fn connect() -> Self {
+        let url = spawn_server(
+            McpServer::builder()
+                .name("body-limit-test")
+                .version("1.0.0")
+                .tool_fn(echo)
+                .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+                .max_request_body_bytes(LIMIT),
+        )
+        .await;
+
+        let mut client = Self {
+            client: reqwest::Client::new(),
+            url,
+            session_id: String::new(),
+        };
+        let response = client
+            .post(
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": "initialize",
+                    "id": 1,
+                    "params": {
+                        "protocolVersion": "2025-11-25",
+                        "capabilities": {},
+                        "clientInfo": { "name": "body-limit-client", "version": "1.0.0" }
+                    }
+                })
+                .to_string(),
+            )
+            .await;
+        client.session_id = response
+            .headers()
+            .get("Mcp-Session-Id")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        client
+            .post(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }).to_string())
+            .await;
+        client
+    }
+
+    async fn post(&self, body: String) -> reqwest::Response {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25");
+        if !self.session_id.is_empty() {
+            request = request.header("Mcp-Session-Id", &self.session_id);
+        }
+        request.body(body).send().await.unwrap()
+    }
+}
+
+/// A `tools/call` request for `echo` padded to exactly `size` bytes
+fn echo_request(size: usize) -> String {
+    let request = |text: &str| {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 2,
+            "params": { "name": "echo", "arguments": { "text": text } }
+        })
+        .to_string()
+    };
+    let padding = size - request("").len();
+    let body = request(&"x".repeat(padding));
+    assert_eq!(body.len(), size);
+    body
+}
+
+#[tokio::test]
+async fn test_body_over_limit_is_rejected_with_json_rpc_error() {
+    let client = Client::connect().await;
+
+    let response = client.post(echo_request(LIMIT + 1)).await;
+
+    assert_eq!(response.status(), 413);
+    let error: Value = response.json().await.unwrap();
+    assert_eq!(error["jsonrpc"], "2.0");
+    assert_eq!(error["id"], Value::Null);
+    assert_eq!(error["error"]["code"], -32600);
+    assert_eq!(error["error"]["data"]["maxBytes"], LIMIT);
+}
+
+#[tokio::test]
+async fn test_body_at_limit_succeeds() {
+    let client = Client::connect().await;
+
+    let body = echo_request(LIMIT);
+    let response = client.post(body).await;
+
+    assert_eq!(response.status(), 200);
+    let frame: Value = response.json().await.unwrap();
+    assert_eq!(frame["id"], 2);
+    let text = frame["result"]["structuredContent"]["result"]
+        .as_str()
+        .unwrap();
+    assert!(text.len() > LIMIT / 2 && text.chars().all(|c| c == 'x'));
+}
@@ -0,0 +1,214 @@
+//! E2E tests for HTTP response compression negotiated via `Accept-Encoding`.
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use std::io::Read;
+use std::sync::Arc;
+use turul_http_mcp_server::ContentEncoding;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, McpServerBuilder};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "repeat", description = "Repeat a word many times")]
+async fn repeat(
+    #[param(description = "Word to repeat")] word: String,
+    #[param(description = "Number of repetitions")] count: u32,
+) -> McpResult<String> {
+    Ok(vec![word; count as usize].join(" "))
+}
+
+struct Client {
+    client: reqwest::Client,
+    url: String,
+    session_id: String,
+}
+
+impl Client {
+    async fn connect(configure: impl FnOnce(McpServerBuilder) -> McpServerBuilder) -> Self {
+        let url = spawn_server(configure(
+            McpServer::builder()
+                .name("compression-test")
+                .version("1.0.0")
+                .tool_fn(repeat)
+                .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+        ))
+        .await;
+
+        let client = reqwest::Client::new();
+        let response = client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "method": "initialize",
+                "id": 1,
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "capabilities": {},
+                    "clientInfo": { "name": "compression-client", "version": "1.0.0" }
+                }
+            }))
+            .send()
+            .await
+            .unwrap();
+        let session_id = response
+            .headers()
+            .get("Mcp-Session-Id")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let client = Self {
+            client,
+            url,
+            session_id,
+        };
+        client
+            .post(
+                None,
+                json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+            )
+            .await;
+        client
+    }
+
+    async fn post(&self, accept_encoding: Option<&str>, body: Value) -> reqwest::Response {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .header("Mcp-Session-Id", &self.session_id);
+        if let Some(accept_encoding) = accept_encoding {
+            request = request.header("Accept-Encoding", accept_encoding);
+        }
+        request.json(&body).send().await.unwrap()
+    }
+
+    async fn call_repeat(&self, accept_encoding: Option<&str>, count: u32) -> reqwest::Response {
+        self.post(
+            accept_encoding,
+            json!({
+                "jsonrpc": "2.0",
+                "method": "tools/call",
+                "id": 2,
+                "params": { "name": "repeat", "arguments": { "word": "hello", "count": count } }
+            }),
+        )
+        .await
+    }
+}
+
+fn content_encoding(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get("Content-Encoding")
+        .map(|value| value.to_str().unwrap().to_string())
+}
+
+fn repeated_text(frame: &Value) -> &str {
+    frame["result"]["structuredContent"]["result"]
+        .as_str()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_gzip_response_decompresses_to_json() {
+    let client = Client::connect(|builder| builder).await;
+
+    let response = client.call_repeat(Some("gzip, deflate"), 500).await;
+    assert_eq!(content_encoding(&response).as_deref(), Some("gzip"));
+
+    let compressed = response.bytes().await.unwrap();
+    let mut decoded = String::new();
+    GzDecoder::new(&compressed[..])
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert!(compressed.len() < decoded.len());
+
+    let frame: Value = serde_json::from_str(&decoded).unwrap();
+    assert_eq!(frame["id"], 2);
+    assert_eq!(repeated_text(&frame), vec!["hello"; 500].join(" "));
+}
+
+#[tokio::test]
+async fn test_deflate_used_when_gzip_not_accepted() {
+    let client = Client::connect(|builder| builder).await;
+
+    let response = client.call_repeat(Some("gzip;q=0, deflate"), 500).await;
+    assert_eq!(content_encoding(&response).as_deref(), Some("deflate"));
+
+    let compressed = response.bytes().await.unwrap();
+    let mut decoded = String::new();
+    ZlibDecoder::new(&compressed[..])
+        .read_to_string(&mut decoded)
+        .unwrap();
+    let frame: Value = serde_json::from_str(&decoded).unwrap();
+    assert_eq!(repeated_text(&frame), vec!["hello"; 500].join(" "));
+}
+
+#[tokio::test]
+async fn test_small_or_unrequested_responses_are_not_compressed() {
+    let client = Client::connect(|builder| builder).await;
+
+    let small = client.call_repeat(Some("gzip"), 1).await;
+    assert_eq!(content_encoding(&small), None);
+    let frame: Value = small.json().await.unwrap();
+    assert_eq!(repeated_text(&frame), "hello");
+
+    let plain = client.call_repeat(None, 500).await;
+    assert_eq!(content_encoding(&plain), None);
+    let frame: Value = plain.json().await.unwrap();
+    assert_eq!(repeated_text(&frame), vec!["hello"; 500].join(" "));
+}
+
+#[tokio::test]
+async fn test_threshold_and_algorithms_are_configurable() {
+    let client = Client::connect(|builder| {
+        builder
+            .compression(vec![ContentEncoding::Deflate])
+            .compression_threshold(16)
+    })
+    .await;
+
+    let response = client.call_repeat(Some("gzip, deflate"), 5).await;
+    assert_eq!(content_encoding(&response).as_deref(), Some("deflate"));
+
+    let only_gzip = client.call_repeat(Some("gzip"), 500).await;
+    assert_eq!(content_encoding(&only_gzip), None);
+
+    let disabled = Client::connect(|builder| builder.compression(Vec::new())).await;
+    let response = disabled.call_repeat(Some("gzip"), 500).await;
+    assert_eq!(content_encoding(&response), None);
+}
+
+#[tokio::test]
+async fn test_sse_stream_is_not_compressed() {
+    let client = Client::connect(|builder| builder.compression_threshold(0)).await;
+
+    let response = client
+        .client
+        .get(&client.url)
+        .header("Accept", "text/event-stream")
+        .header("Accept-Encoding", "gzip")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &client.session_id)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), 200);
+    assert!(
+        response.headers()["Content-Type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/event-stream")
+    );
+    assert_eq!(content_encoding(&response), None);
+}
@@ -0,0 +1,148 @@
+//! E2E tests for a restricted CORS policy on the HTTP transport.
+
+use mcp_e2e_shared::spawn_server;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use turul_http_mcp_server::CorsConfig;
+use turul_mcp_server::McpServer;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+const ALLOWED: &str = "https://app.example.com";
+const DISALLOWED: &str = "https://evil.example.com";
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("cors-test")
+            .version("1.0.0")
+            .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+            .cors_config(
+                CorsConfig::allow_origins(vec![ALLOWED.to_string()])
+                    .allow_credentials(true)
+                    .max_age(Duration::from_secs(600)),
+            ),
+    )
+    .await
+}
+
+async fn initialize(client: &reqwest::Client, url: &str, origin: &str) -> reqwest::Response {
+    client
+        .post(url)
+        .header("Origin", origin)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "initialize",
+            "id": 1,
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "cors-client", "version": "1.0.0" }
+            }
+        }))
+        .send()
+        .await
+        .unwrap()
+}
+
+fn header<'a>(response: &'a reqwest::Response, name: &str) -> Option<&'a str> {
+    response
+        .headers()
+        .get(name)
+        .map(|value| value.to_str().unwrap())
+}
+
+#[tokio::test]
+async fn test_allowed_origin_is_echoed_with_credentials() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+
+    let response = initialize(&client, &url, ALLOWED).await;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        header(&response, "Access-Control-Allow-Origin"),
+        Some(ALLOWED)
+    );
+    assert_eq!(
+        header(&response, "Access-Control-Allow-Credentials"),
+        Some("true")
+    );
+    assert_eq!(
+        header(&response, "Access-Control-Expose-Headers"),
+        Some("Mcp-Session-Id")
+    );
+}
+
+#[tokio::test]
+async fn test_disallowed_origin_gets_no_cors_headers() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+
+    let response = initialize(&client, &url, DISALLOWED).await;
+    let session_id = header(&response, "Mcp-Session-Id").unwrap().to_string();
+    assert!(
+        response
+            .headers()
+            .keys()
+            .all(|name| !name.as_str().starts_with("access-control-")),
+        "unexpected CORS headers: {:?}",
+        response.headers()
+    );
+
+    // SSE responses set their own wildcard origin; the policy removes it
+    let stream = client
+        .get(&url)
+        .header("Origin", DISALLOWED)
+        .header("Accept", "text/event-stream")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(stream.status(), 200);
+    assert_eq!(header(&stream, "Access-Control-Allow-Origin"), None);
+}
+
+#[tokio::test]
+async fn test_preflight_respects_policy() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+
+    let preflight = |origin: &'static str| {
+        client
+            .request(reqwest::Method::OPTIONS, &url)
+            .header("Origin", origin)
+            .header("Access-Control-Request-Method", "POST")
+            .header(
+                "Access-Control-Request-Headers",
+                "content-type, mcp-session-id",
+            )
+            .send()
+    };
+
+    let allowed = preflight(ALLOWED).await.unwrap();
+    assert!(allowed.status().is_success());
+    assert_eq!(
+        header(&allowed, "Access-Control-Allow-Origin"),
+        Some(ALLOWED)
+    );
+    assert!(
+        header(&allowed, "Access-Control-Allow-Methods")
+            .unwrap()
+            .contains("POST")
+    );
+    assert!(
+        header(&allowed, "Access-Control-Allow-Headers")
+            .unwrap()
+            .contains("Mcp-Session-Id")
+    );
+    assert_eq!(header(&allowed, "Access-Control-Max-Age"), Some("600"));
+
+    let disallowed = preflight(DISALLOWED).await.unwrap();
+    assert_eq!(header(&disallowed, "Access-Control-Allow-Origin"), None);
+    assert_eq!(header(&disallowed, "Access-Control-Allow-Methods"), None);
+}
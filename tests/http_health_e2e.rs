@@ -0,0 +1,188 @@
+//! E2E tests for the opt-in liveness (`/healthz`) and readiness (`/readyz`) probes.
+
+use async_trait::async_trait;
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use turul_mcp_protocol::ServerCapabilities;
+use turul_mcp_server::{McpServer, McpServerBuilder};
+use turul_mcp_session_storage::{
+    InMemorySessionStorage, SessionInfo, SessionStorage, SessionStorageError, SseEvent,
+};
+
+/// In-memory storage whose ping fails while `down` is set
+struct FlakyStorage {
+    inner: InMemorySessionStorage,
+    down: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl SessionStorage for FlakyStorage {
+    type Error = SessionStorageError;
+
+    fn backend_name(&self) -> &'static str {
+        "flaky-test"
+    }
+    async fn create_session(&self, caps: ServerCapabilities) -> Result<SessionInfo, Self::Error> {
+        self.inner.create_session(caps).await
+    }
+    async fn create_session_with_id(
+        &self,
+        id: String,
+        caps: ServerCapabilities,
+    ) -> Result<SessionInfo, Self::Error> {
+        self.inner.create_session_with_id(id, caps).await
+    }
+    async fn get_session(&self, id: &str) -> Result<Option<SessionInfo>, Self::Error> {
+        self.inner.get_session(id).await
+    }
+    async fn update_session(&self, info: SessionInfo) -> Result<(), Self::Error> {
+        self.inner.update_session(info).await
+    }
+    async fn set_session_state(
+        &self,
+        id: &str,
+        key: &str,
+        value: Value,
+    ) -> Result<(), Self::Error> {
+        self.inner.set_session_state(id, key, value).await
+    }
+    async fn set_session_state_versioned(
+        &self,
+        id: &str,
+        key: &str,
+        value: Value,
+        expected_version: u64,
+    ) -> Result<u64, Self::Error> {
+        self.inner
+            .set_session_state_versioned(id, key, value, expected_version)
+            .await
+    }
+    async fn get_session_state(&self, id: &str, key: &str) -> Result<Option<Value>, Self::Error> {
+        self.inner.get_session_state(id, key).await
+    }
+    async fn remove_session_state(
+        &self,
+        id: &str,
+        key: &str,
+    ) -> Result<Option<Value>, Self::Error> {
+        self.inner.remove_session_state(id, key).await
+    }
+    async fn delete_session(&self, id: &str) -> Result<bool, Self::Error> {
+        self.inner.delete_session(id).await
+    }
+    async fn list_sessions(&self) -> Result<Vec<String>, Self::Error> {
+        self.inner.list_sessions().await
+    }
+    async fn store_event(&self, id: &str, event: SseEvent) -> Result<SseEvent, Self::Error> {
+        self.inner.store_event(id, event).await
+    }
+    async fn get_events_after(&self, id: &str, after: u64) -> Result<Vec<SseEvent>, Self::Error> {
+        self.inner.get_events_after(id, after).await
+    }
+    async fn get_recent_events(
+        &self,
+        id: &str,
+        limit: usize,
+    ) -> Result<Vec<SseEvent>, Self::Error> {
+        self.inner.get_recent_events(id, limit).await
+    }
+    async fn delete_events_before(&self, id: &str, before: u64) -> Result<u64, Self::Error> {
+        self.inner.delete_events_before(id, before).await
+    }
+    async fn expire_sessions(
+        &self,
+        older_than: std::time::SystemTime,
+    ) -> Result<Vec<String>, Self::Error> {
+        self.inner.expire_sessions(older_than).await
+    }
+    async fn session_count(&self) -> Result<usize, Self::Error> {
+        self.inner.session_count().await
+    }
+    async fn event_count(&self) -> Result<usize, Self::Error> {
+        self.inner.event_count().await
+    }
+    async fn maintenance(&self) -> Result<(), Self::Error> {
+        self.inner.maintenance().await
+    }
+    async fn ping(&self) -> Result<(), Self::Error> {
+        if self.down.load(Ordering::SeqCst) {
+            return Err(SessionStorageError::DatabaseError(
+                "connection refused".to_string(),
+            ));
+        }
+        self.inner.ping().await
+    }
+}
+
+/// Start a server and return its base URL and the storage outage switch
+async fn start_server(
+    configure: impl FnOnce(McpServerBuilder) -> McpServerBuilder,
+) -> (String, Arc<AtomicBool>) {
+    let down = Arc::new(AtomicBool::new(false));
+    let storage = FlakyStorage {
+        inner: InMemorySessionStorage::new(),
+        down: Arc::clone(&down),
+    };
+    let builder = McpServer::builder()
+        .name("health-test")
+        .version("1.0.0")
+        .with_session_storage(Arc::new(storage));
+    let url = spawn_server(configure(builder)).await;
+
+    (url.trim_end_matches("/mcp").to_string(), down)
+}
+
+async fn get(url: String) -> (u16, Value) {
+    let response = reqwest::get(url).await.unwrap();
+    let status = response.status().as_u16();
+    (status, response.json().await.unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_readiness_flips_to_503_when_storage_ping_fails() {
+    let (base, down) = start_server(|builder| builder.health_checks()).await;
+
+    assert_eq!(
+        get(format!("{base}/readyz")).await,
+        (200, json!({ "status": "ready", "storage": "flaky-test" }))
+    );
+
+    down.store(true, Ordering::SeqCst);
+    let (status, body) = get(format!("{base}/readyz")).await;
+    assert_eq!(status, 503);
+    assert_eq!(body["status"], "unavailable");
+    assert!(
+        body["error"]
+            .as_str()
+            .unwrap()
+            .contains("connection refused")
+    );
+
+    // Liveness does not depend on storage
+    assert_eq!(
+        get(format!("{base}/healthz")).await,
+        (200, json!({ "status": "ok" }))
+    );
+
+    down.store(false, Ordering::SeqCst);
+    assert_eq!(get(format!("{base}/readyz")).await.0, 200);
+}
+
+#[tokio::test]
+async fn test_probes_are_off_by_default_and_paths_configurable() {
+    let (base, _) = start_server(|builder| builder).await;
+    assert_eq!(get(format!("{base}/healthz")).await.0, 404);
+    assert_eq!(get(format!("{base}/readyz")).await.0, 404);
+
+    let (base, _) = start_server(|builder| {
+        builder
+            .liveness_path("/internal/live")
+            .readiness_path("/internal/ready")
+    })
+    .await;
+    assert_eq!(get(format!("{base}/internal/live")).await.0, 200);
+    assert_eq!(get(format!("{base}/internal/ready")).await.0, 200);
+    assert_eq!(get(format!("{base}/healthz")).await.0, 404);
+}
@@ -3,7 +3,7 @@
 //! These tests verify that all HTTP server configuration examples from the
 //! turul-http-mcp-server README compile correctly.
 
-use turul_http_mcp_server::ServerConfig;
+use turul_http_mcp_server::{ContentEncoding, CorsConfig, ServerConfig};
 use turul_mcp_derive::mcp_tool;
 use turul_mcp_server::McpServer;
 use turul_mcp_server::{McpResult, SessionContext};
@@ -32,11 +32,16 @@ fn test_basic_http_server_configuration() {
         bind_address: "127.0.0.1:3000".parse().unwrap(),
         mcp_path: "/mcp".to_string(),
         enable_cors: true,
+        cors: CorsConfig::allow_origins(vec!["https://app.example.com".to_string()]),
         max_body_size: 1024 * 1024,
         enable_get_sse: true,
         enable_post_sse: true,
         session_expiry_minutes: 30,
         allow_unauthenticated_ping: true,
+        compression_threshold: 1024,
+        compression_algorithms: vec![ContentEncoding::Gzip],
+        liveness_path: Some("/healthz".to_string()),
+        readiness_path: Some("/readyz".to_string()),
     };
 
     // Note: We don't actually create the HttpMcpServer here since it would try to bind to the port
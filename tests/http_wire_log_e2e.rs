@@ -0,0 +1,172 @@
+//! E2E tests for wire-level JSON-RPC logging (`log_wire_json`).
+
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tracing::Level;
+use tracing_test::traced_test;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, McpServerBuilder};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "login", description = "Check a user's PIN")]
+async fn login(
+    #[param(description = "User name")] user: String,
+    #[param(description = "Secret PIN")] pin: String,
+) -> McpResult<String> {
+    Ok(format!("{} logged in with a {}-digit PIN", user, pin.len()))
+}
+
+struct Client {
+    client: reqwest::Client,
+    url: String,
+    session_id: String,
+}
+
+impl Client {
+    async fn connect(configure: impl FnOnce(McpServerBuilder) -> McpServerBuilder) -> Self {
+        let url = spawn_server(configure(
+            McpServer::builder()
+                .name("wire-log-test")
+                .version("1.0.0")
+                .tool_fn(login)
+                .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+        ))
+        .await;
+
+        let mut client = Self {
+            client: reqwest::Client::new(),
+            url,
+            session_id: String::new(),
+        };
+        let response = client
+            .post(
+                "application/json",
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": "initialize",
+                    "id": 1,
+                    "params": {
+                        "protocolVersion": "2025-11-25",
+                        "capabilities": {},
+                        "clientInfo": { "name": "wire-log-client", "version": "1.0.0" }
+                    }
+                }),
+            )
+            .await;
+        client.session_id = response.headers()["Mcp-Session-Id"]
+            .to_str()
+            .unwrap()
+            .to_string();
+        client
+            .post(
+                "application/json",
+                json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+            )
+            .await;
+        client
+    }
+
+    async fn post(&self, accept: &str, body: Value) -> reqwest::Response {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header("Accept", accept)
+            .header("MCP-Protocol-Version", "2025-11-25");
+        if !self.session_id.is_empty() {
+            request = request.header("Mcp-Session-Id", &self.session_id);
+        }
+        request.json(&body).send().await.unwrap()
+    }
+
+    async fn call_login(&self, accept: &str) -> String {
+        self.post(
+            accept,
+            json!({
+                "jsonrpc": "2.0",
+                "method": "tools/call",
+                "id": 7,
+                "params": { "name": "login", "arguments": { "user": "ada", "pin": "8675309" } }
+            }),
+        )
+        .await
+        .text()
+        .await
+        .unwrap()
+    }
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_requests_and_responses_are_logged_with_redaction() {
+    let client = Client::connect(|builder| {
+        builder
+            .log_wire_json(Level::INFO)
+            .redact_wire_fields(["pin"])
+    })
+    .await;
+
+    let body = client.call_login("application/json").await;
+    assert!(body.contains("7-digit PIN"));
+
+    logs_assert(|lines: &[&str]| {
+        let wire: Vec<_> = lines
+            .iter()
+            .filter(|line| line.contains("turul_mcp_wire"))
+            .collect();
+        let request = wire
+            .iter()
+            .find(|line| {
+                line.contains(r#"direction="inbound""#) && line.contains(r#"method="tools/call""#)
+            })
+            .ok_or("tools/call request not logged")?;
+        if !request.contains(r#""pin":"[REDACTED]""#) || !request.contains(r#""user":"ada""#) {
+            return Err(format!("pin not redacted: {request}"));
+        }
+        if !wire
+            .iter()
+            .any(|line| line.contains(r#"direction="outbound""#) && line.contains(r#"id="7""#))
+        {
+            return Err("tools/call response not logged".to_string());
+        }
+        if wire.iter().any(|line| line.contains("8675309")) {
+            return Err("unredacted pin in wire log".to_string());
+        }
+        Ok(())
+    });
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_sse_responses_are_logged() {
+    let client = Client::connect(|builder| builder.log_wire_json(Level::INFO)).await;
+
+    let body = client
+        .call_login("application/json, text/event-stream")
+        .await;
+    assert!(body.contains("7-digit PIN"));
+
+    logs_assert(|lines: &[&str]| {
+        lines
+            .iter()
+            .any(|line| {
+                line.contains(r#"transport="sse""#)
+                    && line.contains(r#"direction="outbound""#)
+                    && line.contains("7-digit PIN")
+            })
+            .then_some(())
+            .ok_or_else(|| "no outbound SSE message logged".to_string())
+    });
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_nothing_is_logged_when_disabled() {
+    let client = Client::connect(|builder| builder).await;
+
+    client.call_login("application/json").await;
+
+    assert!(!logs_contain("MCP wire message"));
+}
@@ -0,0 +1,100 @@
+//! E2E tests for idempotent `tools/call`: replays carrying the same
+//! `Idempotency-Key` header or `_meta.idempotencyKey` return the cached result
+//! without running the tool again.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::{Value, json};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::McpServer;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+static CHARGES: AtomicUsize = AtomicUsize::new(0);
+
+#[mcp_tool(name = "charge", description = "Charges the card once per call")]
+async fn charge(#[param(description = "Amount in cents")] amount: i64) -> McpResult<String> {
+    let charge = CHARGES.fetch_add(1, Ordering::SeqCst) + 1;
+    Ok(format!("charge #{charge} of {amount}"))
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("idempotency-test")
+            .version("1.0.0")
+            .tool_fn(charge)
+            .idempotency_window(Duration::from_secs(60))
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+/// Call `charge` and return the result text
+async fn call_charge(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: &str,
+    idempotency_key: Option<&str>,
+    params: Value,
+) -> String {
+    let mut request = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", session_id);
+    if let Some(key) = idempotency_key {
+        request = request.header("Idempotency-Key", key);
+    }
+    let response: Value = request
+        .json(&json!({ "jsonrpc": "2.0", "method": "tools/call", "id": 2, "params": params }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    response["result"]["content"][0]["text"]
+        .as_str()
+        .unwrap_or_else(|| panic!("response: {response}"))
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_same_key_executes_tool_once() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &url).await;
+    let params = json!({ "name": "charge", "arguments": { "amount": 500 } });
+
+    let before = CHARGES.load(Ordering::SeqCst);
+    let first = call_charge(&client, &url, &session_id, Some("order-1"), params.clone()).await;
+    let replay = call_charge(&client, &url, &session_id, Some("order-1"), params.clone()).await;
+    assert_eq!(first, replay);
+    assert_eq!(CHARGES.load(Ordering::SeqCst) - before, 1);
+
+    // The same key passed as _meta is the same call
+    let meta_params = json!({
+        "name": "charge",
+        "arguments": { "amount": 500 },
+        "_meta": { "idempotencyKey": "order-1" }
+    });
+    let via_meta = call_charge(&client, &url, &session_id, None, meta_params).await;
+    assert_eq!(via_meta, first);
+    assert_eq!(CHARGES.load(Ordering::SeqCst) - before, 1);
+
+    // A new key, or no key at all, runs the tool
+    call_charge(&client, &url, &session_id, Some("order-2"), params.clone()).await;
+    call_charge(&client, &url, &session_id, None, params.clone()).await;
+    call_charge(&client, &url, &session_id, None, params.clone()).await;
+    assert_eq!(CHARGES.load(Ordering::SeqCst) - before, 4);
+
+    // Keys are scoped to the session
+    let other_session = open_session(&client, &url).await;
+    call_charge(&client, &url, &other_session, Some("order-1"), params).await;
+    assert_eq!(CHARGES.load(Ordering::SeqCst) - before, 5);
+}
@@ -0,0 +1,120 @@
+//! E2E tests for `InProcessTransport`: an `McpClient` driving an `McpServer`
+//! in the same process, without binding a socket.
+
+use std::sync::{Arc, Mutex};
+
+use serde_json::{Value, json};
+use turul_mcp_client::transport::InProcessTransport;
+use turul_mcp_client::{McpClient, McpClientBuilder};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, SessionContext};
+
+#[mcp_tool(name = "add", description = "Add two numbers")]
+async fn add(
+    #[param(description = "First number")] a: f64,
+    #[param(description = "Second number")] b: f64,
+) -> McpResult<f64> {
+    Ok(a + b)
+}
+
+#[mcp_tool(name = "countdown", description = "Count down, reporting progress")]
+async fn countdown(
+    #[param(description = "Where to start")] from: u64,
+    session: Option<SessionContext>,
+) -> McpResult<String> {
+    if let Some(session) = session {
+        for step in 1..=from {
+            session.notify_progress("countdown", step).await;
+        }
+    }
+    Ok("liftoff".to_string())
+}
+
+/// Connect a client to a fresh two-tool server, recording server notifications
+async fn connect() -> (McpClient, Arc<Mutex<Vec<Value>>>) {
+    let server = McpServer::builder()
+        .name("in-process-test")
+        .version("1.0.0")
+        .tool_fn(add)
+        .tool_fn(countdown)
+        .build()
+        .unwrap();
+    let service = server.in_process_service().await;
+
+    let notifications = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&notifications);
+    let client = McpClientBuilder::new()
+        .with_transport(Box::new(InProcessTransport::new(service)))
+        .on_notification(move |method, params| {
+            recorded
+                .lock()
+                .unwrap()
+                .push(json!({ "method": method, "params": params }));
+        })
+        .build();
+    client.connect().await.unwrap();
+    (client, notifications)
+}
+
+#[tokio::test]
+async fn test_calls_both_tools_in_process() {
+    let (client, _) = connect().await;
+
+    let mut names: Vec<_> = client
+        .list_tools()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|tool| tool.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, ["add", "countdown"]);
+
+    let sum = client
+        .call_tool("add", json!({ "a": 2, "b": 3 }))
+        .await
+        .unwrap();
+    assert_eq!(sum.structured_content, Some(json!({ "result": 5.0 })));
+
+    let countdown = client
+        .call_tool("countdown", json!({ "from": 3 }))
+        .await
+        .unwrap();
+    assert_eq!(
+        countdown.structured_content,
+        Some(json!({ "result": "liftoff" }))
+    );
+
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_progress_notifications_flow_back() {
+    let (client, notifications) = connect().await;
+
+    client
+        .call_tool("countdown", json!({ "from": 3 }))
+        .await
+        .unwrap();
+
+    // Notifications are dispatched by the client's event task
+    let progress = tokio::time::timeout(std::time::Duration::from_secs(2), async {
+        loop {
+            let progress: Vec<f64> = notifications
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|n| n["method"] == "notifications/progress")
+                .filter_map(|n| n["params"]["progress"].as_f64())
+                .collect();
+            if progress.len() == 3 {
+                return progress;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("progress notifications should reach the client");
+    assert_eq!(progress, [1.0, 2.0, 3.0]);
+}
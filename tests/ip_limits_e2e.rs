@@ -0,0 +1,132 @@
+//! E2E tests for per-client-IP limits in the HTTP transport.
+//!
+//! Verifies that one client IP hitting its connection or session cap is turned
+//! away with `429` while a different IP proceeds, both for direct peers and for
+//! clients resolved from `X-Forwarded-For` behind a trusted proxy.
+
+use mcp_e2e_shared::spawn_server;
+use serde_json::json;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use turul_http_mcp_server::IpLimitConfig;
+use turul_mcp_server::McpServer;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+async fn start_limited_server(limits: IpLimitConfig) -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("ip-limits-test")
+            .version("1.0.0")
+            .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+            .ip_limits(limits),
+    )
+    .await
+}
+
+async fn initialize(
+    client: &reqwest::Client,
+    server_url: &str,
+    forwarded_for: Option<&str>,
+) -> reqwest::Response {
+    let mut request = client
+        .post(server_url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25");
+    if let Some(forwarded_for) = forwarded_for {
+        request = request.header("X-Forwarded-For", forwarded_for);
+    }
+    request
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "initialize",
+            "id": 1,
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "ip-limits-test", "version": "1.0.0" }
+            }
+        }))
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_session_cap_per_forwarded_client_ip() {
+    let loopback: IpAddr = "127.0.0.1".parse().unwrap();
+    let server_url = start_limited_server(
+        IpLimitConfig::new()
+            .max_sessions_per_ip(2)
+            .trusted_proxies([loopback]),
+    )
+    .await;
+    let client = reqwest::Client::new();
+
+    // The proxy forwards two sessions for the noisy client...
+    for _ in 0..2 {
+        let response = initialize(&client, &server_url, Some("203.0.113.7")).await;
+        assert_eq!(response.status(), 200);
+        assert!(response.headers().contains_key("Mcp-Session-Id"));
+    }
+
+    // ...and the third is refused
+    let response = initialize(&client, &server_url, Some("203.0.113.7")).await;
+    assert_eq!(response.status(), 429);
+    assert!(response.headers().contains_key("Retry-After"));
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["error"]["code"], 429);
+
+    // A different client behind the same proxy still gets through
+    let response = initialize(&client, &server_url, Some("198.51.100.9")).await;
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_forwarded_header_ignored_from_untrusted_peer() {
+    let server_url = start_limited_server(IpLimitConfig::new().max_sessions_per_ip(1)).await;
+    let client = reqwest::Client::new();
+
+    let response = initialize(&client, &server_url, Some("203.0.113.7")).await;
+    assert_eq!(response.status(), 200);
+
+    // Rotating a spoofed header does not mint fresh quota for the real peer
+    let response = initialize(&client, &server_url, Some("203.0.113.8")).await;
+    assert_eq!(response.status(), 429);
+}
+
+#[tokio::test]
+async fn test_connection_cap_blocks_one_ip_while_another_proceeds() {
+    let server_url = start_limited_server(IpLimitConfig::new().max_connections_per_ip(3)).await;
+    let addr = server_url
+        .trim_start_matches("http://")
+        .trim_end_matches("/mcp")
+        .to_string();
+
+    // Hold the noisy IP's connection budget open with idle sockets
+    let mut idle = Vec::new();
+    for _ in 0..3 {
+        idle.push(tokio::net::TcpStream::connect(&addr).await.unwrap());
+    }
+    sleep(Duration::from_millis(100)).await;
+
+    let noisy = reqwest::Client::new();
+    let response = initialize(&noisy, &server_url, None).await;
+    assert_eq!(response.status(), 429);
+
+    // Another loopback source address is a different client IP
+    let other = reqwest::Client::builder()
+        .local_address("127.0.0.2".parse::<IpAddr>().unwrap())
+        .build()
+        .unwrap();
+    let response = initialize(&other, &server_url, None).await;
+    assert_eq!(response.status(), 200);
+
+    // Releasing the held connections frees the noisy IP's budget
+    drop(idle);
+    sleep(Duration::from_millis(100)).await;
+    let response = initialize(&reqwest::Client::new(), &server_url, None).await;
+    assert_eq!(response.status(), 200);
+}
@@ -0,0 +1,87 @@
+//! Tests for shared (`$defs`) and recursive types in `#[derive(JsonSchema)]`
+
+use serde_json::Value;
+use turul_mcp_derive::JsonSchema;
+use turul_mcp_protocol::schema::{JsonSchemaGenerator, ToJsonSchema};
+
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct Customer {
+    name: String,
+    billing: Address,
+    shipping: Option<Address>,
+}
+
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct TreeNode {
+    label: String,
+    children: Vec<TreeNode>,
+}
+
+#[test]
+fn test_shared_type_is_defined_once() {
+    let schema = serde_json::to_value(Customer::json_schema()).unwrap();
+
+    let defs = schema["$defs"]
+        .as_object()
+        .expect("$defs should be emitted");
+    assert_eq!(defs.len(), 1, "only Address is shared: {}", schema);
+    assert_eq!(defs["Address"]["type"], "object");
+    assert_eq!(defs["Address"]["properties"]["city"]["type"], "string");
+
+    assert_eq!(schema["properties"]["billing"]["$ref"], "#/$defs/Address");
+    assert_eq!(schema["properties"]["shipping"]["$ref"], "#/$defs/Address");
+    assert_eq!(schema["properties"]["name"]["type"], "string");
+}
+
+#[test]
+fn test_recursive_type_terminates_with_ref() {
+    let schema = serde_json::to_value(TreeNode::json_schema()).unwrap();
+
+    let children = &schema["properties"]["children"];
+    assert_eq!(children["type"], "array");
+    assert_eq!(children["items"]["$ref"], "#/$defs/TreeNode");
+
+    let definition = &schema["$defs"]["TreeNode"];
+    assert_eq!(definition["properties"]["label"]["type"], "string");
+    assert_eq!(
+        definition["properties"]["children"]["items"]["$ref"],
+        "#/$defs/TreeNode"
+    );
+}
+
+#[test]
+fn test_to_json_schema_is_self_contained() {
+    // Tool parameters embed to_json_schema() without a root to hold $defs
+    let customer = serde_json::to_value(Customer::to_json_schema()).unwrap();
+    assert!(!customer.to_string().contains("$ref"));
+    assert_eq!(
+        customer["properties"]["shipping"]["properties"]["street"]["type"],
+        "string"
+    );
+
+    let tree = serde_json::to_value(TreeNode::to_json_schema()).unwrap();
+    assert!(!tree.to_string().contains("$ref"));
+    let nested = &tree["properties"]["children"]["items"];
+    assert_eq!(nested["properties"]["label"]["type"], "string");
+    // The recursion is cut one level down with an open object schema
+    assert_eq!(nested["properties"]["children"]["items"]["type"], "object");
+    assert_eq!(
+        nested["properties"]["children"]["items"]["properties"],
+        Value::Null
+    );
+}
+
+#[test]
+fn test_flat_struct_has_no_defs() {
+    let schema = serde_json::to_value(Address::json_schema()).unwrap();
+    assert!(schema.get("$defs").is_none());
+}
@@ -0,0 +1,201 @@
+//! E2E tests for `McpServer::notify_tools_changed` and friends: after the tool
+//! set behind a `ToolRouter` changes at runtime, every connected session gets
+//! `notifications/tools/list_changed` on its SSE stream — but only when the
+//! server advertised `tools.listChanged`.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use mcp_e2e_shared::spawn_shared_server;
+use serde_json::{Value, json};
+use tokio::time::timeout;
+use turul_mcp_builders::ToolBuilder;
+use turul_mcp_server::{McpServer, McpTool, ToolRouter};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+/// A router whose tools are added while the server runs
+#[derive(Default)]
+struct PluginTools {
+    tools: Mutex<Vec<Arc<dyn McpTool>>>,
+}
+
+#[async_trait]
+impl ToolRouter for PluginTools {
+    async fn resolve(&self, name: &str) -> Option<Arc<dyn McpTool>> {
+        let tools = self.tools.lock().unwrap();
+        tools.iter().find(|tool| tool.name() == name).cloned()
+    }
+
+    async fn list(&self) -> Vec<turul_mcp_protocol::Tool> {
+        let tools = self.tools.lock().unwrap();
+        tools.iter().map(|tool| tool.to_tool()).collect()
+    }
+}
+
+async fn start_server(list_changed: bool) -> (Arc<McpServer>, Arc<PluginTools>, String) {
+    let plugins = Arc::new(PluginTools::default());
+    let mut builder = McpServer::builder()
+        .name("list-changed-test")
+        .version("1.0.0")
+        .with_tool_router(plugins.clone())
+        .with_session_storage(Arc::new(InMemorySessionStorage::new()));
+    if list_changed {
+        builder = builder.list_changed_notifications();
+    }
+    let (server, url) = spawn_shared_server(builder).await;
+    (server, plugins, url)
+}
+
+/// Initialize a session and return its id and the advertised capabilities
+async fn open_session(client: &reqwest::Client, url: &str) -> (String, Value) {
+    let response = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "initialize",
+            "id": 1,
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "list-changed-test", "version": "1.0.0" }
+            }
+        }))
+        .send()
+        .await
+        .unwrap();
+    let session_id = response
+        .headers()
+        .get("Mcp-Session-Id")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    let initialized: Value = response.json().await.unwrap();
+
+    client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .send()
+        .await
+        .unwrap();
+    (session_id, initialized["result"]["capabilities"].clone())
+}
+
+async fn open_stream(client: &reqwest::Client, url: &str, session_id: &str) -> reqwest::Response {
+    let stream = client
+        .get(url)
+        .header("Accept", "text/event-stream")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", session_id)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(stream.status(), 200);
+    stream
+}
+
+/// Read SSE data frames until one has `method`, or `None` once `wait` passes
+async fn next_with_method(
+    stream: &mut reqwest::Response,
+    method: &str,
+    wait: Duration,
+) -> Option<Value> {
+    let mut buffer = String::new();
+    timeout(wait, async {
+        while let Some(chunk) = stream.chunk().await.unwrap() {
+            buffer.push_str(&String::from_utf8_lossy(&chunk));
+            let found = buffer
+                .lines()
+                .filter_map(|line| line.strip_prefix("data: "))
+                .filter_map(|data| serde_json::from_str::<Value>(data).ok())
+                .find(|frame| frame["method"] == method);
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+fn deploy_tool() -> Arc<dyn McpTool> {
+    Arc::new(
+        ToolBuilder::new("deploy")
+            .description("Deploy the current build")
+            .execute(|_| async { Ok(json!("deployed")) })
+            .build()
+            .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_notify_tools_changed_reaches_every_session() {
+    let (server, plugins, url) = start_server(true).await;
+    let client = reqwest::Client::new();
+
+    let (first_session, capabilities) = open_session(&client, &url).await;
+    assert_eq!(capabilities["tools"]["listChanged"], true);
+    let (second_session, _) = open_session(&client, &url).await;
+    let mut first_stream = open_stream(&client, &url, &first_session).await;
+    let mut second_stream = open_stream(&client, &url, &second_session).await;
+
+    plugins.tools.lock().unwrap().push(deploy_tool());
+    server.notify_tools_changed().await.unwrap();
+
+    for stream in [&mut first_stream, &mut second_stream] {
+        let notification = next_with_method(
+            stream,
+            "notifications/tools/list_changed",
+            Duration::from_secs(5),
+        )
+        .await
+        .expect("subscriber should receive tools/list_changed");
+        assert_eq!(notification["jsonrpc"], "2.0");
+    }
+
+    // The re-fetched list carries the new tool
+    let listed: Value = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &first_session)
+        .json(&json!({ "jsonrpc": "2.0", "method": "tools/list", "id": 2 }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listed["result"]["tools"][0]["name"], "deploy");
+}
+
+#[tokio::test]
+async fn test_notify_skipped_without_list_changed_capability() {
+    let (server, plugins, url) = start_server(false).await;
+    let client = reqwest::Client::new();
+
+    let (session_id, capabilities) = open_session(&client, &url).await;
+    assert_eq!(capabilities["tools"]["listChanged"], false);
+    let mut stream = open_stream(&client, &url, &session_id).await;
+
+    plugins.tools.lock().unwrap().push(deploy_tool());
+    server.notify_tools_changed().await.unwrap();
+
+    let notification = next_with_method(
+        &mut stream,
+        "notifications/tools/list_changed",
+        Duration::from_millis(300),
+    )
+    .await;
+    assert!(notification.is_none(), "got {notification:?}");
+}
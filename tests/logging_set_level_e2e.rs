@@ -0,0 +1,165 @@
+//! E2E tests for `logging/setLevel`: the level a client requests gates the
+//! `notifications/message` it receives, and `on_log_level_change` sees each change.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::{Value, json};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::{EnvFilter, Registry};
+use turul_mcp_client::transport::InProcessTransport;
+use turul_mcp_client::{McpClient, McpClientBuilder};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_protocol::logging::LoggingLevel;
+use turul_mcp_server::{McpServer, SessionContext, tracing_level};
+
+/// Log `message` at debug, then an error marker that every level lets through
+#[mcp_tool(
+    name = "emit",
+    description = "Emit a debug log followed by an error marker"
+)]
+async fn emit(
+    #[param(description = "Tag for this round")] round: String,
+    session: Option<SessionContext>,
+) -> McpResult<String> {
+    if let Some(session) = session {
+        session
+            .notify_log(
+                LoggingLevel::Debug,
+                json!(format!("debug:{}", round)),
+                Some("emit".to_string()),
+                None,
+            )
+            .await;
+        session
+            .notify_log(
+                LoggingLevel::Error,
+                json!(format!("error:{}", round)),
+                Some("emit".to_string()),
+                None,
+            )
+            .await;
+    }
+    Ok(round)
+}
+
+type Recorded<T> = Arc<Mutex<Vec<T>>>;
+
+/// Connect a client, recording `emit` log messages and level changes
+async fn connect(
+    on_change: impl Fn(LoggingLevel) + Send + Sync + 'static,
+) -> (McpClient, Recorded<Value>, Recorded<(String, LoggingLevel)>) {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let recorded_changes = Arc::clone(&changes);
+
+    let server = McpServer::builder()
+        .name("logging-test")
+        .version("1.0.0")
+        .tool_fn(emit)
+        .on_log_level_change(move |session_id, level| {
+            recorded_changes
+                .lock()
+                .unwrap()
+                .push((session_id.to_string(), level));
+            on_change(level);
+        })
+        .build()
+        .unwrap();
+
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&messages);
+    let client = McpClientBuilder::new()
+        .with_transport(Box::new(InProcessTransport::new(
+            server.in_process_service().await,
+        )))
+        .on_notification(move |method, params| {
+            if method == "notifications/message"
+                && let Some(params) = params
+                && params["logger"] == "emit"
+            {
+                recorded.lock().unwrap().push(params["data"].clone());
+            }
+        })
+        .build();
+    client.connect().await.unwrap();
+    (client, messages, changes)
+}
+
+/// Run one `emit` round and return the log messages it delivered
+async fn emit_round(client: &McpClient, messages: &Recorded<Value>, round: &str) -> Vec<Value> {
+    client
+        .call_tool("emit", json!({ "round": round }))
+        .await
+        .unwrap();
+
+    // The error marker is always delivered and arrives after the debug line
+    let marker = json!(format!("error:{}", round));
+    tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            if messages.lock().unwrap().contains(&marker) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("error marker should reach the client");
+
+    let suffix = format!(":{}", round);
+    messages
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|data| data.as_str().is_some_and(|s| s.ends_with(&suffix)))
+        .cloned()
+        .collect()
+}
+
+#[tokio::test]
+async fn test_set_level_gates_debug_messages() {
+    let (client, messages, changes) = connect(|_| {}).await;
+
+    client.set_log_level(LoggingLevel::Debug).await.unwrap();
+    assert_eq!(
+        emit_round(&client, &messages, "verbose").await,
+        [json!("debug:verbose"), json!("error:verbose")]
+    );
+
+    client.set_log_level(LoggingLevel::Error).await.unwrap();
+    assert_eq!(
+        emit_round(&client, &messages, "quiet").await,
+        [json!("error:quiet")]
+    );
+
+    let session_id = client.session_info().await.session_id.unwrap();
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [
+            (session_id.clone(), LoggingLevel::Debug),
+            (session_id, LoggingLevel::Error),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_level_change_reloads_tracing_filter() {
+    let (filter, handle) =
+        tracing_subscriber::reload::Layer::<_, Registry>::new(EnvFilter::new("info"));
+    let reload = handle.clone();
+    let (client, _, _) = connect(move |level| {
+        let directive = LevelFilter::from_level(tracing_level(level)).into();
+        reload
+            .modify(|filter| *filter = EnvFilter::default().add_directive(directive))
+            .unwrap();
+    })
+    .await;
+
+    client.set_log_level(LoggingLevel::Debug).await.unwrap();
+    assert_eq!(handle.with_current(|f| f.to_string()).unwrap(), "debug");
+
+    client.set_log_level(LoggingLevel::Critical).await.unwrap();
+    assert_eq!(handle.with_current(|f| f.to_string()).unwrap(), "error");
+
+    drop(filter);
+}
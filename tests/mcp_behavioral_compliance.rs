@@ -985,11 +985,12 @@ async fn test_version_negotiation_exact_match() {
 }
 
 #[tokio::test]
-async fn test_version_negotiation_ancient_client_error() {
+async fn test_version_negotiation_ancient_client_offered_latest() {
     let server_url = start_test_server_with_tools().await;
     let client = reqwest::Client::new();
 
-    // Test client with very old version gets error
+    // Test client with very old version is offered the latest supported version;
+    // per spec the client decides whether it can proceed
     let response = client
         .post(&server_url)
         .header("Content-Type", "application/json")
@@ -1022,14 +1023,11 @@ async fn test_version_negotiation_ancient_client_error() {
         serde_json::to_string_pretty(&body).unwrap()
     );
 
-    // Should have error field, not result
-    assert!(body["error"].is_object());
-    assert!(body["result"].is_null() || !body.as_object().unwrap().contains_key("result"));
-
-    // Error message should mention version negotiation failure
-    let error_message = body["error"]["message"].as_str().unwrap();
-    assert!(error_message.contains("Cannot negotiate compatible version"));
-    assert!(error_message.contains("2020-01-01"));
+    // Should succeed and answer with the server's newest version
+    if !body["result"].is_object() {
+        panic!("Expected result object, got: {}", body);
+    }
+    assert_eq!(body["result"]["protocolVersion"], "2025-11-25");
 }
 
 // =============================================================================
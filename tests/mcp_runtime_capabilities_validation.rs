@@ -73,8 +73,8 @@ async fn test_runtime_initialize_capabilities_truthfulness() {
 
     assert_eq!(
         capabilities["resources"]["subscribe"],
-        false,
-        "❌ COMPLIANCE VIOLATION: resources.subscribe should be false until implemented, got: {}",
+        true,
+        "❌ COMPLIANCE VIOLATION: resources.subscribe should be true when resources are registered, got: {}",
         capabilities["resources"]["subscribe"]
     );
 
@@ -389,10 +389,9 @@ mod integration {
                 resources["listChanged"], false,
                 "Static framework: resources.listChanged must be false"
             );
-            // Resources subscribe should be false until implemented
             assert_eq!(
-                resources["subscribe"], false,
-                "resources.subscribe must be false until implemented"
+                resources["subscribe"], true,
+                "resources.subscribe must be true when resources are registered"
             );
         }
 
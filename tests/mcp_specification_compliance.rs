@@ -41,12 +41,9 @@ async fn test_runtime_capability_truthfulness() {
                 );
             }
 
-            // Framework doesn't support subscriptions, so subscribe MUST be false (if present)
+            // Servers with resources handle resources/subscribe, so subscribe MUST be true
             if let Some(subscribe) = resources.get("subscribe") {
-                assert_eq!(
-                    subscribe, false,
-                    "Framework doesn't support resource subscriptions"
-                );
+                assert_eq!(subscribe, true, "Framework supports resource subscriptions");
             }
         }
 
@@ -0,0 +1,105 @@
+//! E2E tests for `_meta` round trips on `tools/call`.
+//!
+//! A tool reads custom fields the client sent in `_meta` through
+//! `SessionContext::request_meta()` and attaches fields to its result with
+//! `SessionContext::set_response_meta()`; the client must see them in the
+//! result's `_meta`.
+
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::{McpError, McpResult};
+use turul_mcp_server::{McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(
+    name = "lookup",
+    description = "Looks up an order and echoes the correlation id"
+)]
+async fn lookup(session: Option<SessionContext>) -> McpResult<String> {
+    let session = session.ok_or_else(|| McpError::SessionError("session required".into()))?;
+    let meta = session
+        .request_meta()
+        .ok_or_else(|| McpError::MissingParameter("_meta".into()))?;
+
+    if let Some(correlation_id) = meta.extra.get("correlationId") {
+        session.set_response_meta("correlationId", correlation_id.clone());
+    }
+    session.set_response_meta("servedBy", "lookup");
+    // Structured keys are not taken from attached fields
+    session.set_response_meta("progressToken", "not-echoed");
+
+    Ok(format!(
+        "progress token {}",
+        session.progress_token().unwrap_or("none")
+    ))
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("meta-round-trip-test")
+            .version("1.0.0")
+            .tool_fn(lookup)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+async fn call_lookup(meta: Value) -> Value {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &url).await;
+
+    client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 2,
+            "params": { "name": "lookup", "arguments": {}, "_meta": meta }
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_custom_meta_survives_round_trip() {
+    let response = call_lookup(json!({
+        "correlationId": "req-7f3a",
+        "progressToken": "lookup-1"
+    }))
+    .await;
+
+    let result = &response["result"];
+    assert_eq!(
+        result["structuredContent"]["result"], "progress token lookup-1",
+        "response: {response}"
+    );
+    let meta = &result["_meta"];
+    assert_eq!(meta["correlationId"], "req-7f3a");
+    assert_eq!(meta["servedBy"], "lookup");
+    assert!(meta.get("progressToken").is_none(), "meta: {meta}");
+}
+
+#[tokio::test]
+async fn test_numeric_progress_token_keeps_custom_meta() {
+    // A progressToken that does not fit the typed field must not drop the rest
+    let response = call_lookup(json!({ "correlationId": 42, "progressToken": 9 })).await;
+
+    let result = &response["result"];
+    assert_eq!(
+        result["structuredContent"]["result"], "progress token none",
+        "response: {response}"
+    );
+    assert_eq!(result["_meta"]["correlationId"], 42);
+}
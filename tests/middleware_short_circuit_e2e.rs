@@ -0,0 +1,263 @@
+//! E2E tests for method-level authorization in middleware.
+//!
+//! A middleware that returns `Err` from `before_dispatch` stops the request
+//! before any handler runs: the tool is never invoked, later middleware never
+//! see the request, and the client gets the mapped JSON-RPC error.
+
+use async_trait::async_trait;
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use turul_mcp_builders::ToolBuilder;
+use turul_mcp_server::McpServer;
+use turul_mcp_server::prelude::*;
+use turul_mcp_session_storage::{InMemorySessionStorage, SessionView};
+
+/// Marks a session as authorized when `initialize` carries the right API key
+struct ApiKeyMiddleware;
+
+#[async_trait]
+impl McpMiddleware for ApiKeyMiddleware {
+    async fn before_dispatch(
+        &self,
+        ctx: &mut RequestContext<'_>,
+        _session: Option<&dyn SessionView>,
+        injection: &mut SessionInjection,
+    ) -> Result<(), MiddlewareError> {
+        if ctx.method() == "initialize" {
+            let authorized = ctx.metadata().get("x-api-key") == Some(&json!("secret"));
+            injection.set_state("authorized", json!(authorized));
+        }
+        Ok(())
+    }
+}
+
+/// Denies `tools/call` for sessions that were not authorized
+struct ToolCallGuard;
+
+#[async_trait]
+impl McpMiddleware for ToolCallGuard {
+    async fn before_dispatch(
+        &self,
+        ctx: &mut RequestContext<'_>,
+        session: Option<&dyn SessionView>,
+        _injection: &mut SessionInjection,
+    ) -> Result<(), MiddlewareError> {
+        if ctx.method() != "tools/call" {
+            return Ok(());
+        }
+        let authorized = match session {
+            Some(session) => session.get_state("authorized").await.ok().flatten(),
+            None => None,
+        };
+        if authorized != Some(json!(true)) {
+            return Err(MiddlewareError::unauthorized(
+                "tools/call requires an authorized session",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Records every method that reaches it, before and after dispatch
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+#[async_trait]
+impl McpMiddleware for Recorder {
+    async fn before_dispatch(
+        &self,
+        ctx: &mut RequestContext<'_>,
+        _session: Option<&dyn SessionView>,
+        _injection: &mut SessionInjection,
+    ) -> Result<(), MiddlewareError> {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("before {}", ctx.method()));
+        Ok(())
+    }
+
+    async fn after_dispatch(
+        &self,
+        ctx: &RequestContext<'_>,
+        _result: &mut DispatcherResult,
+    ) -> Result<(), MiddlewareError> {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("after {}", ctx.method()));
+        Ok(())
+    }
+}
+
+struct GuardedServer {
+    url: String,
+    tool_calls: Arc<AtomicUsize>,
+    recorded: Arc<Mutex<Vec<String>>>,
+}
+
+async fn start_guarded_server() -> GuardedServer {
+    let tool_calls = Arc::new(AtomicUsize::new(0));
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+
+    let counter = Arc::clone(&tool_calls);
+    let purge = ToolBuilder::new("purge")
+        .description("Delete everything")
+        .execute(move |_args| {
+            let counter = Arc::clone(&counter);
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(json!({"purged": true}))
+            }
+        })
+        .build()
+        .unwrap();
+
+    let url = spawn_server(
+        McpServer::builder()
+            .name("middleware-short-circuit-test")
+            .version("1.0.0")
+            .tool(purge)
+            .middleware(Arc::new(ApiKeyMiddleware))
+            .middleware(Arc::new(ToolCallGuard))
+            .middleware(Arc::new(Recorder(Arc::clone(&recorded))))
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await;
+    GuardedServer {
+        url,
+        tool_calls,
+        recorded,
+    }
+}
+
+async fn post(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: Option<&str>,
+    api_key: Option<&str>,
+    body: Value,
+) -> reqwest::Response {
+    let mut request = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25");
+    if let Some(session_id) = session_id {
+        request = request.header("Mcp-Session-Id", session_id);
+    }
+    if let Some(api_key) = api_key {
+        request = request.header("X-Api-Key", api_key);
+    }
+    request.json(&body).send().await.unwrap()
+}
+
+async fn initialize_session(client: &reqwest::Client, url: &str, api_key: Option<&str>) -> String {
+    let response = post(
+        client,
+        url,
+        None,
+        api_key,
+        json!({
+            "jsonrpc": "2.0",
+            "method": "initialize",
+            "id": 1,
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "short-circuit-test", "version": "1.0.0" }
+            }
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 200, "initialize should succeed");
+    let session_id = response
+        .headers()
+        .get("Mcp-Session-Id")
+        .expect("Server must return session ID")
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let response = post(
+        client,
+        url,
+        Some(&session_id),
+        None,
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+    )
+    .await;
+    assert_eq!(response.status(), 202);
+    session_id
+}
+
+async fn call_purge(client: &reqwest::Client, url: &str, session_id: &str) -> Value {
+    post(
+        client,
+        url,
+        Some(session_id),
+        None,
+        json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 2,
+            "params": { "name": "purge", "arguments": {} }
+        }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_blocked_method_never_reaches_handler() {
+    let server = start_guarded_server().await;
+    let client = reqwest::Client::new();
+    let session_id = initialize_session(&client, &server.url, None).await;
+    server.recorded.lock().unwrap().clear();
+
+    let body = call_purge(&client, &server.url, &session_id).await;
+
+    assert_eq!(body["error"]["code"], -32002, "unexpected body: {}", body);
+    assert_eq!(
+        body["error"]["message"],
+        "tools/call requires an authorized session"
+    );
+    assert!(body.get("result").is_none());
+    assert_eq!(server.tool_calls.load(Ordering::SeqCst), 0);
+    // Middleware after the guard never saw the request, and no after_dispatch ran
+    assert!(server.recorded.lock().unwrap().is_empty());
+
+    // Other methods on the same session are unaffected
+    let body: Value = post(
+        &client,
+        &server.url,
+        Some(&session_id),
+        None,
+        json!({"jsonrpc": "2.0", "method": "tools/list", "id": 3}),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(body["result"]["tools"][0]["name"], "purge");
+}
+
+#[tokio::test]
+async fn test_authorized_session_reaches_handler() {
+    let server = start_guarded_server().await;
+    let client = reqwest::Client::new();
+    let session_id = initialize_session(&client, &server.url, Some("secret")).await;
+    server.recorded.lock().unwrap().clear();
+
+    let body = call_purge(&client, &server.url, &session_id).await;
+
+    assert!(body.get("error").is_none(), "unexpected body: {}", body);
+    assert_eq!(server.tool_calls.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        *server.recorded.lock().unwrap(),
+        ["before tools/call", "after tools/call"]
+    );
+}
@@ -0,0 +1,174 @@
+//! E2E tests for the MessagePack codec: a POST with `Content-Type:
+//! application/msgpack` is answered in MessagePack, while JSON requests to the
+//! same server keep getting JSON. The test client encodes with `rmp-serde`
+//! directly, as a non-Rust-framework peer would.
+
+use std::sync::Arc;
+
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, MessagePackCodec};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "add", description = "Add two numbers")]
+async fn add(
+    #[param(description = "First number")] a: f64,
+    #[param(description = "Second number")] b: f64,
+) -> McpResult<f64> {
+    Ok(a + b)
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("msgpack-test")
+            .version("1.0.0")
+            .tool_fn(add)
+            .json_rpc_codec(Arc::new(MessagePackCodec))
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+/// POST `message` as MessagePack and return the response
+async fn post_msgpack(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: Option<&str>,
+    message: &Value,
+) -> reqwest::Response {
+    let mut request = client
+        .post(url)
+        .header("Content-Type", "application/msgpack")
+        .header("Accept", "application/msgpack")
+        .header("MCP-Protocol-Version", "2025-11-25");
+    if let Some(session_id) = session_id {
+        request = request.header("Mcp-Session-Id", session_id);
+    }
+    request
+        .body(rmp_serde::to_vec_named(message).unwrap())
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn decode_msgpack(response: reqwest::Response) -> Value {
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/msgpack"
+    );
+    rmp_serde::from_slice(&response.bytes().await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn test_msgpack_session_round_trip() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+
+    let response = post_msgpack(
+        &client,
+        &url,
+        None,
+        &json!({
+            "jsonrpc": "2.0",
+            "method": "initialize",
+            "id": 1,
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "msgpack-test", "version": "1.0.0" }
+            }
+        }),
+    )
+    .await;
+    let session_id = response
+        .headers()
+        .get("Mcp-Session-Id")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    let initialized = decode_msgpack(response).await;
+    assert_eq!(initialized["result"]["serverInfo"]["name"], "msgpack-test");
+
+    let accepted = post_msgpack(
+        &client,
+        &url,
+        Some(&session_id),
+        &json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+    )
+    .await;
+    assert_eq!(accepted.status(), 202);
+
+    let called = decode_msgpack(
+        post_msgpack(
+            &client,
+            &url,
+            Some(&session_id),
+            &json!({
+                "jsonrpc": "2.0",
+                "method": "tools/call",
+                "id": 2,
+                "params": { "name": "add", "arguments": { "a": 2.5, "b": 4 } }
+            }),
+        )
+        .await,
+    )
+    .await;
+    assert_eq!(called["id"], 2);
+    assert_eq!(called["result"]["structuredContent"]["result"], 6.5);
+
+    // The same session still speaks JSON to JSON requests
+    let listed: Value = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!({ "jsonrpc": "2.0", "method": "tools/list", "id": 3 }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listed["result"]["tools"][0]["name"], "add");
+}
+
+#[tokio::test]
+async fn test_undecodable_msgpack_gets_parse_error() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(&url)
+        .header("Content-Type", "application/msgpack")
+        .header("Accept", "application/msgpack")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .body(vec![0xc1])
+        .send()
+        .await
+        .unwrap();
+    let error = decode_msgpack(response).await;
+    assert_eq!(error["error"]["code"], -32700);
+}
+
+#[tokio::test]
+async fn test_msgpack_request_without_session_is_rejected_in_msgpack() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+
+    let response = post_msgpack(
+        &client,
+        &url,
+        None,
+        &json!({ "jsonrpc": "2.0", "method": "tools/list", "id": 5 }),
+    )
+    .await;
+    assert_eq!(response.status(), 401);
+    let error = decode_msgpack(response).await;
+    assert_eq!(error["id"], 5);
+    assert_eq!(error["error"]["code"], -32001);
+}
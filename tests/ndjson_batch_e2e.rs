@@ -0,0 +1,194 @@
+//! E2E tests for NDJSON batch responses.
+//!
+//! A client that accepts `application/x-ndjson` may POST a JSON array of
+//! requests; each response is streamed back as its own line as soon as that
+//! call finishes, rather than waiting for the whole batch.
+
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use turul_mcp_client::transport::HttpTransport;
+use turul_mcp_client::{BatchItem, ClientConfig, McpClient, McpClientError};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::McpServer;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "fast", description = "Returns immediately")]
+async fn fast() -> McpResult<String> {
+    Ok("fast".to_string())
+}
+
+#[mcp_tool(name = "slow", description = "Returns after half a second")]
+async fn slow() -> McpResult<String> {
+    sleep(Duration::from_millis(500)).await;
+    Ok("slow".to_string())
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("ndjson-batch-test")
+            .version("1.0.0")
+            .tool_fn(fast)
+            .tool_fn(slow)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+fn tool_call(id: i64, name: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "tools/call",
+        "id": id,
+        "params": { "name": name, "arguments": {} }
+    })
+}
+
+#[tokio::test]
+async fn test_ndjson_batch_streams_fast_result_before_slow() {
+    let server_url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server_url).await;
+
+    // The slow call is listed first; the fast one must still be written first
+    let mut response = client
+        .post(&server_url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, application/x-ndjson")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!([
+            tool_call(10, "slow"),
+            { "jsonrpc": "2.0", "method": "notifications/progress", "params": { "progressToken": "t", "progress": 1 } },
+            tool_call(11, "fast"),
+        ]))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/x-ndjson"
+    );
+
+    let mut buffer = String::new();
+    let mut lines = Vec::new();
+    while let Some(chunk) = response.chunk().await.unwrap() {
+        buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        while let Some(newline) = buffer.find('\n') {
+            let line: String = buffer.drain(..=newline).collect();
+            lines.push(serde_json::from_str::<Value>(line.trim()).unwrap());
+        }
+    }
+    assert!(buffer.is_empty(), "trailing partial line: {buffer:?}");
+
+    // One line per request; the notification produces none
+    assert_eq!(lines.len(), 2);
+    for line in &lines {
+        assert_eq!(line["jsonrpc"], "2.0");
+        assert!(line["result"].is_object(), "not a success response: {line}");
+    }
+    assert_eq!(lines[0]["id"], 11);
+    assert_eq!(lines[0]["result"]["structuredContent"]["result"], "fast");
+    assert_eq!(lines[1]["id"], 10);
+    assert_eq!(lines[1]["result"]["structuredContent"]["result"], "slow");
+}
+
+#[tokio::test]
+async fn test_ndjson_batch_rejects_initialize() {
+    let server_url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server_url).await;
+
+    let body = client
+        .post(&server_url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, application/x-ndjson")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!([
+            { "jsonrpc": "2.0", "method": "initialize", "id": 20, "params": {} },
+            tool_call(21, "fast"),
+        ]))
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    let lines: Vec<Value> = body
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["id"], 20);
+    assert_eq!(lines[0]["error"]["code"], -32600);
+    assert_eq!(lines[1]["id"], 21);
+    assert!(lines[1]["result"].is_object());
+}
+
+#[tokio::test]
+async fn test_batch_without_ndjson_accept_is_rejected() {
+    let server_url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server_url).await;
+
+    let response = client
+        .post(&server_url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!([tool_call(30, "fast")]))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["error"]["code"], -32600);
+}
+
+#[tokio::test]
+async fn test_client_batch_against_server() {
+    let server_url = start_server().await;
+    let client = McpClient::new(
+        Box::new(HttpTransport::new(&server_url).unwrap()),
+        ClientConfig::default(),
+    );
+    client.connect().await.unwrap();
+
+    let results = client
+        .batch(vec![
+            BatchItem::call_tool("slow", json!({})),
+            BatchItem::notification("notifications/roots/list_changed", json!({})),
+            BatchItem::call_tool("fast", json!({})),
+            BatchItem::request("no/such/method", json!({})),
+        ])
+        .await;
+
+    assert_eq!(results.len(), 4);
+    // Lines arrive fast-first; the client puts them back in request order
+    assert_eq!(
+        results[0].as_ref().unwrap()["structuredContent"]["result"],
+        "slow"
+    );
+    assert_eq!(results[1].as_ref().unwrap(), &Value::Null);
+    assert_eq!(
+        results[2].as_ref().unwrap()["structuredContent"]["result"],
+        "fast"
+    );
+    assert!(matches!(
+        results[3],
+        Err(McpClientError::ServerError { code: -32601, .. })
+    ));
+}
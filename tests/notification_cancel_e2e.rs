@@ -0,0 +1,434 @@
+//! E2E tests for client-sent notifications: `notification_handler` routing,
+//! `notifications/cancelled` aborting the matching in-flight `tools/call`, and
+//! the cancellation reaching blocking work through `SessionContext::cancellation_token`,
+//! which a client disconnecting mid-call fires as well.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use tokio::sync::Notify;
+use turul_mcp_client::transport::{InProcessTransport, Transport};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::{McpError, McpResult};
+use turul_mcp_server::{McpHandler, McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+static SLOW_STARTED: Notify = Notify::const_new();
+static SLOW_DROPPED: AtomicBool = AtomicBool::new(false);
+
+/// Flags the tool future as dropped, whether it finished or was aborted
+struct DropFlag;
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        SLOW_DROPPED.store(true, Ordering::SeqCst);
+    }
+}
+
+#[mcp_tool(name = "slow", description = "Takes a minute to answer")]
+async fn slow() -> McpResult<String> {
+    let _flag = DropFlag;
+    SLOW_STARTED.notify_one();
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    Ok("done".to_string())
+}
+
+/// Works on a blocking thread for a minute, stopping early once the call's token fires
+async fn block_until_cancelled(
+    session: Option<SessionContext>,
+    started: &'static Notify,
+    stopped_early: &'static Notify,
+) -> McpResult<String> {
+    let session = session.ok_or_else(|| McpError::SessionError("session required".into()))?;
+    let token = session.cancellation_token().clone();
+    // Dropping this future does not stop the blocking thread; the token does
+    let completed = tokio::task::spawn_blocking(move || {
+        started.notify_one();
+        for _ in 0..6_000 {
+            if token.is_cancelled() {
+                stopped_early.notify_one();
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        true
+    })
+    .await
+    .map_err(|e| McpError::ToolExecutionError(e.to_string()))?;
+    Ok(format!("completed: {}", completed))
+}
+
+static CRUNCH_STARTED: Notify = Notify::const_new();
+static CRUNCH_STOPPED_EARLY: Notify = Notify::const_new();
+
+#[mcp_tool(
+    name = "crunch",
+    description = "Crunches numbers on a blocking thread for a minute"
+)]
+async fn crunch(session: Option<SessionContext>) -> McpResult<String> {
+    block_until_cancelled(session, &CRUNCH_STARTED, &CRUNCH_STOPPED_EARLY).await
+}
+
+static BATCH_CRUNCH_STARTED: Notify = Notify::const_new();
+static BATCH_CRUNCH_STOPPED_EARLY: Notify = Notify::const_new();
+
+#[mcp_tool(
+    name = "batch_crunch",
+    description = "Crunch, called in an NDJSON batch"
+)]
+async fn batch_crunch(session: Option<SessionContext>) -> McpResult<String> {
+    block_until_cancelled(session, &BATCH_CRUNCH_STARTED, &BATCH_CRUNCH_STOPPED_EARLY).await
+}
+
+static LEGACY_CRUNCH_STARTED: Notify = Notify::const_new();
+static LEGACY_CRUNCH_STOPPED_EARLY: Notify = Notify::const_new();
+
+#[mcp_tool(
+    name = "legacy_crunch",
+    description = "Crunch, called by a 2024-11-05 client"
+)]
+async fn legacy_crunch(session: Option<SessionContext>) -> McpResult<String> {
+    block_until_cancelled(
+        session,
+        &LEGACY_CRUNCH_STARTED,
+        &LEGACY_CRUNCH_STOPPED_EARLY,
+    )
+    .await
+}
+
+/// Records the params of every notification it receives
+#[derive(Clone, Default)]
+struct RecordingHandler {
+    received: Arc<Mutex<Vec<Value>>>,
+}
+
+#[async_trait]
+impl McpHandler for RecordingHandler {
+    async fn handle(&self, params: Option<Value>) -> McpResult<Value> {
+        self.received
+            .lock()
+            .unwrap()
+            .push(params.unwrap_or(Value::Null));
+        Ok(json!({ "ignored": true }))
+    }
+
+    fn supported_methods(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+impl RecordingHandler {
+    /// Notifications are handled off the request path, so poll for `count` of them
+    async fn wait_for(&self, count: usize) -> Vec<Value> {
+        for _ in 0..100 {
+            let received = self.received.lock().unwrap().clone();
+            if received.len() >= count {
+                return received;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("expected {} notification(s)", count);
+    }
+}
+
+/// Serve `server` on `addr` and open a session speaking `protocol_version`
+async fn serve_with_session(
+    server: McpServer,
+    addr: std::net::SocketAddr,
+    protocol_version: &str,
+) -> (String, String) {
+    tokio::spawn(async move {
+        if let Err(e) = server.run().await {
+            eprintln!("Server error: {}", e);
+        }
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let url = format!("http://{}/mcp", addr);
+    let client = reqwest::Client::new();
+    let post = |body: Value| {
+        client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", protocol_version)
+            .json(&body)
+    };
+    let response = post(json!({
+        "jsonrpc": "2.0",
+        "id": "init",
+        "method": "initialize",
+        "params": {
+            "protocolVersion": protocol_version,
+            "capabilities": {},
+            "clientInfo": { "name": "cancel-test-client", "version": "1.0.0" }
+        }
+    }))
+    .send()
+    .await
+    .unwrap();
+    let session_id = response.headers()["Mcp-Session-Id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    post(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .header("Mcp-Session-Id", &session_id)
+        .send()
+        .await
+        .unwrap();
+    (url, session_id)
+}
+
+async fn free_address() -> std::net::SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    listener.local_addr().unwrap()
+}
+
+async fn connect(server: McpServer) -> Arc<InProcessTransport> {
+    let transport = InProcessTransport::new(server.in_process_service().await);
+    transport.connect().await.unwrap();
+    transport
+        .send_request(json!({
+            "jsonrpc": "2.0",
+            "id": "init",
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "cancel-test-client", "version": "1.0.0" }
+            }
+        }))
+        .await
+        .unwrap();
+    transport
+        .send_notification(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .await
+        .unwrap();
+    Arc::new(transport)
+}
+
+#[tokio::test]
+async fn test_cancelled_notification_drops_tool_future() {
+    let cancellations = RecordingHandler::default();
+    let server = McpServer::builder()
+        .name("cancel-test")
+        .version("1.0.0")
+        .tool_fn(slow)
+        .notification_handler("notifications/cancelled", cancellations.clone())
+        .build()
+        .unwrap();
+    let transport = connect(server).await;
+
+    let call = tokio::spawn({
+        let transport = Arc::clone(&transport);
+        async move {
+            transport
+                .send_request(json!({
+                    "jsonrpc": "2.0",
+                    "id": 42,
+                    "method": "tools/call",
+                    "params": { "name": "slow", "arguments": {} }
+                }))
+                .await
+        }
+    });
+    tokio::time::timeout(Duration::from_secs(5), SLOW_STARTED.notified())
+        .await
+        .expect("tool should start");
+    assert!(!SLOW_DROPPED.load(Ordering::SeqCst));
+
+    transport
+        .send_notification(json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/cancelled",
+            "params": { "requestId": 42, "reason": "user aborted" }
+        }))
+        .await
+        .unwrap();
+
+    // The stream ends without any JSON-RPC response for the cancelled request
+    let outcome = tokio::time::timeout(Duration::from_secs(5), call)
+        .await
+        .expect("cancelled call should finish well before the tool would")
+        .unwrap();
+    assert!(
+        outcome.is_err(),
+        "cancelled request must not be answered, got {:?}",
+        outcome
+    );
+    assert!(SLOW_DROPPED.load(Ordering::SeqCst));
+
+    // The registered handler still sees the notification
+    let received = cancellations.wait_for(1).await;
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0]["reason"], "user aborted");
+}
+
+#[tokio::test]
+async fn test_cancelled_notification_cancels_session_token() {
+    let server = McpServer::builder()
+        .name("cancel-token-test")
+        .version("1.0.0")
+        .tool_fn(crunch)
+        .build()
+        .unwrap();
+    let transport = connect(server).await;
+
+    let call = tokio::spawn({
+        let transport = Arc::clone(&transport);
+        async move {
+            transport
+                .send_request(json!({
+                    "jsonrpc": "2.0",
+                    "id": "crunch-1",
+                    "method": "tools/call",
+                    "params": { "name": "crunch", "arguments": {} }
+                }))
+                .await
+        }
+    });
+    tokio::time::timeout(Duration::from_secs(5), CRUNCH_STARTED.notified())
+        .await
+        .expect("blocking work should start");
+
+    transport
+        .send_notification(json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/cancelled",
+            "params": { "requestId": "crunch-1" }
+        }))
+        .await
+        .unwrap();
+
+    tokio::time::timeout(Duration::from_secs(5), CRUNCH_STOPPED_EARLY.notified())
+        .await
+        .expect("blocking work should see the cancellation and stop early");
+    let outcome = tokio::time::timeout(Duration::from_secs(5), call)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(outcome.is_err(), "cancelled request must not be answered");
+}
+
+#[tokio::test]
+async fn test_disconnect_mid_ndjson_batch_cancels_session_token() {
+    let addr = free_address().await;
+    let server = McpServer::builder()
+        .name("ndjson-cancel-test")
+        .version("1.0.0")
+        .tool_fn(batch_crunch)
+        .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+        .bind_address(addr)
+        .build()
+        .unwrap();
+    let (url, session_id) = serve_with_session(server, addr, "2025-11-25").await;
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, application/x-ndjson")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!([{
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": { "name": "batch_crunch", "arguments": {} }
+        }]))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.headers()["content-type"], "application/x-ndjson");
+    tokio::time::timeout(Duration::from_secs(5), BATCH_CRUNCH_STARTED.notified())
+        .await
+        .expect("blocking work should start");
+
+    // Hanging up on the NDJSON stream abandons the calls still running in it
+    drop(response);
+    tokio::time::timeout(
+        Duration::from_secs(5),
+        BATCH_CRUNCH_STOPPED_EARLY.notified(),
+    )
+    .await
+    .expect("blocking work should see the disconnect and stop early");
+}
+
+#[tokio::test]
+async fn test_disconnect_mid_legacy_post_cancels_session_token() {
+    let addr = free_address().await;
+    let server = McpServer::builder()
+        .name("legacy-cancel-test")
+        .version("1.0.0")
+        .tool_fn(legacy_crunch)
+        .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+        .bind_address(addr)
+        .build()
+        .unwrap();
+    let (url, session_id) = serve_with_session(server, addr, "2024-11-05").await;
+
+    let call = tokio::spawn(async move {
+        reqwest::Client::new()
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2024-11-05")
+            .header("Mcp-Session-Id", &session_id)
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "tools/call",
+                "params": { "name": "legacy_crunch", "arguments": {} }
+            }))
+            .send()
+            .await
+    });
+    tokio::time::timeout(Duration::from_secs(5), LEGACY_CRUNCH_STARTED.notified())
+        .await
+        .expect("blocking work should start");
+
+    // Dropping the in-flight request closes its connection
+    call.abort();
+    tokio::time::timeout(
+        Duration::from_secs(5),
+        LEGACY_CRUNCH_STOPPED_EARLY.notified(),
+    )
+    .await
+    .expect("blocking work should see the disconnect and stop early");
+}
+
+#[tokio::test]
+async fn test_notification_handler_receives_custom_notification() {
+    let handler = RecordingHandler::default();
+    let server = McpServer::builder()
+        .name("notification-test")
+        .version("1.0.0")
+        .notification_handler("notifications/custom/ping", handler.clone())
+        .build()
+        .unwrap();
+    let transport = connect(server).await;
+
+    transport
+        .send_notification(json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/custom/ping",
+            "params": { "seq": 1 }
+        }))
+        .await
+        .unwrap();
+
+    let received = handler.wait_for(1).await;
+    assert_eq!(received, vec![json!({ "seq": 1 })]);
+
+    // Cancelling an id that is not in flight is a no-op
+    transport
+        .send_notification(json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/cancelled",
+            "params": { "requestId": "missing" }
+        }))
+        .await
+        .unwrap();
+}
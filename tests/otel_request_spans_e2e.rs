@@ -0,0 +1,189 @@
+//! E2E tests for the `otel` feature: one OpenTelemetry span per JSON-RPC request,
+//! parented on the caller's `traceparent` header.
+
+use mcp_e2e_shared::spawn_server;
+use opentelemetry::trace::{SpanKind, Status, TraceId, TracerProvider};
+use opentelemetry::{KeyValue, Value as OtelValue};
+use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use turul_http_mcp_server::RequestTracing;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::McpServer;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+const PARENT_TRACE: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+
+#[mcp_tool(name = "echo", description = "Echo the input back")]
+async fn echo(#[param(description = "Text to echo")] text: String) -> McpResult<String> {
+    Ok(text)
+}
+
+struct TracedServer {
+    client: reqwest::Client,
+    url: String,
+    exporter: InMemorySpanExporter,
+    _provider: SdkTracerProvider,
+}
+
+impl TracedServer {
+    async fn start() -> Self {
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+
+        let url = spawn_server(
+            McpServer::builder()
+                .name("otel-test")
+                .version("1.0.0")
+                .tool_fn(echo)
+                .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+                .request_tracing(RequestTracing::new(provider.tracer("otel-test"))),
+        )
+        .await;
+
+        Self {
+            client: reqwest::Client::new(),
+            url,
+            exporter,
+            _provider: provider,
+        }
+    }
+
+    async fn post(&self, session_id: Option<&str>, body: Value) -> reqwest::Response {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .header(
+                "traceparent",
+                format!("00-{PARENT_TRACE}-00f067aa0ba902b7-01"),
+            );
+        if let Some(session_id) = session_id {
+            request = request.header("Mcp-Session-Id", session_id);
+        }
+        request.json(&body).send().await.unwrap()
+    }
+
+    async fn initialize(&self) -> String {
+        let response = self
+            .post(
+                None,
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": "initialize",
+                    "id": 1,
+                    "params": {
+                        "protocolVersion": "2025-11-25",
+                        "capabilities": {},
+                        "clientInfo": { "name": "otel-client", "version": "1.0.0" }
+                    }
+                }),
+            )
+            .await;
+        let session_id = response
+            .headers()
+            .get("Mcp-Session-Id")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        self.post(
+            Some(&session_id),
+            json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+        )
+        .await;
+        session_id
+    }
+
+    async fn call(&self, session_id: &str, id: u64, method: &str, params: Value) {
+        let response = self
+            .post(
+                Some(session_id),
+                json!({ "jsonrpc": "2.0", "method": method, "id": id, "params": params }),
+            )
+            .await;
+        // Read the body so the request has finished before spans are inspected
+        response.text().await.unwrap();
+    }
+
+    fn span(&self, name: &str) -> SpanData {
+        let spans = self.exporter.get_finished_spans().unwrap();
+        let mut matching: Vec<_> = spans.into_iter().filter(|s| s.name == name).collect();
+        assert_eq!(matching.len(), 1, "expected one {name} span");
+        matching.remove(0)
+    }
+}
+
+fn attribute(span: &SpanData, key: &str) -> Option<OtelValue> {
+    span.attributes
+        .iter()
+        .find(|kv| kv.key.as_str() == key)
+        .map(|kv: &KeyValue| kv.value.clone())
+}
+
+#[tokio::test]
+async fn test_each_request_gets_a_span_under_the_caller_trace() {
+    let server = TracedServer::start().await;
+    let session_id = server.initialize().await;
+    server.call(&session_id, 2, "tools/list", json!({})).await;
+    server
+        .call(
+            &session_id,
+            3,
+            "tools/call",
+            json!({ "name": "echo", "arguments": { "text": "hi" } }),
+        )
+        .await;
+
+    let parent = TraceId::from_hex(PARENT_TRACE).unwrap();
+    for method in ["initialize", "tools/list", "tools/call"] {
+        let span = server.span(method);
+        assert_eq!(span.span_kind, SpanKind::Server);
+        assert_eq!(span.span_context.trace_id(), parent, "{method} trace id");
+        assert_eq!(span.status, Status::Ok, "{method} status");
+        assert_eq!(attribute(&span, "rpc.method"), Some(method.into()));
+    }
+
+    let list = server.span("tools/list");
+    assert_eq!(
+        attribute(&list, "mcp.session.id"),
+        Some(session_id.clone().into())
+    );
+    assert_eq!(attribute(&list, "mcp.tool.name"), None);
+
+    let call = server.span("tools/call");
+    assert_eq!(attribute(&call, "mcp.tool.name"), Some("echo".into()));
+    assert_eq!(attribute(&call, "mcp.session.id"), Some(session_id.into()));
+
+    // Notifications are not traced
+    let spans = server.exporter.get_finished_spans().unwrap();
+    assert!(spans.iter().all(|s| !s.name.starts_with("notifications/")));
+}
+
+#[tokio::test]
+async fn test_error_response_marks_span_as_error() {
+    let server = TracedServer::start().await;
+    let session_id = server.initialize().await;
+    server
+        .call(
+            &session_id,
+            2,
+            "tools/call",
+            json!({ "name": "missing", "arguments": {} }),
+        )
+        .await;
+
+    let span = server.span("tools/call");
+    assert!(
+        matches!(span.status, Status::Error { .. }),
+        "{:?}",
+        span.status
+    );
+    assert_eq!(attribute(&span, "mcp.tool.name"), Some("missing".into()));
+    assert!(attribute(&span, "rpc.jsonrpc.error_code").is_some());
+}
@@ -0,0 +1,150 @@
+//! Tests for tool parameter aliases (`#[param(aliases = [...])]` / `ToolBuilder::param_aliases`)
+
+use serde_json::{Value, json};
+use tracing_test::traced_test;
+use turul_mcp_builders::ToolBuilder;
+use turul_mcp_builders::traits::HasInputSchema;
+use turul_mcp_derive::{McpTool, mcp_tool};
+use turul_mcp_protocol::tools::CallToolResult;
+use turul_mcp_server::{McpResult, McpTool as McpToolTrait, SessionContext};
+
+#[derive(McpTool, Default)]
+#[tool(name = "search", description = "Search the catalogue")]
+struct SearchTool {
+    #[param(description = "Search terms", aliases = ["q", "search_text"])]
+    query: String,
+    #[param(description = "Maximum results", optional)]
+    limit: Option<u32>,
+}
+
+impl SearchTool {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(format!("{}:{}", self.query, self.limit.unwrap_or(10)))
+    }
+}
+
+#[mcp_tool(name = "greet", description = "Greet someone")]
+async fn greet(
+    #[param(description = "Who to greet", aliases = ["user"])] name: String,
+) -> McpResult<String> {
+    Ok(format!("Hello, {}", name))
+}
+
+fn result_text(result: &CallToolResult) -> String {
+    serde_json::to_string(&result.structured_content).unwrap()
+}
+
+fn schema_properties(tool: &dyn HasInputSchema) -> Vec<String> {
+    let mut names: Vec<String> = tool
+        .input_schema()
+        .properties
+        .as_ref()
+        .map(|properties| properties.keys().cloned().collect())
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_derive_accepts_alias() {
+    let result = SearchTool::default()
+        .call(json!({"q": "rust"}), None)
+        .await
+        .unwrap();
+
+    assert!(result_text(&result).contains("rust:10"));
+    assert!(logs_contain(
+        "Tool 'search': parameter 'q' is deprecated, use 'query' instead"
+    ));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_derive_accepts_current_name() {
+    let result = SearchTool::default()
+        .call(json!({"query": "rust", "limit": 3}), None)
+        .await
+        .unwrap();
+
+    assert!(result_text(&result).contains("rust:3"));
+    assert!(!logs_contain("deprecated"));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_derive_current_name_wins_over_alias() {
+    let result = SearchTool::default()
+        .call(json!({"query": "current", "search_text": "old"}), None)
+        .await
+        .unwrap();
+
+    assert!(result_text(&result).contains("current:10"));
+    assert!(logs_contain(
+        "Tool 'search': ignoring deprecated parameter 'search_text' because 'query' was also supplied"
+    ));
+}
+
+#[tokio::test]
+async fn test_derive_missing_parameter_still_fails() {
+    let err = SearchTool::default()
+        .call(json!({"limit": 3}), None)
+        .await
+        .expect_err("neither the name nor an alias was supplied");
+    assert!(err.to_string().contains("query"));
+}
+
+#[test]
+fn test_schema_advertises_only_current_name() {
+    assert_eq!(
+        schema_properties(&SearchTool::default()),
+        ["limit", "query"]
+    );
+    assert_eq!(schema_properties(&greet()), ["name"]);
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_function_macro_accepts_alias() {
+    let tool = greet();
+
+    let result = tool.call(json!({"user": "Ada"}), None).await.unwrap();
+    assert!(result_text(&result).contains("Hello, Ada"));
+    assert!(logs_contain(
+        "Tool 'greet': parameter 'user' is deprecated, use 'name' instead"
+    ));
+
+    let result = tool
+        .call(json!({"name": "Grace", "user": "Ada"}), None)
+        .await
+        .unwrap();
+    assert!(result_text(&result).contains("Hello, Grace"));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_builder_accepts_alias() {
+    let tool = ToolBuilder::new("echo")
+        .string_param("message", "Text to echo")
+        .param_aliases("message", ["text"])
+        .execute(|args| async move { Ok(args) })
+        .build()
+        .unwrap();
+
+    assert_eq!(schema_properties(&tool), ["message"]);
+
+    let echoed: Value = tool.execute(json!({"text": "hi"})).await.unwrap();
+    assert_eq!(echoed, json!({"message": "hi"}));
+    assert!(logs_contain(
+        "Tool 'echo': parameter 'text' is deprecated, use 'message' instead"
+    ));
+
+    let echoed = tool.execute(json!({"message": "hello"})).await.unwrap();
+    assert_eq!(echoed, json!({"message": "hello"}));
+
+    let echoed = tool
+        .execute(json!({"message": "new", "text": "old"}))
+        .await
+        .unwrap();
+    assert_eq!(echoed, json!({"message": "new"}));
+}
@@ -0,0 +1,97 @@
+//! Tests for parameter defaults (`#[param(default = ...)]`)
+
+use serde_json::{Value, json};
+use turul_mcp_builders::traits::HasInputSchema;
+use turul_mcp_derive::{McpTool, mcp_tool};
+use turul_mcp_server::{McpResult, McpTool as McpToolTrait, SessionContext};
+
+#[derive(McpTool, Default)]
+#[tool(name = "search", description = "Search documents")]
+struct Search {
+    #[param(description = "Search query")]
+    query: String,
+    #[param(description = "Maximum results", default = 10)]
+    limit: Option<u32>,
+    #[param(description = "Result language", default = "en")]
+    language: Option<String>,
+    #[param(description = "Include archived documents", default = true)]
+    archived: Option<bool>,
+    #[param(description = "Score threshold", default = -1.5, optional)]
+    threshold: f64,
+}
+
+impl Search {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<Value> {
+        Ok(json!({
+            "query": self.query,
+            "limit": self.limit,
+            "language": self.language,
+            "archived": self.archived,
+            "threshold": self.threshold,
+        }))
+    }
+}
+
+#[mcp_tool(name = "repeat", description = "Repeat a word")]
+async fn repeat(
+    #[param(description = "Word to repeat")] word: String,
+    #[param(description = "How many times", default = 3)] times: i64,
+) -> McpResult<String> {
+    Ok(vec![word; times as usize].join(" "))
+}
+
+async fn call_search(args: Value) -> Value {
+    let result = Search::default().call(args, None).await.unwrap();
+    result.structured_content.unwrap()["output"].clone()
+}
+
+#[test]
+fn test_default_in_schema() {
+    let tool = Search::default();
+    let schema = serde_json::to_value(tool.input_schema()).unwrap();
+    let properties = &schema["properties"];
+    assert_eq!(properties["limit"]["default"], 10);
+    assert_eq!(properties["language"]["default"], "en");
+    assert_eq!(properties["archived"]["default"], true);
+    assert_eq!(properties["threshold"]["default"], -1.5);
+    assert!(properties["query"].get("default").is_none());
+
+    let schema = serde_json::to_value(repeat().input_schema()).unwrap();
+    assert_eq!(schema["properties"]["times"]["default"], 3);
+    // A parameter with a default is not required
+    assert_eq!(schema["required"], json!(["word"]));
+}
+
+#[tokio::test]
+async fn test_default_applied_when_omitted() {
+    let output = call_search(json!({"query": "rust"})).await;
+    assert_eq!(output["limit"], 10);
+    assert_eq!(output["language"], "en");
+    assert_eq!(output["archived"], true);
+    assert_eq!(output["threshold"], -1.5);
+
+    let result = repeat().call(json!({"word": "hi"}), None).await.unwrap();
+    assert_eq!(result.structured_content.unwrap()["result"], "hi hi hi");
+}
+
+#[tokio::test]
+async fn test_supplied_value_overrides_default() {
+    let output = call_search(json!({
+        "query": "rust",
+        "limit": 2,
+        "language": "fr",
+        "archived": false,
+        "threshold": 0.5
+    }))
+    .await;
+    assert_eq!(output["limit"], 2);
+    assert_eq!(output["language"], "fr");
+    assert_eq!(output["archived"], false);
+    assert_eq!(output["threshold"], 0.5);
+}
+
+#[test]
+fn test_param_default_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("ui/param_default_mismatch.rs");
+}
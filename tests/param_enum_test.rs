@@ -0,0 +1,180 @@
+//! Tests for fieldless enum parameters (`#[derive(JsonSchema)]` on an enum)
+
+use serde::Deserialize;
+use serde_json::json;
+use turul_mcp_builders::traits::HasInputSchema;
+use turul_mcp_derive::{JsonSchema, McpTool, mcp_tool};
+use turul_mcp_protocol::McpError;
+use turul_mcp_protocol::schema::JsonSchema as Schema;
+use turul_mcp_protocol::tools::CallToolResult;
+use turul_mcp_server::{McpResult, McpTool as McpToolTrait, SessionContext};
+
+#[derive(JsonSchema, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum Operation {
+    Add,
+    Subtract,
+    #[serde(rename = "times")]
+    Multiply,
+}
+
+#[derive(JsonSchema, Deserialize, Debug, Clone, Copy, PartialEq)]
+enum Rounding {
+    Floor,
+    Ceil,
+}
+
+#[derive(schemars::JsonSchema, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum Unit {
+    Metric,
+    Imperial,
+}
+
+#[derive(McpTool, Default)]
+#[tool(name = "calculate", description = "Combine two numbers")]
+struct Calculate {
+    #[param(description = "Left operand")]
+    a: f64,
+    #[param(description = "Right operand")]
+    b: f64,
+    #[param(description = "Operation to apply")]
+    operation: Option<Operation>,
+    #[param(description = "How to round the result", optional)]
+    rounding: Option<Rounding>,
+}
+
+impl Calculate {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<f64> {
+        let value = match self.operation.unwrap_or(Operation::Add) {
+            Operation::Add => self.a + self.b,
+            Operation::Subtract => self.a - self.b,
+            Operation::Multiply => self.a * self.b,
+        };
+        Ok(match self.rounding {
+            Some(Rounding::Floor) => value.floor(),
+            Some(Rounding::Ceil) => value.ceil(),
+            None => value,
+        })
+    }
+}
+
+#[mcp_tool(name = "apply", description = "Apply an operation to two numbers")]
+async fn apply(
+    #[param(description = "Operation to apply")] operation: Operation,
+    #[param(description = "Left operand")] a: f64,
+    #[param(description = "Right operand")] b: f64,
+) -> McpResult<f64> {
+    Ok(match operation {
+        Operation::Add => a + b,
+        Operation::Subtract => a - b,
+        Operation::Multiply => a * b,
+    })
+}
+
+#[mcp_tool(name = "convert", description = "Convert a length")]
+async fn convert(
+    #[param(description = "Target unit system")] unit: Unit,
+    #[param(description = "Length in metres")] metres: f64,
+) -> McpResult<f64> {
+    Ok(match unit {
+        Unit::Metric => metres,
+        Unit::Imperial => metres * 3.28084,
+    })
+}
+
+fn output_number(result: &CallToolResult) -> f64 {
+    let output = serde_json::to_value(&result.structured_content).unwrap();
+    output
+        .as_object()
+        .and_then(|fields| fields.values().next())
+        .and_then(|value| value.as_f64())
+        .expect("numeric structured output")
+}
+
+fn property_enum(tool: &dyn HasInputSchema, name: &str) -> Option<Vec<String>> {
+    match tool.input_schema().properties.as_ref()?.get(name)? {
+        Schema::String { enum_values, .. } => enum_values.clone(),
+        _ => None,
+    }
+}
+
+fn property_description(tool: &dyn HasInputSchema, name: &str) -> Option<String> {
+    match tool.input_schema().properties.as_ref()?.get(name)? {
+        Schema::String { description, .. } => description.clone(),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_enum_schema_uses_serde_names() {
+    let tool = Calculate::default();
+    assert_eq!(
+        property_enum(&tool, "operation").unwrap(),
+        ["add", "subtract", "times"]
+    );
+    assert_eq!(property_enum(&tool, "rounding").unwrap(), ["Floor", "Ceil"]);
+    assert_eq!(
+        property_description(&tool, "operation").as_deref(),
+        Some("Operation to apply")
+    );
+
+    let required = tool.input_schema().required.clone().unwrap_or_default();
+    assert!(!required.contains(&"operation".to_string()));
+    assert!(!required.contains(&"rounding".to_string()));
+
+    assert_eq!(
+        property_enum(&apply(), "operation").unwrap(),
+        ["add", "subtract", "times"]
+    );
+}
+
+#[test]
+fn test_schemars_enum_still_supported() {
+    assert_eq!(
+        property_enum(&convert(), "unit").unwrap(),
+        ["metric", "imperial"]
+    );
+}
+
+#[tokio::test]
+async fn test_enum_argument_is_deserialized() {
+    let result = Calculate::default()
+        .call(
+            json!({"a": 2.5, "b": 2.0, "operation": "times", "rounding": "Ceil"}),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output_number(&result), 5.0);
+
+    let result = Calculate::default()
+        .call(json!({"a": 1.0, "b": 2.0}), None)
+        .await
+        .unwrap();
+    assert_eq!(output_number(&result), 3.0);
+
+    let result = apply()
+        .call(json!({"operation": "subtract", "a": 5.0, "b": 2.0}), None)
+        .await
+        .unwrap();
+    assert_eq!(output_number(&result), 3.0);
+}
+
+#[tokio::test]
+async fn test_unknown_variant_is_rejected() {
+    let err = apply()
+        .call(json!({"operation": "Multiply", "a": 5.0, "b": 2.0}), None)
+        .await
+        .expect_err("serde names are lowercase, `Multiply` is renamed to `times`");
+    assert!(matches!(
+        err,
+        McpError::InvalidParameterType { ref param, .. } if param == "operation"
+    ));
+}
+
+#[test]
+fn test_param_enum_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("ui/param_enum_data_variant.rs");
+}
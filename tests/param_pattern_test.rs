@@ -0,0 +1,116 @@
+//! Tests for regex-constrained tool parameters (`#[param(pattern = "...")]`)
+
+use serde_json::json;
+use turul_mcp_builders::traits::HasInputSchema;
+use turul_mcp_derive::{McpTool, mcp_tool};
+use turul_mcp_protocol::McpError;
+use turul_mcp_protocol::schema::JsonSchema;
+use turul_mcp_server::{McpResult, McpTool as McpToolTrait, SessionContext};
+
+#[derive(McpTool, Default)]
+#[tool(name = "lookup_country", description = "Look up a country")]
+struct LookupCountry {
+    #[param(description = "ISO 3166-1 alpha-2 code", pattern = "^[A-Z]{2}$")]
+    code: String,
+    #[param(description = "Optional region code", pattern = "^[0-9]{3}$", optional)]
+    region: Option<String>,
+}
+
+impl LookupCountry {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(match &self.region {
+            Some(region) => format!("{}-{}", self.code, region),
+            None => self.code.clone(),
+        })
+    }
+}
+
+#[mcp_tool(name = "check_version", description = "Check a version string")]
+async fn check_version(
+    #[param(description = "Semantic version", pattern = r"^\d+\.\d+\.\d+$")] version: String,
+) -> McpResult<String> {
+    Ok(version)
+}
+
+fn property_pattern(tool: &dyn HasInputSchema, name: &str) -> Option<String> {
+    match tool.input_schema().properties.as_ref()?.get(name)? {
+        JsonSchema::String { pattern, .. } => pattern.clone(),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_pattern_in_schema() {
+    let tool = LookupCountry::default();
+    assert_eq!(
+        property_pattern(&tool, "code").as_deref(),
+        Some("^[A-Z]{2}$")
+    );
+    assert_eq!(
+        property_pattern(&tool, "region").as_deref(),
+        Some("^[0-9]{3}$")
+    );
+    assert_eq!(
+        property_pattern(&check_version(), "version").as_deref(),
+        Some(r"^\d+\.\d+\.\d+$")
+    );
+}
+
+#[tokio::test]
+async fn test_matching_value_accepted() {
+    let result = LookupCountry::default()
+        .call(json!({"code": "AU", "region": "036"}), None)
+        .await;
+    assert!(result.is_ok(), "{:?}", result.err());
+
+    let result = check_version()
+        .call(json!({"version": "1.2.3"}), None)
+        .await;
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_mismatching_value_rejected_before_execute() {
+    let err = LookupCountry::default()
+        .call(json!({"code": "Australia"}), None)
+        .await
+        .expect_err("code does not match the pattern");
+    assert!(matches!(
+        err,
+        McpError::ParameterOutOfRange { ref param, .. } if param == "code"
+    ));
+    assert!(err.to_string().contains("^[A-Z]{2}$"));
+
+    let err = check_version()
+        .call(json!({"version": "v1"}), None)
+        .await
+        .expect_err("version does not match the pattern");
+    assert!(matches!(
+        err,
+        McpError::ParameterOutOfRange { ref param, .. } if param == "version"
+    ));
+}
+
+#[tokio::test]
+async fn test_absent_optional_value_skips_check() {
+    let result = LookupCountry::default()
+        .call(json!({"code": "NZ"}), None)
+        .await;
+    assert!(result.is_ok(), "{:?}", result.err());
+
+    let err = LookupCountry::default()
+        .call(json!({"code": "NZ", "region": "north"}), None)
+        .await
+        .expect_err("region does not match the pattern");
+    assert!(matches!(
+        err,
+        McpError::ParameterOutOfRange { ref param, .. } if param == "region"
+    ));
+}
+
+#[test]
+fn test_param_pattern_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("ui/param_pattern_pass.rs");
+    t.compile_fail("ui/param_pattern_invalid.rs");
+}
@@ -0,0 +1,79 @@
+//! Tests for parameter display titles (`#[param(title = "...")]`)
+
+use serde_json::{Value, json};
+use turul_mcp_builders::traits::HasInputSchema;
+use turul_mcp_derive::{McpTool, mcp_tool};
+use turul_mcp_protocol::McpError;
+use turul_mcp_server::{McpResult, McpTool as McpToolTrait, SessionContext};
+
+#[derive(McpTool, Default)]
+#[tool(name = "divide", description = "Divide two numbers")]
+struct DivideTool {
+    #[param(title = "First Operand", description = "Dividend")]
+    a: f64,
+    #[param(title = "Second Operand", description = "Divisor")]
+    b: f64,
+    #[param(description = "Decimal places", optional)]
+    precision: Option<u32>,
+}
+
+impl DivideTool {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<f64> {
+        let places = 10f64.powi(self.precision.unwrap_or(2) as i32);
+        Ok((self.a / self.b * places).round() / places)
+    }
+}
+
+#[mcp_tool(name = "rename_file", description = "Rename a file")]
+async fn rename_file(
+    #[param(title = "Current Path", description = "File to rename")] from_path: String,
+    #[param(description = "New file name")] to_name: String,
+) -> McpResult<String> {
+    Ok(format!("{} -> {}", from_path, to_name))
+}
+
+fn properties_json(tool: &dyn HasInputSchema) -> Value {
+    serde_json::to_value(tool.input_schema().properties.as_ref().unwrap()).unwrap()
+}
+
+#[test]
+fn test_derive_schema_snapshot() {
+    assert_eq!(
+        properties_json(&DivideTool::default()),
+        json!({
+            "a": {"type": "number", "title": "First Operand", "description": "Dividend"},
+            "b": {"type": "number", "title": "Second Operand", "description": "Divisor"},
+            "precision": {"type": "integer", "description": "Decimal places"}
+        })
+    );
+}
+
+#[test]
+fn test_function_macro_schema_snapshot() {
+    assert_eq!(
+        properties_json(&rename_file()),
+        json!({
+            "from_path": {"type": "string", "title": "Current Path", "description": "File to rename"},
+            "to_name": {"type": "string", "description": "New file name"}
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_arguments_use_field_names() {
+    let result = DivideTool::default()
+        .call(json!({"a": 7.0, "b": 2.0}), None)
+        .await
+        .unwrap();
+    assert!(
+        serde_json::to_string(&result.structured_content)
+            .unwrap()
+            .contains("3.5")
+    );
+
+    let err = DivideTool::default()
+        .call(json!({"First Operand": 7.0, "b": 2.0}), None)
+        .await
+        .expect_err("the title is not an argument name");
+    assert!(matches!(err, McpError::InvalidParameterType { ref param, .. } if param == "a"));
+}
@@ -0,0 +1,106 @@
+//! E2E tests for per-request SSE on POST.
+//!
+//! A `tools/call` POSTed with `Accept: text/event-stream` alone must be answered
+//! as an SSE stream carrying the tool's progress notifications and then the
+//! JSON-RPC response, after which the server closes the stream. The same call
+//! with `Accept: application/json` gets a plain JSON body.
+
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "build", description = "Builds the project in two stages")]
+async fn build(session: Option<SessionContext>) -> McpResult<String> {
+    let progress = session.expect("session context").progress(None);
+    progress.report(1.0, Some(2.0), Some("Compiling")).await;
+    sleep(Duration::from_millis(20)).await;
+    progress.report(2.0, Some(2.0), Some("Linking")).await;
+    Ok("built".to_string())
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("post-sse-upgrade-test")
+            .version("1.0.0")
+            .tool_fn(build)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+async fn call_build(accept: &str) -> reqwest::Response {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &url).await;
+
+    client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("Accept", accept)
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 7,
+            "params": {
+                "name": "build",
+                "arguments": {},
+                "_meta": { "progressToken": "build-1" }
+            }
+        }))
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_event_stream_accept_streams_progress_then_result() {
+    let response = call_build("text/event-stream").await;
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+
+    // The body only ends once the server closes the stream after the result
+    let body = timeout(Duration::from_secs(5), response.text())
+        .await
+        .expect("stream should close after the result")
+        .unwrap();
+    let frames: Vec<Value> = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str(data).ok())
+        .collect();
+
+    assert_eq!(frames.len(), 3, "frames: {frames:?}");
+    for (frame, (progress, message)) in frames.iter().zip([(1.0, "Compiling"), (2.0, "Linking")]) {
+        assert_eq!(frame["method"], "notifications/progress");
+        assert_eq!(frame["params"]["progressToken"], "build-1");
+        assert_eq!(frame["params"]["progress"], progress);
+        assert_eq!(frame["params"]["message"], message);
+    }
+    let result = &frames[2];
+    assert_eq!(result["id"], 7);
+    assert_eq!(result["result"]["isError"], false);
+}
+
+#[tokio::test]
+async fn test_json_accept_gets_plain_response() {
+    let response = call_build("application/json").await;
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "application/json"
+    );
+
+    let result: Value = response.json().await.unwrap();
+    assert_eq!(result["id"], 7);
+    assert_eq!(result["result"]["isError"], false);
+}
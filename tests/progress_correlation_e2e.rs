@@ -0,0 +1,75 @@
+//! E2E test for request/progress-token correlation between client and server.
+//!
+//! The client derives a `progressToken` from its request id and session with
+//! `McpClient::next_correlation()`; a tool recovers both on the server through
+//! `SessionContext::progress_correlation()`.
+
+use mcp_e2e_shared::spawn_server;
+use serde_json::json;
+use std::sync::Arc;
+use turul_mcp_client::transport::HttpTransport;
+use turul_mcp_client::{ClientConfig, McpClient};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_json_rpc_server::RequestId;
+use turul_mcp_protocol::{McpError, McpResult};
+use turul_mcp_server::{McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(
+    name = "whocalled",
+    description = "Report the correlated request and session"
+)]
+async fn whocalled(session: Option<SessionContext>) -> McpResult<serde_json::Value> {
+    let session = session.ok_or_else(|| McpError::SessionError("session required".into()))?;
+    let correlation = session
+        .progress_correlation()
+        .ok_or_else(|| McpError::InvalidParameters("no correlation token".into()))?;
+    if correlation.session_id() != Some(session.session_id.as_str()) {
+        return Err(McpError::InvalidParameters(
+            "correlation belongs to another session".into(),
+        ));
+    }
+    Ok(json!({
+        "requestId": correlation.request_id(),
+        "sessionId": correlation.session_id(),
+    }))
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("progress-correlation-test")
+            .version("1.0.0")
+            .tool_fn(whocalled)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_server_recovers_client_correlation() {
+    let server_url = start_server().await;
+    let client = McpClient::new(
+        Box::new(HttpTransport::new(&server_url).unwrap()),
+        ClientConfig::default(),
+    );
+    client.connect().await.unwrap();
+    let session_id = client.session_info().await.session_id.unwrap();
+
+    let correlation = client.next_correlation().await;
+    assert_eq!(correlation.session_id(), Some(session_id.as_str()));
+    let RequestId::String(request_id) = correlation.request_id().clone() else {
+        panic!("client request ids are strings");
+    };
+
+    let result = client
+        .call_tool_with_progress(&correlation, "whocalled", json!({}))
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(false));
+    let reported = &result.structured_content.unwrap()["result"];
+    assert_eq!(reported["requestId"], request_id);
+    assert_eq!(reported["sessionId"], session_id);
+
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,117 @@
+//! E2E tests for `SessionContext::progress`.
+//!
+//! A tool reports progress through a `ProgressReporter`; the client must receive the
+//! `notifications/progress` frames on the POST SSE stream, in order, before the result.
+
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "import_rows", description = "Imports rows in three steps")]
+async fn import_rows(session: Option<SessionContext>) -> McpResult<String> {
+    let progress = session.expect("session context").progress(None);
+    for (step, message) in ["Reading", "Validating", "Writing"].into_iter().enumerate() {
+        progress
+            .report((step + 1) as f64, Some(3.0), Some(message))
+            .await;
+    }
+    Ok("imported".to_string())
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("progress-reporter-test")
+            .version("1.0.0")
+            .tool_fn(import_rows)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+/// Call `import_rows` over POST SSE and return every JSON-RPC frame in arrival order
+async fn call_import_rows(params: Value) -> Vec<Value> {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &url).await;
+
+    let response = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "text/event-stream, application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 2,
+            "params": params
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+
+    response
+        .text()
+        .await
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str(data).ok())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_progress_steps_arrive_in_order_before_result() {
+    let frames = call_import_rows(json!({
+        "name": "import_rows",
+        "arguments": {},
+        "_meta": { "progressToken": "import-42" }
+    }))
+    .await;
+
+    let progress: Vec<&Value> = frames
+        .iter()
+        .filter(|frame| frame["method"] == "notifications/progress")
+        .map(|frame| &frame["params"])
+        .collect();
+    assert_eq!(progress.len(), 3, "frames: {frames:?}");
+    for (i, (params, message)) in progress
+        .iter()
+        .zip(["Reading", "Validating", "Writing"])
+        .enumerate()
+    {
+        assert_eq!(params["progressToken"], "import-42");
+        assert_eq!(params["progress"], (i + 1) as f64);
+        assert_eq!(params["total"], 3.0);
+        assert_eq!(params["message"], message);
+    }
+
+    let last = frames.last().unwrap();
+    assert_eq!(last["id"], 2);
+    assert_eq!(last["result"]["structuredContent"]["result"], "imported");
+}
+
+#[tokio::test]
+async fn test_no_progress_without_token() {
+    let frames = call_import_rows(json!({ "name": "import_rows", "arguments": {} })).await;
+
+    assert!(
+        frames
+            .iter()
+            .all(|frame| frame["method"] != "notifications/progress"),
+        "frames: {frames:?}"
+    );
+    assert_eq!(
+        frames.last().unwrap()["result"]["structuredContent"]["result"],
+        "imported"
+    );
+}
@@ -0,0 +1,114 @@
+//! E2E tests for request deadlines: `SessionContext::remaining()` counts down from
+//! the server's `request_timeout`, and a `_meta.timeoutMs` hint shortens it.
+
+use std::time::Duration;
+
+use serde_json::{Value, json};
+use turul_mcp_client::transport::{InProcessTransport, Transport};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, SessionContext};
+
+/// Reads the remaining budget three times, 50ms apart, and returns the readings in ms
+#[mcp_tool(name = "budget", description = "Reports the remaining time budget")]
+async fn budget(session: Option<SessionContext>) -> McpResult<String> {
+    let session = session.expect("tools/call carries a session");
+    let mut readings = Vec::new();
+    for _ in 0..3 {
+        readings.push(session.remaining().map(|left| left.as_millis() as u64));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    Ok(serde_json::to_string(&readings).unwrap())
+}
+
+async fn connect(request_timeout: Option<Duration>) -> InProcessTransport {
+    let mut builder = McpServer::builder()
+        .name("deadline-test")
+        .version("1.0.0")
+        .tool_fn(budget);
+    if let Some(timeout) = request_timeout {
+        builder = builder.request_timeout(timeout);
+    }
+    let server = builder.build().unwrap();
+
+    let transport = InProcessTransport::new(server.in_process_service().await);
+    transport.connect().await.unwrap();
+    transport
+        .send_request(json!({
+            "jsonrpc": "2.0",
+            "id": "init",
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "deadline-test-client", "version": "1.0.0" }
+            }
+        }))
+        .await
+        .unwrap();
+    transport
+        .send_notification(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .await
+        .unwrap();
+    transport
+}
+
+async fn call_budget(transport: &InProcessTransport, params: Value) -> Vec<Option<u64>> {
+    let response = transport
+        .send_request(json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": params
+        }))
+        .await
+        .unwrap();
+    let readings = response["result"]["structuredContent"]["result"]
+        .as_str()
+        .unwrap_or_else(|| panic!("response: {response}"));
+    serde_json::from_str(readings).unwrap()
+}
+
+#[tokio::test]
+async fn test_remaining_decreases_across_delays() {
+    let transport = connect(Some(Duration::from_secs(30))).await;
+
+    let readings = call_budget(&transport, json!({ "name": "budget", "arguments": {} })).await;
+    let readings: Vec<u64> = readings.into_iter().map(Option::unwrap).collect();
+
+    assert!(readings[0] <= 30_000, "readings: {readings:?}");
+    assert!(readings[0] > 29_000, "readings: {readings:?}");
+    assert!(readings[1] < readings[0], "readings: {readings:?}");
+    assert!(readings[2] < readings[1], "readings: {readings:?}");
+    // Each 50ms sleep shows up in the countdown
+    assert!(readings[0] - readings[2] >= 90, "readings: {readings:?}");
+}
+
+#[tokio::test]
+async fn test_meta_hint_shortens_budget() {
+    let transport = connect(Some(Duration::from_secs(30))).await;
+
+    let readings = call_budget(
+        &transport,
+        json!({ "name": "budget", "arguments": {}, "_meta": { "timeoutMs": 2000 } }),
+    )
+    .await;
+    let first = readings[0].unwrap();
+    assert!(first <= 2000 && first > 1000, "readings: {readings:?}");
+    assert!(readings[2].unwrap() < first, "readings: {readings:?}");
+}
+
+#[tokio::test]
+async fn test_meta_hint_alone_sets_deadline() {
+    let transport = connect(None).await;
+
+    let readings = call_budget(&transport, json!({ "name": "budget", "arguments": {} })).await;
+    assert_eq!(readings, vec![None, None, None]);
+
+    let readings = call_budget(
+        &transport,
+        json!({ "name": "budget", "arguments": {}, "_meta": { "timeoutMs": 5000 } }),
+    )
+    .await;
+    assert!(readings[0].unwrap() <= 5000, "readings: {readings:?}");
+}
@@ -0,0 +1,135 @@
+//! E2E tests for the global request interceptor.
+//!
+//! Verifies that an interceptor installed via `McpServer::builder().request_interceptor(..)`
+//! sees every parsed JSON-RPC request, can inject a default `_meta` that a tool reads
+//! through `SessionContext::request_meta()`, and can short-circuit with a JSON-RPC error.
+
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_json_rpc_server::RequestParams;
+use turul_mcp_protocol::{McpError, McpResult};
+use turul_mcp_server::{McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "whoami", description = "Report the tenant from request _meta")]
+async fn whoami(session: Option<SessionContext>) -> McpResult<String> {
+    let session = session.ok_or_else(|| McpError::SessionError("session required".into()))?;
+    let tenant = session
+        .request_meta()
+        .and_then(|meta| meta.extra.get("tenant"))
+        .and_then(|t| t.as_str())
+        .unwrap_or("none")
+        .to_string();
+    Ok(tenant)
+}
+
+async fn start_intercepted_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("request-interceptor-test")
+            .version("1.0.0")
+            .tool_fn(whoami)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+            .request_interceptor(|req| {
+                if req.method == "tools/call"
+                    && let Some(RequestParams::Object(params)) = req.params.as_mut()
+                {
+                    if params.get("name") == Some(&json!("forbidden_tool")) {
+                        return Err(McpError::InvalidParameters(
+                            "forbidden_tool is disabled by policy".to_string(),
+                        ));
+                    }
+                    params
+                        .entry("_meta".to_string())
+                        .or_insert_with(|| json!({"tenant": "default-tenant"}));
+                }
+                Ok(())
+            }),
+    )
+    .await
+}
+
+async fn call_tool(
+    client: &reqwest::Client,
+    server_url: &str,
+    session_id: &str,
+    params: Value,
+) -> Value {
+    let response = client
+        .post(server_url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", session_id)
+        .json(&json!({"jsonrpc": "2.0", "method": "tools/call", "id": 2, "params": params}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    assert!(
+        response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .starts_with("application/json")
+    );
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn test_interceptor_injects_default_meta_read_by_tool() {
+    let server_url = start_intercepted_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server_url).await;
+
+    let body = call_tool(
+        &client,
+        &server_url,
+        &session_id,
+        json!({"name": "whoami", "arguments": {}}),
+    )
+    .await;
+    assert_eq!(
+        body["result"]["structuredContent"]["result"], "default-tenant",
+        "tool should observe interceptor-injected _meta: {}",
+        body
+    );
+
+    // Client-supplied _meta wins over the interceptor's default
+    let body = call_tool(
+        &client,
+        &server_url,
+        &session_id,
+        json!({"name": "whoami", "arguments": {}, "_meta": {"tenant": "acme"}}),
+    )
+    .await;
+    assert_eq!(body["result"]["structuredContent"]["result"], "acme");
+}
+
+#[tokio::test]
+async fn test_interceptor_rejects_request_with_jsonrpc_error() {
+    let server_url = start_intercepted_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &server_url).await;
+
+    let body = call_tool(
+        &client,
+        &server_url,
+        &session_id,
+        json!({"name": "forbidden_tool", "arguments": {}}),
+    )
+    .await;
+
+    assert_eq!(body["id"], 2);
+    assert!(body.get("result").is_none());
+    assert_eq!(body["error"]["code"], -32602);
+    assert!(
+        body["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("disabled by policy")
+    );
+}
@@ -0,0 +1,271 @@
+//! E2E tests for resource subscriptions.
+//!
+//! Sessions subscribe to a resource URI with `resources/subscribe`; the server's
+//! `notify_resource_updated` then pushes `notifications/resources/updated` over SSE
+//! to exactly those sessions.
+
+use futures::StreamExt;
+use mcp_e2e_shared::spawn_shared_server;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::timeout;
+use turul_mcp_derive::mcp_resource;
+use turul_mcp_protocol::resources::ResourceContent;
+use turul_mcp_server::McpServer;
+use turul_mcp_server::prelude::*;
+use turul_mcp_session_storage::{InMemorySessionStorage, SessionStorage};
+
+#[mcp_resource(uri = "file:///config.json", name = "config", description = "Config")]
+async fn config() -> McpResult<Vec<ResourceContent>> {
+    Ok(vec![ResourceContent::text("file:///config.json", "{}")])
+}
+
+#[mcp_resource(uri = "file:///status.json", name = "status", description = "Status")]
+async fn status() -> McpResult<Vec<ResourceContent>> {
+    Ok(vec![ResourceContent::text("file:///status.json", "{}")])
+}
+
+struct TestServer {
+    server: Arc<McpServer>,
+    storage: Arc<InMemorySessionStorage>,
+    url: String,
+}
+
+async fn start_server() -> TestServer {
+    let storage = Arc::new(InMemorySessionStorage::new());
+    let (server, url) = spawn_shared_server(
+        McpServer::builder()
+            .name("resource-subscriptions-test")
+            .version("1.0.0")
+            .resource_fn(config)
+            .resource_fn(status)
+            .with_session_storage(Arc::clone(&storage)),
+    )
+    .await;
+    TestServer {
+        server,
+        storage,
+        url,
+    }
+}
+
+struct TestSession {
+    client: reqwest::Client,
+    url: String,
+    session_id: String,
+}
+
+impl TestSession {
+    async fn open(url: &str) -> Self {
+        let client = reqwest::Client::new();
+        let response = client
+            .post(url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "method": "initialize",
+                "id": 1,
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "capabilities": {},
+                    "clientInfo": { "name": "resource-subscriptions-test", "version": "1.0.0" }
+                }
+            }))
+            .send()
+            .await
+            .unwrap();
+        let session_id = response
+            .headers()
+            .get("Mcp-Session-Id")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let capabilities: Value = response.json().await.unwrap();
+        assert_eq!(
+            capabilities["result"]["capabilities"]["resources"]["subscribe"],
+            true
+        );
+
+        let session = Self {
+            client,
+            url: url.to_string(),
+            session_id,
+        };
+        session
+            .send(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+            .await;
+        session
+    }
+
+    async fn send(&self, body: Value) -> Value {
+        let response = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .header("Mcp-Session-Id", &self.session_id)
+            .json(&body)
+            .send()
+            .await
+            .unwrap();
+        response.json().await.unwrap_or(Value::Null)
+    }
+
+    async fn subscribe(&self, uri: &str) -> Value {
+        self.send(json!({
+            "jsonrpc": "2.0",
+            "method": "resources/subscribe",
+            "id": 2,
+            "params": { "uri": uri }
+        }))
+        .await
+    }
+
+    async fn unsubscribe(&self, uri: &str) -> Value {
+        self.send(json!({
+            "jsonrpc": "2.0",
+            "method": "resources/unsubscribe",
+            "id": 3,
+            "params": { "uri": uri }
+        }))
+        .await
+    }
+
+    /// Open the GET SSE stream for this session
+    async fn open_event_stream(&self) -> reqwest::Response {
+        self.client
+            .get(&self.url)
+            .header("Accept", "text/event-stream")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .header("Mcp-Session-Id", &self.session_id)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    async fn stored_resource_updates(&self, storage: &InMemorySessionStorage) -> usize {
+        storage
+            .get_events_after(&self.session_id, 0)
+            .await
+            .unwrap()
+            .iter()
+            .filter(|event| event.event_type == "notifications/resources/updated")
+            .count()
+    }
+}
+
+/// Read an SSE stream until `notifications/resources/updated` for `uri` arrives
+async fn next_resource_update(events: reqwest::Response, uri: &str) -> Value {
+    let mut stream = events.bytes_stream();
+    let mut buffer = String::new();
+
+    timeout(Duration::from_secs(5), async {
+        while let Some(chunk) = stream.next().await {
+            buffer.push_str(&String::from_utf8_lossy(&chunk.unwrap()));
+            while let Some(pos) = buffer.find("\n\n") {
+                let event = buffer[..pos].to_string();
+                buffer = buffer[pos + 2..].to_string();
+                for line in event.lines() {
+                    if let Some(data) = line.strip_prefix("data: ")
+                        && let Ok(message) = serde_json::from_str::<Value>(data)
+                        && message["method"] == "notifications/resources/updated"
+                        && message["params"]["uri"] == uri
+                    {
+                        return message;
+                    }
+                }
+            }
+        }
+        panic!("SSE stream ended before the resource update arrived");
+    })
+    .await
+    .expect("Timed out waiting for notifications/resources/updated")
+}
+
+#[tokio::test]
+async fn test_update_reaches_only_subscribed_session() {
+    let test = start_server().await;
+    let subscriber = TestSession::open(&test.url).await;
+    let bystander = TestSession::open(&test.url).await;
+
+    assert_eq!(
+        subscriber.subscribe("file:///config.json").await["result"],
+        json!({})
+    );
+    assert_eq!(
+        bystander.subscribe("file:///status.json").await["result"],
+        json!({})
+    );
+
+    let events = subscriber.open_event_stream().await;
+    let notified = test
+        .server
+        .notify_resource_updated("file:///config.json")
+        .await
+        .unwrap();
+    assert_eq!(notified, 1);
+
+    let update = next_resource_update(events, "file:///config.json").await;
+    assert_eq!(update["jsonrpc"], "2.0");
+    assert!(update.get("id").is_none());
+
+    assert_eq!(subscriber.stored_resource_updates(&test.storage).await, 1);
+    assert_eq!(bystander.stored_resource_updates(&test.storage).await, 0);
+}
+
+#[tokio::test]
+async fn test_unsubscribe_stops_updates() {
+    let test = start_server().await;
+    let session = TestSession::open(&test.url).await;
+
+    session.subscribe("file:///config.json").await;
+    assert_eq!(
+        session.unsubscribe("file:///config.json").await["result"],
+        json!({})
+    );
+
+    let notified = test
+        .server
+        .notify_resource_updated("file:///config.json")
+        .await
+        .unwrap();
+    assert_eq!(notified, 0);
+    assert_eq!(session.stored_resource_updates(&test.storage).await, 0);
+}
+
+#[tokio::test]
+async fn test_subscriptions_end_with_session() {
+    let test = start_server().await;
+    let session = TestSession::open(&test.url).await;
+    session.subscribe("file:///config.json").await;
+
+    session
+        .client
+        .delete(&session.url)
+        .header("Mcp-Session-Id", &session.session_id)
+        .send()
+        .await
+        .unwrap();
+
+    let notified = test
+        .server
+        .notify_resource_updated("file:///config.json")
+        .await
+        .unwrap();
+    assert_eq!(notified, 0);
+}
+
+#[tokio::test]
+async fn test_subscribe_to_unknown_resource_is_rejected() {
+    let test = start_server().await;
+    let session = TestSession::open(&test.url).await;
+
+    let body = session.subscribe("file:///missing.json").await;
+    assert!(body["result"].is_null());
+    assert_eq!(body["error"]["code"], -32002);
+}
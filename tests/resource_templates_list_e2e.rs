@@ -0,0 +1,93 @@
+//! E2E test for `resources/templates/list` and reads resolved against a template
+
+use async_trait::async_trait;
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use turul_mcp_builders::ResourceBuilder;
+use turul_mcp_client::{McpClient, McpClientBuilder};
+use turul_mcp_derive::McpResource;
+use turul_mcp_protocol::ResourceContent;
+use turul_mcp_server::prelude::*;
+
+#[derive(McpResource, Clone, Default)]
+#[resource(
+    name = "user_profile",
+    uri = "file:///user/{id}.json",
+    title = "User Profile",
+    description = "Profile of a single user",
+    mime_type = "application/json"
+)]
+struct UserProfile;
+
+#[async_trait]
+impl McpResource for UserProfile {
+    async fn read(
+        &self,
+        params: Option<Value>,
+        _session: Option<&SessionContext>,
+    ) -> McpResult<Vec<ResourceContent>> {
+        let id = params
+            .as_ref()
+            .and_then(|p| p.get("template_variables"))
+            .and_then(|vars| vars.get("id"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| McpError::missing_param("id"))?;
+        Ok(vec![ResourceContent::text(
+            format!("file:///user/{}.json", id),
+            json!({ "id": id }).to_string(),
+        )])
+    }
+}
+
+async fn connect() -> McpClient {
+    let url = spawn_server(
+        McpServer::builder()
+            .name("resource-templates-test")
+            .version("1.0.0")
+            .resource(UserProfile)
+            .resource(
+                ResourceBuilder::new("file:///app/config.json")
+                    .name("app_config")
+                    .json_content(json!({ "version": "1.0.0" }))
+                    .build()
+                    .unwrap(),
+            ),
+    )
+    .await;
+
+    let client = McpClientBuilder::new().with_url(&url).unwrap().build();
+    client.connect().await.unwrap();
+    client
+}
+
+#[tokio::test]
+async fn test_list_templates_then_read_matching_uri() {
+    let client = connect().await;
+
+    let templates = client.list_resource_templates().await.unwrap();
+    assert_eq!(templates.len(), 1);
+    let template = &templates[0];
+    assert_eq!(template.uri_template, "file:///user/{id}.json");
+    assert_eq!(template.name, "user_profile");
+    assert_eq!(template.title.as_deref(), Some("User Profile"));
+    assert_eq!(
+        template.description.as_deref(),
+        Some("Profile of a single user")
+    );
+    assert_eq!(template.mime_type.as_deref(), Some("application/json"));
+
+    // Templates are listed separately from concrete resources
+    let resources = client.list_resources().await.unwrap();
+    assert_eq!(resources.len(), 1);
+    assert_eq!(resources[0].uri, "file:///app/config.json");
+
+    let result = client.read_resource("file:///user/42.json").await.unwrap();
+    match &result.contents[0] {
+        ResourceContent::Text(text) => {
+            assert_eq!(text.uri, "file:///user/42.json");
+            let value: Value = serde_json::from_str(&text.text).unwrap();
+            assert_eq!(value["id"], "42");
+        }
+        other => panic!("expected text content, got {:?}", other),
+    }
+}
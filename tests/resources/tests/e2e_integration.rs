@@ -334,7 +334,6 @@ async fn test_resource_subscription() {
         .expect("Failed to start server");
     let mut client = McpTestClient::new(server.port());
 
-    // First, verify server correctly advertises that subscription is not supported
     let init_response = client
         .initialize_with_capabilities(TestFixtures::resource_capabilities())
         .await
@@ -345,29 +344,28 @@ async fn test_resource_subscription() {
         .expect("Failed to send initialized");
     let server_capabilities = &init_response["result"]["capabilities"]["resources"];
     assert_eq!(
-        server_capabilities["subscribe"], false,
-        "Server should advertise subscribe=false until implemented"
+        server_capabilities["subscribe"], true,
+        "Server should advertise subscribe=true when resources are registered"
     );
 
-    // Test that subscription request properly returns method not found error
     let result = client
-        .subscribe_resource("file:///subscription/updates")
+        .subscribe_resource("file:///subscribe/updates.json")
         .await
-        .expect("Request should succeed but method should not be found");
-
-    // Should get a JSON-RPC error response for unimplemented method
+        .expect("Failed to subscribe");
     assert!(
-        result.contains_key("error"),
-        "Should return error for unimplemented resources/subscribe method"
-    );
-    let error = result["error"].as_object().unwrap();
-    assert_eq!(
-        error["code"], -32601,
-        "Should return method not found error code"
+        result.contains_key("result"),
+        "Subscribing to a registered resource should succeed: {:?}",
+        result
     );
+
+    // Unknown URIs are rejected rather than silently tracked
+    let result = client
+        .subscribe_resource("file:///subscription/unknown")
+        .await
+        .expect("Request should succeed at the transport level");
     assert!(
-        error["message"].as_str().unwrap().contains("not found"),
-        "Error message should indicate method not found"
+        result.contains_key("error"),
+        "Should return error for unknown resource URI"
     );
 }
 
@@ -0,0 +1,162 @@
+//! E2E tests for `McpServer::register_tool` / `deregister_tool`: a tool added to
+//! a running server is announced with `notifications/tools/list_changed`, listed
+//! and callable; once deregistered it is gone from `tools/list` and calls fail.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use mcp_e2e_shared::{open_session, spawn_shared_server};
+use serde_json::{Value, json};
+use tokio::time::timeout;
+use turul_mcp_builders::ToolBuilder;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::{McpServer, McpTool};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(name = "status", description = "Reports server status")]
+async fn status() -> McpResult<String> {
+    Ok("ok".to_string())
+}
+
+async fn start_server() -> (Arc<McpServer>, String) {
+    spawn_shared_server(
+        McpServer::builder()
+            .name("runtime-tools-test")
+            .version("1.0.0")
+            .tool_fn(status)
+            .list_changed_notifications()
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+async fn request(
+    client: &reqwest::Client,
+    url: &str,
+    session_id: &str,
+    method: &str,
+    params: Value,
+) -> Value {
+    client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", session_id)
+        .json(&json!({ "jsonrpc": "2.0", "method": method, "id": 2, "params": params }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+async fn listed_names(client: &reqwest::Client, url: &str, session_id: &str) -> Vec<String> {
+    let listed = request(client, url, session_id, "tools/list", json!({})).await;
+    listed["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Read SSE frames until a `notifications/tools/list_changed` arrives
+async fn wait_for_list_changed(stream: &mut reqwest::Response) {
+    let mut buffer = String::new();
+    timeout(Duration::from_secs(5), async {
+        while let Some(chunk) = stream.chunk().await.unwrap() {
+            buffer.push_str(&String::from_utf8_lossy(&chunk));
+            if buffer.contains("notifications/tools/list_changed") {
+                return;
+            }
+        }
+        panic!("stream ended without tools/list_changed");
+    })
+    .await
+    .expect("subscriber should receive tools/list_changed");
+}
+
+fn deploy_tool() -> Arc<dyn McpTool> {
+    Arc::new(
+        ToolBuilder::new("deploy")
+            .description("Deploy the current build")
+            .string_param("target", "Where to deploy")
+            .execute(|args| async move { Ok(json!(format!("deployed to {}", args["target"]))) })
+            .build()
+            .unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_register_call_and_deregister_tool() {
+    let (server, url) = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &url).await;
+    let mut stream = client
+        .get(&url)
+        .header("Accept", "text/event-stream")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(listed_names(&client, &url, &session_id).await, ["status"]);
+
+    server.register_tool(deploy_tool()).await.unwrap();
+    wait_for_list_changed(&mut stream).await;
+    assert_eq!(
+        listed_names(&client, &url, &session_id).await,
+        ["deploy", "status"]
+    );
+
+    let called = request(
+        &client,
+        &url,
+        &session_id,
+        "tools/call",
+        json!({ "name": "deploy", "arguments": { "target": "staging" } }),
+    )
+    .await;
+    assert_eq!(called["result"]["isError"], false, "response: {called}");
+    assert!(
+        called["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("deployed to")
+    );
+
+    assert!(server.deregister_tool("deploy").await.unwrap());
+    wait_for_list_changed(&mut stream).await;
+    assert_eq!(listed_names(&client, &url, &session_id).await, ["status"]);
+
+    let gone = request(
+        &client,
+        &url,
+        &session_id,
+        "tools/call",
+        json!({ "name": "deploy", "arguments": { "target": "staging" } }),
+    )
+    .await;
+    assert!(gone.get("error").is_some(), "response: {gone}");
+
+    // Nothing left to remove
+    assert!(!server.deregister_tool("deploy").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_builder_tools_cannot_be_replaced_or_removed() {
+    let (server, _url) = start_server().await;
+
+    let replacement: Arc<dyn McpTool> = Arc::new(
+        ToolBuilder::new("status")
+            .execute(|_| async { Ok(json!("replaced")) })
+            .build()
+            .unwrap(),
+    );
+    assert!(server.register_tool(replacement).await.is_err());
+    assert!(server.deregister_tool("status").await.is_err());
+}
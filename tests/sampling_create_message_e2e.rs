@@ -0,0 +1,234 @@
+//! E2E tests for server-initiated sampling.
+//!
+//! A tool calls `SessionContext::create_message`; the `sampling/createMessage` request
+//! arrives on the tool call's POST SSE stream, and a mock client answers it by POSTing
+//! a JSON-RPC response correlated by id.
+
+use futures::StreamExt;
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::timeout;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_protocol::content::ContentBlock;
+use turul_mcp_protocol::sampling::{CreateMessageParams, Role, SamplingMessage};
+use turul_mcp_server::{McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(
+    name = "ask_model",
+    description = "Delegates a question to the client's model"
+)]
+async fn ask_model(
+    #[param(description = "Question for the model")] question: String,
+    #[param(description = "How long to wait for the client, in milliseconds")] timeout_ms: u64,
+    session: Option<SessionContext>,
+) -> McpResult<String> {
+    let params = CreateMessageParams::new(
+        vec![SamplingMessage {
+            role: Role::User,
+            content: ContentBlock::text(question),
+        }],
+        100,
+    );
+    let result = session
+        .expect("session context")
+        .create_message_with_timeout(params, Duration::from_millis(timeout_ms))
+        .await?;
+    match result.content {
+        ContentBlock::Text { text, .. } => Ok(format!("{} says {}", result.model, text)),
+        other => Ok(format!("{} sent {:?}", result.model, other)),
+    }
+}
+
+struct MockClient {
+    client: reqwest::Client,
+    url: String,
+    session_id: String,
+}
+
+impl MockClient {
+    async fn connect(capabilities: Value) -> Self {
+        let url = spawn_server(
+            McpServer::builder()
+                .name("sampling-test")
+                .version("1.0.0")
+                .tool_fn(ask_model)
+                .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+        )
+        .await;
+
+        let client = reqwest::Client::new();
+        let response = client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "method": "initialize",
+                "id": 1,
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "capabilities": capabilities,
+                    "clientInfo": { "name": "mock-sampling-client", "version": "1.0.0" }
+                }
+            }))
+            .send()
+            .await
+            .unwrap();
+        let session_id = response
+            .headers()
+            .get("Mcp-Session-Id")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let mock = Self {
+            client,
+            url,
+            session_id,
+        };
+        mock.post(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+            .await;
+        mock
+    }
+
+    async fn post(&self, body: Value) -> reqwest::Response {
+        self.client
+            .post(&self.url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "text/event-stream, application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .header("Mcp-Session-Id", &self.session_id)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    /// Call `ask_model`, answering any sampling request with `answer`, and return the
+    /// sampling requests seen plus the final `tools/call` frame
+    async fn call_ask_model(&self, timeout_ms: u64, answer: Option<Value>) -> (Vec<Value>, Value) {
+        let response = self
+            .post(json!({
+                "jsonrpc": "2.0",
+                "method": "tools/call",
+                "id": 2,
+                "params": {
+                    "name": "ask_model",
+                    "arguments": { "question": "What is 6 x 7?", "timeout_ms": timeout_ms }
+                }
+            }))
+            .await;
+
+        let mut stream = response.bytes_stream();
+        let mut buffer = String::new();
+        let mut sampling_requests = Vec::new();
+        timeout(Duration::from_secs(10), async {
+            while let Some(chunk) = stream.next().await {
+                buffer.push_str(&String::from_utf8_lossy(&chunk.unwrap()));
+                while let Some(pos) = buffer.find("\n\n") {
+                    let event = buffer[..pos].to_string();
+                    buffer = buffer[pos + 2..].to_string();
+                    for data in event.lines().filter_map(|l| l.strip_prefix("data: ")) {
+                        let frame: Value = serde_json::from_str(data).unwrap();
+                        if frame["method"] == "sampling/createMessage" {
+                            if let Some(result) = &answer {
+                                let reply = self
+                                    .post(json!({
+                                        "jsonrpc": "2.0",
+                                        "id": frame["id"],
+                                        "result": result
+                                    }))
+                                    .await;
+                                assert_eq!(reply.status(), 202);
+                            }
+                            sampling_requests.push(frame);
+                        } else if frame["id"] == 2 {
+                            return (sampling_requests, frame);
+                        }
+                    }
+                }
+            }
+            panic!("tools/call stream ended without a result");
+        })
+        .await
+        .expect("Timed out waiting for the tools/call result")
+    }
+}
+
+fn tool_text(frame: &Value) -> String {
+    frame["result"]["content"][0]["text"]
+        .as_str()
+        .or_else(|| frame["error"]["message"].as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_tool_receives_client_completion() {
+    let client = MockClient::connect(json!({ "sampling": {} })).await;
+
+    let (requests, result) = client
+        .call_ask_model(
+            5_000,
+            Some(json!({
+                "role": "assistant",
+                "content": { "type": "text", "text": "42" },
+                "model": "mock-model",
+                "stopReason": "endTurn"
+            })),
+        )
+        .await;
+
+    assert_eq!(requests.len(), 1);
+    let request = &requests[0];
+    assert_eq!(request["jsonrpc"], "2.0");
+    assert_eq!(request["params"]["maxTokens"], 100);
+    assert_eq!(
+        request["params"]["messages"][0]["content"]["text"],
+        "What is 6 x 7?"
+    );
+    assert_eq!(result["result"]["isError"], false, "result: {result}");
+    assert!(tool_text(&result).contains("mock-model says 42"));
+}
+
+#[tokio::test]
+async fn test_client_without_sampling_capability_is_not_asked() {
+    let client = MockClient::connect(json!({})).await;
+
+    let (requests, result) = client.call_ask_model(5_000, None).await;
+
+    assert!(requests.is_empty());
+    assert!(
+        tool_text(&result).contains("sampling capability"),
+        "result: {result}"
+    );
+}
+
+#[tokio::test]
+async fn test_unanswered_request_times_out() {
+    let client = MockClient::connect(json!({ "sampling": {} })).await;
+
+    let (requests, result) = client.call_ask_model(300, None).await;
+
+    assert_eq!(requests.len(), 1);
+    assert!(
+        tool_text(&result).contains("did not answer"),
+        "result: {result}"
+    );
+
+    // A late answer no longer matches a pending request
+    let late = client
+        .post(json!({
+            "jsonrpc": "2.0",
+            "id": requests[0]["id"],
+            "result": { "role": "assistant", "content": { "type": "text", "text": "late" }, "model": "m" }
+        }))
+        .await;
+    assert_eq!(late.status(), 400);
+}
@@ -0,0 +1,128 @@
+//! Tests for `#[schema(...)]` range and length constraints on `#[derive(JsonSchema)]`
+
+use serde::Deserialize;
+use serde_json::json;
+use turul_mcp_builders::traits::HasInputSchema;
+use turul_mcp_derive::{JsonSchema, McpTool, mcp_tool};
+use turul_mcp_protocol::McpError;
+use turul_mcp_protocol::schema::{JsonSchemaGenerator, ToJsonSchema};
+use turul_mcp_server::{McpResult, McpTool as McpToolTrait, SessionContext};
+
+#[derive(JsonSchema, Deserialize, Debug, Clone, Default)]
+#[allow(dead_code)]
+struct Settings {
+    #[schema(minimum = 0, maximum = 100)]
+    volume: u8,
+    #[schema(minimum = -1.5, maximum = 1.5)]
+    balance: f64,
+    #[schema(min_length = 1, max_length = 8)]
+    label: Option<String>,
+}
+
+#[derive(McpTool, Default)]
+#[tool(name = "apply_settings", description = "Apply audio settings")]
+struct ApplySettings {
+    #[param(description = "Settings to apply")]
+    settings: Settings,
+}
+
+impl ApplySettings {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<u8> {
+        Ok(self.settings.volume)
+    }
+}
+
+#[mcp_tool(name = "apply_all", description = "Apply several settings")]
+async fn apply_all(
+    #[param(description = "Settings to apply")] settings: Vec<Settings>,
+) -> McpResult<usize> {
+    Ok(settings.len())
+}
+
+#[test]
+fn test_constraints_in_schema() {
+    let schema = serde_json::to_value(Settings::json_schema()).unwrap();
+    let properties = &schema["properties"];
+    assert_eq!(properties["volume"]["type"], "integer");
+    assert_eq!(properties["volume"]["minimum"], 0);
+    assert_eq!(properties["volume"]["maximum"], 100);
+    assert_eq!(properties["balance"]["minimum"], -1.5);
+    assert_eq!(properties["balance"]["maximum"], 1.5);
+    assert_eq!(properties["label"]["minLength"], 1);
+    assert_eq!(properties["label"]["maxLength"], 8);
+
+    let nested = serde_json::to_value(Settings::to_json_schema()).unwrap();
+    assert_eq!(nested["properties"], *properties);
+}
+
+#[test]
+fn test_constraints_in_tool_input_schema() {
+    let tool = ApplySettings::default();
+    let schema = serde_json::to_value(tool.input_schema()).unwrap();
+    let settings = &schema["properties"]["settings"]["properties"];
+    assert_eq!(settings["volume"]["maximum"], 100);
+    assert_eq!(settings["label"]["maxLength"], 8);
+}
+
+#[tokio::test]
+async fn test_valid_arguments_accepted() {
+    let result = ApplySettings::default()
+        .call(
+            json!({"settings": {"volume": 100, "balance": -1.5, "label": "hall"}}),
+            None,
+        )
+        .await;
+    assert!(result.is_ok(), "{:?}", result.err());
+
+    let result = apply_all()
+        .call(
+            json!({"settings": [{"volume": 1, "balance": 0.0}, {"volume": 2, "balance": 1.0}]}),
+            None,
+        )
+        .await;
+    assert!(result.is_ok(), "{:?}", result.err());
+}
+
+#[tokio::test]
+async fn test_out_of_range_arguments_rejected() {
+    let err = ApplySettings::default()
+        .call(json!({"settings": {"volume": 101, "balance": 0.0}}), None)
+        .await
+        .expect_err("volume is above the maximum");
+    assert!(matches!(
+        err,
+        McpError::ParameterOutOfRange { ref param, ref constraint, .. }
+            if param == "settings.volume" && constraint == "must be <= 100"
+    ));
+
+    let err = ApplySettings::default()
+        .call(
+            json!({"settings": {"volume": 1, "balance": 0.0, "label": ""}}),
+            None,
+        )
+        .await
+        .expect_err("label is shorter than min_length");
+    assert!(matches!(
+        err,
+        McpError::ParameterOutOfRange { ref param, .. } if param == "settings.label"
+    ));
+
+    let err = apply_all()
+        .call(
+            json!({"settings": [{"volume": 1, "balance": 0.0}, {"volume": 1, "balance": 2.0}]}),
+            None,
+        )
+        .await
+        .expect_err("second balance is above the maximum");
+    assert!(matches!(
+        err,
+        McpError::ParameterOutOfRange { ref param, .. } if param == "settings[1].balance"
+    ));
+}
+
+#[test]
+fn test_schema_constraint_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("ui/schema_constraint_pass.rs");
+    t.compile_fail("ui/schema_constraint_invalid.rs");
+}
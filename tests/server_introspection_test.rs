@@ -0,0 +1,100 @@
+//! Tests for `McpServer::registered_tools`, `registered_resources` and
+//! `registered_prompts`: a built server reports what was registered without
+//! any protocol round trip.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use turul_mcp_builders::{ResourceBuilder, ToolBuilder};
+use turul_mcp_derive::{McpPrompt, mcp_tool};
+use turul_mcp_server::prelude::*;
+
+#[mcp_tool(name = "add", description = "Add two numbers")]
+async fn add(
+    #[param(description = "First number")] a: f64,
+    #[param(description = "Second number")] b: f64,
+) -> McpResult<f64> {
+    Ok(a + b)
+}
+
+#[derive(McpPrompt)]
+#[prompt(name = "summarize", description = "Summarize a document")]
+struct SummarizePrompt {
+    #[argument(name = "text", description = "Document text", required = true)]
+    text: String,
+}
+
+#[async_trait]
+impl McpPrompt for SummarizePrompt {
+    async fn render(&self, _args: Option<HashMap<String, Value>>) -> McpResult<Vec<PromptMessage>> {
+        Ok(vec![PromptMessage::user_text(format!(
+            "Summarize: {}",
+            self.text
+        ))])
+    }
+}
+
+#[test]
+fn test_registered_items_match_builder() {
+    let echo = ToolBuilder::new("echo")
+        .description("Echo the input")
+        .string_param("text", "Text to echo")
+        .execute(|args| async move { Ok(args) })
+        .build()
+        .unwrap();
+    let config = ResourceBuilder::new("file:///app/config.json")
+        .name("app_config")
+        .description("Application configuration")
+        .json_content(json!({ "version": "1.0.0" }))
+        .build()
+        .unwrap();
+
+    let server = McpServer::builder()
+        .name("introspection-test")
+        .version("1.0.0")
+        .tool(echo)
+        .tool_fn(add)
+        .resource(config)
+        .prompt(SummarizePrompt {
+            text: String::new(),
+        })
+        .build()
+        .unwrap();
+
+    let tools = server.registered_tools();
+    let names: Vec<&str> = tools.iter().map(|tool| tool.name.as_str()).collect();
+    assert_eq!(names, ["add", "echo"]);
+    assert_eq!(tools[0].description.as_deref(), Some("Add two numbers"));
+    assert!(
+        tools[1]
+            .input_schema
+            .properties
+            .as_ref()
+            .unwrap()
+            .contains_key("text")
+    );
+
+    let resources = server.registered_resources();
+    assert_eq!(resources.len(), 1);
+    assert_eq!(resources[0].uri, "file:///app/config.json");
+    assert_eq!(resources[0].name, "app_config");
+
+    let prompts = server.registered_prompts();
+    assert_eq!(prompts.len(), 1);
+    assert_eq!(prompts[0].name, "summarize");
+    assert_eq!(prompts[0].arguments.as_ref().unwrap()[0].name, "text");
+}
+
+#[test]
+fn test_empty_server_registers_nothing() {
+    let server = McpServer::builder()
+        .name("empty")
+        .version("1.0.0")
+        .build()
+        .unwrap();
+
+    assert!(server.registered_tools().is_empty());
+    assert!(server.registered_resources().is_empty());
+    assert!(server.registered_prompts().is_empty());
+}
@@ -4,6 +4,10 @@
 //! across different MCP components (resources, prompts, tools, etc.)
 
 pub mod e2e_utils;
+pub mod server_utils;
 
 // Re-export the main types for convenience
 pub use e2e_utils::{McpTestClient, SessionTestUtils, TestFixtures, TestServerManager};
+pub use server_utils::{
+    build_on_free_port, open_session, spawn_server, spawn_shared_server, wait_for_server,
+};
@@ -0,0 +1,139 @@
+//! In-Process Test Server Helpers
+//!
+//! Runs an `McpServer` inside the test process on a free local port, so each
+//! E2E test file only describes the server it needs.
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Client;
+use serde_json::json;
+use tokio::time::{Instant, sleep};
+use turul_mcp_server::{McpServer, McpServerBuilder};
+
+/// How long [`wait_for_server`] waits for the listener to come up
+const READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Build `builder` bound to a free local port without starting it.
+///
+/// Returns the server and its `/mcp` endpoint URL. Use this when the test
+/// drives the server itself (e.g. `run_with_shutdown`), then call
+/// [`wait_for_server`] before sending requests.
+pub async fn build_on_free_port(builder: McpServerBuilder) -> (McpServer, String) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let server = builder
+        .bind_address(addr)
+        .build()
+        .expect("test server should build");
+    (server, endpoint(addr))
+}
+
+/// Run `builder` in the background on a free local port.
+///
+/// Returns the `/mcp` endpoint URL once the server accepts connections.
+pub async fn spawn_server(builder: McpServerBuilder) -> String {
+    let (server, url) = build_on_free_port(builder).await;
+    spawn_until_ready(url, async move { server.run().await }).await
+}
+
+/// Like [`spawn_server`], but keeps a handle on the running server for tests
+/// that change it at runtime (register tools, notify subscribers, ...).
+pub async fn spawn_shared_server(builder: McpServerBuilder) -> (Arc<McpServer>, String) {
+    let (server, url) = build_on_free_port(builder).await;
+    let server = Arc::new(server);
+    let running = Arc::clone(&server);
+    let url = spawn_until_ready(url, async move { running.run().await }).await;
+    (server, url)
+}
+
+/// Wait until the server behind `url` accepts TCP connections.
+///
+/// Panics if it does not come up within a few seconds.
+pub async fn wait_for_server(url: &str) {
+    let authority = url
+        .trim_start_matches("http://")
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    let deadline = Instant::now() + READY_TIMEOUT;
+    while tokio::net::TcpStream::connect(authority).await.is_err() {
+        assert!(
+            Instant::now() < deadline,
+            "test server at {} did not start within {:?}",
+            url,
+            READY_TIMEOUT
+        );
+        sleep(Duration::from_millis(10)).await;
+    }
+}
+
+/// Run the `initialize` / `notifications/initialized` handshake and return the session id
+pub async fn open_session(client: &Client, url: &str) -> String {
+    let response = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "initialize",
+            "id": 1,
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "e2e-test", "version": "1.0.0" }
+            }
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200, "initialize should succeed");
+    let session_id = response
+        .headers()
+        .get("Mcp-Session-Id")
+        .expect("Server must return session ID")
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let response = client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        response.status(),
+        202,
+        "notifications/initialized should be accepted"
+    );
+
+    session_id
+}
+
+fn endpoint(addr: SocketAddr) -> String {
+    format!("http://127.0.0.1:{}/mcp", addr.port())
+}
+
+async fn spawn_until_ready<F, E>(url: String, run: F) -> String
+where
+    F: Future<Output = Result<(), E>> + Send + 'static,
+    E: std::fmt::Display,
+{
+    tokio::spawn(async move {
+        if let Err(e) = run.await {
+            eprintln!("Server error: {}", e);
+        }
+    });
+    wait_for_server(&url).await;
+    url
+}
@@ -0,0 +1,74 @@
+//! E2E tests for `max_streams_per_session`: once a session holds the maximum
+//! number of SSE streams, another GET stream is refused with 429 while the open
+//! streams keep working, and closing a stream frees its slot.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::json;
+use tokio::time::sleep;
+use turul_mcp_server::McpServer;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("sse-stream-limit-test")
+            .version("1.0.0")
+            .max_streams_per_session(2)
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+async fn open_stream(client: &reqwest::Client, url: &str, session_id: &str) -> reqwest::Response {
+    client
+        .get(url)
+        .header("Accept", "text/event-stream")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", session_id)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_stream_over_limit_gets_429() {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &url).await;
+
+    let first = open_stream(&client, &url, &session_id).await;
+    let second = open_stream(&client, &url, &session_id).await;
+    assert_eq!(first.status(), 200);
+    assert_eq!(second.status(), 200);
+
+    let rejected = open_stream(&client, &url, &session_id).await;
+    assert_eq!(rejected.status(), 429);
+
+    // The open streams are unaffected: requests on the session still succeed
+    let ping = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!({ "jsonrpc": "2.0", "method": "ping", "id": 2 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(ping.status(), 200);
+
+    // Closing a stream frees its slot
+    drop(first);
+    sleep(Duration::from_millis(100)).await;
+    let reopened = open_stream(&client, &url, &session_id).await;
+    assert_eq!(reopened.status(), 200);
+
+    // Each session has its own allowance
+    let other_session = open_session(&client, &url).await;
+    let other = open_stream(&client, &url, &other_session).await;
+    assert_eq!(other.status(), 200);
+    drop(second);
+}
@@ -0,0 +1,172 @@
+//! E2E tests for streaming tools over POST SSE.
+//!
+//! A log-tailing tool pushes three chunks into its `ChunkSink`; a client that sent
+//! `_meta.streamToken` must receive three `notifications/tools/chunk` frames, then
+//! `notifications/tools/complete`, then the aggregated result. A client without a
+//! token gets only the aggregated result.
+
+use async_trait::async_trait;
+use mcp_e2e_shared::{open_session, spawn_server};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use turul_mcp_builders::prelude::*;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_protocol::tools::{ToolAnnotations, ToolSchema};
+use turul_mcp_server::streaming::{TOOL_CHUNK_METHOD, TOOL_COMPLETE_METHOD};
+use turul_mcp_server::{ChunkSink, McpServer, McpStreamingTool, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+const LINES: [&str; 3] = ["booting", "listening on :8080", "ready"];
+
+struct TailLog {
+    input_schema: ToolSchema,
+}
+
+impl HasBaseMetadata for TailLog {
+    fn name(&self) -> &str {
+        "tail_log"
+    }
+}
+
+impl HasDescription for TailLog {
+    fn description(&self) -> Option<&str> {
+        Some("Streams the service log line by line")
+    }
+}
+
+impl HasInputSchema for TailLog {
+    fn input_schema(&self) -> &ToolSchema {
+        &self.input_schema
+    }
+}
+
+impl HasOutputSchema for TailLog {}
+
+impl HasAnnotations for TailLog {
+    fn annotations(&self) -> Option<&ToolAnnotations> {
+        None
+    }
+}
+
+impl HasToolMeta for TailLog {
+    fn tool_meta(&self) -> Option<&HashMap<String, Value>> {
+        None
+    }
+}
+
+impl HasIcons for TailLog {}
+impl HasExecution for TailLog {}
+
+#[async_trait]
+impl McpStreamingTool for TailLog {
+    async fn run(
+        &self,
+        _args: Value,
+        sink: ChunkSink,
+        _session: Option<SessionContext>,
+    ) -> McpResult<()> {
+        for line in LINES {
+            sink.send_text(line).await;
+            sleep(Duration::from_millis(10)).await;
+        }
+        Ok(())
+    }
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("streaming-tool-test")
+            .version("1.0.0")
+            .streaming_tool(TailLog {
+                input_schema: ToolSchema::object(),
+            })
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+/// Call `tail_log` over POST SSE and return every JSON-RPC frame in arrival order
+async fn call_tail_log(params: Value) -> Vec<Value> {
+    let url = start_server().await;
+    let client = reqwest::Client::new();
+    let session_id = open_session(&client, &url).await;
+
+    let response = client
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("Accept", "text/event-stream, application/json")
+        .header("MCP-Protocol-Version", "2025-11-25")
+        .header("Mcp-Session-Id", &session_id)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 2,
+            "params": params
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/event-stream"
+    );
+
+    response
+        .text()
+        .await
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str(data).ok())
+        .collect()
+}
+
+fn result_texts(frame: &Value) -> Vec<&str> {
+    frame["result"]["content"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|block| block["text"].as_str().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_three_chunks_then_completion_then_result() {
+    let frames = call_tail_log(json!({
+        "name": "tail_log",
+        "arguments": {},
+        "_meta": { "streamToken": "tail-1" }
+    }))
+    .await;
+
+    assert_eq!(frames.len(), 5, "frames: {frames:?}");
+    for (sequence, (frame, line)) in frames.iter().zip(LINES).enumerate() {
+        assert_eq!(frame["method"], TOOL_CHUNK_METHOD);
+        assert_eq!(frame["params"]["streamToken"], "tail-1");
+        assert_eq!(frame["params"]["sequence"], sequence);
+        assert_eq!(frame["params"]["content"][0]["text"], line);
+    }
+
+    let complete = &frames[3];
+    assert_eq!(complete["method"], TOOL_COMPLETE_METHOD);
+    assert_eq!(complete["params"]["streamToken"], "tail-1");
+    assert_eq!(complete["params"]["chunks"], 3);
+    assert_eq!(complete["params"]["isError"], false);
+
+    let result = &frames[4];
+    assert_eq!(result["id"], 2);
+    assert_eq!(result_texts(result), LINES);
+}
+
+#[tokio::test]
+async fn test_without_stream_token_only_aggregated_result() {
+    let frames = call_tail_log(json!({ "name": "tail_log", "arguments": {} })).await;
+
+    assert_eq!(frames.len(), 1, "frames: {frames:?}");
+    assert_eq!(frames[0]["id"], 2);
+    assert_eq!(result_texts(&frames[0]), LINES);
+}
@@ -0,0 +1,104 @@
+//! E2E test for `McpClient::call_tool_await_task` against a real HTTP server
+//! with in-memory task storage.
+
+use mcp_e2e_shared::spawn_server;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+use turul_mcp_client::transport::HttpTransport;
+use turul_mcp_client::{ClientConfig, McpClient, McpClientError, TaskPollOptions};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::McpServer;
+use turul_mcp_server::task_storage::InMemoryTaskStorage;
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+#[mcp_tool(
+    name = "slow_add",
+    description = "Add two numbers after a delay",
+    task_support = "optional"
+)]
+async fn slow_add(
+    #[param(description = "First number")] a: f64,
+    #[param(description = "Second number")] b: f64,
+    #[param(description = "Delay in milliseconds")] delay_ms: u64,
+) -> McpResult<f64> {
+    sleep(Duration::from_millis(delay_ms)).await;
+    Ok(a + b)
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("task-await-test")
+            .version("1.0.0")
+            .tool_fn(slow_add)
+            .with_task_storage(Arc::new(InMemoryTaskStorage::new()))
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+async fn connect(server_url: &str) -> McpClient {
+    let client = McpClient::new(
+        Box::new(HttpTransport::new(server_url).unwrap()),
+        ClientConfig::default(),
+    );
+    client.connect().await.unwrap();
+    client
+}
+
+#[tokio::test]
+async fn test_await_task_returns_final_result_after_polling() {
+    let server_url = start_server().await;
+    let client = connect(&server_url).await;
+
+    // The server suggests a 1s poll interval, so this takes a few polls
+    let result = client
+        .call_tool_await_task(
+            "slow_add",
+            json!({"a": 2, "b": 3, "delay_ms": 2500}),
+            TaskPollOptions::default().with_timeout(Duration::from_secs(30)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.is_error, Some(false));
+    assert_eq!(result.structured_content.unwrap()["result"], 5.0);
+
+    client.disconnect().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_await_task_times_out_and_cancels() {
+    let server_url = start_server().await;
+    let client = connect(&server_url).await;
+
+    let outcome = client
+        .call_tool_await_task(
+            "slow_add",
+            json!({"a": 1, "b": 1, "delay_ms": 30_000}),
+            TaskPollOptions::default().with_timeout(Duration::from_millis(300)),
+        )
+        .await;
+    assert!(matches!(outcome, Err(McpClientError::Timeout)));
+
+    // The abandoned task is cancelled in the background
+    let cancelled = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let tasks = client.list_tasks().await.unwrap();
+            if tasks
+                .iter()
+                .any(|t| t.status == turul_mcp_protocol::tasks::TaskStatus::Cancelled)
+            {
+                return;
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await;
+    assert!(cancelled.is_ok(), "timed-out task should be cancelled");
+
+    client.disconnect().await.unwrap();
+}
@@ -0,0 +1,289 @@
+//! E2E tests for in-process TLS termination: clients reach the MCP endpoint over
+//! HTTPS with a self-signed certificate, negotiate HTTP/2 through ALPN when it is
+//! offered, and pick up a reloaded certificate on their next connection.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::{Request, StatusCode, Version};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, ServerName};
+use serde_json::{Value, json};
+use tokio::net::TcpStream;
+use tokio::time::sleep;
+use tokio_rustls::TlsConnector;
+use turul_http_mcp_server::HttpMcpServer;
+use turul_mcp_server::{McpServer, TlsConfig};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures/tls")
+        .join(name)
+}
+
+async fn free_address() -> std::net::SocketAddr {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    listener.local_addr().unwrap()
+}
+
+async fn start_server(tls: TlsConfig) -> u16 {
+    let addr = free_address().await;
+    let server = McpServer::builder()
+        .name("tls-test")
+        .version("1.0.0")
+        .with_session_storage(Arc::new(InMemorySessionStorage::new()))
+        .bind_address(addr)
+        .tls_config(tls)
+        .build()
+        .unwrap();
+
+    tokio::spawn(async move {
+        if let Err(e) = server.run().await {
+            eprintln!("Server error: {}", e);
+        }
+    });
+
+    sleep(Duration::from_millis(200)).await;
+    addr.port()
+}
+
+struct Reply {
+    status: StatusCode,
+    version: Version,
+    body: Bytes,
+}
+
+/// Send `request` over a new HTTPS connection that trusts only the certificate in
+/// `ca_file` and offers `alpn`
+async fn send_https(
+    port: u16,
+    ca_file: &Path,
+    alpn: &[&[u8]],
+    request: Request<Full<Bytes>>,
+) -> io::Result<Reply> {
+    let mut roots = rustls::RootCertStore::empty();
+    for cert in CertificateDer::pem_file_iter(ca_file).unwrap() {
+        roots.add(cert.unwrap()).unwrap();
+    }
+    let mut config = rustls::ClientConfig::builder_with_provider(Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .unwrap()
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    config.alpn_protocols = alpn.iter().map(|p| p.to_vec()).collect();
+
+    let tcp = TcpStream::connect(("127.0.0.1", port)).await?;
+    let stream = TlsConnector::from(Arc::new(config))
+        .connect(ServerName::try_from("localhost").unwrap(), tcp)
+        .await?;
+    let h2 = stream.get_ref().1.alpn_protocol() == Some(b"h2".as_slice());
+    let io = TokioIo::new(stream);
+
+    let response = if h2 {
+        let (mut sender, conn) = hyper::client::conn::http2::handshake(TokioExecutor::new(), io)
+            .await
+            .map_err(io::Error::other)?;
+        tokio::spawn(conn);
+        sender.send_request(request).await
+    } else {
+        let (mut sender, conn) = hyper::client::conn::http1::handshake(io)
+            .await
+            .map_err(io::Error::other)?;
+        tokio::spawn(conn);
+        sender.send_request(request).await
+    }
+    .map_err(io::Error::other)?;
+
+    let status = response.status();
+    let version = response.version();
+    let body = response
+        .into_body()
+        .collect()
+        .await
+        .map_err(io::Error::other)?
+        .to_bytes();
+    Ok(Reply {
+        status,
+        version,
+        body,
+    })
+}
+
+fn initialize_request() -> Request<Full<Bytes>> {
+    let message = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-11-25",
+            "capabilities": {},
+            "clientInfo": { "name": "tls-test-client", "version": "1.0.0" }
+        }
+    });
+    Request::post("/mcp")
+        .header("Host", "localhost")
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json")
+        .body(Full::new(Bytes::from(message.to_string())))
+        .unwrap()
+}
+
+fn health_request() -> Request<Full<Bytes>> {
+    Request::get("/healthz")
+        .header("Host", "localhost")
+        .body(Full::default())
+        .unwrap()
+}
+
+fn server_name(reply: &Reply) -> String {
+    let body: Value = serde_json::from_slice(&reply.body).unwrap();
+    body["result"]["serverInfo"]["name"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_initialize_over_https() {
+    let port = start_server(TlsConfig::from_pem_files(
+        fixture("server-cert.pem"),
+        fixture("server-key.pem"),
+    ))
+    .await;
+
+    let reply = send_https(
+        port,
+        &fixture("server-cert.pem"),
+        &[b"h2", b"http/1.1"],
+        initialize_request(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(reply.status, StatusCode::OK);
+    // HTTP/2 is not offered unless enabled
+    assert_eq!(reply.version, Version::HTTP_11);
+    assert_eq!(server_name(&reply), "tls-test");
+
+    // Plain HTTP to the HTTPS port gets no response
+    let tcp = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    let (mut sender, conn) = hyper::client::conn::http1::handshake(TokioIo::new(tcp))
+        .await
+        .unwrap();
+    tokio::spawn(conn);
+    assert!(sender.send_request(initialize_request()).await.is_err());
+
+    // A client that does not trust the certificate fails the handshake
+    let untrusted = send_https(
+        port,
+        &fixture("rotated-cert.pem"),
+        &[b"http/1.1"],
+        initialize_request(),
+    )
+    .await;
+    assert!(untrusted.is_err());
+}
+
+#[tokio::test]
+async fn test_https_negotiates_http2_when_enabled() {
+    let port = start_server(
+        TlsConfig::from_pem_files(fixture("server-cert.pem"), fixture("server-key.pem"))
+            .http2(true),
+    )
+    .await;
+
+    let reply = send_https(
+        port,
+        &fixture("server-cert.pem"),
+        &[b"h2", b"http/1.1"],
+        initialize_request(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(reply.version, Version::HTTP_2);
+    assert_eq!(server_name(&reply), "tls-test");
+
+    // Clients that only speak HTTP/1.1 are still served
+    let reply = send_https(
+        port,
+        &fixture("server-cert.pem"),
+        &[b"http/1.1"],
+        initialize_request(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(reply.version, Version::HTTP_11);
+}
+
+#[tokio::test]
+async fn test_reload_tls_serves_new_certificate() {
+    let dir = tempfile::tempdir().unwrap();
+    let cert_path = dir.path().join("cert.pem");
+    let key_path = dir.path().join("key.pem");
+    std::fs::copy(fixture("server-cert.pem"), &cert_path).unwrap();
+    std::fs::copy(fixture("server-key.pem"), &key_path).unwrap();
+
+    let addr = free_address().await;
+    let server = Arc::new(
+        HttpMcpServer::builder()
+            .bind_address(addr)
+            .health_checks()
+            .tls(&cert_path, &key_path)
+            .build(),
+    );
+    tokio::spawn({
+        let server = Arc::clone(&server);
+        async move { server.run().await }
+    });
+    sleep(Duration::from_millis(200)).await;
+    let port = addr.port();
+    let original = fixture("server-cert.pem");
+    let rotated = fixture("rotated-cert.pem");
+
+    let reply = send_https(port, &original, &[], health_request()).await;
+    assert_eq!(reply.unwrap().status, StatusCode::OK);
+    assert!(
+        send_https(port, &rotated, &[], health_request())
+            .await
+            .is_err()
+    );
+
+    // A key that does not match the certificate is rejected; the old pair stays
+    std::fs::copy(fixture("rotated-cert.pem"), &cert_path).unwrap();
+    assert!(server.reload_tls().is_err());
+    let reply = send_https(port, &original, &[], health_request()).await;
+    assert_eq!(reply.unwrap().status, StatusCode::OK);
+
+    std::fs::copy(fixture("rotated-key.pem"), &key_path).unwrap();
+    server.reload_tls().unwrap();
+    let reply = send_https(port, &rotated, &[], health_request()).await;
+    assert_eq!(reply.unwrap().status, StatusCode::OK);
+    assert!(
+        send_https(port, &original, &[], health_request())
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_missing_certificate_fails_startup() {
+    let server = HttpMcpServer::builder()
+        .bind_address(free_address().await)
+        .tls(fixture("missing-cert.pem"), fixture("server-key.pem"))
+        .build();
+
+    let err = tokio::time::timeout(Duration::from_secs(5), server.run())
+        .await
+        .expect("startup should fail rather than serve")
+        .unwrap_err();
+    assert!(err.to_string().contains("missing-cert.pem"), "{}", err);
+}
@@ -0,0 +1,127 @@
+//! E2E tests for the `audit` feature: every `tools/call` leaves an
+//! `AuditRecord` with the caller's session, the tool and the outcome.
+
+use serde_json::json;
+use turul_mcp_client::config::ClientConfig;
+use turul_mcp_client::transport::InProcessTransport;
+use turul_mcp_client::{McpClient, McpClientBuilder};
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::{ContentBlock, McpError, McpResult};
+use turul_mcp_server::McpServer;
+use turul_mcp_server::audit::{AuditArgumentMode, AuditOutcome, InMemoryAuditSink, hash_arguments};
+
+#[mcp_tool(name = "add", description = "Add two numbers")]
+async fn add(
+    #[param(description = "First number")] a: f64,
+    #[param(description = "Second number")] b: f64,
+) -> McpResult<f64> {
+    Ok(a + b)
+}
+
+#[mcp_tool(name = "divide", description = "Divide two numbers")]
+async fn divide(
+    #[param(description = "Dividend")] a: f64,
+    #[param(description = "Divisor")] b: f64,
+) -> McpResult<f64> {
+    if b == 0.0 {
+        return Err(McpError::tool_execution("division by zero"));
+    }
+    Ok(a / b)
+}
+
+#[mcp_tool(name = "lookup", description = "Look up a user")]
+async fn lookup(#[param(description = "User email")] email: String) -> McpResult<String> {
+    Err(McpError::tool_error_content(vec![ContentBlock::text(
+        format!("no user {}", email),
+    )]))
+}
+
+async fn connect(sink: InMemoryAuditSink, mode: AuditArgumentMode) -> McpClient {
+    let server = McpServer::builder()
+        .name("audit-test")
+        .version("1.0.0")
+        .tool_fn(add)
+        .tool_fn(divide)
+        .tool_fn(lookup)
+        .audit_sink(sink)
+        .audit_arguments(mode)
+        .build()
+        .unwrap();
+
+    // One attempt per call, so each failure maps to exactly one audit record
+    let mut config = ClientConfig::default();
+    config.retry.max_attempts = 1;
+    let client = McpClientBuilder::new()
+        .with_config(config)
+        .with_transport(Box::new(InProcessTransport::new(
+            server.in_process_service().await,
+        )))
+        .build();
+    client.connect().await.unwrap();
+    client
+}
+
+#[tokio::test]
+async fn test_success_and_failure_are_audited() {
+    let sink = InMemoryAuditSink::new();
+    let client = connect(sink.clone(), AuditArgumentMode::Hash).await;
+    let session_id = client.session_info().await.session_id;
+
+    client
+        .call_tool("add", json!({ "a": 2, "b": 3 }))
+        .await
+        .unwrap();
+    assert!(
+        client
+            .call_tool("divide", json!({ "a": 1, "b": 0 }))
+            .await
+            .is_err()
+    );
+
+    let records = sink.records();
+    assert_eq!(records.len(), 2);
+
+    assert_eq!(records[0].tool, "add");
+    assert_eq!(records[0].method, "tools/call");
+    assert_eq!(records[0].session_id, session_id);
+    assert_eq!(records[0].outcome, AuditOutcome::Success);
+    assert_eq!(
+        records[0].args_hash,
+        hash_arguments(&json!({ "a": 2, "b": 3 }))
+    );
+    assert!(
+        records[0].arguments.is_none(),
+        "hash mode keeps raw arguments out"
+    );
+
+    assert_eq!(records[1].tool, "divide");
+    match &records[1].outcome {
+        AuditOutcome::Error { message } => assert!(message.contains("division by zero")),
+        other => panic!("expected an error outcome, got {:?}", other),
+    }
+    assert!(records[0].timestamp <= records[1].timestamp);
+}
+
+#[tokio::test]
+async fn test_tool_error_results_and_unknown_tools_are_audited() {
+    let sink = InMemoryAuditSink::new();
+    let client = connect(sink.clone(), AuditArgumentMode::Full).await;
+
+    let result = client
+        .call_tool("lookup", json!({ "email": "alice@example.com" }))
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(true));
+    assert!(client.call_tool("missing", json!({})).await.is_err());
+
+    let records = sink.records();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].outcome, AuditOutcome::ToolError);
+    assert_eq!(
+        records[0].arguments,
+        Some(json!({ "email": "alice@example.com" })),
+        "full mode records the arguments"
+    );
+    assert_eq!(records[1].tool, "missing");
+    assert!(matches!(records[1].outcome, AuditOutcome::Error { .. }));
+}
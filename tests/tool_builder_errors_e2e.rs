@@ -0,0 +1,101 @@
+//! E2E tests for `ToolBuilder::fallible_execute`: a typed `McpError` returned by
+//! the closure reaches the client with its own JSON-RPC error code.
+
+use serde_json::{Value, json};
+use turul_mcp_client::transport::{InProcessTransport, Transport};
+use turul_mcp_server::{McpError, McpServer, ToolBuilder};
+
+async fn connect() -> InProcessTransport {
+    let set_volume = ToolBuilder::new("set_volume")
+        .integer_param("level", "Volume from 0 to 10")
+        .fallible_execute(|args| async move {
+            let level = args.get("level").and_then(Value::as_i64).unwrap_or(0);
+            if !(0..=10).contains(&level) {
+                return Err(McpError::param_out_of_range(
+                    "level",
+                    &level.to_string(),
+                    "must be between 0 and 10",
+                ));
+            }
+            Ok(json!({ "level": level }))
+        })
+        .build()
+        .unwrap();
+    let legacy = ToolBuilder::new("legacy")
+        .execute(|_args| async move { Err("legacy failure".to_string()) })
+        .build()
+        .unwrap();
+
+    let server = McpServer::builder()
+        .name("fallible-tool-test")
+        .version("1.0.0")
+        .tool(set_volume)
+        .tool(legacy)
+        .build()
+        .unwrap();
+
+    let transport = InProcessTransport::new(server.in_process_service().await);
+    transport.connect().await.unwrap();
+    transport
+        .send_request(json!({
+            "jsonrpc": "2.0",
+            "id": "init",
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-11-25",
+                "capabilities": {},
+                "clientInfo": { "name": "fallible-tool-client", "version": "1.0.0" }
+            }
+        }))
+        .await
+        .unwrap();
+    transport
+        .send_notification(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .await
+        .unwrap();
+    transport
+}
+
+async fn call(transport: &InProcessTransport, name: &str, arguments: Value) -> Value {
+    transport
+        .send_request(json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": name, "arguments": arguments }
+        }))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_param_out_of_range_is_invalid_params() {
+    let transport = connect().await;
+
+    let ok = call(&transport, "set_volume", json!({ "level": 7 })).await;
+    assert!(ok.get("error").is_none(), "response: {ok}");
+    assert!(
+        ok["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains('7')
+    );
+
+    let response = call(&transport, "set_volume", json!({ "level": 11 })).await;
+    assert_eq!(response["error"]["code"], -32602, "response: {response}");
+    assert!(
+        response["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("level")
+    );
+}
+
+#[tokio::test]
+async fn test_string_errors_remain_tool_execution_errors() {
+    let transport = connect().await;
+
+    let response = call(&transport, "legacy", json!({})).await;
+    assert_ne!(response["error"]["code"], -32602, "response: {response}");
+    assert!(response.to_string().contains("legacy failure"));
+}
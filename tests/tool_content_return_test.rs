@@ -0,0 +1,150 @@
+//! Tests for `#[mcp_tool]` functions that return content blocks or a whole `CallToolResult`,
+//! and for failures reported as `isError` content rather than JSON-RPC errors
+
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use turul_mcp_builders::traits::HasOutputSchema;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_json_rpc_server::RequestParams;
+use turul_mcp_protocol::content::ContentBlock;
+use turul_mcp_protocol::tools::CallToolResult;
+use turul_mcp_protocol::{McpError, ServerCapabilities};
+use turul_mcp_server::{
+    JsonRpcHandler, McpResult, McpTool, SessionAwareToolHandler, SessionManager,
+};
+
+// 1x1 transparent PNG
+const PIXEL_PNG: &str =
+    "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+
+#[mcp_tool(name = "render_chart", description = "Render a chart with a caption")]
+async fn render_chart(
+    #[param(description = "Chart title")] title: String,
+) -> McpResult<Vec<ContentBlock>> {
+    Ok(vec![
+        ContentBlock::text(format!("Chart: {title}")),
+        ContentBlock::image(PIXEL_PNG, "image/png"),
+    ])
+}
+
+#[mcp_tool(name = "check_quota", description = "Check the remaining quota")]
+async fn check_quota() -> McpResult<CallToolResult> {
+    Ok(CallToolResult::error(vec![ContentBlock::text(
+        "Quota exhausted",
+    )]))
+}
+
+#[mcp_tool(name = "double", description = "Double a number")]
+async fn double(#[param(description = "Number to double")] value: f64) -> McpResult<f64> {
+    Ok(value * 2.0)
+}
+
+#[tokio::test]
+async fn test_mixed_content_blocks_pass_through() {
+    let tool = render_chart();
+    assert!(tool.output_schema().is_none());
+
+    let result = tool.call(json!({ "title": "Sales" }), None).await.unwrap();
+
+    assert_eq!(result.content.len(), 2);
+    match &result.content[0] {
+        ContentBlock::Text { text, .. } => assert_eq!(text, "Chart: Sales"),
+        other => panic!("expected text block, got {other:?}"),
+    }
+    match &result.content[1] {
+        ContentBlock::Image {
+            data, mime_type, ..
+        } => {
+            assert_eq!(data, PIXEL_PNG);
+            assert_eq!(mime_type, "image/png");
+        }
+        other => panic!("expected image block, got {other:?}"),
+    }
+    assert_eq!(result.is_error, Some(false));
+    assert!(result.structured_content.is_none());
+}
+
+#[tokio::test]
+async fn test_call_tool_result_is_returned_unchanged() {
+    let result = check_quota().call(json!({}), None).await.unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(result.content.len(), 1);
+    assert!(matches!(
+        &result.content[0],
+        ContentBlock::Text { text, .. } if text == "Quota exhausted"
+    ));
+}
+
+#[tokio::test]
+async fn test_scalar_return_still_wrapped() {
+    let tool = double();
+    assert!(tool.output_schema().is_some());
+
+    let result = tool.call(json!({ "value": 21.0 }), None).await.unwrap();
+
+    assert_eq!(result.structured_content, Some(json!({ "result": 42.0 })));
+    assert_eq!(result.content.len(), 1);
+}
+
+#[mcp_tool(name = "transfer", description = "Transfer funds from the account")]
+async fn transfer(#[param(description = "Amount to transfer")] amount: f64) -> McpResult<String> {
+    if amount <= 0.0 {
+        return Err(McpError::tool_execution("amount must be positive"));
+    }
+    if amount > 100.0 {
+        return Err(McpError::tool_error_content(vec![
+            ContentBlock::text("Insufficient funds: balance is 100.00"),
+            ContentBlock::text(json!({ "balance": 100.0, "requested": amount }).to_string()),
+        ]));
+    }
+    Ok(format!("Transferred {amount:.2}"))
+}
+
+async fn call_transfer(amount: f64) -> Result<Value, McpError> {
+    let mut tools: HashMap<String, Arc<dyn McpTool>> = HashMap::new();
+    tools.insert("transfer".to_string(), Arc::new(transfer()));
+    let session_manager = Arc::new(SessionManager::new(ServerCapabilities::default()));
+    let handler = SessionAwareToolHandler::new(tools, session_manager, false);
+
+    let params = RequestParams::Object(HashMap::from([
+        ("name".to_string(), json!("transfer")),
+        ("arguments".to_string(), json!({ "amount": amount })),
+    ]));
+    handler.handle("tools/call", Some(params), None).await
+}
+
+#[tokio::test]
+async fn test_tool_error_content_becomes_is_error_result() {
+    let result: CallToolResult =
+        serde_json::from_value(call_transfer(250.0).await.unwrap()).unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(result.content.len(), 2);
+    assert!(matches!(
+        &result.content[0],
+        ContentBlock::Text { text, .. } if text.starts_with("Insufficient funds")
+    ));
+    match &result.content[1] {
+        ContentBlock::Text { text, .. } => {
+            let details: Value = serde_json::from_str(text).unwrap();
+            assert_eq!(details["requested"], 250.0);
+        }
+        other => panic!("expected text block, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_execution_error_stays_json_rpc_error() {
+    let error = call_transfer(-5.0).await.unwrap_err();
+
+    assert!(
+        matches!(&error, McpError::ToolExecutionError(message) if message.contains("amount must be positive"))
+    );
+    assert_eq!(error.to_error_object().code, -32010);
+
+    let result: CallToolResult =
+        serde_json::from_value(call_transfer(40.0).await.unwrap()).unwrap();
+    assert_eq!(result.is_error, Some(false));
+}
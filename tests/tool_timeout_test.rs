@@ -0,0 +1,97 @@
+//! Tests for per-tool deadlines (`#[mcp_tool(timeout_ms = ...)]` / `#[tool(timeout_ms = ...)]`)
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+use turul_mcp_derive::{McpTool, mcp_tool};
+use turul_mcp_protocol::McpError;
+use turul_mcp_server::{McpResult, McpTool as McpToolTrait, SessionContext};
+
+static FUNCTION_GUARD_DROPPED: AtomicBool = AtomicBool::new(false);
+static DERIVE_GUARD_DROPPED: AtomicBool = AtomicBool::new(false);
+
+/// Stands in for a connection or lock held across the slow call
+struct Guard(&'static AtomicBool);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[mcp_tool(name = "slow_fetch", description = "Fetch slowly", timeout_ms = 50)]
+async fn slow_fetch(
+    #[param(description = "Delay before answering")] delay_ms: u64,
+) -> McpResult<String> {
+    let _guard = Guard(&FUNCTION_GUARD_DROPPED);
+    tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    Ok("fetched".to_string())
+}
+
+#[derive(McpTool, Default)]
+#[tool(name = "slow_query", description = "Query slowly", timeout_ms = 50)]
+struct SlowQuery {
+    #[param(description = "Delay before answering")]
+    delay_ms: u64,
+}
+
+impl SlowQuery {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        let _guard = Guard(&DERIVE_GUARD_DROPPED);
+        tokio::time::sleep(Duration::from_millis(self.delay_ms)).await;
+        Ok("queried".to_string())
+    }
+}
+
+fn assert_timed_out(err: McpError, tool_name: &str) {
+    assert!(matches!(err, McpError::ToolExecutionError(_)));
+    assert!(
+        err.to_string()
+            .contains(&format!("Tool '{}' timed out after 50ms", tool_name)),
+        "unexpected error: {}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_function_tool_times_out_and_drops_future() {
+    let tool = slow_fetch();
+
+    let started = Instant::now();
+    let err = tool
+        .call(json!({"delay_ms": 10_000}), None)
+        .await
+        .expect_err("call should hit the deadline");
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_timed_out(err, "slow_fetch");
+    assert!(FUNCTION_GUARD_DROPPED.load(Ordering::SeqCst));
+
+    let result = tool.call(json!({"delay_ms": 0}), None).await.unwrap();
+    assert!(
+        serde_json::to_string(&result.structured_content)
+            .unwrap()
+            .contains("fetched")
+    );
+}
+
+#[tokio::test]
+async fn test_derive_tool_times_out_and_drops_future() {
+    let tool = SlowQuery::default();
+
+    let started = Instant::now();
+    let err = tool
+        .call(json!({"delay_ms": 10_000}), None)
+        .await
+        .expect_err("call should hit the deadline");
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_timed_out(err, "slow_query");
+    assert!(DERIVE_GUARD_DROPPED.load(Ordering::SeqCst));
+
+    let result = tool.call(json!({"delay_ms": 0}), None).await.unwrap();
+    assert!(
+        serde_json::to_string(&result.structured_content)
+            .unwrap()
+            .contains("queried")
+    );
+}
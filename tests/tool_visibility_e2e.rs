@@ -0,0 +1,188 @@
+//! E2E tests for per-session tool visibility.
+//!
+//! An admin-only tool declared with `#[mcp_tool(visible_when = ...)]` is listed
+//! and callable only for sessions a middleware has elevated; everyone else sees
+//! it as an unknown tool.
+
+use async_trait::async_trait;
+use mcp_e2e_shared::spawn_server;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use turul_mcp_derive::mcp_tool;
+use turul_mcp_protocol::McpResult;
+use turul_mcp_server::prelude::*;
+use turul_mcp_server::{McpServer, SessionContext};
+use turul_mcp_session_storage::InMemorySessionStorage;
+
+fn is_admin(session: &SessionContext) -> bool {
+    session.get_extension("role") == Some(&json!("admin"))
+}
+
+#[mcp_tool(name = "echo", description = "Echo the input")]
+async fn echo(text: String) -> McpResult<String> {
+    Ok(text)
+}
+
+#[mcp_tool(
+    name = "purge_cache",
+    description = "Purge server caches (admin only)",
+    visible_when = "is_admin"
+)]
+async fn purge_cache() -> McpResult<String> {
+    Ok("purged".to_string())
+}
+
+/// Copies the `X-Role` request header into the `role` extension
+struct RoleMiddleware;
+
+#[async_trait]
+impl McpMiddleware for RoleMiddleware {
+    async fn before_dispatch(
+        &self,
+        ctx: &mut RequestContext<'_>,
+        _session: Option<&dyn turul_mcp_session_storage::SessionView>,
+        _injection: &mut SessionInjection,
+    ) -> Result<(), MiddlewareError> {
+        if let Some(role) = ctx.metadata().get("x-role").cloned() {
+            ctx.set_extension("role", role);
+        }
+        Ok(())
+    }
+}
+
+async fn start_server() -> String {
+    spawn_server(
+        McpServer::builder()
+            .name("tool-visibility-test")
+            .version("1.0.0")
+            .tool_fn(echo)
+            .tool_fn(purge_cache)
+            .middleware(Arc::new(RoleMiddleware))
+            .with_session_storage(Arc::new(InMemorySessionStorage::new())),
+    )
+    .await
+}
+
+struct TestSession {
+    client: reqwest::Client,
+    server_url: String,
+    session_id: String,
+    role: Option<&'static str>,
+}
+
+impl TestSession {
+    async fn open(server_url: &str, role: Option<&'static str>) -> Self {
+        let client = reqwest::Client::new();
+        let response = client
+            .post(server_url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "method": "initialize",
+                "id": 1,
+                "params": {
+                    "protocolVersion": "2025-11-25",
+                    "capabilities": {},
+                    "clientInfo": { "name": "tool-visibility-test", "version": "1.0.0" }
+                }
+            }))
+            .send()
+            .await
+            .unwrap();
+        let session_id = response
+            .headers()
+            .get("Mcp-Session-Id")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let session = Self {
+            client,
+            server_url: server_url.to_string(),
+            session_id,
+            role,
+        };
+        session
+            .send(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+            .await;
+        session
+    }
+
+    async fn send(&self, body: Value) -> Value {
+        let mut request = self
+            .client
+            .post(&self.server_url)
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("MCP-Protocol-Version", "2025-11-25")
+            .header("Mcp-Session-Id", &self.session_id);
+        if let Some(role) = self.role {
+            request = request.header("X-Role", role);
+        }
+        let response = request.json(&body).send().await.unwrap();
+        response.json().await.unwrap_or(Value::Null)
+    }
+
+    async fn tool_names(&self) -> Vec<String> {
+        let body = self
+            .send(json!({ "jsonrpc": "2.0", "method": "tools/list", "id": 2 }))
+            .await;
+        body["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    async fn call(&self, name: &str) -> Value {
+        self.send(json!({
+            "jsonrpc": "2.0",
+            "method": "tools/call",
+            "id": 3,
+            "params": { "name": name, "arguments": { "text": "hi" } }
+        }))
+        .await
+    }
+}
+
+#[tokio::test]
+async fn test_admin_tool_hidden_from_normal_session() {
+    let server_url = start_server().await;
+    let session = TestSession::open(&server_url, None).await;
+
+    assert_eq!(session.tool_names().await, vec!["echo"]);
+}
+
+#[tokio::test]
+async fn test_admin_tool_visible_to_elevated_session() {
+    let server_url = start_server().await;
+    let session = TestSession::open(&server_url, Some("admin")).await;
+
+    assert_eq!(session.tool_names().await, vec!["echo", "purge_cache"]);
+    let body = session.call("purge_cache").await;
+    assert_eq!(body["result"]["structuredContent"]["result"], "purged");
+}
+
+#[tokio::test]
+async fn test_hidden_tool_call_reports_not_found() {
+    let server_url = start_server().await;
+    let session = TestSession::open(&server_url, Some("viewer")).await;
+
+    let hidden = session.call("purge_cache").await;
+    let unknown = session.call("no_such_tool").await;
+
+    // Indistinguishable from a tool that does not exist
+    assert!(hidden["result"].is_null());
+    assert_eq!(hidden["error"]["code"], unknown["error"]["code"]);
+    assert_eq!(
+        hidden["error"]["message"],
+        unknown["error"]["message"]
+            .as_str()
+            .unwrap()
+            .replace("no_such_tool", "purge_cache")
+    );
+}
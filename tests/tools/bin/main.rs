@@ -693,17 +693,21 @@ impl HasInputSchema for LegacyCalculatorTool {
                     (
                         "a".to_string(),
                         JsonSchema::Number {
+                            title: None,
                             description: Some("First number".to_string()),
                             minimum: None,
                             maximum: None,
+                            default: None,
                         },
                     ),
                     (
                         "b".to_string(),
                         JsonSchema::Number {
+                            title: None,
                             description: Some("Second number".to_string()),
                             minimum: None,
                             maximum: None,
+                            default: None,
                         },
                     ),
                 ]))
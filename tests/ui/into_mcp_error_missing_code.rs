@@ -0,0 +1,8 @@
+use turul_mcp_derive::IntoMcpError;
+
+#[derive(Debug, thiserror::Error, IntoMcpError)]
+#[error("quota exceeded")]
+#[mcp(message = "no code given")]
+struct QuotaExceeded;
+
+fn main() {}
@@ -0,0 +1,41 @@
+use turul_mcp_derive::IntoMcpError;
+use turul_mcp_protocol::{McpError, McpResult};
+
+#[derive(Debug, thiserror::Error, IntoMcpError)]
+enum FetchError {
+    #[error("timed out after {0}s")]
+    #[mcp(code = -32070)]
+    Timeout(u64),
+    #[error("blocked host")]
+    #[mcp(code = -32071, message = "host {host} is blocked", data = host)]
+    Blocked { host: String },
+    #[error("connection reset")]
+    Reset,
+}
+
+#[derive(Debug, thiserror::Error, IntoMcpError)]
+#[error("rate limited, retry in {retry_after}s")]
+#[mcp(code = -32072, data = retry_after)]
+struct RateLimited {
+    retry_after: u64,
+}
+
+#[derive(Debug, thiserror::Error, IntoMcpError)]
+#[error("opaque failure")]
+struct Opaque;
+
+fn fetch() -> Result<String, FetchError> {
+    Err(FetchError::Timeout(30))
+}
+
+fn tool_body() -> McpResult<String> {
+    Ok(fetch()?)
+}
+
+fn main() {
+    assert_eq!(tool_body().unwrap_err().to_error_object().code, -32070);
+    let err: McpError = RateLimited { retry_after: 5 }.into();
+    assert_eq!(err.to_error_object().code, -32072);
+    let err: McpError = Opaque.into();
+    assert!(matches!(err, McpError::ToolExecutionError(_)));
+}
@@ -0,0 +1,56 @@
+use turul_mcp_derive::McpTool;
+use turul_mcp_server::{McpResult, SessionContext};
+
+#[derive(McpTool, Default)]
+#[tool(name = "limit", description = "String default on an integer")]
+struct Limit {
+    #[param(description = "Maximum results", default = "ten")]
+    limit: Option<u32>,
+}
+
+#[derive(McpTool, Default)]
+#[tool(name = "page", description = "Negative default on an unsigned integer")]
+struct Page {
+    #[param(description = "Page number", default = -1)]
+    page: Option<u8>,
+}
+
+#[derive(McpTool, Default)]
+#[tool(name = "language", description = "Integer default on a string")]
+struct Language {
+    #[param(description = "Result language", default = 1)]
+    language: Option<String>,
+}
+
+#[derive(McpTool, Default)]
+#[tool(name = "tags", description = "Default on a list")]
+struct Tags {
+    #[param(description = "Tags", default = "all")]
+    tags: Option<Vec<String>>,
+}
+
+impl Limit {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(String::new())
+    }
+}
+
+impl Page {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(String::new())
+    }
+}
+
+impl Language {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(String::new())
+    }
+}
+
+impl Tags {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(String::new())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use turul_mcp_derive::JsonSchema;
+
+#[derive(JsonSchema)]
+enum Shape {
+    Point,
+    Circle(f64),
+}
+
+fn main() {}
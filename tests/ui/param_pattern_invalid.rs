@@ -0,0 +1,17 @@
+use turul_mcp_derive::McpTool;
+use turul_mcp_server::{McpResult, SessionContext};
+
+#[derive(McpTool, Default)]
+#[tool(name = "lookup_country", description = "Look up a country")]
+struct LookupCountry {
+    #[param(description = "ISO 3166-1 alpha-2 code", pattern = "^[A-Z{2}$")]
+    code: String,
+}
+
+impl LookupCountry {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(self.code.clone())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use turul_mcp_derive::{McpTool, mcp_tool};
+use turul_mcp_server::{McpResult, SessionContext};
+
+#[derive(McpTool, Default)]
+#[tool(name = "lookup_country", description = "Look up a country")]
+struct LookupCountry {
+    #[param(description = "ISO 3166-1 alpha-2 code", pattern = "^[A-Z]{2}$")]
+    code: String,
+}
+
+impl LookupCountry {
+    async fn execute(&self, _session: Option<SessionContext>) -> McpResult<String> {
+        Ok(self.code.clone())
+    }
+}
+
+#[mcp_tool(name = "check_version", description = "Check a version string")]
+async fn check_version(
+    #[param(description = "Semantic version", pattern = r"^\d+\.\d+\.\d+$")] version: String,
+) -> McpResult<String> {
+    Ok(version)
+}
+
+fn main() {
+    let _ = LookupCountry::default();
+    let _ = check_version();
+}
@@ -0,0 +1,21 @@
+use turul_mcp_derive::JsonSchema;
+
+#[derive(JsonSchema)]
+struct Volume {
+    #[schema(min_length = 1)]
+    level: u8,
+}
+
+#[derive(JsonSchema)]
+struct Label {
+    #[schema(maximum = 10)]
+    text: String,
+}
+
+#[derive(JsonSchema)]
+struct Range {
+    #[schema(minimum = 10, maximum = 1)]
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use turul_mcp_derive::JsonSchema;
+
+#[derive(JsonSchema)]
+struct Settings {
+    #[schema(minimum = 0, maximum = 100)]
+    volume: u8,
+    #[schema(minimum = -1.5, maximum = 1.5)]
+    balance: f64,
+    #[schema(min_length = 1, max_length = 64)]
+    label: Option<String>,
+}
+
+fn main() {
+    let _ = Settings {
+        volume: 0,
+        balance: 0.0,
+        label: None,
+    };
+}
@@ -0,0 +1,10 @@
+use turul_mcp_derive::McpToolError;
+
+#[derive(Debug, thiserror::Error, McpToolError)]
+enum LookupError {
+    #[error("missing key")]
+    #[mcp_error(message = "no code given")]
+    Missing,
+}
+
+fn main() {}
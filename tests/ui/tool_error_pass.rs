@@ -0,0 +1,27 @@
+use turul_mcp_derive::McpToolError;
+use turul_mcp_protocol::{McpError, McpResult};
+
+#[derive(Debug, thiserror::Error, McpToolError)]
+enum LookupError {
+    #[error("missing key {0}")]
+    #[mcp_error(code = -32060)]
+    Missing(String),
+    #[error("bad key")]
+    #[mcp_error(code = -32061, message = "key {key} rejected", data = "reason")]
+    Rejected { key: String, reason: String },
+    #[error("unknown failure")]
+    Unknown,
+}
+
+fn lookup(key: &str) -> Result<String, LookupError> {
+    Err(LookupError::Missing(key.to_string()))
+}
+
+fn tool_body() -> McpResult<String> {
+    Ok(lookup("a")?)
+}
+
+fn main() {
+    let err: McpError = tool_body().unwrap_err();
+    assert_eq!(err.to_error_object().code, -32060);
+}